@@ -9,10 +9,20 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 use crate::kubernetes::watch::{
     PodWatcher, DeploymentWatcher, StatefulSetWatcher, DaemonSetWatcher,
-    ReplicaSetWatcher, ServiceWatcher, CronJobWatcher
+    ReplicaSetWatcher, ServiceWatcher, CronJobWatcher, JobProgressWatcher, WarningFeedWatcher
 };
+use crate::kubernetes::shared_watch::SharedWatcher;
+use crate::kubernetes::list_delta::ListDeltaStore;
+use crate::kubernetes::prefetch::PrefetchCache;
+use crate::kubernetes::keepalive::ClientKeepalive;
+use crate::kubernetes::trash::TrashStore;
+use crate::kubernetes::guardrails::{ConfirmationStore, GuardrailPaths};
 use crate::kubernetes::exec::ExecSession;
 use crate::kubernetes::port_forward::PortForwardSession;
+use crate::kubernetes::notifier::IncidentNotifier;
+use crate::kubernetes::drift::DriftScheduler;
+use crate::kubernetes::scaling::ScalingScheduler;
+use crate::types::{FormatSettings, KuboardProfile};
 use std::collections::HashMap;
 // use crate::commands::optimized::ClusterCache;
 
@@ -28,8 +38,23 @@ pub struct AppState {
     pub replicaset_watcher: Arc<RwLock<ReplicaSetWatcher>>,
     pub service_watcher: Arc<RwLock<ServiceWatcher>>,
     pub cronjob_watcher: Arc<RwLock<CronJobWatcher>>,
+    pub job_progress_watcher: Arc<RwLock<JobProgressWatcher>>,
+    pub warning_feed_watcher: Arc<RwLock<WarningFeedWatcher>>,
+    pub shared_watcher: Arc<RwLock<SharedWatcher>>,
+    pub list_snapshots: Arc<RwLock<ListDeltaStore>>,
+    pub prefetch: Arc<RwLock<PrefetchCache>>,
+    pub client_keepalive: Arc<RwLock<ClientKeepalive>>,
+    pub trash: Arc<RwLock<TrashStore>>,
+    pub confirmations: Arc<RwLock<ConfirmationStore>>,
+    // None without a local data directory, which makes guarded mutations fail closed
+    pub guardrail_paths: Option<GuardrailPaths>,
     pub exec_sessions: Arc<RwLock<HashMap<String, ExecSession>>>,
     pub port_forward_sessions: Arc<RwLock<HashMap<String, PortForwardSession>>>,
+    pub incident_notifier: Arc<RwLock<IncidentNotifier>>,
+    pub drift_scheduler: Arc<RwLock<DriftScheduler>>,
+    pub scaling_scheduler: Arc<RwLock<ScalingScheduler>>,
+    pub profile: Arc<RwLock<KuboardProfile>>,
+    pub format_settings: Arc<RwLock<FormatSettings>>,
     // pub cluster_cache: Arc<RwLock<Option<ClusterCache>>>,
 }
 
@@ -46,8 +71,22 @@ impl AppState {
             replicaset_watcher: Arc::new(RwLock::new(ReplicaSetWatcher::new())),
             service_watcher: Arc::new(RwLock::new(ServiceWatcher::new())),
             cronjob_watcher: Arc::new(RwLock::new(CronJobWatcher::new())),
+            job_progress_watcher: Arc::new(RwLock::new(JobProgressWatcher::new())),
+            warning_feed_watcher: Arc::new(RwLock::new(WarningFeedWatcher::new())),
+            shared_watcher: Arc::new(RwLock::new(SharedWatcher::new())),
+            list_snapshots: Arc::new(RwLock::new(ListDeltaStore::new())),
+            prefetch: Arc::new(RwLock::new(PrefetchCache::new())),
+            client_keepalive: Arc::new(RwLock::new(ClientKeepalive::new())),
+            trash: Arc::new(RwLock::new(TrashStore::new())),
+            confirmations: Arc::new(RwLock::new(ConfirmationStore::new())),
+            guardrail_paths: GuardrailPaths::user_data().ok(),
             exec_sessions: Arc::new(RwLock::new(HashMap::new())),
             port_forward_sessions: Arc::new(RwLock::new(HashMap::new())),
+            incident_notifier: Arc::new(RwLock::new(IncidentNotifier::new())),
+            drift_scheduler: Arc::new(RwLock::new(DriftScheduler::new())),
+            scaling_scheduler: Arc::new(RwLock::new(ScalingScheduler::new())),
+            profile: Arc::new(RwLock::new(KuboardProfile::default())),
+            format_settings: Arc::new(RwLock::new(FormatSettings::default())),
             // cluster_cache: Arc::new(RwLock::new(Some(ClusterCache::new()))),
         }
     }
@@ -4,22 +4,28 @@
 // Kuboard Tauri Commands Module
 // This module contains all Tauri command functions with kuboard_ prefix
 
-use tauri::State;
-use kube::Api;
-use kube::api::DeleteParams;
+use tauri::{AppHandle, Emitter, Runtime, State};
+use kube::{Api, Client};
+use kube::api::{DeleteParams, DynamicObject, ListParams, Patch, PatchParams};
 use k8s_openapi::api::{
-    apps::v1::{Deployment, ReplicaSet, StatefulSet, DaemonSet},
+    apps::v1::{ControllerRevision, Deployment, ReplicaSet, StatefulSet, DaemonSet},
     batch::v1::{CronJob, Job},
-    core::v1::{Node, Namespace, Pod, Service, ConfigMap, Secret, Endpoints},
+    core::v1::{Node, Namespace, Pod, PodTemplateSpec, Service, ConfigMap, Secret, Endpoints, Event, PersistentVolume, PersistentVolumeClaim},
+    networking::v1::Ingress,
+    storage::v1::VolumeAttachment,
 };
-use tracing::{error, info, warn};
+use tracing::{debug, error, info, warn};
 
 use crate::app_state::AppState;
 use crate::types::*;
 use crate::kubernetes::{
     kuboard_load_kubeconfig,
     kuboard_create_client_from_context,
-    kuboard_calculate_cluster_metrics,
+    kuboard_build_pod_describe,
+    kuboard_build_service_details,
+    kuboard_service_is_external_name,
+    kuboard_service_pod_selector,
+    kuboard_pod_controlled_by,
 };
 use crate::metrics::{
     kuboard_fetch_node_metrics_real,
@@ -27,11 +33,145 @@ use crate::metrics::{
     kuboard_fetch_pod_metrics_real,
     kuboard_fetch_pod_metrics_history,
     kuboard_check_metrics_server_availability,
+    get_pod_metrics,
+    get_namespace_pod_metrics,
+    MetricsDataPoint,
 };
 use crate::kubernetes::{kuboard_fetch_pod_events, kuboard_fetch_pod_logs};
-use crate::kubernetes::exec::start_exec_session;
+use crate::kubernetes::exec::{attach_exec_stream, start_exec_session, ExecOutputEvent, EXEC_OUTPUT_EVENT};
+use crate::kubernetes::workloads::{kuboard_suspend_workload_replicas, kuboard_resume_workload_replicas};
+use crate::kubernetes::node_shell::{cleanup_node_shell, kuboard_node_shell_command, start_node_shell};
+use crate::kubernetes::files::{exec_capture, kuboard_list_pod_directory, kuboard_read_pod_file, kuboard_stat_pod_file};
+use crate::kubernetes::saved_commands::{
+    kuboard_saved_commands_path,
+    kuboard_add_saved_command,
+    kuboard_list_saved_commands_for_context,
+    kuboard_remove_saved_command,
+    kuboard_find_saved_command,
+    kuboard_mark_saved_command_run,
+    kuboard_filter_log_lines,
+    MAX_SAVED_COMMAND_OUTPUT_BYTES,
+    SAVED_COMMAND_TIMEOUT,
+};
+use crate::kubernetes::recording::{
+    ExecRecorder,
+    kuboard_recordings_dir,
+    kuboard_list_recordings,
+    kuboard_read_recording,
+    kuboard_delete_recording,
+};
 use crate::kubernetes::port_forward::start_port_forward_session;
+use crate::kubernetes::notifier::{IncidentRule, NotifierRuleStatus};
+use crate::kubernetes::drift::DriftAlert;
+use crate::kubernetes::clone::kuboard_clone_namespace_resources;
+use crate::kubernetes::progress::ProgressReporter;
+use crate::kubernetes::manifests::{kuboard_export_manifest, kuboard_get_live_object, kuboard_parse_manifest, kuboard_render_manifest};
+use crate::kubernetes::schema::kuboard_validate_manifest;
+use crate::kubernetes::service_accounts::{kuboard_map_service_accounts, RbacSnapshot};
+use crate::kubernetes::rbac::{kuboard_who_can_subjects, RbacAction};
+use crate::kubernetes::best_practices::kuboard_scan_namespace_best_practices;
+use crate::kubernetes::deprecations::kuboard_find_deprecated_api_usage;
+use crate::kubernetes::upgrade::kuboard_check_upgrade_readiness;
+use crate::kubernetes::resilience::kuboard_find_resilience_gaps;
+use crate::kubernetes::placement::kuboard_get_pod_placement;
+use crate::kubernetes::topology::{kuboard_find_zone_concentrations, kuboard_list_topology_zones};
+use crate::kubernetes::endpoint_probe::{kuboard_probe_endpoints, ProbeMode};
+use crate::kubernetes::http_probe::kuboard_send_http_probe;
+use crate::kubernetes::dns::kuboard_summarize_cluster_dns;
+use crate::kubernetes::ingress::{kuboard_build_ingress_route_table, kuboard_create_ingress_checked, kuboard_match_ingress_route};
+use crate::kubernetes::tls::{kuboard_inspect_tls_certificates, DEFAULT_EXPIRY_WINDOW_DAYS};
+use crate::kubernetes::namespace_access::{
+    kuboard_namespace_filters_path,
+    kuboard_load_namespace_filter,
+    kuboard_save_namespace_filter,
+    kuboard_discover_namespaces,
+    kuboard_list_accessible_namespaces,
+    kuboard_list_accessible,
+    kuboard_list_scoped,
+    kuboard_list_scoped_page,
+    kuboard_namespace_allowed,
+};
+use crate::kubernetes::namespace_overview::kuboard_namespace_detail;
+use crate::kubernetes::config_reload::{kuboard_configmap_consumers, kuboard_restart_consumer, kuboard_set_configmap_key};
+use crate::kubernetes::profiles::{
+    kuboard_profile_path,
+    kuboard_save_profile,
+    kuboard_profile_allows_command,
+    kuboard_profile_namespace_filter,
+    kuboard_profile_object_rejection,
+};
+use crate::kubernetes::rate_limit::{kuboard_rate_limits_path, kuboard_load_rate_limit, kuboard_save_rate_limit};
+use crate::kubernetes::shared_watch::kuboard_shared_watch_kinds;
+use crate::kubernetes::list_delta::{kuboard_compute_list_delta, kuboard_get_kind, kuboard_list_kind};
+use crate::kubernetes::compression::{kuboard_encode_payload, DEFAULT_COMPRESSION_THRESHOLD};
+use crate::kubernetes::prefetch::{kuboard_build_cluster_overview, kuboard_prefetch_context};
+use crate::kubernetes::keepalive::KEEPALIVE_INTERVAL;
+use crate::kubernetes::auth_monitor::{kuboard_apply_refreshed_client, kuboard_refresh_credentials};
+use crate::kubernetes::trash::{kuboard_capture_deleted_object, kuboard_restore_trash_entry, kuboard_trash_entry};
+use crate::kubernetes::batch::{
+    kuboard_create_cronjob_from_request, kuboard_create_job_from_request, kuboard_validate_cron_schedule,
+};
+use crate::kubernetes::pvc::{kuboard_create_pvc_checked, kuboard_pvc_mounts, kuboard_pvc_summary};
+use crate::kubernetes::persistent_volumes::{kuboard_pv_summary, kuboard_volume_binding};
+use crate::kubernetes::expose::kuboard_expose_workload_service;
+use crate::kubernetes::service_urls::kuboard_service_external_access;
+use crate::kubernetes::load_balancer::kuboard_inspect_load_balancer;
+use crate::kubernetes::addons::kuboard_list_cluster_addons;
+use crate::kubernetes::metrics_server::kuboard_deploy_metrics_server;
+use crate::kubernetes::context_metadata::{kuboard_load_context_metadata, kuboard_save_context_metadata};
+use crate::kubernetes::guardrails::{kuboard_decide_mutation, GuardrailPaths, MutationDecision};
+use crate::kubernetes::audit::{kuboard_append_audit_entry, kuboard_read_audit_log};
+use crate::utils::{kuboard_format_cpu_with, kuboard_format_memory_with, kuboard_validate_format_settings};
+use crate::quantity::{kuboard_parse_bytes, kuboard_parse_cpu};
+use crate::kubernetes::list_view::{kuboard_list_view, kuboard_pod_list, kuboard_pod_usage, kuboard_workload_list};
+use crate::kubernetes::manifest_search::{kuboard_manifest_query, kuboard_search_kind, kuboard_search_scoped, DEFAULT_SEARCH_KINDS};
+use crate::kubernetes::field_managers::kuboard_field_ownership;
+use crate::kubernetes::conflict::{kuboard_replace_with_retry, ConflictError};
+use crate::kubernetes::change_cause::{kuboard_change_cause, kuboard_record_change_cause, kuboard_record_change_cause_json};
+use crate::kubernetes::workload_status::{kuboard_daemonset_summary, kuboard_deployment_summary, kuboard_statefulset_summary};
+use crate::kubernetes::pod_security::{
+    kuboard_check_pod_security_level, kuboard_patch_pod_security_labels, kuboard_pod_security_labels,
+};
+use crate::kubernetes::eviction::kuboard_eviction_order;
+use crate::kubernetes::csi::{
+    kuboard_csi_driver_health, kuboard_fetch_csi_inventory, kuboard_volume_attach_issues, kuboard_volume_attachment_info,
+};
+use crate::kubernetes::ephemeral_storage::{
+    kuboard_fetch_ephemeral_usage, kuboard_fetch_ephemeral_usage_on_nodes, kuboard_pod_ephemeral_storage,
+    kuboard_pods_near_ephemeral_limit, DEFAULT_EPHEMERAL_STORAGE_THRESHOLD_PERCENT,
+};
+use crate::kubernetes::apply::{
+    kuboard_apply_object, kuboard_apply_params, kuboard_apply_target, kuboard_parse_manifests, kuboard_prepare_for_apply,
+    kuboard_resolve_apply_target, ApplyTarget,
+};
+use crate::kubernetes::diff::kuboard_diff_target;
+use crate::kubernetes::edit::kuboard_apply_edit;
+use crate::kubernetes::rollout::{
+    kuboard_list_deployment_replicasets, kuboard_replicaset_revision, kuboard_rollback_target, kuboard_rollback_template,
+    kuboard_rollout_history, kuboard_rollout_status,
+};
+use crate::kubernetes::scale_guard::kuboard_managed_scale_warning;
+use crate::kubernetes::dynamic::{kuboard_propagation_policy, kuboard_resolve_gvk, kuboard_resolve_kind};
+use crate::kubernetes::printer_columns::{kuboard_fetch_crd, kuboard_printer_columns, kuboard_resource_table};
+use crate::kubernetes::template_diff::{kuboard_controller_revision_template, kuboard_template_changes};
+use crate::kubernetes::statefulset_volumes::{kuboard_select_orphaned_claims, kuboard_statefulset_volumes};
+use crate::kubernetes::templates::{
+    kuboard_add_template, kuboard_find_template, kuboard_load_templates,
+    kuboard_remove_template, kuboard_render_template, kuboard_templates_path,
+};
+use crate::kubernetes::scaling::{execute_scaling_rule, ScalingExecution, ScalingRule, ScalingRuleRequest};
+use crate::kubernetes::kubectl::kuboard_kubectl_command;
+use crate::kubernetes::cronjobs::{kuboard_cronjob_run, kuboard_is_cronjob_job, kuboard_summarize_cronjob_runs};
+use crate::kubernetes::job_progress::kuboard_job_progress;
+use crate::kubernetes::event_trends::{kuboard_event_trends, DEFAULT_EVENT_BUCKET_MINUTES, DEFAULT_EVENT_WINDOW_MINUTES};
+use crate::kubernetes::selectors::{
+    kuboard_label_selector_matches,
+    kuboard_label_selector_string,
+    kuboard_service_label_selector,
+    kuboard_list_pods_by_selector,
+};
 use serde_json::json;
+use std::collections::HashMap;
 
 // Context Management Commands
 #[tauri::command]
@@ -50,6 +190,13 @@ pub async fn kuboard_list_contexts(state: State<'_, AppState>) -> Result<Context
     };
 
     let current_context = kubeconfig.current_context.clone();
+    let mut metadata = match guardrail_paths(&state).and_then(|paths| kuboard_load_context_metadata(&paths.context_metadata)) {
+        Ok(metadata) => metadata,
+        Err(e) => {
+            warn!("Failed to load context metadata: {}", e);
+            Default::default()
+        }
+    };
     let mut contexts = Vec::new();
 
     // Simple iteration over contexts
@@ -57,12 +204,15 @@ pub async fn kuboard_list_contexts(state: State<'_, AppState>) -> Result<Context
         let context_name = &context_entry.name;
         
         if let Some(context) = &context_entry.context {
+            let context_metadata = metadata.remove(context_name).unwrap_or_default();
             contexts.push(KubeContext {
                 name: context_name.clone(),
                 cluster: context.cluster.clone(),
                 user: context.user.clone().unwrap_or_default(),
                 namespace: context.namespace.clone(),
                 is_current: current_context.as_ref() == Some(context_name),
+                metadata: context_metadata.clone(),
+                protected: context_metadata.is_protected(),
             });
         }
     }
@@ -94,8 +244,154 @@ pub async fn kuboard_list_contexts(state: State<'_, AppState>) -> Result<Context
     })
 }
 
+// Saves display name, colour, environment and group for a context; these come back with kuboard_list_contexts
+#[tauri::command]
+pub async fn kuboard_set_context_metadata(
+    context_name: String,
+    metadata: ContextMetadata,
+    state: State<'_, AppState>
+) -> Result<ContextMetadata, String> {
+    if let Some(kubeconfig) = state.kubeconfig.read().await.as_ref() {
+        if !kubeconfig.contexts.iter().any(|c| c.name == context_name) {
+            return Err(format!("Context '{}' not found", context_name));
+        }
+    }
+
+    let paths = guardrail_paths(&state).map_err(|e| format!("Failed to save context metadata: {}", e))?;
+    let saved = kuboard_save_context_metadata(&paths.context_metadata, &context_name, &metadata)
+        .map_err(|e| format!("Failed to save context metadata: {}", e))?;
+    info!("✅ Saved metadata for context {}", context_name);
+    Ok(saved)
+}
+
+// Production Guardrail Commands
+// Every mutating command calls this first. Read-only contexts refuse the mutation; production ones
+// need a confirmation for exactly this action and target, and are audited either way
+// Context settings and audit log locations; tests point these at a temporary directory
+fn guardrail_paths(state: &AppState) -> anyhow::Result<GuardrailPaths> {
+    state.guardrail_paths.clone().ok_or_else(|| anyhow::anyhow!("Could not find local data directory"))
+}
+
+async fn production_guard(state: &AppState, action: &str, target: &str) -> Result<(), String> {
+    // Without a context the command itself reports the missing client
+    let Some(context) = state.current_context.read().await.clone() else {
+        return Ok(());
+    };
+    context_guard(state, &context, action, target).await.map(|_| ())
+}
+
+// The same check for a mutation that runs against a context other than the active one; returns
+// the confirmation token when a production confirmation was used up
+async fn context_guard(state: &AppState, context: &str, action: &str, target: &str) -> Result<Option<String>, String> {
+    // Fails closed: a guardrail that can't read its settings shouldn't let mutations through
+    let paths = guardrail_paths(state).map_err(|e| format!("Failed to read context settings: {}", e))?;
+    let metadata = kuboard_load_context_metadata(&paths.context_metadata)
+        .map_err(|e| format!("Failed to read context settings: {}", e))?
+        .remove(context)
+        .unwrap_or_default();
+
+    let decision = kuboard_decide_mutation(&metadata, &mut *state.confirmations.write().await, context, action, target);
+    if let Some(outcome) = decision.outcome() {
+        let entry = AuditEntry {
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            context: context.to_string(),
+            action: action.to_string(),
+            target: target.to_string(),
+            outcome: outcome.to_string(),
+            confirmation_token: match &decision {
+                MutationDecision::Confirmed(token) => Some(token.clone()),
+                _ => None,
+            },
+        };
+        if let Err(e) = kuboard_append_audit_entry(&paths.audit_log, &entry) {
+            error!("Failed to write audit log entry for {} on {}: {}", action, target, e);
+            return Err(format!("Failed to write the audit log, so {} on {} was not run: {}", action, target, e));
+        }
+    }
+
+    match decision {
+        MutationDecision::Allowed => Ok(None),
+        MutationDecision::Confirmed(token) => Ok(Some(token)),
+        MutationDecision::ReadOnly => {
+            warn!("Refused {} on {}: context {} is read-only", action, target, context);
+            Err(format!("Context {} is read-only; {} on {} was refused", context, action, target))
+        }
+        MutationDecision::ConfirmationRequired => Err(format!(
+            "Confirmation required: {} is a production context; confirm {} on {} first",
+            context, action, target
+        )),
+    }
+}
+
+// A conflict that outlived its retries is returned as a JSON ConflictReport the frontend can show;
+// anything else as the usual message
+fn mutation_error(error: anyhow::Error, failure: &str) -> String {
+    match error.downcast_ref::<ConflictError>() {
+        Some(ConflictError(report)) => serde_json::to_string(report).unwrap_or_else(|_| report.message.clone()),
+        None => format!("{}: {}", failure, error),
+    }
+}
+
+// The change-cause for a mutation in the current context, naming the kubeconfig user behind it
+async fn change_cause(state: &AppState, action: &str) -> String {
+    let context = state.current_context.read().await.clone();
+    let user = state.kubeconfig.read().await.as_ref().and_then(|kubeconfig| {
+        kubeconfig.contexts.iter()
+            .find(|named| Some(&named.name) == context.as_ref())
+            .and_then(|named| named.context.as_ref())
+            .and_then(|ctx| ctx.user.clone())
+    });
+    kuboard_change_cause(action, context.as_deref(), user.as_deref(), chrono::Utc::now())
+}
+
+// Starts the confirmation flow for one mutation; `action` is the command without its kuboard_ prefix
+// and `target` is namespace/name, or the bare name for cluster-scoped targets. `context` defaults to
+// the active one; scaling rules name the context they scale
+#[tauri::command]
+pub async fn kuboard_request_confirmation(
+    action: String,
+    target: String,
+    context: Option<String>,
+    state: State<'_, AppState>
+) -> Result<ProductionConfirmation, String> {
+    let context = match context {
+        Some(context) => context,
+        None => state.current_context.read().await.clone()
+            .ok_or_else(|| "No active context. Please set a context first.".to_string())?,
+    };
+    Ok(state.confirmations.write().await.issue(&context, &action, &target))
+}
+
+#[tauri::command]
+pub async fn kuboard_confirm_action(
+    token: String,
+    context_name: String,
+    state: State<'_, AppState>
+) -> Result<ProductionConfirmation, String> {
+    let confirmation = state.confirmations.write().await.confirm(&token, &context_name)
+        .map_err(|e| format!("Failed to confirm: {}", e))?;
+    info!("✅ Confirmed {} on {} in {}", confirmation.action, confirmation.target, confirmation.context);
+    Ok(confirmation)
+}
+
+// Newest first, optionally for one context
+#[tauri::command]
+pub async fn kuboard_get_audit_log(
+    context_name: Option<String>,
+    limit: Option<usize>,
+    state: State<'_, AppState>
+) -> Result<Vec<AuditEntry>, String> {
+    let paths = guardrail_paths(&state).map_err(|e| format!("Failed to read audit log: {}", e))?;
+    kuboard_read_audit_log(&paths.audit_log, context_name.as_deref(), limit.unwrap_or(200))
+        .map_err(|e| format!("Failed to read audit log: {}", e))
+}
+
 #[tauri::command]
-pub async fn kuboard_set_context(context_name: String, state: State<'_, AppState>) -> Result<String, String> {
+pub async fn kuboard_set_context<R: Runtime>(
+    context_name: String,
+    app: AppHandle<R>,
+    state: State<'_, AppState>
+) -> Result<String, String> {
     info!("Setting context to: {}", context_name);
     
     let kubeconfig = state.kubeconfig.read().await;
@@ -115,8 +411,11 @@ pub async fn kuboard_set_context(context_name: String, state: State<'_, AppState
     // Create client for the new context
     match kuboard_create_client_from_context(kubeconfig, &context_name).await {
         Ok(client) => {
-            *state.current_client.write().await = Some(client);
+            *state.current_client.write().await = Some(client.clone());
             *state.current_context.write().await = Some(context_name.clone());
+            state.prefetch.write().await.clear();
+            state.client_keepalive.write().await.start(client.clone(), KEEPALIVE_INTERVAL);
+            spawn_context_prefetch(client, context_name.clone(), app, &state).await;
             Ok(format!("Context switched to: {}", context_name))
         }
         Err(e) => {
@@ -126,12 +425,56 @@ pub async fn kuboard_set_context(context_name: String, state: State<'_, AppState
     }
 }
 
+// Warms the first screens in the background; results for a context that is no longer current are dropped
+async fn spawn_context_prefetch<R: Runtime>(client: Client, context_name: String, app: AppHandle<R>, state: &AppState) {
+    let filter = current_namespace_filter(state).await;
+    let current_context = state.current_context.clone();
+    let prefetch = state.prefetch.clone();
+    tokio::spawn(async move {
+        let data = kuboard_prefetch_context(&client, &context_name, &filter).await;
+        if current_context.read().await.as_deref() != Some(context_name.as_str()) {
+            return;
+        }
+        let event = data.warm_event(&context_name);
+        prefetch.write().await.store(&context_name, data);
+        info!("✅ Prefetched data for context {}", context_name);
+        if let Err(e) = app.emit("context-warm", event) {
+            error!("Failed to emit context-warm event: {}", e);
+        }
+    });
+}
+
 #[tauri::command]
 pub async fn kuboard_get_current_context(state: State<'_, AppState>) -> Result<Option<String>, String> {
     let current_context = state.current_context.read().await.clone();
     Ok(current_context)
 }
 
+// Latest warm-up/keepalive ping for the active client
+#[tauri::command]
+pub async fn kuboard_get_connection_status(state: State<'_, AppState>) -> Result<Option<ConnectionStatus>, String> {
+    Ok(state.client_keepalive.read().await.status())
+}
+
+// Retries the active context with a freshly loaded kubeconfig, e.g. after signing in again
+#[tauri::command]
+pub async fn kuboard_reauthenticate(state: State<'_, AppState>) -> Result<String, String> {
+    let context = state.current_context.read().await.clone()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    match kuboard_refresh_credentials(&context).await {
+        Ok((kubeconfig, client)) => {
+            kuboard_apply_refreshed_client(&state, &context, kubeconfig, client).await;
+            info!("✅ Re-authenticated context {}", context);
+            Ok(format!("Re-authenticated context: {}", context))
+        }
+        Err(e) => {
+            error!("Failed to re-authenticate context {}: {}", context, e);
+            Err(format!("Failed to re-authenticate: {}", e))
+        }
+    }
+}
+
 // Cluster Overview Commands
 #[tauri::command]
 pub async fn kuboard_get_cluster_overview(state: State<'_, AppState>) -> Result<ClusterOverview, String> {
@@ -152,6 +495,7 @@ pub async fn kuboard_get_cluster_overview(state: State<'_, AppState>) -> Result<
                     Ok(client) => {
                         *state.current_client.write().await = Some(client.clone());
                         *state.current_context.write().await = Some(current_context.clone());
+                        state.client_keepalive.write().await.start(client.clone(), KEEPALIVE_INTERVAL);
                         info!("Automatically set context to: {}", current_context);
                         client
                     }
@@ -171,27 +515,15 @@ pub async fn kuboard_get_cluster_overview(state: State<'_, AppState>) -> Result<
         .clone()
         .unwrap_or_else(|| "unknown".to_string());
 
-    // Get cluster info
-    let cluster_info = ClusterInfo {
-        name: context_name,
-        server: "unknown".to_string(), // Client doesn't expose apiserver_url in this version
-        version: None,
-    };
-
-    // Count nodes
-    let nodes_api: Api<Node> = Api::all(client.clone());
-    let node_count = match nodes_api.list(&Default::default()).await {
-        Ok(nodes) => nodes.items.len(),
-        Err(e) => {
-            warn!("Failed to get nodes: {}", e);
-            0
-        }
-    };
+    if let Some(overview) = state.prefetch.write().await.take(&context_name, |data| data.overview.take()) {
+        info!("Returning prefetched cluster overview");
+        return Ok(overview);
+    }
 
-    // Count namespaces
-    let namespaces_api: Api<Namespace> = Api::all(client.clone());
-    let namespace_count = match namespaces_api.list(&Default::default()).await {
-        Ok(namespaces) => namespaces.items.len(),
+    // Count namespaces, falling back to per-namespace access for restricted users and honouring the namespace filter
+    let filter = current_namespace_filter(&state).await;
+    let namespace_count = match kuboard_list_accessible_namespaces(&client, &filter).await {
+        Ok(namespaces) => namespaces.len(),
         Err(e) => {
             warn!("Failed to get namespaces: {}", e);
             0
@@ -199,9 +531,8 @@ pub async fn kuboard_get_cluster_overview(state: State<'_, AppState>) -> Result<
     };
 
     // Count pods
-    let pods_api: Api<Pod> = Api::all(client.clone());
-    let pod_count = match pods_api.list(&Default::default()).await {
-        Ok(pods) => pods.items.len(),
+    let pod_count = match kuboard_list_accessible::<Pod>(&client, &filter, &ListParams::default()).await {
+        Ok(pods) => pods.len(),
         Err(e) => {
             warn!("Failed to get pods: {}", e);
             0
@@ -209,42 +540,35 @@ pub async fn kuboard_get_cluster_overview(state: State<'_, AppState>) -> Result<
     };
 
     // Count deployments
-    let deployments_api: Api<Deployment> = Api::all(client.clone());
-    let deployment_count = match deployments_api.list(&Default::default()).await {
-        Ok(deployments) => deployments.items.len(),
+    let deployment_count = match kuboard_list_accessible::<Deployment>(&client, &filter, &ListParams::default()).await {
+        Ok(deployments) => deployments.len(),
         Err(e) => {
             warn!("Failed to get deployments: {}", e);
             0
         }
     };
 
-    // Try to get Kubernetes version
-    let kubernetes_version = match client.apiserver_version().await {
-        Ok(version) => Some(format!("{}.{}", version.major, version.minor)),
-        Err(e) => {
-            warn!("Failed to get Kubernetes version: {}", e);
-            None
-        }
-    };
+    Ok(kuboard_build_cluster_overview(&client, context_name, namespace_count, pod_count, deployment_count).await)
+}
+
+// Well-known addons running in the cluster, with their versions and readiness
+#[tauri::command]
+pub async fn kuboard_get_cluster_addons(state: State<'_, AppState>) -> Result<Vec<ClusterAddon>, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    // Calculate cluster metrics
-    let cluster_metrics = match kuboard_calculate_cluster_metrics(&client).await {
-        Ok(metrics) => Some(metrics),
+    match kuboard_list_cluster_addons(client).await {
+        Ok(addons) => {
+            info!("✅ Detected {} cluster addons", addons.len());
+            Ok(addons)
+        }
         Err(e) => {
-            warn!("Failed to calculate cluster metrics: {}", e);
-            None
+            error!("Failed to detect cluster addons: {}", e);
+            Err(format!("Failed to detect cluster addons: {}", e))
         }
-    };
-
-    Ok(ClusterOverview {
-        cluster_info,
-        node_count,
-        namespace_count,
-        pod_count,
-        deployment_count,
-        kubernetes_version,
-        cluster_metrics,
-    })
+    }
 }
 
 // Resource Commands
@@ -269,53 +593,181 @@ pub async fn kuboard_get_namespaces(state: State<'_, AppState>) -> Result<Vec<Na
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let namespaces_api: Api<Namespace> = Api::all(client.clone());
-    match namespaces_api.list(&Default::default()).await {
-        Ok(namespaces) => Ok(namespaces.items),
-        Err(e) => Err(format!("Failed to get namespaces: {}", e)),
+    let context = state.current_context.read().await.clone().unwrap_or_default();
+    if let Some(namespaces) = state.prefetch.write().await.take(&context, |data| data.namespaces.take()) {
+        info!("Returning prefetched namespaces");
+        return Ok(namespaces);
     }
+
+    let filter = current_namespace_filter(&state).await;
+    kuboard_list_accessible_namespaces(client, &filter).await
+        .map_err(|e| format!("Failed to get namespaces: {}", e))
 }
 
+// A namespace landing page in one call: workloads, pod health, quotas, warnings and top consumers
 #[tauri::command]
-pub async fn kuboard_get_pods(state: State<'_, AppState>) -> Result<Vec<Pod>, String> {
+pub async fn kuboard_get_namespace_detail(
+    namespace: String,
+    state: State<'_, AppState>
+) -> Result<NamespaceDetail, String> {
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let pods_api: Api<Pod> = Api::all(client.clone());
-    match pods_api.list(&Default::default()).await {
-        Ok(pods) => Ok(pods.items),
-        Err(e) => Err(format!("Failed to get pods: {}", e)),
+    let filter = current_namespace_filter(&state).await;
+    if !kuboard_namespace_allowed(&filter, Some(&namespace)) {
+        return Err(format!("Namespace {} is excluded by the namespace filter", namespace));
     }
+    kuboard_namespace_detail(client, &namespace).await
+        .map_err(|e| format!("Failed to get namespace detail: {}", e))
 }
 
 #[tauri::command]
-pub async fn kuboard_get_deployments(state: State<'_, AppState>) -> Result<Vec<Deployment>, String> {
+pub async fn kuboard_get_pods(
+    namespace: Option<String>,
+    label_selector: Option<String>,
+    field_selector: Option<String>,
+    state: State<'_, AppState>
+) -> Result<Vec<Pod>, String> {
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let deployments_api: Api<Deployment> = Api::all(client.clone());
-    match deployments_api.list(&Default::default()).await {
-        Ok(deployments) => Ok(deployments.items),
-        Err(e) => Err(format!("Failed to get deployments: {}", e)),
+    // Prefetched lists cover every accessible namespace and carry no selector
+    if namespace.is_none() && label_selector.is_none() && field_selector.is_none() {
+        let context = state.current_context.read().await.clone().unwrap_or_default();
+        if let Some(pods) = state.prefetch.write().await.take(&context, |data| data.pods.take()) {
+            info!("Returning prefetched pods");
+            return Ok(pods);
+        }
     }
+
+    let filter = current_namespace_filter(&state).await;
+    kuboard_list_scoped::<Pod>(client, &filter, namespace.as_deref(), &selector_params(label_selector.as_deref(), field_selector.as_deref())).await
+        .map_err(|e| format!("Failed to get pods: {}", e))
+}
+
+// One page of pods for very large clusters: pass the returned continue_token back for the next
+// page, which ends when it is None. `limit` defaults to 500
+#[tauri::command]
+pub async fn kuboard_get_pods_page(
+    namespace: Option<String>,
+    label_selector: Option<String>,
+    field_selector: Option<String>,
+    limit: Option<u32>,
+    continue_token: Option<String>,
+    state: State<'_, AppState>
+) -> Result<ListPage<Pod>, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let filter = current_namespace_filter(&state).await;
+    let params = selector_params(label_selector.as_deref(), field_selector.as_deref());
+    kuboard_list_scoped_page::<Pod>(client, &filter, namespace.as_deref(), &params, limit, continue_token.as_deref()).await
+        .map_err(|e| format!("Failed to get pods: {}", e))
+}
+
+// Pods with the kubectl-style STATUS column derived server-side; `sort_by` is name, age, restarts,
+// cpu or memory and `status` a comma-separated list of statuses or phases to keep
+#[tauri::command]
+pub async fn kuboard_get_pod_summaries(
+    namespace: Option<String>,
+    label_selector: Option<String>,
+    field_selector: Option<String>,
+    sort_by: Option<String>,
+    status: Option<String>,
+    state: State<'_, AppState>
+) -> Result<Vec<PodSummary>, String> {
+    let view = kuboard_list_view(sort_by.as_deref(), status.as_deref()).map_err(|e| e.to_string())?;
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let filter = current_namespace_filter(&state).await;
+    let pods = kuboard_list_scoped::<Pod>(client, &filter, namespace.as_deref(), &selector_params(label_selector.as_deref(), field_selector.as_deref())).await
+        .map_err(|e| format!("Failed to get pod summaries: {}", e))?;
+    let usage = match view.sort_by.filter(|key| key.needs_metrics()) {
+        Some(_) => {
+            let metrics = match namespace.as_deref().filter(|namespace| !namespace.is_empty()) {
+                Some(namespace) => get_namespace_pod_metrics(client, namespace).await,
+                None => get_pod_metrics(client).await,
+            };
+            kuboard_pod_usage(&metrics.map_err(|e| format!("Sorting by usage needs metrics-server: {}", e))?.items)
+        }
+        None => HashMap::new(),
+    };
+    Ok(kuboard_pod_list(&pods, &view, &usage))
 }
 
 #[tauri::command]
-pub async fn kuboard_get_services(state: State<'_, AppState>) -> Result<Vec<Service>, String> {
+pub async fn kuboard_get_deployments(
+    namespace: Option<String>,
+    label_selector: Option<String>,
+    field_selector: Option<String>,
+    state: State<'_, AppState>
+) -> Result<Vec<Deployment>, String> {
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let services_api: Api<Service> = Api::all(client.clone());
-    match services_api.list(&Default::default()).await {
-        Ok(services) => Ok(services.items),
-        Err(e) => Err(format!("Failed to get services: {}", e)),
+    // Prefetched lists cover every accessible namespace and carry no selector
+    if namespace.is_none() && label_selector.is_none() && field_selector.is_none() {
+        let context = state.current_context.read().await.clone().unwrap_or_default();
+        if let Some(deployments) = state.prefetch.write().await.take(&context, |data| data.deployments.take()) {
+            info!("Returning prefetched deployments");
+            return Ok(deployments);
+        }
     }
+
+    let filter = current_namespace_filter(&state).await;
+    kuboard_list_scoped::<Deployment>(client, &filter, namespace.as_deref(), &selector_params(label_selector.as_deref(), field_selector.as_deref())).await
+        .map_err(|e| format!("Failed to get deployments: {}", e))
+}
+
+// Deployments with ready/up-to-date/available counts and a health verdict; `status` filters on the
+// verdict and `sort_by` is name or age
+#[tauri::command]
+pub async fn kuboard_get_deployment_summaries(
+    namespace: Option<String>,
+    label_selector: Option<String>,
+    field_selector: Option<String>,
+    sort_by: Option<String>,
+    status: Option<String>,
+    state: State<'_, AppState>
+) -> Result<Vec<WorkloadSummary>, String> {
+    let view = kuboard_list_view(sort_by.as_deref(), status.as_deref()).map_err(|e| e.to_string())?;
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let filter = current_namespace_filter(&state).await;
+    kuboard_list_scoped::<Deployment>(client, &filter, namespace.as_deref(), &selector_params(label_selector.as_deref(), field_selector.as_deref())).await
+        .map_err(|e| format!("Failed to get deployment summaries: {}", e))
+        .and_then(|items| kuboard_workload_list(&items, kuboard_deployment_summary, &view).map_err(|e| e.to_string()))
+}
+
+#[tauri::command]
+pub async fn kuboard_get_services(
+    namespace: Option<String>,
+    label_selector: Option<String>,
+    field_selector: Option<String>,
+    state: State<'_, AppState>
+) -> Result<Vec<Service>, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let filter = current_namespace_filter(&state).await;
+    kuboard_list_scoped::<Service>(client, &filter, namespace.as_deref(), &selector_params(label_selector.as_deref(), field_selector.as_deref())).await
+        .map_err(|e| format!("Failed to get services: {}", e))
 }
 
 #[tauri::command]
@@ -360,82 +812,98 @@ pub async fn kuboard_get_service_endpoints(
     }
 }
 
-
-
+// Externally reachable URLs per Service, for "open in browser"; only Services with at least one URL or a pending load balancer are returned
 #[tauri::command]
-pub async fn kuboard_get_replicasets(state: State<'_, AppState>) -> Result<Vec<ReplicaSet>, String> {
+pub async fn kuboard_get_service_urls(
+    namespace: Option<String>,
+    state: State<'_, AppState>
+) -> Result<Vec<ServiceExternalAccess>, String> {
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let replicasets_api: Api<ReplicaSet> = Api::all(client.clone());
-    match replicasets_api.list(&Default::default()).await {
-        Ok(replicasets) => Ok(replicasets.items),
-        Err(e) => Err(format!("Failed to get replicasets: {}", e)),
-    }
+    let filter = current_namespace_filter(&state).await;
+    let services = kuboard_list_scoped::<Service>(client, &filter, namespace.as_deref(), &ListParams::default()).await
+        .map_err(|e| format!("Failed to get services: {}", e))?;
+
+    // Without access to nodes, node port URLs are left out rather than failing the whole list
+    let nodes = match Api::<Node>::all(client.clone()).list(&ListParams::default()).await {
+        Ok(list) => list.items,
+        Err(e) => {
+            warn!("Failed to list nodes for node port URLs: {}", e);
+            Vec::new()
+        }
+    };
+
+    let access: Vec<ServiceExternalAccess> = services.iter()
+        .map(|service| kuboard_service_external_access(service, &nodes))
+        .filter(|access| access.pending || !access.urls.is_empty())
+        .collect();
+    info!("✅ Found external URLs for {} services", access.len());
+    Ok(access)
 }
 
+// Provisioning state and classified errors for a LoadBalancer Service
 #[tauri::command]
-pub async fn kuboard_get_replicaset(
+pub async fn kuboard_get_load_balancer_status(
     name: String,
     namespace: String,
     state: State<'_, AppState>
-) -> Result<ReplicaSet, String> {
+) -> Result<LoadBalancerStatus, String> {
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let replicasets_api: Api<ReplicaSet> = Api::namespaced(client.clone(), &namespace);
-    match replicasets_api.get(&name).await {
-        Ok(replicaset) => Ok(replicaset),
-        Err(kube::Error::Api(e)) if e.code == 404 => {
-            Err(format!("ReplicaSet {}/{} not found", namespace, name))
+    match kuboard_inspect_load_balancer(client, &name, &namespace).await {
+        Ok(status) => {
+            info!("✅ Load balancer for {}/{} is {} ({} issues)", namespace, name, status.state, status.issues.len());
+            Ok(status)
+        }
+        Err(e) => {
+            error!("Failed to inspect load balancer for {}/{}: {}", namespace, name, e);
+            Err(format!("Failed to inspect load balancer: {}", e))
         }
-        Err(e) => Err(format!("Failed to get replicaset: {}", e)),
     }
 }
 
 #[tauri::command]
-pub async fn kuboard_scale_replicaset(
+pub async fn kuboard_get_service_details(
     name: String,
     namespace: String,
-    replicas: i32,
     state: State<'_, AppState>
-) -> Result<ReplicaSet, String> {
+) -> Result<ServiceDetails, String> {
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let replicasets_api: Api<ReplicaSet> = Api::namespaced(client.clone(), &namespace);
-    
-    // Get current replicaset
-    let mut replicaset = match replicasets_api.get(&name).await {
-        Ok(rs) => rs,
+    let services_api: Api<Service> = Api::namespaced(client.clone(), &namespace);
+    let service = match services_api.get(&name).await {
+        Ok(service) => service,
         Err(kube::Error::Api(e)) if e.code == 404 => {
-            return Err(format!("ReplicaSet {}/{} not found", namespace, name));
+            return Err(format!("Service {}/{} not found", namespace, name));
         }
-        Err(e) => return Err(format!("Failed to get replicaset: {}", e)),
+        Err(e) => return Err(format!("Failed to get service: {}", e)),
     };
 
-    // Update replica count
-    if let Some(spec) = replicaset.spec.as_mut() {
-        spec.replicas = Some(replicas);
+    // ExternalName services resolve through DNS and never have endpoints
+    let endpoints = if kuboard_service_is_external_name(&service) {
+        None
     } else {
-        return Err("ReplicaSet spec is missing".to_string());
-    }
+        let endpoints_api: Api<Endpoints> = Api::namespaced(client.clone(), &namespace);
+        match endpoints_api.get_opt(&name).await {
+            Ok(endpoints) => endpoints,
+            Err(e) => return Err(format!("Failed to get service endpoints: {}", e)),
+        }
+    };
 
-    // Apply the update
-    match replicasets_api.replace(&name, &Default::default(), &replicaset).await {
-        Ok(updated) => Ok(updated),
-        Err(e) => Err(format!("Failed to scale replicaset: {}", e)),
-    }
+    Ok(kuboard_build_service_details(service, endpoints))
 }
 
 #[tauri::command]
-pub async fn kuboard_get_replicaset_pods(
+pub async fn kuboard_get_service_pods(
     name: String,
     namespace: String,
     state: State<'_, AppState>
@@ -445,116 +913,224 @@ pub async fn kuboard_get_replicaset_pods(
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    // Get the replicaset to find its selector
-    let replicasets_api: Api<ReplicaSet> = Api::namespaced(client.clone(), &namespace);
-    let replicaset = match replicasets_api.get(&name).await {
-        Ok(rs) => rs,
+    let services_api: Api<Service> = Api::namespaced(client.clone(), &namespace);
+    let service = match services_api.get(&name).await {
+        Ok(service) => service,
         Err(kube::Error::Api(e)) if e.code == 404 => {
-            return Err(format!("ReplicaSet {}/{} not found", namespace, name));
+            return Err(format!("Service {}/{} not found", namespace, name));
         }
-        Err(e) => return Err(format!("Failed to get replicaset: {}", e)),
+        Err(e) => return Err(format!("Failed to get service: {}", e)),
     };
 
-    // Get selector from replicaset
-    let selector = match replicaset.spec.as_ref() {
-        Some(spec) => &spec.selector,
-        None => return Err("ReplicaSet has no spec".to_string()),
+    // Selector-less and ExternalName services don't target pods directly
+    let Some(selector) = kuboard_service_pod_selector(&service) else {
+        return Ok(Vec::new());
     };
 
-    // List pods with matching labels
+    kuboard_list_pods_by_selector(client, &namespace, &kuboard_service_label_selector(selector))
+        .await
+        .map_err(|e| format!("Failed to list pods: {}", e))
+}
+
+#[tauri::command]
+pub async fn kuboard_get_pod_services(
+    pod_name: String,
+    namespace: String,
+    state: State<'_, AppState>
+) -> Result<Vec<Service>, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
     let pods_api: Api<Pod> = Api::namespaced(client.clone(), &namespace);
-    let pods = match pods_api.list(&Default::default()).await {
-        Ok(pod_list) => pod_list.items,
-        Err(e) => return Err(format!("Failed to list pods: {}", e)),
+    let pod = match pods_api.get(&pod_name).await {
+        Ok(pod) => pod,
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            return Err(format!("Pod {}/{} not found", namespace, pod_name));
+        }
+        Err(e) => return Err(format!("Failed to get pod: {}", e)),
+    };
+    let pod_labels = pod.metadata.labels.unwrap_or_default();
+
+    let services_api: Api<Service> = Api::namespaced(client.clone(), &namespace);
+    let services = match services_api.list(&Default::default()).await {
+        Ok(service_list) => service_list.items,
+        Err(e) => return Err(format!("Failed to list services: {}", e)),
     };
 
-    // Filter pods by selector
-    let matching_pods: Vec<Pod> = pods
+    // The reverse lookup can't be expressed as a label query, so selectors are evaluated here.
+    // An empty or missing selector selects nothing for services.
+    let matching_services: Vec<Service> = services
         .into_iter()
-        .filter(|pod| {
-            if let Some(pod_labels) = pod.metadata.labels.as_ref() {
-                if let Some(match_labels) = selector.match_labels.as_ref() {
-                    match_labels.iter().all(|(key, value)| {
-                        pod_labels.get(key).map_or(false, |v| v == value)
-                    })
-                } else {
-                    false
-                }
-            } else {
-                false
-            }
+        .filter(|service| {
+            kuboard_service_pod_selector(service)
+                .is_some_and(|selector| kuboard_label_selector_matches(&kuboard_service_label_selector(selector), &pod_labels))
         })
         .collect();
 
-    Ok(matching_pods)
+    Ok(matching_services)
 }
 
-// Deployment Commands
+
+
 #[tauri::command]
-pub async fn kuboard_get_deployment(
-    name: String,
-    namespace: String,
+pub async fn kuboard_get_replicasets(
+    namespace: Option<String>,
+    label_selector: Option<String>,
+    field_selector: Option<String>,
     state: State<'_, AppState>
-) -> Result<Deployment, String> {
+) -> Result<Vec<ReplicaSet>, String> {
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let deployments_api: Api<Deployment> = Api::namespaced(client.clone(), &namespace);
-    match deployments_api.get(&name).await {
-        Ok(deployment) => Ok(deployment),
-        Err(kube::Error::Api(e)) if e.code == 404 => {
-            Err(format!("Deployment {}/{} not found", namespace, name))
-        }
-        Err(e) => Err(format!("Failed to get deployment: {}", e)),
-    }
+    let filter = current_namespace_filter(&state).await;
+    kuboard_list_scoped::<ReplicaSet>(client, &filter, namespace.as_deref(), &selector_params(label_selector.as_deref(), field_selector.as_deref())).await
+        .map_err(|e| format!("Failed to get replicasets: {}", e))
 }
 
 #[tauri::command]
-pub async fn kuboard_scale_deployment(
+pub async fn kuboard_get_replicaset(
     name: String,
     namespace: String,
-    replicas: i32,
     state: State<'_, AppState>
-) -> Result<Deployment, String> {
+) -> Result<ReplicaSet, String> {
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let deployments_api: Api<Deployment> = Api::namespaced(client.clone(), &namespace);
-    
-    // Get current deployment
-    let mut deployment = match deployments_api.get(&name).await {
-        Ok(dep) => dep,
+    let replicasets_api: Api<ReplicaSet> = Api::namespaced(client.clone(), &namespace);
+    match replicasets_api.get(&name).await {
+        Ok(replicaset) => Ok(replicaset),
         Err(kube::Error::Api(e)) if e.code == 404 => {
-            return Err(format!("Deployment {}/{} not found", namespace, name));
+            Err(format!("ReplicaSet {}/{} not found", namespace, name))
         }
-        Err(e) => return Err(format!("Failed to get deployment: {}", e)),
-    };
-
-    // Update replica count
-    if let Some(spec) = deployment.spec.as_mut() {
-        spec.replicas = Some(replicas);
-    } else {
-        return Err("Deployment spec is missing".to_string());
-    }
-
-    // Apply the update
-    match deployments_api.replace(&name, &Default::default(), &deployment).await {
-        Ok(updated) => Ok(updated),
-        Err(e) => Err(format!("Failed to scale deployment: {}", e)),
+        Err(e) => Err(format!("Failed to get replicaset: {}", e)),
     }
 }
 
+// A ReplicaSet its Deployment (or another controller) manages is refused with a JSON
+// ManagedScaleWarning pointing at the controller, unless `force` is set
 #[tauri::command]
-pub async fn kuboard_rollback_deployment(
+pub async fn kuboard_scale_replicaset(
     name: String,
     namespace: String,
-    _revision: Option<i64>,
+    replicas: i32,
+    dry_run: Option<bool>,
+    force: Option<bool>,
+    state: State<'_, AppState>
+) -> Result<ReplicaSet, String> {
+    let dry_run = dry_run.unwrap_or(false);
+
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let replicasets_api: Api<ReplicaSet> = Api::namespaced(client.clone(), &namespace);
+    
+    // Get current replicaset
+    let replicaset = match replicasets_api.get(&name).await {
+        Ok(rs) => rs,
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            return Err(format!("ReplicaSet {}/{} not found", namespace, name));
+        }
+        Err(e) => return Err(format!("Failed to get replicaset: {}", e)),
+    };
+
+    // Checked before the guardrail, so a refused scale doesn't use up a production confirmation
+    if !force.unwrap_or(false) {
+        if let Some(warning) = kuboard_managed_scale_warning("ReplicaSet", &replicaset.metadata) {
+            warn!("Refused to scale replicaset {}/{}: {}", namespace, name, warning.message);
+            return Err(serde_json::to_string(&warning).unwrap_or(warning.message));
+        }
+    }
+    if !dry_run {
+        production_guard(&state, "scale_replicaset", &format!("{}/{}", namespace, name)).await?;
+    }
+
+    let cause = change_cause(&state, &format!("scale replicaset/{} --replicas={}", name, replicas)).await;
+    // Re-applied to the latest version if something else updates the replicaset first
+    kuboard_replace_with_retry(&replicasets_api, &name, replicaset, dry_run, |replicaset| {
+        replicaset.spec.as_mut().ok_or_else(|| anyhow::anyhow!("ReplicaSet spec is missing"))?.replicas = Some(replicas);
+        kuboard_record_change_cause(&mut replicaset.metadata, &cause);
+        Ok(())
+    }).await.map_err(|e| mutation_error(e, "Failed to scale replicaset"))
+}
+
+#[tauri::command]
+pub async fn kuboard_get_replicaset_pods(
+    name: String,
+    namespace: String,
+    state: State<'_, AppState>
+) -> Result<Vec<Pod>, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    // Get the replicaset to find its selector
+    let replicasets_api: Api<ReplicaSet> = Api::namespaced(client.clone(), &namespace);
+    let replicaset = match replicasets_api.get(&name).await {
+        Ok(rs) => rs,
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            return Err(format!("ReplicaSet {}/{} not found", namespace, name));
+        }
+        Err(e) => return Err(format!("Failed to get replicaset: {}", e)),
+    };
+
+    // Get selector from replicaset
+    let selector = match replicaset.spec.as_ref() {
+        Some(spec) => &spec.selector,
+        None => return Err("ReplicaSet has no spec".to_string()),
+    };
+
+    // List pods matching the selector server-side
+    let matching_pods = kuboard_list_pods_by_selector(client, &namespace, selector)
+        .await
+        .map_err(|e| format!("Failed to list pods: {}", e))?;
+
+    Ok(matching_pods)
+}
+
+// Deployment Commands
+#[tauri::command]
+pub async fn kuboard_get_deployment(
+    name: String,
+    namespace: String,
+    state: State<'_, AppState>
+) -> Result<Deployment, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let deployments_api: Api<Deployment> = Api::namespaced(client.clone(), &namespace);
+    match deployments_api.get(&name).await {
+        Ok(deployment) => Ok(deployment),
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            Err(format!("Deployment {}/{} not found", namespace, name))
+        }
+        Err(e) => Err(format!("Failed to get deployment: {}", e)),
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_scale_deployment(
+    name: String,
+    namespace: String,
+    replicas: i32,
+    dry_run: Option<bool>,
     state: State<'_, AppState>
 ) -> Result<Deployment, String> {
+    let dry_run = dry_run.unwrap_or(false);
+    if !dry_run {
+        production_guard(&state, "scale_deployment", &format!("{}/{}", namespace, name)).await?;
+    }
+
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
@@ -562,8 +1138,8 @@ pub async fn kuboard_rollback_deployment(
 
     let deployments_api: Api<Deployment> = Api::namespaced(client.clone(), &namespace);
     
-    // Get current deployment (verify it exists)
-    let _deployment = match deployments_api.get(&name).await {
+    // Get current deployment
+    let deployment = match deployments_api.get(&name).await {
         Ok(dep) => dep,
         Err(kube::Error::Api(e)) if e.code == 404 => {
             return Err(format!("Deployment {}/{} not found", namespace, name));
@@ -571,18 +1147,30 @@ pub async fn kuboard_rollback_deployment(
         Err(e) => return Err(format!("Failed to get deployment: {}", e)),
     };
 
-    // For rollback, we need to use the rollout subresource
-    // This is a simplified version - in production, you'd use kubectl rollout undo
-    // For now, we'll return an error indicating this needs kubectl
-    Err("Rollback requires kubectl rollout undo command. This feature will be enhanced in Phase 2.".to_string())
+    let cause = change_cause(&state, &format!("scale deployment/{} --replicas={}", name, replicas)).await;
+    // Re-applied to the latest version if something else updates the deployment first
+    kuboard_replace_with_retry(&deployments_api, &name, deployment, dry_run, |deployment| {
+        deployment.spec.as_mut().ok_or_else(|| anyhow::anyhow!("Deployment spec is missing"))?.replicas = Some(replicas);
+        kuboard_record_change_cause(&mut deployment.metadata, &cause);
+        Ok(())
+    }).await.map_err(|e| mutation_error(e, "Failed to scale deployment"))
 }
 
+// kubectl rollout undo: copies the pod template of the ReplicaSet at `revision`, or of the previous
+// revision when none is given, back into the deployment
 #[tauri::command]
-pub async fn kuboard_restart_deployment(
+pub async fn kuboard_rollback_deployment(
     name: String,
     namespace: String,
+    revision: Option<i64>,
+    dry_run: Option<bool>,
     state: State<'_, AppState>
 ) -> Result<Deployment, String> {
+    let dry_run = dry_run.unwrap_or(false);
+    if !dry_run {
+        production_guard(&state, "rollback_deployment", &format!("{}/{}", namespace, name)).await?;
+    }
+
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
@@ -591,34 +1179,142 @@ pub async fn kuboard_restart_deployment(
     let deployments_api: Api<Deployment> = Api::namespaced(client.clone(), &namespace);
     
     // Get current deployment
-    let mut deployment = match deployments_api.get(&name).await {
+    let deployment = match deployments_api.get(&name).await {
         Ok(dep) => dep,
         Err(kube::Error::Api(e)) if e.code == 404 => {
             return Err(format!("Deployment {}/{} not found", namespace, name));
         }
         Err(e) => return Err(format!("Failed to get deployment: {}", e)),
     };
+    let spec = deployment.spec.as_ref().ok_or_else(|| "Deployment has no spec".to_string())?;
+    // The controller ignores template changes while paused, as kubectl rollout undo refuses to
+    if spec.paused == Some(true) {
+        return Err(format!("Deployment {}/{} is paused; resume it before rolling back", namespace, name));
+    }
 
-    // Add restart annotation to trigger pod recreation
-    // The annotation must be in spec.template.metadata.annotations, not metadata.annotations
-    let spec = deployment.spec.as_mut().ok_or_else(|| "Deployment spec is missing".to_string())?;
-    let metadata = spec.template.metadata.get_or_insert_with(Default::default);
-    let annotations = metadata.annotations.get_or_insert_with(Default::default);
-    use std::time::{SystemTime, UNIX_EPOCH};
-    let timestamp = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap()
-        .as_secs();
-    annotations.insert(
-        "kubectl.kubernetes.io/restartedAt".to_string(),
-        timestamp.to_string(),
-    );
+    let replicasets = kuboard_list_deployment_replicasets(client, &namespace, &name, &spec.selector).await
+        .map_err(|e| format!("Failed to list replicasets: {}", e))?;
+    let target = kuboard_rollback_target(&replicasets, revision)
+        .map_err(|e| format!("Cannot roll back deployment {}/{}: {}", namespace, name, e))?;
+    let target_revision = kuboard_replicaset_revision(target).unwrap_or_default();
+    let template = kuboard_rollback_template(target).map_err(|e| format!("Failed to roll back deployment: {}", e))?;
+    info!("Rolling back deployment {}/{} to revision {} ({})", namespace, name, target_revision, target.metadata.name.as_deref().unwrap_or_default());
+
+    let cause = change_cause(&state, &format!("rollout undo deployment/{} --to-revision={}", name, target_revision)).await;
+    kuboard_replace_with_retry(&deployments_api, &name, deployment, dry_run, |deployment| {
+        deployment.spec.as_mut().ok_or_else(|| anyhow::anyhow!("Deployment spec is missing"))?.template = template.clone();
+        kuboard_record_change_cause(&mut deployment.metadata, &cause);
+        Ok(())
+    }).await.map_err(|e| mutation_error(e, "Failed to roll back deployment"))
+}
+
+// Rollout Pause Commands
+// A paused deployment keeps its pods but ignores template changes until resumed, which halts a bad
+// rollout where it stands
+async fn set_deployment_paused(state: &AppState, name: &str, namespace: &str, pause: bool) -> Result<Deployment, String> {
+    let action = if pause { "pause" } else { "resume" };
+    production_guard(state, &format!("{}_deployment", action), &format!("{}/{}", namespace, name)).await?;
+
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let deployments_api: Api<Deployment> = Api::namespaced(client.clone(), namespace);
+    let deployment = match deployments_api.get(name).await {
+        Ok(dep) => dep,
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            return Err(format!("Deployment {}/{} not found", namespace, name));
+        }
+        Err(e) => return Err(format!("Failed to get deployment: {}", e)),
+    };
+    if deployment.spec.as_ref().and_then(|s| s.paused).unwrap_or(false) == pause {
+        return Err(format!("Deployment {}/{} is already {}", namespace, name, if pause { "paused" } else { "running" }));
+    }
+
+    kuboard_replace_with_retry(&deployments_api, name, deployment, false, |deployment| {
+        deployment.spec.as_mut().ok_or_else(|| anyhow::anyhow!("Deployment spec is missing"))?.paused = Some(pause);
+        Ok(())
+    }).await.map_err(|e| mutation_error(e, &format!("Failed to {} deployment", action)))
+}
+
+#[tauri::command]
+pub async fn kuboard_pause_deployment(
+    name: String,
+    namespace: String,
+    state: State<'_, AppState>
+) -> Result<Deployment, String> {
+    set_deployment_paused(&state, &name, &namespace, true).await
+}
+
+#[tauri::command]
+pub async fn kuboard_resume_deployment(
+    name: String,
+    namespace: String,
+    state: State<'_, AppState>
+) -> Result<Deployment, String> {
+    set_deployment_paused(&state, &name, &namespace, false).await
+}
+
+// kubectl rollout status as one snapshot: complete, progressing, paused or stuck past its deadline
+#[tauri::command]
+pub async fn kuboard_get_deployment_rollout_status(
+    name: String,
+    namespace: String,
+    state: State<'_, AppState>
+) -> Result<DeploymentRolloutStatus, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let deployments_api: Api<Deployment> = Api::namespaced(client.clone(), &namespace);
+    match deployments_api.get(&name).await {
+        Ok(deployment) => Ok(kuboard_rollout_status(&deployment, chrono::Utc::now())),
+        Err(kube::Error::Api(e)) if e.code == 404 => Err(format!("Deployment {}/{} not found", namespace, name)),
+        Err(e) => Err(format!("Failed to get deployment: {}", e)),
+    }
+}
 
-    // Apply the update
-    match deployments_api.replace(&name, &Default::default(), &deployment).await {
-        Ok(updated) => Ok(updated),
-        Err(e) => Err(format!("Failed to restart deployment: {}", e)),
+#[tauri::command]
+pub async fn kuboard_restart_deployment(
+    name: String,
+    namespace: String,
+    dry_run: Option<bool>,
+    state: State<'_, AppState>
+) -> Result<Deployment, String> {
+    let dry_run = dry_run.unwrap_or(false);
+    if !dry_run {
+        production_guard(&state, "restart_deployment", &format!("{}/{}", namespace, name)).await?;
     }
+
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let deployments_api: Api<Deployment> = Api::namespaced(client.clone(), &namespace);
+    
+    // Get current deployment
+    let deployment = match deployments_api.get(&name).await {
+        Ok(dep) => dep,
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            return Err(format!("Deployment {}/{} not found", namespace, name));
+        }
+        Err(e) => return Err(format!("Failed to get deployment: {}", e)),
+    };
+
+    let cause = change_cause(&state, &format!("rollout restart deployment/{}", name)).await;
+    let restarted_at = chrono::Utc::now().timestamp().to_string();
+    kuboard_replace_with_retry(&deployments_api, &name, deployment, dry_run, |deployment| {
+        // The annotation must be in spec.template.metadata.annotations, not metadata.annotations
+        let spec = deployment.spec.as_mut().ok_or_else(|| anyhow::anyhow!("Deployment spec is missing"))?;
+        spec.template.metadata.get_or_insert_with(Default::default)
+            .annotations.get_or_insert_with(Default::default)
+            .insert("kubectl.kubernetes.io/restartedAt".to_string(), restarted_at.clone());
+        kuboard_record_change_cause(&mut deployment.metadata, &cause);
+        Ok(())
+    }).await.map_err(|e| mutation_error(e, "Failed to restart deployment"))
 }
 
 #[tauri::command]
@@ -642,34 +1338,152 @@ pub async fn kuboard_get_deployment_replicasets(
         Err(e) => return Err(format!("Failed to get deployment: {}", e)),
     };
 
-    // Get selector from deployment (currently unused, filtering by owner reference instead)
-    let _selector = match deployment.spec.as_ref() {
+    // Get selector from deployment
+    let selector = match deployment.spec.as_ref() {
         Some(spec) => &spec.selector,
         None => return Err("Deployment has no spec".to_string()),
     };
 
-    // List all replicasets in namespace
-    let replicasets_api: Api<ReplicaSet> = Api::namespaced(client.clone(), &namespace);
-    let replicasets = match replicasets_api.list(&Default::default()).await {
-        Ok(rs_list) => rs_list.items,
-        Err(e) => return Err(format!("Failed to list replicasets: {}", e)),
+    kuboard_list_deployment_replicasets(client, &namespace, &name, selector)
+        .await
+        .map_err(|e| format!("Failed to list replicasets: {}", e))
+}
+
+// Revisions newest first, as kubectl rollout history lists them; a revision's number is what
+// kuboard_rollback_deployment takes
+#[tauri::command]
+pub async fn kuboard_get_deployment_rollout_history(
+    name: String,
+    namespace: String,
+    state: State<'_, AppState>
+) -> Result<Vec<DeploymentRevision>, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let deployments_api: Api<Deployment> = Api::namespaced(client.clone(), &namespace);
+    let deployment = match deployments_api.get(&name).await {
+        Ok(dep) => dep,
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            return Err(format!("Deployment {}/{} not found", namespace, name));
+        }
+        Err(e) => return Err(format!("Failed to get deployment: {}", e)),
     };
+    let selector = &deployment.spec.as_ref().ok_or_else(|| "Deployment has no spec".to_string())?.selector;
 
-    // Filter replicasets by owner reference (owned by this deployment)
-    let matching_replicasets: Vec<ReplicaSet> = replicasets
-        .into_iter()
-        .filter(|rs| {
-            if let Some(owner_refs) = rs.metadata.owner_references.as_ref() {
-                owner_refs.iter().any(|owner| {
-                    owner.kind == "Deployment" && owner.name == name
-                })
-            } else {
-                false
-            }
+    let replicasets = kuboard_list_deployment_replicasets(client, &namespace, &name, selector).await
+        .map_err(|e| format!("Failed to list replicasets: {}", e))?;
+    Ok(kuboard_rollout_history(&replicasets))
+}
+
+// What changed between two revisions' pod templates. Deployments compare ReplicaSet revisions and
+// default to the previous revision against the current one; StatefulSets compare ControllerRevisions
+// and default to status.currentRevision against updateRevision, i.e. what a rolling update is applying
+#[tauri::command]
+pub async fn kuboard_diff_workload_revisions(
+    kind: String,
+    name: String,
+    namespace: String,
+    from_revision: Option<i64>,
+    to_revision: Option<i64>,
+    state: State<'_, AppState>
+) -> Result<TemplateDiff, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let ((from_revision, from), (to_revision, to)) = match kind.as_str() {
+        "Deployment" => deployment_revision_templates(client, &name, &namespace, from_revision, to_revision).await?,
+        "StatefulSet" => statefulset_revision_templates(client, &name, &namespace, from_revision, to_revision).await?,
+        _ => return Err(format!("Revision diffs are not supported for {}; use Deployment or StatefulSet", kind)),
+    };
+    let changes = kuboard_template_changes(&from, &to)
+        .map_err(|e| format!("Failed to diff revisions: {}", e))?;
+    Ok(TemplateDiff { kind, name, namespace, from_revision, to_revision, changes })
+}
+
+type RevisionTemplate = (i64, PodTemplateSpec);
+
+async fn deployment_revision_templates(
+    client: &Client,
+    name: &str,
+    namespace: &str,
+    from_revision: Option<i64>,
+    to_revision: Option<i64>,
+) -> Result<(RevisionTemplate, RevisionTemplate), String> {
+    let deployments_api: Api<Deployment> = Api::namespaced(client.clone(), namespace);
+    let deployment = match deployments_api.get(name).await {
+        Ok(dep) => dep,
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            return Err(format!("Deployment {}/{} not found", namespace, name));
+        }
+        Err(e) => return Err(format!("Failed to get deployment: {}", e)),
+    };
+    let selector = &deployment.spec.as_ref().ok_or_else(|| "Deployment has no spec".to_string())?.selector;
+    let replicasets = kuboard_list_deployment_replicasets(client, namespace, name, selector).await
+        .map_err(|e| format!("Failed to list replicasets: {}", e))?;
+
+    let latest = replicasets.iter().filter_map(kuboard_replicaset_revision).max()
+        .ok_or_else(|| format!("Deployment {}/{} has no revisions", namespace, name))?;
+    // 0 or no revision means the previous one, as for rollbacks
+    let template = |revision: Option<i64>| -> Result<RevisionTemplate, String> {
+        let replicaset = kuboard_rollback_target(&replicasets, revision)
+            .map_err(|e| format!("Cannot diff deployment {}/{}: {}", namespace, name, e))?;
+        let template = kuboard_rollback_template(replicaset).map_err(|e| e.to_string())?;
+        Ok((kuboard_replicaset_revision(replicaset).unwrap_or_default(), template))
+    };
+    Ok((template(from_revision)?, template(to_revision.or(Some(latest)))?))
+}
+
+async fn statefulset_revision_templates(
+    client: &Client,
+    name: &str,
+    namespace: &str,
+    from_revision: Option<i64>,
+    to_revision: Option<i64>,
+) -> Result<(RevisionTemplate, RevisionTemplate), String> {
+    let statefulsets_api: Api<StatefulSet> = Api::namespaced(client.clone(), namespace);
+    let statefulset = match statefulsets_api.get(name).await {
+        Ok(sts) => sts,
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            return Err(format!("StatefulSet {}/{} not found", namespace, name));
+        }
+        Err(e) => return Err(format!("Failed to get statefulset: {}", e)),
+    };
+    let selector = &statefulset.spec.as_ref().ok_or_else(|| "StatefulSet has no spec".to_string())?.selector;
+    let label_selector = kuboard_label_selector_string(selector).map_err(|e| e.to_string())?;
+
+    let revisions_api: Api<ControllerRevision> = Api::namespaced(client.clone(), namespace);
+    let revisions: Vec<ControllerRevision> = revisions_api.list(&ListParams::default().labels(&label_selector)).await
+        .map_err(|e| format!("Failed to list controller revisions: {}", e))?
+        .items.into_iter()
+        .filter(|revision| {
+            revision.metadata.owner_references.as_ref()
+                .is_some_and(|owners| owners.iter().any(|owner| owner.kind == "StatefulSet" && owner.name == name))
         })
         .collect();
 
-    Ok(matching_replicasets)
+    let status = statefulset.status.as_ref();
+    let template = |revision: Option<i64>, default: Option<&String>| -> Result<RevisionTemplate, String> {
+        let found = match revision {
+            Some(wanted) => revisions.iter().find(|r| r.revision == wanted)
+                .ok_or_else(|| format!("Cannot diff statefulset {}/{}: Revision {} not found", namespace, name, wanted))?,
+            None => {
+                let default = default
+                    .ok_or_else(|| format!("StatefulSet {}/{} has not reported its revisions yet", namespace, name))?;
+                revisions.iter().find(|r| r.metadata.name.as_ref() == Some(default))
+                    .ok_or_else(|| format!("Cannot diff statefulset {}/{}: ControllerRevision {} not found", namespace, name, default))?
+            }
+        };
+        let template = kuboard_controller_revision_template(found).map_err(|e| e.to_string())?;
+        Ok((found.revision, template))
+    };
+    Ok((
+        template(from_revision, status.and_then(|s| s.current_revision.as_ref()))?,
+        template(to_revision, status.and_then(|s| s.update_revision.as_ref()))?,
+    ))
 }
 
 #[tauri::command]
@@ -699,47 +1513,53 @@ pub async fn kuboard_get_deployment_pods(
         None => return Err("Deployment has no spec".to_string()),
     };
 
-    // List pods with matching labels
-    let pods_api: Api<Pod> = Api::namespaced(client.clone(), &namespace);
-    let pods = match pods_api.list(&Default::default()).await {
-        Ok(pod_list) => pod_list.items,
-        Err(e) => return Err(format!("Failed to list pods: {}", e)),
-    };
-
-    // Filter pods by selector
-    let matching_pods: Vec<Pod> = pods
-        .into_iter()
-        .filter(|pod| {
-            if let Some(pod_labels) = pod.metadata.labels.as_ref() {
-                if let Some(match_labels) = selector.match_labels.as_ref() {
-                    match_labels.iter().all(|(key, value)| {
-                        pod_labels.get(key).map_or(false, |v| v == value)
-                    })
-                } else {
-                    false
-                }
-            } else {
-                false
-            }
-        })
-        .collect();
+    // List pods matching the selector server-side
+    let matching_pods = kuboard_list_pods_by_selector(client, &namespace, selector)
+        .await
+        .map_err(|e| format!("Failed to list pods: {}", e))?;
 
     Ok(matching_pods)
 }
 
 // StatefulSet Commands
 #[tauri::command]
-pub async fn kuboard_get_statefulsets(state: State<'_, AppState>) -> Result<Vec<StatefulSet>, String> {
+pub async fn kuboard_get_statefulsets(
+    namespace: Option<String>,
+    label_selector: Option<String>,
+    field_selector: Option<String>,
+    state: State<'_, AppState>
+) -> Result<Vec<StatefulSet>, String> {
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let statefulsets_api: Api<StatefulSet> = Api::all(client.clone());
-    match statefulsets_api.list(&Default::default()).await {
-        Ok(statefulsets) => Ok(statefulsets.items),
-        Err(e) => Err(format!("Failed to get statefulsets: {}", e)),
-    }
+    let filter = current_namespace_filter(&state).await;
+    kuboard_list_scoped::<StatefulSet>(client, &filter, namespace.as_deref(), &selector_params(label_selector.as_deref(), field_selector.as_deref())).await
+        .map_err(|e| format!("Failed to get statefulsets: {}", e))
+}
+
+// StatefulSets with ready/up-to-date/available counts and a health verdict; `status` filters on the
+// verdict and `sort_by` is name or age
+#[tauri::command]
+pub async fn kuboard_get_statefulset_summaries(
+    namespace: Option<String>,
+    label_selector: Option<String>,
+    field_selector: Option<String>,
+    sort_by: Option<String>,
+    status: Option<String>,
+    state: State<'_, AppState>
+) -> Result<Vec<WorkloadSummary>, String> {
+    let view = kuboard_list_view(sort_by.as_deref(), status.as_deref()).map_err(|e| e.to_string())?;
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let filter = current_namespace_filter(&state).await;
+    kuboard_list_scoped::<StatefulSet>(client, &filter, namespace.as_deref(), &selector_params(label_selector.as_deref(), field_selector.as_deref())).await
+        .map_err(|e| format!("Failed to get statefulset summaries: {}", e))
+        .and_then(|items| kuboard_workload_list(&items, kuboard_statefulset_summary, &view).map_err(|e| e.to_string()))
 }
 
 #[tauri::command]
@@ -768,8 +1588,14 @@ pub async fn kuboard_scale_statefulset(
     name: String,
     namespace: String,
     replicas: i32,
+    dry_run: Option<bool>,
     state: State<'_, AppState>
 ) -> Result<StatefulSet, String> {
+    let dry_run = dry_run.unwrap_or(false);
+    if !dry_run {
+        production_guard(&state, "scale_statefulset", &format!("{}/{}", namespace, name)).await?;
+    }
+
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
@@ -778,7 +1604,7 @@ pub async fn kuboard_scale_statefulset(
     let statefulsets_api: Api<StatefulSet> = Api::namespaced(client.clone(), &namespace);
     
     // Get current statefulset
-    let mut statefulset = match statefulsets_api.get(&name).await {
+    let statefulset = match statefulsets_api.get(&name).await {
         Ok(ss) => ss,
         Err(kube::Error::Api(e)) if e.code == 404 => {
             return Err(format!("StatefulSet {}/{} not found", namespace, name));
@@ -786,26 +1612,27 @@ pub async fn kuboard_scale_statefulset(
         Err(e) => return Err(format!("Failed to get statefulset: {}", e)),
     };
 
-    // Update replica count
-    if let Some(spec) = statefulset.spec.as_mut() {
-        spec.replicas = Some(replicas);
-    } else {
-        return Err("StatefulSet spec is missing".to_string());
-    }
-
-    // Apply the update
-    match statefulsets_api.replace(&name, &Default::default(), &statefulset).await {
-        Ok(updated) => Ok(updated),
-        Err(e) => Err(format!("Failed to scale statefulset: {}", e)),
-    }
+    let cause = change_cause(&state, &format!("scale statefulset/{} --replicas={}", name, replicas)).await;
+    // Re-applied to the latest version if something else updates the statefulset first
+    kuboard_replace_with_retry(&statefulsets_api, &name, statefulset, dry_run, |statefulset| {
+        statefulset.spec.as_mut().ok_or_else(|| anyhow::anyhow!("StatefulSet spec is missing"))?.replicas = Some(replicas);
+        kuboard_record_change_cause(&mut statefulset.metadata, &cause);
+        Ok(())
+    }).await.map_err(|e| mutation_error(e, "Failed to scale statefulset"))
 }
 
 #[tauri::command]
 pub async fn kuboard_restart_statefulset(
     name: String,
     namespace: String,
+    dry_run: Option<bool>,
     state: State<'_, AppState>
 ) -> Result<StatefulSet, String> {
+    let dry_run = dry_run.unwrap_or(false);
+    if !dry_run {
+        production_guard(&state, "restart_statefulset", &format!("{}/{}", namespace, name)).await?;
+    }
+
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
@@ -814,7 +1641,7 @@ pub async fn kuboard_restart_statefulset(
     let statefulsets_api: Api<StatefulSet> = Api::namespaced(client.clone(), &namespace);
     
     // Get current statefulset
-    let mut statefulset = match statefulsets_api.get(&name).await {
+    let statefulset = match statefulsets_api.get(&name).await {
         Ok(ss) => ss,
         Err(kube::Error::Api(e)) if e.code == 404 => {
             return Err(format!("StatefulSet {}/{} not found", namespace, name));
@@ -822,26 +1649,17 @@ pub async fn kuboard_restart_statefulset(
         Err(e) => return Err(format!("Failed to get statefulset: {}", e)),
     };
 
-    // Add restart annotation to trigger pod recreation
-    // The annotation must be in spec.template.metadata.annotations, not metadata.annotations
-    let spec = statefulset.spec.as_mut().ok_or_else(|| "StatefulSet spec is missing".to_string())?;
-    let metadata = spec.template.metadata.get_or_insert_with(Default::default);
-    let annotations = metadata.annotations.get_or_insert_with(Default::default);
-    use std::time::{SystemTime, UNIX_EPOCH};
-    let timestamp = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap()
-        .as_secs();
-    annotations.insert(
-        "kubectl.kubernetes.io/restartedAt".to_string(),
-        timestamp.to_string(),
-    );
-
-    // Apply the update
-    match statefulsets_api.replace(&name, &Default::default(), &statefulset).await {
-        Ok(updated) => Ok(updated),
-        Err(e) => Err(format!("Failed to restart statefulset: {}", e)),
-    }
+    let cause = change_cause(&state, &format!("rollout restart statefulset/{}", name)).await;
+    let restarted_at = chrono::Utc::now().timestamp().to_string();
+    kuboard_replace_with_retry(&statefulsets_api, &name, statefulset, dry_run, |statefulset| {
+        // The annotation must be in spec.template.metadata.annotations, not metadata.annotations
+        let spec = statefulset.spec.as_mut().ok_or_else(|| anyhow::anyhow!("StatefulSet spec is missing"))?;
+        spec.template.metadata.get_or_insert_with(Default::default)
+            .annotations.get_or_insert_with(Default::default)
+            .insert("kubectl.kubernetes.io/restartedAt".to_string(), restarted_at.clone());
+        kuboard_record_change_cause(&mut statefulset.metadata, &cause);
+        Ok(())
+    }).await.map_err(|e| mutation_error(e, "Failed to restart statefulset"))
 }
 
 #[tauri::command]
@@ -871,30 +1689,10 @@ pub async fn kuboard_get_statefulset_pods(
         None => return Err("StatefulSet has no spec".to_string()),
     };
 
-    // List pods with matching labels
-    let pods_api: Api<Pod> = Api::namespaced(client.clone(), &namespace);
-    let pods = match pods_api.list(&Default::default()).await {
-        Ok(pod_list) => pod_list.items,
-        Err(e) => return Err(format!("Failed to list pods: {}", e)),
-    };
-
-    // Filter pods by selector and sort by ordinal (StatefulSet pods are named with ordinal suffix)
-    let matching_pods: Vec<Pod> = pods
-        .into_iter()
-        .filter(|pod| {
-            if let Some(pod_labels) = pod.metadata.labels.as_ref() {
-                if let Some(match_labels) = selector.match_labels.as_ref() {
-                    match_labels.iter().all(|(key, value)| {
-                        pod_labels.get(key).map_or(false, |v| v == value)
-                    })
-                } else {
-                    false
-                }
-            } else {
-                false
-            }
-        })
-        .collect();
+    // List pods matching the selector server-side
+    let matching_pods = kuboard_list_pods_by_selector(client, &namespace, selector)
+        .await
+        .map_err(|e| format!("Failed to list pods: {}", e))?;
 
     // Sort by pod name (which contains ordinal) for StatefulSet ordering
     let mut sorted_pods = matching_pods;
@@ -907,19 +1705,135 @@ pub async fn kuboard_get_statefulset_pods(
     Ok(sorted_pods)
 }
 
-// DaemonSet Commands
+async fn statefulset_volumes(client: &Client, name: &str, namespace: &str) -> Result<StatefulSetVolumes, String> {
+    let statefulsets_api: Api<StatefulSet> = Api::namespaced(client.clone(), namespace);
+    let statefulset = match statefulsets_api.get(name).await {
+        Ok(ss) => ss,
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            return Err(format!("StatefulSet {}/{} not found", namespace, name));
+        }
+        Err(e) => return Err(format!("Failed to get statefulset: {}", e)),
+    };
+    let selector = &statefulset.spec.as_ref().ok_or_else(|| "StatefulSet has no spec".to_string())?.selector;
+
+    let pods = kuboard_list_pods_by_selector(client, namespace, selector).await
+        .map_err(|e| format!("Failed to list pods: {}", e))?;
+    let claims = Api::<PersistentVolumeClaim>::namespaced(client.clone(), namespace).list(&ListParams::default()).await
+        .map_err(|e| format!("Failed to list persistent volume claims: {}", e))?;
+    // Namespace-scoped users often can't read PersistentVolumes; reclaim policies are then reported unknown
+    let volumes = match Api::<PersistentVolume>::all(client.clone()).list(&ListParams::default()).await {
+        Ok(volumes) => volumes.items,
+        Err(e) => {
+            warn!("Could not read persistent volumes for {}/{}: {}", namespace, name, e);
+            Vec::new()
+        }
+    };
+    Ok(kuboard_statefulset_volumes(&statefulset, &claims.items, &pods, &volumes))
+}
+
+// Each ordinal's claims, including the ones left behind by a scale-down
+#[tauri::command]
+pub async fn kuboard_get_statefulset_volumes(
+    name: String,
+    namespace: String,
+    state: State<'_, AppState>
+) -> Result<StatefulSetVolumes, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    statefulset_volumes(client, &name, &namespace).await
+}
+
+// Deletes claims orphaned by a scale-down, all of them unless `claim_names` picks some; refuses
+// claims an ordinal within the replica count or a still running pod uses. Claims aren't kept for
+// undo, since recreating one gives an empty volume
 #[tauri::command]
-pub async fn kuboard_get_daemonsets(state: State<'_, AppState>) -> Result<Vec<DaemonSet>, String> {
+pub async fn kuboard_delete_statefulset_orphaned_pvcs(
+    name: String,
+    namespace: String,
+    claim_names: Option<Vec<String>>,
+    dry_run: Option<bool>,
+    state: State<'_, AppState>
+) -> Result<OrphanedClaimCleanup, String> {
+    info!("Deleting orphaned PVCs of statefulset {}/{}", namespace, name);
+    let dry_run = dry_run.unwrap_or(false);
+    if !dry_run {
+        production_guard(&state, "delete_statefulset_orphaned_pvcs", &format!("{}/{}", namespace, name)).await?;
+    }
+
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let daemonsets_api: Api<DaemonSet> = Api::all(client.clone());
-    match daemonsets_api.list(&Default::default()).await {
-        Ok(daemonsets) => Ok(daemonsets.items),
-        Err(e) => Err(format!("Failed to get daemonsets: {}", e)),
+    // Checked against the statefulset as it is now, in case it scaled up since the view was read
+    let volumes = statefulset_volumes(client, &name, &namespace).await?;
+    let selected = kuboard_select_orphaned_claims(&volumes, claim_names.as_deref()).map_err(|e| e.to_string())?;
+    let warnings = volumes.warnings.into_iter()
+        .filter(|warning| selected.iter().any(|claim| warning.contains(claim.as_str())))
+        .collect();
+
+    let pvc_api: Api<PersistentVolumeClaim> = Api::namespaced(client.clone(), &namespace);
+    let mut deleted = Vec::new();
+    for claim in selected {
+        match pvc_api.delete(&claim, &delete_params(dry_run)).await {
+            Ok(_) => deleted.push(claim),
+            Err(kube::Error::Api(e)) if e.code == 404 => {
+                warn!("PVC {}/{} not found during delete - treating as already deleted", namespace, claim);
+            }
+            Err(e) => {
+                error!("Failed to delete PVC {}/{}: {}", namespace, claim, e);
+                return Err(format!("Failed to delete PVC {}: {}", claim, e));
+            }
+        }
+    }
+    if !dry_run {
+        info!("✅ Deleted {} orphaned PVCs of statefulset {}/{}", deleted.len(), namespace, name);
     }
+    Ok(OrphanedClaimCleanup { deleted, warnings, dry_run })
+}
+
+// DaemonSet Commands
+#[tauri::command]
+pub async fn kuboard_get_daemonsets(
+    namespace: Option<String>,
+    label_selector: Option<String>,
+    field_selector: Option<String>,
+    state: State<'_, AppState>
+) -> Result<Vec<DaemonSet>, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let filter = current_namespace_filter(&state).await;
+    kuboard_list_scoped::<DaemonSet>(client, &filter, namespace.as_deref(), &selector_params(label_selector.as_deref(), field_selector.as_deref())).await
+        .map_err(|e| format!("Failed to get daemonsets: {}", e))
+}
+
+// DaemonSets with ready/up-to-date/available counts and a health verdict; `status` filters on the
+// verdict and `sort_by` is name or age
+#[tauri::command]
+pub async fn kuboard_get_daemonset_summaries(
+    namespace: Option<String>,
+    label_selector: Option<String>,
+    field_selector: Option<String>,
+    sort_by: Option<String>,
+    status: Option<String>,
+    state: State<'_, AppState>
+) -> Result<Vec<WorkloadSummary>, String> {
+    let view = kuboard_list_view(sort_by.as_deref(), status.as_deref()).map_err(|e| e.to_string())?;
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let filter = current_namespace_filter(&state).await;
+    kuboard_list_scoped::<DaemonSet>(client, &filter, namespace.as_deref(), &selector_params(label_selector.as_deref(), field_selector.as_deref())).await
+        .map_err(|e| format!("Failed to get daemonset summaries: {}", e))
+        .and_then(|items| kuboard_workload_list(&items, kuboard_daemonset_summary, &view).map_err(|e| e.to_string()))
 }
 
 #[tauri::command]
@@ -947,8 +1861,14 @@ pub async fn kuboard_get_daemonset(
 pub async fn kuboard_restart_daemonset(
     name: String,
     namespace: String,
+    dry_run: Option<bool>,
     state: State<'_, AppState>
 ) -> Result<DaemonSet, String> {
+    let dry_run = dry_run.unwrap_or(false);
+    if !dry_run {
+        production_guard(&state, "restart_daemonset", &format!("{}/{}", namespace, name)).await?;
+    }
+
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
@@ -957,7 +1877,7 @@ pub async fn kuboard_restart_daemonset(
     let daemonsets_api: Api<DaemonSet> = Api::namespaced(client.clone(), &namespace);
     
     // Get current daemonset
-    let mut daemonset = match daemonsets_api.get(&name).await {
+    let daemonset = match daemonsets_api.get(&name).await {
         Ok(ds) => ds,
         Err(kube::Error::Api(e)) if e.code == 404 => {
             return Err(format!("DaemonSet {}/{} not found", namespace, name));
@@ -965,26 +1885,17 @@ pub async fn kuboard_restart_daemonset(
         Err(e) => return Err(format!("Failed to get daemonset: {}", e)),
     };
 
-    // Add restart annotation to trigger pod recreation
-    // The annotation must be in spec.template.metadata.annotations, not metadata.annotations
-    let spec = daemonset.spec.as_mut().ok_or_else(|| "DaemonSet spec is missing".to_string())?;
-    let metadata = spec.template.metadata.get_or_insert_with(Default::default);
-    let annotations = metadata.annotations.get_or_insert_with(Default::default);
-    use std::time::{SystemTime, UNIX_EPOCH};
-    let timestamp = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap()
-        .as_secs();
-    annotations.insert(
-        "kubectl.kubernetes.io/restartedAt".to_string(),
-        timestamp.to_string(),
-    );
-
-    // Apply the update
-    match daemonsets_api.replace(&name, &Default::default(), &daemonset).await {
-        Ok(updated) => Ok(updated),
-        Err(e) => Err(format!("Failed to restart daemonset: {}", e)),
-    }
+    let cause = change_cause(&state, &format!("rollout restart daemonset/{}", name)).await;
+    let restarted_at = chrono::Utc::now().timestamp().to_string();
+    kuboard_replace_with_retry(&daemonsets_api, &name, daemonset, dry_run, |daemonset| {
+        // The annotation must be in spec.template.metadata.annotations, not metadata.annotations
+        let spec = daemonset.spec.as_mut().ok_or_else(|| anyhow::anyhow!("DaemonSet spec is missing"))?;
+        spec.template.metadata.get_or_insert_with(Default::default)
+            .annotations.get_or_insert_with(Default::default)
+            .insert("kubectl.kubernetes.io/restartedAt".to_string(), restarted_at.clone());
+        kuboard_record_change_cause(&mut daemonset.metadata, &cause);
+        Ok(())
+    }).await.map_err(|e| mutation_error(e, "Failed to restart daemonset"))
 }
 
 #[tauri::command]
@@ -1014,30 +1925,10 @@ pub async fn kuboard_get_daemonset_pods(
         None => return Err("DaemonSet has no spec".to_string()),
     };
 
-    // List pods with matching labels
-    let pods_api: Api<Pod> = Api::namespaced(client.clone(), &namespace);
-    let pods = match pods_api.list(&Default::default()).await {
-        Ok(pod_list) => pod_list.items,
-        Err(e) => return Err(format!("Failed to list pods: {}", e)),
-    };
-
-    // Filter pods by selector
-    let matching_pods: Vec<Pod> = pods
-        .into_iter()
-        .filter(|pod| {
-            if let Some(pod_labels) = pod.metadata.labels.as_ref() {
-                if let Some(match_labels) = selector.match_labels.as_ref() {
-                    match_labels.iter().all(|(key, value)| {
-                        pod_labels.get(key).map_or(false, |v| v == value)
-                    })
-                } else {
-                    false
-                }
-            } else {
-                false
-            }
-        })
-        .collect();
+    // List pods matching the selector server-side
+    let matching_pods = kuboard_list_pods_by_selector(client, &namespace, selector)
+        .await
+        .map_err(|e| format!("Failed to list pods: {}", e))?;
 
     // Sort by node name, then by pod name for consistent ordering
     let mut sorted_pods = matching_pods;
@@ -1059,17 +1950,20 @@ pub async fn kuboard_get_daemonset_pods(
 
 // CronJob Commands
 #[tauri::command]
-pub async fn kuboard_get_cronjobs(state: State<'_, AppState>) -> Result<Vec<CronJob>, String> {
+pub async fn kuboard_get_cronjobs(
+    namespace: Option<String>,
+    label_selector: Option<String>,
+    field_selector: Option<String>,
+    state: State<'_, AppState>
+) -> Result<Vec<CronJob>, String> {
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let cronjobs_api: Api<CronJob> = Api::all(client.clone());
-    match cronjobs_api.list(&Default::default()).await {
-        Ok(cronjobs) => Ok(cronjobs.items),
-        Err(e) => Err(format!("Failed to get cronjobs: {}", e)),
-    }
+    let filter = current_namespace_filter(&state).await;
+    kuboard_list_scoped::<CronJob>(client, &filter, namespace.as_deref(), &selector_params(label_selector.as_deref(), field_selector.as_deref())).await
+        .map_err(|e| format!("Failed to get cronjobs: {}", e))
 }
 
 #[tauri::command]
@@ -1099,6 +1993,8 @@ pub async fn kuboard_trigger_cronjob(
     namespace: String,
     state: State<'_, AppState>
 ) -> Result<Job, String> {
+    production_guard(&state, "trigger_cronjob", &format!("{}/{}", namespace, name)).await?;
+
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
@@ -1155,6 +2051,8 @@ pub async fn kuboard_suspend_cronjob(
     namespace: String,
     state: State<'_, AppState>
 ) -> Result<CronJob, String> {
+    production_guard(&state, "suspend_cronjob", &format!("{}/{}", namespace, name)).await?;
+
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
@@ -1163,7 +2061,7 @@ pub async fn kuboard_suspend_cronjob(
     let cronjobs_api: Api<CronJob> = Api::namespaced(client.clone(), &namespace);
     
     // Get current cronjob
-    let mut cronjob = match cronjobs_api.get(&name).await {
+    let cronjob = match cronjobs_api.get(&name).await {
         Ok(cj) => cj,
         Err(kube::Error::Api(e)) if e.code == 404 => {
             return Err(format!("CronJob {}/{} not found", namespace, name));
@@ -1171,18 +2069,10 @@ pub async fn kuboard_suspend_cronjob(
         Err(e) => return Err(format!("Failed to get cronjob: {}", e)),
     };
 
-    // Set suspend to true
-    if let Some(spec) = cronjob.spec.as_mut() {
-        spec.suspend = Some(true);
-    } else {
-        return Err("CronJob has no spec".to_string());
-    }
-
-    // Apply the update
-    match cronjobs_api.replace(&name, &Default::default(), &cronjob).await {
-        Ok(updated) => Ok(updated),
-        Err(e) => Err(format!("Failed to suspend cronjob: {}", e)),
-    }
+    kuboard_replace_with_retry(&cronjobs_api, &name, cronjob, false, |cronjob| {
+        cronjob.spec.as_mut().ok_or_else(|| anyhow::anyhow!("CronJob has no spec"))?.suspend = Some(true);
+        Ok(())
+    }).await.map_err(|e| mutation_error(e, "Failed to suspend cronjob"))
 }
 
 #[tauri::command]
@@ -1191,6 +2081,8 @@ pub async fn kuboard_resume_cronjob(
     namespace: String,
     state: State<'_, AppState>
 ) -> Result<CronJob, String> {
+    production_guard(&state, "resume_cronjob", &format!("{}/{}", namespace, name)).await?;
+
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
@@ -1199,7 +2091,7 @@ pub async fn kuboard_resume_cronjob(
     let cronjobs_api: Api<CronJob> = Api::namespaced(client.clone(), &namespace);
     
     // Get current cronjob
-    let mut cronjob = match cronjobs_api.get(&name).await {
+    let cronjob = match cronjobs_api.get(&name).await {
         Ok(cj) => cj,
         Err(kube::Error::Api(e)) if e.code == 404 => {
             return Err(format!("CronJob {}/{} not found", namespace, name));
@@ -1207,18 +2099,10 @@ pub async fn kuboard_resume_cronjob(
         Err(e) => return Err(format!("Failed to get cronjob: {}", e)),
     };
 
-    // Set suspend to false
-    if let Some(spec) = cronjob.spec.as_mut() {
-        spec.suspend = Some(false);
-    } else {
-        return Err("CronJob has no spec".to_string());
-    }
-
-    // Apply the update
-    match cronjobs_api.replace(&name, &Default::default(), &cronjob).await {
-        Ok(updated) => Ok(updated),
-        Err(e) => Err(format!("Failed to resume cronjob: {}", e)),
-    }
+    kuboard_replace_with_retry(&cronjobs_api, &name, cronjob, false, |cronjob| {
+        cronjob.spec.as_mut().ok_or_else(|| anyhow::anyhow!("CronJob has no spec"))?.suspend = Some(false);
+        Ok(())
+    }).await.map_err(|e| mutation_error(e, "Failed to resume cronjob"))
 }
 
 #[tauri::command]
@@ -1252,17 +2136,7 @@ pub async fn kuboard_get_cronjob_jobs(
     // Filter jobs by owner reference (jobs created by this cronjob)
     let matching_jobs: Vec<Job> = jobs
         .into_iter()
-        .filter(|job| {
-            if let Some(owner_refs) = job.metadata.owner_references.as_ref() {
-                owner_refs.iter().any(|owner| {
-                    owner.kind == "CronJob" && 
-                    owner.name == name &&
-                    owner.controller == Some(true)
-                })
-            } else {
-                false
-            }
-        })
+        .filter(|job| kuboard_is_cronjob_job(job, &name))
         .collect();
 
     // Sort by creation timestamp (newest first)
@@ -1281,1569 +2155,4495 @@ pub async fn kuboard_get_cronjob_jobs(
 }
 
 #[tauri::command]
-pub async fn kuboard_get_configmaps(state: State<'_, AppState>) -> Result<Vec<ConfigMap>, String> {
+pub async fn kuboard_get_cronjob_run_summary(
+    name: String,
+    namespace: String,
+    state: State<'_, AppState>
+) -> Result<CronJobRunSummary, String> {
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let configmaps_api: Api<ConfigMap> = Api::all(client.clone());
-    match configmaps_api.list(&Default::default()).await {
-        Ok(configmaps) => Ok(configmaps.items),
-        Err(e) => Err(format!("Failed to get configmaps: {}", e)),
-    }
+    let cronjobs_api: Api<CronJob> = Api::namespaced(client.clone(), &namespace);
+    let cronjob = match cronjobs_api.get(&name).await {
+        Ok(cj) => cj,
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            return Err(format!("CronJob {}/{} not found", namespace, name));
+        }
+        Err(e) => return Err(format!("Failed to get cronjob: {}", e)),
+    };
+
+    let jobs_api: Api<Job> = Api::namespaced(client.clone(), &namespace);
+    let jobs: Vec<Job> = match jobs_api.list(&Default::default()).await {
+        Ok(job_list) => job_list.items.into_iter().filter(|job| kuboard_is_cronjob_job(job, &name)).collect(),
+        Err(e) => return Err(format!("Failed to list jobs: {}", e)),
+    };
+
+    Ok(kuboard_summarize_cronjob_runs(&cronjob, &jobs))
 }
 
 #[tauri::command]
-pub async fn kuboard_get_secrets(state: State<'_, AppState>) -> Result<Vec<Secret>, String> {
-    let client_guard = state.current_client.read().await;
-    let client = client_guard
-        .as_ref()
-        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+pub async fn kuboard_set_cronjob_history_limits(
+    name: String,
+    namespace: String,
+    successful_jobs_history_limit: Option<i32>,
+    failed_jobs_history_limit: Option<i32>,
+    state: State<'_, AppState>
+) -> Result<CronJob, String> {
+    production_guard(&state, "set_cronjob_history_limits", &format!("{}/{}", namespace, name)).await?;
 
-    let secrets_api: Api<Secret> = Api::all(client.clone());
-    match secrets_api.list(&Default::default()).await {
-        Ok(secrets) => Ok(secrets.items),
-        Err(e) => Err(format!("Failed to get secrets: {}", e)),
+    if successful_jobs_history_limit.is_some_and(|limit| limit < 0)
+        || failed_jobs_history_limit.is_some_and(|limit| limit < 0)
+    {
+        return Err("History limits must not be negative".to_string());
     }
-}
 
-// Metrics Commands - Real Implementation
-#[tauri::command]
-pub async fn kuboard_get_node_metrics(node_name: String, state: State<'_, AppState>) -> Result<serde_json::Value, String> {
-    info!("Fetching real-time metrics for node: {}", node_name);
-    
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    // Check if metrics server is available
-    match kuboard_check_metrics_server_availability(client).await {
-        Ok(true) => {
-            info!("Metrics server is available, fetching real metrics");
-        }
-        Ok(false) => {
-            warn!("Metrics server is not available");
-            return Err("Metrics server is not available".to_string());
-        }
-        Err(e) => {
-            warn!("Error checking metrics server availability: {}", e);
-            return Err(format!("Error checking metrics server availability: {}", e));
-        }
+    // Only the limits that were provided are touched; the rest of the spec is left as-is
+    let mut spec = serde_json::Map::new();
+    if let Some(limit) = successful_jobs_history_limit {
+        spec.insert("successfulJobsHistoryLimit".to_string(), limit.into());
+    }
+    if let Some(limit) = failed_jobs_history_limit {
+        spec.insert("failedJobsHistoryLimit".to_string(), limit.into());
     }
+    let patch = serde_json::json!({ "spec": spec });
 
-    // Fetch real metrics
-    match kuboard_fetch_node_metrics_real(client, &node_name).await {
-        Ok(metrics) => {
-            let response = serde_json::json!({
-                "cpu": {
-                    "usage": format!("{}m", (metrics.cpu_usage_cores * 1000.0) as i32),
-                    "usage_percent": metrics.cpu_usage_percent
-                },
-                "memory": {
-                    "usage": format!("{:.1}Gi", metrics.memory_usage_bytes as f64 / (1024.0 * 1024.0 * 1024.0)),
-                    "usage_percent": metrics.memory_usage_percent
-                },
-                "disk": {
-                    "usage": format!("{:.1}Gi", metrics.disk_usage_bytes as f64 / (1024.0 * 1024.0 * 1024.0)),
-                    "usage_percent": metrics.disk_usage_percent
-                },
-                "timestamp": metrics.timestamp,
-                "is_mock_data": metrics.is_mock_data
-            });
-            
-            Ok(response)
+    let cronjobs_api: Api<CronJob> = Api::namespaced(client.clone(), &namespace);
+    match cronjobs_api.patch(&name, &PatchParams::default(), &Patch::Merge(&patch)).await {
+        Ok(updated) => {
+            info!("✅ Updated history limits for cronjob {}/{}", namespace, name);
+            Ok(updated)
         }
-        Err(e) => {
-            error!("Failed to fetch metrics for node {}: {}", node_name, e);
-            Err(format!("Failed to fetch metrics: {}", e))
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            Err(format!("CronJob {}/{} not found", namespace, name))
         }
+        Err(e) => Err(format!("Failed to update cronjob history limits: {}", e)),
     }
 }
 
+// Batch Job Wizard Commands
 #[tauri::command]
-pub async fn kuboard_get_node_metrics_history(
-    node_name: String, 
-    duration_minutes: u32, 
+pub async fn kuboard_create_job(
+    request: JobCreateRequest,
+    dry_run: Option<bool>,
     state: State<'_, AppState>
-) -> Result<Vec<serde_json::Value>, String> {
-    info!("Fetching {} minutes of metrics history for node: {}", duration_minutes, node_name);
-    
+) -> Result<Job, String> {
+    let dry_run = dry_run.unwrap_or(false);
+    if !dry_run {
+        production_guard(&state, "create_job", &format!("{}/{}", request.namespace, request.name)).await?;
+    }
+    info!("Creating job {}/{} (dry run: {})", request.namespace, request.name, dry_run);
+
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    // Fetch historical metrics
-    match kuboard_fetch_node_metrics_history(client, &node_name, duration_minutes).await {
-        Ok(history) => {
-            let json_history: Vec<serde_json::Value> = history.into_iter().map(|data_point| {
-                serde_json::json!({
-                    "timestamp": data_point.timestamp,
-                    "cpu": {
-                        "usage": format!("{}m", (data_point.cpu_usage_cores * 1000.0) as i32),
-                        "usage_percent": data_point.cpu_usage_percent
-                    },
-                    "memory": {
-                        "usage": format!("{:.1}Gi", data_point.memory_usage_bytes as f64 / (1024.0 * 1024.0 * 1024.0)),
-                        "usage_percent": data_point.memory_usage_percent
-                    },
-                    "disk": {
-                        "usage": format!("{:.1}Gi", data_point.disk_usage_bytes as f64 / (1024.0 * 1024.0 * 1024.0)),
-                        "usage_percent": data_point.disk_usage_percent
-                    },
-                    "is_mock_data": data_point.is_mock_data
-                })
-            }).collect();
-            
-            Ok(json_history)
+    match kuboard_create_job_from_request(client, &request, dry_run).await {
+        Ok(job) => {
+            info!("✅ {} job {}/{}", if dry_run { "Validated" } else { "Created" }, request.namespace, request.name);
+            Ok(job)
         }
         Err(e) => {
-            error!("Failed to fetch metrics history for node {}: {}", node_name, e);
-            Err(format!("Failed to fetch metrics history: {}", e))
+            error!("Failed to create job {}/{}: {}", request.namespace, request.name, e);
+            Err(format!("Failed to create job: {}", e))
         }
     }
 }
 
-// Check metrics server availability
 #[tauri::command]
-pub async fn kuboard_check_metrics_availability(state: State<'_, AppState>) -> Result<serde_json::Value, String> {
+pub async fn kuboard_create_cronjob(
+    request: CronJobCreateRequest,
+    dry_run: Option<bool>,
+    state: State<'_, AppState>
+) -> Result<CronJob, String> {
+    let dry_run = dry_run.unwrap_or(false);
+    if !dry_run {
+        production_guard(&state, "create_cronjob", &format!("{}/{}", request.job.namespace, request.job.name)).await?;
+    }
+    info!("Creating cronjob {}/{} (dry run: {})", request.job.namespace, request.job.name, dry_run);
+
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    match kuboard_check_metrics_server_availability(client).await {
-        Ok(available) => {
-            let response = serde_json::json!({
-                "available": available
-            });
-            Ok(response)
+    match kuboard_create_cronjob_from_request(client, &request, dry_run).await {
+        Ok(cronjob) => {
+            info!("✅ {} cronjob {}/{}", if dry_run { "Validated" } else { "Created" }, request.job.namespace, request.job.name);
+            Ok(cronjob)
         }
         Err(e) => {
-            error!("Error checking metrics server availability: {}", e);
-            Err(format!("Error checking metrics server availability: {}", e))
+            error!("Failed to create cronjob {}/{}: {}", request.job.namespace, request.job.name, e);
+            Err(format!("Failed to create cronjob: {}", e))
         }
     }
 }
 
-// Pod metrics commands
+// Checks a schedule locally, for inline feedback while the user types
 #[tauri::command]
-pub async fn kuboard_get_pod_metrics(podName: String, namespace: String, state: State<'_, AppState>) -> Result<serde_json::Value, String> {
-    info!("Fetching real-time metrics for pod: {}/{}", namespace, podName);
-    
+pub async fn kuboard_check_cron_schedule(schedule: String) -> Result<(), String> {
+    kuboard_validate_cron_schedule(&schedule).map_err(|e| e.to_string())
+}
+
+// Job Progress Commands
+// Counts against completions and an estimated finish; kuboard_start_job_progress_watch streams the same live
+#[tauri::command]
+pub async fn kuboard_get_job_progress(
+    name: String,
+    namespace: String,
+    state: State<'_, AppState>
+) -> Result<JobProgress, String> {
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    // Check if metrics server is available
-    match kuboard_check_metrics_server_availability(client).await {
-        Ok(true) => {
-            info!("Metrics server is available, fetching real metrics");
-        }
-        Ok(false) => {
-            warn!("Metrics server is not available, using mock data");
-        }
-        Err(e) => {
-            warn!("Error checking metrics server availability: {}, using mock data", e);
+    let jobs_api: Api<Job> = Api::namespaced(client.clone(), &namespace);
+    match jobs_api.get(&name).await {
+        Ok(job) => Ok(kuboard_job_progress(&job, chrono::Utc::now())),
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            Err(format!("Job {}/{} not found", namespace, name))
         }
+        Err(e) => Err(format!("Failed to get job: {}", e)),
     }
+}
 
-    // Fetch real metrics
-    match kuboard_fetch_pod_metrics_real(client, &podName, &namespace).await {
-        Ok(metrics) => {
-            info!("✅ Successfully fetched real pod metrics for: {}/{}", namespace, podName);
-            Ok(serde_json::to_value(metrics).unwrap())
-        }
-        Err(e) => {
-            error!("Failed to fetch real pod metrics for {}/{}: {}", namespace, podName, e);
-            Err(format!("Failed to fetch pod metrics: {}", e))
+// Job Suspend Commands
+// Suspending terminates the Job's active pods but keeps its completions; resuming starts pods again
+async fn set_job_suspended(state: &AppState, name: &str, namespace: &str, suspend: bool) -> Result<Job, String> {
+    let action = if suspend { "suspend" } else { "resume" };
+    production_guard(state, &format!("{}_job", action), &format!("{}/{}", namespace, name)).await?;
+
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let jobs_api: Api<Job> = Api::namespaced(client.clone(), namespace);
+    let job = match jobs_api.get(name).await {
+        Ok(job) => job,
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            return Err(format!("Job {}/{} not found", namespace, name));
         }
+        Err(e) => return Err(format!("Failed to get job: {}", e)),
+    };
+    if kuboard_cronjob_run(&job).state != CronJobRunState::Active {
+        return Err(format!("Job {}/{} has already finished", namespace, name));
     }
+
+    kuboard_replace_with_retry(&jobs_api, name, job, false, |job| {
+        job.spec.as_mut().ok_or_else(|| anyhow::anyhow!("Job has no spec"))?.suspend = Some(suspend);
+        Ok(())
+    }).await.map_err(|e| mutation_error(e, &format!("Failed to {} job", action)))
 }
 
 #[tauri::command]
-pub async fn kuboard_get_pod_metrics_history(
-    podName: String,
+pub async fn kuboard_suspend_job(
+    name: String,
     namespace: String,
-    durationMinutes: u32,
     state: State<'_, AppState>
-) -> Result<Vec<serde_json::Value>, String> {
-    info!("Fetching {} minutes of pod metrics history for: {}/{}", durationMinutes, namespace, podName);
-    
+) -> Result<Job, String> {
+    set_job_suspended(&state, &name, &namespace, true).await
+}
+
+#[tauri::command]
+pub async fn kuboard_resume_job(
+    name: String,
+    namespace: String,
+    state: State<'_, AppState>
+) -> Result<Job, String> {
+    set_job_suspended(&state, &name, &namespace, false).await
+}
+
+// PVC Creation Commands
+#[tauri::command]
+pub async fn kuboard_create_pvc(
+    name: String,
+    namespace: String,
+    size: String,
+    storage_class: Option<String>,
+    access_modes: Option<Vec<String>>,
+    wait_for_bound: Option<bool>,
+    state: State<'_, AppState>
+) -> Result<PvcCreateResult, String> {
+    info!("Creating PVC {}/{} ({}, class {:?})", namespace, name, size, storage_class);
+    production_guard(&state, "create_pvc", &format!("{}/{}", namespace, name)).await?;
+
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    // Check if metrics server is available
-    match kuboard_check_metrics_server_availability(client).await {
-        Ok(true) => {
-            info!("Metrics server is available, fetching real metrics history");
-        }
-        Ok(false) => {
-            warn!("Metrics server is not available");
-            return Err("Metrics server is not available".to_string());
+    let access_modes = access_modes.unwrap_or_default();
+    match kuboard_create_pvc_checked(client, &name, &namespace, &size, storage_class.as_deref(), &access_modes, wait_for_bound.unwrap_or(false)).await {
+        Ok(result) => {
+            info!("✅ Created PVC {}/{} with StorageClass {} (bound: {})", namespace, name, result.storage_class, result.bound);
+            Ok(result)
         }
         Err(e) => {
-            warn!("Error checking metrics server availability: {}", e);
-            return Err(format!("Error checking metrics server availability: {}", e));
+            error!("Failed to create PVC {}/{}: {}", namespace, name, e);
+            Err(format!("Failed to create PVC: {}", e))
         }
     }
+}
 
-    // Fetch real metrics history
-    match kuboard_fetch_pod_metrics_history(client, &podName, &namespace, durationMinutes).await {
-        Ok(history) => {
-            info!("✅ Successfully fetched real pod metrics history for: {}/{}", namespace, podName);
-            let json_history: Vec<serde_json::Value> = history.into_iter()
-                .map(|dp| serde_json::to_value(dp).unwrap())
-                .collect();
-            Ok(json_history)
-        }
+// PVC Commands
+// Namespace-scoped users often can't read PersistentVolumes; the volume details are then left out
+async fn list_persistent_volumes(client: &Client) -> Vec<PersistentVolume> {
+    match Api::<PersistentVolume>::all(client.clone()).list(&ListParams::default()).await {
+        Ok(volumes) => volumes.items,
         Err(e) => {
-            error!("Failed to fetch real pod metrics history for {}/{}: {}", namespace, podName, e);
-            Err(format!("Failed to fetch pod metrics history: {}", e))
+            warn!("Could not read persistent volumes: {}", e);
+            Vec::new()
         }
     }
 }
 
 #[tauri::command]
-pub async fn kuboard_get_pod_events(
-    podName: String,
-    namespace: String,
+pub async fn kuboard_get_pvcs(
+    namespace: Option<String>,
     state: State<'_, AppState>
-) -> Result<Vec<serde_json::Value>, String> {
-    info!("Fetching events for pod: {}/{}", namespace, podName);
-    
+) -> Result<Vec<PvcSummary>, String> {
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    match kuboard_fetch_pod_events(client, &podName, &namespace).await {
-        Ok(events) => {
-            info!("✅ Successfully fetched events for pod: {}/{}", namespace, podName);
-            let json_events: Vec<serde_json::Value> = events.into_iter()
-                .map(|event| serde_json::to_value(event).unwrap())
-                .collect();
-            Ok(json_events)
-        }
-        Err(e) => {
-            error!("Failed to fetch events for pod: {}/{}: {}", namespace, podName, e);
-            Err(e.to_string())
-        }
-    }
+    let filter = current_namespace_filter(&state).await;
+    let claims = kuboard_list_scoped::<PersistentVolumeClaim>(client, &filter, namespace.as_deref(), &ListParams::default()).await
+        .map_err(|e| format!("Failed to get persistent volume claims: {}", e))?;
+    let pods = kuboard_list_scoped::<Pod>(client, &filter, namespace.as_deref(), &ListParams::default()).await
+        .map_err(|e| format!("Failed to list pods: {}", e))?;
+    let volumes = list_persistent_volumes(client).await;
+
+    // A claim can only be mounted by pods in its own namespace
+    Ok(claims.iter()
+        .map(|claim| {
+            let namespace = claim.metadata.namespace.as_deref();
+            let pods: Vec<Pod> = pods.iter().filter(|pod| pod.metadata.namespace.as_deref() == namespace).cloned().collect();
+            kuboard_pvc_summary(claim, &volumes, &pods)
+        })
+        .collect())
 }
 
 #[tauri::command]
-pub async fn kuboard_get_pod_logs(
-    podName: String,
+pub async fn kuboard_get_pvc(
+    name: String,
     namespace: String,
-    containerName: Option<String>,
-    tailLines: Option<u32>,
-    follow: Option<bool>,
     state: State<'_, AppState>
-) -> Result<String, String> {
-    info!("Fetching logs for pod: {}/{}", namespace, podName);
-    
+) -> Result<PvcDetail, String> {
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    match kuboard_fetch_pod_logs(client, &podName, &namespace, containerName.as_deref(), tailLines, follow.unwrap_or(false)).await {
-        Ok(logs) => {
-            info!("✅ Successfully fetched logs for pod: {}/{}", namespace, podName);
-            Ok(logs)
-        }
-        Err(e) => {
-            error!("Failed to fetch logs for pod: {}/{}: {}", namespace, podName, e);
-            Err(e.to_string())
+    let claim = match Api::<PersistentVolumeClaim>::namespaced(client.clone(), &namespace).get(&name).await {
+        Ok(claim) => claim,
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            return Err(format!("PersistentVolumeClaim {}/{} not found", namespace, name));
         }
-    }
+        Err(e) => return Err(format!("Failed to get persistent volume claim: {}", e)),
+    };
+    let pods = Api::<Pod>::namespaced(client.clone(), &namespace).list(&ListParams::default()).await
+        .map_err(|e| format!("Failed to list pods: {}", e))?;
+    let volumes = list_persistent_volumes(client).await;
+
+    let summary = kuboard_pvc_summary(&claim, &volumes, &pods.items);
+    let volume = summary.volume_name.as_deref()
+        .and_then(|bound| volumes.into_iter().find(|pv| pv.metadata.name.as_deref() == Some(bound)));
+    Ok(PvcDetail { summary, claim, volume })
 }
 
-// Cluster-wide metrics command
+// Not kept for undo: recreating the claim wouldn't bring back a volume its reclaim policy deleted
 #[tauri::command]
-pub async fn kuboard_get_cluster_metrics(state: State<'_, AppState>) -> Result<serde_json::Value, String> {
-    info!("Fetching cluster-wide metrics");
-    
+pub async fn kuboard_delete_pvc(
+    name: String,
+    namespace: String,
+    dry_run: Option<bool>,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Deleting PVC: {}/{}", namespace, name);
+    let dry_run = dry_run.unwrap_or(false);
+    if !dry_run {
+        production_guard(&state, "delete_pvc", &format!("{}/{}", namespace, name)).await?;
+    }
+
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    // Get all nodes
-    let nodes_api: Api<Node> = Api::all(client.clone());
-    let nodes = match nodes_api.list(&Default::default()).await {
-        Ok(nodes) => nodes.items,
+    // The pvc-protection finalizer holds the claim in Terminating while any pod still mounts it
+    let mounted_by = match Api::<Pod>::namespaced(client.clone(), &namespace).list(&ListParams::default()).await {
+        Ok(pods) => kuboard_pvc_mounts(&name, &pods.items).into_iter().map(|mount| mount.pod).collect(),
         Err(e) => {
-            error!("Failed to get nodes for cluster metrics: {}", e);
-            return Err(format!("Failed to get nodes: {}", e));
+            warn!("Could not list pods mounting {}/{}: {}", namespace, name, e);
+            Vec::new()
         }
     };
+    let in_use = if mounted_by.is_empty() {
+        String::new()
+    } else {
+        format!("; it stays Terminating until {} stop using it", mounted_by.join(", "))
+    };
 
-    // Calculate cluster-wide totals
-    let mut total_cpu_cores = 0.0;
-    let mut total_memory_bytes = 0u64;
-    let mut total_disk_bytes = 0u64;
-    let mut total_cpu_usage = 0.0;
-    let mut total_memory_usage = 0u64;
-    let mut total_disk_usage = 0u64;
-
-    // Check if metrics server is available
-    let metrics_available = kuboard_check_metrics_server_availability(client).await.unwrap_or(false);
-
-    for node in &nodes {
-        // Parse node capacity
-        if let Some(capacity) = &node.status.as_ref().and_then(|s| s.capacity.as_ref()) {
-            // CPU capacity
-            if let Some(cpu_quantity) = capacity.get("cpu") {
-                if let Ok(cpu_cores) = parse_cpu_capacity(&cpu_quantity.0) {
-                    total_cpu_cores += cpu_cores;
-                }
-            }
-            
-            // Memory capacity
-            if let Some(memory_quantity) = capacity.get("memory") {
-                if let Ok(memory_bytes) = parse_memory_capacity(&memory_quantity.0) {
-                    total_memory_bytes += memory_bytes;
-                }
-            }
-            
-            // Disk capacity
-            if let Some(disk_quantity) = capacity.get("ephemeral-storage") {
-                if let Ok(disk_bytes) = parse_memory_capacity(&disk_quantity.0) {
-                    total_disk_bytes += disk_bytes;
-                }
-            }
+    let pvc_api: Api<PersistentVolumeClaim> = Api::namespaced(client.clone(), &namespace);
+    match pvc_api.delete(&name, &delete_params(dry_run)).await {
+        Ok(_) if dry_run => Ok(format!("PersistentVolumeClaim {}/{} would be deleted (dry run){}", namespace, name, in_use)),
+        Ok(_) => {
+            info!("✅ Successfully deleted PVC: {}/{}", namespace, name);
+            Ok(format!("PersistentVolumeClaim {}/{} deleted successfully{}", namespace, name, in_use))
         }
-
-        // Get usage from metrics server if available
-        if metrics_available {
-            if let Some(node_name) = node.metadata.name.as_ref() {
-                match kuboard_fetch_node_metrics_real(client, node_name).await {
-                    Ok(metrics) => {
-                        total_cpu_usage += metrics.cpu_usage_cores;
-                        total_memory_usage += metrics.memory_usage_bytes;
-                        total_disk_usage += metrics.disk_usage_bytes;
-                    }
-                    Err(e) => {
-                        warn!("Failed to get metrics for node {}: {}", node_name, e);
-                    }
-                }
-            }
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            warn!("PVC {}/{} not found during delete - treating as already deleted", namespace, name);
+            Ok(format!("PersistentVolumeClaim {}/{} not found (already deleted)", namespace, name))
         }
-    }
-
-    // If metrics server not available, calculate from pod requests/limits
-    if !metrics_available {
-        let pods_api: Api<Pod> = Api::all(client.clone());
-        if let Ok(pods) = pods_api.list(&Default::default()).await {
-            for pod in &pods.items {
-                if let Some(spec) = &pod.spec {
-                    for container in &spec.containers {
-                        // CPU requests
-                        if let Some(requests) = &container.resources.as_ref().and_then(|r| r.requests.as_ref()) {
-                            if let Some(cpu_quantity) = requests.get("cpu") {
-                                if let Ok(cpu_cores) = parse_cpu_capacity(&cpu_quantity.0) {
-                                    total_cpu_usage += cpu_cores;
-                                }
-                            }
-                        }
-                        
-                        // Memory requests
-                        if let Some(requests) = &container.resources.as_ref().and_then(|r| r.requests.as_ref()) {
-                            if let Some(memory_quantity) = requests.get("memory") {
-                                if let Ok(memory_bytes) = parse_memory_capacity(&memory_quantity.0) {
-                                    total_memory_usage += memory_bytes;
-                                }
-                            }
-                        }
-                    }
-                }
-            }
+        Err(e) => {
+            error!("Failed to delete PVC {}/{}: {}", namespace, name, e);
+            Err(format!("Failed to delete persistent volume claim: {}", e))
         }
     }
+}
 
-    // Calculate percentages
-    let cpu_usage_percent = if total_cpu_cores > 0.0 {
-        (total_cpu_usage / total_cpu_cores * 100.0).min(100.0)
-    } else {
-        0.0
-    };
+// Persistent Volume Commands
+async fn get_persistent_volume(client: &Client, name: &str) -> Result<PersistentVolume, String> {
+    match Api::<PersistentVolume>::all(client.clone()).get(name).await {
+        Ok(volume) => Ok(volume),
+        Err(kube::Error::Api(e)) if e.code == 404 => Err(format!("PersistentVolume {} not found", name)),
+        Err(e) => Err(format!("Failed to get persistent volume: {}", e)),
+    }
+}
 
-    let memory_usage_percent = if total_memory_bytes > 0 {
-        (total_memory_usage as f64 / total_memory_bytes as f64 * 100.0).min(100.0)
-    } else {
-        0.0
-    };
+#[tauri::command]
+pub async fn kuboard_get_persistent_volumes(state: State<'_, AppState>) -> Result<Vec<PvSummary>, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let disk_usage_percent = if total_disk_bytes > 0 {
-        (total_disk_usage as f64 / total_disk_bytes as f64 * 100.0).min(100.0)
-    } else {
-        0.0
-    };
+    let volumes = Api::<PersistentVolume>::all(client.clone()).list(&ListParams::default()).await
+        .map_err(|e| format!("Failed to get persistent volumes: {}", e))?;
+    Ok(volumes.items.iter().map(kuboard_pv_summary).collect())
+}
 
-    let response = serde_json::json!({
-        "cpu": {
-            "total_cores": total_cpu_cores,
-            "used_cores": total_cpu_usage,
-            "usage_percent": cpu_usage_percent
-        },
-        "memory": {
-            "total_bytes": total_memory_bytes,
-            "used_bytes": total_memory_usage,
-            "usage_percent": memory_usage_percent
-        },
-        "disk": {
-            "total_bytes": total_disk_bytes,
-            "used_bytes": total_disk_usage,
-            "usage_percent": disk_usage_percent
-        },
-        "nodes_count": nodes.len(),
-        "metrics_available": metrics_available
-    });
+#[tauri::command]
+pub async fn kuboard_get_persistent_volume(
+    name: String,
+    state: State<'_, AppState>
+) -> Result<PvDetail, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    Ok(response)
+    let volume = get_persistent_volume(client, &name).await?;
+    Ok(PvDetail { summary: kuboard_pv_summary(&volume), volume })
 }
 
-// Helper functions for parsing capacity strings
-fn parse_cpu_capacity(cpu_str: &str) -> Result<f64, String> {
-    let cpu_str = cpu_str.trim();
-    
-    if cpu_str.ends_with('m') {
-        let millicores_str = cpu_str.trim_end_matches('m');
-        let millicores = millicores_str.parse::<f64>()
-            .map_err(|e| format!("Invalid CPU millicores '{}': {}", cpu_str, e))?;
-        Ok(millicores / 1000.0)
-    } else {
-        cpu_str.parse::<f64>()
-            .map_err(|e| format!("Invalid CPU cores '{}': {}", cpu_str, e))
-    }
-}
-
-fn parse_memory_capacity(memory_str: &str) -> Result<u64, String> {
-    let memory_str = memory_str.trim();
-    
-    if memory_str.ends_with("Ki") {
-        let kibibytes_str = memory_str.trim_end_matches("Ki");
-        let kibibytes = kibibytes_str.parse::<f64>()
-            .map_err(|e| format!("Invalid memory KiB '{}': {}", memory_str, e))?;
-        Ok((kibibytes * 1024.0) as u64)
-    } else if memory_str.ends_with("Mi") {
-        let mebibytes_str = memory_str.trim_end_matches("Mi");
-        let mebibytes = mebibytes_str.parse::<f64>()
-            .map_err(|e| format!("Invalid memory MiB '{}': {}", memory_str, e))?;
-        Ok((mebibytes * 1024.0 * 1024.0) as u64)
-    } else if memory_str.ends_with("Gi") {
-        let gibibytes_str = memory_str.trim_end_matches("Gi");
-        let gibibytes = gibibytes_str.parse::<f64>()
-            .map_err(|e| format!("Invalid memory GiB '{}': {}", memory_str, e))?;
-        Ok((gibibytes * 1024.0 * 1024.0 * 1024.0) as u64)
-    } else {
-        memory_str.parse::<u64>()
-            .map_err(|e| format!("Invalid memory bytes '{}': {}", memory_str, e))
-    }
+// The claim the volume is reserved for and the pods mounting it, with where the chain breaks
+#[tauri::command]
+pub async fn kuboard_get_volume_binding(
+    name: String,
+    state: State<'_, AppState>
+) -> Result<VolumeBinding, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let volume = get_persistent_volume(client, &name).await?;
+    let claim_ref = volume.spec.as_ref()
+        .and_then(|s| s.claim_ref.as_ref())
+        .and_then(|r| Some((r.namespace.clone()?, r.name.clone()?)));
+    let Some((claim_namespace, claim_name)) = claim_ref else {
+        return Ok(kuboard_volume_binding(&volume, None, &[]));
+    };
+
+    let claim = match Api::<PersistentVolumeClaim>::namespaced(client.clone(), &claim_namespace).get(&claim_name).await {
+        Ok(claim) => Some(claim),
+        Err(kube::Error::Api(e)) if e.code == 404 => None,
+        Err(e) => return Err(format!("Failed to get persistent volume claim: {}", e)),
+    };
+    let pods = Api::<Pod>::namespaced(client.clone(), &claim_namespace).list(&ListParams::default()).await
+        .map_err(|e| format!("Failed to list pods: {}", e))?;
+    Ok(kuboard_volume_binding(&volume, claim.as_ref(), &pods.items))
 }
 
-// Pod Actions Commands
+// CSI Health Commands
 #[tauri::command]
-pub async fn kuboard_delete_pod(
-    pod_name: String,
-    namespace: String,
+pub async fn kuboard_get_volume_attachments(
+    node_name: Option<String>,
     state: State<'_, AppState>
-) -> Result<String, String> {
-    info!("Deleting pod: {}/{}", namespace, pod_name);
-    
+) -> Result<Vec<VolumeAttachmentInfo>, String> {
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let pods_api: Api<Pod> = Api::namespaced(client.clone(), &namespace);
-    
-    match pods_api.delete(&pod_name, &DeleteParams::default()).await {
-        Ok(_) => {
-            info!("✅ Successfully deleted pod: {}/{}", namespace, pod_name);
-            Ok(format!("Pod {}/{} deleted successfully", namespace, pod_name))
-        }
-        Err(kube::Error::Api(e)) if e.code == 404 => {
-            // Treat 404 as successful deletion (already gone)
-            warn!("Pod {}/{} not found during delete - treating as already deleted", namespace, pod_name);
-            Ok(format!("Pod {}/{} not found (already deleted)", namespace, pod_name))
-        }
-        Err(e) => {
-            error!("Failed to delete pod {}/{}: {}", namespace, pod_name, e);
-            Err(format!("Failed to delete pod: {}", e))
-        }
-    }
+    let attachments = Api::<VolumeAttachment>::all(client.clone()).list(&ListParams::default()).await
+        .map_err(|e| format!("Failed to list volume attachments: {}", e))?;
+    Ok(attachments.items.iter()
+        .map(kuboard_volume_attachment_info)
+        .filter(|info| node_name.as_ref().is_none_or(|node| &info.node == node))
+        .collect())
 }
 
 #[tauri::command]
-pub async fn kuboard_restart_pod(
-    pod_name: String,
-    namespace: String,
-    state: State<'_, AppState>
-) -> Result<String, String> {
-    info!("Restarting pod: {}/{}", namespace, pod_name);
-    
+pub async fn kuboard_get_csi_drivers(state: State<'_, AppState>) -> Result<Vec<CsiDriverHealth>, String> {
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let pods_api: Api<Pod> = Api::namespaced(client.clone(), &namespace);
-    // Delete directly to trigger recreation by controller. If already gone, treat as success.
-    match pods_api.delete(&pod_name, &DeleteParams::default()).await {
-        Ok(_) => {
-            info!("✅ Successfully restarted (deleted for recreation) pod: {}/{}", namespace, pod_name);
-            Ok(format!("Pod {}/{} restarted (deleted for recreation)", namespace, pod_name))
-        }
-        Err(kube::Error::Api(e)) if e.code == 404 => {
-            warn!("Pod {}/{} not found during restart - treating as already restarted", namespace, pod_name);
-            Ok(format!("Pod {}/{} not found (already restarted)", namespace, pod_name))
-        }
-        Err(e) => {
-            error!("Failed to restart pod {}/{}: {}", namespace, pod_name, e);
-            Err(format!("Failed to restart pod: {}", e))
-        }
-    }
+    let inventory = kuboard_fetch_csi_inventory(client).await
+        .map_err(|e| format!("Failed to read CSI drivers: {}", e))?;
+    Ok(kuboard_csi_driver_health(&inventory))
 }
 
-// Delete Commands for All Resource Types
+// Pods with attach or mount warnings, each affected volume traced to its driver, node and attachment
 #[tauri::command]
-pub async fn kuboard_delete_deployment(
-    name: String,
-    namespace: String,
+pub async fn kuboard_get_volume_attach_issues(
+    namespace: Option<String>,
     state: State<'_, AppState>
-) -> Result<String, String> {
-    info!("Deleting deployment: {}/{}", namespace, name);
-    
+) -> Result<Vec<VolumeAttachIssue>, String> {
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let deployments_api: Api<Deployment> = Api::namespaced(client.clone(), &namespace);
-    
-    match deployments_api.delete(&name, &DeleteParams::default()).await {
-        Ok(_) => {
-            info!("✅ Successfully deleted deployment: {}/{}", namespace, name);
-            Ok(format!("Deployment {}/{} deleted successfully", namespace, name))
-        }
-        Err(kube::Error::Api(e)) if e.code == 404 => {
-            warn!("Deployment {}/{} not found during delete - treating as already deleted", namespace, name);
-            Ok(format!("Deployment {}/{} not found (already deleted)", namespace, name))
-        }
-        Err(e) => {
-            error!("Failed to delete deployment {}/{}: {}", namespace, name, e);
-            Err(format!("Failed to delete deployment: {}", e))
-        }
-    }
+    let filter = current_namespace_filter(&state).await;
+    let warnings = ListParams::default().fields("type=Warning");
+    let events = kuboard_list_scoped::<Event>(client, &filter, namespace.as_deref(), &warnings).await
+        .map_err(|e| format!("Failed to list events: {}", e))?;
+    let pods = kuboard_list_scoped::<Pod>(client, &filter, namespace.as_deref(), &ListParams::default()).await
+        .map_err(|e| format!("Failed to list pods: {}", e))?;
+    let claims = kuboard_list_scoped::<PersistentVolumeClaim>(client, &filter, namespace.as_deref(), &ListParams::default()).await
+        .map_err(|e| format!("Failed to list persistent volume claims: {}", e))?;
+    let inventory = kuboard_fetch_csi_inventory(client).await
+        .map_err(|e| format!("Failed to read CSI drivers: {}", e))?;
+
+    let issues = kuboard_volume_attach_issues(&events, &pods, &claims, &inventory);
+    info!("✅ Found {} volumes stuck attaching or mounting", issues.len());
+    Ok(issues)
 }
 
+// Workload Exposure Commands
 #[tauri::command]
-pub async fn kuboard_delete_statefulset(
+pub async fn kuboard_expose_workload(
+    kind: String,
     name: String,
     namespace: String,
+    port: i32,
+    target_port: Option<String>,
+    service_type: Option<String>,
     state: State<'_, AppState>
-) -> Result<String, String> {
-    info!("Deleting statefulset: {}/{}", namespace, name);
-    
+) -> Result<Service, String> {
+    info!("Exposing {} {}/{} on port {}", kind, namespace, name, port);
+    production_guard(&state, "expose_workload", &format!("{}/{}", namespace, name)).await?;
+
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let statefulsets_api: Api<StatefulSet> = Api::namespaced(client.clone(), &namespace);
-    
-    match statefulsets_api.delete(&name, &DeleteParams::default()).await {
-        Ok(_) => {
-            info!("✅ Successfully deleted statefulset: {}/{}", namespace, name);
-            Ok(format!("StatefulSet {}/{} deleted successfully", namespace, name))
-        }
-        Err(kube::Error::Api(e)) if e.code == 404 => {
-            warn!("StatefulSet {}/{} not found during delete - treating as already deleted", namespace, name);
-            Ok(format!("StatefulSet {}/{} not found (already deleted)", namespace, name))
+    match kuboard_expose_workload_service(client, &kind, &name, &namespace, port, target_port.as_deref(), service_type.as_deref()).await {
+        Ok(service) => {
+            info!("✅ Exposed {} {}/{} as service {}", kind, namespace, name, name);
+            Ok(service)
         }
         Err(e) => {
-            error!("Failed to delete statefulset {}/{}: {}", namespace, name, e);
-            Err(format!("Failed to delete statefulset: {}", e))
+            error!("Failed to expose {} {}/{}: {}", kind, namespace, name, e);
+            Err(format!("Failed to expose workload: {}", e))
         }
     }
 }
 
 #[tauri::command]
-pub async fn kuboard_delete_daemonset(
-    name: String,
-    namespace: String,
+pub async fn kuboard_get_configmaps(
+    namespace: Option<String>,
+    label_selector: Option<String>,
+    field_selector: Option<String>,
     state: State<'_, AppState>
-) -> Result<String, String> {
-    info!("Deleting daemonset: {}/{}", namespace, name);
-    
+) -> Result<Vec<ConfigMap>, String> {
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let daemonsets_api: Api<DaemonSet> = Api::namespaced(client.clone(), &namespace);
-    
-    match daemonsets_api.delete(&name, &DeleteParams::default()).await {
-        Ok(_) => {
-            info!("✅ Successfully deleted daemonset: {}/{}", namespace, name);
-            Ok(format!("DaemonSet {}/{} deleted successfully", namespace, name))
-        }
-        Err(kube::Error::Api(e)) if e.code == 404 => {
-            warn!("DaemonSet {}/{} not found during delete - treating as already deleted", namespace, name);
-            Ok(format!("DaemonSet {}/{} not found (already deleted)", namespace, name))
-        }
-        Err(e) => {
-            error!("Failed to delete daemonset {}/{}: {}", namespace, name, e);
-            Err(format!("Failed to delete daemonset: {}", e))
-        }
-    }
+    let filter = current_namespace_filter(&state).await;
+    kuboard_list_scoped::<ConfigMap>(client, &filter, namespace.as_deref(), &selector_params(label_selector.as_deref(), field_selector.as_deref())).await
+        .map_err(|e| format!("Failed to get configmaps: {}", e))
 }
 
 #[tauri::command]
-pub async fn kuboard_delete_replicaset(
-    name: String,
-    namespace: String,
+pub async fn kuboard_get_secrets(
+    namespace: Option<String>,
+    label_selector: Option<String>,
+    field_selector: Option<String>,
     state: State<'_, AppState>
-) -> Result<String, String> {
-    info!("Deleting replicaset: {}/{}", namespace, name);
-    
+) -> Result<Vec<Secret>, String> {
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let replicasets_api: Api<ReplicaSet> = Api::namespaced(client.clone(), &namespace);
-    
-    match replicasets_api.delete(&name, &DeleteParams::default()).await {
-        Ok(_) => {
-            info!("✅ Successfully deleted replicaset: {}/{}", namespace, name);
-            Ok(format!("ReplicaSet {}/{} deleted successfully", namespace, name))
-        }
-        Err(kube::Error::Api(e)) if e.code == 404 => {
-            warn!("ReplicaSet {}/{} not found during delete - treating as already deleted", namespace, name);
-            Ok(format!("ReplicaSet {}/{} not found (already deleted)", namespace, name))
-        }
-        Err(e) => {
-            error!("Failed to delete replicaset {}/{}: {}", namespace, name, e);
-            Err(format!("Failed to delete replicaset: {}", e))
-        }
-    }
+    let filter = current_namespace_filter(&state).await;
+    kuboard_list_scoped::<Secret>(client, &filter, namespace.as_deref(), &selector_params(label_selector.as_deref(), field_selector.as_deref())).await
+        .map_err(|e| format!("Failed to get secrets: {}", e))
 }
 
+// ConfigMap Reload Commands
+// Sets one key, e.g. a log level or feature flag, then with `restart` rolls the workloads reading
+// the ConfigMap so they pick the value up. A restart that fails is reported on its consumer
 #[tauri::command]
-pub async fn kuboard_delete_service(
+pub async fn kuboard_update_configmap_key(
     name: String,
     namespace: String,
+    key: String,
+    value: String,
+    restart: Option<bool>,
+    dry_run: Option<bool>,
     state: State<'_, AppState>
-) -> Result<String, String> {
-    info!("Deleting service: {}/{}", namespace, name);
-    
+) -> Result<ConfigMapKeyUpdate, String> {
+    info!("Setting key {} of configmap {}/{}", key, namespace, name);
+    let dry_run = dry_run.unwrap_or(false);
+    if !dry_run {
+        production_guard(&state, "update_configmap_key", &format!("{}/{}", namespace, name)).await?;
+    }
+
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let services_api: Api<Service> = Api::namespaced(client.clone(), &namespace);
-    
-    match services_api.delete(&name, &DeleteParams::default()).await {
-        Ok(_) => {
-            info!("✅ Successfully deleted service: {}/{}", namespace, name);
-            Ok(format!("Service {}/{} deleted successfully", namespace, name))
-        }
+    let configmaps_api: Api<ConfigMap> = Api::namespaced(client.clone(), &namespace);
+    let configmap = match configmaps_api.get(&name).await {
+        Ok(configmap) => configmap,
         Err(kube::Error::Api(e)) if e.code == 404 => {
-            warn!("Service {}/{} not found during delete - treating as already deleted", namespace, name);
-            Ok(format!("Service {}/{} not found (already deleted)", namespace, name))
+            return Err(format!("ConfigMap {}/{} not found", namespace, name));
         }
-        Err(e) => {
-            error!("Failed to delete service {}/{}: {}", namespace, name, e);
-            Err(format!("Failed to delete service: {}", e))
+        Err(e) => return Err(format!("Failed to get configmap: {}", e)),
+    };
+    let previous_value = configmap.data.as_ref().and_then(|data| data.get(&key)).cloned();
+    let changed = previous_value.as_deref() != Some(value.as_str());
+    if changed {
+        kuboard_replace_with_retry(&configmaps_api, &name, configmap, dry_run, |configmap| {
+            kuboard_set_configmap_key(configmap, &key, &value).map(|_| ())
+        }).await.map_err(|e| mutation_error(e, "Failed to update configmap"))?;
+    }
+
+    let all = ListParams::default();
+    let deployments = Api::<Deployment>::namespaced(client.clone(), &namespace).list(&all).await
+        .map_err(|e| format!("Failed to list deployments: {}", e))?;
+    let statefulsets = Api::<StatefulSet>::namespaced(client.clone(), &namespace).list(&all).await
+        .map_err(|e| format!("Failed to list statefulsets: {}", e))?;
+    let daemonsets = Api::<DaemonSet>::namespaced(client.clone(), &namespace).list(&all).await
+        .map_err(|e| format!("Failed to list daemonsets: {}", e))?;
+    let mut consumers = kuboard_configmap_consumers(&name, &deployments.items, &statefulsets.items, &daemonsets.items);
+
+    if changed && restart.unwrap_or(false) {
+        let cause = change_cause(&state, &format!("rollout restart after configmap/{} {}={}", name, key, value)).await;
+        let restarted_at = chrono::Utc::now().timestamp().to_string();
+        for consumer in consumers.iter_mut() {
+            match kuboard_restart_consumer(client, &namespace, consumer, &restarted_at, &cause, dry_run).await {
+                Ok(()) => consumer.restarted = true,
+                Err(e) => {
+                    warn!("Failed to restart {} {}/{}: {}", consumer.kind, namespace, consumer.name, e);
+                    consumer.error = Some(e.to_string());
+                }
+            }
         }
     }
+
+    if changed && !dry_run {
+        info!("✅ Set {} of configmap {}/{}, restarted {} of {} consumers",
+            key, namespace, name, consumers.iter().filter(|c| c.restarted).count(), consumers.len());
+    }
+    Ok(ConfigMapKeyUpdate { name, namespace, key, previous_value, changed, dry_run, consumers })
 }
 
+// Manifest Search Commands
+// The objects whose manifests have a field matching `key` and/or `value`, e.g. key `env.name` and
+// value LOG_LEVEL; `kinds` are kind names and default to the workload kinds
 #[tauri::command]
-pub async fn kuboard_delete_cronjob(
-    name: String,
-    namespace: String,
+pub async fn kuboard_search_manifests(
+    key: Option<String>,
+    value: Option<String>,
+    kinds: Option<Vec<String>>,
+    namespace: Option<String>,
+    label_selector: Option<String>,
+    field_selector: Option<String>,
     state: State<'_, AppState>
-) -> Result<String, String> {
-    info!("Deleting cronjob: {}/{}", namespace, name);
-    
+) -> Result<Vec<ManifestSearchHit>, String> {
+    let query = kuboard_manifest_query(key.as_deref(), value.as_deref()).map_err(|e| e.to_string())?;
+    let kinds = match kinds.filter(|kinds| !kinds.is_empty()) {
+        Some(kinds) => kinds.iter().map(|kind| kuboard_search_kind(kind)).collect::<anyhow::Result<Vec<_>>>().map_err(|e| e.to_string())?,
+        None => DEFAULT_SEARCH_KINDS.to_vec(),
+    };
+
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let cronjobs_api: Api<CronJob> = Api::namespaced(client.clone(), &namespace);
-    
-    match cronjobs_api.delete(&name, &DeleteParams::default()).await {
-        Ok(_) => {
-            info!("✅ Successfully deleted cronjob: {}/{}", namespace, name);
-            Ok(format!("CronJob {}/{} deleted successfully", namespace, name))
-        }
-        Err(kube::Error::Api(e)) if e.code == 404 => {
-            warn!("CronJob {}/{} not found during delete - treating as already deleted", namespace, name);
-            Ok(format!("CronJob {}/{} not found (already deleted)", namespace, name))
-        }
-        Err(e) => {
-            error!("Failed to delete cronjob {}/{}: {}", namespace, name, e);
-            Err(format!("Failed to delete cronjob: {}", e))
-        }
+    let filter = current_namespace_filter(&state).await;
+    let params = selector_params(label_selector.as_deref(), field_selector.as_deref());
+    let namespace = namespace.as_deref();
+    let mut hits = Vec::new();
+    for kind in &kinds {
+        let found = match *kind {
+            "Deployment" => kuboard_search_scoped::<Deployment>(client, &filter, namespace, &params, &query).await,
+            "StatefulSet" => kuboard_search_scoped::<StatefulSet>(client, &filter, namespace, &params, &query).await,
+            "DaemonSet" => kuboard_search_scoped::<DaemonSet>(client, &filter, namespace, &params, &query).await,
+            "CronJob" => kuboard_search_scoped::<CronJob>(client, &filter, namespace, &params, &query).await,
+            "Job" => kuboard_search_scoped::<Job>(client, &filter, namespace, &params, &query).await,
+            "Pod" => kuboard_search_scoped::<Pod>(client, &filter, namespace, &params, &query).await,
+            "Service" => kuboard_search_scoped::<Service>(client, &filter, namespace, &params, &query).await,
+            "ConfigMap" => kuboard_search_scoped::<ConfigMap>(client, &filter, namespace, &params, &query).await,
+            other => return Err(format!("Can't search {}", other)),
+        };
+        hits.extend(found.map_err(|e| e.to_string())?);
     }
+    info!("Manifest search matched {} objects across {} kinds", hits.len(), kinds.len());
+    Ok(hits)
 }
 
+// Metrics Commands - Real Implementation
 #[tauri::command]
-pub async fn kuboard_get_pod_yaml(
-    pod_name: String,
-    namespace: String,
-    state: State<'_, AppState>
-) -> Result<String, String> {
-    info!("Getting YAML for pod: {}/{}", namespace, pod_name);
+pub async fn kuboard_get_node_metrics(node_name: String, state: State<'_, AppState>) -> Result<NodeMetricsResponse, String> {
+    info!("Fetching real-time metrics for node: {}", node_name);
     
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let pods_api: Api<Pod> = Api::namespaced(client.clone(), &namespace);
-    
-    match pods_api.get(&pod_name).await {
-        Ok(pod) => {
-            // Convert to JSON first, then format as YAML-like structure
-            // Note: We'll use JSON for now, YAML can be added later if needed
-            match serde_json::to_string_pretty(&pod) {
-                Ok(json) => {
-                    info!("✅ Successfully retrieved pod data: {}/{}", namespace, pod_name);
-                    Ok(json)
-                }
-                Err(e) => {
-                    error!("Failed to serialize pod to JSON: {}", e);
-                    Err(format!("Failed to serialize pod: {}", e))
-                }
-            }
+    // Check if metrics server is available
+    match kuboard_check_metrics_server_availability(client).await {
+        Ok(true) => {
+            info!("Metrics server is available, fetching real metrics");
         }
-        Err(kube::Error::Api(e)) if e.code == 404 => {
-            Err(format!("Pod {}/{} not found", namespace, pod_name))
+        Ok(false) => {
+            warn!("Metrics server is not available");
+            return Err("Metrics server is not available".to_string());
         }
         Err(e) => {
-            error!("Failed to get pod {}/{}: {}", namespace, pod_name, e);
-            Err(format!("Failed to get pod: {}", e))
+            warn!("Error checking metrics server availability: {}", e);
+            return Err(format!("Error checking metrics server availability: {}", e));
+        }
+    }
+
+    // Fetch real metrics
+    match kuboard_fetch_node_metrics_real(client, &node_name).await {
+        Ok(metrics) => Ok(NodeMetricsResponse::formatted(metrics, &*state.format_settings.read().await)),
+        Err(e) => {
+            error!("Failed to fetch metrics for node {}: {}", node_name, e);
+            Err(format!("Failed to fetch metrics: {}", e))
         }
     }
 }
 
-// YAML Get Commands for All Resource Types
 #[tauri::command]
-pub async fn kuboard_get_deployment_yaml(
-    name: String,
-    namespace: String,
+pub async fn kuboard_get_node_metrics_history(
+    node_name: String, 
+    duration_minutes: u32, 
     state: State<'_, AppState>
-) -> Result<String, String> {
+) -> Result<Vec<NodeMetricsResponse>, String> {
+    info!("Fetching {} minutes of metrics history for node: {}", duration_minutes, node_name);
+    
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let deployments_api: Api<Deployment> = Api::namespaced(client.clone(), &namespace);
-    
-    match deployments_api.get(&name).await {
-        Ok(deployment) => {
-            match serde_json::to_string_pretty(&deployment) {
-                Ok(json) => Ok(json),
-                Err(e) => Err(format!("Failed to serialize deployment: {}", e))
-            }
+    // Fetch historical metrics
+    match kuboard_fetch_node_metrics_history(client, &node_name, duration_minutes).await {
+        Ok(history) => {
+            let format = state.format_settings.read().await;
+            Ok(history.into_iter().map(|point| NodeMetricsResponse::formatted(point, &format)).collect())
         }
-        Err(kube::Error::Api(e)) if e.code == 404 => {
-            Err(format!("Deployment {}/{} not found", namespace, name))
+        Err(e) => {
+            error!("Failed to fetch metrics history for node {}: {}", node_name, e);
+            Err(format!("Failed to fetch metrics history: {}", e))
         }
-        Err(e) => Err(format!("Failed to get deployment: {}", e))
     }
 }
 
+// Eviction Order Commands
 #[tauri::command]
-pub async fn kuboard_get_statefulset_yaml(
-    name: String,
-    namespace: String,
-    state: State<'_, AppState>
-) -> Result<String, String> {
+pub async fn kuboard_get_node_eviction_order(node_name: String, state: State<'_, AppState>) -> Result<NodeEvictionReport, String> {
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let statefulsets_api: Api<StatefulSet> = Api::namespaced(client.clone(), &namespace);
-    
-    match statefulsets_api.get(&name).await {
-        Ok(statefulset) => {
-            match serde_json::to_string_pretty(&statefulset) {
-                Ok(json) => Ok(json),
-                Err(e) => Err(format!("Failed to serialize statefulset: {}", e))
-            }
-        }
-        Err(kube::Error::Api(e)) if e.code == 404 => {
-            Err(format!("StatefulSet {}/{} not found", namespace, name))
+    let pods_api: Api<Pod> = Api::all(client.clone());
+    let pods = pods_api.list(&ListParams::default().fields(&format!("spec.nodeName={}", node_name))).await
+        .map_err(|e| format!("Failed to list pods on node {}: {}", node_name, e))?;
+
+    // Usage only sharpens the ranking, so a cluster without metrics-server still gets a report
+    let usage: Option<HashMap<(String, String), u64>> = match get_pod_metrics(client).await {
+        Ok(metrics) => Some(metrics.items.into_iter()
+            .map(|pod| {
+                let memory = pod.containers.iter().filter_map(|c| kuboard_parse_bytes(&c.usage.memory).ok()).sum();
+                ((pod.metadata.namespace, pod.metadata.name), memory)
+            })
+            .collect()),
+        Err(e) => {
+            warn!("Ranking pods on {} without usage, pod metrics unavailable: {}", node_name, e);
+            None
         }
-        Err(e) => Err(format!("Failed to get statefulset: {}", e))
-    }
+    };
+
+    let metrics_available = usage.is_some();
+    let candidates = kuboard_eviction_order(&pods.items, &usage.unwrap_or_default());
+    info!("✅ Ranked {} pods on node {} for eviction", candidates.len(), node_name);
+    Ok(NodeEvictionReport {
+        node: node_name,
+        metrics_available,
+        pods: candidates,
+    })
 }
 
+// Ephemeral Storage Commands
 #[tauri::command]
-pub async fn kuboard_get_daemonset_yaml(
-    name: String,
+pub async fn kuboard_get_pod_ephemeral_storage(
+    pod_name: String,
     namespace: String,
     state: State<'_, AppState>
-) -> Result<String, String> {
+) -> Result<EphemeralStorageUsage, String> {
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let daemonsets_api: Api<DaemonSet> = Api::namespaced(client.clone(), &namespace);
-    
-    match daemonsets_api.get(&name).await {
-        Ok(daemonset) => {
-            match serde_json::to_string_pretty(&daemonset) {
-                Ok(json) => Ok(json),
-                Err(e) => Err(format!("Failed to serialize daemonset: {}", e))
+    let pods_api: Api<Pod> = Api::namespaced(client.clone(), &namespace);
+    let pod = match pods_api.get(&pod_name).await {
+        Ok(pod) => pod,
+        Err(kube::Error::Api(e)) if e.code == 404 => return Err(format!("Pod {}/{} not found", namespace, pod_name)),
+        Err(e) => return Err(format!("Failed to get pod: {}", e)),
+    };
+
+    // An unscheduled pod has no usage yet, and unreachable kubelet stats still leave requests and limits to show
+    let used = match pod.spec.as_ref().and_then(|s| s.node_name.as_deref()) {
+        Some(node) => match kuboard_fetch_ephemeral_usage(client, node).await {
+            Ok(usage) => usage.get(&(namespace.clone(), pod_name.clone())).copied(),
+            Err(e) => {
+                warn!("Failed to read kubelet stats from {}: {}", node, e);
+                None
             }
-        }
-        Err(kube::Error::Api(e)) if e.code == 404 => {
-            Err(format!("DaemonSet {}/{} not found", namespace, name))
-        }
-        Err(e) => Err(format!("Failed to get daemonset: {}", e))
-    }
+        },
+        None => None,
+    };
+    Ok(kuboard_pod_ephemeral_storage(&pod, used))
 }
 
 #[tauri::command]
-pub async fn kuboard_get_replicaset_yaml(
-    name: String,
-    namespace: String,
+pub async fn kuboard_get_ephemeral_storage_report(
+    namespace: Option<String>,
+    threshold_percent: Option<f64>,
     state: State<'_, AppState>
-) -> Result<String, String> {
+) -> Result<EphemeralStorageReport, String> {
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let replicasets_api: Api<ReplicaSet> = Api::namespaced(client.clone(), &namespace);
-    
-    match replicasets_api.get(&name).await {
-        Ok(replicaset) => {
-            match serde_json::to_string_pretty(&replicaset) {
-                Ok(json) => Ok(json),
-                Err(e) => Err(format!("Failed to serialize replicaset: {}", e))
-            }
-        }
-        Err(kube::Error::Api(e)) if e.code == 404 => {
-            Err(format!("ReplicaSet {}/{} not found", namespace, name))
-        }
-        Err(e) => Err(format!("Failed to get replicaset: {}", e))
-    }
+    let filter = current_namespace_filter(&state).await;
+    let pods = kuboard_list_scoped::<Pod>(client, &filter, namespace.as_deref(), &ListParams::default()).await
+        .map_err(|e| format!("Failed to list pods: {}", e))?;
+
+    let mut nodes: Vec<String> = pods.iter().filter_map(|pod| pod.spec.as_ref()?.node_name.clone()).collect();
+    nodes.sort();
+    nodes.dedup();
+    let (usage, unreachable_nodes) = kuboard_fetch_ephemeral_usage_on_nodes(client, &nodes).await;
+
+    let threshold_percent = threshold_percent.unwrap_or(DEFAULT_EPHEMERAL_STORAGE_THRESHOLD_PERCENT);
+    let usages = pods.iter()
+        .map(|pod| {
+            let key = (pod.metadata.namespace.clone().unwrap_or_default(), pod.metadata.name.clone().unwrap_or_default());
+            kuboard_pod_ephemeral_storage(pod, usage.get(&key).copied())
+        })
+        .collect();
+    let near = kuboard_pods_near_ephemeral_limit(usages, threshold_percent);
+    info!("✅ {} pods at or above {}% of their ephemeral-storage limit", near.len(), threshold_percent);
+    Ok(EphemeralStorageReport { threshold_percent, pods: near, unreachable_nodes })
 }
 
+// Check metrics server availability
 #[tauri::command]
-pub async fn kuboard_get_service_yaml(
-    name: String,
-    namespace: String,
-    state: State<'_, AppState>
-) -> Result<String, String> {
+pub async fn kuboard_check_metrics_availability(state: State<'_, AppState>) -> Result<MetricsAvailability, String> {
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let services_api: Api<Service> = Api::namespaced(client.clone(), &namespace);
-    
-    match services_api.get(&name).await {
-        Ok(service) => {
-            match serde_json::to_string_pretty(&service) {
-                Ok(json) => Ok(json),
-                Err(e) => Err(format!("Failed to serialize service: {}", e))
-            }
-        }
-        Err(kube::Error::Api(e)) if e.code == 404 => {
-            Err(format!("Service {}/{} not found", namespace, name))
+    match kuboard_check_metrics_server_availability(client).await {
+        Ok(available) => Ok(MetricsAvailability { available }),
+        Err(e) => {
+            error!("Error checking metrics server availability: {}", e);
+            Err(format!("Error checking metrics server availability: {}", e))
         }
-        Err(e) => Err(format!("Failed to get service: {}", e))
     }
 }
 
+// Opt-in install of the pinned upstream metrics-server when the metrics API is missing
 #[tauri::command]
-pub async fn kuboard_get_cronjob_yaml(
-    name: String,
-    namespace: String,
+pub async fn kuboard_install_metrics_server(
+    insecure_kubelet_tls: bool,
     state: State<'_, AppState>
-) -> Result<String, String> {
+) -> Result<MetricsServerInstallResult, String> {
+    production_guard(&state, "install_metrics_server", "kube-system/metrics-server").await?;
+
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let cronjobs_api: Api<CronJob> = Api::namespaced(client.clone(), &namespace);
-    
-    match cronjobs_api.get(&name).await {
-        Ok(cronjob) => {
-            match serde_json::to_string_pretty(&cronjob) {
-                Ok(json) => Ok(json),
-                Err(e) => Err(format!("Failed to serialize cronjob: {}", e))
-            }
+    match kuboard_deploy_metrics_server(client, insecure_kubelet_tls).await {
+        Ok(result) => {
+            info!("✅ metrics-server {}: {} objects applied, metrics available: {}", result.version, result.applied.len(), result.available);
+            Ok(result)
         }
-        Err(kube::Error::Api(e)) if e.code == 404 => {
-            Err(format!("CronJob {}/{} not found", namespace, name))
+        Err(e) => {
+            error!("Failed to install metrics-server: {}", e);
+            Err(format!("Failed to install metrics-server: {}", e))
         }
-        Err(e) => Err(format!("Failed to get cronjob: {}", e))
     }
 }
 
+// Pod commands take their arguments wrapped in `request`; frontends that predate the request
+// structs send flat camelCase arguments instead, which Tauri maps onto the snake_case parameters
+fn request_or_flat<T: serde::de::DeserializeOwned>(request: Option<T>, flat: serde_json::Value) -> Result<T, String> {
+    match request {
+        Some(request) => Ok(request),
+        None => serde_json::from_value(flat).map_err(|e| format!("Invalid command arguments: {}", e)),
+    }
+}
+
+// Pod metrics commands
 #[tauri::command]
-pub async fn kuboard_update_pod_from_yaml(
-    pod_name: String,
-    namespace: String,
-    yaml_content: String,
+pub async fn kuboard_get_pod_metrics(
+    request: Option<PodMetricsRequest>,
+    pod_name: Option<String>,
+    namespace: Option<String>,
     state: State<'_, AppState>
-) -> Result<String, String> {
-    info!("Updating pod from YAML: {}/{}", namespace, pod_name);
+) -> Result<MetricsDataPoint, String> {
+    let PodMetricsRequest { pod_name, namespace } =
+        request_or_flat(request, json!({ "pod_name": pod_name, "namespace": namespace }))?;
+    info!("Fetching real-time metrics for pod: {}/{}", namespace, pod_name);
     
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let pods_api: Api<Pod> = Api::namespaced(client.clone(), &namespace);
-    
-    // Parse JSON/YAML content
-    let mut updated_pod: Pod = match serde_json::from_str(&yaml_content) {
-        Ok(pod) => pod,
-        Err(e) => {
-            error!("Failed to parse YAML/JSON: {}", e);
+    // Check if metrics server is available
+    match kuboard_check_metrics_server_availability(client).await {
+        Ok(true) => {
+            info!("Metrics server is available, fetching real metrics");
+        }
+        Ok(false) => {
+            warn!("Metrics server is not available, using mock data");
+        }
+        Err(e) => {
+            warn!("Error checking metrics server availability: {}, using mock data", e);
+        }
+    }
+
+    // Fetch real metrics
+    match kuboard_fetch_pod_metrics_real(client, &pod_name, &namespace).await {
+        Ok(metrics) => {
+            info!("✅ Successfully fetched real pod metrics for: {}/{}", namespace, pod_name);
+            Ok(metrics)
+        }
+        Err(e) => {
+            error!("Failed to fetch real pod metrics for {}/{}: {}", namespace, pod_name, e);
+            Err(format!("Failed to fetch pod metrics: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_get_pod_metrics_history(
+    request: Option<PodMetricsHistoryRequest>,
+    pod_name: Option<String>,
+    namespace: Option<String>,
+    duration_minutes: Option<u32>,
+    state: State<'_, AppState>
+) -> Result<Vec<MetricsDataPoint>, String> {
+    let PodMetricsHistoryRequest { pod_name, namespace, duration_minutes } = request_or_flat(
+        request,
+        json!({ "pod_name": pod_name, "namespace": namespace, "duration_minutes": duration_minutes }),
+    )?;
+    info!("Fetching {} minutes of pod metrics history for: {}/{}", duration_minutes, namespace, pod_name);
+    
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    // Check if metrics server is available
+    match kuboard_check_metrics_server_availability(client).await {
+        Ok(true) => {
+            info!("Metrics server is available, fetching real metrics history");
+        }
+        Ok(false) => {
+            warn!("Metrics server is not available");
+            return Err("Metrics server is not available".to_string());
+        }
+        Err(e) => {
+            warn!("Error checking metrics server availability: {}", e);
+            return Err(format!("Error checking metrics server availability: {}", e));
+        }
+    }
+
+    // Fetch real metrics history
+    match kuboard_fetch_pod_metrics_history(client, &pod_name, &namespace, duration_minutes).await {
+        Ok(history) => {
+            info!("✅ Successfully fetched real pod metrics history for: {}/{}", namespace, pod_name);
+            Ok(history)
+        }
+        Err(e) => {
+            error!("Failed to fetch real pod metrics history for {}/{}: {}", namespace, pod_name, e);
+            Err(format!("Failed to fetch pod metrics history: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_get_pod_events(
+    request: Option<PodEventsRequest>,
+    pod_name: Option<String>,
+    namespace: Option<String>,
+    state: State<'_, AppState>
+) -> Result<Vec<serde_json::Value>, String> {
+    let PodEventsRequest { pod_name, namespace } =
+        request_or_flat(request, json!({ "pod_name": pod_name, "namespace": namespace }))?;
+    info!("Fetching events for pod: {}/{}", namespace, pod_name);
+    
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    match kuboard_fetch_pod_events(client, &pod_name, &namespace).await {
+        Ok(events) => {
+            info!("✅ Successfully fetched events for pod: {}/{}", namespace, pod_name);
+            let json_events: Vec<serde_json::Value> = events.into_iter()
+                .map(|event| serde_json::to_value(event).unwrap())
+                .collect();
+            Ok(json_events)
+        }
+        Err(e) => {
+            error!("Failed to fetch events for pod: {}/{}: {}", namespace, pod_name, e);
+            Err(e.to_string())
+        }
+    }
+}
+
+// Event counts over the last `window_minutes` (default an hour) by namespace and reason, in
+// `bucket_minutes` buckets (default 5), with the noisiest namespaces first
+#[tauri::command]
+pub async fn kuboard_get_event_trends(
+    namespace: Option<String>,
+    window_minutes: Option<i64>,
+    bucket_minutes: Option<i64>,
+    state: State<'_, AppState>
+) -> Result<EventTrends, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let filter = current_namespace_filter(&state).await;
+    let events = kuboard_list_scoped::<Event>(client, &filter, namespace.as_deref(), &ListParams::default()).await
+        .map_err(|e| format!("Failed to get events: {}", e))?;
+    let trends = kuboard_event_trends(
+        &events,
+        chrono::Utc::now(),
+        window_minutes.unwrap_or(DEFAULT_EVENT_WINDOW_MINUTES),
+        bucket_minutes.unwrap_or(DEFAULT_EVENT_BUCKET_MINUTES),
+    );
+    info!("✅ Counted {} events ({} warnings) in the last {} minutes", trends.total, trends.warnings, trends.window_minutes);
+    Ok(trends)
+}
+
+#[tauri::command]
+pub async fn kuboard_get_pod_logs(
+    request: Option<PodLogsRequest>,
+    pod_name: Option<String>,
+    namespace: Option<String>,
+    container_name: Option<String>,
+    tail_lines: Option<u32>,
+    follow: Option<bool>,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    let PodLogsRequest { pod_name, namespace, container_name, tail_lines, follow } = request_or_flat(
+        request,
+        json!({
+            "pod_name": pod_name,
+            "namespace": namespace,
+            "container_name": container_name,
+            "tail_lines": tail_lines,
+            "follow": follow,
+        }),
+    )?;
+    info!("Fetching logs for pod: {}/{}", namespace, pod_name);
+    
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    match kuboard_fetch_pod_logs(client, &pod_name, &namespace, container_name.as_deref(), tail_lines, follow.unwrap_or(false)).await {
+        Ok(logs) => {
+            info!("✅ Successfully fetched logs for pod: {}/{}", namespace, pod_name);
+            Ok(logs)
+        }
+        Err(e) => {
+            error!("Failed to fetch logs for pod: {}/{}: {}", namespace, pod_name, e);
+            Err(e.to_string())
+        }
+    }
+}
+
+// Cluster-wide metrics command
+#[tauri::command]
+pub async fn kuboard_get_cluster_metrics(state: State<'_, AppState>) -> Result<ClusterMetricsSummary, String> {
+    info!("Fetching cluster-wide metrics");
+    
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    // Get all nodes
+    let nodes_api: Api<Node> = Api::all(client.clone());
+    let nodes = match nodes_api.list(&Default::default()).await {
+        Ok(nodes) => nodes.items,
+        Err(e) => {
+            error!("Failed to get nodes for cluster metrics: {}", e);
+            return Err(format!("Failed to get nodes: {}", e));
+        }
+    };
+
+    // Calculate cluster-wide totals
+    let mut total_cpu_cores = 0.0;
+    let mut total_memory_bytes = 0u64;
+    let mut total_disk_bytes = 0u64;
+    let mut total_cpu_usage = 0.0;
+    let mut total_memory_usage = 0u64;
+    let mut total_disk_usage = 0u64;
+
+    // Check if metrics server is available
+    let metrics_available = kuboard_check_metrics_server_availability(client).await.unwrap_or(false);
+
+    for node in &nodes {
+        // Parse node capacity
+        if let Some(capacity) = &node.status.as_ref().and_then(|s| s.capacity.as_ref()) {
+            // CPU capacity
+            if let Some(cpu_quantity) = capacity.get("cpu") {
+                if let Ok(cpu_cores) = kuboard_parse_cpu(&cpu_quantity.0) {
+                    total_cpu_cores += cpu_cores;
+                }
+            }
+            
+            // Memory capacity
+            if let Some(memory_quantity) = capacity.get("memory") {
+                if let Ok(memory_bytes) = kuboard_parse_bytes(&memory_quantity.0) {
+                    total_memory_bytes += memory_bytes;
+                }
+            }
+            
+            // Disk capacity
+            if let Some(disk_quantity) = capacity.get("ephemeral-storage") {
+                if let Ok(disk_bytes) = kuboard_parse_bytes(&disk_quantity.0) {
+                    total_disk_bytes += disk_bytes;
+                }
+            }
+        }
+
+        // Get usage from metrics server if available
+        if metrics_available {
+            if let Some(node_name) = node.metadata.name.as_ref() {
+                match kuboard_fetch_node_metrics_real(client, node_name).await {
+                    Ok(metrics) => {
+                        total_cpu_usage += metrics.cpu_usage_cores;
+                        total_memory_usage += metrics.memory_usage_bytes;
+                        total_disk_usage += metrics.disk_usage_bytes;
+                    }
+                    Err(e) => {
+                        warn!("Failed to get metrics for node {}: {}", node_name, e);
+                    }
+                }
+            }
+        }
+    }
+
+    // If metrics server not available, calculate from pod requests/limits
+    if !metrics_available {
+        let pods_api: Api<Pod> = Api::all(client.clone());
+        if let Ok(pods) = pods_api.list(&Default::default()).await {
+            for pod in &pods.items {
+                if let Some(spec) = &pod.spec {
+                    for container in &spec.containers {
+                        // CPU requests
+                        if let Some(requests) = &container.resources.as_ref().and_then(|r| r.requests.as_ref()) {
+                            if let Some(cpu_quantity) = requests.get("cpu") {
+                                if let Ok(cpu_cores) = kuboard_parse_cpu(&cpu_quantity.0) {
+                                    total_cpu_usage += cpu_cores;
+                                }
+                            }
+                        }
+                        
+                        // Memory requests
+                        if let Some(requests) = &container.resources.as_ref().and_then(|r| r.requests.as_ref()) {
+                            if let Some(memory_quantity) = requests.get("memory") {
+                                if let Ok(memory_bytes) = kuboard_parse_bytes(&memory_quantity.0) {
+                                    total_memory_usage += memory_bytes;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // Calculate percentages
+    let cpu_usage_percent = if total_cpu_cores > 0.0 {
+        (total_cpu_usage / total_cpu_cores * 100.0).min(100.0)
+    } else {
+        0.0
+    };
+
+    let memory_usage_percent = if total_memory_bytes > 0 {
+        (total_memory_usage as f64 / total_memory_bytes as f64 * 100.0).min(100.0)
+    } else {
+        0.0
+    };
+
+    let disk_usage_percent = if total_disk_bytes > 0 {
+        (total_disk_usage as f64 / total_disk_bytes as f64 * 100.0).min(100.0)
+    } else {
+        0.0
+    };
+
+    let format = state.format_settings.read().await.clone();
+    Ok(ClusterMetricsSummary {
+        cpu: CpuCapacitySummary {
+            total_cores: total_cpu_cores,
+            used_cores: total_cpu_usage,
+            usage_percent: cpu_usage_percent,
+            total_formatted: kuboard_format_cpu_with(total_cpu_cores, &format),
+            used_formatted: kuboard_format_cpu_with(total_cpu_usage, &format),
+        },
+        memory: ByteCapacitySummary {
+            total_bytes: total_memory_bytes,
+            used_bytes: total_memory_usage,
+            usage_percent: memory_usage_percent,
+            total_formatted: kuboard_format_memory_with(total_memory_bytes, &format),
+            used_formatted: kuboard_format_memory_with(total_memory_usage, &format),
+        },
+        disk: ByteCapacitySummary {
+            total_bytes: total_disk_bytes,
+            used_bytes: total_disk_usage,
+            usage_percent: disk_usage_percent,
+            total_formatted: kuboard_format_memory_with(total_disk_bytes, &format),
+            used_formatted: kuboard_format_memory_with(total_disk_usage, &format),
+        },
+        nodes_count: nodes.len(),
+        metrics_available,
+    })
+}
+
+// Pod Actions Commands
+#[tauri::command]
+pub async fn kuboard_delete_pod(
+    pod_name: String,
+    namespace: String,
+    grace_period_seconds: Option<u32>,
+    force: Option<bool>,
+    operation_id: Option<String>,
+    dry_run: Option<bool>,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    let dry_run = dry_run.unwrap_or(false);
+    if !dry_run {
+        production_guard(&state, "delete_pod", &format!("{}/{}", namespace, pod_name)).await?;
+    }
+
+    let force = force.unwrap_or(false);
+    info!("Deleting pod: {}/{} (grace period: {:?}, force: {}, dry run: {})", namespace, pod_name, grace_period_seconds, force, dry_run);
+
+    // Same rule as kubectl: a forced delete skips graceful termination entirely
+    if force && grace_period_seconds.is_some_and(|seconds| seconds != 0) {
+        return Err("Force delete requires a grace period of 0".to_string());
+    }
+
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let pods_api: Api<Pod> = Api::namespaced(client.clone(), &namespace);
+
+    let mut delete_params = if force {
+        warn!("Force deleting pod {}/{}; the kubelet may still be running its containers", namespace, pod_name);
+        DeleteParams::background().grace_period(0)
+    } else {
+        match grace_period_seconds {
+            Some(seconds) => DeleteParams::default().grace_period(seconds),
+            None => DeleteParams::default(),
+        }
+    };
+    delete_params.dry_run = dry_run;
+
+    let undo_id = if dry_run { None } else { capture_for_undo(&state, client, "Pod", &pod_name, &namespace, operation_id).await };
+    match pods_api.delete(&pod_name, &delete_params).await {
+        Ok(_) if dry_run => Ok(format!("Pod {}/{} would be deleted (dry run)", namespace, pod_name)),
+        Ok(_) => {
+            info!("✅ Successfully deleted pod: {}/{}", namespace, pod_name);
+            Ok(format!("Pod {}/{} deleted successfully", namespace, pod_name))
+        }
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            // Treat 404 as successful deletion (already gone)
+            warn!("Pod {}/{} not found during delete - treating as already deleted", namespace, pod_name);
+            Ok(format!("Pod {}/{} not found (already deleted)", namespace, pod_name))
+        }
+        Err(e) => {
+            discard_undo(&state, undo_id).await;
+            error!("Failed to delete pod {}/{}: {}", namespace, pod_name, e);
+            Err(format!("Failed to delete pod: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_get_pod_delete_confirmation(
+    pod_name: String,
+    namespace: String,
+    state: State<'_, AppState>
+) -> Result<PodDeleteConfirmation, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let pods_api: Api<Pod> = Api::namespaced(client.clone(), &namespace);
+    let pod = match pods_api.get(&pod_name).await {
+        Ok(pod) => pod,
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            return Err(format!("Pod {}/{} not found", namespace, pod_name));
+        }
+        Err(e) => return Err(format!("Failed to get pod: {}", e)),
+    };
+
+    Ok(PodDeleteConfirmation {
+        name: pod_name,
+        namespace,
+        // The API server defaults terminationGracePeriodSeconds to 30
+        termination_grace_period_seconds: pod.spec.as_ref()
+            .and_then(|s| s.termination_grace_period_seconds)
+            .unwrap_or(30),
+        terminating: pod.metadata.deletion_timestamp.is_some(),
+        deletion_timestamp: pod.metadata.deletion_timestamp.map(|t| t.0.to_rfc3339()),
+        finalizers: pod.metadata.finalizers.unwrap_or_default(),
+    })
+}
+
+#[tauri::command]
+pub async fn kuboard_restart_pod(
+    pod_name: String,
+    namespace: String,
+    dry_run: Option<bool>,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Restarting pod: {}/{}", namespace, pod_name);
+    let dry_run = dry_run.unwrap_or(false);
+    if !dry_run {
+        production_guard(&state, "restart_pod", &format!("{}/{}", namespace, pod_name)).await?;
+    }
+    
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let pods_api: Api<Pod> = Api::namespaced(client.clone(), &namespace);
+    // Delete directly to trigger recreation by controller. If already gone, treat as success.
+    match pods_api.delete(&pod_name, &delete_params(dry_run)).await {
+        Ok(_) if dry_run => Ok(format!("Pod {}/{} would be restarted (dry run)", namespace, pod_name)),
+        Ok(_) => {
+            info!("✅ Successfully restarted (deleted for recreation) pod: {}/{}", namespace, pod_name);
+            Ok(format!("Pod {}/{} restarted (deleted for recreation)", namespace, pod_name))
+        }
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            warn!("Pod {}/{} not found during restart - treating as already restarted", namespace, pod_name);
+            Ok(format!("Pod {}/{} not found (already restarted)", namespace, pod_name))
+        }
+        Err(e) => {
+            error!("Failed to restart pod {}/{}: {}", namespace, pod_name, e);
+            Err(format!("Failed to restart pod: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_restart_statefulset_pod(
+    name: String,
+    namespace: String,
+    ordinal: u32,
+    dry_run: Option<bool>,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Restarting statefulset pod: {}/{}-{}", namespace, name, ordinal);
+    let dry_run = dry_run.unwrap_or(false);
+    if !dry_run {
+        production_guard(&state, "restart_statefulset_pod", &format!("{}/{}", namespace, name)).await?;
+    }
+
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let statefulsets_api: Api<StatefulSet> = Api::namespaced(client.clone(), &namespace);
+    let statefulset = match statefulsets_api.get(&name).await {
+        Ok(ss) => ss,
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            return Err(format!("StatefulSet {}/{} not found", namespace, name));
+        }
+        Err(e) => return Err(format!("Failed to get statefulset: {}", e)),
+    };
+
+    let replicas = statefulset.spec.as_ref().and_then(|s| s.replicas).unwrap_or(1);
+    // spec.ordinals.start shifts the ordinals to start..start+replicas
+    let start = statefulset.spec.as_ref().and_then(|s| s.ordinals.as_ref()).and_then(|o| o.start).unwrap_or(0);
+    let ordinals = i64::from(start)..i64::from(start) + i64::from(replicas);
+    if !ordinals.contains(&i64::from(ordinal)) {
+        return Err(format!(
+            "StatefulSet {}/{} has no ordinal {} (ordinals {} to {})",
+            namespace, name, ordinal, ordinals.start, ordinals.end - 1
+        ));
+    }
+
+    // StatefulSet pods have stable names, so the ordinal maps directly to a pod
+    let pod_name = format!("{}-{}", name, ordinal);
+    let pods_api: Api<Pod> = Api::namespaced(client.clone(), &namespace);
+    let pod = match pods_api.get(&pod_name).await {
+        Ok(pod) => pod,
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            return Err(format!("Pod {}/{} not found", namespace, pod_name));
+        }
+        Err(e) => return Err(format!("Failed to get pod: {}", e)),
+    };
+    if !kuboard_pod_controlled_by(&pod, "StatefulSet", &name) {
+        return Err(format!("Pod {}/{} is not managed by StatefulSet {}", namespace, pod_name, name));
+    }
+
+    match pods_api.delete(&pod_name, &delete_params(dry_run)).await {
+        Ok(_) if dry_run => Ok(format!("Pod {}/{} would be restarted (dry run)", namespace, pod_name)),
+        Ok(_) => {
+            info!("✅ Successfully restarted statefulset pod: {}/{}", namespace, pod_name);
+            Ok(format!("Pod {}/{} restarted (deleted for recreation)", namespace, pod_name))
+        }
+        Err(e) => {
+            error!("Failed to restart statefulset pod {}/{}: {}", namespace, pod_name, e);
+            Err(format!("Failed to restart pod: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_restart_daemonset_pod(
+    name: String,
+    namespace: String,
+    node_name: String,
+    dry_run: Option<bool>,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Restarting daemonset pod: {}/{} on node {}", namespace, name, node_name);
+    let dry_run = dry_run.unwrap_or(false);
+    if !dry_run {
+        production_guard(&state, "restart_daemonset_pod", &format!("{}/{}", namespace, name)).await?;
+    }
+
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let daemonsets_api: Api<DaemonSet> = Api::namespaced(client.clone(), &namespace);
+    let daemonset = match daemonsets_api.get(&name).await {
+        Ok(ds) => ds,
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            return Err(format!("DaemonSet {}/{} not found", namespace, name));
+        }
+        Err(e) => return Err(format!("Failed to get daemonset: {}", e)),
+    };
+
+    let selector = match daemonset.spec.as_ref() {
+        Some(spec) => &spec.selector,
+        None => return Err("DaemonSet has no spec".to_string()),
+    };
+
+    let pods = kuboard_list_pods_by_selector(client, &namespace, selector)
+        .await
+        .map_err(|e| format!("Failed to list pods: {}", e))?;
+
+    let pod_name = pods
+        .iter()
+        .filter(|pod| kuboard_pod_controlled_by(pod, "DaemonSet", &name))
+        .find(|pod| pod.spec.as_ref().and_then(|s| s.node_name.as_deref()) == Some(node_name.as_str()))
+        .and_then(|pod| pod.metadata.name.clone())
+        .ok_or_else(|| format!("DaemonSet {}/{} has no pod on node {}", namespace, name, node_name))?;
+
+    let pods_api: Api<Pod> = Api::namespaced(client.clone(), &namespace);
+    match pods_api.delete(&pod_name, &delete_params(dry_run)).await {
+        Ok(_) if dry_run => Ok(format!("Pod {}/{} would be restarted (dry run)", namespace, pod_name)),
+        Ok(_) => {
+            info!("✅ Successfully restarted daemonset pod: {}/{} on node {}", namespace, pod_name, node_name);
+            Ok(format!("Pod {}/{} restarted (deleted for recreation)", namespace, pod_name))
+        }
+        Err(e) => {
+            error!("Failed to restart daemonset pod {}/{}: {}", namespace, pod_name, e);
+            Err(format!("Failed to restart pod: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_suspend_workload(
+    kind: String,
+    name: String,
+    namespace: String,
+    state: State<'_, AppState>
+) -> Result<WorkloadSuspendState, String> {
+    production_guard(&state, "suspend_workload", &format!("{}/{}", namespace, name)).await?;
+
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    match kuboard_suspend_workload_replicas(client, &kind, &namespace, &name).await {
+        Ok(suspended) => {
+            info!("✅ Suspended {} {}/{} (was {} replicas)", kind, namespace, name, suspended.suspended_replicas.unwrap_or(0));
+            Ok(suspended)
+        }
+        Err(e) => Err(mutation_error(e, &format!("Failed to suspend {} {}/{}", kind, namespace, name))),
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_resume_workload(
+    kind: String,
+    name: String,
+    namespace: String,
+    state: State<'_, AppState>
+) -> Result<WorkloadSuspendState, String> {
+    production_guard(&state, "resume_workload", &format!("{}/{}", namespace, name)).await?;
+
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    match kuboard_resume_workload_replicas(client, &kind, &namespace, &name).await {
+        Ok(resumed) => {
+            info!("✅ Resumed {} {}/{} to {} replicas", kind, namespace, name, resumed.replicas);
+            Ok(resumed)
+        }
+        Err(e) => Err(mutation_error(e, &format!("Failed to resume {} {}/{}", kind, namespace, name))),
+    }
+}
+
+// Undo Commands
+// Keeps the object for kuboard_undo_delete; a failed capture never blocks the delete itself
+async fn capture_for_undo(
+    state: &AppState,
+    client: &Client,
+    kind: &str,
+    name: &str,
+    namespace: &str,
+    operation_id: Option<String>,
+) -> Option<String> {
+    let context = state.current_context.read().await.clone()?;
+    match kuboard_capture_deleted_object(client, &context, kind, name, namespace, operation_id).await {
+        Ok(Some(entry)) => {
+            let operation_id = entry.operation_id.clone();
+            state.trash.write().await.push(entry);
+            Some(operation_id)
+        }
+        Ok(None) => {
+            debug!("{} {}/{} is recreated by its controller, not keeping it for undo", kind, namespace, name);
+            None
+        }
+        Err(e) => {
+            warn!("Could not keep {} {}/{} for undo: {}", kind, namespace, name, e);
+            None
+        }
+    }
+}
+
+// The object is still there, so there is nothing to undo
+async fn discard_undo(state: &AppState, operation_id: Option<String>) {
+    if let Some(operation_id) = operation_id {
+        state.trash.write().await.remove(&operation_id);
+    }
+}
+
+// Deleted objects of the current context that can still be restored, newest first
+#[tauri::command]
+pub async fn kuboard_list_trash(state: State<'_, AppState>) -> Result<Vec<TrashEntry>, String> {
+    let context = state.current_context.read().await.clone()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+    Ok(state.trash.write().await.list(&context))
+}
+
+#[tauri::command]
+pub async fn kuboard_undo_delete(operation_id: String, state: State<'_, AppState>) -> Result<String, String> {
+    production_guard(&state, "undo_delete", &operation_id).await?;
+
+    let entry = state.trash.write().await.get(&operation_id)
+        .ok_or_else(|| format!("Nothing to undo for operation {}; it may have expired", operation_id))?;
+    let context = state.current_context.read().await.clone();
+    if context.as_deref() != Some(entry.context.as_str()) {
+        return Err(format!("{} {}/{} was deleted in context {}; switch to it to undo", entry.kind, entry.namespace, entry.name, entry.context));
+    }
+
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    match kuboard_restore_trash_entry(client, &entry).await {
+        Ok(()) => {
+            state.trash.write().await.remove(&operation_id);
+            info!("✅ Restored {} {}/{}", entry.kind, entry.namespace, entry.name);
+            Ok(format!("{} {}/{} restored", entry.kind, entry.namespace, entry.name))
+        }
+        Err(e) => {
+            error!("Failed to restore {} {}/{}: {}", entry.kind, entry.namespace, entry.name, e);
+            Err(format!("Failed to undo delete: {}", e))
+        }
+    }
+}
+
+// Dynamic Resource Commands
+// Any kind the cluster serves, custom resources included, for views without typed support
+#[tauri::command]
+pub async fn kuboard_get_resource(
+    group: String,
+    version: String,
+    kind: String,
+    name: String,
+    namespace: Option<String>,
+    state: State<'_, AppState>
+) -> Result<DynamicObject, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let dynamic_kind = kuboard_resolve_gvk(client, &group, &version, &kind).await.map_err(|e| e.to_string())?;
+    let api = dynamic_kind.object_api(client, namespace.as_deref()).map_err(|e| e.to_string())?;
+    match api.get(&name).await {
+        Ok(object) => Ok(object),
+        Err(kube::Error::Api(e)) if e.code == 404 => match namespace.as_deref().filter(|_| dynamic_kind.namespaced) {
+            Some(namespace) => Err(format!("{} {}/{} not found", kind, namespace, name)),
+            None => Err(format!("{} {} not found", kind, name)),
+        },
+        Err(e) => Err(format!("Failed to get {}: {}", kind, e)),
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_list_resources(
+    group: String,
+    version: String,
+    kind: String,
+    namespace: Option<String>,
+    label_selector: Option<String>,
+    field_selector: Option<String>,
+    state: State<'_, AppState>
+) -> Result<Vec<DynamicObject>, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let dynamic_kind = kuboard_resolve_gvk(client, &group, &version, &kind).await.map_err(|e| e.to_string())?;
+    let filter = current_namespace_filter(&state).await;
+    dynamic_kind.list(client, &filter, namespace.as_deref(), &selector_params(label_selector.as_deref(), field_selector.as_deref())).await
+        .map_err(|e| format!("Failed to list {}: {}", dynamic_kind.resource.plural, e))
+}
+
+// kuboard_list_resources one page at a time, as kuboard_get_pods_page
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn kuboard_list_resources_page(
+    group: String,
+    version: String,
+    kind: String,
+    namespace: Option<String>,
+    label_selector: Option<String>,
+    field_selector: Option<String>,
+    limit: Option<u32>,
+    continue_token: Option<String>,
+    state: State<'_, AppState>
+) -> Result<ListPage<DynamicObject>, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let dynamic_kind = kuboard_resolve_gvk(client, &group, &version, &kind).await.map_err(|e| e.to_string())?;
+    let filter = current_namespace_filter(&state).await;
+    let params = selector_params(label_selector.as_deref(), field_selector.as_deref());
+    dynamic_kind.list_page(client, &filter, namespace.as_deref(), &params, limit, continue_token.as_deref()).await
+        .map_err(|e| format!("Failed to list {}: {}", dynamic_kind.resource.plural, e))
+}
+
+// The objects as rows of the columns `kubectl get` shows for the kind
+#[tauri::command]
+pub async fn kuboard_list_resource_table(
+    group: String,
+    version: String,
+    kind: String,
+    namespace: Option<String>,
+    label_selector: Option<String>,
+    field_selector: Option<String>,
+    state: State<'_, AppState>
+) -> Result<ResourceTable, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let dynamic_kind = kuboard_resolve_gvk(client, &group, &version, &kind).await.map_err(|e| e.to_string())?;
+    let filter = current_namespace_filter(&state).await;
+    let objects = dynamic_kind.list(client, &filter, namespace.as_deref(), &selector_params(label_selector.as_deref(), field_selector.as_deref())).await
+        .map_err(|e| format!("Failed to list {}: {}", dynamic_kind.resource.plural, e))?;
+    let crd = kuboard_fetch_crd(client, &dynamic_kind.resource).await;
+    let columns = kuboard_printer_columns(crd.as_ref(), &dynamic_kind.resource.version);
+    kuboard_resource_table(&dynamic_kind.resource.kind, columns, &objects)
+        .map_err(|e| format!("Failed to build {} table: {}", kind, e))
+}
+
+// Delete Commands for All Resource Types
+// Any kind the cluster serves, custom resources included, resolved through discovery. `propagation`
+// is Background, Foreground or Orphan; without it the kind's default applies. `namespace` is
+// ignored for cluster-scoped kinds
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn kuboard_delete_resource(
+    api_version: String,
+    kind: String,
+    name: String,
+    namespace: Option<String>,
+    propagation: Option<String>,
+    operation_id: Option<String>,
+    dry_run: Option<bool>,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    let dry_run = dry_run.unwrap_or(false);
+    let propagation_policy = propagation.as_deref()
+        .map(kuboard_propagation_policy)
+        .transpose()
+        .map_err(|e| e.to_string())?;
+
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let dynamic_kind = kuboard_resolve_kind(client, &api_version, &kind).await.map_err(|e| e.to_string())?;
+    let api = dynamic_kind.object_api(client, namespace.as_deref()).map_err(|e| e.to_string())?;
+    let target = match namespace.as_deref().filter(|_| dynamic_kind.namespaced) {
+        Some(namespace) => format!("{}/{}", namespace, name),
+        None => name.clone(),
+    };
+    info!("Deleting {} {}", kind, target);
+    if !dry_run {
+        production_guard(&state, &format!("delete_{}", kind.to_lowercase()), &target).await?;
+    }
+
+    let object = match api.get(&name).await {
+        Ok(object) => object,
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            warn!("{} {} not found during delete - treating as already deleted", kind, target);
+            return Ok(format!("{} {} not found (already deleted)", kind, target));
+        }
+        Err(e) => return Err(format!("Failed to get {} {}: {}", kind, target, e)),
+    };
+    // Kept for kuboard_undo_delete; a failed capture never blocks the delete itself
+    let context = state.current_context.read().await.clone();
+    let undo_id = match context {
+        Some(context) if !dry_run => match kuboard_trash_entry(&context, &object, operation_id) {
+            Ok(Some(entry)) => {
+                let operation_id = entry.operation_id.clone();
+                state.trash.write().await.push(entry);
+                Some(operation_id)
+            }
+            Ok(None) => None,
+            Err(e) => {
+                warn!("Could not keep {} {} for undo: {}", kind, target, e);
+                None
+            }
+        },
+        _ => None,
+    };
+
+    let params = DeleteParams { dry_run, propagation_policy, ..Default::default() };
+    match api.delete(&name, &params).await {
+        Ok(_) if dry_run => Ok(format!("{} {} would be deleted (dry run)", kind, target)),
+        Ok(_) => {
+            info!("✅ Successfully deleted {} {}", kind, target);
+            Ok(format!("{} {} deleted successfully", kind, target))
+        }
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            warn!("{} {} not found during delete - treating as already deleted", kind, target);
+            Ok(format!("{} {} not found (already deleted)", kind, target))
+        }
+        Err(e) => {
+            discard_undo(&state, undo_id).await;
+            error!("Failed to delete {} {}: {}", kind, target, e);
+            Err(format!("Failed to delete {}: {}", kind, e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_delete_deployment(
+    name: String,
+    namespace: String,
+    operation_id: Option<String>,
+    dry_run: Option<bool>,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Deleting deployment: {}/{}", namespace, name);
+    let dry_run = dry_run.unwrap_or(false);
+    if !dry_run {
+        production_guard(&state, "delete_deployment", &format!("{}/{}", namespace, name)).await?;
+    }
+    
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let deployments_api: Api<Deployment> = Api::namespaced(client.clone(), &namespace);
+    
+    let undo_id = if dry_run { None } else { capture_for_undo(&state, client, "Deployment", &name, &namespace, operation_id).await };
+    match deployments_api.delete(&name, &delete_params(dry_run)).await {
+        Ok(_) if dry_run => Ok(format!("Deployment {}/{} would be deleted (dry run)", namespace, name)),
+        Ok(_) => {
+            info!("✅ Successfully deleted deployment: {}/{}", namespace, name);
+            Ok(format!("Deployment {}/{} deleted successfully", namespace, name))
+        }
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            warn!("Deployment {}/{} not found during delete - treating as already deleted", namespace, name);
+            Ok(format!("Deployment {}/{} not found (already deleted)", namespace, name))
+        }
+        Err(e) => {
+            discard_undo(&state, undo_id).await;
+            error!("Failed to delete deployment {}/{}: {}", namespace, name, e);
+            Err(format!("Failed to delete deployment: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_delete_statefulset(
+    name: String,
+    namespace: String,
+    operation_id: Option<String>,
+    dry_run: Option<bool>,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Deleting statefulset: {}/{}", namespace, name);
+    let dry_run = dry_run.unwrap_or(false);
+    if !dry_run {
+        production_guard(&state, "delete_statefulset", &format!("{}/{}", namespace, name)).await?;
+    }
+    
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let statefulsets_api: Api<StatefulSet> = Api::namespaced(client.clone(), &namespace);
+    
+    let undo_id = if dry_run { None } else { capture_for_undo(&state, client, "StatefulSet", &name, &namespace, operation_id).await };
+    match statefulsets_api.delete(&name, &delete_params(dry_run)).await {
+        Ok(_) if dry_run => Ok(format!("StatefulSet {}/{} would be deleted (dry run)", namespace, name)),
+        Ok(_) => {
+            info!("✅ Successfully deleted statefulset: {}/{}", namespace, name);
+            Ok(format!("StatefulSet {}/{} deleted successfully", namespace, name))
+        }
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            warn!("StatefulSet {}/{} not found during delete - treating as already deleted", namespace, name);
+            Ok(format!("StatefulSet {}/{} not found (already deleted)", namespace, name))
+        }
+        Err(e) => {
+            discard_undo(&state, undo_id).await;
+            error!("Failed to delete statefulset {}/{}: {}", namespace, name, e);
+            Err(format!("Failed to delete statefulset: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_delete_daemonset(
+    name: String,
+    namespace: String,
+    operation_id: Option<String>,
+    dry_run: Option<bool>,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Deleting daemonset: {}/{}", namespace, name);
+    let dry_run = dry_run.unwrap_or(false);
+    if !dry_run {
+        production_guard(&state, "delete_daemonset", &format!("{}/{}", namespace, name)).await?;
+    }
+    
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let daemonsets_api: Api<DaemonSet> = Api::namespaced(client.clone(), &namespace);
+    
+    let undo_id = if dry_run { None } else { capture_for_undo(&state, client, "DaemonSet", &name, &namespace, operation_id).await };
+    match daemonsets_api.delete(&name, &delete_params(dry_run)).await {
+        Ok(_) if dry_run => Ok(format!("DaemonSet {}/{} would be deleted (dry run)", namespace, name)),
+        Ok(_) => {
+            info!("✅ Successfully deleted daemonset: {}/{}", namespace, name);
+            Ok(format!("DaemonSet {}/{} deleted successfully", namespace, name))
+        }
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            warn!("DaemonSet {}/{} not found during delete - treating as already deleted", namespace, name);
+            Ok(format!("DaemonSet {}/{} not found (already deleted)", namespace, name))
+        }
+        Err(e) => {
+            discard_undo(&state, undo_id).await;
+            error!("Failed to delete daemonset {}/{}: {}", namespace, name, e);
+            Err(format!("Failed to delete daemonset: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_delete_replicaset(
+    name: String,
+    namespace: String,
+    operation_id: Option<String>,
+    dry_run: Option<bool>,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Deleting replicaset: {}/{}", namespace, name);
+    let dry_run = dry_run.unwrap_or(false);
+    if !dry_run {
+        production_guard(&state, "delete_replicaset", &format!("{}/{}", namespace, name)).await?;
+    }
+    
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let replicasets_api: Api<ReplicaSet> = Api::namespaced(client.clone(), &namespace);
+    
+    let undo_id = if dry_run { None } else { capture_for_undo(&state, client, "ReplicaSet", &name, &namespace, operation_id).await };
+    match replicasets_api.delete(&name, &delete_params(dry_run)).await {
+        Ok(_) if dry_run => Ok(format!("ReplicaSet {}/{} would be deleted (dry run)", namespace, name)),
+        Ok(_) => {
+            info!("✅ Successfully deleted replicaset: {}/{}", namespace, name);
+            Ok(format!("ReplicaSet {}/{} deleted successfully", namespace, name))
+        }
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            warn!("ReplicaSet {}/{} not found during delete - treating as already deleted", namespace, name);
+            Ok(format!("ReplicaSet {}/{} not found (already deleted)", namespace, name))
+        }
+        Err(e) => {
+            discard_undo(&state, undo_id).await;
+            error!("Failed to delete replicaset {}/{}: {}", namespace, name, e);
+            Err(format!("Failed to delete replicaset: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_delete_service(
+    name: String,
+    namespace: String,
+    operation_id: Option<String>,
+    dry_run: Option<bool>,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Deleting service: {}/{}", namespace, name);
+    let dry_run = dry_run.unwrap_or(false);
+    if !dry_run {
+        production_guard(&state, "delete_service", &format!("{}/{}", namespace, name)).await?;
+    }
+    
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let services_api: Api<Service> = Api::namespaced(client.clone(), &namespace);
+    
+    let undo_id = if dry_run { None } else { capture_for_undo(&state, client, "Service", &name, &namespace, operation_id).await };
+    match services_api.delete(&name, &delete_params(dry_run)).await {
+        Ok(_) if dry_run => Ok(format!("Service {}/{} would be deleted (dry run)", namespace, name)),
+        Ok(_) => {
+            info!("✅ Successfully deleted service: {}/{}", namespace, name);
+            Ok(format!("Service {}/{} deleted successfully", namespace, name))
+        }
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            warn!("Service {}/{} not found during delete - treating as already deleted", namespace, name);
+            Ok(format!("Service {}/{} not found (already deleted)", namespace, name))
+        }
+        Err(e) => {
+            discard_undo(&state, undo_id).await;
+            error!("Failed to delete service {}/{}: {}", namespace, name, e);
+            Err(format!("Failed to delete service: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_delete_cronjob(
+    name: String,
+    namespace: String,
+    operation_id: Option<String>,
+    dry_run: Option<bool>,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Deleting cronjob: {}/{}", namespace, name);
+    let dry_run = dry_run.unwrap_or(false);
+    if !dry_run {
+        production_guard(&state, "delete_cronjob", &format!("{}/{}", namespace, name)).await?;
+    }
+    
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let cronjobs_api: Api<CronJob> = Api::namespaced(client.clone(), &namespace);
+    
+    let undo_id = if dry_run { None } else { capture_for_undo(&state, client, "CronJob", &name, &namespace, operation_id).await };
+    match cronjobs_api.delete(&name, &delete_params(dry_run)).await {
+        Ok(_) if dry_run => Ok(format!("CronJob {}/{} would be deleted (dry run)", namespace, name)),
+        Ok(_) => {
+            info!("✅ Successfully deleted cronjob: {}/{}", namespace, name);
+            Ok(format!("CronJob {}/{} deleted successfully", namespace, name))
+        }
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            warn!("CronJob {}/{} not found during delete - treating as already deleted", namespace, name);
+            Ok(format!("CronJob {}/{} not found (already deleted)", namespace, name))
+        }
+        Err(e) => {
+            discard_undo(&state, undo_id).await;
+            error!("Failed to delete cronjob {}/{}: {}", namespace, name, e);
+            Err(format!("Failed to delete cronjob: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_get_pod_yaml(
+    pod_name: String,
+    namespace: String,
+    format: Option<ManifestFormat>,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Getting YAML for pod: {}/{}", namespace, pod_name);
+    
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let pods_api: Api<Pod> = Api::namespaced(client.clone(), &namespace);
+    
+    match pods_api.get(&pod_name).await {
+        Ok(pod) => {
+            match kuboard_render_manifest(&pod, format.unwrap_or_default()) {
+                Ok(manifest) => {
+                    info!("✅ Successfully retrieved pod data: {}/{}", namespace, pod_name);
+                    Ok(manifest)
+                }
+                Err(e) => {
+                    error!("Failed to serialize pod: {}", e);
+                    Err(format!("Failed to serialize pod: {}", e))
+                }
+            }
+        }
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            Err(format!("Pod {}/{} not found", namespace, pod_name))
+        }
+        Err(e) => {
+            error!("Failed to get pod {}/{}: {}", namespace, pod_name, e);
+            Err(format!("Failed to get pod: {}", e))
+        }
+    }
+}
+
+// YAML Get Commands for All Resource Types
+#[tauri::command]
+pub async fn kuboard_get_deployment_yaml(
+    name: String,
+    namespace: String,
+    format: Option<ManifestFormat>,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let deployments_api: Api<Deployment> = Api::namespaced(client.clone(), &namespace);
+    
+    match deployments_api.get(&name).await {
+        Ok(deployment) => {
+            match kuboard_render_manifest(&deployment, format.unwrap_or_default()) {
+                Ok(manifest) => Ok(manifest),
+                Err(e) => Err(format!("Failed to serialize deployment: {}", e))
+            }
+        }
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            Err(format!("Deployment {}/{} not found", namespace, name))
+        }
+        Err(e) => Err(format!("Failed to get deployment: {}", e))
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_get_statefulset_yaml(
+    name: String,
+    namespace: String,
+    format: Option<ManifestFormat>,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let statefulsets_api: Api<StatefulSet> = Api::namespaced(client.clone(), &namespace);
+    
+    match statefulsets_api.get(&name).await {
+        Ok(statefulset) => {
+            match kuboard_render_manifest(&statefulset, format.unwrap_or_default()) {
+                Ok(manifest) => Ok(manifest),
+                Err(e) => Err(format!("Failed to serialize statefulset: {}", e))
+            }
+        }
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            Err(format!("StatefulSet {}/{} not found", namespace, name))
+        }
+        Err(e) => Err(format!("Failed to get statefulset: {}", e))
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_get_daemonset_yaml(
+    name: String,
+    namespace: String,
+    format: Option<ManifestFormat>,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let daemonsets_api: Api<DaemonSet> = Api::namespaced(client.clone(), &namespace);
+    
+    match daemonsets_api.get(&name).await {
+        Ok(daemonset) => {
+            match kuboard_render_manifest(&daemonset, format.unwrap_or_default()) {
+                Ok(manifest) => Ok(manifest),
+                Err(e) => Err(format!("Failed to serialize daemonset: {}", e))
+            }
+        }
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            Err(format!("DaemonSet {}/{} not found", namespace, name))
+        }
+        Err(e) => Err(format!("Failed to get daemonset: {}", e))
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_get_replicaset_yaml(
+    name: String,
+    namespace: String,
+    format: Option<ManifestFormat>,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let replicasets_api: Api<ReplicaSet> = Api::namespaced(client.clone(), &namespace);
+    
+    match replicasets_api.get(&name).await {
+        Ok(replicaset) => {
+            match kuboard_render_manifest(&replicaset, format.unwrap_or_default()) {
+                Ok(manifest) => Ok(manifest),
+                Err(e) => Err(format!("Failed to serialize replicaset: {}", e))
+            }
+        }
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            Err(format!("ReplicaSet {}/{} not found", namespace, name))
+        }
+        Err(e) => Err(format!("Failed to get replicaset: {}", e))
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_get_service_yaml(
+    name: String,
+    namespace: String,
+    format: Option<ManifestFormat>,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let services_api: Api<Service> = Api::namespaced(client.clone(), &namespace);
+    
+    match services_api.get(&name).await {
+        Ok(service) => {
+            match kuboard_render_manifest(&service, format.unwrap_or_default()) {
+                Ok(manifest) => Ok(manifest),
+                Err(e) => Err(format!("Failed to serialize service: {}", e))
+            }
+        }
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            Err(format!("Service {}/{} not found", namespace, name))
+        }
+        Err(e) => Err(format!("Failed to get service: {}", e))
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_get_cronjob_yaml(
+    name: String,
+    namespace: String,
+    format: Option<ManifestFormat>,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let cronjobs_api: Api<CronJob> = Api::namespaced(client.clone(), &namespace);
+    
+    match cronjobs_api.get(&name).await {
+        Ok(cronjob) => {
+            match kuboard_render_manifest(&cronjob, format.unwrap_or_default()) {
+                Ok(manifest) => Ok(manifest),
+                Err(e) => Err(format!("Failed to serialize cronjob: {}", e))
+            }
+        }
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            Err(format!("CronJob {}/{} not found", namespace, name))
+        }
+        Err(e) => Err(format!("Failed to get cronjob: {}", e))
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_update_pod_from_yaml(
+    pod_name: String,
+    namespace: String,
+    yaml_content: String,
+    force: Option<bool>,
+    dry_run: Option<bool>,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Updating pod from YAML: {}/{}", namespace, pod_name);
+    let dry_run = dry_run.unwrap_or(false);
+    if !dry_run {
+        production_guard(&state, "update_pod_from_yaml", &format!("{}/{}", namespace, pod_name)).await?;
+    }
+    
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let pods_api: Api<Pod> = Api::namespaced(client.clone(), &namespace);
+    
+    let mut updated_pod: Pod = match kuboard_parse_manifest(&yaml_content) {
+        Ok(pod) => pod,
+        Err(e) => {
+            error!("Failed to parse YAML/JSON: {}", e);
             return Err(format!("Invalid YAML/JSON format: {}", e));
         }
     };
-    
-    // Verify the pod name matches (metadata.name is Option<String>)
-    match &updated_pod.metadata.name {
-        Some(name) if name != &pod_name => {
-            return Err(format!("Pod name mismatch: expected {}, got {}", 
-                pod_name, name));
+    
+    // Verify the pod name matches (metadata.name is Option<String>)
+    match &updated_pod.metadata.name {
+        Some(name) if name != &pod_name => {
+            return Err(format!("Pod name mismatch: expected {}, got {}", 
+                pod_name, name));
+        }
+        None => {
+            // If name is None, set it to the expected name
+            updated_pod.metadata.name = Some(pod_name.clone());
+        }
+        _ => {} // Name matches or will be set
+    }
+    kuboard_prepare_for_apply(&mut updated_pod.metadata);
+
+    // Server-side apply only claims the fields in the edit, so controller-owned fields survive; it would
+    // also create a missing pod, which an edit must not
+    if pods_api.get_opt(&pod_name).await.map_err(|e| format!("Failed to get pod: {}", e))?.is_none() {
+        return Err(format!("Pod {}/{} not found", namespace, pod_name));
+    }
+    match pods_api.patch(&pod_name, &kuboard_apply_params(force.unwrap_or(false), dry_run), &Patch::Apply(&updated_pod)).await {
+        Ok(_) if dry_run => Ok(format!("Pod {}/{} would be updated (dry run)", namespace, pod_name)),
+        Ok(_) => {
+            info!("✅ Successfully updated pod: {}/{}", namespace, pod_name);
+            Ok(format!("Pod {}/{} updated successfully", namespace, pod_name))
+        }
+        Err(kube::Error::Api(e)) if e.code == 409 => {
+            warn!("Field conflict updating pod {}/{}: {}", namespace, pod_name, e.message);
+            Err(format!("{}; retry with force to take ownership of these fields", e.message))
+        }
+        Err(e) => {
+            error!("Failed to update pod {}/{}: {}", namespace, pod_name, e);
+            Err(format!("Failed to update pod: {}", e))
+        }
+    }
+}
+
+// Saves an edited manifest of any kind (Deployment, Service, ConfigMap, a custom resource, ...) with
+// server-side apply. A stale edit fails with a JSON ConflictReport; `force` applies over the latest
+// version instead.
+// `namespace` is ignored for cluster-scoped kinds
+#[tauri::command]
+pub async fn kuboard_update_resource_from_yaml(
+    kind: String,
+    name: String,
+    namespace: String,
+    yaml_content: String,
+    force: Option<bool>,
+    dry_run: Option<bool>,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Updating {} from YAML: {}/{}", kind, namespace, name);
+    let dry_run = dry_run.unwrap_or(false);
+    if !dry_run {
+        production_guard(&state, "update_resource_from_yaml", &format!("{}/{}/{}", kind, namespace, name)).await?;
+    }
+
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let manifest: serde_json::Value = kuboard_parse_manifest(&yaml_content)
+        .map_err(|e| format!("Invalid YAML/JSON format: {}", e))?;
+    match kuboard_apply_edit(client, manifest, &kind, &name, &namespace, force.unwrap_or(false), dry_run).await {
+        Ok(_) if dry_run => Ok(format!("{} {}/{} would be updated (dry run)", kind, namespace, name)),
+        Ok(_) => {
+            info!("✅ Successfully updated {}: {}/{}", kind, namespace, name);
+            Ok(format!("{} {}/{} updated successfully", kind, namespace, name))
+        }
+        Err(e) => {
+            error!("Failed to update {} {}/{}: {}", kind, namespace, name, e);
+            Err(mutation_error(e, &format!("Failed to update {}", kind)))
+        }
+    }
+}
+
+// Pod Watch Commands
+#[tauri::command]
+pub async fn kuboard_start_pod_watch(
+    app: tauri::AppHandle,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Starting pod watch");
+
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?
+        .clone();
+    drop(client_guard);
+
+    let filter = current_namespace_filter(&state).await;
+    let mut watcher_guard = state.pod_watcher.write().await;
+    
+    match watcher_guard.start(client, app, filter).await {
+        Ok(_) => {
+            info!("✅ Pod watch started successfully");
+            Ok("Pod watch started".to_string())
+        }
+        Err(e) => {
+            error!("Failed to start pod watch: {}", e);
+            Err(format!("Failed to start pod watch: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_stop_pod_watch(
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Stopping pod watch");
+
+    let mut watcher_guard = state.pod_watcher.write().await;
+    watcher_guard.stop();
+    
+    info!("✅ Pod watch stopped");
+    Ok("Pod watch stopped".to_string())
+}
+
+// Deployment Watch Commands
+#[tauri::command]
+pub async fn kuboard_start_deployment_watch(
+    app: tauri::AppHandle,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Starting deployment watch");
+
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?
+        .clone();
+    drop(client_guard);
+
+    let filter = current_namespace_filter(&state).await;
+    let mut watcher_guard = state.deployment_watcher.write().await;
+    
+    match watcher_guard.start(client, app, filter).await {
+        Ok(_) => {
+            info!("✅ Deployment watch started successfully");
+            Ok("Deployment watch started".to_string())
+        }
+        Err(e) => {
+            error!("Failed to start deployment watch: {}", e);
+            Err(format!("Failed to start deployment watch: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_stop_deployment_watch(
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Stopping deployment watch");
+
+    let mut watcher_guard = state.deployment_watcher.write().await;
+    watcher_guard.stop();
+    
+    info!("✅ Deployment watch stopped");
+    Ok("Deployment watch stopped".to_string())
+}
+
+// StatefulSet Watch Commands
+#[tauri::command]
+pub async fn kuboard_start_statefulset_watch(
+    app: tauri::AppHandle,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Starting statefulset watch");
+
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?
+        .clone();
+    drop(client_guard);
+
+    let filter = current_namespace_filter(&state).await;
+    let mut watcher_guard = state.statefulset_watcher.write().await;
+    
+    match watcher_guard.start(client, app, filter).await {
+        Ok(_) => {
+            info!("✅ StatefulSet watch started successfully");
+            Ok("StatefulSet watch started".to_string())
+        }
+        Err(e) => {
+            error!("Failed to start statefulset watch: {}", e);
+            Err(format!("Failed to start statefulset watch: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_stop_statefulset_watch(
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Stopping statefulset watch");
+
+    let mut watcher_guard = state.statefulset_watcher.write().await;
+    watcher_guard.stop();
+    
+    info!("✅ StatefulSet watch stopped");
+    Ok("StatefulSet watch stopped".to_string())
+}
+
+// DaemonSet Watch Commands
+#[tauri::command]
+pub async fn kuboard_start_daemonset_watch(
+    app: tauri::AppHandle,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Starting daemonset watch");
+
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?
+        .clone();
+    drop(client_guard);
+
+    let filter = current_namespace_filter(&state).await;
+    let mut watcher_guard = state.daemonset_watcher.write().await;
+    
+    match watcher_guard.start(client, app, filter).await {
+        Ok(_) => {
+            info!("✅ DaemonSet watch started successfully");
+            Ok("DaemonSet watch started".to_string())
+        }
+        Err(e) => {
+            error!("Failed to start daemonset watch: {}", e);
+            Err(format!("Failed to start daemonset watch: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_stop_daemonset_watch(
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Stopping daemonset watch");
+
+    let mut watcher_guard = state.daemonset_watcher.write().await;
+    watcher_guard.stop();
+    
+    info!("✅ DaemonSet watch stopped");
+    Ok("DaemonSet watch stopped".to_string())
+}
+
+// ReplicaSet Watch Commands
+#[tauri::command]
+pub async fn kuboard_start_replicaset_watch(
+    app: tauri::AppHandle,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Starting replicaset watch");
+
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?
+        .clone();
+    drop(client_guard);
+
+    let filter = current_namespace_filter(&state).await;
+    let mut watcher_guard = state.replicaset_watcher.write().await;
+    
+    match watcher_guard.start(client, app, filter).await {
+        Ok(_) => {
+            info!("✅ ReplicaSet watch started successfully");
+            Ok("ReplicaSet watch started".to_string())
+        }
+        Err(e) => {
+            error!("Failed to start replicaset watch: {}", e);
+            Err(format!("Failed to start replicaset watch: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_stop_replicaset_watch(
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Stopping replicaset watch");
+
+    let mut watcher_guard = state.replicaset_watcher.write().await;
+    watcher_guard.stop();
+    
+    info!("✅ ReplicaSet watch stopped");
+    Ok("ReplicaSet watch stopped".to_string())
+}
+
+// Service Watch Commands
+#[tauri::command]
+pub async fn kuboard_start_service_watch(
+    app: tauri::AppHandle,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Starting service watch");
+
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?
+        .clone();
+    drop(client_guard);
+
+    let filter = current_namespace_filter(&state).await;
+    let mut watcher_guard = state.service_watcher.write().await;
+    
+    match watcher_guard.start(client, app, filter).await {
+        Ok(_) => {
+            info!("✅ Service watch started successfully");
+            Ok("Service watch started".to_string())
+        }
+        Err(e) => {
+            error!("Failed to start service watch: {}", e);
+            Err(format!("Failed to start service watch: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_stop_service_watch(
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Stopping service watch");
+
+    let mut watcher_guard = state.service_watcher.write().await;
+    watcher_guard.stop();
+    
+    info!("✅ Service watch stopped");
+    Ok("Service watch stopped".to_string())
+}
+
+// CronJob Watch Commands
+#[tauri::command]
+pub async fn kuboard_start_cronjob_watch(
+    app: tauri::AppHandle,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Starting cronjob watch");
+
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?
+        .clone();
+    drop(client_guard);
+
+    let filter = current_namespace_filter(&state).await;
+    let mut watcher_guard = state.cronjob_watcher.write().await;
+    
+    match watcher_guard.start(client, app, filter).await {
+        Ok(_) => {
+            info!("✅ CronJob watch started successfully");
+            Ok("CronJob watch started".to_string())
+        }
+        Err(e) => {
+            error!("Failed to start cronjob watch: {}", e);
+            Err(format!("Failed to start cronjob watch: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_stop_cronjob_watch(
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Stopping cronjob watch");
+
+    let mut watcher_guard = state.cronjob_watcher.write().await;
+    watcher_guard.stop();
+    
+    info!("✅ CronJob watch stopped");
+    Ok("CronJob watch stopped".to_string())
+}
+
+// Emits job-progress-event with a JobProgress for every Job change
+#[tauri::command]
+pub async fn kuboard_start_job_progress_watch(
+    app: tauri::AppHandle,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Starting job progress watch");
+
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?
+        .clone();
+    drop(client_guard);
+
+    let filter = current_namespace_filter(&state).await;
+    let mut watcher_guard = state.job_progress_watcher.write().await;
+
+    match watcher_guard.start(client, app, filter).await {
+        Ok(_) => {
+            info!("✅ Job progress watch started successfully");
+            Ok("Job progress watch started".to_string())
+        }
+        Err(e) => {
+            error!("Failed to start job progress watch: {}", e);
+            Err(format!("Failed to start job progress watch: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_stop_job_progress_watch(
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Stopping job progress watch");
+
+    let mut watcher_guard = state.job_progress_watcher.write().await;
+    watcher_guard.stop();
+
+    info!("✅ Job progress watch stopped");
+    Ok("Job progress watch stopped".to_string())
+}
+
+// Emits warning-feed-event with a WarningToast for each new occurrence of a Warning event matching
+// `filter`; starting again replaces the filter
+#[tauri::command]
+pub async fn kuboard_start_warning_feed(
+    filter: Option<WarningFeedFilter>,
+    app: tauri::AppHandle,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Starting warning feed");
+
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?
+        .clone();
+    drop(client_guard);
+
+    let namespace_filter = current_namespace_filter(&state).await;
+    let mut watcher_guard = state.warning_feed_watcher.write().await;
+
+    match watcher_guard.start(client, app, namespace_filter, filter.unwrap_or_default()).await {
+        Ok(_) => {
+            info!("✅ Warning feed started successfully");
+            Ok("Warning feed started".to_string())
+        }
+        Err(e) => {
+            error!("Failed to start warning feed: {}", e);
+            Err(format!("Failed to start warning feed: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_stop_warning_feed(
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Stopping warning feed");
+
+    let mut watcher_guard = state.warning_feed_watcher.write().await;
+    watcher_guard.stop();
+
+    info!("✅ Warning feed stopped");
+    Ok("Warning feed stopped".to_string())
+}
+
+// Shared Watch Commands
+#[tauri::command]
+pub async fn kuboard_start_shared_watch(
+    kinds: Vec<WatchKind>,
+    app: tauri::AppHandle,
+    state: State<'_, AppState>
+) -> Result<Vec<WatchKind>, String> {
+    info!("Starting shared watch");
+
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?
+        .clone();
+    drop(client_guard);
+
+    // The shared stream replaces the dedicated watchers so events aren't emitted twice
+    for kind in kuboard_shared_watch_kinds(&kinds) {
+        match kind {
+            WatchKind::Pod => state.pod_watcher.write().await.stop(),
+            WatchKind::Deployment => state.deployment_watcher.write().await.stop(),
+            WatchKind::StatefulSet => state.statefulset_watcher.write().await.stop(),
+            WatchKind::DaemonSet => state.daemonset_watcher.write().await.stop(),
+            WatchKind::ReplicaSet => state.replicaset_watcher.write().await.stop(),
+            WatchKind::Service => state.service_watcher.write().await.stop(),
+            WatchKind::CronJob => state.cronjob_watcher.write().await.stop(),
+        }
+    }
+
+    let filter = current_namespace_filter(&state).await;
+    let mut watcher_guard = state.shared_watcher.write().await;
+
+    match watcher_guard.start(client, app, filter, &kinds).await {
+        Ok(_) => {
+            info!("✅ Shared watch started for {:?}", watcher_guard.kinds());
+            Ok(watcher_guard.kinds().to_vec())
+        }
+        Err(e) => {
+            error!("Failed to start shared watch: {}", e);
+            Err(format!("Failed to start shared watch: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_stop_shared_watch(
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Stopping shared watch");
+
+    let mut watcher_guard = state.shared_watcher.write().await;
+    watcher_guard.stop();
+    
+    info!("✅ Shared watch stopped");
+    Ok("Shared watch stopped".to_string())
+}
+
+// Incremental List Commands
+// `since` is the revision of the list the caller holds; an unknown or missing one gets a full list
+#[tauri::command]
+pub async fn kuboard_get_list_delta(
+    kind: WatchKind,
+    since: Option<String>,
+    state: State<'_, AppState>
+) -> Result<ListDelta, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let context = state.current_context.read().await.clone().unwrap_or_default();
+    let filter = current_namespace_filter(&state).await;
+    let objects = kuboard_list_kind(client, &filter, kind).await
+        .map_err(|e| format!("Failed to list {:?}: {}", kind, e))?;
+
+    let mut store = state.list_snapshots.write().await;
+    let previous = since.as_deref().and_then(|revision| store.find(&context, kind, revision));
+    if since.is_some() && previous.is_none() {
+        info!("Revision {:?} for {:?} is unknown, sending a full resync", since, kind);
+    }
+    let (delta, snapshot) = kuboard_compute_list_delta(kind, previous, objects);
+    store.record(&context, kind, snapshot);
+    Ok(delta)
+}
+
+// Compressed Payload Commands
+// Same data as the list and YAML commands, in an envelope compressed with the first accepted encoding
+#[tauri::command]
+pub async fn kuboard_get_list_compressed(
+    kind: WatchKind,
+    accept: Vec<PayloadEncoding>,
+    threshold: Option<usize>,
+    state: State<'_, AppState>
+) -> Result<CompressedPayload, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let filter = current_namespace_filter(&state).await;
+    let objects = kuboard_list_kind(client, &filter, kind).await
+        .map_err(|e| format!("Failed to list {:?}: {}", kind, e))?;
+
+    match kuboard_encode_payload(&objects, &accept, threshold.unwrap_or(DEFAULT_COMPRESSION_THRESHOLD)) {
+        Ok(payload) => {
+            info!("✅ Listed {} {:?} objects ({} bytes, {:?})", objects.len(), kind, payload.original_size, payload.encoding);
+            Ok(payload)
+        }
+        Err(e) => {
+            error!("Failed to encode {:?} list: {}", kind, e);
+            Err(format!("Failed to encode list: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_get_yaml_compressed(
+    kind: WatchKind,
+    name: String,
+    namespace: String,
+    accept: Vec<PayloadEncoding>,
+    threshold: Option<usize>,
+    format: Option<ManifestFormat>,
+    state: State<'_, AppState>
+) -> Result<CompressedPayload, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let object = kuboard_get_kind(client, kind, &namespace, &name).await
+        .map_err(|e| format!("Failed to get {:?}: {}", kind, e))?
+        .ok_or_else(|| format!("{:?} {}/{} not found", kind, namespace, name))?;
+    let yaml = kuboard_render_manifest(&object, format.unwrap_or_default())
+        .map_err(|e| format!("Failed to serialize {:?}: {}", kind, e))?;
+
+    kuboard_encode_payload(&yaml, &accept, threshold.unwrap_or(DEFAULT_COMPRESSION_THRESHOLD))
+        .map_err(|e| format!("Failed to encode YAML: {}", e))
+}
+
+// Incident Notifier Commands
+#[tauri::command]
+pub async fn kuboard_start_incident_notifier(
+    app: tauri::AppHandle,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Starting incident notifier");
+
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?
+        .clone();
+    drop(client_guard);
+
+    let mut notifier_guard = state.incident_notifier.write().await;
+
+    match notifier_guard.start(client, app).await {
+        Ok(_) => {
+            info!("✅ Incident notifier started successfully");
+            Ok("Incident notifier started".to_string())
+        }
+        Err(e) => {
+            error!("Failed to start incident notifier: {}", e);
+            Err(format!("Failed to start incident notifier: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_stop_incident_notifier(
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Stopping incident notifier");
+
+    let mut notifier_guard = state.incident_notifier.write().await;
+    notifier_guard.stop();
+
+    info!("✅ Incident notifier stopped");
+    Ok("Incident notifier stopped".to_string())
+}
+
+#[tauri::command]
+pub async fn kuboard_get_notifier_rules(
+    state: State<'_, AppState>
+) -> Result<Vec<NotifierRuleStatus>, String> {
+    let notifier_guard = state.incident_notifier.read().await;
+    Ok(notifier_guard.rules().await)
+}
+
+#[tauri::command]
+pub async fn kuboard_set_notifier_rule_muted(
+    rule: IncidentRule,
+    muted: bool,
+    state: State<'_, AppState>
+) -> Result<Vec<NotifierRuleStatus>, String> {
+    info!("Setting notifier rule {:?} muted: {}", rule, muted);
+
+    let notifier_guard = state.incident_notifier.read().await;
+    notifier_guard.set_muted(rule, muted).await;
+    Ok(notifier_guard.rules().await)
+}
+
+// Resource Describe Commands
+#[tauri::command]
+pub async fn kuboard_describe_pod(
+    pod_name: String,
+    namespace: String,
+    state: State<'_, AppState>
+) -> Result<PodDescribe, String> {
+    info!("Describing pod: {}/{}", namespace, pod_name);
+    
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let pods_api: Api<Pod> = Api::namespaced(client.clone(), &namespace);
+    
+    match pods_api.get(&pod_name).await {
+        Ok(pod) => {
+            // Get pod events
+            let events = kuboard_fetch_pod_events(client, &pod_name, &namespace).await.unwrap_or_default();
+            
+            let describe = kuboard_build_pod_describe(&pod, &events);
+            
+            info!("✅ Successfully described pod: {}/{}", namespace, pod_name);
+            Ok(describe)
+        }
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            Err(format!("Pod {}/{} not found", namespace, pod_name))
+        }
+        Err(e) => {
+            error!("Failed to describe pod {}/{}: {}", namespace, pod_name, e);
+            Err(format!("Failed to describe pod: {}", e))
+        }
+    }
+}
+
+// Pod Exec Commands
+// Output arrives as "exec-output" events keyed by session id; input goes through kuboard_send_exec_input
+fn exec_output_emitter<R: Runtime>(app: AppHandle<R>, session_id: String) -> impl Fn(String) + Send + Sync + 'static {
+    move |data| {
+        let event = ExecOutputEvent { session_id: session_id.clone(), data };
+        if let Err(e) = app.emit(EXEC_OUTPUT_EVENT, event) {
+            error!("Failed to emit exec output event: {}", e);
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_exec_into_pod<R: Runtime>(
+    pod_name: String,
+    namespace: String,
+    container_name: Option<String>,
+    command: Option<Vec<String>>,
+    tty: Option<bool>,
+    record: Option<bool>,
+    app: AppHandle<R>,
+    state: State<'_, AppState>
+) -> Result<serde_json::Value, String> {
+    info!("Exec into pod: {}/{} (container: {:?})", namespace, pod_name, container_name);
+    production_guard(&state, "exec_into_pod", &format!("{}/{}", namespace, pod_name)).await?;
+    
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    // Start exec session
+    let mut session = start_exec_session(
+        client,
+        &pod_name,
+        &namespace,
+        container_name.as_deref(),
+    ).await.map_err(|e| format!("Failed to start exec session: {}", e))?;
+
+    if record.unwrap_or(false) {
+        let recordings_dir = kuboard_recordings_dir().map_err(|e| format!("Failed to start recording: {}", e))?;
+        // The recording shares the session id so the two can be matched up afterwards
+        let recorder = ExecRecorder::create(
+            &recordings_dir,
+            &session.session_id,
+            &session.pod_name,
+            &session.namespace,
+            session.container_name.as_deref(),
+            80,
+            24,
+        ).map_err(|e| format!("Failed to start recording: {}", e))?;
+        session.recorder = Some(recorder);
+    }
+
+    let on_output = exec_output_emitter(app, session.session_id.clone());
+    attach_exec_stream(
+        client,
+        &mut session,
+        command.unwrap_or_else(|| vec!["sh".to_string()]),
+        tty.unwrap_or(true),
+        on_output,
+    ).await.map_err(|e| format!("Failed to attach exec session: {}", e))?;
+
+    // Store session in app state
+    {
+        let mut sessions = state.exec_sessions.write().await;
+        sessions.insert(session.session_id.clone(), session.clone());
+    }
+
+    Ok(json!({
+        "sessionId": session.session_id,
+        "podName": session.pod_name,
+        "namespace": session.namespace,
+        "containerName": session.container_name,
+        "recordingId": session.recorder.as_ref().map(|r| r.recording_id.clone()),
+        "status": "connected",
+    }))
+}
+
+#[tauri::command]
+pub async fn kuboard_send_exec_input(
+    session_id: String,
+    data: String,
+    state: State<'_, AppState>
+) -> Result<(), String> {
+    let session = state.exec_sessions.read().await.get(&session_id).cloned()
+        .ok_or_else(|| format!("Exec session {} not found", session_id))?;
+    session.send_input(data).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn kuboard_start_node_shell<R: Runtime>(
+    node_name: String,
+    namespace: Option<String>,
+    image: Option<String>,
+    app: AppHandle<R>,
+    state: State<'_, AppState>
+) -> Result<NodeShellSession, String> {
+    production_guard(&state, "start_node_shell", &node_name).await?;
+
+    let namespace = namespace.unwrap_or_else(|| "default".to_string());
+    info!("Starting node shell on {} (namespace: {})", node_name, namespace);
+
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let mut session = start_node_shell(client, &node_name, &namespace, image.as_deref())
+        .await
+        .map_err(|e| format!("Failed to start node shell: {}", e))?;
+
+    let on_output = exec_output_emitter(app, session.session_id.clone());
+    if let Err(e) = attach_exec_stream(client, &mut session, kuboard_node_shell_command(), true, on_output).await {
+        // Don't leave a privileged pod behind when the shell can't be used
+        cleanup_node_shell(&session).await;
+        return Err(format!("Failed to attach node shell: {}", e));
+    }
+
+    {
+        let mut sessions = state.exec_sessions.write().await;
+        sessions.insert(session.session_id.clone(), session.clone());
+    }
+
+    info!("✅ Node shell ready on {} via pod {}/{}", node_name, namespace, session.pod_name);
+    Ok(NodeShellSession {
+        session_id: session.session_id,
+        node_name,
+        pod_name: session.pod_name,
+        namespace: session.namespace,
+        container_name: session.container_name,
+    })
+}
+
+#[tauri::command]
+pub async fn kuboard_close_exec_session(
+    session_id: String,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    let mut sessions = state.exec_sessions.write().await;
+    // Dropping the session ends its exec stream, which closes the recording file
+    match sessions.remove(&session_id) {
+        Some(session) => {
+            // Deleted through the session's own client, in case the context has changed since
+            cleanup_node_shell(&session).await;
+            info!("✅ Closed exec session {} for {}/{}", session_id, session.namespace, session.pod_name);
+            Ok(format!("Exec session {} closed", session_id))
+        }
+        None => Err(format!("Exec session {} not found", session_id)),
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_list_exec_recordings() -> Result<Vec<ExecRecordingInfo>, String> {
+    let recordings_dir = kuboard_recordings_dir().map_err(|e| format!("Failed to list recordings: {}", e))?;
+    kuboard_list_recordings(&recordings_dir).map_err(|e| format!("Failed to list recordings: {}", e))
+}
+
+#[tauri::command]
+pub async fn kuboard_get_exec_recording(recording_id: String) -> Result<String, String> {
+    let recordings_dir = kuboard_recordings_dir().map_err(|e| format!("Failed to read recording: {}", e))?;
+    kuboard_read_recording(&recordings_dir, &recording_id)
+        .map_err(|e| format!("Failed to read recording {}: {}", recording_id, e))
+}
+
+#[tauri::command]
+pub async fn kuboard_delete_exec_recording(recording_id: String) -> Result<String, String> {
+    let recordings_dir = kuboard_recordings_dir().map_err(|e| format!("Failed to delete recording: {}", e))?;
+    kuboard_delete_recording(&recordings_dir, &recording_id)
+        .map_err(|e| format!("Failed to delete recording {}: {}", recording_id, e))?;
+    info!("✅ Deleted exec recording {}", recording_id);
+    Ok(format!("Recording {} deleted", recording_id))
+}
+
+// Pod File Browser Commands
+#[tauri::command]
+pub async fn kuboard_list_pod_files(
+    pod_name: String,
+    namespace: String,
+    container_name: Option<String>,
+    path: String,
+    state: State<'_, AppState>
+) -> Result<Vec<PodFileEntry>, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    kuboard_list_pod_directory(client, &pod_name, &namespace, container_name.as_deref(), &path)
+        .await
+        .map_err(|e| format!("Failed to list {} in pod {}/{}: {}", path, namespace, pod_name, e))
+}
+
+#[tauri::command]
+pub async fn kuboard_stat_pod_path(
+    pod_name: String,
+    namespace: String,
+    container_name: Option<String>,
+    path: String,
+    state: State<'_, AppState>
+) -> Result<PodFileStat, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    kuboard_stat_pod_file(client, &pod_name, &namespace, container_name.as_deref(), &path)
+        .await
+        .map_err(|e| format!("Failed to stat {} in pod {}/{}: {}", path, namespace, pod_name, e))
+}
+
+#[tauri::command]
+pub async fn kuboard_get_pod_file(
+    pod_name: String,
+    namespace: String,
+    container_name: Option<String>,
+    path: String,
+    max_bytes: Option<u64>,
+    state: State<'_, AppState>
+) -> Result<PodFileContent, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    kuboard_read_pod_file(client, &pod_name, &namespace, container_name.as_deref(), &path, max_bytes)
+        .await
+        .map_err(|e| format!("Failed to read {} in pod {}/{}: {}", path, namespace, pod_name, e))
+}
+
+// Saved Commands
+#[tauri::command]
+pub async fn kuboard_save_command(
+    request: SavedCommandRequest,
+    state: State<'_, AppState>
+) -> Result<SavedCommand, String> {
+    let context = state.current_context.read().await.clone()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let path = kuboard_saved_commands_path().map_err(|e| format!("Failed to save command: {}", e))?;
+    let saved = kuboard_add_saved_command(&path, &context, request)
+        .map_err(|e| format!("Failed to save command: {}", e))?;
+    info!("✅ Saved command {} for context {}", saved.name, context);
+    Ok(saved)
+}
+
+#[tauri::command]
+pub async fn kuboard_list_saved_commands(state: State<'_, AppState>) -> Result<Vec<SavedCommand>, String> {
+    let context = state.current_context.read().await.clone()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let path = kuboard_saved_commands_path().map_err(|e| format!("Failed to list saved commands: {}", e))?;
+    kuboard_list_saved_commands_for_context(&path, &context)
+        .map_err(|e| format!("Failed to list saved commands: {}", e))
+}
+
+#[tauri::command]
+pub async fn kuboard_delete_saved_command(
+    id: String,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    let context = state.current_context.read().await.clone()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let path = kuboard_saved_commands_path().map_err(|e| format!("Failed to delete saved command: {}", e))?;
+    kuboard_remove_saved_command(&path, &context, &id)
+        .map_err(|e| format!("Failed to delete saved command: {}", e))?;
+    Ok(format!("Saved command {} deleted", id))
+}
+
+#[tauri::command]
+pub async fn kuboard_run_saved_command(
+    id: String,
+    pod_name: String,
+    namespace: String,
+    container_name: Option<String>,
+    state: State<'_, AppState>
+) -> Result<SavedCommandRun, String> {
+    let context = state.current_context.read().await.clone()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let path = kuboard_saved_commands_path().map_err(|e| format!("Failed to run saved command: {}", e))?;
+    let saved = kuboard_find_saved_command(&path, &context, &id)
+        .map_err(|e| format!("Failed to run saved command: {}", e))?;
+    // Log queries only read, so only exec commands go through the guardrails
+    if saved.kind == SavedCommandKind::Exec {
+        production_guard(&state, "run_saved_command", &format!("{}/{}", namespace, pod_name)).await?;
+    }
+    info!("Running saved command {} against {}/{}", saved.name, namespace, pod_name);
+
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let output = match saved.kind {
+        SavedCommandKind::Exec => {
+            let stdout = exec_capture(
+                client,
+                &pod_name,
+                &namespace,
+                container_name.as_deref(),
+                saved.command.clone(),
+                MAX_SAVED_COMMAND_OUTPUT_BYTES,
+                Some(SAVED_COMMAND_TIMEOUT),
+            ).await.map_err(|e| format!("Failed to run {}: {}", saved.name, e))?;
+            String::from_utf8_lossy(&stdout).into_owned()
+        }
+        SavedCommandKind::LogQuery => {
+            let logs = kuboard_fetch_pod_logs(client, &pod_name, &namespace, container_name.as_deref(), saved.tail_lines, false)
+                .await
+                .map_err(|e| format!("Failed to fetch logs: {}", e))?;
+            kuboard_filter_log_lines(&logs, saved.log_filter.as_deref())
+        }
+    };
+
+    // Only runs that completed count towards the library's usage ordering
+    let command = kuboard_mark_saved_command_run(&path, &context, &id)
+        .map_err(|e| format!("Failed to record saved command run: {}", e))?;
+    info!("✅ Saved command {} finished", command.name);
+    Ok(SavedCommandRun { command, output })
+}
+
+// Port Forwarding Commands
+// Note: Port forwarding requires WebSocket support for data streaming
+// This is a placeholder that will be enhanced with proper WebSocket integration
+
+#[tauri::command]
+pub async fn kuboard_port_forward(
+    resource_type: String, // "pod" or "service"
+    resource_name: String,
+    namespace: String,
+    local_port: u16,
+    remote_port: u16,
+    container_name: Option<String>,
+    state: State<'_, AppState>
+) -> Result<serde_json::Value, String> {
+    info!("Port forward: {} {}:{}/{} -> localhost:{}", resource_type, namespace, resource_name, remote_port, local_port);
+    
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    // Start port forward session
+    let session = start_port_forward_session(
+        client,
+        &resource_type,
+        &resource_name,
+        &namespace,
+        local_port,
+        remote_port,
+        container_name.as_deref(),
+    ).await.map_err(|e| format!("Failed to start port forward: {}", e))?;
+
+    // Store session in app state
+    {
+        let mut sessions = state.port_forward_sessions.write().await;
+        sessions.insert(session.session_id.clone(), session.clone());
+    }
+
+    // TODO: Implement WebSocket streaming and TCP forwarding
+    // For now, return session info
+    // The actual port forwarding will be implemented with WebSocket
+    
+    Ok(json!({
+        "sessionId": session.session_id,
+        "resourceType": session.resource_type,
+        "resourceName": session.resource_name,
+        "namespace": session.namespace,
+        "localPort": session.local_port,
+        "remotePort": session.remote_port,
+        "url": session.url(),
+        "status": "connecting",
+        "message": "Port forward session created. Full forwarding support coming soon."
+    }))
+}
+
+#[tauri::command]
+pub async fn kuboard_list_port_forwards(
+    state: State<'_, AppState>
+) -> Result<Vec<serde_json::Value>, String> {
+    info!("Listing active port forwards");
+    
+    let sessions = state.port_forward_sessions.read().await;
+    let forwards: Vec<serde_json::Value> = sessions.values().map(|session| {
+        json!({
+            "id": session.session_id,
+            "podName": if session.resource_type == "pod" { Some(&session.resource_name) } else { None },
+            "serviceName": if session.resource_type == "service" { Some(&session.resource_name) } else { None },
+            "namespace": session.namespace,
+            "localPort": session.local_port,
+            "remotePort": session.remote_port,
+            "url": session.url(),
+            "status": "active"
+        })
+    }).collect();
+    
+    Ok(forwards)
+}
+
+#[tauri::command]
+pub async fn kuboard_stop_port_forward(
+    forward_id: String,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Stopping port forward: {}", forward_id);
+    
+    let mut sessions = state.port_forward_sessions.write().await;
+    
+    if let Some(session) = sessions.remove(&forward_id) {
+        info!("Stopped port forward session: {} ({}:{}/{} -> localhost:{})", 
+              forward_id, session.namespace, session.resource_name, session.remote_port, session.local_port);
+        
+        // TODO: Close WebSocket connection and TCP listener
+        // For now, just remove from state
+        
+        Ok(format!("Port forward {} stopped", forward_id))
+    } else {
+        Err(format!("Port forward {} not found", forward_id))
+    }
+}
+
+// Drift Snapshot Commands
+#[tauri::command]
+pub async fn kuboard_start_drift_scheduler(
+    namespaces: Vec<String>,
+    interval_seconds: Option<u64>,
+    app: tauri::AppHandle,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    // Snapshots list every workload in each namespace, so keep the interval sensible
+    let interval_seconds = interval_seconds.unwrap_or(300).max(30);
+    info!("Starting drift scheduler for {:?} every {}s", namespaces, interval_seconds);
+
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?
+        .clone();
+    drop(client_guard);
+
+    let mut scheduler_guard = state.drift_scheduler.write().await;
+
+    match scheduler_guard.start(client, app, namespaces, interval_seconds).await {
+        Ok(_) => {
+            info!("✅ Drift scheduler started successfully");
+            Ok("Drift scheduler started".to_string())
+        }
+        Err(e) => {
+            error!("Failed to start drift scheduler: {}", e);
+            Err(format!("Failed to start drift scheduler: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_stop_drift_scheduler(
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Stopping drift scheduler");
+
+    let mut scheduler_guard = state.drift_scheduler.write().await;
+    scheduler_guard.stop();
+
+    info!("✅ Drift scheduler stopped");
+    Ok("Drift scheduler stopped".to_string())
+}
+
+#[tauri::command]
+pub async fn kuboard_get_drift_alerts(
+    state: State<'_, AppState>
+) -> Result<Vec<DriftAlert>, String> {
+    let scheduler_guard = state.drift_scheduler.read().await;
+    Ok(scheduler_guard.alerts().await)
+}
+
+// Scheduled Scaling Commands
+#[tauri::command]
+pub async fn kuboard_create_scaling_rule(
+    rule: ScalingRuleRequest,
+    state: State<'_, AppState>
+) -> Result<ScalingRule, String> {
+    info!("Creating scaling rule {} for namespace {}", rule.name, rule.namespace);
+
+    let context = match rule.context.clone() {
+        Some(context) => context,
+        None => state.current_context.read().await.clone()
+            .ok_or_else(|| "No active context. Please set a context first.".to_string())?,
+    };
+
+    // A production rule is confirmed once here, since nobody is around when it fires
+    let confirmation_token = context_guard(&state, &context, "create_scaling_rule", &rule.namespace).await?;
+
+    let scheduler_guard = state.scaling_scheduler.read().await;
+    let created = scheduler_guard.add_rule(ScalingRuleRequest { context: Some(context), ..rule }, confirmation_token).await?;
+
+    info!("✅ Created scaling rule {}", created.id);
+    Ok(created)
+}
+
+#[tauri::command]
+pub async fn kuboard_update_scaling_rule(
+    id: String,
+    rule: ScalingRuleRequest,
+    state: State<'_, AppState>
+) -> Result<ScalingRule, String> {
+    info!("Updating scaling rule {}", id);
+
+    let scheduler_guard = state.scaling_scheduler.read().await;
+    let existing = scheduler_guard.rule(&id).await
+        .ok_or_else(|| format!("Scaling rule {} not found", id))?;
+    let context = rule.context.clone().unwrap_or(existing.context);
+    let confirmation_token = context_guard(&state, &context, "update_scaling_rule", &id).await?;
+
+    scheduler_guard.update_rule(&id, ScalingRuleRequest { context: Some(context), ..rule }, confirmation_token).await
+}
+
+#[tauri::command]
+pub async fn kuboard_delete_scaling_rule(
+    id: String,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Deleting scaling rule {}", id);
+
+    let scheduler_guard = state.scaling_scheduler.read().await;
+    scheduler_guard.delete_rule(&id).await?;
+
+    info!("✅ Deleted scaling rule {}", id);
+    Ok(format!("Scaling rule {} deleted", id))
+}
+
+#[tauri::command]
+pub async fn kuboard_list_scaling_rules(
+    state: State<'_, AppState>
+) -> Result<Vec<ScalingRule>, String> {
+    let scheduler_guard = state.scaling_scheduler.read().await;
+    Ok(scheduler_guard.rules().await)
+}
+
+#[tauri::command]
+pub async fn kuboard_run_scaling_rule(
+    id: String,
+    state: State<'_, AppState>
+) -> Result<Vec<ScalingExecution>, String> {
+    info!("Running scaling rule {} now", id);
+
+    let scheduler_guard = state.scaling_scheduler.read().await;
+    let rule = scheduler_guard.rule(&id).await
+        .ok_or_else(|| format!("Scaling rule {} not found", id))?;
+    // The rule scales its own context, whichever one is active
+    context_guard(&state, &rule.context, "run_scaling_rule", &id).await?;
+
+    let paths = guardrail_paths(&state).map_err(|e| format!("Failed to run scaling rule: {}", e))?;
+    let executions = execute_scaling_rule(&rule, &paths).await;
+    scheduler_guard.record(executions.clone()).await;
+
+    info!("✅ Scaling rule {} touched {} workloads", rule.name, executions.len());
+    Ok(executions)
+}
+
+#[tauri::command]
+pub async fn kuboard_start_scaling_scheduler(
+    app: tauri::AppHandle,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Starting scaling scheduler");
+
+    // Each rule connects to its own context when it fires
+    let paths = guardrail_paths(&state).map_err(|e| format!("Failed to start scaling scheduler: {}", e))?;
+    let mut scheduler_guard = state.scaling_scheduler.write().await;
+
+    match scheduler_guard.start(app, paths).await {
+        Ok(_) => {
+            info!("✅ Scaling scheduler started successfully");
+            Ok("Scaling scheduler started".to_string())
         }
-        None => {
-            // If name is None, set it to the expected name
-            updated_pod.metadata.name = Some(pod_name.clone());
+        Err(e) => {
+            error!("Failed to start scaling scheduler: {}", e);
+            Err(format!("Failed to start scaling scheduler: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_stop_scaling_scheduler(
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    info!("Stopping scaling scheduler");
+
+    let mut scheduler_guard = state.scaling_scheduler.write().await;
+    scheduler_guard.stop();
+
+    info!("✅ Scaling scheduler stopped");
+    Ok("Scaling scheduler stopped".to_string())
+}
+
+#[tauri::command]
+pub async fn kuboard_get_scaling_audit(
+    state: State<'_, AppState>
+) -> Result<Vec<ScalingExecution>, String> {
+    let scheduler_guard = state.scaling_scheduler.read().await;
+    Ok(scheduler_guard.executions().await)
+}
+
+// Namespace Clone Commands
+#[tauri::command]
+pub async fn kuboard_clone_namespace<R: Runtime>(
+    source: String,
+    target: String,
+    include_kinds: Option<Vec<String>>,
+    image_tag: Option<String>,
+    operation_id: Option<String>,
+    app: AppHandle<R>,
+    state: State<'_, AppState>
+) -> Result<NamespaceCloneResult, String> {
+    info!("Cloning namespace {} into {}", source, target);
+    production_guard(&state, "clone_namespace", &target).await?;
+
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let include_kinds = include_kinds.unwrap_or_default();
+    let image_tag = image_tag.filter(|t| !t.trim().is_empty());
+    let progress = ProgressReporter::for_app(app, "clone-namespace", operation_id);
+    match kuboard_clone_namespace_resources(client, &source, &target, &include_kinds, image_tag.as_deref(), &progress).await {
+        Ok(result) => {
+            let failed = result.resources.iter().filter(|r| !r.success).count();
+            if failed > 0 {
+                warn!("Cloned namespace {} into {} with {} failures", source, target, failed);
+            } else {
+                info!("✅ Cloned {} resources from {} into {}", result.resources.len(), source, target);
+            }
+            Ok(result)
+        }
+        Err(e) => {
+            error!("Failed to clone namespace {} into {}: {}", source, target, e);
+            Err(format!("Failed to clone namespace: {}", e))
+        }
+    }
+}
+
+// Manifest Template Commands
+#[tauri::command]
+pub async fn kuboard_list_manifest_templates() -> Result<Vec<ManifestTemplate>, String> {
+    let path = kuboard_templates_path().map_err(|e| format!("Failed to list templates: {}", e))?;
+    kuboard_load_templates(&path).map_err(|e| format!("Failed to list templates: {}", e))
+}
+
+#[tauri::command]
+pub async fn kuboard_save_manifest_template(request: ManifestTemplateRequest) -> Result<ManifestTemplate, String> {
+    let path = kuboard_templates_path().map_err(|e| format!("Failed to save template: {}", e))?;
+    let template = kuboard_add_template(&path, request)
+        .map_err(|e| format!("Failed to save template: {}", e))?;
+    info!("✅ Saved {} template {}", template.kind, template.name);
+    Ok(template)
+}
+
+#[tauri::command]
+pub async fn kuboard_delete_manifest_template(id: String) -> Result<String, String> {
+    let path = kuboard_templates_path().map_err(|e| format!("Failed to delete template: {}", e))?;
+    kuboard_remove_template(&path, &id).map_err(|e| format!("Failed to delete template: {}", e))?;
+    info!("✅ Deleted template {}", id);
+    Ok(format!("Template {} deleted", id))
+}
+
+#[tauri::command]
+pub async fn kuboard_render_manifest_template(
+    id: String,
+    parameters: HashMap<String, String>,
+) -> Result<String, String> {
+    let path = kuboard_templates_path().map_err(|e| format!("Failed to render template: {}", e))?;
+    let template = kuboard_find_template(&path, &id).map_err(|e| e.to_string())?;
+    let manifest = kuboard_render_template(&template, &parameters)
+        .map_err(|e| format!("Failed to render template: {}", e))?;
+    serde_json::to_string_pretty(&manifest).map_err(|e| format!("Failed to serialize manifest: {}", e))
+}
+
+#[tauri::command]
+pub async fn kuboard_apply_manifest_template(
+    id: String,
+    parameters: HashMap<String, String>,
+    namespace: String,
+    state: State<'_, AppState>
+) -> Result<AppliedManifest, String> {
+    info!("Applying template {} in namespace {}", id, namespace);
+    production_guard(&state, "apply_manifest_template", &format!("{}/{}", namespace, id)).await?;
+
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let path = kuboard_templates_path().map_err(|e| format!("Failed to apply template: {}", e))?;
+    let template = kuboard_find_template(&path, &id).map_err(|e| e.to_string())?;
+    let mut manifest = kuboard_render_template(&template, &parameters)
+        .map_err(|e| format!("Failed to render template: {}", e))?;
+    kuboard_record_change_cause_json(&mut manifest, &change_cause(&state, &format!("apply template {}", template.id)).await);
+    // The namespace picked for the template wins over one written into its body
+    if let Some(metadata) = manifest.get_mut("metadata").and_then(|m| m.as_object_mut()) {
+        metadata.remove("namespace");
+    }
+
+    match kuboard_apply_object(client, &manifest, &namespace).await {
+        Ok(applied) => {
+            info!("✅ Applied {} {} from template {}", applied.kind, applied.name, template.name);
+            Ok(applied)
+        }
+        Err(e) => {
+            error!("Failed to apply template {}: {}", template.name, e);
+            Err(format!("Failed to apply template: {}", e))
+        }
+    }
+}
+
+// Generic Apply Commands
+// Every object in the YAML/JSON is resolved before anything is done with it, so an unknown kind, a
+// missing name or a namespace outside the profile fails the whole manifest
+async fn resolve_manifest_targets(
+    state: &AppState,
+    client: &Client,
+    yaml_content: &str,
+    namespace: Option<String>,
+    failure: &str,
+) -> Result<Vec<ApplyTarget>, String> {
+    let manifests = kuboard_parse_manifests(yaml_content).map_err(|e| format!("Invalid YAML/JSON format: {}", e))?;
+    if manifests.is_empty() {
+        return Err("The manifest contains no objects".to_string());
+    }
+    let namespace = namespace.filter(|ns| !ns.is_empty()).unwrap_or_else(|| "default".to_string());
+
+    let profile = state.profile.read().await.clone();
+    let mut targets = Vec::new();
+    for manifest in &manifests {
+        let target = kuboard_resolve_apply_target(client, manifest, &namespace).await
+            .map_err(|e| format!("{}: {}", failure, e))?;
+        if let Some(rejection) = kuboard_profile_object_rejection(&profile, &target.kind, &target.name, target.namespace.as_deref()) {
+            return Err(rejection);
+        }
+        targets.push(target);
+    }
+    Ok(targets)
+}
+
+// kubectl apply for the editor
+#[tauri::command]
+pub async fn kuboard_apply_manifest(
+    yaml_content: String,
+    namespace: Option<String>,
+    force: Option<bool>,
+    dry_run: Option<bool>,
+    state: State<'_, AppState>
+) -> Result<Vec<AppliedManifest>, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let targets = resolve_manifest_targets(&state, client, &yaml_content, namespace, "Failed to apply manifest").await?;
+    let dry_run = dry_run.unwrap_or(false);
+    if !dry_run {
+        for target in &targets {
+            let name = match &target.namespace {
+                Some(namespace) => format!("{}/{}", namespace, target.name),
+                None => target.name.clone(),
+            };
+            production_guard(&state, "apply_manifest", &name).await?;
+        }
+    }
+
+    let mut applied = Vec::new();
+    for mut target in targets {
+        let cause = change_cause(&state, &format!("apply {}/{}", target.kind.to_lowercase(), target.name)).await;
+        kuboard_record_change_cause(&mut target.object.metadata, &cause);
+        match kuboard_apply_target(&target, force.unwrap_or(false), dry_run).await {
+            Ok(result) => {
+                info!("✅ {} {} {:?}", result.kind, result.name, result.outcome);
+                applied.push(result);
+            }
+            Err(e) => {
+                error!("Failed to apply {} {}: {}", target.kind, target.name, e);
+                let done: Vec<String> = applied.iter().map(|a| format!("{} {}", a.kind, a.name)).collect();
+                return Err(if done.is_empty() {
+                    format!("Failed to apply {} {}: {}", target.kind, target.name, e)
+                } else {
+                    format!("Failed to apply {} {} after applying {}: {}", target.kind, target.name, done.join(", "), e)
+                });
+            }
+        }
+    }
+    Ok(applied)
+}
+
+// kubectl diff for the editor: what applying the manifest would change in each live object
+#[tauri::command]
+pub async fn kuboard_diff_manifest(
+    yaml_content: String,
+    namespace: Option<String>,
+    state: State<'_, AppState>
+) -> Result<Vec<ManifestDiff>, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let targets = resolve_manifest_targets(&state, client, &yaml_content, namespace, "Failed to diff manifest").await?;
+    let mut diffs = Vec::new();
+    for target in &targets {
+        let diff = kuboard_diff_target(target).await
+            .map_err(|e| format!("Failed to diff {} {}: {}", target.kind, target.name, e))?;
+        diffs.push(diff);
+    }
+    Ok(diffs)
+}
+
+// GitOps Export Commands
+#[tauri::command]
+pub async fn kuboard_export_clean_yaml(
+    kind: String,
+    name: String,
+    namespace: String,
+    format: Option<ManifestFormat>,
+    state: State<'_, AppState>
+) -> Result<String, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    match kuboard_export_manifest(client, &kind, &name, &namespace).await {
+        Ok(manifest) => {
+            info!("✅ Exported {} {}/{}", kind, namespace, name);
+            kuboard_render_manifest(&manifest, format.unwrap_or_default()).map_err(|e| format!("Failed to serialize {}: {}", kind, e))
         }
-        _ => {} // Name matches or will be set
+        Err(e) => match e.downcast_ref::<kube::Error>() {
+            Some(kube::Error::Api(api_error)) if api_error.code == 404 => {
+                Err(format!("{} {}/{} not found", kind, namespace, name))
+            }
+            _ => Err(format!("Failed to export {} {}/{}: {}", kind, namespace, name, e)),
+        },
     }
-    
-    // Replace the pod
-    match pods_api.replace(&pod_name, &Default::default(), &updated_pod).await {
-        Ok(_) => {
-            info!("✅ Successfully updated pod: {}/{}", namespace, pod_name);
-            Ok(format!("Pod {}/{} updated successfully", namespace, pod_name))
+}
+
+// Field Manager Commands
+#[tauri::command]
+pub async fn kuboard_get_field_managers(
+    kind: String,
+    name: String,
+    namespace: String,
+    state: State<'_, AppState>
+) -> Result<FieldOwnership, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    match kuboard_get_live_object(client, &kind, &name, &namespace).await {
+        Ok(object) => {
+            let ownership = kuboard_field_ownership(&kind, &object.metadata);
+            info!("✅ Found {} field managers on {} {}/{}", ownership.managers.len(), kind, namespace, name);
+            Ok(ownership)
         }
-        Err(kube::Error::Api(e)) if e.code == 404 => {
-            Err(format!("Pod {}/{} not found", namespace, pod_name))
+        Err(e) => match e.downcast_ref::<kube::Error>() {
+            Some(kube::Error::Api(api_error)) if api_error.code == 404 => {
+                Err(format!("{} {}/{} not found", kind, namespace, name))
+            }
+            _ => Err(format!("Failed to get field managers for {} {}/{}: {}", kind, namespace, name, e)),
+        },
+    }
+}
+
+// Manifest Validation Commands
+#[tauri::command]
+pub async fn kuboard_validate_yaml(
+    yaml_content: String,
+    state: State<'_, AppState>
+) -> Result<ManifestValidation, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    match kuboard_validate_manifest(client, &yaml_content).await {
+        Ok(validation) => {
+            info!("Validated {} {}: {} diagnostics", validation.api_version, validation.kind, validation.diagnostics.len());
+            Ok(validation)
         }
         Err(e) => {
-            error!("Failed to update pod {}/{}: {}", namespace, pod_name, e);
-            Err(format!("Failed to update pod: {}", e))
+            error!("Failed to validate manifest: {}", e);
+            Err(format!("Failed to validate manifest: {}", e))
         }
     }
 }
 
-// Pod Watch Commands
+// Pod Security Admission Commands
 #[tauri::command]
-pub async fn kuboard_start_pod_watch(
-    app: tauri::AppHandle,
+pub async fn kuboard_get_pod_security_labels(
+    namespace: String,
     state: State<'_, AppState>
-) -> Result<String, String> {
-    info!("Starting pod watch");
+) -> Result<PodSecurityLabels, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let namespaces_api: Api<Namespace> = Api::all(client.clone());
+    match namespaces_api.get(&namespace).await {
+        Ok(ns) => Ok(kuboard_pod_security_labels(&ns)),
+        Err(kube::Error::Api(e)) if e.code == 404 => Err(format!("Namespace {} not found", namespace)),
+        Err(e) => Err(format!("Failed to get namespace: {}", e)),
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_set_pod_security_labels(
+    namespace: String,
+    enforce: Option<String>,
+    audit: Option<String>,
+    warn: Option<String>,
+    state: State<'_, AppState>
+) -> Result<PodSecurityLabels, String> {
+    info!("Setting pod security labels on {}: enforce={:?} audit={:?} warn={:?}", namespace, enforce, audit, warn);
+    production_guard(&state, "set_pod_security_labels", &namespace).await?;
 
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
-        .ok_or_else(|| "No active context. Please set a context first.".to_string())?
-        .clone();
-    drop(client_guard);
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let mut watcher_guard = state.pod_watcher.write().await;
-    
-    match watcher_guard.start(client, app).await {
-        Ok(_) => {
-            info!("✅ Pod watch started successfully");
-            Ok("Pod watch started".to_string())
+    let levels = [("enforce", enforce), ("audit", audit), ("warn", warn)];
+    match kuboard_patch_pod_security_labels(client, &namespace, &levels).await {
+        Ok(labels) => {
+            info!("✅ Updated pod security labels on {}", namespace);
+            Ok(labels)
         }
         Err(e) => {
-            error!("Failed to start pod watch: {}", e);
-            Err(format!("Failed to start pod watch: {}", e))
+            error!("Failed to set pod security labels on {}: {}", namespace, e);
+            Err(format!("Failed to set pod security labels: {}", e))
         }
     }
 }
 
 #[tauri::command]
-pub async fn kuboard_stop_pod_watch(
+pub async fn kuboard_dry_run_pod_security_level(
+    namespace: String,
+    level: String,
     state: State<'_, AppState>
-) -> Result<String, String> {
-    info!("Stopping pod watch");
+) -> Result<PodSecurityCheck, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let mut watcher_guard = state.pod_watcher.write().await;
-    watcher_guard.stop();
-    
-    info!("✅ Pod watch stopped");
-    Ok("Pod watch stopped".to_string())
+    match kuboard_check_pod_security_level(client, &namespace, &level).await {
+        Ok(check) => {
+            info!("Pod security dry run for {} at {}: {} of {} pods would violate", namespace, level, check.violations.len(), check.pods_checked);
+            Ok(check)
+        }
+        Err(e) => Err(format!("Failed to check pod security level: {}", e)),
+    }
 }
 
-// Deployment Watch Commands
+// ServiceAccount Usage Commands
 #[tauri::command]
-pub async fn kuboard_start_deployment_watch(
-    app: tauri::AppHandle,
+pub async fn kuboard_get_service_account_usage(
+    namespace: String,
     state: State<'_, AppState>
-) -> Result<String, String> {
-    info!("Starting deployment watch");
-
+) -> Result<Vec<ServiceAccountUsage>, String> {
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
-        .ok_or_else(|| "No active context. Please set a context first.".to_string())?
-        .clone();
-    drop(client_guard);
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let mut watcher_guard = state.deployment_watcher.write().await;
-    
-    match watcher_guard.start(client, app).await {
-        Ok(_) => {
-            info!("✅ Deployment watch started successfully");
-            Ok("Deployment watch started".to_string())
+    match kuboard_map_service_accounts(client, &namespace).await {
+        Ok(usage) => {
+            info!("✅ Mapped {} service accounts in {}", usage.len(), namespace);
+            Ok(usage)
         }
         Err(e) => {
-            error!("Failed to start deployment watch: {}", e);
-            Err(format!("Failed to start deployment watch: {}", e))
+            error!("Failed to map service accounts in {}: {}", namespace, e);
+            Err(format!("Failed to map service accounts: {}", e))
         }
     }
 }
 
+// RBAC Who-Can Commands
 #[tauri::command]
-pub async fn kuboard_stop_deployment_watch(
+pub async fn kuboard_who_can(
+    verb: String,
+    resource: String,
+    namespace: Option<String>,
     state: State<'_, AppState>
-) -> Result<String, String> {
-    info!("Stopping deployment watch");
+) -> Result<Vec<WhoCanSubject>, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let mut watcher_guard = state.deployment_watcher.write().await;
-    watcher_guard.stop();
-    
-    info!("✅ Deployment watch stopped");
-    Ok("Deployment watch stopped".to_string())
+    let namespace = namespace.filter(|ns| !ns.is_empty());
+    let snapshot = RbacSnapshot::fetch(client, namespace.as_deref()).await
+        .map_err(|e| format!("Failed to read RBAC configuration: {}", e))?;
+    let subjects = kuboard_who_can_subjects(&snapshot, &RbacAction::parse(&verb, &resource));
+
+    info!("✅ {} subjects can {} {} in {}", subjects.len(), verb, resource, namespace.as_deref().unwrap_or("the cluster"));
+    Ok(subjects)
 }
 
-// StatefulSet Watch Commands
+// Best-Practices Scanner Commands
 #[tauri::command]
-pub async fn kuboard_start_statefulset_watch(
-    app: tauri::AppHandle,
+pub async fn kuboard_scan_best_practices(
+    namespace: Option<String>,
     state: State<'_, AppState>
-) -> Result<String, String> {
-    info!("Starting statefulset watch");
+) -> Result<Vec<BestPracticeReport>, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let namespace = namespace.filter(|ns| !ns.is_empty());
+    match kuboard_scan_namespace_best_practices(client, namespace.as_deref()).await {
+        Ok(reports) => {
+            info!("✅ Scanned {} namespaces for best practices", reports.len());
+            Ok(reports)
+        }
+        Err(e) => {
+            error!("Failed to scan best practices: {}", e);
+            Err(format!("Failed to scan best practices: {}", e))
+        }
+    }
+}
 
+// Deprecated API Scanner Commands
+#[tauri::command]
+pub async fn kuboard_scan_deprecated_apis(
+    target_version: Option<String>,
+    state: State<'_, AppState>
+) -> Result<DeprecatedApiReport, String> {
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
-        .ok_or_else(|| "No active context. Please set a context first.".to_string())?
-        .clone();
-    drop(client_guard);
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let mut watcher_guard = state.statefulset_watcher.write().await;
-    
-    match watcher_guard.start(client, app).await {
-        Ok(_) => {
-            info!("✅ StatefulSet watch started successfully");
-            Ok("StatefulSet watch started".to_string())
+    match kuboard_find_deprecated_api_usage(client, target_version.as_deref()).await {
+        Ok(report) => {
+            info!("✅ Found {} objects using APIs removed by {}", report.findings.len(), report.target_version);
+            Ok(report)
         }
         Err(e) => {
-            error!("Failed to start statefulset watch: {}", e);
-            Err(format!("Failed to start statefulset watch: {}", e))
+            error!("Failed to scan for deprecated APIs: {}", e);
+            Err(format!("Failed to scan for deprecated APIs: {}", e))
         }
     }
 }
 
+// Upgrade Readiness Commands
 #[tauri::command]
-pub async fn kuboard_stop_statefulset_watch(
+pub async fn kuboard_get_upgrade_readiness(
+    target_version: Option<String>,
     state: State<'_, AppState>
-) -> Result<String, String> {
-    info!("Stopping statefulset watch");
+) -> Result<UpgradeReadinessReport, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let mut watcher_guard = state.statefulset_watcher.write().await;
-    watcher_guard.stop();
-    
-    info!("✅ StatefulSet watch stopped");
-    Ok("StatefulSet watch stopped".to_string())
+    match kuboard_check_upgrade_readiness(client, target_version.as_deref()).await {
+        Ok(report) => {
+            info!("✅ Upgrade to {} readiness: {} issues, ready: {}", report.target_version, report.issues.len(), report.ready);
+            Ok(report)
+        }
+        Err(e) => {
+            error!("Failed to check upgrade readiness: {}", e);
+            Err(format!("Failed to check upgrade readiness: {}", e))
+        }
+    }
 }
 
-// DaemonSet Watch Commands
+// Workload Resilience Commands
 #[tauri::command]
-pub async fn kuboard_start_daemonset_watch(
-    app: tauri::AppHandle,
+pub async fn kuboard_get_resilience_report(
+    namespace: Option<String>,
     state: State<'_, AppState>
-) -> Result<String, String> {
-    info!("Starting daemonset watch");
+) -> Result<Vec<WorkloadResilience>, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    match kuboard_find_resilience_gaps(client, namespace.as_deref()).await {
+        Ok(workloads) => {
+            info!("✅ Found {} workloads with availability gaps", workloads.len());
+            Ok(workloads)
+        }
+        Err(e) => {
+            error!("Failed to build resilience report: {}", e);
+            Err(format!("Failed to build resilience report: {}", e))
+        }
+    }
+}
 
+// Pod Placement Heatmap Commands
+#[tauri::command]
+pub async fn kuboard_get_placement_heatmap(
+    group_by: Option<String>,
+    namespace: Option<String>,
+    state: State<'_, AppState>
+) -> Result<PlacementHeatmap, String> {
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
-        .ok_or_else(|| "No active context. Please set a context first.".to_string())?
-        .clone();
-    drop(client_guard);
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let mut watcher_guard = state.daemonset_watcher.write().await;
-    
-    match watcher_guard.start(client, app).await {
-        Ok(_) => {
-            info!("✅ DaemonSet watch started successfully");
-            Ok("DaemonSet watch started".to_string())
+    let by_workload = match group_by.as_deref().unwrap_or("namespace") {
+        "namespace" => false,
+        "workload" => true,
+        other => return Err(format!("Unknown grouping {}, expected namespace or workload", other)),
+    };
+
+    match kuboard_get_pod_placement(client, namespace.as_deref(), by_workload).await {
+        Ok(heatmap) => {
+            info!("✅ Built placement heatmap: {} rows across {} nodes", heatmap.rows.len(), heatmap.nodes.len());
+            Ok(heatmap)
         }
         Err(e) => {
-            error!("Failed to start daemonset watch: {}", e);
-            Err(format!("Failed to start daemonset watch: {}", e))
+            error!("Failed to build placement heatmap: {}", e);
+            Err(format!("Failed to build placement heatmap: {}", e))
         }
     }
 }
 
+// Topology Commands
 #[tauri::command]
-pub async fn kuboard_stop_daemonset_watch(
+pub async fn kuboard_get_topology_zones(state: State<'_, AppState>) -> Result<Vec<TopologyZone>, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    match kuboard_list_topology_zones(client).await {
+        Ok(zones) => {
+            info!("✅ Found {} topology zones", zones.len());
+            Ok(zones)
+        }
+        Err(e) => {
+            error!("Failed to group nodes by zone: {}", e);
+            Err(format!("Failed to group nodes by zone: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn kuboard_get_zone_concentration(
+    namespace: Option<String>,
     state: State<'_, AppState>
-) -> Result<String, String> {
-    info!("Stopping daemonset watch");
+) -> Result<Vec<ZoneConcentration>, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let mut watcher_guard = state.daemonset_watcher.write().await;
-    watcher_guard.stop();
-    
-    info!("✅ DaemonSet watch stopped");
-    Ok("DaemonSet watch stopped".to_string())
+    match kuboard_find_zone_concentrations(client, namespace.as_deref()).await {
+        Ok(workloads) => {
+            info!("✅ Found {} workloads concentrated in a single zone", workloads.len());
+            Ok(workloads)
+        }
+        Err(e) => {
+            error!("Failed to check zone concentration: {}", e);
+            Err(format!("Failed to check zone concentration: {}", e))
+        }
+    }
 }
 
-// ReplicaSet Watch Commands
+// Service Endpoint Probe Commands
 #[tauri::command]
-pub async fn kuboard_start_replicaset_watch(
-    app: tauri::AppHandle,
+pub async fn kuboard_probe_service_endpoints(
+    name: String,
+    namespace: String,
+    mode: Option<String>,
+    port: Option<u16>,
+    path: Option<String>,
     state: State<'_, AppState>
-) -> Result<String, String> {
-    info!("Starting replicaset watch");
+) -> Result<ServiceProbeReport, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let mode = ProbeMode::parse(mode.as_deref().unwrap_or("tcp")).map_err(|e| e.to_string())?;
+    let path = path.unwrap_or_else(|| "/".to_string());
 
+    match kuboard_probe_endpoints(client, &namespace, &name, mode, port, &path).await {
+        Ok(report) => {
+            info!("✅ Probed {} endpoints of service {}/{}: {} reachable", report.endpoints.len(), namespace, name, report.reachable);
+            Ok(report)
+        }
+        Err(e) => match e.downcast_ref::<kube::Error>() {
+            Some(kube::Error::Api(api_error)) if api_error.code == 404 => Err(format!("Endpoints {}/{} not found", namespace, name)),
+            _ => {
+                error!("Failed to probe service {}/{}: {}", namespace, name, e);
+                Err(format!("Failed to probe service endpoints: {}", e))
+            }
+        },
+    }
+}
+
+// HTTP Request Tester Commands
+#[tauri::command]
+pub async fn kuboard_http_probe(
+    service_or_pod: String,
+    namespace: String,
+    port: u16,
+    path: Option<String>,
+    method: Option<String>,
+    headers: Option<HashMap<String, String>>,
+    state: State<'_, AppState>
+) -> Result<HttpProbeResponse, String> {
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
-        .ok_or_else(|| "No active context. Please set a context first.".to_string())?
-        .clone();
-    drop(client_guard);
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let mut watcher_guard = state.replicaset_watcher.write().await;
-    
-    match watcher_guard.start(client, app).await {
-        Ok(_) => {
-            info!("✅ ReplicaSet watch started successfully");
-            Ok("ReplicaSet watch started".to_string())
+    let path = path.unwrap_or_else(|| "/".to_string());
+    let method = method.unwrap_or_else(|| "GET".to_string());
+    let headers = headers.unwrap_or_default();
+
+    match kuboard_send_http_probe(client, &namespace, &service_or_pod, port, &path, &method, &headers).await {
+        Ok(response) => {
+            info!("✅ {} {} on {}/{}: {} in {}ms", method, path, namespace, service_or_pod, response.status, response.latency_ms);
+            Ok(response)
+        }
+        Err(e) => match e.downcast_ref::<kube::Error>() {
+            Some(kube::Error::Api(api_error)) if api_error.code == 404 => Err(format!("{} in namespace {} not found", service_or_pod, namespace)),
+            _ => {
+                error!("HTTP probe of {}/{} failed: {}", namespace, service_or_pod, e);
+                Err(format!("HTTP probe failed: {}", e))
+            }
+        },
+    }
+}
+
+// Cluster DNS Commands
+#[tauri::command]
+pub async fn kuboard_get_cluster_dns(state: State<'_, AppState>) -> Result<ClusterDnsSummary, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    match kuboard_summarize_cluster_dns(client).await {
+        Ok(summary) => {
+            info!("✅ Cluster DNS ({}): {} ready endpoints, {} recent errors", summary.provider, summary.ready_endpoints, summary.recent_errors.len());
+            Ok(summary)
         }
         Err(e) => {
-            error!("Failed to start replicaset watch: {}", e);
-            Err(format!("Failed to start replicaset watch: {}", e))
+            error!("Failed to read cluster DNS configuration: {}", e);
+            Err(format!("Failed to read cluster DNS configuration: {}", e))
         }
     }
 }
 
+// Ingress Route Commands
 #[tauri::command]
-pub async fn kuboard_stop_replicaset_watch(
-    state: State<'_, AppState>
-) -> Result<String, String> {
-    info!("Stopping replicaset watch");
+pub async fn kuboard_get_ingress_routes(state: State<'_, AppState>) -> Result<IngressRouteTable, String> {
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let mut watcher_guard = state.replicaset_watcher.write().await;
-    watcher_guard.stop();
-    
-    info!("✅ ReplicaSet watch stopped");
-    Ok("ReplicaSet watch stopped".to_string())
+    match kuboard_build_ingress_route_table(client).await {
+        Ok(table) => {
+            info!("✅ Found {} ingress controllers and {} routes", table.controllers.len(), table.routes.len());
+            Ok(table)
+        }
+        Err(e) => {
+            error!("Failed to build ingress route table: {}", e);
+            Err(format!("Failed to build ingress route table: {}", e))
+        }
+    }
 }
 
-// Service Watch Commands
 #[tauri::command]
-pub async fn kuboard_start_service_watch(
-    app: tauri::AppHandle,
-    state: State<'_, AppState>
-) -> Result<String, String> {
-    info!("Starting service watch");
-
+pub async fn kuboard_lookup_ingress_route(url: String, state: State<'_, AppState>) -> Result<Option<IngressRoute>, String> {
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
-        .ok_or_else(|| "No active context. Please set a context first.".to_string())?
-        .clone();
-    drop(client_guard);
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let mut watcher_guard = state.service_watcher.write().await;
-    
-    match watcher_guard.start(client, app).await {
-        Ok(_) => {
-            info!("✅ Service watch started successfully");
-            Ok("Service watch started".to_string())
+    match kuboard_build_ingress_route_table(client).await {
+        Ok(table) => {
+            let route = kuboard_match_ingress_route(&table.routes, &url).cloned();
+            info!("✅ {} routes to {}", url, route.as_ref().map(|r| format!("{}/{}", r.namespace, r.service)).unwrap_or_else(|| "no service".to_string()));
+            Ok(route)
         }
         Err(e) => {
-            error!("Failed to start service watch: {}", e);
-            Err(format!("Failed to start service watch: {}", e))
+            error!("Failed to look up ingress route for {}: {}", url, e);
+            Err(format!("Failed to look up ingress route: {}", e))
         }
     }
 }
 
 #[tauri::command]
-pub async fn kuboard_stop_service_watch(
-    state: State<'_, AppState>
-) -> Result<String, String> {
-    info!("Stopping service watch");
+pub async fn kuboard_create_ingress(request: IngressCreateRequest, state: State<'_, AppState>) -> Result<Ingress, String> {
+    info!("Creating ingress {}/{}", request.namespace, request.name);
+    production_guard(&state, "create_ingress", &format!("{}/{}", request.namespace, request.name)).await?;
 
-    let mut watcher_guard = state.service_watcher.write().await;
-    watcher_guard.stop();
-    
-    info!("✅ Service watch stopped");
-    Ok("Service watch stopped".to_string())
+    let client_guard = state.current_client.read().await;
+    let client = client_guard
+        .as_ref()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    match kuboard_create_ingress_checked(client, &request).await {
+        Ok(ingress) => {
+            info!("✅ Created ingress {}/{}", request.namespace, request.name);
+            Ok(ingress)
+        }
+        Err(e) => {
+            error!("Failed to create ingress {}/{}: {}", request.namespace, request.name, e);
+            Err(format!("Failed to create ingress: {}", e))
+        }
+    }
 }
 
-// CronJob Watch Commands
+// TLS Secret Commands
 #[tauri::command]
-pub async fn kuboard_start_cronjob_watch(
-    app: tauri::AppHandle,
-    state: State<'_, AppState>
-) -> Result<String, String> {
-    info!("Starting cronjob watch");
-
+pub async fn kuboard_inspect_tls_secrets(
+    namespace: Option<String>,
+    expiry_window_days: Option<i64>,
+    state: State<'_, AppState>,
+) -> Result<TlsInspectionReport, String> {
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
-        .ok_or_else(|| "No active context. Please set a context first.".to_string())?
-        .clone();
-    drop(client_guard);
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let mut watcher_guard = state.cronjob_watcher.write().await;
-    
-    match watcher_guard.start(client, app).await {
-        Ok(_) => {
-            info!("✅ CronJob watch started successfully");
-            Ok("CronJob watch started".to_string())
+    let window_days = expiry_window_days.unwrap_or(DEFAULT_EXPIRY_WINDOW_DAYS);
+    match kuboard_inspect_tls_certificates(client, namespace.as_deref(), window_days).await {
+        Ok(report) => {
+            info!("✅ Inspected {} TLS secrets: {} expired, {} expiring within {} days", report.secrets.len(), report.expired, report.expiring, window_days);
+            Ok(report)
         }
         Err(e) => {
-            error!("Failed to start cronjob watch: {}", e);
-            Err(format!("Failed to start cronjob watch: {}", e))
+            error!("Failed to inspect TLS secrets: {}", e);
+            Err(format!("Failed to inspect TLS secrets: {}", e))
         }
     }
 }
 
-#[tauri::command]
-pub async fn kuboard_stop_cronjob_watch(
-    state: State<'_, AppState>
-) -> Result<String, String> {
-    info!("Stopping cronjob watch");
+// dryRun=All: the API server runs admission and validation but persists nothing
+fn delete_params(dry_run: bool) -> DeleteParams {
+    DeleteParams { dry_run, ..Default::default() }
+}
 
-    let mut watcher_guard = state.cronjob_watcher.write().await;
-    watcher_guard.stop();
-    
-    info!("✅ CronJob watch stopped");
-    Ok("CronJob watch stopped".to_string())
+// List parameters for an optional label selector such as "app=web,tier!=cache" and field selector
+// such as "status.phase=Running,spec.nodeName=node-1"; the API server validates the syntax and
+// rejects fields the kind doesn't support
+fn selector_params(label_selector: Option<&str>, field_selector: Option<&str>) -> ListParams {
+    let params = match label_selector.map(str::trim).filter(|selector| !selector.is_empty()) {
+        Some(selector) => ListParams::default().labels(selector),
+        None => ListParams::default(),
+    };
+    match field_selector.map(str::trim).filter(|selector| !selector.is_empty()) {
+        Some(selector) => params.fields(selector),
+        None => params,
+    }
+}
+
+// Namespace Access Commands
+// The current context's namespace filter, narrowed by the access profile; a missing or unreadable file counts as no filter
+async fn current_namespace_filter(state: &AppState) -> NamespaceFilter {
+    let context = state.current_context.read().await.clone();
+    let filter = match context.as_deref().map(|context| {
+        kuboard_namespace_filters_path().and_then(|path| kuboard_load_namespace_filter(&path, context))
+    }) {
+        Some(Ok(filter)) => filter,
+        Some(Err(e)) => {
+            warn!("Failed to load namespace filter for {}: {}", context.unwrap_or_default(), e);
+            NamespaceFilter::default()
+        }
+        None => NamespaceFilter::default(),
+    };
+    let profile = state.profile.read().await.clone();
+    kuboard_profile_namespace_filter(&profile, filter)
 }
 
-// Resource Describe Commands
 #[tauri::command]
-pub async fn kuboard_describe_pod(
-    pod_name: String,
-    namespace: String,
-    state: State<'_, AppState>
-) -> Result<serde_json::Value, String> {
-    info!("Describing pod: {}/{}", namespace, pod_name);
-    
+pub async fn kuboard_get_namespace_access(state: State<'_, AppState>) -> Result<NamespaceAccess, String> {
     let client_guard = state.current_client.read().await;
     let client = client_guard
         .as_ref()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    let pods_api: Api<Pod> = Api::namespaced(client.clone(), &namespace);
-    
-    match pods_api.get(&pod_name).await {
-        Ok(pod) => {
-            // Get pod events
-            let events = kuboard_fetch_pod_events(client, &pod_name, &namespace).await.unwrap_or_default();
-            
-            // Build describe output structure
-            let describe = json!({
-                "name": pod.metadata.name.as_ref().unwrap_or(&"Unknown".to_string()),
-                "namespace": pod.metadata.namespace.as_ref().unwrap_or(&"default".to_string()),
-                "labels": pod.metadata.labels.as_ref().unwrap_or(&std::collections::BTreeMap::new()),
-                "annotations": pod.metadata.annotations.as_ref().unwrap_or(&std::collections::BTreeMap::new()),
-                "status": {
-                    "phase": pod.status.as_ref().and_then(|s| s.phase.as_ref()).unwrap_or(&"Unknown".to_string()),
-                    "podIP": pod.status.as_ref().and_then(|s| s.pod_ip.as_ref()).unwrap_or(&"None".to_string()),
-                    "hostIP": pod.status.as_ref().and_then(|s| s.host_ip.as_ref()).unwrap_or(&"None".to_string()),
-                    "nodeName": pod.spec.as_ref().and_then(|s| s.node_name.as_ref()).unwrap_or(&"None".to_string()),
-                    "qosClass": pod.status.as_ref().and_then(|s| s.qos_class.as_ref()).unwrap_or(&"Unknown".to_string()),
-                    "startTime": pod.status.as_ref().and_then(|s| s.start_time.as_ref()).map(|t| t.0.to_rfc3339()).unwrap_or_else(|| "None".to_string()),
-                },
-                "conditions": pod.status.as_ref()
-                    .and_then(|s| s.conditions.as_ref())
-                    .map(|conditions| conditions.iter().map(|c| json!({
-                        "type": c.type_,
-                        "status": c.status,
-                        "reason": c.reason.as_ref().unwrap_or(&"None".to_string()),
-                        "message": c.message.as_ref().unwrap_or(&"None".to_string()),
-                        "lastTransitionTime": c.last_transition_time.as_ref().map(|t| t.0.to_rfc3339()).unwrap_or_else(|| "None".to_string()),
-                    })).collect::<Vec<_>>())
-                    .unwrap_or_default(),
-                "containers": pod.spec.as_ref()
-                    .map(|s| s.containers.iter().map(|c| {
-                        let status = pod.status.as_ref()
-                            .and_then(|s| s.container_statuses.as_ref())
-                            .and_then(|statuses| statuses.iter().find(|cs| cs.name == c.name));
-                        json!({
-                            "name": c.name,
-                            "image": c.image,
-                            "imagePullPolicy": c.image_pull_policy.as_ref().unwrap_or(&"IfNotPresent".to_string()),
-                            "resources": c.resources.as_ref().map(|r| json!({
-                                "requests": r.requests.as_ref().map(|reqs| reqs.iter().map(|(k, v)| (k, v.0.clone())).collect::<std::collections::BTreeMap<_, _>>()).unwrap_or_default(),
-                                "limits": r.limits.as_ref().map(|lims| lims.iter().map(|(k, v)| (k, v.0.clone())).collect::<std::collections::BTreeMap<_, _>>()).unwrap_or_default(),
-                            })),
-                            "ports": c.ports.as_ref().map(|ports| ports.iter().map(|p| json!({
-                                "name": p.name.as_ref().unwrap_or(&"None".to_string()),
-                                "containerPort": p.container_port,
-                                "protocol": p.protocol.as_ref().unwrap_or(&"TCP".to_string()),
-                            })).collect::<Vec<_>>()).unwrap_or_default(),
-                            "env": c.env.as_ref().map(|envs| envs.iter().map(|e| {
-                                let mut env_json = serde_json::Map::new();
-                                env_json.insert("name".to_string(), json!(e.name));
-                                env_json.insert("value".to_string(), json!(e.value.as_ref().unwrap_or(&"None".to_string())));
-                                if let Some(vf) = e.value_from.as_ref() {
-                                    let mut value_from_json = serde_json::Map::new();
-                                    if let Some(fr) = vf.field_ref.as_ref() {
-                                        let mut field_ref_json: serde_json::Map<String, serde_json::Value> = serde_json::Map::new();
-                                        field_ref_json.insert("fieldPath".to_string(), serde_json::Value::String(fr.field_path.clone()));
-                                        value_from_json.insert("fieldRef".to_string(), serde_json::Value::Object(field_ref_json));
-                                    }
-                                    env_json.insert("valueFrom".to_string(), json!(value_from_json));
-                                }
-                                json!(env_json)
-                            }).collect::<Vec<_>>()).unwrap_or_default(),
-                            "status": status.map(|s| json!({
-                                "ready": s.ready,
-                                "restartCount": s.restart_count,
-                                "state": {
-                                    "running": s.state.as_ref().and_then(|st| st.running.as_ref()).map(|_| json!({"startedAt": "Running"})),
-                                    "waiting": s.state.as_ref().and_then(|st| st.waiting.as_ref()).map(|w| json!({
-                                        "reason": w.reason.as_ref().unwrap_or(&"None".to_string()),
-                                        "message": w.message.as_ref().unwrap_or(&"None".to_string()),
-                                    })),
-                                    "terminated": s.state.as_ref().and_then(|st| st.terminated.as_ref()).map(|t| json!({
-                                        "reason": t.reason.as_ref().unwrap_or(&"None".to_string()),
-                                        "exitCode": t.exit_code,
-                                        "startedAt": t.started_at.as_ref().map(|dt| dt.0.to_rfc3339()).unwrap_or_else(|| "None".to_string()),
-                                        "finishedAt": t.finished_at.as_ref().map(|dt| dt.0.to_rfc3339()).unwrap_or_else(|| "None".to_string()),
-                                    })),
-                                },
-                            })).unwrap_or(json!({})),
-                        })
-                    }).collect::<Vec<_>>())
-                    .unwrap_or_default(),
-                "volumes": pod.spec.as_ref()
-                    .and_then(|s| s.volumes.as_ref())
-                    .map(|volumes| volumes.iter().map(|v| json!({
-                        "name": v.name,
-                        "type": if v.config_map.is_some() { "ConfigMap" } 
-                               else if v.secret.is_some() { "Secret" }
-                               else if v.persistent_volume_claim.is_some() { "PVC" }
-                               else if v.empty_dir.is_some() { "EmptyDir" }
-                               else { "Other" },
-                    })).collect::<Vec<_>>())
-                    .unwrap_or_default(),
-                "tolerations": pod.spec.as_ref()
-                    .and_then(|s| s.tolerations.as_ref())
-                    .map(|tolerations| tolerations.iter().map(|t| json!({
-                        "key": t.key.as_ref().unwrap_or(&"".to_string()),
-                        "operator": t.operator.as_ref().unwrap_or(&"Equal".to_string()),
-                        "value": t.value.as_ref().unwrap_or(&"None".to_string()),
-                        "effect": t.effect.as_ref().unwrap_or(&"None".to_string()),
-                        "tolerationSeconds": t.toleration_seconds,
-                    })).collect::<Vec<_>>())
-                    .unwrap_or_default(),
-                "events": events.iter().map(|e| json!({
-                    "type": e.type_,
-                    "reason": e.reason,
-                    "message": e.message,
-                    "count": e.count,
-                    "firstTimestamp": e.first_timestamp.as_deref().unwrap_or("None"),
-                    "lastTimestamp": e.last_timestamp.as_deref().unwrap_or("None"),
-                })).collect::<Vec<_>>(),
-                "metadata": {
-                    "uid": pod.metadata.uid.as_ref().unwrap_or(&"None".to_string()),
-                    "resourceVersion": pod.metadata.resource_version.as_ref().unwrap_or(&"None".to_string()),
-                    "creationTimestamp": pod.metadata.creation_timestamp.as_ref().map(|t| t.0.to_rfc3339()).unwrap_or_else(|| "None".to_string()),
-                    "generation": pod.metadata.generation.unwrap_or(0),
-                },
-            });
-            
-            info!("✅ Successfully described pod: {}/{}", namespace, pod_name);
-            Ok(describe)
-        }
-        Err(kube::Error::Api(e)) if e.code == 404 => {
-            Err(format!("Pod {}/{} not found", namespace, pod_name))
+    let filter = current_namespace_filter(&state).await;
+    match kuboard_discover_namespaces(client, &filter).await {
+        Ok(access) => {
+            info!("✅ {} accessible namespaces (source: {})", access.namespaces.len(), access.source);
+            Ok(access)
         }
         Err(e) => {
-            error!("Failed to describe pod {}/{}: {}", namespace, pod_name, e);
-            Err(format!("Failed to describe pod: {}", e))
+            error!("Failed to discover accessible namespaces: {}", e);
+            Err(format!("Failed to discover accessible namespaces: {}", e))
         }
     }
 }
 
-// Pod Exec Commands
-// Note: Full exec implementation requires WebSocket support for bidirectional streaming
-// This creates a session that can be used for streaming
 #[tauri::command]
-pub async fn kuboard_exec_into_pod(
-    pod_name: String,
-    namespace: String,
-    container_name: Option<String>,
-    command: Option<Vec<String>>,
-    tty: Option<bool>,
+pub async fn kuboard_get_namespace_filter(state: State<'_, AppState>) -> Result<NamespaceFilter, String> {
+    let context = state.current_context.read().await.clone()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let path = kuboard_namespace_filters_path().map_err(|e| format!("Failed to load namespace filter: {}", e))?;
+    kuboard_load_namespace_filter(&path, &context)
+        .map_err(|e| format!("Failed to load namespace filter: {}", e))
+}
+
+#[tauri::command]
+pub async fn kuboard_set_namespace_filter(
+    filter: NamespaceFilter,
     state: State<'_, AppState>
-) -> Result<serde_json::Value, String> {
-    info!("Exec into pod: {}/{} (container: {:?})", namespace, pod_name, container_name);
-    
-    let client_guard = state.current_client.read().await;
-    let client = client_guard
-        .as_ref()
+) -> Result<NamespaceFilter, String> {
+    let context = state.current_context.read().await.clone()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    // Start exec session
-    let session = start_exec_session(
-        client,
-        &pod_name,
-        &namespace,
-        container_name.as_deref(),
-        command.clone(),
-        tty.unwrap_or(true),
-    ).await.map_err(|e| format!("Failed to start exec session: {}", e))?;
+    let path = kuboard_namespace_filters_path().map_err(|e| format!("Failed to save namespace filter: {}", e))?;
+    let saved = kuboard_save_namespace_filter(&path, &context, &filter)
+        .map_err(|e| format!("Failed to save namespace filter: {}", e))?;
+    // Prefetched lists were built with the old filter
+    state.prefetch.write().await.clear();
+    info!("✅ Saved namespace filter for context {}: {} allowed, {} denied", context, saved.allow.len(), saved.deny.len());
+    Ok(saved)
+}
 
-    // Store session in app state
-    {
-        let mut sessions = state.exec_sessions.write().await;
-        sessions.insert(session.session_id.clone(), session.clone());
-    }
+// Access Profile Commands
+#[tauri::command]
+pub async fn kuboard_get_profile(state: State<'_, AppState>) -> Result<KuboardProfile, String> {
+    Ok(state.profile.read().await.clone())
+}
 
-    // TODO: Implement WebSocket streaming
-    // For now, return session info
-    // The frontend will need to connect to a streaming endpoint
-    
-    Ok(json!({
-        "sessionId": session.session_id,
-        "podName": session.pod_name,
-        "namespace": session.namespace,
-        "containerName": session.container_name,
-        "status": "connected",
-        "message": "Exec session created. Full streaming support coming soon."
-    }))
+// Blocked in the developer profile, so a developer build can't widen its own access
+#[tauri::command]
+pub async fn kuboard_set_profile(
+    profile: KuboardProfile,
+    state: State<'_, AppState>
+) -> Result<KuboardProfile, String> {
+    let path = kuboard_profile_path().map_err(|e| format!("Failed to save profile: {}", e))?;
+    let saved = kuboard_save_profile(&path, &profile).map_err(|e| format!("Failed to save profile: {}", e))?;
+    *state.profile.write().await = saved.clone();
+    state.prefetch.write().await.clear();
+    info!("✅ Switched to the {:?} profile ({} namespaces)", saved.kind, saved.namespaces.len());
+    Ok(saved)
 }
 
-// Port Forwarding Commands
-// Note: Port forwarding requires WebSocket support for data streaming
-// This is a placeholder that will be enhanced with proper WebSocket integration
+// API Rate Limit Commands
+#[tauri::command]
+pub async fn kuboard_get_rate_limit(state: State<'_, AppState>) -> Result<RateLimitSettings, String> {
+    let context = state.current_context.read().await.clone()
+        .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
+
+    let path = kuboard_rate_limits_path().map_err(|e| format!("Failed to load rate limit: {}", e))?;
+    kuboard_load_rate_limit(&path, &context).map_err(|e| format!("Failed to load rate limit: {}", e))
+}
 
+// Saves the context's limit and reconnects so it applies to the client in use
 #[tauri::command]
-pub async fn kuboard_port_forward(
-    resource_type: String, // "pod" or "service"
-    resource_name: String,
-    namespace: String,
-    local_port: u16,
-    remote_port: u16,
-    container_name: Option<String>,
+pub async fn kuboard_set_rate_limit(
+    settings: RateLimitSettings,
     state: State<'_, AppState>
-) -> Result<serde_json::Value, String> {
-    info!("Port forward: {} {}:{}/{} -> localhost:{}", resource_type, namespace, resource_name, remote_port, local_port);
-    
-    let client_guard = state.current_client.read().await;
-    let client = client_guard
-        .as_ref()
+) -> Result<RateLimitSettings, String> {
+    let context = state.current_context.read().await.clone()
         .ok_or_else(|| "No active context. Please set a context first.".to_string())?;
 
-    // Start port forward session
-    let session = start_port_forward_session(
-        client,
-        &resource_type,
-        &resource_name,
-        &namespace,
-        local_port,
-        remote_port,
-        container_name.as_deref(),
-    ).await.map_err(|e| format!("Failed to start port forward: {}", e))?;
+    let path = kuboard_rate_limits_path().map_err(|e| format!("Failed to save rate limit: {}", e))?;
+    let saved = kuboard_save_rate_limit(&path, &context, &settings)
+        .map_err(|e| format!("Failed to save rate limit: {}", e))?;
 
-    // Store session in app state
-    {
-        let mut sessions = state.port_forward_sessions.write().await;
-        sessions.insert(session.session_id.clone(), session.clone());
+    let kubeconfig = state.kubeconfig.read().await;
+    if let Some(kubeconfig) = kubeconfig.as_ref() {
+        let client = kuboard_create_client_from_context(kubeconfig, &context).await
+            .map_err(|e| format!("Saved rate limit, but failed to reconnect: {}", e))?;
+        *state.current_client.write().await = Some(client.clone());
+        state.client_keepalive.write().await.start(client, KEEPALIVE_INTERVAL);
     }
+    info!("✅ Rate limit for {} set to {} QPS, burst {}", context, saved.qps, saved.burst);
+    Ok(saved)
+}
 
-    // TODO: Implement WebSocket streaming and TCP forwarding
-    // For now, return session info
-    // The actual port forwarding will be implemented with WebSocket
-    
-    Ok(json!({
-        "sessionId": session.session_id,
-        "resourceType": session.resource_type,
-        "resourceName": session.resource_name,
-        "namespace": session.namespace,
-        "localPort": session.local_port,
-        "remotePort": session.remote_port,
-        "url": session.url(),
-        "status": "connecting",
-        "message": "Port forward session created. Full forwarding support coming soon."
-    }))
+// Formatting Commands
+#[tauri::command]
+pub async fn kuboard_get_format_settings(state: State<'_, AppState>) -> Result<FormatSettings, String> {
+    Ok(state.format_settings.read().await.clone())
 }
 
+// Units and decimal separator for the formatted strings in metrics summaries, until the app closes
 #[tauri::command]
-pub async fn kuboard_list_port_forwards(
+pub async fn kuboard_set_format_settings(
+    settings: FormatSettings,
     state: State<'_, AppState>
-) -> Result<Vec<serde_json::Value>, String> {
-    info!("Listing active port forwards");
-    
-    let sessions = state.port_forward_sessions.read().await;
-    let forwards: Vec<serde_json::Value> = sessions.values().map(|session| {
-        json!({
-            "id": session.session_id,
-            "podName": if session.resource_type == "pod" { Some(&session.resource_name) } else { None },
-            "serviceName": if session.resource_type == "service" { Some(&session.resource_name) } else { None },
-            "namespace": session.namespace,
-            "localPort": session.local_port,
-            "remotePort": session.remote_port,
-            "url": session.url(),
-            "status": "active"
-        })
-    }).collect();
-    
-    Ok(forwards)
+) -> Result<FormatSettings, String> {
+    kuboard_validate_format_settings(&settings).map_err(|e| format!("Invalid format settings: {}", e))?;
+    *state.format_settings.write().await = settings.clone();
+    info!("✅ Format settings: {:?} memory units, {:?} CPU units, decimal separator {:?}", settings.memory_units, settings.cpu_units, settings.decimal_separator);
+    Ok(settings)
 }
 
+// Capability Discovery Commands
 #[tauri::command]
-pub async fn kuboard_stop_port_forward(
-    forward_id: String,
+pub async fn kuboard_get_capabilities(state: State<'_, AppState>) -> Result<BackendCapabilities, String> {
+    let client_guard = state.current_client.read().await;
+
+    // Capabilities are still reported without an active context, just without cluster features
+    let metrics_available = match client_guard.as_ref() {
+        Some(client) => kuboard_check_metrics_server_availability(client).await.unwrap_or(false),
+        None => false,
+    };
+
+    // Commands the profile blocks are hidden so the frontend doesn't offer them
+    let profile = state.profile.read().await.clone();
+    Ok(BackendCapabilities {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        api_version: crate::KUBOARD_API_VERSION,
+        commands: crate::KUBOARD_COMMANDS.iter()
+            .filter(|c| kuboard_profile_allows_command(&profile, c))
+            .map(|c| c.to_string())
+            .collect(),
+        profile,
+        features: BackendFeatures {
+            metrics_available,
+            helm_support: false,
+        },
+    })
+}
+
+// kubectl Command Generation
+#[tauri::command]
+pub async fn kuboard_get_kubectl_command(
+    action: KubectlAction,
     state: State<'_, AppState>
 ) -> Result<String, String> {
-    info!("Stopping port forward: {}", forward_id);
-    
-    let mut sessions = state.port_forward_sessions.write().await;
-    
-    if let Some(session) = sessions.remove(&forward_id) {
-        info!("Stopped port forward session: {} ({}:{}/{} -> localhost:{})", 
-              forward_id, session.namespace, session.resource_name, session.remote_port, session.local_port);
-        
-        // TODO: Close WebSocket connection and TCP listener
-        // For now, just remove from state
-        
-        Ok(format!("Port forward {} stopped", forward_id))
-    } else {
-        Err(format!("Port forward {} not found", forward_id))
-    }
-}
\ No newline at end of file
+    // Works without a client so the command can be shown even when the cluster is unreachable
+    let context = state.current_context.read().await.clone();
+    Ok(kuboard_kubectl_command(&action, context.as_deref()))
+}
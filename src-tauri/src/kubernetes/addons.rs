@@ -0,0 +1,209 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Cluster Addon Inventory
+// Recognises common addons (DNS, metrics, ingress, certificates, CNI, CSI) by their container
+// images and reports each one's version and readiness
+
+use anyhow::Result;
+use k8s_openapi::api::apps::v1::{DaemonSet, Deployment};
+use k8s_openapi::api::core::v1::PodSpec;
+use k8s_openapi::api::storage::v1::CSIDriver;
+use kube::api::ListParams;
+use kube::{Api, Client};
+use tracing::warn;
+
+use crate::kubernetes::namespace_access::kuboard_is_forbidden;
+use crate::types::ClusterAddon;
+
+// (addon, category, image fragment); the first match wins, so more specific fragments come first
+const KNOWN_ADDONS: &[(&str, &str, &str)] = &[
+    ("metrics-server", "metrics", "metrics-server"),
+    ("CoreDNS", "dns", "coredns"),
+    ("kube-dns", "dns", "k8s-dns-kube-dns"),
+    ("kube-proxy", "proxy", "kube-proxy"),
+    ("cert-manager", "certificates", "cert-manager-controller"),
+    ("ingress-nginx", "ingress", "ingress-nginx/controller"),
+    ("Traefik", "ingress", "traefik:"),
+    ("HAProxy Ingress", "ingress", "haproxy-ingress"),
+    ("Calico", "network", "calico/node"),
+    ("Cilium", "network", "cilium/cilium"),
+    ("Flannel", "network", "flannel"),
+    ("Weave Net", "network", "weave-kube"),
+    ("AWS VPC CNI", "network", "amazon-k8s-cni"),
+    ("kindnet", "network", "kindnetd"),
+    ("Antrea", "network", "antrea-agent"),
+    ("kube-router", "network", "kube-router"),
+    ("AWS EBS CSI driver", "storage", "aws-ebs-csi-driver"),
+    ("AWS EFS CSI driver", "storage", "aws-efs-csi-driver"),
+    ("GCE PD CSI driver", "storage", "gcp-compute-persistent-disk-csi-driver"),
+    ("Azure Disk CSI driver", "storage", "azuredisk-csi"),
+    ("Azure File CSI driver", "storage", "azurefile-csi"),
+    ("Longhorn", "storage", "longhorn-manager"),
+    ("local-path-provisioner", "storage", "local-path-provisioner"),
+];
+
+// CSIDriver names of the storage addons above
+const CSI_DRIVER_ADDONS: &[(&str, &str)] = &[
+    ("ebs.csi.aws.com", "AWS EBS CSI driver"),
+    ("efs.csi.aws.com", "AWS EFS CSI driver"),
+    ("pd.csi.storage.gke.io", "GCE PD CSI driver"),
+    ("disk.csi.azure.com", "Azure Disk CSI driver"),
+    ("file.csi.azure.com", "Azure File CSI driver"),
+    ("driver.longhorn.io", "Longhorn"),
+];
+
+// The tag of an image reference, ignoring a registry port and any digest
+fn image_tag(image: &str) -> Option<String> {
+    let without_digest = image.split('@').next().unwrap_or(image);
+    let name = without_digest.rsplit('/').next().unwrap_or(without_digest);
+    name.rsplit_once(':').map(|(_, tag)| tag.to_string())
+}
+
+pub fn kuboard_match_addon(image: &str) -> Option<(&'static str, &'static str)> {
+    let image = image.to_lowercase();
+    KNOWN_ADDONS.iter()
+        .find(|(_, _, fragment)| image.contains(fragment))
+        .map(|(name, category, _)| (*name, *category))
+}
+
+fn workload_addon(
+    kind: &str,
+    name: Option<&String>,
+    namespace: Option<&String>,
+    spec: Option<&PodSpec>,
+    ready: i32,
+    desired: i32,
+) -> Option<ClusterAddon> {
+    let (addon, category, image) = spec?.containers.iter()
+        .filter_map(|c| c.image.as_deref())
+        .find_map(|image| kuboard_match_addon(image).map(|(addon, category)| (addon, category, image)))?;
+    Some(ClusterAddon {
+        name: addon.to_string(),
+        category: category.to_string(),
+        namespace: namespace.cloned(),
+        workload_kind: Some(kind.to_string()),
+        workload_name: name.cloned(),
+        image: Some(image.to_string()),
+        version: image_tag(image),
+        ready,
+        desired,
+        healthy: desired > 0 && ready >= desired,
+    })
+}
+
+pub fn kuboard_detect_addons(deployments: &[Deployment], daemonsets: &[DaemonSet], csi_drivers: &[CSIDriver]) -> Vec<ClusterAddon> {
+    let mut addons: Vec<ClusterAddon> = Vec::new();
+    for deployment in deployments {
+        let status = deployment.status.as_ref();
+        addons.extend(workload_addon(
+            "Deployment",
+            deployment.metadata.name.as_ref(),
+            deployment.metadata.namespace.as_ref(),
+            deployment.spec.as_ref().and_then(|s| s.template.spec.as_ref()),
+            status.and_then(|s| s.ready_replicas).unwrap_or(0),
+            deployment.spec.as_ref().and_then(|s| s.replicas).unwrap_or(1),
+        ));
+    }
+    for daemonset in daemonsets {
+        let status = daemonset.status.as_ref();
+        addons.extend(workload_addon(
+            "DaemonSet",
+            daemonset.metadata.name.as_ref(),
+            daemonset.metadata.namespace.as_ref(),
+            daemonset.spec.as_ref().and_then(|s| s.template.spec.as_ref()),
+            status.map_or(0, |s| s.number_ready),
+            status.map_or(0, |s| s.desired_number_scheduled),
+        ));
+    }
+
+    // CSI drivers with no recognised node plugin, e.g. ones a managed cluster runs off-cluster
+    for driver in csi_drivers {
+        let driver_name = driver.metadata.name.clone().unwrap_or_default();
+        let addon = CSI_DRIVER_ADDONS.iter()
+            .find(|(name, _)| *name == driver_name)
+            .map_or(driver_name.as_str(), |(_, addon)| addon);
+        if addons.iter().any(|a| a.name == addon) {
+            continue;
+        }
+        addons.push(ClusterAddon {
+            name: addon.to_string(),
+            category: "storage".to_string(),
+            namespace: None,
+            workload_kind: Some("CSIDriver".to_string()),
+            workload_name: Some(driver_name.clone()),
+            image: None,
+            version: None,
+            ready: 0,
+            desired: 0,
+            // Registration is all the CSIDriver object tells us
+            healthy: true,
+        });
+    }
+
+    addons.sort_by(|a, b| (&a.category, &a.name).cmp(&(&b.category, &b.name)));
+    addons
+}
+
+// Lists cluster-wide, or just kube-system when the user can't list every namespace
+pub async fn kuboard_list_cluster_addons(client: &Client) -> Result<Vec<ClusterAddon>> {
+    let params = ListParams::default();
+    let (deployments, daemonsets) = match Api::<Deployment>::all(client.clone()).list(&params).await {
+        Ok(list) => (list.items, Api::<DaemonSet>::all(client.clone()).list(&params).await?.items),
+        Err(e) if kuboard_is_forbidden(&e) => (
+            Api::<Deployment>::namespaced(client.clone(), "kube-system").list(&params).await?.items,
+            Api::<DaemonSet>::namespaced(client.clone(), "kube-system").list(&params).await?.items,
+        ),
+        Err(e) => return Err(e.into()),
+    };
+    let csi_drivers = match Api::<CSIDriver>::all(client.clone()).list(&params).await {
+        Ok(list) => list.items,
+        Err(e) => {
+            warn!("Failed to list CSI drivers: {}", e);
+            Vec::new()
+        }
+    };
+    Ok(kuboard_detect_addons(&deployments, &daemonsets, &csi_drivers))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_detect_addons() {
+        let coredns: Deployment = serde_json::from_value(json!({
+            "metadata": { "name": "coredns", "namespace": "kube-system" },
+            "spec": {
+                "replicas": 2,
+                "selector": {},
+                "template": { "spec": { "containers": [{ "name": "coredns", "image": "registry.k8s.io/coredns/coredns:v1.11.1" }] } },
+            },
+            "status": { "readyReplicas": 1 },
+        })).unwrap();
+        let cilium: DaemonSet = serde_json::from_value(json!({
+            "metadata": { "name": "cilium", "namespace": "kube-system" },
+            "spec": {
+                "selector": {},
+                "template": { "spec": { "containers": [{ "name": "agent", "image": "quay.io/cilium/cilium:v1.15.4@sha256:abc" }] } },
+            },
+            "status": { "desiredNumberScheduled": 3, "numberReady": 3, "currentNumberScheduled": 3, "numberMisscheduled": 0 },
+        })).unwrap();
+        let driver: CSIDriver = serde_json::from_value(json!({
+            "metadata": { "name": "pd.csi.storage.gke.io" },
+            "spec": {},
+        })).unwrap();
+
+        let addons = kuboard_detect_addons(&[coredns], &[cilium], &[driver]);
+
+        let summary: Vec<(&str, &str, Option<&str>, bool)> = addons.iter()
+            .map(|a| (a.category.as_str(), a.name.as_str(), a.version.as_deref(), a.healthy))
+            .collect();
+        assert_eq!(summary, vec![
+            ("dns", "CoreDNS", Some("v1.11.1"), false),
+            ("network", "Cilium", Some("v1.15.4"), true),
+            ("storage", "GCE PD CSI driver", None, true),
+        ]);
+    }
+}
@@ -0,0 +1,145 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Generic Apply
+// The kubectl apply equivalent: any manifest, built-in kind or custom resource, is resolved through
+// API discovery and server-side applied
+
+use anyhow::{anyhow, Result};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use kube::api::{DynamicObject, GroupVersionKind, Patch, PatchParams};
+use kube::discovery::{pinned_kind, Scope};
+use kube::{Api, Client};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::kubernetes::conflict::kuboard_changed_fields;
+use crate::types::{AppliedManifest, ApplyOutcome};
+
+pub const FIELD_MANAGER: &str = "kuboard";
+
+// Server-side apply parameters for the Kuboard field manager; `force` takes over fields other
+// managers own instead of failing with a conflict, `dry_run` persists nothing
+pub fn kuboard_apply_params(force: bool, dry_run: bool) -> PatchParams {
+    let mut params = PatchParams::apply(FIELD_MANAGER);
+    params.force = force;
+    params.dry_run = dry_run;
+    params
+}
+
+// An edited object still carries the managedFields and resourceVersion it was read with. The API
+// server refuses apply requests with managedFields, and a resourceVersion would fail the apply
+// whenever anything else wrote since; field ownership decides conflicts instead
+pub fn kuboard_prepare_for_apply(metadata: &mut ObjectMeta) {
+    metadata.managed_fields = None;
+    metadata.resource_version = None;
+}
+
+// An object ready to apply, with where it will land
+pub struct ApplyTarget {
+    pub kind: String,
+    pub name: String,
+    // None for cluster-scoped kinds
+    pub namespace: Option<String>,
+    pub object: DynamicObject,
+    api: Api<DynamicObject>,
+}
+
+impl ApplyTarget {
+    // The object as it is in the cluster now, if it exists
+    pub async fn live(&self) -> Result<Option<DynamicObject>> {
+        Ok(self.api.get_opt(&self.name).await?)
+    }
+
+    // Applies `object` as is; a resourceVersion left on it makes the apply fail with a 409 when the
+    // live object has moved on
+    pub async fn apply(&self, params: &PatchParams) -> kube::Result<DynamicObject> {
+        self.api.patch(&self.name, params, &Patch::Apply(&self.object)).await
+    }
+}
+
+// Every object in a YAML stream or JSON document, with `kind: List` expanded into its items
+pub fn kuboard_parse_manifests(content: &str) -> Result<Vec<Value>> {
+    let documents: Vec<Value> = if content.trim_start().starts_with('{') {
+        vec![serde_json::from_str(content)?]
+    } else {
+        serde_yaml::Deserializer::from_str(content)
+            .map(Value::deserialize)
+            .collect::<Result<_, _>>()?
+    };
+
+    let mut objects = Vec::new();
+    for document in documents.into_iter().filter(|d| !d.is_null()) {
+        match document.get("items") {
+            Some(Value::Array(items)) if document["kind"] == "List" => objects.extend(items.iter().cloned()),
+            _ => objects.push(document),
+        }
+    }
+    Ok(objects)
+}
+
+// Looks the manifest's kind up through discovery; namespaced objects without a namespace go into `namespace`
+pub async fn kuboard_resolve_apply_target(client: &Client, manifest: &Value, namespace: &str) -> Result<ApplyTarget> {
+    let mut object: DynamicObject = serde_json::from_value(manifest.clone())?;
+    let types = object.types.clone().ok_or_else(|| anyhow!("Manifest has no apiVersion/kind"))?;
+    let name = object.metadata.name.clone().ok_or_else(|| anyhow!("{} manifest has no metadata.name", types.kind))?;
+    let gvk = GroupVersionKind::try_from(&types)?;
+    let (resource, capabilities) = pinned_kind(client, &gvk).await
+        .map_err(|e| anyhow!("Unknown kind {} in {}: {}", types.kind, types.api_version, e))?;
+
+    kuboard_prepare_for_apply(&mut object.metadata);
+    let api = if capabilities.scope == Scope::Namespaced {
+        let namespace = object.metadata.namespace.get_or_insert_with(|| namespace.to_string()).clone();
+        Api::namespaced_with(client.clone(), &namespace, &resource)
+    } else {
+        object.metadata.namespace = None;
+        Api::all_with(client.clone(), &resource)
+    };
+
+    Ok(ApplyTarget { kind: types.kind, name, namespace: object.metadata.namespace.clone(), object, api })
+}
+
+// A dry run reports the outcome the apply would have without persisting it
+pub async fn kuboard_apply_target(target: &ApplyTarget, force: bool, dry_run: bool) -> Result<AppliedManifest> {
+    let existing = target.live().await?;
+    let applied = target.apply(&kuboard_apply_params(force, dry_run)).await?;
+
+    // Compared by content rather than resourceVersion, which a dry run never bumps
+    let outcome = match existing {
+        None => ApplyOutcome::Created,
+        Some(existing) if kuboard_changed_fields(&serde_json::to_value(&existing)?, &serde_json::to_value(&applied)?).is_empty() => {
+            ApplyOutcome::Unchanged
+        }
+        Some(_) => ApplyOutcome::Configured,
+    };
+    Ok(AppliedManifest {
+        kind: target.kind.clone(),
+        name: target.name.clone(),
+        namespace: target.namespace.clone(),
+        outcome,
+    })
+}
+
+// Resolves and applies one manifest without forcing ownership
+pub async fn kuboard_apply_object(client: &Client, manifest: &Value, namespace: &str) -> Result<AppliedManifest> {
+    let target = kuboard_resolve_apply_target(client, manifest, namespace).await?;
+    kuboard_apply_target(&target, false, false).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_manifests() {
+        let stream = "apiVersion: v1\nkind: ConfigMap\nmetadata:\n  name: a\n---\n---\napiVersion: v1\nkind: List\nitems:\n- apiVersion: v1\n  kind: Secret\n  metadata:\n    name: b\n- apiVersion: apps/v1\n  kind: Deployment\n  metadata:\n    name: c\n";
+        let objects = kuboard_parse_manifests(stream).unwrap();
+        let kinds: Vec<&str> = objects.iter().map(|o| o["kind"].as_str().unwrap()).collect();
+        assert_eq!(kinds, vec!["ConfigMap", "Secret", "Deployment"]);
+
+        let json = kuboard_parse_manifests(r#"{ "apiVersion": "v1", "kind": "Service", "metadata": { "name": "web" } }"#).unwrap();
+        assert_eq!(json[0]["metadata"]["name"], "web");
+        assert!(kuboard_parse_manifests("").unwrap().is_empty());
+        assert!(kuboard_parse_manifests("kind: [").is_err());
+    }
+}
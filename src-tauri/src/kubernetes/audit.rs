@@ -0,0 +1,74 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Audit Log
+// Append-only JSON Lines record of mutations attempted against protected contexts
+
+use anyhow::{anyhow, Result};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::types::AuditEntry;
+
+pub fn kuboard_audit_log_path() -> Result<PathBuf> {
+    dirs::data_local_dir()
+        .map(|dir| dir.join("kuboard").join("audit.jsonl"))
+        .ok_or_else(|| anyhow!("Could not find local data directory"))
+}
+
+pub fn kuboard_append_audit_entry(path: &Path, entry: &AuditEntry) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", serde_json::to_string(entry)?)?;
+    Ok(())
+}
+
+// Newest first; a line that can't be parsed (e.g. cut short by a crash) is skipped
+pub fn kuboard_read_audit_log(path: &Path, context: Option<&str>, limit: usize) -> Result<Vec<AuditEntry>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    Ok(fs::read_to_string(path)?
+        .lines()
+        .rev()
+        .filter_map(|line| serde_json::from_str::<AuditEntry>(line).ok())
+        .filter(|entry| context.is_none_or(|c| entry.context == c))
+        .take(limit)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(context: &str, target: &str) -> AuditEntry {
+        AuditEntry {
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            context: context.to_string(),
+            action: "delete_pod".to_string(),
+            target: target.to_string(),
+            outcome: "confirmed".to_string(),
+            confirmation_token: None,
+        }
+    }
+
+    #[test]
+    fn test_audit_log_round_trip() {
+        let dir = std::env::temp_dir().join(format!("kuboard-audit-{}", uuid::Uuid::new_v4()));
+        let path = dir.join("audit.jsonl");
+
+        kuboard_append_audit_entry(&path, &entry("prod-eu", "shop/api-0")).unwrap();
+        kuboard_append_audit_entry(&path, &entry("staging", "shop/api-0")).unwrap();
+        kuboard_append_audit_entry(&path, &entry("prod-eu", "shop/api-1")).unwrap();
+        fs::write(&path, fs::read_to_string(&path).unwrap() + "{\"truncated").unwrap();
+
+        let entries = kuboard_read_audit_log(&path, Some("prod-eu"), 10).unwrap();
+        let targets: Vec<&str> = entries.iter().map(|e| e.target.as_str()).collect();
+        assert_eq!(targets, vec!["shop/api-1", "shop/api-0"]);
+        assert_eq!(kuboard_read_audit_log(&path, None, 1).unwrap().len(), 1);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
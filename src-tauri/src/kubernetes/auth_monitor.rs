@@ -0,0 +1,180 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Credential Expiry Monitor
+// Every client reports 401 responses here; the monitor reloads the kubeconfig and rebuilds the
+// client, and only asks the user to sign in again when that doesn't restore access
+
+use anyhow::{anyhow, Result};
+use http::{Request, Response, StatusCode};
+use kube::config::Kubeconfig;
+use kube::Client;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, OnceLock};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+use tokio::sync::broadcast;
+use tower::{Layer, Service};
+use tracing::{info, warn};
+
+use crate::app_state::AppState;
+use crate::kubernetes::keepalive::KEEPALIVE_INTERVAL;
+use crate::kubernetes::{kuboard_create_client_from_context, kuboard_load_kubeconfig};
+
+// A context whose refresh failed isn't retried for this long, so a burst of 401s prompts once
+pub const REFRESH_COOLDOWN: Duration = Duration::from_secs(30);
+
+// Payload of the "auth-required" event
+#[derive(Debug, Clone, Serialize)]
+pub struct AuthRequiredEvent {
+    pub context: String,
+    pub error: String,
+}
+
+fn auth_failures() -> &'static broadcast::Sender<String> {
+    static AUTH_FAILURES: OnceLock<broadcast::Sender<String>> = OnceLock::new();
+    AUTH_FAILURES.get_or_init(|| broadcast::channel(16).0)
+}
+
+// Context names of clients that received a 401
+pub fn kuboard_subscribe_auth_failures() -> broadcast::Receiver<String> {
+    auth_failures().subscribe()
+}
+
+#[derive(Clone)]
+pub struct AuthFailureLayer {
+    context: Arc<str>,
+}
+
+impl AuthFailureLayer {
+    pub fn new(context: &str) -> Self {
+        Self { context: Arc::from(context) }
+    }
+}
+
+impl<S> Layer<S> for AuthFailureLayer {
+    type Service = AuthFailure<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        AuthFailure { inner, context: self.context.clone() }
+    }
+}
+
+pub struct AuthFailure<S> {
+    inner: S,
+    context: Arc<str>,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for AuthFailure<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<ReqBody>) -> Self::Future {
+        let response = self.inner.call(request);
+        let context = self.context.clone();
+        Box::pin(async move {
+            let response = response.await?;
+            if response.status() == StatusCode::UNAUTHORIZED {
+                // No receivers just means the monitor isn't running (tests, CLI tools)
+                let _ = auth_failures().send(context.to_string());
+            }
+            Ok(response)
+        })
+    }
+}
+
+// Re-reads the kubeconfig so credentials renewed outside Kuboard (cloud CLI logins, rotated
+// tokens) are picked up, and checks the new client is actually accepted
+pub async fn kuboard_refresh_credentials(context: &str) -> Result<(Kubeconfig, Client)> {
+    let kubeconfig = kuboard_load_kubeconfig().await?;
+    let client = kuboard_create_client_from_context(&kubeconfig, context).await?;
+    client.apiserver_version().await
+        .map_err(|e| anyhow!("Credentials for {} are still rejected: {}", context, e))?;
+    Ok((kubeconfig, client))
+}
+
+// Swaps in the refreshed client if the context is still the active one
+pub async fn kuboard_apply_refreshed_client(state: &AppState, context: &str, kubeconfig: Kubeconfig, client: Client) -> bool {
+    if state.current_context.read().await.as_deref() != Some(context) {
+        return false;
+    }
+    *state.kubeconfig.write().await = Some(kubeconfig);
+    *state.current_client.write().await = Some(client.clone());
+    state.client_keepalive.write().await.start(client, KEEPALIVE_INTERVAL);
+    true
+}
+
+// Runs for the life of the app, handling 401s from whichever client is current
+pub fn kuboard_spawn_auth_monitor<R: Runtime>(app: AppHandle<R>) {
+    let mut failures = kuboard_subscribe_auth_failures();
+    tauri::async_runtime::spawn(async move {
+        let mut last_attempt: HashMap<String, Instant> = HashMap::new();
+        loop {
+            let context = match failures.recv().await {
+                Ok(context) => context,
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => break,
+            };
+            let state = app.state::<AppState>();
+            // 401s from a client that has since been replaced don't matter
+            if state.current_context.read().await.as_deref() != Some(context.as_str()) {
+                continue;
+            }
+            if last_attempt.get(&context).is_some_and(|at| at.elapsed() < REFRESH_COOLDOWN) {
+                continue;
+            }
+            last_attempt.insert(context.clone(), Instant::now());
+
+            warn!("Context {} returned 401, refreshing credentials", context);
+            match kuboard_refresh_credentials(&context).await {
+                Ok((kubeconfig, client)) => {
+                    if kuboard_apply_refreshed_client(&state, &context, kubeconfig, client).await {
+                        info!("✅ Refreshed credentials for {}", context);
+                        last_attempt.remove(&context);
+                        let _ = app.emit("auth-refreshed", serde_json::json!({ "context": context }));
+                    }
+                }
+                Err(e) => {
+                    warn!("Credential refresh for {} failed: {}", context, e);
+                    let _ = app.emit("auth-required", AuthRequiredEvent { context, error: e.to_string() });
+                }
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::Infallible;
+
+    #[tokio::test]
+    async fn test_unauthorized_responses_are_reported() {
+        let mut failures = kuboard_subscribe_auth_failures();
+        let service = tower::service_fn(|request: Request<()>| async move {
+            let status = if request.uri().path() == "/expired" { StatusCode::UNAUTHORIZED } else { StatusCode::OK };
+            Ok::<_, Infallible>(Response::builder().status(status).body(()).unwrap())
+        });
+        let mut service = AuthFailureLayer::new("laptop-cluster").layer(service);
+
+        service.call(Request::get("/version").body(()).unwrap()).await.unwrap();
+        assert!(failures.try_recv().is_err());
+
+        let response = service.call(Request::get("/expired").body(()).unwrap()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(failures.try_recv().unwrap(), "laptop-cluster");
+    }
+}
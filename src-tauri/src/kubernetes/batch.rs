@@ -0,0 +1,270 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Batch Job Wizard
+// Builds Jobs and CronJobs from a few structured fields, so batch tasks can be created without YAML
+
+use anyhow::{anyhow, Result};
+use k8s_openapi::api::batch::v1::{CronJob, CronJobSpec, Job, JobSpec, JobTemplateSpec};
+use k8s_openapi::api::core::v1::{Container, EnvVar, PodSpec, PodTemplateSpec};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use kube::api::PostParams;
+use kube::{Api, Client};
+use std::collections::BTreeMap;
+
+use crate::types::{CronJobCreateRequest, JobCreateRequest};
+
+// The job controller appends an 11 character suffix to CronJob names, and Job names must stay within 63
+const MAX_CRONJOB_NAME_LENGTH: usize = 52;
+const MAX_JOB_NAME_LENGTH: usize = 63;
+
+const CRON_MACROS: &[&str] = &["@yearly", "@annually", "@monthly", "@weekly", "@daily", "@midnight", "@hourly"];
+const MONTH_NAMES: &[&str] = &["jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec"];
+const DAY_NAMES: &[&str] = &["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+struct CronField {
+    name: &'static str,
+    min: u32,
+    max: u32,
+    // Names counted from `min`, e.g. "jan" is month 1
+    names: &'static [&'static str],
+    allows_question_mark: bool,
+}
+
+const CRON_FIELDS: [CronField; 5] = [
+    CronField { name: "minute", min: 0, max: 59, names: &[], allows_question_mark: false },
+    CronField { name: "hour", min: 0, max: 23, names: &[], allows_question_mark: false },
+    CronField { name: "day of month", min: 1, max: 31, names: &[], allows_question_mark: true },
+    CronField { name: "month", min: 1, max: 12, names: MONTH_NAMES, allows_question_mark: false },
+    // 7 is accepted as Sunday as well as 0
+    CronField { name: "day of week", min: 0, max: 7, names: DAY_NAMES, allows_question_mark: true },
+];
+
+fn parse_cron_value(field: &CronField, value: &str) -> Result<u32> {
+    let lower = value.to_lowercase();
+    let parsed = match field.names.iter().position(|name| *name == lower) {
+        Some(index) => index as u32 + field.min,
+        None => value.parse::<u32>().map_err(|_| anyhow!("Invalid {} value {}", field.name, value))?,
+    };
+    if parsed < field.min || parsed > field.max {
+        return Err(anyhow!("{} value {} is outside {}-{}", field.name, value, field.min, field.max));
+    }
+    Ok(parsed)
+}
+
+fn validate_cron_field(field: &CronField, text: &str) -> Result<()> {
+    for item in text.split(',') {
+        let (range, step) = match item.split_once('/') {
+            Some((range, step)) => (range, Some(step)),
+            None => (item, None),
+        };
+        if let Some(step) = step {
+            match step.parse::<u32>() {
+                Ok(step) if step > 0 => {}
+                _ => return Err(anyhow!("Invalid {} step {}", field.name, step)),
+            }
+        }
+        match range {
+            "*" => {}
+            "?" if field.allows_question_mark => {}
+            _ => match range.split_once('-') {
+                Some((start, end)) => {
+                    if parse_cron_value(field, start)? > parse_cron_value(field, end)? {
+                        return Err(anyhow!("Invalid {} range {}", field.name, range));
+                    }
+                }
+                None => {
+                    parse_cron_value(field, range)?;
+                }
+            },
+        }
+    }
+    Ok(())
+}
+
+// Accepts the standard five-field syntax and macros the CronJob controller understands
+pub fn kuboard_validate_cron_schedule(schedule: &str) -> Result<()> {
+    let schedule = schedule.trim();
+    if schedule.starts_with("TZ=") || schedule.starts_with("CRON_TZ=") {
+        return Err(anyhow!("Time zones in the schedule are not supported; set the time zone field instead"));
+    }
+    if schedule.starts_with('@') {
+        return if CRON_MACROS.contains(&schedule) {
+            Ok(())
+        } else {
+            Err(anyhow!("Unknown schedule macro {}", schedule))
+        };
+    }
+
+    let fields: Vec<&str> = schedule.split_whitespace().collect();
+    if fields.len() != CRON_FIELDS.len() {
+        return Err(anyhow!("Schedule must have 5 fields (minute hour day-of-month month day-of-week), got {}", fields.len()));
+    }
+    for (field, text) in CRON_FIELDS.iter().zip(fields) {
+        validate_cron_field(field, text)?;
+    }
+    Ok(())
+}
+
+// Lowercase alphanumerics and '-', starting and ending with an alphanumeric
+fn validate_name(name: &str, max_length: usize) -> Result<()> {
+    let valid_chars = name.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+    if name.is_empty() || !valid_chars || name.starts_with('-') || name.ends_with('-') {
+        return Err(anyhow!("Invalid name {}: use lowercase letters, digits and '-'", name));
+    }
+    if name.len() > max_length {
+        return Err(anyhow!("Name {} is longer than {} characters", name, max_length));
+    }
+    Ok(())
+}
+
+fn build_job_spec(request: &JobCreateRequest) -> Result<JobSpec> {
+    if request.image.trim().is_empty() {
+        return Err(anyhow!("An image is required"));
+    }
+    let restart_policy = request.restart_policy.clone().unwrap_or_else(|| "OnFailure".to_string());
+    if restart_policy != "OnFailure" && restart_policy != "Never" {
+        return Err(anyhow!("Restart policy must be OnFailure or Never, not {}", restart_policy));
+    }
+    if let Some(name) = request.env.keys().find(|name| name.is_empty() || name.contains('=')) {
+        return Err(anyhow!("Invalid environment variable name {:?}", name));
+    }
+
+    let labels = BTreeMap::from([("app".to_string(), request.name.clone())]);
+    let container = Container {
+        name: request.name.clone(),
+        image: Some(request.image.trim().to_string()),
+        command: (!request.command.is_empty()).then(|| request.command.clone()),
+        env: (!request.env.is_empty()).then(|| {
+            request.env.iter()
+                .map(|(name, value)| EnvVar { name: name.clone(), value: Some(value.clone()), ..Default::default() })
+                .collect()
+        }),
+        ..Default::default()
+    };
+
+    Ok(JobSpec {
+        backoff_limit: request.backoff_limit,
+        active_deadline_seconds: request.active_deadline_seconds,
+        template: PodTemplateSpec {
+            metadata: Some(ObjectMeta { labels: Some(labels), ..Default::default() }),
+            spec: Some(PodSpec {
+                containers: vec![container],
+                restart_policy: Some(restart_policy),
+                ..Default::default()
+            }),
+        },
+        ..Default::default()
+    })
+}
+
+pub fn kuboard_build_job(request: &JobCreateRequest) -> Result<Job> {
+    validate_name(&request.name, MAX_JOB_NAME_LENGTH)?;
+    Ok(Job {
+        metadata: ObjectMeta {
+            name: Some(request.name.clone()),
+            namespace: Some(request.namespace.clone()),
+            labels: Some(BTreeMap::from([("app".to_string(), request.name.clone())])),
+            ..Default::default()
+        },
+        spec: Some(build_job_spec(request)?),
+        ..Default::default()
+    })
+}
+
+pub fn kuboard_build_cronjob(request: &CronJobCreateRequest) -> Result<CronJob> {
+    let job = &request.job;
+    validate_name(&job.name, MAX_CRONJOB_NAME_LENGTH)?;
+    kuboard_validate_cron_schedule(&request.schedule)?;
+    if let Some(policy) = request.concurrency_policy.as_deref() {
+        if !["Allow", "Forbid", "Replace"].contains(&policy) {
+            return Err(anyhow!("Concurrency policy must be Allow, Forbid or Replace, not {}", policy));
+        }
+    }
+
+    let labels = BTreeMap::from([("app".to_string(), job.name.clone())]);
+    Ok(CronJob {
+        metadata: ObjectMeta {
+            name: Some(job.name.clone()),
+            namespace: Some(job.namespace.clone()),
+            labels: Some(labels.clone()),
+            ..Default::default()
+        },
+        spec: Some(CronJobSpec {
+            schedule: request.schedule.trim().to_string(),
+            time_zone: request.time_zone.clone().filter(|tz| !tz.trim().is_empty()),
+            concurrency_policy: request.concurrency_policy.clone(),
+            suspend: request.suspend,
+            job_template: JobTemplateSpec {
+                metadata: Some(ObjectMeta { labels: Some(labels), ..Default::default() }),
+                spec: Some(build_job_spec(job)?),
+            },
+            ..Default::default()
+        }),
+        ..Default::default()
+    })
+}
+
+// A dry run is validated by the API server (quota, admission, time zone) without creating anything
+fn post_params(dry_run: bool) -> PostParams {
+    PostParams { dry_run, ..Default::default() }
+}
+
+pub async fn kuboard_create_job_from_request(client: &Client, request: &JobCreateRequest, dry_run: bool) -> Result<Job> {
+    let job = kuboard_build_job(request)?;
+    let api: Api<Job> = Api::namespaced(client.clone(), &request.namespace);
+    Ok(api.create(&post_params(dry_run), &job).await?)
+}
+
+pub async fn kuboard_create_cronjob_from_request(client: &Client, request: &CronJobCreateRequest, dry_run: bool) -> Result<CronJob> {
+    let cronjob = kuboard_build_cronjob(request)?;
+    let api: Api<CronJob> = Api::namespaced(client.clone(), &request.job.namespace);
+    Ok(api.create(&post_params(dry_run), &cronjob).await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_cron_schedule() {
+        for schedule in ["*/5 * * * *", "0 2 * * 1-5", "30 9 1,15 * ?", "0 0 * jan-mar sun", "0 12 * * 7", "@daily"] {
+            assert!(kuboard_validate_cron_schedule(schedule).is_ok(), "{}", schedule);
+        }
+        for schedule in ["* * * *", "60 * * * *", "0 24 * * *", "*/0 * * * *", "0 0 0 * *", "5-1 * * * *", "@every 5m", "TZ=UTC 0 0 * * *", "0 0 * foo *"] {
+            assert!(kuboard_validate_cron_schedule(schedule).is_err(), "{}", schedule);
+        }
+    }
+
+    #[test]
+    fn test_build_cronjob() {
+        let request = CronJobCreateRequest {
+            job: JobCreateRequest {
+                name: "nightly-report".to_string(),
+                namespace: "reports".to_string(),
+                image: "ghcr.io/acme/report:1.0".to_string(),
+                command: vec!["report".to_string(), "--daily".to_string()],
+                env: BTreeMap::from([("LEVEL".to_string(), "info".to_string())]),
+                restart_policy: None,
+                backoff_limit: Some(2),
+                active_deadline_seconds: None,
+            },
+            schedule: "0 3 * * *".to_string(),
+            time_zone: Some("Europe/Berlin".to_string()),
+            concurrency_policy: Some("Forbid".to_string()),
+            suspend: None,
+        };
+
+        let spec = kuboard_build_cronjob(&request).unwrap().spec.unwrap();
+        assert_eq!(spec.concurrency_policy.as_deref(), Some("Forbid"));
+        let pod_spec = spec.job_template.spec.unwrap().template.spec.unwrap();
+        assert_eq!(pod_spec.restart_policy.as_deref(), Some("OnFailure"));
+        assert_eq!(pod_spec.containers[0].env.as_ref().unwrap()[0].name, "LEVEL");
+
+        let invalid = CronJobCreateRequest { concurrency_policy: Some("Sometimes".to_string()), ..request.clone() };
+        assert!(kuboard_build_cronjob(&invalid).is_err());
+        let long_name = JobCreateRequest { name: "a".repeat(53), ..request.job.clone() };
+        assert!(kuboard_build_cronjob(&CronJobCreateRequest { job: long_name.clone(), ..request }).is_err());
+        assert!(kuboard_build_job(&long_name).is_ok());
+    }
+}
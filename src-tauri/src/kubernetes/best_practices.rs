@@ -0,0 +1,199 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Best-Practices Scanner
+// Built-in configuration checks for workloads, scored per namespace
+
+use anyhow::Result;
+use kube::api::ListParams;
+use kube::{Api, Client};
+use k8s_openapi::api::core::v1::{Container, PodSpec, ServiceAccount};
+use std::collections::{BTreeMap, HashMap};
+
+use crate::kubernetes::service_accounts::kuboard_pod_service_account_name;
+use crate::kubernetes::workloads::{kuboard_list_workload_pod_specs, WorkloadPodSpec};
+use crate::types::{BestPracticeFinding, BestPracticeReport, FindingSeverity};
+
+// Weight of a check in the namespace score
+fn severity_weight(severity: FindingSeverity) -> u32 {
+    match severity {
+        FindingSeverity::Critical => 5,
+        FindingSeverity::Warning => 2,
+        FindingSeverity::Info => 1,
+    }
+}
+
+// Outcome of every check run against one workload, so passes count towards the score too
+pub struct CheckOutcome {
+    pub severity: FindingSeverity,
+    pub finding: Option<BestPracticeFinding>,
+}
+
+fn image_uses_latest(image: &str) -> bool {
+    if image.contains('@') {
+        return false; // pinned by digest
+    }
+    // An untagged image pulls latest
+    let name = image.rsplit('/').next().unwrap_or(image);
+    match name.rsplit_once(':') {
+        Some((_, tag)) => tag == "latest",
+        None => true,
+    }
+}
+
+fn containers(spec: &PodSpec) -> impl Iterator<Item = &Container> {
+    spec.init_containers.iter().flatten().chain(spec.containers.iter())
+}
+
+// Runs every check against a workload's pod spec
+pub fn kuboard_check_workload(workload: &WorkloadPodSpec, default_sa_automounts: bool) -> Vec<CheckOutcome> {
+    let mut outcomes = Vec::new();
+    let mut check = |check: &str, severity: FindingSeverity, container: Option<&str>, failed: Option<String>| {
+        outcomes.push(CheckOutcome {
+            severity,
+            finding: failed.map(|message| BestPracticeFinding {
+                check: check.to_string(),
+                severity,
+                kind: workload.kind.clone(),
+                name: workload.name.clone(),
+                container: container.map(str::to_string),
+                message,
+            }),
+        });
+    };
+    // Run-to-completion workloads aren't expected to have probes
+    let long_running = !matches!(workload.kind.as_str(), "Job" | "CronJob");
+
+    for container in containers(&workload.spec) {
+        let name = container.name.as_str();
+        let limits = container.resources.as_ref().and_then(|r| r.limits.as_ref());
+        let missing: Vec<&str> = ["cpu", "memory"].into_iter().filter(|r| !limits.is_some_and(|l| l.contains_key(*r))).collect();
+        check("resource-limits", FindingSeverity::Warning, Some(name),
+            (!missing.is_empty()).then(|| format!("No {} limit set", missing.join("/"))));
+
+        let image = container.image.as_deref().unwrap_or_default();
+        check("image-tag", FindingSeverity::Warning, Some(name),
+            image_uses_latest(image).then(|| format!("Image {} uses the latest tag", image)));
+
+        let privileged = container.security_context.as_ref().and_then(|c| c.privileged) == Some(true);
+        check("privileged", FindingSeverity::Critical, Some(name),
+            privileged.then(|| "Container runs privileged".to_string()));
+    }
+
+    if long_running {
+        for container in &workload.spec.containers {
+            check("readiness-probe", FindingSeverity::Warning, Some(&container.name),
+                container.readiness_probe.is_none().then(|| "No readiness probe".to_string()));
+            check("liveness-probe", FindingSeverity::Info, Some(&container.name),
+                container.liveness_probe.is_none().then(|| "No liveness probe".to_string()));
+        }
+    }
+
+    let uses_default = kuboard_pod_service_account_name(Some(&workload.spec)) == "default";
+    let automounts = workload.spec.automount_service_account_token.unwrap_or(default_sa_automounts);
+    check("default-service-account", FindingSeverity::Warning, None,
+        (uses_default && automounts).then(|| "Runs as the default service account with its token mounted".to_string()));
+
+    outcomes
+}
+
+// 100 when every check passes; failed checks subtract in proportion to their severity
+pub fn kuboard_best_practice_score(outcomes: &[CheckOutcome]) -> u32 {
+    let total: u32 = outcomes.iter().map(|o| severity_weight(o.severity)).sum();
+    if total == 0 {
+        return 100;
+    }
+    let failed: u32 = outcomes.iter().filter(|o| o.finding.is_some()).map(|o| severity_weight(o.severity)).sum();
+    ((total - failed) * 100 + total / 2) / total
+}
+
+pub async fn kuboard_scan_namespace_best_practices(client: &Client, namespace: Option<&str>) -> Result<Vec<BestPracticeReport>> {
+    let workloads = kuboard_list_workload_pod_specs(client, namespace).await?;
+
+    let sa_api: Api<ServiceAccount> = match namespace {
+        Some(namespace) => Api::namespaced(client.clone(), namespace),
+        None => Api::all(client.clone()),
+    };
+    // A default account without automountServiceAccountToken mounts its token into every pod
+    let default_sa_automounts: HashMap<String, bool> = sa_api
+        .list(&ListParams::default().fields("metadata.name=default"))
+        .await?
+        .items
+        .into_iter()
+        .map(|sa| (sa.metadata.namespace.unwrap_or_default(), sa.automount_service_account_token != Some(false)))
+        .collect();
+
+    let mut by_namespace: BTreeMap<String, (usize, Vec<CheckOutcome>)> = BTreeMap::new();
+    for workload in &workloads {
+        let automounts = default_sa_automounts.get(&workload.namespace).copied().unwrap_or(true);
+        let entry = by_namespace.entry(workload.namespace.clone()).or_default();
+        entry.0 += 1;
+        entry.1.extend(kuboard_check_workload(workload, automounts));
+    }
+
+    Ok(by_namespace
+        .into_iter()
+        .map(|(namespace, (workloads_scanned, outcomes))| BestPracticeReport {
+            namespace,
+            score: kuboard_best_practice_score(&outcomes),
+            workloads_scanned,
+            checks_run: outcomes.len(),
+            findings: outcomes.into_iter().filter_map(|o| o.finding).collect(),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn workload(kind: &str, spec: serde_json::Value) -> WorkloadPodSpec {
+        WorkloadPodSpec {
+            kind: kind.to_string(),
+            name: "app".to_string(),
+            namespace: "default".to_string(),
+            spec: serde_json::from_value(spec).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_check_workload_findings_and_score() {
+        let hardened = workload("Deployment", json!({
+            "serviceAccountName": "app",
+            "containers": [{
+                "name": "app",
+                "image": "ghcr.io/acme/app:1.4.2",
+                "resources": { "limits": { "cpu": "500m", "memory": "256Mi" } },
+                "readinessProbe": { "httpGet": { "path": "/ready", "port": 8080 } },
+                "livenessProbe": { "httpGet": { "path": "/live", "port": 8080 } },
+            }],
+        }));
+        let outcomes = kuboard_check_workload(&hardened, true);
+        assert!(outcomes.iter().all(|o| o.finding.is_none()));
+        assert_eq!(kuboard_best_practice_score(&outcomes), 100);
+
+        let careless = workload("Deployment", json!({
+            "containers": [{ "name": "app", "image": "nginx", "securityContext": { "privileged": true } }],
+        }));
+        let outcomes = kuboard_check_workload(&careless, true);
+        let checks: Vec<&str> = outcomes.iter().filter_map(|o| o.finding.as_ref()).map(|f| f.check.as_str()).collect();
+        assert_eq!(checks, vec!["resource-limits", "image-tag", "privileged", "readiness-probe", "liveness-probe", "default-service-account"]);
+        assert_eq!(kuboard_best_practice_score(&outcomes), 0);
+
+        // Jobs skip probe checks, and a default account without automount is fine
+        let job = workload("Job", json!({
+            "containers": [{ "name": "app", "image": "busybox:1.36", "resources": { "limits": { "cpu": "1", "memory": "1Gi" } } }],
+        }));
+        assert!(kuboard_check_workload(&job, false).iter().all(|o| o.finding.is_none()));
+    }
+
+    #[test]
+    fn test_image_uses_latest() {
+        assert!(image_uses_latest("nginx"));
+        assert!(image_uses_latest("registry:5000/nginx:latest"));
+        assert!(image_uses_latest("registry:5000/nginx"));
+        assert!(!image_uses_latest("registry:5000/nginx:1.27"));
+        assert!(!image_uses_latest("nginx@sha256:abc"));
+    }
+}
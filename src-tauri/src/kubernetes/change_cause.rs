@@ -0,0 +1,67 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Change Cause Recording
+// Stamps kubernetes.io/change-cause on objects Kuboard mutates, so rollout history shows which
+// action, context and user produced each revision
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use serde_json::Value;
+
+// The annotation `kubectl rollout history` prints; Deployments copy it onto their ReplicaSets
+pub const CHANGE_CAUSE_ANNOTATION: &str = "kubernetes.io/change-cause";
+
+// e.g. "kuboard scale deployment/web --replicas=5 (context kind-dev, user admin, 2025-01-01T00:00:00Z)"
+pub fn kuboard_change_cause(action: &str, context: Option<&str>, user: Option<&str>, at: DateTime<Utc>) -> String {
+    let mut details = Vec::new();
+    if let Some(context) = context {
+        details.push(format!("context {}", context));
+    }
+    if let Some(user) = user {
+        details.push(format!("user {}", user));
+    }
+    details.push(at.to_rfc3339_opts(SecondsFormat::Secs, true));
+    format!("kuboard {} ({})", action, details.join(", "))
+}
+
+pub fn kuboard_record_change_cause(metadata: &mut ObjectMeta, cause: &str) {
+    metadata.annotations
+        .get_or_insert_with(Default::default)
+        .insert(CHANGE_CAUSE_ANNOTATION.to_string(), cause.to_string());
+}
+
+// The same for a manifest that hasn't been typed yet; non-objects are left alone
+pub fn kuboard_record_change_cause_json(manifest: &mut Value, cause: &str) {
+    let Some(object) = manifest.as_object_mut() else { return };
+    let metadata = object.entry("metadata").or_insert_with(|| Value::Object(Default::default()));
+    let Some(metadata) = metadata.as_object_mut() else { return };
+    let annotations = metadata.entry("annotations").or_insert_with(|| Value::Object(Default::default()));
+    if let Some(annotations) = annotations.as_object_mut() {
+        annotations.insert(CHANGE_CAUSE_ANNOTATION.to_string(), Value::String(cause.to_string()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use serde_json::json;
+
+    #[test]
+    fn test_change_cause_annotation() {
+        let at = Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap();
+        let cause = kuboard_change_cause("scale deployment/web --replicas=5", Some("kind-dev"), Some("admin"), at);
+        assert_eq!(cause, "kuboard scale deployment/web --replicas=5 (context kind-dev, user admin, 2025-03-01T12:00:00Z)");
+        assert_eq!(kuboard_change_cause("restart daemonset/agent", None, None, at), "kuboard restart daemonset/agent (2025-03-01T12:00:00Z)");
+
+        let mut metadata = ObjectMeta::default();
+        kuboard_record_change_cause(&mut metadata, &cause);
+        assert_eq!(metadata.annotations.unwrap()[CHANGE_CAUSE_ANNOTATION], cause);
+
+        let mut manifest = json!({ "kind": "ConfigMap", "metadata": { "name": "settings", "annotations": { "team": "web" } } });
+        kuboard_record_change_cause_json(&mut manifest, &cause);
+        assert_eq!(manifest["metadata"]["annotations"][CHANGE_CAUSE_ANNOTATION], cause.as_str());
+        assert_eq!(manifest["metadata"]["annotations"]["team"], "web");
+    }
+}
@@ -0,0 +1,127 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Namespace Clone
+// Copies deployments, services and configmaps into a new namespace, e.g. for review environments
+
+use anyhow::{anyhow, Result};
+use kube::api::{ListParams, PostParams};
+use kube::{Api, Client, Resource};
+use k8s_openapi::NamespaceResourceScope;
+use k8s_openapi::api::apps::v1::Deployment;
+use k8s_openapi::api::core::v1::{ConfigMap, Namespace, Service};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::fmt::Debug;
+
+use crate::kubernetes::progress::ProgressReporter;
+use crate::kubernetes::manifests::{kuboard_clean_manifest, kuboard_rewrite_pod_template_images};
+use crate::types::{ClonedResource, NamespaceCloneResult};
+
+// Cloned in this order so configuration exists before the workloads that mount it
+pub const CLONEABLE_KINDS: &[&str] = &["ConfigMap", "Service", "Deployment"];
+
+// Published into every namespace by the control plane
+const SKIPPED_CONFIGMAPS: &[&str] = &["kube-root-ca.crt"];
+
+async fn clone_kind<K>(
+    client: &Client,
+    kind: &str,
+    source: &str,
+    target: &str,
+    image_tag: Option<&str>,
+) -> Result<Vec<ClonedResource>>
+where
+    K: Resource<DynamicType = (), Scope = NamespaceResourceScope> + Clone + Debug + Serialize + DeserializeOwned,
+{
+    let source_api: Api<K> = Api::namespaced(client.clone(), source);
+    let target_api: Api<K> = Api::namespaced(client.clone(), target);
+
+    let mut results = Vec::new();
+    for object in source_api.list(&ListParams::default()).await?.items {
+        let name = object.meta().name.clone().unwrap_or_default();
+        if kind == "ConfigMap" && SKIPPED_CONFIGMAPS.contains(&name.as_str()) {
+            continue;
+        }
+
+        let mut manifest = serde_json::to_value(&object)?;
+        kuboard_clean_manifest(&mut manifest);
+        manifest["metadata"]["namespace"] = Value::String(target.to_string());
+        if let Some(tag) = image_tag {
+            kuboard_rewrite_pod_template_images(&mut manifest, tag);
+        }
+
+        let created = match serde_json::from_value::<K>(manifest) {
+            Ok(cleaned) => target_api.create(&PostParams::default(), &cleaned).await.map_err(anyhow::Error::from),
+            Err(e) => Err(e.into()),
+        };
+        results.push(ClonedResource {
+            kind: kind.to_string(),
+            name,
+            success: created.is_ok(),
+            message: created.err().map(|e| e.to_string()),
+        });
+    }
+    Ok(results)
+}
+
+pub async fn kuboard_clone_namespace_resources(
+    client: &Client,
+    source: &str,
+    target: &str,
+    include_kinds: &[String],
+    image_tag: Option<&str>,
+    progress: &ProgressReporter,
+) -> Result<NamespaceCloneResult> {
+    if let Some(kind) = include_kinds.iter().find(|k| !CLONEABLE_KINDS.contains(&k.as_str())) {
+        return Err(anyhow!("Cloning {} is not supported", kind));
+    }
+    if source == target {
+        return Err(anyhow!("Source and target namespace must differ"));
+    }
+
+    let kinds: Vec<&str> = CLONEABLE_KINDS.iter().copied()
+        .filter(|k| include_kinds.is_empty() || include_kinds.iter().any(|i| i == k))
+        .collect();
+    // Creating the namespace counts as the first step
+    let total = kinds.len() + 1;
+    progress.report("namespace", 0, total, Some(format!("Creating namespace {}", target)));
+
+    let namespaces_api: Api<Namespace> = Api::all(client.clone());
+    namespaces_api.get(source).await
+        .map_err(|e| anyhow!("Source namespace {} not found: {}", source, e))?;
+
+    let namespace: Namespace = serde_json::from_value(json!({
+        "apiVersion": "v1",
+        "kind": "Namespace",
+        "metadata": {
+            "name": target,
+            "labels": { "kuboard.io/cloned-from": source },
+        },
+    }))?;
+    match namespaces_api.create(&PostParams::default(), &namespace).await {
+        Ok(_) => {}
+        // Refuse to merge into an existing namespace; names would collide with whatever lives there
+        Err(kube::Error::Api(e)) if e.code == 409 => return Err(anyhow!("Namespace {} already exists", target)),
+        Err(e) => return Err(e.into()),
+    }
+
+    let mut resources = Vec::new();
+    for (step, kind) in kinds.into_iter().enumerate() {
+        progress.report(kind, step + 1, total, Some(format!("Cloning {} resources", kind)));
+        let cloned = match kind {
+            "ConfigMap" => clone_kind::<ConfigMap>(client, kind, source, target, image_tag).await?,
+            "Service" => clone_kind::<Service>(client, kind, source, target, image_tag).await?,
+            _ => clone_kind::<Deployment>(client, kind, source, target, image_tag).await?,
+        };
+        resources.extend(cloned);
+    }
+    progress.finish(Some(format!("Cloned {} resources into {}", resources.len(), target)));
+
+    Ok(NamespaceCloneResult {
+        source: source.to_string(),
+        target: target.to_string(),
+        resources,
+    })
+}
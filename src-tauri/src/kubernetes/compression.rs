@@ -0,0 +1,109 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// IPC Payload Compression
+// Wraps large command responses in an envelope that names the encoding, so the webview
+// holds a compressed string instead of a multi-megabyte JSON document
+
+use anyhow::{anyhow, Result};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::Serialize;
+use std::io::{Read, Write};
+
+use crate::types::{CompressedPayload, PayloadEncoding};
+
+// Below this the envelope and decode step cost more than they save
+pub const DEFAULT_COMPRESSION_THRESHOLD: usize = 64 * 1024;
+const ZSTD_LEVEL: i32 = 3;
+
+fn compress(json: &[u8], encoding: PayloadEncoding) -> Result<Vec<u8>> {
+    match encoding {
+        PayloadEncoding::Identity => Ok(json.to_vec()),
+        PayloadEncoding::Gzip => {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(json)?;
+            Ok(encoder.finish()?)
+        }
+        PayloadEncoding::Zstd => Ok(zstd::encode_all(json, ZSTD_LEVEL)?),
+    }
+}
+
+// zstd is preferred when the caller accepts it; identity is used below the threshold,
+// when nothing is accepted, or when compression doesn't make the payload smaller
+pub fn kuboard_encode_payload<T: Serialize>(value: &T, accept: &[PayloadEncoding], threshold: usize) -> Result<CompressedPayload> {
+    let json = serde_json::to_vec(value)?;
+    let original_size = json.len();
+    let identity = |json: Vec<u8>| -> Result<CompressedPayload> {
+        Ok(CompressedPayload { encoding: PayloadEncoding::Identity, original_size, data: String::from_utf8(json)? })
+    };
+
+    let encoding = [PayloadEncoding::Zstd, PayloadEncoding::Gzip].into_iter().find(|e| accept.contains(e));
+    let Some(encoding) = encoding.filter(|_| original_size >= threshold) else {
+        return identity(json);
+    };
+    let compressed = compress(&json, encoding)?;
+    // base64 grows the data by a third, so compare against the encoded size
+    if compressed.len().div_ceil(3) * 4 >= original_size {
+        return identity(json);
+    }
+    Ok(CompressedPayload { encoding, original_size, data: STANDARD.encode(compressed) })
+}
+
+// The JSON document inside an envelope
+pub fn kuboard_decode_payload(payload: &CompressedPayload) -> Result<Vec<u8>> {
+    let json = match payload.encoding {
+        PayloadEncoding::Identity => return Ok(payload.data.clone().into_bytes()),
+        PayloadEncoding::Gzip => {
+            let mut json = Vec::with_capacity(payload.original_size);
+            GzDecoder::new(STANDARD.decode(&payload.data)?.as_slice()).read_to_end(&mut json)?;
+            json
+        }
+        PayloadEncoding::Zstd => zstd::decode_all(STANDARD.decode(&payload.data)?.as_slice())?,
+    };
+    if json.len() != payload.original_size {
+        return Err(anyhow!("Decoded {} bytes but the envelope says {}", json.len(), payload.original_size));
+    }
+    Ok(json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    fn pod_list() -> Value {
+        let pods: Vec<Value> = (0..500)
+            .map(|i| json!({ "metadata": { "name": format!("web-{}", i), "namespace": "default" }, "status": { "phase": "Running" } }))
+            .collect();
+        json!(pods)
+    }
+
+    #[test]
+    fn test_encode_payload_round_trip() {
+        let pods = pod_list();
+        for encoding in [PayloadEncoding::Gzip, PayloadEncoding::Zstd] {
+            let payload = kuboard_encode_payload(&pods, &[encoding], 1024).unwrap();
+            assert_eq!(payload.encoding, encoding);
+            assert!(payload.data.len() < payload.original_size);
+            let decoded: Value = serde_json::from_slice(&kuboard_decode_payload(&payload).unwrap()).unwrap();
+            assert_eq!(decoded, pods);
+        }
+
+        let both = kuboard_encode_payload(&pods, &[PayloadEncoding::Gzip, PayloadEncoding::Zstd], 1024).unwrap();
+        assert_eq!(both.encoding, PayloadEncoding::Zstd);
+    }
+
+    #[test]
+    fn test_small_payloads_stay_uncompressed() {
+        let small = kuboard_encode_payload(&json!({ "name": "web" }), &[PayloadEncoding::Zstd], 1024).unwrap();
+        assert_eq!(small.encoding, PayloadEncoding::Identity);
+        assert_eq!(small.data, r#"{"name":"web"}"#);
+
+        let unaccepted = kuboard_encode_payload(&pod_list(), &[], 0).unwrap();
+        assert_eq!(unaccepted.encoding, PayloadEncoding::Identity);
+    }
+}
@@ -0,0 +1,190 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// ConfigMap Reload
+// Changes one key of a ConfigMap, such as a log level or feature flag, and finds the workloads
+// whose pod templates read it so they can be restarted to pick the new value up
+
+use anyhow::{anyhow, Result};
+use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, StatefulSet};
+use k8s_openapi::api::core::v1::{ConfigMap, PodSpec, PodTemplateSpec};
+use kube::{Api, Client};
+use std::collections::BTreeSet;
+
+use crate::kubernetes::change_cause::kuboard_record_change_cause;
+use crate::kubernetes::conflict::kuboard_replace_with_retry;
+use crate::types::ConfigMapConsumer;
+
+pub const RESTARTED_AT_ANNOTATION: &str = "kubectl.kubernetes.io/restartedAt";
+
+// How the pod spec reads the ConfigMap, empty when it doesn't
+pub fn kuboard_configmap_uses(spec: &PodSpec, configmap: &str) -> Vec<String> {
+    let mut uses = BTreeSet::new();
+    for container in spec.containers.iter().chain(spec.init_containers.iter().flatten()) {
+        let env_ref = container.env.iter().flatten()
+            .filter_map(|env| env.value_from.as_ref()?.config_map_key_ref.as_ref())
+            .any(|selector| selector.name == configmap);
+        if env_ref {
+            uses.insert("env");
+        }
+        let env_from = container.env_from.iter().flatten()
+            .filter_map(|source| source.config_map_ref.as_ref())
+            .any(|source| source.name == configmap);
+        if env_from {
+            uses.insert("envFrom");
+        }
+    }
+    let mounted = spec.volumes.iter().flatten().any(|volume| {
+        volume.config_map.as_ref().is_some_and(|source| source.name == configmap)
+            || volume.projected.as_ref()
+                .and_then(|projected| projected.sources.as_ref())
+                .is_some_and(|sources| sources.iter().any(|s| s.config_map.as_ref().is_some_and(|c| c.name == configmap)))
+    });
+    if mounted {
+        uses.insert("volume");
+    }
+    uses.into_iter().map(str::to_string).collect()
+}
+
+// The Deployments, StatefulSets and DaemonSets of one namespace whose pods read the ConfigMap
+pub fn kuboard_configmap_consumers(
+    configmap: &str,
+    deployments: &[Deployment],
+    statefulsets: &[StatefulSet],
+    daemonsets: &[DaemonSet],
+) -> Vec<ConfigMapConsumer> {
+    let templates = deployments.iter().map(|d| ("Deployment", &d.metadata, d.spec.as_ref().map(|s| &s.template)))
+        .chain(statefulsets.iter().map(|s| ("StatefulSet", &s.metadata, s.spec.as_ref().map(|s| &s.template))))
+        .chain(daemonsets.iter().map(|d| ("DaemonSet", &d.metadata, d.spec.as_ref().map(|s| &s.template))));
+    templates
+        .filter_map(|(kind, metadata, template)| {
+            let uses = kuboard_configmap_uses(template?.spec.as_ref()?, configmap);
+            (!uses.is_empty()).then(|| ConfigMapConsumer {
+                kind: kind.to_string(),
+                name: metadata.name.clone().unwrap_or_default(),
+                uses,
+                restarted: false,
+                error: None,
+            })
+        })
+        .collect()
+}
+
+// Sets the key, refusing what the API server would reject anyway with a clearer message
+pub fn kuboard_set_configmap_key(configmap: &mut ConfigMap, key: &str, value: &str) -> Result<Option<String>> {
+    let name = configmap.metadata.name.as_deref().unwrap_or_default();
+    if configmap.immutable == Some(true) {
+        return Err(anyhow!("ConfigMap {} is immutable", name));
+    }
+    if configmap.binary_data.as_ref().is_some_and(|data| data.contains_key(key)) {
+        return Err(anyhow!("Key {} of ConfigMap {} holds binary data", key, name));
+    }
+    Ok(configmap.data.get_or_insert_with(Default::default).insert(key.to_string(), value.to_string()))
+}
+
+fn set_restarted_at(template: &mut PodTemplateSpec, restarted_at: &str) {
+    // The annotation must be in spec.template.metadata.annotations, not metadata.annotations
+    template.metadata.get_or_insert_with(Default::default)
+        .annotations.get_or_insert_with(Default::default)
+        .insert(RESTARTED_AT_ANNOTATION.to_string(), restarted_at.to_string());
+}
+
+// A `kubectl rollout restart` of one consumer
+pub async fn kuboard_restart_consumer(
+    client: &Client,
+    namespace: &str,
+    consumer: &ConfigMapConsumer,
+    restarted_at: &str,
+    cause: &str,
+    dry_run: bool,
+) -> Result<()> {
+    let name = consumer.name.as_str();
+    match consumer.kind.as_str() {
+        "Deployment" => {
+            let api: Api<Deployment> = Api::namespaced(client.clone(), namespace);
+            kuboard_replace_with_retry(&api, name, api.get(name).await?, dry_run, |deployment| {
+                let spec = deployment.spec.as_mut().ok_or_else(|| anyhow!("Deployment spec is missing"))?;
+                set_restarted_at(&mut spec.template, restarted_at);
+                kuboard_record_change_cause(&mut deployment.metadata, cause);
+                Ok(())
+            }).await?;
+        }
+        "StatefulSet" => {
+            let api: Api<StatefulSet> = Api::namespaced(client.clone(), namespace);
+            kuboard_replace_with_retry(&api, name, api.get(name).await?, dry_run, |statefulset| {
+                let spec = statefulset.spec.as_mut().ok_or_else(|| anyhow!("StatefulSet spec is missing"))?;
+                set_restarted_at(&mut spec.template, restarted_at);
+                kuboard_record_change_cause(&mut statefulset.metadata, cause);
+                Ok(())
+            }).await?;
+        }
+        "DaemonSet" => {
+            let api: Api<DaemonSet> = Api::namespaced(client.clone(), namespace);
+            kuboard_replace_with_retry(&api, name, api.get(name).await?, dry_run, |daemonset| {
+                let spec = daemonset.spec.as_mut().ok_or_else(|| anyhow!("DaemonSet spec is missing"))?;
+                set_restarted_at(&mut spec.template, restarted_at);
+                kuboard_record_change_cause(&mut daemonset.metadata, cause);
+                Ok(())
+            }).await?;
+        }
+        kind => return Err(anyhow!("Cannot restart a {}", kind)),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn deployment(name: &str, pod_spec: serde_json::Value) -> Deployment {
+        serde_json::from_value(json!({
+            "metadata": { "name": name },
+            "spec": { "selector": {}, "template": { "spec": pod_spec } },
+        })).unwrap()
+    }
+
+    #[test]
+    fn test_configmap_consumers() {
+        let deployments = vec![
+            deployment("api", json!({
+                "containers": [{
+                    "name": "api",
+                    "env": [{ "name": "LOG_LEVEL", "valueFrom": { "configMapKeyRef": { "name": "settings", "key": "log_level" } } }],
+                }],
+                "volumes": [{ "name": "flags", "projected": { "sources": [{ "configMap": { "name": "settings" } }] } }],
+            })),
+            deployment("worker", json!({
+                "containers": [{ "name": "worker" }],
+                "initContainers": [{ "name": "init", "envFrom": [{ "configMapRef": { "name": "settings" } }] }],
+            })),
+            deployment("web", json!({
+                "containers": [{ "name": "web" }],
+                "volumes": [{ "name": "conf", "configMap": { "name": "web-config" } }],
+            })),
+        ];
+
+        let consumers = kuboard_configmap_consumers("settings", &deployments, &[], &[]);
+        let found: Vec<(&str, Vec<&str>)> = consumers.iter()
+            .map(|c| (c.name.as_str(), c.uses.iter().map(String::as_str).collect()))
+            .collect();
+        assert_eq!(found, vec![("api", vec!["env", "volume"]), ("worker", vec!["envFrom"])]);
+        assert_eq!(kuboard_configmap_consumers("web-config", &deployments, &[], &[])[0].uses, vec!["volume"]);
+    }
+
+    #[test]
+    fn test_set_configmap_key() {
+        let mut settings: ConfigMap = serde_json::from_value(json!({
+            "metadata": { "name": "settings" },
+            "data": { "log_level": "info" },
+            "binaryData": { "cert": "AAEC" },
+        })).unwrap();
+        assert_eq!(kuboard_set_configmap_key(&mut settings, "log_level", "debug").unwrap().as_deref(), Some("info"));
+        assert_eq!(kuboard_set_configmap_key(&mut settings, "new_flag", "on").unwrap(), None);
+        assert_eq!(settings.data.as_ref().unwrap()["log_level"], "debug");
+        assert!(kuboard_set_configmap_key(&mut settings, "cert", "x").is_err());
+
+        settings.immutable = Some(true);
+        assert!(kuboard_set_configmap_key(&mut settings, "log_level", "warn").is_err());
+    }
+}
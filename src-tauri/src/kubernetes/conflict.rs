@@ -0,0 +1,118 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Conflict Resolution
+// Replaces objects under optimistic concurrency: a 409 means someone else wrote first, so the
+// change is re-applied to the latest version a few times before giving up with a report of what
+// moved underneath
+
+use anyhow::Result;
+use kube::api::PostParams;
+use kube::{Api, Resource, ResourceExt};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt::{self, Debug, Display};
+use tracing::{info, warn};
+
+use crate::kubernetes::diff::kuboard_diff_values;
+use crate::types::ConflictReport;
+
+pub const CONFLICT_RETRY_ATTEMPTS: u32 = 3;
+
+// Written by the server or controllers on every update, so never a user's conflicting change
+const IGNORED_PATHS: &[&str] = &["/metadata/resourceVersion", "/metadata/managedFields", "/metadata/generation", "/status"];
+
+// Carries the report through anyhow so commands can return it as structured JSON
+#[derive(Debug)]
+pub struct ConflictError(pub ConflictReport);
+
+impl Display for ConflictError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0.message)
+    }
+}
+
+impl std::error::Error for ConflictError {}
+
+// JSON pointers of the fields that differ, ignoring server-maintained metadata and status
+pub fn kuboard_changed_fields(before: &Value, after: &Value) -> Vec<String> {
+    kuboard_diff_values(before, after).into_iter()
+        .map(|change| change.path)
+        .filter(|path| !IGNORED_PATHS.iter().any(|ignored| path == ignored || path.starts_with(&format!("{}/", ignored))))
+        .collect()
+}
+
+// Applies `change` to `current` and replaces it; on a conflict the latest version is fetched and
+// the change applied again, up to CONFLICT_RETRY_ATTEMPTS times, before failing with a ConflictError.
+// A dry run returns the object the API server would have stored
+pub async fn kuboard_replace_with_retry<K, F>(api: &Api<K>, name: &str, current: K, dry_run: bool, mut change: F) -> Result<K>
+where
+    K: Resource<DynamicType = ()> + Clone + Serialize + DeserializeOwned + Debug,
+    F: FnMut(&mut K) -> Result<()>,
+{
+    let original = serde_json::to_value(&current)?;
+    let mut base = current;
+    for attempt in 1..=CONFLICT_RETRY_ATTEMPTS {
+        let mut desired = base.clone();
+        change(&mut desired)?;
+        match api.replace(name, &PostParams { dry_run, ..Default::default() }, &desired).await {
+            Ok(updated) => {
+                if attempt > 1 {
+                    info!("Replaced {} {} after {} conflicts", K::kind(&()), name, attempt - 1);
+                }
+                return Ok(updated);
+            }
+            Err(kube::Error::Api(e)) if e.code == 409 => {
+                warn!("Conflict replacing {} {} (attempt {} of {})", K::kind(&()), name, attempt, CONFLICT_RETRY_ATTEMPTS);
+                base = api.get(name).await?;
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    let target = match base.namespace() {
+        Some(namespace) => format!("{}/{}", namespace, name),
+        None => name.to_string(),
+    };
+    Err(ConflictError(ConflictReport {
+        reason: "Conflict".to_string(),
+        kind: K::kind(&()).to_string(),
+        name: name.to_string(),
+        namespace: base.namespace(),
+        attempts: CONFLICT_RETRY_ATTEMPTS,
+        changed_fields: kuboard_changed_fields(&original, &serde_json::to_value(&base)?),
+        latest_resource_version: base.resource_version(),
+        message: format!(
+            "{} {} kept changing while it was being updated; gave up after {} attempts",
+            K::kind(&()), target, CONFLICT_RETRY_ATTEMPTS
+        ),
+    }).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_changed_fields() {
+        let before = json!({
+            "metadata": { "name": "web", "resourceVersion": "10", "labels": { "app/tier": "web" } },
+            "spec": { "replicas": 2, "template": { "spec": { "containers": [{ "image": "nginx:1.26" }] } } },
+            "status": { "readyReplicas": 2 },
+        });
+        let after = json!({
+            "metadata": { "name": "web", "resourceVersion": "14", "labels": { "app/tier": "api" }, "annotations": { "owner": "ops" } },
+            "spec": { "replicas": 2, "template": { "spec": { "containers": [{ "image": "nginx:1.27" }] } } },
+            "status": { "readyReplicas": 1 },
+        });
+
+        assert_eq!(kuboard_changed_fields(&before, &after), vec![
+            "/metadata/annotations",
+            "/metadata/labels/app~1tier",
+            "/spec/template/spec/containers/0/image",
+        ]);
+        assert!(kuboard_changed_fields(&before, &before).is_empty());
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Context Metadata
+// Per-context display names, colour tags, environment labels and groups, kept outside the kubeconfig
+
+use anyhow::{anyhow, Result};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::types::ContextMetadata;
+
+pub fn kuboard_context_metadata_path() -> Result<PathBuf> {
+    dirs::data_local_dir()
+        .map(|dir| dir.join("kuboard").join("context_metadata.json"))
+        .ok_or_else(|| anyhow!("Could not find local data directory"))
+}
+
+pub fn kuboard_load_context_metadata(path: &Path) -> Result<BTreeMap<String, ContextMetadata>> {
+    if !path.exists() {
+        return Ok(BTreeMap::new());
+    }
+    Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+}
+
+fn trimmed(value: &Option<String>) -> Option<String> {
+    value.as_deref().map(str::trim).filter(|v| !v.is_empty()).map(str::to_string)
+}
+
+// #rgb or #rrggbb, so the frontend can use it directly as a CSS colour
+fn is_hex_color(color: &str) -> bool {
+    color.strip_prefix('#')
+        .is_some_and(|hex| matches!(hex.len(), 3 | 6) && hex.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+// Replaces the context's metadata; metadata with nothing set removes the entry
+pub fn kuboard_save_context_metadata(path: &Path, context: &str, metadata: &ContextMetadata) -> Result<ContextMetadata> {
+    let metadata = ContextMetadata {
+        display_name: trimmed(&metadata.display_name),
+        color: trimmed(&metadata.color).map(|c| c.to_lowercase()),
+        environment: metadata.environment,
+        group: trimmed(&metadata.group),
+        read_only: metadata.read_only,
+    };
+    if let Some(color) = metadata.color.as_deref().filter(|c| !is_hex_color(c)) {
+        return Err(anyhow!("Colour must be a hex colour like #d03050, not {}", color));
+    }
+
+    let mut entries = kuboard_load_context_metadata(path)?;
+    if metadata == ContextMetadata::default() {
+        entries.remove(context);
+    } else {
+        entries.insert(context.to_string(), metadata.clone());
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let tmp_path = path.with_extension("json.tmp");
+    fs::write(&tmp_path, serde_json::to_string_pretty(&entries)?)?;
+    fs::rename(&tmp_path, path)?;
+    Ok(metadata)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ContextEnvironment;
+
+    #[test]
+    fn test_save_context_metadata() {
+        let dir = std::env::temp_dir().join(format!("kuboard-context-metadata-{}", uuid::Uuid::new_v4()));
+        let path = dir.join("context_metadata.json");
+
+        let saved = kuboard_save_context_metadata(&path, "prod-eu", &ContextMetadata {
+            display_name: Some("  Production EU ".to_string()),
+            color: Some("#D03050".to_string()),
+            environment: Some(ContextEnvironment::Prod),
+            group: Some("EU".to_string()),
+            read_only: false,
+        }).unwrap();
+        assert_eq!(saved.display_name.as_deref(), Some("Production EU"));
+        assert_eq!(saved.color.as_deref(), Some("#d03050"));
+        assert!(saved.is_protected());
+        assert_eq!(kuboard_load_context_metadata(&path).unwrap()["prod-eu"], saved);
+
+        let bad_color = ContextMetadata { color: Some("red".to_string()), ..Default::default() };
+        assert!(kuboard_save_context_metadata(&path, "prod-eu", &bad_color).is_err());
+
+        kuboard_save_context_metadata(&path, "prod-eu", &ContextMetadata::default()).unwrap();
+        assert!(kuboard_load_context_metadata(&path).unwrap().is_empty());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
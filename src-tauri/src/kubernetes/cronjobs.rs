@@ -0,0 +1,151 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// CronJob Run History
+// Summarizes the Jobs spawned by a CronJob into a last-run overview
+
+use k8s_openapi::api::batch::v1::{CronJob, Job};
+
+use crate::types::{CronJobRun, CronJobRunState, CronJobRunSummary};
+use crate::utils::{kuboard_format_age, kuboard_format_duration};
+
+// Jobs controlled by the named CronJob, matching the controller owner reference
+pub fn kuboard_is_cronjob_job(job: &Job, cronjob_name: &str) -> bool {
+    job.metadata.owner_references.as_ref().is_some_and(|owner_refs| {
+        owner_refs.iter().any(|owner| {
+            owner.kind == "CronJob" && owner.name == cronjob_name && owner.controller == Some(true)
+        })
+    })
+}
+
+pub fn kuboard_cronjob_run(job: &Job) -> CronJobRun {
+    let status = job.status.as_ref();
+    let terminal = status
+        .and_then(|s| s.conditions.as_ref())
+        .and_then(|conditions| {
+            conditions.iter().find(|c| (c.type_ == "Complete" || c.type_ == "Failed") && c.status == "True")
+        });
+
+    let state = match terminal.map(|c| c.type_.as_str()) {
+        Some("Complete") => CronJobRunState::Succeeded,
+        Some(_) => CronJobRunState::Failed,
+        None => CronJobRunState::Active,
+    };
+
+    let start_time = status.and_then(|s| s.start_time.as_ref()).map(|t| t.0);
+    // Failed jobs never get a completionTime, so the failure condition marks the end
+    let finish_time = status
+        .and_then(|s| s.completion_time.as_ref())
+        .or_else(|| terminal.and_then(|c| c.last_transition_time.as_ref()))
+        .map(|t| t.0);
+
+    let duration_seconds = match (start_time, finish_time) {
+        (Some(start), Some(finish)) => Some((finish - start).num_seconds()),
+        _ => None,
+    };
+
+    CronJobRun {
+        name: job.metadata.name.clone().unwrap_or_default(),
+        state,
+        start_time: start_time.map(|t| t.to_rfc3339()),
+        completion_time: finish_time.map(|t| t.to_rfc3339()),
+        duration_seconds,
+        duration: duration_seconds.map(kuboard_format_duration),
+        age: start_time.map(kuboard_format_age),
+        failure_reason: terminal
+            .filter(|_| state == CronJobRunState::Failed)
+            .and_then(|c| c.reason.clone()),
+        failure_message: terminal
+            .filter(|_| state == CronJobRunState::Failed)
+            .and_then(|c| c.message.clone()),
+    }
+}
+
+pub fn kuboard_summarize_cronjob_runs(cronjob: &CronJob, jobs: &[Job]) -> CronJobRunSummary {
+    let spec = cronjob.spec.as_ref();
+    let status = cronjob.status.as_ref();
+
+    let mut runs: Vec<(i64, CronJobRun)> = jobs
+        .iter()
+        .map(|job| {
+            let created = job.metadata.creation_timestamp.as_ref().map(|ts| ts.0.timestamp()).unwrap_or(0);
+            (created, kuboard_cronjob_run(job))
+        })
+        .collect();
+    // Newest first, matching kuboard_get_cronjob_jobs
+    runs.sort_by_key(|(created, _)| std::cmp::Reverse(*created));
+    let runs: Vec<CronJobRun> = runs.into_iter().map(|(_, run)| run).collect();
+
+    let latest = |state: CronJobRunState| runs.iter().find(|run| run.state == state).cloned();
+
+    CronJobRunSummary {
+        suspended: spec.and_then(|s| s.suspend).unwrap_or(false),
+        schedule: spec.map(|s| s.schedule.clone()).unwrap_or_default(),
+        last_schedule_time: status.and_then(|s| s.last_schedule_time.as_ref()).map(|t| t.0.to_rfc3339()),
+        last_successful_time: status.and_then(|s| s.last_successful_time.as_ref()).map(|t| t.0.to_rfc3339()),
+        // Defaults applied by the API server when the fields are unset
+        successful_jobs_history_limit: spec.and_then(|s| s.successful_jobs_history_limit).unwrap_or(3),
+        failed_jobs_history_limit: spec.and_then(|s| s.failed_jobs_history_limit).unwrap_or(1),
+        active: runs.iter().filter(|run| run.state == CronJobRunState::Active).cloned().collect(),
+        last_successful: latest(CronJobRunState::Succeeded),
+        last_failed: latest(CronJobRunState::Failed),
+        runs,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn job(name: &str, created: &str, status: serde_json::Value) -> Job {
+        serde_json::from_value(serde_json::json!({
+            "metadata": { "name": name, "creationTimestamp": created },
+            "status": status
+        })).unwrap()
+    }
+
+    #[test]
+    fn test_summarize_cronjob_runs_picks_latest_outcomes() {
+        let cronjob: CronJob = serde_json::from_value(serde_json::json!({
+            "metadata": { "name": "report" },
+            "spec": {
+                "schedule": "0 2 * * *",
+                "failedJobsHistoryLimit": 5,
+                "jobTemplate": { "spec": { "template": { "spec": { "containers": [] } } } }
+            }
+        })).unwrap();
+        let jobs = vec![
+            job("report-1", "2025-01-01T02:00:00Z", serde_json::json!({
+                "startTime": "2025-01-01T02:00:00Z",
+                "completionTime": "2025-01-01T02:01:30Z",
+                "conditions": [{ "type": "Complete", "status": "True" }]
+            })),
+            job("report-2", "2025-01-02T02:00:00Z", serde_json::json!({
+                "startTime": "2025-01-02T02:00:00Z",
+                "conditions": [{
+                    "type": "Failed", "status": "True", "reason": "BackoffLimitExceeded",
+                    "message": "Job has reached the specified backoff limit",
+                    "lastTransitionTime": "2025-01-02T02:05:00Z"
+                }]
+            })),
+            job("report-3", "2025-01-03T02:00:00Z", serde_json::json!({
+                "startTime": "2025-01-03T02:00:00Z", "active": 1
+            })),
+        ];
+
+        let summary = kuboard_summarize_cronjob_runs(&cronjob, &jobs);
+
+        assert_eq!(summary.runs[0].name, "report-3");
+        assert_eq!(summary.active.len(), 1);
+        let succeeded = summary.last_successful.unwrap();
+        assert_eq!(succeeded.name, "report-1");
+        assert_eq!(succeeded.duration_seconds, Some(90));
+        assert_eq!(succeeded.duration.as_deref(), Some("1m30s"));
+        let failed = summary.last_failed.unwrap();
+        assert_eq!(failed.failure_reason.as_deref(), Some("BackoffLimitExceeded"));
+        assert_eq!(failed.duration_seconds, Some(300));
+        assert_eq!(failed.duration.as_deref(), Some("5m"));
+        assert_eq!(summary.successful_jobs_history_limit, 3);
+        assert_eq!(summary.failed_jobs_history_limit, 5);
+    }
+}
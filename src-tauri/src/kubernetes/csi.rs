@@ -0,0 +1,367 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// CSI Health
+// VolumeAttachments, CSI drivers and their node registrations, and the pods stuck on attach or
+// mount errors traced back to the driver, node and attachment responsible
+
+use anyhow::Result;
+use k8s_openapi::api::core::v1::{Event, PersistentVolume, PersistentVolumeClaim, Pod};
+use k8s_openapi::api::storage::v1::{CSIDriver, CSINode, VolumeAttachment};
+use kube::{Api, Client};
+use std::collections::{BTreeSet, HashMap};
+
+use crate::types::{CsiDriverHealth, CsiDriverNode, VolumeAttachIssue, VolumeAttachmentInfo};
+
+// Warning reasons the kubelet and the attach/detach controller record on a pod whose volume won't come up
+pub const VOLUME_EVENT_REASONS: &[&str] = &["FailedAttachVolume", "FailedMount", "FailedMapVolume"];
+
+// The cluster-scoped storage objects every view here is built from
+pub struct CsiInventory {
+    pub drivers: Vec<CSIDriver>,
+    pub csi_nodes: Vec<CSINode>,
+    pub attachments: Vec<VolumeAttachment>,
+    pub volumes: Vec<PersistentVolume>,
+}
+
+pub async fn kuboard_fetch_csi_inventory(client: &Client) -> Result<CsiInventory> {
+    let params = Default::default();
+    let (drivers, csi_nodes, attachments, volumes) = (
+        Api::<CSIDriver>::all(client.clone()),
+        Api::<CSINode>::all(client.clone()),
+        Api::<VolumeAttachment>::all(client.clone()),
+        Api::<PersistentVolume>::all(client.clone()),
+    );
+    let (drivers, csi_nodes, attachments, volumes) = tokio::try_join!(
+        drivers.list(&params),
+        csi_nodes.list(&params),
+        attachments.list(&params),
+        volumes.list(&params),
+    )?;
+    Ok(CsiInventory {
+        drivers: drivers.items,
+        csi_nodes: csi_nodes.items,
+        attachments: attachments.items,
+        volumes: volumes.items,
+    })
+}
+
+pub fn kuboard_volume_attachment_info(attachment: &VolumeAttachment) -> VolumeAttachmentInfo {
+    let status = attachment.status.as_ref();
+    VolumeAttachmentInfo {
+        name: attachment.metadata.name.clone().unwrap_or_default(),
+        driver: attachment.spec.attacher.clone(),
+        node: attachment.spec.node_name.clone(),
+        persistent_volume: attachment.spec.source.persistent_volume_name.clone(),
+        attached: status.is_some_and(|s| s.attached),
+        attach_error: status.and_then(|s| s.attach_error.as_ref()).and_then(|e| e.message.clone()),
+        detach_error: status.and_then(|s| s.detach_error.as_ref()).and_then(|e| e.message.clone()),
+        deleting: attachment.metadata.deletion_timestamp.is_some(),
+        created: attachment.metadata.creation_timestamp.as_ref().map(|t| t.0.to_rfc3339()),
+    }
+}
+
+fn driver_object<'a>(inventory: &'a CsiInventory, driver: &str) -> Option<&'a CSIDriver> {
+    inventory.drivers.iter().find(|d| d.metadata.name.as_deref() == Some(driver))
+}
+
+// Without a CSIDriver object Kubernetes assumes the driver needs attaching
+fn attach_required(inventory: &CsiInventory, driver: &str) -> bool {
+    driver_object(inventory, driver).and_then(|d| d.spec.attach_required).unwrap_or(true)
+}
+
+fn registered_on_node(inventory: &CsiInventory, driver: &str, node: &str) -> bool {
+    inventory.csi_nodes.iter()
+        .filter(|csi_node| csi_node.metadata.name.as_deref() == Some(node))
+        .any(|csi_node| csi_node.spec.drivers.iter().any(|d| d.name == driver))
+}
+
+// Every driver named by a CSIDriver object, a node registration or an attachment, so drivers whose
+// CSIDriver object is missing still show up
+pub fn kuboard_csi_driver_health(inventory: &CsiInventory) -> Vec<CsiDriverHealth> {
+    let names: BTreeSet<String> = inventory.drivers.iter().filter_map(|d| d.metadata.name.clone())
+        .chain(inventory.csi_nodes.iter().flat_map(|n| n.spec.drivers.iter().map(|d| d.name.clone())))
+        .chain(inventory.attachments.iter().map(|a| a.spec.attacher.clone()))
+        .collect();
+
+    names.into_iter().map(|name| {
+        let driver = driver_object(inventory, &name);
+        let attachments: Vec<&VolumeAttachment> = inventory.attachments.iter().filter(|a| a.spec.attacher == name).collect();
+        let nodes = inventory.csi_nodes.iter()
+            .filter_map(|csi_node| {
+                let registration = csi_node.spec.drivers.iter().find(|d| d.name == name)?;
+                let node = csi_node.metadata.name.clone().unwrap_or_default();
+                Some(CsiDriverNode {
+                    attached_count: attachments.iter()
+                        .filter(|a| a.spec.node_name == node && a.status.as_ref().is_some_and(|s| s.attached))
+                        .count(),
+                    node_id: registration.node_id.clone(),
+                    allocatable_count: registration.allocatable.as_ref().and_then(|a| a.count),
+                    node,
+                })
+            })
+            .collect();
+
+        CsiDriverHealth {
+            registered: driver.is_some(),
+            attach_required: attach_required(inventory, &name),
+            volume_lifecycle_modes: driver.and_then(|d| d.spec.volume_lifecycle_modes.clone()).unwrap_or_default(),
+            nodes,
+            attachments: attachments.len(),
+            failing_attachments: attachments.iter()
+                .filter(|a| a.status.as_ref().is_some_and(|s| s.attach_error.is_some() || s.detach_error.is_some()))
+                .count(),
+            name,
+        }
+    }).collect()
+}
+
+fn event_time(event: &Event) -> Option<String> {
+    event.last_timestamp.as_ref().map(|t| t.0.to_rfc3339())
+        .or_else(|| event.event_time.as_ref().map(|t| t.0.to_rfc3339()))
+}
+
+// Whole-word match, so a volume called "data" isn't found inside "metadata"
+fn mentions(message: &str, name: &str) -> bool {
+    message.split(|c: char| !(c.is_ascii_alphanumeric() || c == '-' || c == '.')).any(|word| word == name)
+}
+
+// A pod volume that is backed by a CSI driver, or by a claim that should be
+struct PodVolume {
+    volume: String,
+    claim: Option<String>,
+    persistent_volume: Option<String>,
+    driver: Option<String>,
+}
+
+fn pod_volumes(pod: &Pod, claims: &[PersistentVolumeClaim], inventory: &CsiInventory) -> Vec<PodVolume> {
+    let pod_name = pod.metadata.name.clone().unwrap_or_default();
+    let namespace = pod.metadata.namespace.as_deref();
+    pod.spec.as_ref().and_then(|s| s.volumes.as_ref()).into_iter().flatten()
+        .filter_map(|volume| {
+            if let Some(csi) = &volume.csi {
+                return Some(PodVolume { volume: volume.name.clone(), claim: None, persistent_volume: None, driver: Some(csi.driver.clone()) });
+            }
+            // Generic ephemeral volumes get a claim named after the pod and the volume
+            let claim = match (&volume.persistent_volume_claim, &volume.ephemeral) {
+                (Some(source), _) => source.claim_name.clone(),
+                (None, Some(_)) => format!("{}-{}", pod_name, volume.name),
+                (None, None) => return None,
+            };
+            let persistent_volume = claims.iter()
+                .find(|c| c.metadata.name.as_deref() == Some(claim.as_str()) && c.metadata.namespace.as_deref() == namespace)
+                .and_then(|c| c.spec.as_ref()?.volume_name.clone());
+            let driver = persistent_volume.as_ref().and_then(|pv| {
+                inventory.volumes.iter()
+                    .find(|v| v.metadata.name.as_ref() == Some(pv))
+                    .and_then(|v| v.spec.as_ref()?.csi.as_ref().map(|csi| csi.driver.clone()))
+            });
+            Some(PodVolume { volume: volume.name.clone(), claim: Some(claim), persistent_volume, driver })
+        })
+        .collect()
+}
+
+// Where attaching this volume to the pod's node is stuck, and the attachment involved
+fn diagnose<'a>(volume: &PodVolume, node: Option<&str>, inventory: &'a CsiInventory) -> (Option<&'a VolumeAttachment>, String) {
+    let Some(driver) = volume.driver.as_deref() else {
+        return match (&volume.claim, &volume.persistent_volume) {
+            (Some(claim), None) => (None, format!("Claim {} is not bound to a volume yet", claim)),
+            _ => (None, "Not a CSI volume; the kubelet's in-tree plugin handles it".to_string()),
+        };
+    };
+    let Some(node) = node else {
+        return (None, "The pod isn't scheduled to a node yet".to_string());
+    };
+    if !registered_on_node(inventory, driver, node) {
+        return (None, format!("{} has no node plugin registered on {}; check the driver's node pod there", driver, node));
+    }
+    if !attach_required(inventory, driver) {
+        return (None, format!("{} doesn't attach volumes; mounting failed on {}", driver, node));
+    }
+    // Inline CSI volumes have no PersistentVolume to match attachments on
+    let Some(pv) = volume.persistent_volume.as_deref() else {
+        return (None, format!("Waiting for {} to attach the volume to {}", driver, node));
+    };
+
+    let for_volume = |a: &&VolumeAttachment| a.spec.source.persistent_volume_name.as_deref() == Some(pv);
+    let Some(attachment) = inventory.attachments.iter().filter(for_volume).find(|a| a.spec.node_name == node) else {
+        let diagnosis = match inventory.attachments.iter().filter(for_volume).find(|a| a.status.as_ref().is_some_and(|s| s.attached)) {
+            Some(elsewhere) => format!("{} is still attached to {} and has to detach there first", pv, elsewhere.spec.node_name),
+            None => format!("No VolumeAttachment for {} on {} yet; the attach/detach controller hasn't requested one", pv, node),
+        };
+        return (None, diagnosis);
+    };
+
+    let info = kuboard_volume_attachment_info(attachment);
+    let diagnosis = match (info.attach_error, info.attached) {
+        (Some(error), _) => format!("{} failed to attach {}: {}", driver, pv, error),
+        (None, true) => format!("{} is attached to {}; mounting it on the node failed", pv, node),
+        (None, false) => format!("Waiting for {}'s attacher to attach {} to {}", driver, pv, node),
+    };
+    (Some(attachment), diagnosis)
+}
+
+// One entry per affected volume of each pod with a recent attach or mount warning, newest event per pod
+pub fn kuboard_volume_attach_issues(events: &[Event], pods: &[Pod], claims: &[PersistentVolumeClaim], inventory: &CsiInventory) -> Vec<VolumeAttachIssue> {
+    let mut latest: HashMap<(String, String), &Event> = HashMap::new();
+    for event in events.iter().filter(|e| {
+        e.type_.as_deref() == Some("Warning")
+            && e.involved_object.kind.as_deref() == Some("Pod")
+            && e.reason.as_deref().is_some_and(|r| VOLUME_EVENT_REASONS.contains(&r))
+    }) {
+        let key = (
+            event.involved_object.namespace.clone().unwrap_or_default(),
+            event.involved_object.name.clone().unwrap_or_default(),
+        );
+        let newer = latest.get(&key).is_none_or(|current| event_time(event) > event_time(current));
+        if newer {
+            latest.insert(key, event);
+        }
+    }
+
+    let mut issues = Vec::new();
+    for pod in pods {
+        let namespace = pod.metadata.namespace.clone().unwrap_or_default();
+        let name = pod.metadata.name.clone().unwrap_or_default();
+        let Some(event) = latest.get(&(namespace.clone(), name.clone())) else { continue };
+        let message = event.message.clone().unwrap_or_default();
+        let node = pod.spec.as_ref().and_then(|s| s.node_name.clone());
+
+        let volumes = pod_volumes(pod, claims, inventory);
+        // The event usually names the volumes it is about; otherwise every candidate is reported
+        let named: Vec<&PodVolume> = volumes.iter()
+            .filter(|v| mentions(&message, &v.volume) || v.persistent_volume.as_deref().is_some_and(|pv| mentions(&message, pv)))
+            .collect();
+        let affected = if named.is_empty() { volumes.iter().collect() } else { named };
+
+        for volume in affected {
+            let (attachment, diagnosis) = diagnose(volume, node.as_deref(), inventory);
+            issues.push(VolumeAttachIssue {
+                pod: name.clone(),
+                namespace: namespace.clone(),
+                node: node.clone(),
+                reason: event.reason.clone().unwrap_or_default(),
+                message: message.clone(),
+                count: event.count.unwrap_or(1),
+                last_seen: event_time(event),
+                volume: volume.volume.clone(),
+                claim: volume.claim.clone(),
+                persistent_volume: volume.persistent_volume.clone(),
+                driver: volume.driver.clone(),
+                attachment: attachment.map(kuboard_volume_attachment_info),
+                diagnosis,
+            });
+        }
+    }
+    issues.sort_by(|a, b| b.last_seen.cmp(&a.last_seen));
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    fn attachment(pv: &str, node: &str, status: Value) -> VolumeAttachment {
+        serde_json::from_value(json!({
+            "metadata": { "name": format!("csi-{}-{}", pv, node) },
+            "spec": { "attacher": "ebs.csi.aws.com", "nodeName": node, "source": { "persistentVolumeName": pv } },
+            "status": status,
+        })).unwrap()
+    }
+
+    fn inventory(attachments: Vec<VolumeAttachment>) -> CsiInventory {
+        CsiInventory {
+            drivers: vec![serde_json::from_value(json!({ "metadata": { "name": "ebs.csi.aws.com" }, "spec": { "attachRequired": true } })).unwrap()],
+            csi_nodes: ["node-1", "node-2"].iter().map(|node| serde_json::from_value(json!({
+                "metadata": { "name": node },
+                "spec": { "drivers": [{ "name": "ebs.csi.aws.com", "nodeID": format!("i-{}", node), "allocatable": { "count": 25 } }] },
+            })).unwrap()).collect(),
+            attachments,
+            volumes: vec![serde_json::from_value(json!({
+                "metadata": { "name": "pvc-123" },
+                "spec": { "csi": { "driver": "ebs.csi.aws.com", "volumeHandle": "vol-1" } },
+            })).unwrap()],
+        }
+    }
+
+    fn stuck_pod(node: &str) -> (Vec<Event>, Vec<Pod>, Vec<PersistentVolumeClaim>) {
+        let event = serde_json::from_value(json!({
+            "metadata": { "name": "db-0.attach", "namespace": "default" },
+            "involvedObject": { "kind": "Pod", "name": "db-0", "namespace": "default" },
+            "type": "Warning",
+            "reason": "FailedAttachVolume",
+            "message": "AttachVolume.Attach failed for volume \"pvc-123\" : rpc error: code = Internal",
+            "count": 4,
+            "lastTimestamp": "2025-01-01T00:05:00Z",
+        })).unwrap();
+        let pod = serde_json::from_value(json!({
+            "metadata": { "name": "db-0", "namespace": "default" },
+            "spec": {
+                "nodeName": node,
+                "containers": [{ "name": "db" }],
+                "volumes": [
+                    { "name": "data", "persistentVolumeClaim": { "claimName": "data-db-0" } },
+                    { "name": "config", "configMap": { "name": "db" } },
+                ],
+            },
+        })).unwrap();
+        let claim = serde_json::from_value(json!({
+            "metadata": { "name": "data-db-0", "namespace": "default" },
+            "spec": { "volumeName": "pvc-123" },
+        })).unwrap();
+        (vec![event], vec![pod], vec![claim])
+    }
+
+    #[test]
+    fn test_attach_error_is_traced_to_the_attachment() {
+        let (events, pods, claims) = stuck_pod("node-1");
+        let inventory = inventory(vec![attachment("pvc-123", "node-1", json!({ "attached": false, "attachError": { "message": "volume limit reached" } }))]);
+        let issues = kuboard_volume_attach_issues(&events, &pods, &claims, &inventory);
+        assert_eq!(issues.len(), 1);
+        assert_eq!((issues[0].volume.as_str(), issues[0].driver.as_deref()), ("data", Some("ebs.csi.aws.com")));
+        assert_eq!(issues[0].attachment.as_ref().unwrap().attach_error.as_deref(), Some("volume limit reached"));
+        assert!(issues[0].diagnosis.contains("failed to attach pvc-123: volume limit reached"));
+    }
+
+    #[test]
+    fn test_multi_attach_and_missing_node_plugin() {
+        let (events, pods, claims) = stuck_pod("node-2");
+        let multi_attach = inventory(vec![attachment("pvc-123", "node-1", json!({ "attached": true }))]);
+        let issues = kuboard_volume_attach_issues(&events, &pods, &claims, &multi_attach);
+        assert!(issues[0].attachment.is_none());
+        assert!(issues[0].diagnosis.contains("still attached to node-1"));
+
+        let (events, pods, claims) = stuck_pod("node-3");
+        let issues = kuboard_volume_attach_issues(&events, &pods, &claims, &inventory(Vec::new()));
+        assert!(issues[0].diagnosis.contains("no node plugin registered on node-3"));
+    }
+
+    #[test]
+    fn test_csi_driver_health() {
+        let mut inventory = inventory(vec![
+            attachment("pvc-123", "node-1", json!({ "attached": true })),
+            attachment("pvc-456", "node-2", json!({ "attached": false, "attachError": { "message": "timeout" } })),
+        ]);
+        inventory.attachments.push(serde_json::from_value(json!({
+            "metadata": { "name": "csi-orphan" },
+            "spec": { "attacher": "nfs.csi.k8s.io", "nodeName": "node-1", "source": { "persistentVolumeName": "pvc-789" } },
+        })).unwrap());
+
+        let drivers = kuboard_csi_driver_health(&inventory);
+        assert_eq!(drivers.len(), 2);
+        let ebs = &drivers[0];
+        assert_eq!((ebs.attachments, ebs.failing_attachments, ebs.nodes.len()), (2, 1, 2));
+        assert_eq!((ebs.nodes[0].attached_count, ebs.nodes[0].allocatable_count), (1, Some(25)));
+        // Referenced by an attachment but never installed as a CSIDriver
+        assert!(!drivers[1].registered);
+        assert!(drivers[1].attach_required);
+        assert!(drivers[1].nodes.is_empty());
+    }
+
+    #[test]
+    fn test_mentions_whole_words() {
+        assert!(mentions("unmounted volumes=[data], unattached volumes=[data kube-api-access-x]", "data"));
+        assert!(!mentions("failed to read metadata", "data"));
+    }
+}
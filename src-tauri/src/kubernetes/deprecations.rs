@@ -0,0 +1,214 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Deprecated API Scanner
+// Finds objects still written through deprecated or removed API versions before a cluster upgrade
+
+use anyhow::{anyhow, Result};
+use kube::api::{DynamicObject, GroupVersionKind, ListParams};
+use kube::discovery::pinned_kind;
+use kube::{Api, Client};
+use serde_json::Value;
+use tracing::debug;
+
+use crate::types::{DeprecatedApiFinding, DeprecatedApiReport};
+
+pub struct ApiDeprecation {
+    pub api_version: &'static str,
+    pub kind: &'static str,
+    // Served version to list existing objects through
+    pub replacement: &'static str,
+    pub deprecated_in: u32,
+    pub removed_in: u32,
+}
+
+const fn deprecation(api_version: &'static str, kind: &'static str, replacement: &'static str, deprecated_in: u32, removed_in: u32) -> ApiDeprecation {
+    ApiDeprecation { api_version, kind, replacement, deprecated_in, removed_in }
+}
+
+// Minor versions of Kubernetes 1.x, from the upstream deprecation guide
+pub const API_DEPRECATIONS: &[ApiDeprecation] = &[
+    // 1.16
+    deprecation("extensions/v1beta1", "Deployment", "apps/v1", 9, 16),
+    deprecation("apps/v1beta1", "Deployment", "apps/v1", 9, 16),
+    deprecation("apps/v1beta2", "Deployment", "apps/v1", 9, 16),
+    deprecation("extensions/v1beta1", "DaemonSet", "apps/v1", 9, 16),
+    deprecation("apps/v1beta2", "DaemonSet", "apps/v1", 9, 16),
+    deprecation("apps/v1beta1", "StatefulSet", "apps/v1", 9, 16),
+    deprecation("apps/v1beta2", "StatefulSet", "apps/v1", 9, 16),
+    deprecation("extensions/v1beta1", "ReplicaSet", "apps/v1", 9, 16),
+    deprecation("apps/v1beta1", "ReplicaSet", "apps/v1", 9, 16),
+    deprecation("apps/v1beta2", "ReplicaSet", "apps/v1", 9, 16),
+    deprecation("extensions/v1beta1", "NetworkPolicy", "networking.k8s.io/v1", 9, 16),
+    // 1.22
+    deprecation("extensions/v1beta1", "Ingress", "networking.k8s.io/v1", 14, 22),
+    deprecation("networking.k8s.io/v1beta1", "Ingress", "networking.k8s.io/v1", 19, 22),
+    deprecation("networking.k8s.io/v1beta1", "IngressClass", "networking.k8s.io/v1", 19, 22),
+    deprecation("rbac.authorization.k8s.io/v1beta1", "Role", "rbac.authorization.k8s.io/v1", 17, 22),
+    deprecation("rbac.authorization.k8s.io/v1beta1", "RoleBinding", "rbac.authorization.k8s.io/v1", 17, 22),
+    deprecation("rbac.authorization.k8s.io/v1beta1", "ClusterRole", "rbac.authorization.k8s.io/v1", 17, 22),
+    deprecation("rbac.authorization.k8s.io/v1beta1", "ClusterRoleBinding", "rbac.authorization.k8s.io/v1", 17, 22),
+    deprecation("apiextensions.k8s.io/v1beta1", "CustomResourceDefinition", "apiextensions.k8s.io/v1", 16, 22),
+    deprecation("admissionregistration.k8s.io/v1beta1", "MutatingWebhookConfiguration", "admissionregistration.k8s.io/v1", 16, 22),
+    deprecation("admissionregistration.k8s.io/v1beta1", "ValidatingWebhookConfiguration", "admissionregistration.k8s.io/v1", 16, 22),
+    deprecation("apiregistration.k8s.io/v1beta1", "APIService", "apiregistration.k8s.io/v1", 19, 22),
+    deprecation("certificates.k8s.io/v1beta1", "CertificateSigningRequest", "certificates.k8s.io/v1", 19, 22),
+    deprecation("coordination.k8s.io/v1beta1", "Lease", "coordination.k8s.io/v1", 19, 22),
+    deprecation("scheduling.k8s.io/v1beta1", "PriorityClass", "scheduling.k8s.io/v1", 14, 22),
+    deprecation("storage.k8s.io/v1beta1", "StorageClass", "storage.k8s.io/v1", 6, 22),
+    deprecation("storage.k8s.io/v1beta1", "VolumeAttachment", "storage.k8s.io/v1", 13, 22),
+    deprecation("storage.k8s.io/v1beta1", "CSIDriver", "storage.k8s.io/v1", 19, 22),
+    deprecation("storage.k8s.io/v1beta1", "CSINode", "storage.k8s.io/v1", 17, 22),
+    // 1.25
+    deprecation("batch/v1beta1", "CronJob", "batch/v1", 21, 25),
+    deprecation("policy/v1beta1", "PodDisruptionBudget", "policy/v1", 21, 25),
+    deprecation("discovery.k8s.io/v1beta1", "EndpointSlice", "discovery.k8s.io/v1", 21, 25),
+    deprecation("node.k8s.io/v1beta1", "RuntimeClass", "node.k8s.io/v1", 20, 25),
+    deprecation("autoscaling/v2beta1", "HorizontalPodAutoscaler", "autoscaling/v2", 22, 25),
+    // 1.26
+    deprecation("autoscaling/v2beta2", "HorizontalPodAutoscaler", "autoscaling/v2", 23, 26),
+    deprecation("flowcontrol.apiserver.k8s.io/v1beta1", "FlowSchema", "flowcontrol.apiserver.k8s.io/v1", 23, 26),
+    deprecation("flowcontrol.apiserver.k8s.io/v1beta1", "PriorityLevelConfiguration", "flowcontrol.apiserver.k8s.io/v1", 23, 26),
+    // 1.27
+    deprecation("storage.k8s.io/v1beta1", "CSIStorageCapacity", "storage.k8s.io/v1", 24, 27),
+    // 1.29
+    deprecation("flowcontrol.apiserver.k8s.io/v1beta2", "FlowSchema", "flowcontrol.apiserver.k8s.io/v1", 26, 29),
+    deprecation("flowcontrol.apiserver.k8s.io/v1beta2", "PriorityLevelConfiguration", "flowcontrol.apiserver.k8s.io/v1", 26, 29),
+    // 1.32
+    deprecation("flowcontrol.apiserver.k8s.io/v1beta3", "FlowSchema", "flowcontrol.apiserver.k8s.io/v1", 29, 32),
+    deprecation("flowcontrol.apiserver.k8s.io/v1beta3", "PriorityLevelConfiguration", "flowcontrol.apiserver.k8s.io/v1", 29, 32),
+];
+
+// "1.31", "v1.31.2" or "31" -> 31
+pub fn parse_minor_version(version: &str) -> Option<u32> {
+    let version = version.trim().trim_start_matches('v');
+    let minor = match version.split_once('.') {
+        Some((_, rest)) => rest.split('.').next().unwrap_or(rest),
+        None => version,
+    };
+    minor.trim_end_matches('+').parse().ok()
+}
+
+// Field managers (and the last kubectl apply) that still write the object through the deprecated version
+pub fn kuboard_deprecated_writers(object: &Value, deprecated_api_version: &str) -> Vec<String> {
+    let mut writers: Vec<String> = object.pointer("/metadata/managedFields")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter(|entry| entry.get("apiVersion").and_then(Value::as_str) == Some(deprecated_api_version))
+        .map(|entry| entry.get("manager").and_then(Value::as_str).unwrap_or("unknown").to_string())
+        .collect();
+
+    let last_applied = object.pointer("/metadata/annotations/kubectl.kubernetes.io~1last-applied-configuration")
+        .and_then(Value::as_str)
+        .and_then(|config| serde_json::from_str::<Value>(config).ok());
+    if last_applied.as_ref().and_then(|c| c.get("apiVersion")).and_then(Value::as_str) == Some(deprecated_api_version) {
+        writers.push("kubectl apply (last-applied-configuration)".to_string());
+    }
+
+    writers.sort();
+    writers.dedup();
+    writers
+}
+
+fn finding(object: &Value, deprecation: &ApiDeprecation, writers: Vec<String>, current_minor: u32) -> DeprecatedApiFinding {
+    let metadata = |pointer: &str| object.pointer(pointer).and_then(Value::as_str).map(str::to_string);
+    DeprecatedApiFinding {
+        kind: deprecation.kind.to_string(),
+        name: metadata("/metadata/name").unwrap_or_default(),
+        namespace: metadata("/metadata/namespace"),
+        api_version: deprecation.api_version.to_string(),
+        replacement_api_version: deprecation.replacement.to_string(),
+        deprecated_in: format!("1.{}", deprecation.deprecated_in),
+        removed_in: format!("1.{}", deprecation.removed_in),
+        already_removed: deprecation.removed_in <= current_minor,
+        writers,
+        helm_release: metadata("/metadata/annotations/meta.helm.sh~1release-name"),
+    }
+}
+
+// target_version defaults to two minors ahead of the cluster
+pub async fn kuboard_find_deprecated_api_usage(client: &Client, target_version: Option<&str>) -> Result<DeprecatedApiReport> {
+    let server_version = client.apiserver_version().await?;
+    let current_minor = parse_minor_version(&server_version.minor)
+        .ok_or_else(|| anyhow!("Could not parse server version {}", server_version.git_version))?;
+    let target_minor = match target_version {
+        Some(target) => parse_minor_version(target).ok_or_else(|| anyhow!("Invalid target version {}", target))?,
+        None => current_minor + 2,
+    };
+
+    let mut findings = Vec::new();
+    for deprecation in API_DEPRECATIONS.iter().filter(|d| d.removed_in <= target_minor) {
+        let (group, version) = deprecation.replacement.rsplit_once('/').unwrap_or(("", deprecation.replacement));
+        // Kinds the cluster doesn't serve at all can't have live objects
+        let Ok((resource, _)) = pinned_kind(client, &GroupVersionKind::gvk(group, version, deprecation.kind)).await else {
+            debug!("Skipping {} {}: not served by the cluster", deprecation.replacement, deprecation.kind);
+            continue;
+        };
+
+        let api: Api<DynamicObject> = Api::all_with(client.clone(), &resource);
+        for object in api.list(&ListParams::default()).await?.items {
+            let object = serde_json::to_value(&object)?;
+            let writers = kuboard_deprecated_writers(&object, deprecation.api_version);
+            if !writers.is_empty() {
+                findings.push(finding(&object, deprecation, writers, current_minor));
+            }
+        }
+    }
+
+    Ok(DeprecatedApiReport {
+        server_version: format!("1.{}", current_minor),
+        target_version: format!("1.{}", target_minor),
+        findings,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_minor_version() {
+        assert_eq!(parse_minor_version("1.31"), Some(31));
+        assert_eq!(parse_minor_version("v1.29.4"), Some(29));
+        assert_eq!(parse_minor_version("27+"), Some(27));
+        assert_eq!(parse_minor_version("latest"), None);
+    }
+
+    #[test]
+    fn test_api_deprecations_table() {
+        let hpa = API_DEPRECATIONS.iter()
+            .find(|d| d.api_version == "autoscaling/v2beta1" && d.kind == "HorizontalPodAutoscaler")
+            .unwrap();
+        assert_eq!((hpa.deprecated_in, hpa.removed_in), (22, 25));
+
+        let mut seen = std::collections::HashSet::new();
+        for d in API_DEPRECATIONS {
+            assert!(d.deprecated_in < d.removed_in, "{} {}", d.api_version, d.kind);
+            assert_ne!(d.api_version, d.replacement);
+            assert!(seen.insert((d.api_version, d.kind)), "duplicate {} {}", d.api_version, d.kind);
+        }
+    }
+
+    #[test]
+    fn test_deprecated_writers() {
+        let last_applied = json!({ "apiVersion": "networking.k8s.io/v1beta1", "kind": "Ingress" }).to_string();
+        let ingress = json!({
+            "metadata": {
+                "name": "web",
+                "annotations": { "kubectl.kubernetes.io/last-applied-configuration": last_applied },
+                "managedFields": [
+                    { "manager": "helm", "apiVersion": "networking.k8s.io/v1beta1" },
+                    { "manager": "nginx-ingress-controller", "apiVersion": "networking.k8s.io/v1" },
+                ],
+            },
+        });
+
+        assert_eq!(kuboard_deprecated_writers(&ingress, "networking.k8s.io/v1beta1"), vec![
+            "helm".to_string(),
+            "kubectl apply (last-applied-configuration)".to_string(),
+        ]);
+        assert!(kuboard_deprecated_writers(&ingress, "extensions/v1beta1").is_empty());
+    }
+}
@@ -0,0 +1,124 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Manifest Diff
+// Field-level differences between a live object and an edited manifest, both cleaned of server
+// metadata and status first, so only what the author controls shows up
+
+use anyhow::Result;
+use serde_json::{Map, Value};
+
+use crate::kubernetes::apply::ApplyTarget;
+use crate::kubernetes::manifests::kuboard_clean_manifest;
+use crate::types::{DiffOperation, FieldDiff, ManifestDiff};
+
+fn escape_pointer(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+// Null and absent mean the same to the API server, e.g. a template's `creationTimestamp: null`
+fn present(value: Option<&Value>) -> Option<&Value> {
+    value.filter(|v| !v.is_null())
+}
+
+fn diff(path: String, before: Option<&Value>, after: Option<&Value>, changes: &mut Vec<FieldDiff>) {
+    let (before, after) = (present(before), present(after));
+    if before == after {
+        return;
+    }
+    match (before, after) {
+        (Some(Value::Object(before)), Some(Value::Object(after))) => {
+            let mut keys: Vec<&String> = before.keys().chain(after.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                diff(format!("{}/{}", path, escape_pointer(key)), before.get(key), after.get(key), changes);
+            }
+        }
+        // Items are compared by position; extra items on either side are added or removed
+        (Some(Value::Array(before)), Some(Value::Array(after))) => {
+            for index in 0..before.len().max(after.len()) {
+                diff(format!("{}/{}", path, index), before.get(index), after.get(index), changes);
+            }
+        }
+        (before, after) => changes.push(FieldDiff {
+            path,
+            operation: match (before, after) {
+                (None, _) => DiffOperation::Added,
+                (_, None) => DiffOperation::Removed,
+                _ => DiffOperation::Changed,
+            },
+            before: before.cloned(),
+            after: after.cloned(),
+        }),
+    }
+}
+
+// Every leaf that differs, as JSON pointers in key order
+pub fn kuboard_diff_values(before: &Value, after: &Value) -> Vec<FieldDiff> {
+    let mut changes = Vec::new();
+    diff(String::new(), Some(before), Some(after), &mut changes);
+    changes
+}
+
+// The live object against the manifest resolved for it; an object that doesn't exist yet diffs
+// against nothing, so every field is added
+pub async fn kuboard_diff_target(target: &ApplyTarget) -> Result<ManifestDiff> {
+    let live = target.live().await?;
+    let exists = live.is_some();
+    let mut before = match live {
+        Some(object) => serde_json::to_value(object)?,
+        None => Value::Object(Map::new()),
+    };
+    let mut after = serde_json::to_value(&target.object)?;
+    kuboard_clean_manifest(&mut before);
+    kuboard_clean_manifest(&mut after);
+
+    Ok(ManifestDiff {
+        kind: target.kind.clone(),
+        name: target.name.clone(),
+        namespace: target.namespace.clone(),
+        exists,
+        changes: kuboard_diff_values(&before, &after),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_diff_values() {
+        let live = json!({
+            "metadata": { "name": "web", "labels": { "app/tier": "web", "team": "a" } },
+            "spec": {
+                "replicas": 2,
+                "template": {
+                    "metadata": { "creationTimestamp": null },
+                    "spec": { "containers": [{ "name": "web", "image": "nginx:1.26" }] },
+                },
+            },
+        });
+        let edited = json!({
+            "metadata": { "name": "web", "labels": { "app/tier": "web" } },
+            "spec": {
+                "replicas": 3,
+                "template": {
+                    "metadata": {},
+                    "spec": { "containers": [{ "name": "web", "image": "nginx:1.26" }, { "name": "proxy", "image": "envoy" }] },
+                },
+            },
+        });
+
+        let changes = kuboard_diff_values(&live, &edited);
+        let summary: Vec<(&str, DiffOperation)> = changes.iter().map(|c| (c.path.as_str(), c.operation)).collect();
+        assert_eq!(summary, vec![
+            ("/metadata/labels/team", DiffOperation::Removed),
+            ("/spec/replicas", DiffOperation::Changed),
+            ("/spec/template/spec/containers/1", DiffOperation::Added),
+        ]);
+        assert_eq!((changes[1].before.clone(), changes[1].after.clone()), (Some(json!(2)), Some(json!(3))));
+        assert!(kuboard_diff_values(&live, &live).is_empty());
+    }
+}
@@ -0,0 +1,220 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Cluster DNS
+// Summarizes the CoreDNS (or legacy kube-dns) configuration, service health and recent errors
+
+use anyhow::Result;
+use kube::api::{ListParams, LogParams};
+use kube::{Api, Client};
+use k8s_openapi::api::core::v1::{ConfigMap, Endpoints, Pod, Service};
+use std::collections::BTreeMap;
+use tracing::warn;
+
+use crate::types::{ClusterDnsSummary, DnsLogLine, DnsPodStatus, DnsServerBlock};
+use crate::utils::kuboard_format_age;
+
+const DNS_NAMESPACE: &str = "kube-system";
+// Both CoreDNS and kube-dns deployments keep the historical service name and pod label
+const DNS_SERVICE: &str = "kube-dns";
+const DNS_POD_SELECTOR: &str = "k8s-app=kube-dns";
+const LOG_TAIL_LINES: i64 = 500;
+const MAX_ERROR_LINES: usize = 50;
+
+fn strip_comment(line: &str) -> &str {
+    line.split_once('#').map(|(code, _)| code).unwrap_or(line).trim()
+}
+
+// Targets of a `forward FROM TO...` line, stopping at an options block
+fn forward_targets(line: &str) -> Vec<String> {
+    line.split_whitespace()
+        .skip(2)
+        .take_while(|token| *token != "{")
+        .map(str::to_string)
+        .collect()
+}
+
+// Top-level server blocks of a Corefile with their plugins and forward targets
+pub fn kuboard_parse_corefile(corefile: &str) -> Vec<DnsServerBlock> {
+    let mut blocks = Vec::new();
+    let mut current: Option<DnsServerBlock> = None;
+    let mut depth = 0usize;
+
+    for raw in corefile.lines() {
+        let line = strip_comment(raw);
+        if line.is_empty() {
+            continue;
+        }
+        let opens = line.matches('{').count();
+        let closes = line.matches('}').count();
+
+        if depth == 0 && opens > 0 {
+            let zones = line.trim_end_matches('{').split_whitespace().map(str::to_string).collect();
+            current = Some(DnsServerBlock { zones, plugins: Vec::new(), forwarders: Vec::new() });
+        } else if depth == 1 {
+            if let Some(block) = current.as_mut() {
+                let plugin = line.split_whitespace().next().unwrap_or_default();
+                if plugin != "}" {
+                    block.plugins.push(plugin.to_string());
+                }
+                if plugin == "forward" || plugin == "proxy" {
+                    block.forwarders.extend(forward_targets(line));
+                }
+            }
+        }
+
+        depth = (depth + opens).saturating_sub(closes);
+        if depth == 0 {
+            if let Some(block) = current.take() {
+                blocks.push(block);
+            }
+        }
+    }
+    blocks
+}
+
+// The root zone may be written "." or ".:53"
+fn is_root_zone(zone: &str) -> bool {
+    let zone = zone.trim_start_matches("dns://");
+    zone == "." || zone.starts_with(".:")
+}
+
+// (upstream forwarders, stub domains) from parsed server blocks
+pub fn kuboard_dns_forwarding(blocks: &[DnsServerBlock]) -> (Vec<String>, BTreeMap<String, Vec<String>>) {
+    let mut upstream = Vec::new();
+    let mut stub_domains = BTreeMap::new();
+    for block in blocks {
+        if block.zones.iter().any(|z| is_root_zone(z)) {
+            upstream.extend(block.forwarders.iter().cloned());
+        } else if !block.forwarders.is_empty() {
+            for zone in &block.zones {
+                stub_domains.insert(zone.clone(), block.forwarders.clone());
+            }
+        }
+    }
+    (upstream, stub_domains)
+}
+
+// kube-dns keeps stubDomains and upstreamNameservers as JSON strings
+fn kube_dns_forwarding(config: &ConfigMap) -> (Vec<String>, BTreeMap<String, Vec<String>>) {
+    let data = config.data.clone().unwrap_or_default();
+    let stub_domains = data.get("stubDomains").and_then(|s| serde_json::from_str(s).ok()).unwrap_or_default();
+    let upstream = data.get("upstreamNameservers").and_then(|s| serde_json::from_str(s).ok()).unwrap_or_default();
+    (upstream, stub_domains)
+}
+
+// CoreDNS writes failures as "[ERROR] ..." lines
+pub fn kuboard_dns_error_lines(logs: &str) -> Vec<String> {
+    logs.lines().filter(|line| line.contains("[ERROR]")).map(str::to_string).collect()
+}
+
+pub async fn kuboard_summarize_cluster_dns(client: &Client) -> Result<ClusterDnsSummary> {
+    let config_maps: Api<ConfigMap> = Api::namespaced(client.clone(), DNS_NAMESPACE);
+    let mut summary = ClusterDnsSummary {
+        provider: "unknown".to_string(),
+        corefile: None,
+        server_blocks: Vec::new(),
+        upstream_forwarders: Vec::new(),
+        stub_domains: BTreeMap::new(),
+        service_ip: None,
+        ready_endpoints: 0,
+        not_ready_endpoints: 0,
+        pods: Vec::new(),
+        recent_errors: Vec::new(),
+    };
+
+    if let Some(coredns) = config_maps.get_opt("coredns").await? {
+        let corefile = coredns.data.and_then(|d| d.get("Corefile").cloned()).unwrap_or_default();
+        summary.provider = "coredns".to_string();
+        summary.server_blocks = kuboard_parse_corefile(&corefile);
+        (summary.upstream_forwarders, summary.stub_domains) = kuboard_dns_forwarding(&summary.server_blocks);
+        summary.corefile = Some(corefile);
+    } else if let Some(kube_dns) = config_maps.get_opt("kube-dns").await? {
+        summary.provider = "kube-dns".to_string();
+        (summary.upstream_forwarders, summary.stub_domains) = kube_dns_forwarding(&kube_dns);
+    }
+
+    let services: Api<Service> = Api::namespaced(client.clone(), DNS_NAMESPACE);
+    summary.service_ip = services.get_opt(DNS_SERVICE).await?.and_then(|s| s.spec?.cluster_ip);
+    let endpoints: Api<Endpoints> = Api::namespaced(client.clone(), DNS_NAMESPACE);
+    if let Some(endpoints) = endpoints.get_opt(DNS_SERVICE).await? {
+        for subset in endpoints.subsets.iter().flatten() {
+            summary.ready_endpoints += subset.addresses.as_ref().map_or(0, Vec::len);
+            summary.not_ready_endpoints += subset.not_ready_addresses.as_ref().map_or(0, Vec::len);
+        }
+    }
+
+    let pods_api: Api<Pod> = Api::namespaced(client.clone(), DNS_NAMESPACE);
+    for pod in pods_api.list(&ListParams::default().labels(DNS_POD_SELECTOR)).await?.items {
+        let name = pod.metadata.name.clone().unwrap_or_default();
+        let status = pod.status.as_ref();
+        let statuses = status.and_then(|s| s.container_statuses.as_ref());
+        summary.pods.push(DnsPodStatus {
+            name: name.clone(),
+            phase: status.and_then(|s| s.phase.clone()).unwrap_or_default(),
+            ready: statuses.is_some_and(|s| !s.is_empty() && s.iter().all(|c| c.ready)),
+            restarts: statuses.map_or(0, |s| s.iter().map(|c| c.restart_count).sum()),
+            node: pod.spec.as_ref().and_then(|s| s.node_name.clone()),
+            age: pod.metadata.creation_timestamp.as_ref().map(|t| kuboard_format_age(t.0)),
+        });
+
+        let params = LogParams { tail_lines: Some(LOG_TAIL_LINES), ..LogParams::default() };
+        match pods_api.logs(&name, &params).await {
+            Ok(logs) => summary.recent_errors.extend(
+                kuboard_dns_error_lines(&logs).into_iter().map(|line| DnsLogLine { pod: name.clone(), line }),
+            ),
+            // One unreadable pod shouldn't hide the rest of the summary
+            Err(e) => warn!("Could not read logs of DNS pod {}: {}", name, e),
+        }
+    }
+    let excess = summary.recent_errors.len().saturating_sub(MAX_ERROR_LINES);
+    summary.recent_errors.drain(..excess);
+
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COREFILE: &str = r#"
+.:53 {
+    errors
+    health {
+       lameduck 5s
+    }
+    kubernetes cluster.local in-addr.arpa ip6.arpa {
+       pods insecure
+       fallthrough in-addr.arpa ip6.arpa
+    }
+    forward . /etc/resolv.conf {
+       max_concurrent 1000
+    }
+    cache 30
+}
+consul.local:53 {
+    errors
+    forward . 10.150.0.1 10.150.0.2 # consul servers
+}
+"#;
+
+    #[test]
+    fn test_parse_corefile() {
+        let blocks = kuboard_parse_corefile(COREFILE);
+
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].zones, vec![".:53".to_string()]);
+        assert_eq!(blocks[0].plugins, vec!["errors", "health", "kubernetes", "forward", "cache"]);
+        assert_eq!(blocks[1].forwarders, vec!["10.150.0.1".to_string(), "10.150.0.2".to_string()]);
+
+        let (upstream, stub_domains) = kuboard_dns_forwarding(&blocks);
+        assert_eq!(upstream, vec!["/etc/resolv.conf".to_string()]);
+        assert_eq!(stub_domains["consul.local:53"].len(), 2);
+    }
+
+    #[test]
+    fn test_dns_error_lines() {
+        let logs = "[INFO] plugin/reload: Running configuration\n[ERROR] plugin/errors: 2 example.com. A: read udp i/o timeout\n";
+        assert_eq!(kuboard_dns_error_lines(logs).len(), 1);
+    }
+}
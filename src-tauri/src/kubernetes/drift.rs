@@ -0,0 +1,355 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Scheduled Resource Snapshots
+// Periodically snapshots namespaces and raises drift alerts on changes
+
+use anyhow::Result;
+use kube::{Api, Client};
+use k8s_openapi::api::apps::v1::{Deployment, StatefulSet, DaemonSet};
+use k8s_openapi::api::core::v1::PodTemplateSpec;
+use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter};
+use tokio::sync::{mpsc, RwLock};
+use tokio::task::JoinHandle;
+use tracing::{error, info, warn};
+
+// Keep the alert history bounded so a long-running session doesn't grow without limit
+const MAX_DRIFT_ALERTS: usize = 100;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkloadSnapshot {
+    pub kind: String,
+    pub name: String,
+    pub namespace: String,
+    pub replicas: Option<i32>,
+    pub images: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NamespaceSnapshot {
+    pub namespace: String,
+    pub taken_at: i64,
+    // Keyed by "kind/name" for stable comparison between snapshots
+    pub workloads: BTreeMap<String, WorkloadSnapshot>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DriftChangeType {
+    WorkloadAdded,
+    WorkloadRemoved,
+    ImageChanged,
+    ReplicasChanged,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DriftChange {
+    pub change_type: DriftChangeType,
+    pub kind: String,
+    pub name: String,
+    pub namespace: String,
+    pub previous: Option<String>,
+    pub current: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DriftAlert {
+    pub namespace: String,
+    pub detected_at: i64,
+    pub changes: Vec<DriftChange>,
+}
+
+fn template_images(template: &PodTemplateSpec) -> Vec<String> {
+    template.spec.as_ref()
+        .map(|spec| spec.containers.iter()
+            .map(|c| c.image.clone().unwrap_or_default())
+            .collect())
+        .unwrap_or_default()
+}
+
+fn workload_snapshot(kind: &str, name: Option<&String>, namespace: &str, replicas: Option<i32>, images: Vec<String>) -> WorkloadSnapshot {
+    WorkloadSnapshot {
+        kind: kind.to_string(),
+        name: name.cloned().unwrap_or_default(),
+        namespace: namespace.to_string(),
+        replicas,
+        images,
+    }
+}
+
+pub async fn take_namespace_snapshot(client: &Client, namespace: &str) -> Result<NamespaceSnapshot> {
+    let mut workloads = Vec::new();
+
+    let deployments_api: Api<Deployment> = Api::namespaced(client.clone(), namespace);
+    for deployment in deployments_api.list(&Default::default()).await?.items {
+        let spec = deployment.spec.as_ref();
+        workloads.push(workload_snapshot(
+            "Deployment",
+            deployment.metadata.name.as_ref(),
+            namespace,
+            spec.and_then(|s| s.replicas),
+            spec.map(|s| template_images(&s.template)).unwrap_or_default(),
+        ));
+    }
+
+    let statefulsets_api: Api<StatefulSet> = Api::namespaced(client.clone(), namespace);
+    for statefulset in statefulsets_api.list(&Default::default()).await?.items {
+        let spec = statefulset.spec.as_ref();
+        workloads.push(workload_snapshot(
+            "StatefulSet",
+            statefulset.metadata.name.as_ref(),
+            namespace,
+            spec.and_then(|s| s.replicas),
+            spec.map(|s| template_images(&s.template)).unwrap_or_default(),
+        ));
+    }
+
+    let daemonsets_api: Api<DaemonSet> = Api::namespaced(client.clone(), namespace);
+    for daemonset in daemonsets_api.list(&Default::default()).await?.items {
+        let spec = daemonset.spec.as_ref();
+        workloads.push(workload_snapshot(
+            "DaemonSet",
+            daemonset.metadata.name.as_ref(),
+            namespace,
+            None,
+            spec.map(|s| template_images(&s.template)).unwrap_or_default(),
+        ));
+    }
+
+    Ok(NamespaceSnapshot {
+        namespace: namespace.to_string(),
+        taken_at: chrono::Utc::now().timestamp(),
+        workloads: workloads
+            .into_iter()
+            .map(|w| (format!("{}/{}", w.kind, w.name), w))
+            .collect(),
+    })
+}
+
+pub fn diff_snapshots(previous: &NamespaceSnapshot, current: &NamespaceSnapshot) -> Vec<DriftChange> {
+    let mut changes = Vec::new();
+
+    for (key, workload) in &current.workloads {
+        let change = |change_type, previous: Option<String>, current: Option<String>| DriftChange {
+            change_type,
+            kind: workload.kind.clone(),
+            name: workload.name.clone(),
+            namespace: workload.namespace.clone(),
+            previous,
+            current,
+        };
+
+        match previous.workloads.get(key) {
+            None => changes.push(change(DriftChangeType::WorkloadAdded, None, Some(workload.images.join(", ")))),
+            Some(before) => {
+                if before.images != workload.images {
+                    changes.push(change(
+                        DriftChangeType::ImageChanged,
+                        Some(before.images.join(", ")),
+                        Some(workload.images.join(", ")),
+                    ));
+                }
+                if before.replicas != workload.replicas {
+                    changes.push(change(
+                        DriftChangeType::ReplicasChanged,
+                        before.replicas.map(|r| r.to_string()),
+                        workload.replicas.map(|r| r.to_string()),
+                    ));
+                }
+            }
+        }
+    }
+
+    for (key, workload) in &previous.workloads {
+        if !current.workloads.contains_key(key) {
+            changes.push(DriftChange {
+                change_type: DriftChangeType::WorkloadRemoved,
+                kind: workload.kind.clone(),
+                name: workload.name.clone(),
+                namespace: workload.namespace.clone(),
+                previous: Some(workload.images.join(", ")),
+                current: None,
+            });
+        }
+    }
+
+    changes
+}
+
+pub struct DriftScheduler {
+    handle: Option<JoinHandle<()>>,
+    stop_tx: Option<mpsc::Sender<()>>,
+    snapshots: Arc<RwLock<HashMap<String, NamespaceSnapshot>>>,
+    alerts: Arc<RwLock<Vec<DriftAlert>>>,
+}
+
+impl DriftScheduler {
+    pub fn new() -> Self {
+        Self {
+            handle: None,
+            stop_tx: None,
+            snapshots: Arc::new(RwLock::new(HashMap::new())),
+            alerts: Arc::new(RwLock::new(Vec::new())),
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.handle.is_some()
+    }
+
+    pub async fn alerts(&self) -> Vec<DriftAlert> {
+        self.alerts.read().await.clone()
+    }
+
+    pub fn stop(&mut self) {
+        if let Some(tx) = self.stop_tx.take() {
+            let _ = tx.try_send(());
+        }
+        if let Some(handle) = self.handle.take() {
+            handle.abort();
+        }
+    }
+
+    pub async fn start(
+        &mut self,
+        client: Client,
+        app_handle: AppHandle,
+        namespaces: Vec<String>,
+        interval_seconds: u64,
+    ) -> Result<(), String> {
+        self.stop();
+
+        if namespaces.is_empty() {
+            return Err("At least one namespace must be selected for drift snapshots".to_string());
+        }
+
+        info!("Starting drift scheduler for {:?} every {}s", namespaces, interval_seconds);
+
+        // Baselines from a previous run may belong to another context
+        self.snapshots.write().await.clear();
+
+        let snapshots = self.snapshots.clone();
+        let alerts = self.alerts.clone();
+        let (stop_tx, mut stop_rx) = mpsc::channel::<()>(1);
+
+        let handle = tokio::spawn(async move {
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(interval_seconds));
+
+            loop {
+                tokio::select! {
+                    _ = stop_rx.recv() => {
+                        info!("Drift scheduler stopped by user");
+                        break;
+                    }
+                    _ = interval.tick() => {
+                        for namespace in &namespaces {
+                            let current = match take_namespace_snapshot(&client, namespace).await {
+                                Ok(snapshot) => snapshot,
+                                Err(e) => {
+                                    warn!("Failed to snapshot namespace {}: {}", namespace, e);
+                                    continue;
+                                }
+                            };
+
+                            let previous = snapshots.write().await.insert(namespace.clone(), current.clone());
+                            let Some(previous) = previous else {
+                                info!("Recorded baseline snapshot for namespace {}", namespace);
+                                continue;
+                            };
+
+                            let changes = diff_snapshots(&previous, &current);
+                            if changes.is_empty() {
+                                continue;
+                            }
+
+                            let alert = DriftAlert {
+                                namespace: namespace.clone(),
+                                detected_at: current.taken_at,
+                                changes,
+                            };
+                            info!("Drift detected in namespace {}: {} change(s)", namespace, alert.changes.len());
+
+                            {
+                                let mut alerts_guard = alerts.write().await;
+                                alerts_guard.push(alert.clone());
+                                if alerts_guard.len() > MAX_DRIFT_ALERTS {
+                                    let excess = alerts_guard.len() - MAX_DRIFT_ALERTS;
+                                    alerts_guard.drain(..excess);
+                                }
+                            }
+
+                            if let Err(e) = app_handle.emit("drift-alert", alert) {
+                                error!("Failed to emit drift alert: {}", e);
+                            }
+                        }
+                    }
+                }
+            }
+
+            info!("Drift scheduler task completed");
+        });
+
+        self.handle = Some(handle);
+        self.stop_tx = Some(stop_tx);
+
+        Ok(())
+    }
+}
+
+impl Drop for DriftScheduler {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(workloads: Vec<WorkloadSnapshot>) -> NamespaceSnapshot {
+        NamespaceSnapshot {
+            namespace: "default".to_string(),
+            taken_at: 0,
+            workloads: workloads
+                .into_iter()
+                .map(|w| (format!("{}/{}", w.kind, w.name), w))
+                .collect(),
+        }
+    }
+
+    fn deployment(name: &str, replicas: i32, image: &str) -> WorkloadSnapshot {
+        WorkloadSnapshot {
+            kind: "Deployment".to_string(),
+            name: name.to_string(),
+            namespace: "default".to_string(),
+            replicas: Some(replicas),
+            images: vec![image.to_string()],
+        }
+    }
+
+    #[test]
+    fn test_diff_snapshots_detects_image_and_replica_drift() {
+        let previous = snapshot(vec![deployment("api", 2, "api:1.0"), deployment("old", 1, "old:1.0")]);
+        let current = snapshot(vec![deployment("api", 3, "api:1.1"), deployment("new", 1, "new:1.0")]);
+
+        let changes = diff_snapshots(&previous, &current);
+        let types: Vec<DriftChangeType> = changes.iter().map(|c| c.change_type.clone()).collect();
+
+        assert_eq!(changes.len(), 4);
+        assert!(types.contains(&DriftChangeType::ImageChanged));
+        assert!(types.contains(&DriftChangeType::ReplicasChanged));
+        assert!(types.contains(&DriftChangeType::WorkloadAdded));
+        assert!(types.contains(&DriftChangeType::WorkloadRemoved));
+    }
+
+    #[test]
+    fn test_diff_snapshots_no_drift() {
+        let previous = snapshot(vec![deployment("api", 2, "api:1.0")]);
+        let current = snapshot(vec![deployment("api", 2, "api:1.0")]);
+
+        assert!(diff_snapshots(&previous, &current).is_empty());
+    }
+}
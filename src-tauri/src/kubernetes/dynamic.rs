@@ -0,0 +1,103 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Dynamic Resources
+// Any kind the cluster serves, built-in or custom, addressed by apiVersion and kind through API
+// discovery, for commands that work the same way on every kind
+
+use anyhow::{anyhow, Result};
+use kube::api::{ApiResource, DynamicObject, GroupVersionKind, ListParams, PropagationPolicy};
+use kube::discovery::{pinned_kind, Scope};
+use kube::{Api, Client};
+
+use crate::kubernetes::namespace_access::{kuboard_list_scoped_dynamic, kuboard_list_scoped_dynamic_page};
+use crate::kubernetes::pagination::{kuboard_list_all_pages, kuboard_list_page};
+use crate::types::{ListPage, NamespaceFilter};
+
+pub struct DynamicKind {
+    pub resource: ApiResource,
+    pub namespaced: bool,
+}
+
+impl DynamicKind {
+    // The API to address one object by name; namespaced kinds need a namespace, cluster-scoped
+    // kinds ignore it
+    pub fn object_api(&self, client: &Client, namespace: Option<&str>) -> Result<Api<DynamicObject>> {
+        if !self.namespaced {
+            return Ok(Api::all_with(client.clone(), &self.resource));
+        }
+        match namespace.filter(|ns| !ns.is_empty()) {
+            Some(namespace) => Ok(Api::namespaced_with(client.clone(), namespace, &self.resource)),
+            None => Err(anyhow!("{} is namespaced; a namespace is required", self.resource.kind)),
+        }
+    }
+
+    // Objects of one namespace, or of every namespace the filter allows when none is given;
+    // cluster-scoped kinds ignore the namespace
+    pub async fn list(
+        &self,
+        client: &Client,
+        filter: &NamespaceFilter,
+        namespace: Option<&str>,
+        params: &ListParams,
+    ) -> Result<Vec<DynamicObject>> {
+        if !self.namespaced {
+            return Ok(kuboard_list_all_pages(&Api::<DynamicObject>::all_with(client.clone(), &self.resource), params).await?);
+        }
+        kuboard_list_scoped_dynamic(client, filter, &self.resource, namespace, params).await
+    }
+
+    // One page of `list`
+    pub async fn list_page(
+        &self,
+        client: &Client,
+        filter: &NamespaceFilter,
+        namespace: Option<&str>,
+        params: &ListParams,
+        limit: Option<u32>,
+        continue_token: Option<&str>,
+    ) -> Result<ListPage<DynamicObject>> {
+        if !self.namespaced {
+            let api = Api::<DynamicObject>::all_with(client.clone(), &self.resource);
+            return kuboard_list_page(&api, params, limit, continue_token).await;
+        }
+        kuboard_list_scoped_dynamic_page(client, filter, &self.resource, namespace, params, limit, continue_token).await
+    }
+}
+
+pub async fn kuboard_resolve_kind(client: &Client, api_version: &str, kind: &str) -> Result<DynamicKind> {
+    let (group, version) = api_version.rsplit_once('/').unwrap_or(("", api_version));
+    kuboard_resolve_gvk(client, group, version, kind).await
+}
+
+// The core group may be given as "" or "core", as in RBAC rules and kubectl api-resources
+pub async fn kuboard_resolve_gvk(client: &Client, group: &str, version: &str, kind: &str) -> Result<DynamicKind> {
+    let group = if group == "core" { "" } else { group };
+    let gvk = GroupVersionKind::gvk(group, version, kind);
+    let (resource, capabilities) = pinned_kind(client, &gvk).await
+        .map_err(|e| anyhow!("Unknown kind {} in {}: {}", kind, gvk.api_version(), e))?;
+    Ok(DynamicKind { resource, namespaced: capabilities.scope == Scope::Namespaced })
+}
+
+// kubectl's --cascade values are accepted too: background, foreground and orphan
+pub fn kuboard_propagation_policy(value: &str) -> Result<PropagationPolicy> {
+    match value.to_ascii_lowercase().as_str() {
+        "background" => Ok(PropagationPolicy::Background),
+        "foreground" => Ok(PropagationPolicy::Foreground),
+        "orphan" => Ok(PropagationPolicy::Orphan),
+        _ => Err(anyhow!("Unknown propagation policy {}; use Background, Foreground or Orphan", value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_propagation_policy() {
+        assert!(matches!(kuboard_propagation_policy("Foreground"), Ok(PropagationPolicy::Foreground)));
+        assert!(matches!(kuboard_propagation_policy("orphan"), Ok(PropagationPolicy::Orphan)));
+        assert!(matches!(kuboard_propagation_policy("Background"), Ok(PropagationPolicy::Background)));
+        assert!(kuboard_propagation_policy("cascade").is_err());
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// YAML Editing
+// Saves an edited manifest of any kind over the live object with server-side apply. The
+// resourceVersion the edit was loaded with guards the write, so an object someone else changed in
+// the meantime is reported as a conflict instead of silently losing their change
+
+use anyhow::{anyhow, Result};
+use kube::api::DynamicObject;
+use kube::Client;
+use serde_json::Value;
+
+use crate::kubernetes::apply::{kuboard_apply_params, kuboard_resolve_apply_target};
+use crate::kubernetes::conflict::{kuboard_changed_fields, ConflictError};
+use crate::types::ConflictReport;
+
+// The edit has to be of the object being edited; a missing name is taken from the target
+pub fn kuboard_check_edit_target(manifest: &mut Value, kind: &str, name: &str, namespace: &str) -> Result<()> {
+    match manifest.get("kind").and_then(Value::as_str) {
+        Some(edited) if edited != kind => return Err(anyhow!("Kind mismatch: expected {}, got {}", kind, edited)),
+        Some(_) => {}
+        None => return Err(anyhow!("Manifest has no kind")),
+    }
+    let metadata = manifest.as_object_mut()
+        .ok_or_else(|| anyhow!("Manifest is not an object"))?
+        .entry("metadata")
+        .or_insert_with(|| Value::Object(Default::default()));
+    match metadata.get("name").and_then(Value::as_str) {
+        Some(edited) if edited != name => return Err(anyhow!("{} name mismatch: expected {}, got {}", kind, name, edited)),
+        Some(_) => {}
+        None => metadata["name"] = Value::String(name.to_string()),
+    }
+    match metadata.get("namespace").and_then(Value::as_str) {
+        Some(edited) if edited != namespace => {
+            Err(anyhow!("{} namespace mismatch: expected {}, got {}", kind, namespace, edited))
+        }
+        _ => Ok(()),
+    }
+}
+
+// Applies the edit over the live object. With `force`, or when the edit carries no resourceVersion
+// (e.g. a clean export), it applies over the latest version, `force` also taking over fields other
+// managers own; otherwise a write since the edit was loaded fails with a ConflictError listing
+// where the edit and the latest version differ
+pub async fn kuboard_apply_edit(
+    client: &Client,
+    mut manifest: Value,
+    kind: &str,
+    name: &str,
+    namespace: &str,
+    force: bool,
+    dry_run: bool,
+) -> Result<DynamicObject> {
+    kuboard_check_edit_target(&mut manifest, kind, name, namespace)?;
+    let edited_version = manifest.pointer("/metadata/resourceVersion").and_then(Value::as_str).map(str::to_string);
+    let mut target = kuboard_resolve_apply_target(client, &manifest, namespace).await?;
+    let target_name = match &target.namespace {
+        Some(namespace) => format!("{}/{}", namespace, name),
+        None => name.to_string(),
+    };
+
+    // Applying would otherwise create the object, which an edit must not
+    target.live().await?.ok_or_else(|| anyhow!("{} {} not found", kind, target_name))?;
+    target.object.metadata.resource_version = edited_version.clone().filter(|_| !force);
+
+    match target.apply(&kuboard_apply_params(force, dry_run)).await {
+        Ok(updated) => Ok(updated),
+        Err(kube::Error::Api(e)) if e.code == 409 => {
+            let latest = target.live().await?.ok_or_else(|| anyhow!("{} {} was deleted while it was being edited", kind, target_name))?;
+            let latest_version = latest.metadata.resource_version.clone();
+            // An unchanged object means the apply conflicted over fields another manager owns
+            let message = if target.object.metadata.resource_version.is_none() || latest_version == edited_version {
+                format!("{} {} has fields owned by another manager ({}); save with force to take them over", kind, target_name, e.message)
+            } else {
+                format!(
+                    "{} {} changed since it was loaded (resourceVersion {} is now {}); reload it, or save with force to overwrite the latest version",
+                    kind,
+                    target_name,
+                    edited_version.as_deref().unwrap_or("unknown"),
+                    latest_version.as_deref().unwrap_or("unknown"),
+                )
+            };
+            Err(ConflictError(ConflictReport {
+                reason: "Conflict".to_string(),
+                kind: kind.to_string(),
+                name: name.to_string(),
+                namespace: target.namespace.clone(),
+                attempts: 1,
+                changed_fields: kuboard_changed_fields(&manifest, &serde_json::to_value(&latest)?),
+                message,
+                latest_resource_version: latest_version,
+            }).into())
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_check_edit_target() {
+        let mut unnamed = json!({ "apiVersion": "v1", "kind": "ConfigMap", "data": { "mode": "fast" } });
+        kuboard_check_edit_target(&mut unnamed, "ConfigMap", "settings", "default").unwrap();
+        assert_eq!(unnamed["metadata"]["name"], "settings");
+
+        let mut edited = json!({ "kind": "ConfigMap", "metadata": { "name": "settings", "namespace": "default" } });
+        assert!(kuboard_check_edit_target(&mut edited, "ConfigMap", "settings", "default").is_ok());
+        assert!(kuboard_check_edit_target(&mut edited, "Secret", "settings", "default").is_err());
+        assert!(kuboard_check_edit_target(&mut edited, "ConfigMap", "other", "default").is_err());
+        assert!(kuboard_check_edit_target(&mut edited, "ConfigMap", "settings", "staging").is_err());
+        assert!(kuboard_check_edit_target(&mut json!({ "metadata": {} }), "ConfigMap", "settings", "default").is_err());
+    }
+}
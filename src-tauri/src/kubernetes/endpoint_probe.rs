@@ -0,0 +1,197 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Service Endpoint Probes
+// Checks each endpoint behind a service over TCP (port-forward) or HTTP (API server pod proxy)
+
+use anyhow::{anyhow, Result};
+use futures_util::future::join_all;
+use kube::client::Body;
+use kube::{Api, Client};
+use k8s_openapi::api::core::v1::{EndpointAddress, Endpoints, Pod};
+use std::time::{Duration, Instant};
+
+use crate::types::{EndpointProbeResult, ServiceProbeReport};
+
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+// How long a port-forward may stay error-free before the target port counts as open
+const TCP_SETTLE: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProbeMode {
+    Tcp,
+    Http,
+}
+
+impl ProbeMode {
+    pub fn parse(mode: &str) -> Result<Self> {
+        match mode {
+            "tcp" => Ok(Self::Tcp),
+            "http" => Ok(Self::Http),
+            other => Err(anyhow!("Unknown probe mode {}, expected tcp or http", other)),
+        }
+    }
+}
+
+// One endpoint address/port to probe
+pub struct ProbeTarget {
+    pub ip: String,
+    pub port: u16,
+    pub port_name: Option<String>,
+    pub pod_name: Option<String>,
+    pub node_name: Option<String>,
+    pub ready: bool,
+}
+
+// Ready and not-ready addresses crossed with their subset's ports, optionally limited to one port
+pub fn kuboard_endpoint_probe_targets(endpoints: &Endpoints, port: Option<u16>) -> Vec<ProbeTarget> {
+    let mut targets = Vec::new();
+    for subset in endpoints.subsets.iter().flatten() {
+        let addresses = subset.addresses.iter().flatten().map(|a| (a, true))
+            .chain(subset.not_ready_addresses.iter().flatten().map(|a| (a, false)));
+        for (address, ready) in addresses {
+            for endpoint_port in subset.ports.iter().flatten() {
+                if endpoint_port.protocol.as_deref().is_some_and(|p| p != "TCP") {
+                    continue;
+                }
+                let Ok(number) = u16::try_from(endpoint_port.port) else { continue };
+                if port.is_some_and(|p| p != number) {
+                    continue;
+                }
+                targets.push(target(address, number, endpoint_port.name.clone(), ready));
+            }
+        }
+    }
+    targets
+}
+
+fn target(address: &EndpointAddress, port: u16, port_name: Option<String>, ready: bool) -> ProbeTarget {
+    ProbeTarget {
+        ip: address.ip.clone(),
+        port,
+        port_name,
+        pod_name: address.target_ref.as_ref().filter(|r| r.kind.as_deref() == Some("Pod")).and_then(|r| r.name.clone()),
+        node_name: address.node_name.clone(),
+        ready,
+    }
+}
+
+// GET through /pods/{name}:{port}/proxy; the API server answers 502/503 itself when it can't reach the pod
+async fn probe_http(client: &Client, namespace: &str, pod: &str, port: u16, path: &str) -> Result<(bool, Option<u16>)> {
+    let uri = format!("/api/v1/namespaces/{}/pods/{}:{}/proxy/{}", namespace, pod, port, path.trim_start_matches('/'));
+    let request = http::Request::get(uri).body(Body::empty())?;
+    let response = client.send(request).await?;
+    let status = response.status().as_u16();
+    // Any status from the container itself, even a 404, means the endpoint answered
+    Ok((!matches!(status, 502 | 503), Some(status)))
+}
+
+// The kubelet dials the pod as the port-forward opens and reports a refused connection on the error channel
+async fn probe_tcp(client: &Client, namespace: &str, pod: &str, port: u16) -> Result<(bool, Option<u16>)> {
+    let pods_api: Api<Pod> = Api::namespaced(client.clone(), namespace);
+    let mut forwarder = pods_api.portforward(pod, &[port]).await?;
+    let _stream = forwarder.take_stream(port).ok_or_else(|| anyhow!("Port-forward stream unavailable"))?;
+    let error = forwarder.take_error(port).ok_or_else(|| anyhow!("Port-forward error channel unavailable"))?;
+    let result = match tokio::time::timeout(TCP_SETTLE, error).await {
+        Ok(Some(message)) => Err(anyhow!(message)),
+        _ => Ok((true, None)),
+    };
+    forwarder.abort();
+    result
+}
+
+async fn probe(client: &Client, namespace: &str, target: ProbeTarget, mode: ProbeMode, path: &str) -> EndpointProbeResult {
+    let mut result = EndpointProbeResult {
+        ip: target.ip,
+        port: target.port,
+        port_name: target.port_name,
+        pod_name: target.pod_name.clone(),
+        node_name: target.node_name,
+        ready: target.ready,
+        reachable: false,
+        status_code: None,
+        latency_ms: None,
+        error: None,
+    };
+    // Both probe paths go through the pod; manually managed endpoints have none
+    let Some(pod) = target.pod_name else {
+        result.error = Some("Endpoint is not backed by a pod".to_string());
+        return result;
+    };
+
+    let started = Instant::now();
+    let outcome = tokio::time::timeout(PROBE_TIMEOUT, async {
+        match mode {
+            ProbeMode::Http => probe_http(client, namespace, &pod, target.port, path).await,
+            ProbeMode::Tcp => probe_tcp(client, namespace, &pod, target.port).await,
+        }
+    }).await;
+
+    match outcome {
+        Ok(Ok((reachable, status_code))) => {
+            result.reachable = reachable;
+            result.status_code = status_code;
+            result.latency_ms = Some(started.elapsed().as_millis() as u64);
+        }
+        Ok(Err(e)) => result.error = Some(e.to_string()),
+        Err(_) => result.error = Some(format!("Timed out after {}s", PROBE_TIMEOUT.as_secs())),
+    }
+    result
+}
+
+pub async fn kuboard_probe_endpoints(
+    client: &Client,
+    namespace: &str,
+    service: &str,
+    mode: ProbeMode,
+    port: Option<u16>,
+    path: &str,
+) -> Result<ServiceProbeReport> {
+    let endpoints_api: Api<Endpoints> = Api::namespaced(client.clone(), namespace);
+    let endpoints = endpoints_api.get(service).await?;
+
+    let probes = kuboard_endpoint_probe_targets(&endpoints, port)
+        .into_iter()
+        .map(|target| probe(client, namespace, target, mode, path));
+    let results = join_all(probes).await;
+
+    Ok(ServiceProbeReport {
+        service: service.to_string(),
+        namespace: namespace.to_string(),
+        mode: match mode {
+            ProbeMode::Tcp => "tcp".to_string(),
+            ProbeMode::Http => "http".to_string(),
+        },
+        reachable: results.iter().filter(|r| r.reachable).count(),
+        endpoints: results,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_endpoint_probe_targets() {
+        let endpoints: Endpoints = serde_json::from_value(json!({
+            "metadata": { "name": "api" },
+            "subsets": [{
+                "addresses": [{ "ip": "10.0.0.1", "targetRef": { "kind": "Pod", "name": "api-1" } }],
+                "notReadyAddresses": [{ "ip": "10.0.0.2", "targetRef": { "kind": "Pod", "name": "api-2" } }],
+                "ports": [
+                    { "name": "http", "port": 8080, "protocol": "TCP" },
+                    { "name": "metrics", "port": 9090 },
+                    { "name": "dns", "port": 53, "protocol": "UDP" },
+                ],
+            }],
+        })).unwrap();
+
+        let targets = kuboard_endpoint_probe_targets(&endpoints, None);
+        assert_eq!(targets.len(), 4);
+        assert!(!targets[3].ready);
+
+        let http = kuboard_endpoint_probe_targets(&endpoints, Some(8080));
+        assert_eq!(http.iter().map(|t| t.pod_name.as_deref()).collect::<Vec<_>>(), vec![Some("api-1"), Some("api-2")]);
+    }
+}
@@ -0,0 +1,161 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Ephemeral Storage
+// Compares each pod's ephemeral-storage usage, as the kubelet's stats summary reports it, with the
+// limits the kubelet evicts on, so pods about to be evicted for disk use show up before it happens
+
+use anyhow::Result;
+use futures_util::future::join_all;
+use k8s_openapi::api::core::v1::Pod;
+use kube::Client;
+use serde::Deserialize;
+use std::collections::HashMap;
+use tracing::warn;
+
+use crate::quantity::{kuboard_parse_bytes, kuboard_quantity_percent};
+use crate::types::EphemeralStorageUsage;
+
+pub const DEFAULT_EPHEMERAL_STORAGE_THRESHOLD_PERCENT: f64 = 80.0;
+
+// The parts of the kubelet's /stats/summary this module reads
+#[derive(Deserialize)]
+struct StatsSummary {
+    #[serde(default)]
+    pods: Vec<PodStats>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PodStats {
+    pod_ref: PodReference,
+    // Container writable layers, logs and local volumes together, which is what the pod limit covers
+    #[serde(rename = "ephemeral-storage")]
+    ephemeral_storage: Option<FsStats>,
+}
+
+#[derive(Deserialize)]
+struct PodReference {
+    name: String,
+    namespace: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FsStats {
+    used_bytes: Option<u64>,
+}
+
+// Used bytes per (namespace, name) for the pods on one node, read through the API server's node proxy
+pub async fn kuboard_fetch_ephemeral_usage(client: &Client, node: &str) -> Result<HashMap<(String, String), u64>> {
+    let request = http::Request::get(format!("/api/v1/nodes/{}/proxy/stats/summary", node)).body(vec![])?;
+    let summary: StatsSummary = client.request(request).await?;
+    Ok(summary.pods.into_iter()
+        .filter_map(|pod| {
+            let used = pod.ephemeral_storage?.used_bytes?;
+            Some(((pod.pod_ref.namespace, pod.pod_ref.name), used))
+        })
+        .collect())
+}
+
+// Usage across several nodes at once; nodes whose kubelet can't be reached are returned by name
+pub async fn kuboard_fetch_ephemeral_usage_on_nodes(client: &Client, nodes: &[String]) -> (HashMap<(String, String), u64>, Vec<String>) {
+    let results = join_all(nodes.iter().map(|node| kuboard_fetch_ephemeral_usage(client, node))).await;
+    let mut usage = HashMap::new();
+    let mut unreachable = Vec::new();
+    for (node, result) in nodes.iter().zip(results) {
+        match result {
+            Ok(node_usage) => usage.extend(node_usage),
+            Err(e) => {
+                warn!("Failed to read kubelet stats from {}: {}", node, e);
+                unreachable.push(node.clone());
+            }
+        }
+    }
+    (usage, unreachable)
+}
+
+fn container_quantities<'a>(pod: &'a Pod, limits: bool) -> impl Iterator<Item = u64> + 'a {
+    pod.spec.as_ref()
+        .map(|s| s.containers.iter())
+        .into_iter()
+        .flatten()
+        .filter_map(move |c| {
+            let resources = c.resources.as_ref()?;
+            let quantities = if limits { resources.limits.as_ref() } else { resources.requests.as_ref() };
+            quantities?.get("ephemeral-storage")
+        })
+        .filter_map(|q| kuboard_parse_bytes(&q.0).ok())
+}
+
+// The kubelet evicts a pod whose usage passes the sum of its containers' limits
+pub fn kuboard_pod_ephemeral_storage(pod: &Pod, used_bytes: Option<u64>) -> EphemeralStorageUsage {
+    let limit: u64 = container_quantities(pod, true).sum();
+    let limit_bytes = (limit > 0).then_some(limit);
+    EphemeralStorageUsage {
+        name: pod.metadata.name.clone().unwrap_or_default(),
+        namespace: pod.metadata.namespace.clone().unwrap_or_default(),
+        node: pod.spec.as_ref().and_then(|s| s.node_name.clone()),
+        request_bytes: container_quantities(pod, false).sum(),
+        limit_bytes,
+        used_bytes,
+        percent_of_limit: used_bytes.zip(limit_bytes).map(|(used, limit)| kuboard_quantity_percent(used as f64, limit as f64)),
+    }
+}
+
+// Pods at or past `threshold_percent` of their limit, closest to eviction first
+pub fn kuboard_pods_near_ephemeral_limit(usages: Vec<EphemeralStorageUsage>, threshold_percent: f64) -> Vec<EphemeralStorageUsage> {
+    let mut near: Vec<EphemeralStorageUsage> = usages.into_iter()
+        .filter(|usage| usage.percent_of_limit.is_some_and(|percent| percent >= threshold_percent))
+        .collect();
+    near.sort_by(|a, b| b.percent_of_limit.partial_cmp(&a.percent_of_limit).unwrap_or(std::cmp::Ordering::Equal));
+    near
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn pod(name: &str, containers: serde_json::Value) -> Pod {
+        serde_json::from_value(json!({
+            "metadata": { "name": name, "namespace": "default" },
+            "spec": { "nodeName": "node-1", "containers": containers },
+        })).unwrap()
+    }
+
+    #[test]
+    fn test_pod_ephemeral_storage() {
+        let limited = pod("cache", json!([
+            { "name": "app", "resources": { "requests": { "ephemeral-storage": "1Gi" }, "limits": { "ephemeral-storage": "2Gi" } } },
+            { "name": "logs", "resources": { "limits": { "ephemeral-storage": "1Gi" } } },
+        ]));
+        let gib = 1024 * 1024 * 1024;
+        let usage = kuboard_pod_ephemeral_storage(&limited, Some(gib * 3 * 9 / 10));
+        assert_eq!((usage.request_bytes, usage.limit_bytes), (gib, Some(3 * gib)));
+        assert!((usage.percent_of_limit.unwrap() - 90.0).abs() < 0.01);
+
+        let unlimited = kuboard_pod_ephemeral_storage(&pod("web", json!([{ "name": "app" }])), Some(gib));
+        assert_eq!(unlimited.limit_bytes, None);
+        assert_eq!(unlimited.percent_of_limit, None);
+
+        let quiet = kuboard_pod_ephemeral_storage(&limited, Some(gib));
+        let near = kuboard_pods_near_ephemeral_limit(vec![quiet, unlimited, usage], DEFAULT_EPHEMERAL_STORAGE_THRESHOLD_PERCENT);
+        assert_eq!(near.len(), 1);
+        assert_eq!(near[0].name, "cache");
+    }
+
+    #[test]
+    fn test_stats_summary_parsing() {
+        let summary: StatsSummary = serde_json::from_value(json!({
+            "node": { "nodeName": "node-1" },
+            "pods": [
+                { "podRef": { "name": "cache", "namespace": "default", "uid": "1" }, "ephemeral-storage": { "usedBytes": 1024, "capacityBytes": 4096 } },
+                { "podRef": { "name": "new", "namespace": "default", "uid": "2" } },
+            ],
+        })).unwrap();
+        assert_eq!(summary.pods.len(), 2);
+        assert_eq!(summary.pods[0].ephemeral_storage.as_ref().and_then(|s| s.used_bytes), Some(1024));
+        assert!(summary.pods[1].ephemeral_storage.is_none());
+    }
+}
@@ -0,0 +1,207 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Event Trends
+// Event occurrences over a recent window, counted by namespace and reason and bucketed over time,
+// so the noisiest namespaces and whether their warnings are picking up stand out
+
+use chrono::{DateTime, Duration, Utc};
+use k8s_openapi::api::core::v1::Event;
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::types::{EventReasonCount, EventTrendBucket, EventTrendDirection, EventTrends, NamespaceEventTrend};
+
+pub const DEFAULT_EVENT_WINDOW_MINUTES: i64 = 60;
+pub const DEFAULT_EVENT_BUCKET_MINUTES: i64 = 5;
+const TOP_REASONS_PER_NAMESPACE: usize = 5;
+
+// An event object stands for `count` occurrences between its first and last timestamps; the API
+// keeps nothing in between, so they're spread evenly over that span
+fn occurrences(event: &Event) -> Vec<DateTime<Utc>> {
+    let last = event.series.as_ref().and_then(|s| s.last_observed_time.as_ref()).map(|t| t.0)
+        .or_else(|| event.last_timestamp.as_ref().map(|t| t.0))
+        .or_else(|| event.event_time.as_ref().map(|t| t.0))
+        .or_else(|| event.metadata.creation_timestamp.as_ref().map(|t| t.0));
+    let Some(last) = last else { return Vec::new() };
+    let first = event.first_timestamp.as_ref().map(|t| t.0)
+        .or_else(|| event.event_time.as_ref().map(|t| t.0))
+        .unwrap_or(last)
+        .min(last);
+    let count = event.series.as_ref().and_then(|s| s.count).or(event.count).unwrap_or(1).max(1) as i64;
+    if count == 1 {
+        return vec![last];
+    }
+    let span = (last - first).num_milliseconds();
+    (0..count).map(|i| first + Duration::milliseconds(span * i / (count - 1))).collect()
+}
+
+// A couple of warnings either way is noise, not a trend
+fn trend(buckets: &[i64]) -> EventTrendDirection {
+    let half = buckets.len() / 2;
+    let earlier: i64 = buckets[..half].iter().sum();
+    let later: i64 = buckets[buckets.len() - half..].iter().sum();
+    if later - earlier >= 3 && later * 2 >= earlier * 3 {
+        EventTrendDirection::Rising
+    } else if earlier - later >= 3 && earlier * 2 >= later * 3 {
+        EventTrendDirection::Falling
+    } else {
+        EventTrendDirection::Steady
+    }
+}
+
+fn sorted_reasons(reasons: BTreeMap<(String, String), (i64, BTreeSet<String>)>) -> Vec<EventReasonCount> {
+    let mut reasons: Vec<EventReasonCount> = reasons.into_iter()
+        .map(|((reason, type_), (count, namespaces))| EventReasonCount {
+            reason,
+            type_,
+            count,
+            namespaces: namespaces.into_iter().collect(),
+        })
+        .collect();
+    reasons.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.reason.cmp(&b.reason)));
+    reasons
+}
+
+#[derive(Default)]
+struct NamespaceTally {
+    total: i64,
+    warnings: i64,
+    warning_buckets: Vec<i64>,
+    reasons: BTreeMap<(String, String), (i64, BTreeSet<String>)>,
+}
+
+// The window is rounded up to whole buckets ending at `now`
+pub fn kuboard_event_trends(events: &[Event], now: DateTime<Utc>, window_minutes: i64, bucket_minutes: i64) -> EventTrends {
+    let window_minutes = window_minutes.max(1);
+    let bucket_minutes = bucket_minutes.clamp(1, window_minutes);
+    let bucket_count = ((window_minutes + bucket_minutes - 1) / bucket_minutes) as usize;
+    let since = now - Duration::minutes(bucket_minutes * bucket_count as i64);
+
+    let mut buckets: Vec<EventTrendBucket> = (0..bucket_count)
+        .map(|index| EventTrendBucket {
+            start: (since + Duration::minutes(bucket_minutes * index as i64)).to_rfc3339(),
+            total: 0,
+            warnings: 0,
+        })
+        .collect();
+    let mut namespaces: BTreeMap<String, NamespaceTally> = BTreeMap::new();
+    let mut reasons: BTreeMap<(String, String), (i64, BTreeSet<String>)> = BTreeMap::new();
+
+    for event in events {
+        let in_window: Vec<usize> = occurrences(event).into_iter()
+            .filter(|at| *at > since && *at <= now)
+            .map(|at| (((at - since).num_seconds() - 1) / (bucket_minutes * 60)) as usize)
+            .collect();
+        if in_window.is_empty() {
+            continue;
+        }
+        let namespace = event.metadata.namespace.clone().unwrap_or_default();
+        let reason = event.reason.clone().unwrap_or_else(|| "Unknown".to_string());
+        let type_ = event.type_.clone().unwrap_or_else(|| "Normal".to_string());
+        let warning = type_ == "Warning";
+        let count = in_window.len() as i64;
+
+        let tally = namespaces.entry(namespace.clone()).or_default();
+        tally.warning_buckets.resize(bucket_count, 0);
+        for index in in_window {
+            let index = index.min(bucket_count - 1);
+            buckets[index].total += 1;
+            if warning {
+                buckets[index].warnings += 1;
+                tally.warning_buckets[index] += 1;
+            }
+        }
+        tally.total += count;
+        if warning {
+            tally.warnings += count;
+        }
+        let entry = tally.reasons.entry((reason.clone(), type_.clone())).or_default();
+        entry.0 += count;
+        entry.1.insert(namespace.clone());
+        let entry = reasons.entry((reason, type_)).or_default();
+        entry.0 += count;
+        entry.1.insert(namespace);
+    }
+
+    let hours = (bucket_minutes * bucket_count as i64) as f64 / 60.0;
+    let mut namespaces: Vec<NamespaceEventTrend> = namespaces.into_iter()
+        .map(|(namespace, tally)| NamespaceEventTrend {
+            namespace,
+            total: tally.total,
+            warnings: tally.warnings,
+            per_hour: (tally.total as f64 / hours * 10.0).round() / 10.0,
+            trend: trend(&tally.warning_buckets),
+            warning_buckets: tally.warning_buckets,
+            top_reasons: sorted_reasons(tally.reasons).into_iter().take(TOP_REASONS_PER_NAMESPACE).collect(),
+        })
+        .collect();
+    namespaces.sort_by(|a, b| {
+        b.warnings.cmp(&a.warnings).then(b.total.cmp(&a.total)).then_with(|| a.namespace.cmp(&b.namespace))
+    });
+
+    let warning_buckets: Vec<i64> = buckets.iter().map(|b| b.warnings).collect();
+    EventTrends {
+        since: since.to_rfc3339(),
+        window_minutes: bucket_minutes * bucket_count as i64,
+        bucket_minutes,
+        total: buckets.iter().map(|b| b.total).sum(),
+        warnings: warning_buckets.iter().sum(),
+        trend: trend(&warning_buckets),
+        buckets,
+        namespaces,
+        reasons: sorted_reasons(reasons),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn event(namespace: &str, type_: &str, reason: &str, first: &str, last: &str, count: i32) -> Event {
+        serde_json::from_value(json!({
+            "metadata": { "name": format!("{}.{}", reason, last), "namespace": namespace },
+            "involvedObject": { "kind": "Pod", "name": "web-0", "namespace": namespace },
+            "type": type_,
+            "reason": reason,
+            "firstTimestamp": first,
+            "lastTimestamp": last,
+            "count": count,
+        })).unwrap()
+    }
+
+    #[test]
+    fn test_event_trends() {
+        let now: DateTime<Utc> = "2025-01-01T01:00:00Z".parse().unwrap();
+        let events = vec![
+            // 11 back-offs, one every two minutes from 00:39
+            event("shop", "Warning", "BackOff", "2025-01-01T00:39:00Z", "2025-01-01T00:59:00Z", 11),
+            event("shop", "Warning", "Unhealthy", "2025-01-01T00:45:00Z", "2025-01-01T00:45:00Z", 1),
+            event("shop", "Normal", "Pulled", "2025-01-01T00:41:00Z", "2025-01-01T00:41:00Z", 1),
+            event("batch", "Warning", "FailedScheduling", "2025-01-01T00:05:00Z", "2025-01-01T00:19:00Z", 4),
+            // Before the 40 minute window
+            event("batch", "Warning", "BackOff", "2025-01-01T00:01:00Z", "2025-01-01T00:10:00Z", 3),
+            event("kube-system", "Normal", "Scheduled", "2025-01-01T00:50:00Z", "2025-01-01T00:50:00Z", 1),
+        ];
+
+        let trends = kuboard_event_trends(&events, now, 40, 10);
+        assert_eq!((trends.window_minutes, trends.since.as_str()), (40, "2025-01-01T00:20:00+00:00"));
+        assert_eq!((trends.total, trends.warnings), (14, 12));
+        let buckets: Vec<(i64, i64)> = trends.buckets.iter().map(|b| (b.total, b.warnings)).collect();
+        assert_eq!(buckets, vec![(0, 0), (1, 1), (8, 6), (5, 5)]);
+        assert_eq!(trends.trend, EventTrendDirection::Rising);
+
+        let namespaces: Vec<(&str, i64, i64)> = trends.namespaces.iter().map(|n| (n.namespace.as_str(), n.total, n.warnings)).collect();
+        assert_eq!(namespaces, vec![("shop", 13, 12), ("kube-system", 1, 0)]);
+        assert_eq!(trends.namespaces[0].per_hour, 19.5);
+        assert_eq!(trends.namespaces[0].warning_buckets, vec![0, 1, 6, 5]);
+        assert_eq!(trends.namespaces[1].trend, EventTrendDirection::Steady);
+        assert_eq!(trends.reasons[0].reason, "BackOff");
+        assert_eq!(trends.reasons[0].count, 11);
+
+        let hour = kuboard_event_trends(&events, now, 60, 30);
+        assert_eq!(hour.reasons.iter().find(|r| r.reason == "FailedScheduling").map(|r| r.count), Some(4));
+        assert_eq!(hour.reasons[0].namespaces, vec!["batch", "shop"]);
+        assert_eq!(hour.namespaces[0].namespace, "shop");
+    }
+}
@@ -0,0 +1,164 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Eviction Order
+// Ranks the pods on a node the way the kubelet picks victims under memory pressure: pods using more
+// than they requested first, then lower priority, then the largest overshoot
+
+use k8s_openapi::api::core::v1::{Container, Pod};
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use crate::quantity::kuboard_parse_bytes;
+use crate::types::EvictionCandidate;
+
+// Pods at or above system-cluster-critical are never evicted by the kubelet
+const SYSTEM_CRITICAL_PRIORITY: i32 = 2_000_000_000;
+const MIRROR_POD_ANNOTATION: &str = "kubernetes.io/config.mirror";
+
+// Regular containers plus sidecars, which run for the whole life of the pod
+fn long_running_containers(pod: &Pod) -> impl Iterator<Item = &Container> {
+    let spec = pod.spec.as_ref();
+    let sidecars = spec
+        .and_then(|s| s.init_containers.as_ref())
+        .into_iter()
+        .flatten()
+        .filter(|c| c.restart_policy.as_deref() == Some("Always"));
+    spec.map(|s| s.containers.iter()).into_iter().flatten().chain(sidecars)
+}
+
+fn resource(container: &Container, limits: bool, name: &str) -> Option<String> {
+    let resources = container.resources.as_ref()?;
+    let quantities = if limits { resources.limits.as_ref() } else { resources.requests.as_ref() };
+    quantities?.get(name).map(|q| q.0.clone())
+}
+
+// The status field when the API server has set it, otherwise the same rules: every container with
+// equal cpu and memory requests and limits is Guaranteed, no requests or limits at all is BestEffort
+pub fn kuboard_qos_class(pod: &Pod) -> String {
+    if let Some(qos) = pod.status.as_ref().and_then(|s| s.qos_class.clone()) {
+        return qos;
+    }
+    let containers: Vec<&Container> = long_running_containers(pod).collect();
+    let any_set = containers.iter().any(|c| {
+        ["cpu", "memory"].iter().any(|r| resource(c, false, r).is_some() || resource(c, true, r).is_some())
+    });
+    if !any_set {
+        return "BestEffort".to_string();
+    }
+    // Requests default to limits, so only the limits have to be present
+    let guaranteed = containers.iter().all(|c| {
+        ["cpu", "memory"].iter().all(|r| match resource(c, true, r) {
+            Some(limit) => resource(c, false, r).is_none_or(|request| request == limit),
+            None => false,
+        })
+    });
+    if guaranteed { "Guaranteed" } else { "Burstable" }.to_string()
+}
+
+pub fn kuboard_memory_request_bytes(pod: &Pod) -> u64 {
+    let containers: u64 = long_running_containers(pod)
+        .filter_map(|c| resource(c, false, "memory").or_else(|| resource(c, true, "memory")))
+        .filter_map(|q| kuboard_parse_bytes(&q).ok())
+        .sum();
+    let overhead = pod.spec.as_ref()
+        .and_then(|s| s.overhead.as_ref())
+        .and_then(|o| o.get("memory"))
+        .and_then(|q| kuboard_parse_bytes(&q.0).ok())
+        .unwrap_or(0);
+    containers + overhead
+}
+
+// Static and mirror pods, and pods at critical priority, are skipped by the kubelet's eviction manager
+fn critical(pod: &Pod, priority: i32) -> bool {
+    let mirror = pod.metadata.annotations.as_ref().is_some_and(|a| a.contains_key(MIRROR_POD_ANNOTATION));
+    mirror || priority >= SYSTEM_CRITICAL_PRIORITY
+}
+
+// `usage` is memory working set in bytes by (namespace, name); pods missing from it rank by request alone
+pub fn kuboard_eviction_order(pods: &[Pod], usage: &HashMap<(String, String), u64>) -> Vec<EvictionCandidate> {
+    let mut candidates: Vec<EvictionCandidate> = pods.iter()
+        .filter(|pod| !matches!(pod.status.as_ref().and_then(|s| s.phase.as_deref()), Some("Succeeded" | "Failed")))
+        .map(|pod| {
+            let name = pod.metadata.name.clone().unwrap_or_default();
+            let namespace = pod.metadata.namespace.clone().unwrap_or_default();
+            let priority = pod.spec.as_ref().and_then(|s| s.priority).unwrap_or(0);
+            let memory_request_bytes = kuboard_memory_request_bytes(pod);
+            let memory_usage_bytes = usage.get(&(namespace.clone(), name.clone())).copied();
+            EvictionCandidate {
+                rank: 0,
+                qos_class: kuboard_qos_class(pod),
+                priority,
+                priority_class: pod.spec.as_ref().and_then(|s| s.priority_class_name.clone()),
+                memory_request_bytes,
+                memory_usage_bytes,
+                exceeds_requests: memory_usage_bytes.is_some_and(|used| used > memory_request_bytes),
+                evictable: !critical(pod, priority),
+                name,
+                namespace,
+            }
+        })
+        .collect();
+
+    candidates.sort_by_key(|c| {
+        let overshoot = c.memory_usage_bytes.unwrap_or(0) as i128 - c.memory_request_bytes as i128;
+        (!c.evictable, !c.exceeds_requests, c.priority, Reverse(overshoot))
+    });
+    for (index, candidate) in candidates.iter_mut().enumerate() {
+        candidate.rank = index + 1;
+    }
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    fn pod(name: &str, priority: i32, resources: Value) -> Pod {
+        serde_json::from_value(json!({
+            "metadata": { "name": name, "namespace": "default" },
+            "spec": { "priority": priority, "containers": [{ "name": "app", "image": "app", "resources": resources }] },
+            "status": { "phase": "Running" },
+        })).unwrap()
+    }
+
+    #[test]
+    fn test_qos_class() {
+        assert_eq!(kuboard_qos_class(&pod("a", 0, json!({}))), "BestEffort");
+        assert_eq!(kuboard_qos_class(&pod("b", 0, json!({ "limits": { "cpu": "1", "memory": "1Gi" } }))), "Guaranteed");
+        assert_eq!(kuboard_qos_class(&pod("c", 0, json!({ "requests": { "memory": "1Gi" } }))), "Burstable");
+        assert_eq!(kuboard_qos_class(&pod("d", 0, json!({
+            "requests": { "cpu": "500m", "memory": "1Gi" }, "limits": { "cpu": "1", "memory": "1Gi" },
+        }))), "Burstable");
+    }
+
+    #[test]
+    fn test_eviction_order() {
+        let mut system = pod("system", SYSTEM_CRITICAL_PRIORITY, json!({}));
+        system.metadata.namespace = Some("kube-system".to_string());
+        let pods = vec![
+            pod("guaranteed", 0, json!({ "limits": { "cpu": "1", "memory": "1Gi" } })),
+            pod("best-effort", 0, json!({})),
+            pod("important-overshoot", 1000, json!({ "requests": { "memory": "100Mi" } })),
+            pod("big-overshoot", 0, json!({ "requests": { "memory": "100Mi" } })),
+            system,
+        ];
+        let mib = 1024 * 1024;
+        let usage = HashMap::from([
+            (("default".to_string(), "guaranteed".to_string()), 900 * mib),
+            (("default".to_string(), "best-effort".to_string()), 50 * mib),
+            (("default".to_string(), "important-overshoot".to_string()), 500 * mib),
+            (("default".to_string(), "big-overshoot".to_string()), 400 * mib),
+            (("kube-system".to_string(), "system".to_string()), 2048 * mib),
+        ]);
+
+        let order = kuboard_eviction_order(&pods, &usage);
+        let names: Vec<&str> = order.iter().map(|c| c.name.as_str()).collect();
+        // Priority outranks the size of the overshoot; pods within their requests come after
+        assert_eq!(names, vec!["big-overshoot", "best-effort", "important-overshoot", "guaranteed", "system"]);
+        assert_eq!(order[0].rank, 1);
+        assert!(!order[4].evictable);
+        assert!(!order[3].exceeds_requests);
+    }
+}
@@ -4,10 +4,32 @@
 // Kubernetes Exec Module
 // Handles pod exec functionality with WebSocket streaming
 
+use futures_util::StreamExt;
+use kube::api::AttachParams;
 use kube::{Api, Client};
 use k8s_openapi::api::core::v1::Pod;
 use anyhow::{Result, anyhow};
-use tracing::info;
+use serde::Serialize;
+use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
+use tokio::sync::mpsc;
+use tokio_util::io::ReaderStream;
+use tracing::{info, warn};
+
+use crate::kubernetes::node_shell::{cleanup_node_shell, NodeShellPod};
+use crate::kubernetes::recording::ExecRecorder;
+
+pub const EXEC_OUTPUT_EVENT: &str = "exec-output";
+
+// Keystrokes queued for stdin before senders wait
+const EXEC_INPUT_BUFFER: usize = 64;
+
+// Payload of the "exec-output" event
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecOutputEvent {
+    pub session_id: String,
+    pub data: String,
+}
 
 #[derive(Clone)]
 pub struct ExecSession {
@@ -15,6 +37,11 @@ pub struct ExecSession {
     pub pod_name: String,
     pub namespace: String,
     pub container_name: Option<String>,
+    pub recorder: Option<ExecRecorder>,
+    // Terminal input for the attached stream; dropping the last sender ends the stream
+    pub input: Option<mpsc::Sender<String>>,
+    // Debug pod created for a node shell, deleted when the stream ends or the session closes
+    pub node_shell: Option<NodeShellPod>,
 }
 
 impl ExecSession {
@@ -24,19 +51,165 @@ impl ExecSession {
             pod_name,
             namespace,
             container_name,
+            recorder: None,
+            input: None,
+            node_shell: None,
+        }
+    }
+
+    // Recording failures must never break the interactive session itself
+    pub fn record_output(&self, data: &str) {
+        if let Some(recorder) = &self.recorder {
+            if let Err(e) = recorder.record_output(data) {
+                warn!("Failed to record exec output for {}: {}", self.session_id, e);
+            }
+        }
+    }
+
+    pub fn record_input(&self, data: &str) {
+        if let Some(recorder) = &self.recorder {
+            if let Err(e) = recorder.record_input(data) {
+                warn!("Failed to record exec input for {}: {}", self.session_id, e);
+            }
+        }
+    }
+
+    pub async fn send_input(&self, data: String) -> Result<()> {
+        let input = self.input.as_ref().ok_or_else(|| anyhow!("Exec session {} has no attached stream", self.session_id))?;
+        input.send(data).await.map_err(|_| anyhow!("Exec session {} has ended", self.session_id))
+    }
+}
+
+// Writes terminal input to the container's stdin, recording each chunk as it is sent
+pub async fn forward_exec_input<W>(mut input: mpsc::Receiver<String>, mut stdin: W, session: &ExecSession)
+where
+    W: AsyncWrite + Unpin,
+{
+    while let Some(data) = input.recv().await {
+        session.record_input(&data);
+        if let Err(e) = stdin.write_all(data.as_bytes()).await {
+            warn!("Exec stdin for {} closed: {}", session.session_id, e);
+            break;
+        }
+    }
+}
+
+// Decodes the complete UTF-8 prefix of `pending`, leaving a character split across
+// chunks for the next one; invalid bytes become U+FFFD
+fn take_utf8(pending: &mut Vec<u8>) -> String {
+    let mut text = String::new();
+    loop {
+        match std::str::from_utf8(pending) {
+            Ok(valid) => {
+                text.push_str(valid);
+                pending.clear();
+                return text;
+            }
+            Err(e) => {
+                let valid_up_to = e.valid_up_to();
+                text.push_str(std::str::from_utf8(&pending[..valid_up_to]).expect("prefix is valid UTF-8"));
+                match e.error_len() {
+                    Some(len) => {
+                        text.push(char::REPLACEMENT_CHARACTER);
+                        pending.drain(..valid_up_to + len);
+                    }
+                    None => {
+                        pending.drain(..valid_up_to);
+                        return text;
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Hands container output to `on_output`, recording each chunk as it arrives
+pub async fn forward_exec_output<R, F>(output: R, session: &ExecSession, on_output: &F)
+where
+    R: AsyncRead + Unpin,
+    F: Fn(String),
+{
+    let emit = |data: String| {
+        if !data.is_empty() {
+            session.record_output(&data);
+            on_output(data);
+        }
+    };
+
+    let mut chunks = ReaderStream::new(output);
+    let mut pending = Vec::new();
+    while let Some(chunk) = chunks.next().await {
+        match chunk {
+            Ok(chunk) => {
+                pending.extend_from_slice(&chunk);
+                emit(take_utf8(&mut pending));
+            }
+            Err(e) => {
+                warn!("Exec output for {} failed: {}", session.session_id, e);
+                break;
+            }
         }
     }
+    // A character cut off by the end of the stream can't be completed
+    emit(String::from_utf8_lossy(&pending).into_owned());
+}
+
+// Opens the exec stream for a session: output goes to `on_output` and `send_input` reaches stdin.
+// The stream ends when the command exits or the session (and with it the input sender) is dropped;
+// a node shell's debug pod is deleted once it ends
+pub async fn attach_exec_stream<F>(
+    client: &Client,
+    session: &mut ExecSession,
+    command: Vec<String>,
+    tty: bool,
+    on_output: F,
+) -> Result<()>
+where
+    F: Fn(String) + Send + Sync + 'static,
+{
+    let pods_api: Api<Pod> = Api::namespaced(client.clone(), &session.namespace);
+    // A TTY merges stderr into stdout
+    let mut params = AttachParams::default().stdin(true).stdout(true).stderr(!tty).tty(tty);
+    if let Some(container) = &session.container_name {
+        params = params.container(container.as_str());
+    }
+
+    let mut process = pods_api.exec(&session.pod_name, command, &params).await?;
+    let stdin = process.stdin().ok_or_else(|| anyhow!("Exec session has no stdin"))?;
+    let stdout = process.stdout().ok_or_else(|| anyhow!("Exec session has no stdout"))?;
+    let stderr = process.stderr();
+
+    // The forwarding task gets a copy without the sender, so it can't keep its own input open
+    let forwarding = session.clone();
+    let (input_tx, input_rx) = mpsc::channel::<String>(EXEC_INPUT_BUFFER);
+    session.input = Some(input_tx);
+
+    tokio::spawn(async move {
+        let forward_stderr = async {
+            match stderr {
+                Some(stderr) => forward_exec_output(stderr, &forwarding, &on_output).await,
+                None => std::future::pending().await,
+            }
+        };
+        tokio::select! {
+            _ = forward_exec_input(input_rx, stdin, &forwarding) => {}
+            _ = forward_exec_output(stdout, &forwarding, &on_output) => {}
+            _ = forward_stderr => {}
+        }
+        process.abort();
+        info!("Exec stream for {} ended", forwarding.session_id);
+        cleanup_node_shell(&forwarding).await;
+    });
+    Ok(())
 }
 
 // Start exec session - returns session ID
-// The actual streaming will be handled via a separate command that uses Tauri events
+// The command itself runs once the session is passed to attach_exec_stream
 pub async fn start_exec_session(
     client: &Client,
     pod_name: &str,
     namespace: &str,
     container_name: Option<&str>,
-    _command: Option<Vec<String>>,
-    _tty: bool,
 ) -> Result<ExecSession> {
     info!("Starting exec session for pod: {}/{}", namespace, pod_name);
     
@@ -56,3 +229,77 @@ pub async fn start_exec_session(
     Ok(session)
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kubernetes::recording::kuboard_read_recording;
+    use std::sync::Mutex;
+    use tokio::io::AsyncReadExt;
+
+    #[tokio::test]
+    async fn test_exec_stream_forwarding_is_recorded() {
+        let dir = std::env::temp_dir().join(format!("kuboard-exec-{}", uuid::Uuid::new_v4()));
+        let mut session = ExecSession::new("web-abc".to_string(), "default".to_string(), Some("nginx".to_string()));
+        session.recorder = Some(ExecRecorder::create(&dir, &session.session_id, "web-abc", "default", Some("nginx"), 80, 24).unwrap());
+
+        // Each duplex pair stands in for one direction of the container's streams
+        let (stdin, mut container_stdin) = tokio::io::duplex(1024);
+        let (mut container_stdout, stdout) = tokio::io::duplex(1024);
+
+        let (input_tx, input_rx) = mpsc::channel(8);
+        input_tx.send("ls\r".to_string()).await.unwrap();
+        drop(input_tx);
+        forward_exec_input(input_rx, stdin, &session).await;
+        let mut typed = [0u8; 3];
+        container_stdin.read_exact(&mut typed).await.unwrap();
+        assert_eq!(&typed, b"ls\r");
+
+        container_stdout.write_all(b"index.html\r\n").await.unwrap();
+        drop(container_stdout);
+        let shown = Mutex::new(Vec::new());
+        forward_exec_output(stdout, &session, &|data: String| shown.lock().unwrap().push(data)).await;
+        assert_eq!(shown.into_inner().unwrap(), vec!["index.html\r\n".to_string()]);
+
+        let cast = kuboard_read_recording(&dir, &session.session_id).unwrap();
+        let events: Vec<serde_json::Value> = cast.lines().skip(1).map(|l| serde_json::from_str(l).unwrap()).collect();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0][1], "i");
+        assert_eq!(events[0][2], "ls\r");
+        assert_eq!(events[1][1], "o");
+        assert_eq!(events[1][2], "index.html\r\n");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_exec_output_keeps_characters_split_across_chunks() {
+        let session = ExecSession::new("web-abc".to_string(), "default".to_string(), None);
+        // "é" is 0xc3 0xa9; each slice arrives as its own read
+        let chunks: Vec<std::io::Result<&[u8]>> = vec![Ok(&b"caf\xc3"[..]), Ok(&b"\xa9\n\xff"[..])];
+        let stdout = tokio_util::io::StreamReader::new(futures_util::stream::iter(chunks));
+
+        let shown = Mutex::new(Vec::new());
+        forward_exec_output(stdout, &session, &|data: String| shown.lock().unwrap().push(data)).await;
+        assert_eq!(shown.into_inner().unwrap(), vec!["caf".to_string(), "é\n\u{fffd}".to_string()]);
+    }
+
+    #[test]
+    fn test_take_utf8_keeps_incomplete_tail() {
+        let mut pending = vec![b'c', 0xc3];
+        assert_eq!(take_utf8(&mut pending), "c");
+        assert_eq!(pending, vec![0xc3]);
+        pending.push(0xa9);
+        assert_eq!(take_utf8(&mut pending), "é");
+        assert!(pending.is_empty());
+
+        let mut pending = vec![b'a', 0xff, b'b'];
+        assert_eq!(take_utf8(&mut pending), "a\u{fffd}b");
+        assert!(pending.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_exec_session_without_stream_rejects_input() {
+        let session = ExecSession::new("web-abc".to_string(), "default".to_string(), None);
+        assert!(session.send_input("ls\r".to_string()).await.is_err());
+    }
+}
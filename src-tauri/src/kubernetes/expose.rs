@@ -0,0 +1,166 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Workload Exposure
+// Generates a Service selecting a workload's pods, the equivalent of `kubectl expose`
+
+use anyhow::{anyhow, Result};
+use k8s_openapi::api::core::v1::{Container, Service, ServicePort, ServiceSpec};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::{LabelSelector, ObjectMeta};
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+use kube::api::PostParams;
+use kube::{Api, Client};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use crate::kubernetes::manifests::kuboard_get_live_object;
+
+pub const EXPOSABLE_KINDS: &[&str] = &["Deployment", "StatefulSet", "DaemonSet", "ReplicaSet", "Pod"];
+const SERVICE_TYPES: &[&str] = &["ClusterIP", "NodePort", "LoadBalancer"];
+
+// The labels a Service can select on; Services have no matchExpressions, so like kubectl
+// a workload selector using them can't be exposed
+pub fn kuboard_expose_selector(kind: &str, workload: &Value) -> Result<BTreeMap<String, String>> {
+    if kind == "Pod" {
+        let labels: BTreeMap<String, String> = serde_json::from_value(workload.pointer("/metadata/labels").cloned().unwrap_or_default())
+            .unwrap_or_default();
+        if labels.is_empty() {
+            return Err(anyhow!("Pod has no labels to select it by"));
+        }
+        return Ok(labels);
+    }
+
+    let selector: LabelSelector = serde_json::from_value(workload.pointer("/spec/selector").cloned().unwrap_or_default())
+        .unwrap_or_default();
+    if selector.match_expressions.as_ref().is_some_and(|e| !e.is_empty()) {
+        return Err(anyhow!("{} selector uses matchExpressions, which a Service can't express", kind));
+    }
+    selector.match_labels.filter(|labels| !labels.is_empty())
+        .ok_or_else(|| anyhow!("{} has no matchLabels selector", kind))
+}
+
+fn workload_containers(kind: &str, workload: &Value) -> Vec<Container> {
+    let pointer = if kind == "Pod" { "/spec/containers" } else { "/spec/template/spec/containers" };
+    serde_json::from_value(workload.pointer(pointer).cloned().unwrap_or_default()).unwrap_or_default()
+}
+
+// Numeric target ports are taken as given; a named one must exist on a container
+fn target_port(containers: &[Container], port: i32, target_port: Option<&str>) -> Result<IntOrString> {
+    let Some(target) = target_port.filter(|t| !t.is_empty()) else {
+        return Ok(IntOrString::Int(port));
+    };
+    if let Ok(number) = target.parse::<i32>() {
+        return Ok(IntOrString::Int(number));
+    }
+    let named = containers.iter()
+        .flat_map(|c| c.ports.iter().flatten())
+        .any(|p| p.name.as_deref() == Some(target));
+    if !named {
+        return Err(anyhow!("No container declares a port named {}", target));
+    }
+    Ok(IntOrString::String(target.to_string()))
+}
+
+pub fn kuboard_build_expose_service(
+    kind: &str,
+    workload: &Value,
+    port: i32,
+    target: Option<&str>,
+    service_type: Option<&str>,
+) -> Result<Service> {
+    let service_type = service_type.unwrap_or("ClusterIP");
+    if !SERVICE_TYPES.contains(&service_type) {
+        return Err(anyhow!("Service type must be ClusterIP, NodePort or LoadBalancer, not {}", service_type));
+    }
+    if !(1..=65535).contains(&port) {
+        return Err(anyhow!("Port {} is outside 1-65535", port));
+    }
+
+    let name = workload.pointer("/metadata/name").and_then(Value::as_str).unwrap_or_default();
+    let namespace = workload.pointer("/metadata/namespace").and_then(Value::as_str).unwrap_or_default();
+    let selector = kuboard_expose_selector(kind, workload)?;
+    let target_port = target_port(&workload_containers(kind, workload), port, target)?;
+    // kubectl gives the service the workload's labels, falling back to the selector
+    let labels: BTreeMap<String, String> = serde_json::from_value(workload.pointer("/metadata/labels").cloned().unwrap_or_default())
+        .ok()
+        .filter(|labels: &BTreeMap<String, String>| !labels.is_empty())
+        .unwrap_or_else(|| selector.clone());
+
+    Ok(Service {
+        metadata: ObjectMeta {
+            name: Some(name.to_string()),
+            namespace: Some(namespace.to_string()),
+            labels: Some(labels),
+            ..Default::default()
+        },
+        spec: Some(ServiceSpec {
+            type_: Some(service_type.to_string()),
+            selector: Some(selector),
+            ports: Some(vec![ServicePort {
+                port,
+                target_port: Some(target_port),
+                protocol: Some("TCP".to_string()),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    })
+}
+
+// The Service is named after the workload
+pub async fn kuboard_expose_workload_service(
+    client: &Client,
+    kind: &str,
+    name: &str,
+    namespace: &str,
+    port: i32,
+    target_port: Option<&str>,
+    service_type: Option<&str>,
+) -> Result<Service> {
+    if !EXPOSABLE_KINDS.contains(&kind) {
+        return Err(anyhow!("Exposing {} is not supported", kind));
+    }
+    let workload = serde_json::to_value(kuboard_get_live_object(client, kind, name, namespace).await?)?;
+    let service = kuboard_build_expose_service(kind, &workload, port, target_port, service_type)?;
+
+    let services_api: Api<Service> = Api::namespaced(client.clone(), namespace);
+    match services_api.create(&PostParams::default(), &service).await {
+        Ok(created) => Ok(created),
+        Err(kube::Error::Api(e)) if e.code == 409 => Err(anyhow!("Service {}/{} already exists", namespace, name)),
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn deployment(selector: Value) -> Value {
+        json!({
+            "metadata": { "name": "api", "namespace": "shop", "labels": { "app": "api", "team": "payments" } },
+            "spec": {
+                "selector": selector,
+                "template": { "spec": { "containers": [{ "name": "api", "ports": [{ "name": "http", "containerPort": 8080 }] }] } },
+            },
+        })
+    }
+
+    #[test]
+    fn test_build_expose_service() {
+        let workload = deployment(json!({ "matchLabels": { "app": "api" } }));
+
+        let service = kuboard_build_expose_service("Deployment", &workload, 80, Some("http"), None).unwrap();
+        let spec = service.spec.unwrap();
+        assert_eq!(spec.selector.unwrap(), BTreeMap::from([("app".to_string(), "api".to_string())]));
+        assert_eq!(spec.type_.as_deref(), Some("ClusterIP"));
+        assert_eq!(spec.ports.unwrap()[0].target_port, Some(IntOrString::String("http".to_string())));
+        assert_eq!(service.metadata.labels.unwrap().get("team").map(String::as_str), Some("payments"));
+
+        assert!(kuboard_build_expose_service("Deployment", &workload, 80, Some("grpc"), None).is_err());
+        assert!(kuboard_build_expose_service("Deployment", &workload, 80, None, Some("ExternalName")).is_err());
+        let expressions = deployment(json!({ "matchExpressions": [{ "key": "app", "operator": "Exists" }] }));
+        assert!(kuboard_build_expose_service("Deployment", &expressions, 80, None, None).is_err());
+    }
+}
@@ -0,0 +1,144 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Field Managers
+// Reads an object's managedFields into readable field paths per manager, so it is clear which
+// controller, operator or GitOps tool owns a field a manual edit keeps losing
+
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use crate::types::{FieldManagerEntry, FieldOwnership, SharedField};
+
+// One FieldsV1 key as a path segment: "f:spec" is a field, "k:{...}" a list item by key,
+// "v:..." a set value and "i:3" a list index
+fn path_segment(key: &str) -> String {
+    if let Some(field) = key.strip_prefix("f:") {
+        return format!(".{}", field);
+    }
+    if let Some(keys) = key.strip_prefix("k:") {
+        let keys: BTreeMap<String, Value> = serde_json::from_str(keys).unwrap_or_default();
+        let keys: Vec<String> = keys.iter()
+            .map(|(name, value)| format!("{}={}", name, value.as_str().map(str::to_string).unwrap_or_else(|| value.to_string())))
+            .collect();
+        return format!("[{}]", keys.join(","));
+    }
+    if let Some(value) = key.strip_prefix("v:") {
+        return format!("[={}]", value);
+    }
+    if let Some(index) = key.strip_prefix("i:") {
+        return format!("[{}]", index);
+    }
+    format!(".{}", key)
+}
+
+fn collect_paths(prefix: &str, fields: &Value, paths: &mut Vec<String>) {
+    let Some(children) = fields.as_object() else { return };
+    // An empty set is a leaf the manager owns; "." marks ownership of a node that also has children
+    if !prefix.is_empty() && (children.is_empty() || children.contains_key(".")) {
+        paths.push(prefix.to_string());
+    }
+    for (key, child) in children {
+        if key != "." {
+            collect_paths(&format!("{}{}", prefix, path_segment(key)), child, paths);
+        }
+    }
+}
+
+// Field paths such as ".spec.template.spec.containers[name=web].image" from a FieldsV1 set
+pub fn kuboard_fields_v1_paths(fields: &Value) -> Vec<String> {
+    let mut paths = Vec::new();
+    collect_paths("", fields, &mut paths);
+    paths.sort();
+    paths
+}
+
+pub fn kuboard_field_ownership(kind: &str, metadata: &ObjectMeta) -> FieldOwnership {
+    let managers: Vec<FieldManagerEntry> = metadata.managed_fields.iter().flatten()
+        .map(|entry| FieldManagerEntry {
+            manager: entry.manager.clone().unwrap_or_else(|| "unknown".to_string()),
+            operation: entry.operation.clone().unwrap_or_default(),
+            api_version: entry.api_version.clone(),
+            subresource: entry.subresource.clone().filter(|s| !s.is_empty()),
+            time: entry.time.as_ref().map(|t| t.0.to_rfc3339()),
+            fields: entry.fields_v1.as_ref().map(|f| kuboard_fields_v1_paths(&f.0)).unwrap_or_default(),
+        })
+        .collect();
+
+    // A field several managers claim is where edits get reverted: the next apply or reconcile
+    // from the other manager writes its own value back
+    let mut owners: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    for entry in &managers {
+        for field in &entry.fields {
+            let claimants = owners.entry(field.as_str()).or_default();
+            if !claimants.contains(&entry.manager) {
+                claimants.push(entry.manager.clone());
+            }
+        }
+    }
+    let shared_fields = owners.into_iter()
+        .filter(|(_, managers)| managers.len() > 1)
+        .map(|(path, managers)| SharedField { path: path.to_string(), managers })
+        .collect();
+
+    FieldOwnership {
+        kind: kind.to_string(),
+        name: metadata.name.clone().unwrap_or_default(),
+        namespace: metadata.namespace.clone(),
+        managers,
+        shared_fields,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_field_ownership() {
+        let metadata: ObjectMeta = serde_json::from_value(json!({
+            "name": "web",
+            "namespace": "default",
+            "managedFields": [
+                {
+                    "manager": "argocd-controller", "operation": "Apply", "apiVersion": "apps/v1", "fieldsType": "FieldsV1",
+                    "fieldsV1": { "f:spec": { "f:replicas": {}, "f:template": { "f:spec": { "f:containers": {
+                        "k:{\"name\":\"web\"}": { ".": {}, "f:image": {}, "f:name": {} },
+                    } } } } },
+                },
+                {
+                    "manager": "kubectl-edit", "operation": "Update", "apiVersion": "apps/v1", "fieldsType": "FieldsV1",
+                    "time": "2025-01-01T00:00:00Z",
+                    "fieldsV1": { "f:metadata": { "f:labels": { "f:tier": {} } }, "f:spec": { "f:replicas": {} } },
+                },
+                {
+                    "manager": "kube-controller-manager", "operation": "Update", "apiVersion": "apps/v1", "subresource": "status",
+                    "fieldsType": "FieldsV1", "fieldsV1": { "f:status": { "f:replicas": {} } },
+                },
+            ],
+        })).unwrap();
+
+        let ownership = kuboard_field_ownership("Deployment", &metadata);
+        assert_eq!(ownership.managers.len(), 3);
+        assert_eq!(ownership.managers[0].fields, vec![
+            ".spec.replicas",
+            ".spec.template.spec.containers[name=web]",
+            ".spec.template.spec.containers[name=web].image",
+            ".spec.template.spec.containers[name=web].name",
+        ]);
+        assert_eq!(ownership.managers[1].fields, vec![".metadata.labels.tier", ".spec.replicas"]);
+        assert_eq!(ownership.managers[2].subresource.as_deref(), Some("status"));
+
+        assert_eq!(ownership.shared_fields.len(), 1);
+        assert_eq!(ownership.shared_fields[0].path, ".spec.replicas");
+        assert_eq!(ownership.shared_fields[0].managers, vec!["argocd-controller", "kubectl-edit"]);
+    }
+
+    #[test]
+    fn test_fields_v1_set_and_index_paths() {
+        let fields = json!({ "f:spec": { "f:finalizers": { "v:\"kubernetes\"": {} }, "f:args": { "i:0": {} } } });
+        assert_eq!(kuboard_fields_v1_paths(&fields), vec![".spec.args[0]", ".spec.finalizers[=\"kubernetes\"]"]);
+    }
+}
@@ -0,0 +1,253 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Pod File Browser
+// Read-only directory listing and file access inside containers, implemented via exec
+
+use anyhow::{anyhow, Result};
+use futures_util::StreamExt;
+use kube::api::AttachParams;
+use kube::{Api, Client};
+use k8s_openapi::api::core::v1::Pod;
+use std::time::Duration;
+use tokio::sync::Notify;
+use tokio_util::io::ReaderStream;
+
+use crate::types::{PodFileContent, PodFileEntry, PodFileStat};
+
+// Upper bound for file previews; larger files are truncated rather than streamed whole
+pub const MAX_FILE_PREVIEW_BYTES: u64 = 1024 * 1024;
+
+// Keeps the start of stderr for error messages
+const MAX_EXEC_STDERR_BYTES: usize = 64 * 1024;
+
+// Runs a non-interactive command and collects stdout up to `limit` bytes; with a timeout,
+// a command still running when it fires is aborted
+pub async fn exec_capture(
+    client: &Client,
+    pod_name: &str,
+    namespace: &str,
+    container_name: Option<&str>,
+    command: Vec<String>,
+    limit: u64,
+    timeout: Option<Duration>,
+) -> Result<Vec<u8>> {
+    let pods_api: Api<Pod> = Api::namespaced(client.clone(), namespace);
+    let mut params = AttachParams::default().stdin(false).stdout(true).stderr(true);
+    if let Some(container) = container_name {
+        params = params.container(container);
+    }
+
+    let mut process = pods_api.exec(pod_name, command, &params).await?;
+    let stdout = process.stdout().ok_or_else(|| anyhow!("Exec session has no stdout"))?;
+    let stderr = process.stderr().ok_or_else(|| anyhow!("Exec session has no stderr"))?;
+    let status = process.take_status();
+
+    // Both streams are drained together: a command that fills the stderr pipe while stdout is
+    // still being read would otherwise block forever
+    let limit_reached = Notify::new();
+    let read_stdout = async {
+        let mut output = Vec::new();
+        let mut chunks = ReaderStream::new(stdout);
+        while let Some(chunk) = chunks.next().await {
+            output.extend_from_slice(&chunk?);
+            if output.len() as u64 >= limit {
+                output.truncate(limit as usize);
+                limit_reached.notify_one();
+                return Ok::<_, std::io::Error>((output, true));
+            }
+        }
+        Ok((output, false))
+    };
+    let read_stderr = async {
+        let mut errors = Vec::new();
+        let mut chunks = ReaderStream::new(stderr);
+        loop {
+            tokio::select! {
+                _ = limit_reached.notified() => break,
+                chunk = chunks.next() => match chunk {
+                    Some(Ok(chunk)) => {
+                        let room = MAX_EXEC_STDERR_BYTES.saturating_sub(errors.len());
+                        errors.extend_from_slice(&chunk[..chunk.len().min(room)]);
+                    }
+                    _ => break,
+                },
+            }
+        }
+        String::from_utf8_lossy(&errors).into_owned()
+    };
+    let capture = async {
+        let (stdout_result, errors) = tokio::join!(read_stdout, read_stderr);
+        let (output, truncated) = stdout_result?;
+        if truncated {
+            // Enough for the caller; don't wait for the rest of the stream
+            process.abort();
+            return Ok(output);
+        }
+
+        if let Some(status) = status {
+            if let Some(status) = status.await {
+                if status.status.as_deref() == Some("Failure") {
+                    let message = if errors.trim().is_empty() { status.message.unwrap_or_default() } else { errors.trim().to_string() };
+                    return Err(anyhow!("{}", message));
+                }
+            }
+        }
+        Ok(output)
+    };
+
+    let Some(timeout) = timeout else {
+        return capture.await;
+    };
+    match tokio::time::timeout(timeout, capture).await {
+        Ok(result) => result,
+        Err(_) => {
+            // Don't leave a `tail -f` or `top` holding the exec stream open
+            process.abort();
+            Err(anyhow!("Command did not finish within {}s", timeout.as_secs()))
+        }
+    }
+}
+
+fn file_type_from_mode(mode: &str) -> &'static str {
+    match mode.chars().next() {
+        Some('d') => "directory",
+        Some('l') => "symlink",
+        Some('-') => "file",
+        _ => "other",
+    }
+}
+
+// Parses `ls -lAn` output; numeric ids keep the column count fixed
+pub fn kuboard_parse_ls_output(output: &str) -> Vec<PodFileEntry> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let permissions = fields.next()?;
+            if permissions.len() < 10 {
+                return None; // "total N" header
+            }
+            let _links = fields.next()?;
+            let _uid = fields.next()?;
+            let _gid = fields.next()?;
+            let size_field = fields.next()?;
+            // Device files report "major, minor" instead of a size
+            let size = if size_field.ends_with(',') {
+                fields.next()?;
+                0
+            } else {
+                size_field.parse::<u64>().unwrap_or(0)
+            };
+            let modified = [fields.next()?, fields.next()?, fields.next()?].join(" ");
+            let rest = fields.collect::<Vec<_>>().join(" ");
+
+            let file_type = file_type_from_mode(permissions);
+            let (name, link_target) = match rest.split_once(" -> ") {
+                Some((name, target)) if file_type == "symlink" => (name.to_string(), Some(target.to_string())),
+                _ => (rest, None),
+            };
+
+            Some(PodFileEntry {
+                name,
+                file_type: file_type.to_string(),
+                size,
+                permissions: permissions.to_string(),
+                modified,
+                link_target,
+            })
+        })
+        .collect()
+}
+
+pub async fn kuboard_list_pod_directory(
+    client: &Client,
+    pod_name: &str,
+    namespace: &str,
+    container_name: Option<&str>,
+    path: &str,
+) -> Result<Vec<PodFileEntry>> {
+    let command = vec!["ls".to_string(), "-lAn".to_string(), "--".to_string(), path.to_string()];
+    let output = exec_capture(client, pod_name, namespace, container_name, command, 4 * MAX_FILE_PREVIEW_BYTES, None).await?;
+
+    let mut entries = kuboard_parse_ls_output(&String::from_utf8_lossy(&output));
+    entries.sort_by(|a, b| (b.file_type == "directory").cmp(&(a.file_type == "directory")).then_with(|| a.name.cmp(&b.name)));
+    Ok(entries)
+}
+
+pub async fn kuboard_stat_pod_file(
+    client: &Client,
+    pod_name: &str,
+    namespace: &str,
+    container_name: Option<&str>,
+    path: &str,
+) -> Result<PodFileStat> {
+    // %A/%s/%Y are understood by both GNU coreutils and busybox stat
+    let command = vec!["stat".to_string(), "-c".to_string(), "%A|%s|%Y".to_string(), "--".to_string(), path.to_string()];
+    let output = exec_capture(client, pod_name, namespace, container_name, command, 4096, None).await?;
+    let output = String::from_utf8_lossy(&output);
+
+    let mut fields = output.trim().split('|');
+    let (Some(permissions), Some(size), Some(modified)) = (fields.next(), fields.next(), fields.next()) else {
+        return Err(anyhow!("Unexpected stat output for {}: {}", path, output.trim()));
+    };
+
+    Ok(PodFileStat {
+        path: path.to_string(),
+        file_type: file_type_from_mode(permissions).to_string(),
+        size: size.parse().unwrap_or(0),
+        permissions: permissions.to_string(),
+        modified_unix: modified.parse().unwrap_or(0),
+    })
+}
+
+pub async fn kuboard_read_pod_file(
+    client: &Client,
+    pod_name: &str,
+    namespace: &str,
+    container_name: Option<&str>,
+    path: &str,
+    max_bytes: Option<u64>,
+) -> Result<PodFileContent> {
+    let max_bytes = max_bytes.unwrap_or(MAX_FILE_PREVIEW_BYTES).min(MAX_FILE_PREVIEW_BYTES);
+    let stat = kuboard_stat_pod_file(client, pod_name, namespace, container_name, path).await?;
+    if stat.file_type != "file" {
+        return Err(anyhow!("{} is not a regular file", path));
+    }
+
+    let command = vec!["head".to_string(), "-c".to_string(), max_bytes.to_string(), "--".to_string(), path.to_string()];
+    let bytes = exec_capture(client, pod_name, namespace, container_name, command, max_bytes, None).await?;
+
+    Ok(PodFileContent {
+        path: path.to_string(),
+        // A NUL byte is the usual tell for binary content
+        binary: bytes.contains(&0),
+        content: String::from_utf8_lossy(&bytes).into_owned(),
+        size_bytes: stat.size,
+        truncated: stat.size > bytes.len() as u64,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ls_output() {
+        let output = "total 12\n\
+            drwxr-xr-x    2 0        0             4096 Jan  1 12:00 conf.d\n\
+            -rw-r--r--    1 101      101            612 Dec 31  2024 index file.html\n\
+            lrwxrwxrwx    1 0        0               11 Jan  1 12:00 current -> /srv/v2\n\
+            crw-rw-rw-    1 0        0           1,   3 Jan  1 12:00 null\n";
+
+        let entries = kuboard_parse_ls_output(output);
+
+        assert_eq!(entries.len(), 4);
+        assert_eq!(entries[0].file_type, "directory");
+        assert_eq!(entries[1].name, "index file.html");
+        assert_eq!(entries[1].size, 612);
+        assert_eq!(entries[1].modified, "Dec 31 2024");
+        assert_eq!(entries[2].link_target.as_deref(), Some("/srv/v2"));
+        assert_eq!(entries[3].file_type, "other");
+    }
+}
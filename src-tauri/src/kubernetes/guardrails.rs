@@ -0,0 +1,168 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Production Guardrails
+// Mutations against a production context need a confirmation token the user has confirmed by
+// typing the context name; read-only contexts refuse mutations outright
+
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::kubernetes::audit::kuboard_audit_log_path;
+use crate::kubernetes::context_metadata::kuboard_context_metadata_path;
+use crate::types::{ContextMetadata, ProductionConfirmation};
+
+// Where the guardrails read context settings and write the audit log
+#[derive(Debug, Clone)]
+pub struct GuardrailPaths {
+    pub context_metadata: PathBuf,
+    pub audit_log: PathBuf,
+}
+
+impl GuardrailPaths {
+    pub fn user_data() -> Result<Self> {
+        Ok(Self { context_metadata: kuboard_context_metadata_path()?, audit_log: kuboard_audit_log_path()? })
+    }
+
+    // Both files side by side in `dir`, for tests that must not touch the user's own
+    pub fn in_dir(dir: &Path) -> Self {
+        Self { context_metadata: dir.join("context_metadata.json"), audit_log: dir.join("audit.jsonl") }
+    }
+}
+
+// Long enough to read the prompt and type the context name, short enough that a stale approval can't linger
+pub const CONFIRMATION_TTL: Duration = Duration::from_secs(2 * 60);
+
+struct PendingConfirmation {
+    issued: Instant,
+    confirmation: ProductionConfirmation,
+    confirmed: bool,
+}
+
+// Each confirmation covers one action on one target and is used up by it
+pub struct ConfirmationStore {
+    pending: Vec<PendingConfirmation>,
+}
+
+impl ConfirmationStore {
+    pub fn new() -> Self {
+        Self { pending: Vec::new() }
+    }
+
+    fn prune(&mut self) {
+        self.pending.retain(|p| p.issued.elapsed() < CONFIRMATION_TTL);
+    }
+
+    pub fn issue(&mut self, context: &str, action: &str, target: &str) -> ProductionConfirmation {
+        self.prune();
+        let confirmation = ProductionConfirmation {
+            token: uuid::Uuid::new_v4().to_string(),
+            context: context.to_string(),
+            action: action.to_string(),
+            target: target.to_string(),
+            expires_at: (Utc::now() + chrono::Duration::from_std(CONFIRMATION_TTL).unwrap_or_default()).to_rfc3339(),
+        };
+        self.pending.push(PendingConfirmation { issued: Instant::now(), confirmation: confirmation.clone(), confirmed: false });
+        confirmation
+    }
+
+    // The user confirms by typing the context name, like deleting a repository on a code host
+    pub fn confirm(&mut self, token: &str, typed_context: &str) -> Result<ProductionConfirmation> {
+        self.prune();
+        let pending = self.pending.iter_mut()
+            .find(|p| p.confirmation.token == token)
+            .ok_or_else(|| anyhow!("Confirmation has expired or does not exist; request a new one"))?;
+        if typed_context.trim() != pending.confirmation.context {
+            return Err(anyhow!("Typed name does not match context {}", pending.confirmation.context));
+        }
+        pending.confirmed = true;
+        Ok(pending.confirmation.clone())
+    }
+
+    // Takes a confirmed approval for exactly this action, returning its token
+    pub fn consume(&mut self, context: &str, action: &str, target: &str) -> Option<String> {
+        self.prune();
+        let index = self.pending.iter().position(|p| {
+            p.confirmed && p.confirmation.context == context && p.confirmation.action == action && p.confirmation.target == target
+        })?;
+        Some(self.pending.remove(index).confirmation.token)
+    }
+}
+
+impl Default for ConfirmationStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MutationDecision {
+    // Not a protected context; nothing to record
+    Allowed,
+    Confirmed(String),
+    ReadOnly,
+    ConfirmationRequired,
+}
+
+impl MutationDecision {
+    // The audit log outcome, None when the mutation isn't audited
+    pub fn outcome(&self) -> Option<&'static str> {
+        match self {
+            MutationDecision::Allowed => None,
+            MutationDecision::Confirmed(_) => Some("confirmed"),
+            MutationDecision::ReadOnly => Some("blocked_read_only"),
+            MutationDecision::ConfirmationRequired => Some("confirmation_required"),
+        }
+    }
+}
+
+// Read-only applies to any context it is set on, confirmation only to production ones
+pub fn kuboard_decide_mutation(
+    metadata: &ContextMetadata,
+    confirmations: &mut ConfirmationStore,
+    context: &str,
+    action: &str,
+    target: &str,
+) -> MutationDecision {
+    if metadata.read_only {
+        return MutationDecision::ReadOnly;
+    }
+    if !metadata.is_protected() {
+        return MutationDecision::Allowed;
+    }
+    match confirmations.consume(context, action, target) {
+        Some(token) => MutationDecision::Confirmed(token),
+        None => MutationDecision::ConfirmationRequired,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ContextEnvironment;
+
+    #[test]
+    fn test_production_confirmation_flow() {
+        let prod = ContextMetadata { environment: Some(ContextEnvironment::Prod), ..Default::default() };
+        let mut store = ConfirmationStore::new();
+
+        assert_eq!(kuboard_decide_mutation(&prod, &mut store, "prod-eu", "delete_pod", "shop/api-0"), MutationDecision::ConfirmationRequired);
+
+        let issued = store.issue("prod-eu", "delete_pod", "shop/api-0");
+        // Issued but not yet confirmed
+        assert_eq!(kuboard_decide_mutation(&prod, &mut store, "prod-eu", "delete_pod", "shop/api-0"), MutationDecision::ConfirmationRequired);
+        assert!(store.confirm(&issued.token, "staging").is_err());
+        store.confirm(&issued.token, "prod-eu").unwrap();
+
+        // The approval covers only its own target, and only once
+        assert_eq!(kuboard_decide_mutation(&prod, &mut store, "prod-eu", "delete_pod", "shop/api-1"), MutationDecision::ConfirmationRequired);
+        assert_eq!(kuboard_decide_mutation(&prod, &mut store, "prod-eu", "delete_pod", "shop/api-0"), MutationDecision::Confirmed(issued.token));
+        assert_eq!(kuboard_decide_mutation(&prod, &mut store, "prod-eu", "delete_pod", "shop/api-0"), MutationDecision::ConfirmationRequired);
+
+        assert_eq!(kuboard_decide_mutation(&ContextMetadata::default(), &mut store, "dev", "delete_pod", "shop/api-0"), MutationDecision::Allowed);
+        let read_only = ContextMetadata { read_only: true, ..prod };
+        assert_eq!(kuboard_decide_mutation(&read_only, &mut store, "prod-eu", "delete_pod", "shop/api-0"), MutationDecision::ReadOnly);
+    }
+}
@@ -0,0 +1,255 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// HTTP Request Tester
+// Sends one HTTP/1.1 request to a pod or service over a temporary port-forward, like curl
+
+use anyhow::{anyhow, Result};
+use kube::{Api, Client};
+use k8s_openapi::api::core::v1::{Endpoints, Pod, Service};
+use std::collections::{BTreeMap, HashMap};
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+use crate::types::HttpProbeResponse;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+// Responses are read up to this size; the body snippet is cut shorter
+const MAX_RESPONSE_BYTES: usize = 256 * 1024;
+const BODY_SNIPPET_BYTES: usize = 4096;
+
+// "service/web", "svc/web", "pod/web-0" or a bare pod name
+pub fn parse_probe_target(target: &str) -> (bool, &str) {
+    match target.split_once('/') {
+        Some(("service" | "svc" | "services", name)) => (true, name),
+        Some(("pod" | "pods" | "po", name)) => (false, name),
+        _ => (false, target),
+    }
+}
+
+pub fn build_http_request(method: &str, path: &str, host: &str, headers: &HashMap<String, String>) -> Vec<u8> {
+    let path = if path.starts_with('/') { path.to_string() } else { format!("/{}", path) };
+    let mut request = format!("{} {} HTTP/1.1\r\n", method.to_uppercase(), path);
+    let has = |name: &str| headers.keys().any(|k| k.eq_ignore_ascii_case(name));
+    if !has("host") {
+        request.push_str(&format!("Host: {}\r\n", host));
+    }
+    if !has("user-agent") {
+        request.push_str("User-Agent: kuboard\r\n");
+    }
+    if !has("accept") {
+        request.push_str("Accept: */*\r\n");
+    }
+    for (name, value) in headers {
+        request.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    request.push_str("Connection: close\r\n\r\n");
+    request.into_bytes()
+}
+
+pub struct HttpHead {
+    pub status: u16,
+    pub reason: String,
+    // Lower-cased names; repeated headers are joined with ", "
+    pub headers: BTreeMap<String, String>,
+    pub body_offset: usize,
+}
+
+// None until the blank line ending the headers has arrived
+pub fn parse_http_head(buffer: &[u8]) -> Result<Option<HttpHead>> {
+    let Some(end) = buffer.windows(4).position(|w| w == b"\r\n\r\n") else {
+        return Ok(None);
+    };
+    let head = String::from_utf8_lossy(&buffer[..end]);
+    let mut lines = head.split("\r\n");
+    let status_line = lines.next().unwrap_or_default();
+    let mut parts = status_line.splitn(3, ' ');
+    let version = parts.next().unwrap_or_default();
+    if !version.starts_with("HTTP/") {
+        return Err(anyhow!("Not an HTTP response: {}", status_line));
+    }
+    let status = parts.next().and_then(|s| s.parse().ok()).ok_or_else(|| anyhow!("Invalid status line: {}", status_line))?;
+
+    let mut headers: BTreeMap<String, String> = BTreeMap::new();
+    for line in lines {
+        if let Some((name, value)) = line.split_once(':') {
+            headers.entry(name.trim().to_lowercase())
+                .and_modify(|v| { v.push_str(", "); v.push_str(value.trim()); })
+                .or_insert_with(|| value.trim().to_string());
+        }
+    }
+    Ok(Some(HttpHead { status, reason: parts.next().unwrap_or_default().to_string(), headers, body_offset: end + 4 }))
+}
+
+fn is_chunked(head: &HttpHead) -> bool {
+    head.headers.get("transfer-encoding").is_some_and(|v| v.to_lowercase().contains("chunked"))
+}
+
+// The port-forward may stay open after the server is done, so completion comes from framing, not EOF
+fn body_complete(head: &HttpHead, body: &[u8]) -> bool {
+    if head.status / 100 == 1 || head.status == 204 || head.status == 304 {
+        return true;
+    }
+    if is_chunked(head) {
+        return body.ends_with(b"0\r\n\r\n");
+    }
+    match head.headers.get("content-length").and_then(|v| v.parse::<usize>().ok()) {
+        Some(length) => body.len() >= length,
+        None => false,
+    }
+}
+
+// Best effort: stops at the first malformed or incomplete chunk
+pub fn decode_chunked(body: &[u8]) -> Vec<u8> {
+    let mut decoded = Vec::new();
+    let mut rest = body;
+    while let Some(line_end) = rest.windows(2).position(|w| w == b"\r\n") {
+        let size_field = String::from_utf8_lossy(&rest[..line_end]);
+        let Ok(size) = usize::from_str_radix(size_field.split(';').next().unwrap_or_default().trim(), 16) else { break };
+        if size == 0 {
+            break;
+        }
+        let start = line_end + 2;
+        // The size comes from the pod, so one that can't be addressed is malformed
+        let Some(chunk_end) = start.checked_add(size) else { break };
+        let end = chunk_end.min(rest.len());
+        decoded.extend_from_slice(&rest[start..end]);
+        if end < chunk_end || end + 2 > rest.len() {
+            break;
+        }
+        rest = &rest[end + 2..];
+    }
+    decoded
+}
+
+// Resolves the pod and container port to forward to; services go to their first ready endpoint
+async fn resolve_target(client: &Client, namespace: &str, target: &str, port: u16) -> Result<(String, u16)> {
+    let (is_service, name) = parse_probe_target(target);
+    if !is_service {
+        return Ok((name.to_string(), port));
+    }
+
+    let service = Api::<Service>::namespaced(client.clone(), namespace).get(name).await?;
+    let service_port = service.spec.as_ref()
+        .and_then(|s| s.ports.as_ref())
+        .and_then(|ports| ports.iter().find(|p| p.port == i32::from(port)))
+        .ok_or_else(|| anyhow!("Service {}/{} has no port {}", namespace, name, port))?;
+
+    let endpoints = Api::<Endpoints>::namespaced(client.clone(), namespace).get(name).await?;
+    for subset in endpoints.subsets.iter().flatten() {
+        // Endpoint ports carry the service port's name with the resolved target port number
+        let ports = subset.ports.as_deref().unwrap_or_default();
+        let target_port = ports.iter()
+            .find(|p| p.name == service_port.name)
+            .or(if ports.len() == 1 { ports.first() } else { None })
+            .and_then(|p| u16::try_from(p.port).ok());
+        let pod = subset.addresses.iter().flatten()
+            .find_map(|a| a.target_ref.as_ref().filter(|r| r.kind.as_deref() == Some("Pod")).and_then(|r| r.name.clone()));
+        if let (Some(pod), Some(target_port)) = (pod, target_port) {
+            return Ok((pod, target_port));
+        }
+    }
+    Err(anyhow!("Service {}/{} has no ready pod endpoints", namespace, name))
+}
+
+pub async fn kuboard_send_http_probe(
+    client: &Client,
+    namespace: &str,
+    target: &str,
+    port: u16,
+    path: &str,
+    method: &str,
+    headers: &HashMap<String, String>,
+) -> Result<HttpProbeResponse> {
+    let (pod, pod_port) = resolve_target(client, namespace, target, port).await?;
+    let pods_api: Api<Pod> = Api::namespaced(client.clone(), namespace);
+    let mut forwarder = pods_api.portforward(&pod, &[pod_port]).await?;
+    let mut stream = forwarder.take_stream(pod_port).ok_or_else(|| anyhow!("Port-forward stream unavailable"))?;
+
+    let request = build_http_request(method, path, &format!("{}:{}", parse_probe_target(target).1, port), headers);
+    let started = Instant::now();
+    let exchange = async {
+        stream.write_all(&request).await?;
+        let mut buffer = Vec::new();
+        let mut chunk = [0u8; 8192];
+        loop {
+            let read = stream.read(&mut chunk).await?;
+            if read == 0 {
+                break;
+            }
+            buffer.extend_from_slice(&chunk[..read]);
+            if let Some(head) = parse_http_head(&buffer)? {
+                if body_complete(&head, &buffer[head.body_offset..]) || buffer.len() >= MAX_RESPONSE_BYTES {
+                    break;
+                }
+            }
+        }
+        Ok::<_, anyhow::Error>(buffer)
+    };
+    let buffer = tokio::time::timeout(REQUEST_TIMEOUT, exchange).await
+        .map_err(|_| anyhow!("No complete response within {}s", REQUEST_TIMEOUT.as_secs()))??;
+    let latency_ms = started.elapsed().as_millis() as u64;
+    drop(stream);
+    forwarder.abort();
+
+    let head = parse_http_head(&buffer)?.ok_or_else(|| anyhow!("Connection closed before a complete HTTP response"))?;
+    let raw_body = &buffer[head.body_offset..];
+    let body = if is_chunked(&head) { decode_chunked(raw_body) } else { raw_body.to_vec() };
+
+    Ok(HttpProbeResponse {
+        pod,
+        port: pod_port,
+        status: head.status,
+        reason: head.reason,
+        headers: head.headers,
+        latency_ms,
+        body_truncated: body.len() > BODY_SNIPPET_BYTES || buffer.len() >= MAX_RESPONSE_BYTES,
+        body_snippet: String::from_utf8_lossy(&body[..body.len().min(BODY_SNIPPET_BYTES)]).to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_http_request() {
+        let headers = HashMap::from([("Authorization".to_string(), "Bearer t".to_string())]);
+        let request = String::from_utf8(build_http_request("get", "healthz", "web:80", &headers)).unwrap();
+        assert!(request.starts_with("GET /healthz HTTP/1.1\r\nHost: web:80\r\n"));
+        assert!(request.contains("Authorization: Bearer t\r\n"));
+        assert!(request.ends_with("Connection: close\r\n\r\n"));
+    }
+
+    #[test]
+    fn test_parse_response_framing() {
+        assert!(parse_http_head(b"HTTP/1.1 200 OK\r\nContent-Le").unwrap().is_none());
+        assert!(parse_http_head(b"SSH-2.0-OpenSSH\r\n\r\n").is_err());
+
+        let response = b"HTTP/1.1 200 OK\r\nSet-Cookie: a=1\r\nSet-Cookie: b=2\r\nTransfer-Encoding: chunked\r\n\r\n5\r\nhello\r\n6\r\n world\r\n0\r\n\r\n";
+        let head = parse_http_head(response).unwrap().unwrap();
+        assert_eq!((head.status, head.reason.as_str()), (200, "OK"));
+        assert_eq!(head.headers["set-cookie"], "a=1, b=2");
+        let body = &response[head.body_offset..];
+        assert!(body_complete(&head, body));
+        assert_eq!(decode_chunked(body), b"hello world");
+
+        let partial = b"HTTP/1.1 404 Not Found\r\nContent-Length: 10\r\n\r\nnot";
+        let head = parse_http_head(partial).unwrap().unwrap();
+        assert!(!body_complete(&head, &partial[head.body_offset..]));
+    }
+
+    #[test]
+    fn test_decode_chunked_stops_at_oversized_chunk() {
+        assert_eq!(decode_chunked(b"5\r\nhello\r\nffffffffffffffff\r\nabc\r\n0\r\n\r\n"), b"hello");
+        assert_eq!(decode_chunked(b"fffffffffffffffff\r\nabc\r\n"), b"");
+        assert_eq!(decode_chunked(b"5\r\nhel"), b"hel");
+    }
+
+    #[test]
+    fn test_parse_probe_target() {
+        assert_eq!(parse_probe_target("svc/web"), (true, "web"));
+        assert_eq!(parse_probe_target("pod/db-0"), (false, "db-0"));
+        assert_eq!(parse_probe_target("db-0"), (false, "db-0"));
+    }
+}
@@ -0,0 +1,374 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Ingress Routes
+// Detects ingress controllers, flattens Ingress rules into a host/path to service table, and
+// creates Ingresses from structured routes
+
+use anyhow::{anyhow, Result};
+use kube::api::{ListParams, PostParams};
+use kube::{Api, Client};
+use k8s_openapi::api::core::v1::{Pod, Secret, Service};
+use k8s_openapi::api::networking::v1::{
+    HTTPIngressPath, HTTPIngressRuleValue, Ingress, IngressBackend, IngressClass, IngressRule, IngressServiceBackend,
+    IngressSpec, IngressTLS, ServiceBackendPort,
+};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use std::collections::BTreeMap;
+
+use crate::types::{IngressControllerInfo, IngressCreateRequest, IngressPathRequest, IngressRoute, IngressRouteTable};
+
+const DEFAULT_CLASS_ANNOTATION: &str = "ingressclass.kubernetes.io/is-default-class";
+const LEGACY_CLASS_ANNOTATION: &str = "kubernetes.io/ingress.class";
+
+// nginx, traefik, haproxy or None, from an IngressClass controller or a container image
+pub fn kuboard_ingress_controller_kind(value: &str) -> Option<&'static str> {
+    let value = value.to_lowercase();
+    if value.contains("traefik") {
+        Some("traefik")
+    } else if value.contains("haproxy") {
+        Some("haproxy")
+    } else if value.contains("ingress-nginx") || value.contains("nginx-ingress") || value.contains("nginx.org") {
+        Some("nginx")
+    } else {
+        None
+    }
+}
+
+fn controller_entry<'a>(controllers: &'a mut BTreeMap<String, IngressControllerInfo>, kind: &str) -> &'a mut IngressControllerInfo {
+    controllers.entry(kind.to_string()).or_insert_with(|| IngressControllerInfo {
+        kind: kind.to_string(),
+        controller: None,
+        ingress_classes: Vec::new(),
+        default_class: false,
+        namespace: None,
+        pods: 0,
+        ready_pods: 0,
+    })
+}
+
+pub fn kuboard_detect_ingress_controllers(classes: &[IngressClass], pods: &[Pod]) -> Vec<IngressControllerInfo> {
+    let mut controllers: BTreeMap<String, IngressControllerInfo> = BTreeMap::new();
+
+    for class in classes {
+        let controller = class.spec.as_ref().and_then(|s| s.controller.clone()).unwrap_or_default();
+        let kind = kuboard_ingress_controller_kind(&controller).unwrap_or("other");
+        let entry = controller_entry(&mut controllers, kind);
+        entry.controller.get_or_insert(controller);
+        entry.ingress_classes.push(class.metadata.name.clone().unwrap_or_default());
+        let is_default = class.metadata.annotations.as_ref()
+            .and_then(|a| a.get(DEFAULT_CLASS_ANNOTATION))
+            .is_some_and(|v| v == "true");
+        entry.default_class |= is_default;
+    }
+
+    for pod in pods {
+        let images = pod.spec.iter().flat_map(|s| s.containers.iter()).filter_map(|c| c.image.as_deref());
+        let Some(kind) = images.filter_map(kuboard_ingress_controller_kind).next() else { continue };
+        let ready = pod.status.as_ref()
+            .and_then(|s| s.container_statuses.as_ref())
+            .is_some_and(|statuses| !statuses.is_empty() && statuses.iter().all(|c| c.ready));
+        let entry = controller_entry(&mut controllers, kind);
+        entry.namespace.get_or_insert_with(|| pod.metadata.namespace.clone().unwrap_or_default());
+        entry.pods += 1;
+        entry.ready_pods += usize::from(ready);
+    }
+
+    controllers.into_values().collect()
+}
+
+fn backend_target(backend: &IngressBackend) -> (String, Option<String>) {
+    match backend.service.as_ref() {
+        Some(service) => {
+            let port = service.port.as_ref().and_then(|p| p.number.map(|n| n.to_string()).or_else(|| p.name.clone()));
+            (service.name.clone(), port)
+        }
+        // Resource backends (e.g. a storage bucket) aren't services
+        None => {
+            let resource = backend.resource.as_ref();
+            (resource.map(|r| format!("{}/{}", r.kind, r.name)).unwrap_or_default(), None)
+        }
+    }
+}
+
+// One route per host/path; rules without a host answer for any host ("*")
+pub fn kuboard_flatten_ingress_routes(ingresses: &[Ingress]) -> Vec<IngressRoute> {
+    let mut routes = Vec::new();
+    for ingress in ingresses {
+        let Some(spec) = ingress.spec.as_ref() else { continue };
+        let name = ingress.metadata.name.clone().unwrap_or_default();
+        let namespace = ingress.metadata.namespace.clone().unwrap_or_default();
+        let class = spec.ingress_class_name.clone().or_else(|| {
+            ingress.metadata.annotations.as_ref().and_then(|a| a.get(LEGACY_CLASS_ANNOTATION)).cloned()
+        });
+        let tls_hosts: Vec<&String> = spec.tls.iter().flatten().flat_map(|t| t.hosts.iter().flatten()).collect();
+        let route = |host: &str, path: &str, path_type: &str, backend: &IngressBackend| {
+            let (service, service_port) = backend_target(backend);
+            IngressRoute {
+                host: host.to_string(),
+                path: path.to_string(),
+                path_type: path_type.to_string(),
+                service,
+                service_port,
+                ingress: name.clone(),
+                namespace: namespace.clone(),
+                ingress_class: class.clone(),
+                tls: tls_hosts.iter().any(|h| *h == host),
+            }
+        };
+
+        for rule in spec.rules.iter().flatten() {
+            let host = rule.host.as_deref().unwrap_or("*");
+            for path in rule.http.iter().flat_map(|h| h.paths.iter()) {
+                routes.push(route(host, path.path.as_deref().unwrap_or("/"), &path.path_type, &path.backend));
+            }
+        }
+        if let Some(backend) = spec.default_backend.as_ref() {
+            routes.push(route("*", "/", "Default", backend));
+        }
+    }
+    routes
+}
+
+fn host_matches(pattern: &str, host: &str) -> bool {
+    match pattern.strip_prefix("*.") {
+        // A wildcard covers exactly one extra DNS label
+        Some(suffix) => host.split_once('.').is_some_and(|(label, rest)| !label.is_empty() && rest == suffix),
+        None => pattern == "*" || pattern.eq_ignore_ascii_case(host),
+    }
+}
+
+// Prefix paths match whole path elements: /api matches /api/v1 but not /apiv1
+fn path_matches(route: &IngressRoute, path: &str) -> bool {
+    match route.path_type.as_str() {
+        "Exact" => route.path == path,
+        _ => {
+            let prefix = route.path.trim_end_matches('/');
+            prefix.is_empty() || path == prefix || path.starts_with(&format!("{}/", prefix))
+        }
+    }
+}
+
+// The route an HTTP request would take: exact hosts beat wildcards, then Exact paths, then the longest prefix
+pub fn kuboard_match_ingress_route<'a>(routes: &'a [IngressRoute], url: &str) -> Option<&'a IngressRoute> {
+    let without_scheme = url.split_once("://").map(|(_, rest)| rest).unwrap_or(url);
+    let (authority, path) = match without_scheme.find('/') {
+        Some(index) => (&without_scheme[..index], &without_scheme[index..]),
+        None => (without_scheme, "/"),
+    };
+    let host = authority.split(':').next().unwrap_or(authority);
+    let path = path.split(['?', '#']).next().unwrap_or(path);
+
+    routes.iter()
+        .filter(|r| host_matches(&r.host, host) && path_matches(r, path))
+        .max_by_key(|r| (
+            if r.host == "*" { 0 } else if r.host.starts_with("*.") { 1 } else { 2 },
+            r.path_type == "Exact",
+            r.path.len(),
+        ))
+}
+
+pub async fn kuboard_build_ingress_route_table(client: &Client) -> Result<IngressRouteTable> {
+    let params = ListParams::default();
+    let classes = Api::<IngressClass>::all(client.clone()).list(&params).await?.items;
+    let pods = Api::<Pod>::all(client.clone()).list(&params).await?.items;
+    let ingresses = Api::<Ingress>::all(client.clone()).list(&params).await?.items;
+
+    Ok(IngressRouteTable {
+        controllers: kuboard_detect_ingress_controllers(&classes, &pods),
+        routes: kuboard_flatten_ingress_routes(&ingresses),
+    })
+}
+
+const PATH_TYPES: &[&str] = &["Prefix", "Exact", "ImplementationSpecific"];
+
+// A numeric port refers to the service port number, anything else to a port name
+fn service_backend_port(port: &str) -> ServiceBackendPort {
+    match port.parse::<i32>() {
+        Ok(number) => ServiceBackendPort { number: Some(number), name: None },
+        Err(_) => ServiceBackendPort { number: None, name: Some(port.to_string()) },
+    }
+}
+
+fn ingress_path(path: &IngressPathRequest) -> Result<HTTPIngressPath> {
+    let path_type = path.path_type.clone().unwrap_or_else(|| "Prefix".to_string());
+    if !PATH_TYPES.contains(&path_type.as_str()) {
+        return Err(anyhow!("Path type must be Prefix, Exact or ImplementationSpecific, not {}", path_type));
+    }
+    if !path.path.starts_with('/') {
+        return Err(anyhow!("Path {} must start with /", path.path));
+    }
+    Ok(HTTPIngressPath {
+        path: Some(path.path.clone()),
+        path_type,
+        backend: IngressBackend {
+            service: Some(IngressServiceBackend {
+                name: path.service.clone(),
+                port: Some(service_backend_port(&path.service_port)),
+            }),
+            resource: None,
+        },
+    })
+}
+
+// Checks that don't need the cluster; the host, when set, becomes the only rule's host
+pub fn kuboard_build_ingress(request: &IngressCreateRequest) -> Result<Ingress> {
+    if request.paths.is_empty() {
+        return Err(anyhow!("An Ingress needs at least one path"));
+    }
+    let host = request.host.clone().filter(|h| !h.trim().is_empty());
+    if request.tls_secret.is_some() && host.is_none() {
+        return Err(anyhow!("TLS needs a host to serve the certificate for"));
+    }
+    let paths = request.paths.iter().map(ingress_path).collect::<Result<Vec<_>>>()?;
+
+    Ok(Ingress {
+        metadata: ObjectMeta {
+            name: Some(request.name.clone()),
+            namespace: Some(request.namespace.clone()),
+            ..Default::default()
+        },
+        spec: Some(IngressSpec {
+            ingress_class_name: request.ingress_class.clone(),
+            tls: request.tls_secret.as_ref().map(|secret| vec![IngressTLS {
+                hosts: host.clone().map(|h| vec![h]),
+                secret_name: Some(secret.clone()),
+            }]),
+            rules: Some(vec![IngressRule {
+                host,
+                http: Some(HTTPIngressRuleValue { paths }),
+            }]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    })
+}
+
+// Every backend service must expose the port, and the TLS secret and class must exist
+pub async fn kuboard_check_ingress_references(client: &Client, request: &IngressCreateRequest) -> Result<()> {
+    let services_api: Api<Service> = Api::namespaced(client.clone(), &request.namespace);
+    for path in &request.paths {
+        let service = services_api.get_opt(&path.service).await?
+            .ok_or_else(|| anyhow!("Service {}/{} does not exist", request.namespace, path.service))?;
+        let ports = service.spec.and_then(|s| s.ports).unwrap_or_default();
+        let exposed = ports.iter().any(|port| {
+            port.port.to_string() == path.service_port || port.name.as_deref() == Some(path.service_port.as_str())
+        });
+        if !exposed {
+            return Err(anyhow!("Service {} has no port {}", path.service, path.service_port));
+        }
+    }
+
+    if let Some(secret_name) = request.tls_secret.as_deref() {
+        let secret = Api::<Secret>::namespaced(client.clone(), &request.namespace).get_opt(secret_name).await?
+            .ok_or_else(|| anyhow!("TLS secret {}/{} does not exist", request.namespace, secret_name))?;
+        if secret.type_.as_deref() != Some("kubernetes.io/tls") {
+            return Err(anyhow!("Secret {} is not a kubernetes.io/tls secret", secret_name));
+        }
+    }
+
+    if let Some(class) = request.ingress_class.as_deref() {
+        Api::<IngressClass>::all(client.clone()).get_opt(class).await?
+            .ok_or_else(|| anyhow!("IngressClass {} does not exist", class))?;
+    }
+    Ok(())
+}
+
+pub async fn kuboard_create_ingress_checked(client: &Client, request: &IngressCreateRequest) -> Result<Ingress> {
+    let ingress = kuboard_build_ingress(request)?;
+    kuboard_check_ingress_references(client, request).await?;
+    let api: Api<Ingress> = Api::namespaced(client.clone(), &request.namespace);
+    Ok(api.create(&PostParams::default(), &ingress).await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn ingress() -> Ingress {
+        serde_json::from_value(json!({
+            "metadata": { "name": "shop", "namespace": "web" },
+            "spec": {
+                "ingressClassName": "nginx",
+                "tls": [{ "hosts": ["shop.example.com"], "secretName": "shop-tls" }],
+                "defaultBackend": { "service": { "name": "fallback", "port": { "number": 80 } } },
+                "rules": [
+                    {
+                        "host": "shop.example.com",
+                        "http": { "paths": [
+                            { "path": "/", "pathType": "Prefix", "backend": { "service": { "name": "frontend", "port": { "name": "http" } } } },
+                            { "path": "/api", "pathType": "Prefix", "backend": { "service": { "name": "api", "port": { "number": 8080 } } } },
+                            { "path": "/api/health", "pathType": "Exact", "backend": { "service": { "name": "health", "port": { "number": 8081 } } } },
+                        ] },
+                    },
+                    {
+                        "host": "*.example.com",
+                        "http": { "paths": [
+                            { "path": "/", "pathType": "Prefix", "backend": { "service": { "name": "tenant", "port": { "number": 80 } } } },
+                        ] },
+                    },
+                ],
+            },
+        })).unwrap()
+    }
+
+    #[test]
+    fn test_flatten_and_match_routes() {
+        let routes = kuboard_flatten_ingress_routes(&[ingress()]);
+        assert_eq!(routes.len(), 5);
+        assert!(routes[0].tls);
+        assert_eq!(routes[0].service_port.as_deref(), Some("http"));
+
+        let service = |url: &str| kuboard_match_ingress_route(&routes, url).map(|r| r.service.as_str());
+        assert_eq!(service("https://shop.example.com/api/v1/items?page=2"), Some("api"));
+        assert_eq!(service("https://shop.example.com/apiv1"), Some("frontend"));
+        assert_eq!(service("https://shop.example.com/api/health"), Some("health"));
+        assert_eq!(service("http://acme.example.com/"), Some("tenant"));
+        assert_eq!(service("http://a.b.example.com/"), Some("fallback"));
+    }
+
+    #[test]
+    fn test_detect_ingress_controllers() {
+        let class: IngressClass = serde_json::from_value(json!({
+            "metadata": { "name": "nginx", "annotations": { DEFAULT_CLASS_ANNOTATION: "true" } },
+            "spec": { "controller": "k8s.io/ingress-nginx" },
+        })).unwrap();
+        let pod: Pod = serde_json::from_value(json!({
+            "metadata": { "name": "traefik-abc", "namespace": "traefik" },
+            "spec": { "containers": [{ "name": "traefik", "image": "docker.io/traefik:v3.1" }] },
+        })).unwrap();
+
+        let controllers = kuboard_detect_ingress_controllers(&[class], &[pod]);
+
+        assert_eq!(controllers.len(), 2);
+        assert_eq!((controllers[0].kind.as_str(), controllers[0].default_class), ("nginx", true));
+        assert_eq!((controllers[1].kind.as_str(), controllers[1].pods, controllers[1].ready_pods), ("traefik", 1, 0));
+    }
+
+    #[test]
+    fn test_build_ingress() {
+        let request = IngressCreateRequest {
+            name: "shop".to_string(),
+            namespace: "web".to_string(),
+            host: Some("shop.example.com".to_string()),
+            paths: vec![
+                IngressPathRequest { path: "/".to_string(), path_type: None, service: "frontend".to_string(), service_port: "http".to_string() },
+                IngressPathRequest { path: "/api".to_string(), path_type: None, service: "api".to_string(), service_port: "8080".to_string() },
+            ],
+            tls_secret: Some("shop-tls".to_string()),
+            ingress_class: Some("nginx".to_string()),
+        };
+
+        let routes = kuboard_flatten_ingress_routes(&[kuboard_build_ingress(&request).unwrap()]);
+        assert_eq!(routes.len(), 2);
+        assert!(routes.iter().all(|r| r.tls && r.host == "shop.example.com" && r.path_type == "Prefix"));
+        assert_eq!(routes[1].service_port.as_deref(), Some("8080"));
+
+        let without_host = IngressCreateRequest { host: None, ..request.clone() };
+        assert!(kuboard_build_ingress(&without_host).is_err());
+        let mut relative = request.clone();
+        relative.paths[0].path = "api".to_string();
+        assert!(kuboard_build_ingress(&relative).is_err());
+    }
+}
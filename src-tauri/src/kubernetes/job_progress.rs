@@ -0,0 +1,105 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Job Progress
+// Succeeded, failed and active pod counts of a Job against its completions, with the time left
+// extrapolated from how fast pods have completed so far
+
+use chrono::{DateTime, Duration, Utc};
+use k8s_openapi::api::batch::v1::Job;
+
+use crate::kubernetes::cronjobs::kuboard_cronjob_run;
+use crate::quantity::kuboard_quantity_percent;
+use crate::types::{CronJobRunState, JobProgress};
+use crate::utils::kuboard_format_duration;
+
+pub fn kuboard_job_progress(job: &Job, now: DateTime<Utc>) -> JobProgress {
+    let spec = job.spec.as_ref();
+    let status = job.status.as_ref();
+    let run = kuboard_cronjob_run(job);
+    let suspended = spec.and_then(|s| s.suspend).unwrap_or(false);
+    let completions = spec.and_then(|s| s.completions);
+    let succeeded = status.and_then(|s| s.succeeded).unwrap_or(0);
+
+    let start_time = status.and_then(|s| s.start_time.as_ref()).map(|t| t.0);
+    let elapsed_seconds = run.duration_seconds.or_else(|| start_time.map(|start| (now - start).num_seconds().max(0)));
+
+    // Remaining completions at the average rate so far; the rate already reflects parallelism
+    let estimated_remaining_seconds = match (completions, elapsed_seconds) {
+        (Some(completions), Some(elapsed)) if run.state == CronJobRunState::Active && !suspended && succeeded > 0 => {
+            let remaining = (completions - succeeded).max(0) as f64;
+            Some((remaining * elapsed as f64 / succeeded as f64).ceil() as i64)
+        }
+        _ => None,
+    };
+
+    JobProgress {
+        name: job.metadata.name.clone().unwrap_or_default(),
+        namespace: job.metadata.namespace.clone().unwrap_or_default(),
+        state: run.state,
+        suspended,
+        completions,
+        // Defaults applied by the API server when the fields are unset
+        parallelism: spec.and_then(|s| s.parallelism).unwrap_or(1),
+        succeeded,
+        failed: status.and_then(|s| s.failed).unwrap_or(0),
+        active: status.and_then(|s| s.active).unwrap_or(0),
+        ready: status.and_then(|s| s.ready).unwrap_or(0),
+        backoff_limit: spec.and_then(|s| s.backoff_limit).unwrap_or(6),
+        percent_complete: completions.map(|completions| kuboard_quantity_percent(succeeded as f64, completions as f64)),
+        start_time: run.start_time,
+        elapsed: elapsed_seconds.map(kuboard_format_duration),
+        elapsed_seconds,
+        estimated_remaining: estimated_remaining_seconds.map(kuboard_format_duration),
+        estimated_completion: estimated_remaining_seconds.map(|seconds| (now + Duration::seconds(seconds)).to_rfc3339()),
+        estimated_remaining_seconds,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn job(spec: serde_json::Value, status: serde_json::Value) -> Job {
+        serde_json::from_value(json!({
+            "metadata": { "name": "render", "namespace": "batch" },
+            "spec": spec,
+            "status": status,
+        })).unwrap()
+    }
+
+    #[test]
+    fn test_job_progress() {
+        let now: DateTime<Utc> = "2025-01-01T01:00:00Z".parse().unwrap();
+        let running = job(
+            json!({ "completions": 40, "parallelism": 4, "template": {} }),
+            json!({ "startTime": "2025-01-01T00:00:00Z", "succeeded": 10, "failed": 1, "active": 4, "ready": 3 }),
+        );
+        let progress = kuboard_job_progress(&running, now);
+        assert_eq!((progress.succeeded, progress.failed, progress.active, progress.parallelism), (10, 1, 4, 4));
+        assert_eq!(progress.percent_complete, Some(25.0));
+        assert_eq!(progress.elapsed_seconds, Some(3600));
+        // 10 completions an hour leaves 3 hours for the other 30
+        assert_eq!(progress.estimated_remaining_seconds, Some(3 * 3600));
+        assert_eq!(progress.estimated_completion.as_deref(), Some("2025-01-01T04:00:00+00:00"));
+
+        let starting = job(json!({ "completions": 40, "template": {} }), json!({ "startTime": "2025-01-01T00:59:00Z", "active": 1 }));
+        assert_eq!(kuboard_job_progress(&starting, now).estimated_remaining_seconds, None);
+
+        let queue = job(json!({ "parallelism": 5, "template": {} }), json!({ "startTime": "2025-01-01T00:00:00Z", "succeeded": 2, "active": 3 }));
+        let progress = kuboard_job_progress(&queue, now);
+        assert_eq!((progress.completions, progress.percent_complete, progress.estimated_remaining_seconds), (None, None, None));
+
+        let done = job(
+            json!({ "completions": 2, "template": {} }),
+            json!({
+                "startTime": "2025-01-01T00:00:00Z", "completionTime": "2025-01-01T00:10:00Z", "succeeded": 2,
+                "conditions": [{ "type": "Complete", "status": "True" }],
+            }),
+        );
+        let progress = kuboard_job_progress(&done, now);
+        assert_eq!(progress.state, CronJobRunState::Succeeded);
+        assert_eq!((progress.elapsed_seconds, progress.estimated_remaining_seconds), (Some(600), None));
+    }
+}
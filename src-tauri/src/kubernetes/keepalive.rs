@@ -0,0 +1,93 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Connection Warm-up and Keepalive
+// Pings /version when a client is created and then periodically, so the pooled connection,
+// TLS session and exec-plugin token are ready when the next real command runs
+
+use chrono::Utc;
+use kube::Client;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
+use tracing::{debug, warn};
+
+use crate::types::ConnectionStatus;
+
+// kube's HTTP/1.1 pool drops connections idle for 90 seconds, so ping comfortably inside that
+pub const KEEPALIVE_INTERVAL: Duration = Duration::from_secs(60);
+
+// One /version round trip; the first after connecting pays for TCP, TLS and credential setup
+pub async fn kuboard_ping_client(client: &Client) -> ConnectionStatus {
+    let started = Instant::now();
+    let result = client.apiserver_version().await;
+    ConnectionStatus {
+        checked_at: Utc::now().to_rfc3339(),
+        latency_ms: started.elapsed().as_millis() as u64,
+        server_version: result.as_ref().ok().map(|version| version.git_version.clone()),
+        error: result.err().map(|e| e.to_string()),
+    }
+}
+
+pub struct ClientKeepalive {
+    handle: Option<JoinHandle<()>>,
+    status: Arc<Mutex<Option<ConnectionStatus>>>,
+}
+
+impl ClientKeepalive {
+    pub fn new() -> Self {
+        Self {
+            handle: None,
+            status: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.handle.is_some()
+    }
+
+    // Result of the most recent ping, None until the warm-up ping completes
+    pub fn status(&self) -> Option<ConnectionStatus> {
+        self.status.lock().expect("keepalive status lock poisoned").clone()
+    }
+
+    pub fn stop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            handle.abort();
+        }
+        *self.status.lock().expect("keepalive status lock poisoned") = None;
+    }
+
+    // Replaces any previous client's keepalive; the first ping runs immediately as the warm-up
+    pub fn start(&mut self, client: Client, interval: Duration) {
+        self.stop();
+
+        let status = self.status.clone();
+        let handle = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                let result = kuboard_ping_client(&client).await;
+                match &result.error {
+                    Some(e) => warn!("Keepalive ping failed: {}", e),
+                    None => debug!("Keepalive ping took {}ms", result.latency_ms),
+                }
+                *status.lock().expect("keepalive status lock poisoned") = Some(result);
+            }
+        });
+        self.handle = Some(handle);
+    }
+}
+
+impl Default for ClientKeepalive {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for ClientKeepalive {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
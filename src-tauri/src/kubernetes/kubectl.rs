@@ -0,0 +1,157 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// kubectl Command Generation
+// Renders UI actions as equivalent kubectl invocations for runbooks and incident notes
+
+use crate::types::KubectlAction;
+
+// Quotes an argument for POSIX shells; plain names are left readable
+fn shell_quote(arg: &str) -> String {
+    let safe = !arg.is_empty()
+        && arg.chars().all(|c| c.is_ascii_alphanumeric() || "-_./:=@,".contains(c));
+    if safe {
+        arg.to_string()
+    } else {
+        format!("'{}'", arg.replace('\'', r"'\''"))
+    }
+}
+
+fn resource_ref(kind: &str, name: &str) -> String {
+    format!("{}/{}", kind.to_lowercase(), name)
+}
+
+pub fn kuboard_kubectl_args(action: &KubectlAction) -> Vec<String> {
+    let mut args: Vec<String> = Vec::new();
+    let mut push = |parts: &[&str]| args.extend(parts.iter().map(|p| p.to_string()));
+
+    match action {
+        KubectlAction::Scale { kind, name, namespace, replicas } => {
+            push(&["scale", &resource_ref(kind, name), "-n", namespace, &format!("--replicas={}", replicas)]);
+        }
+        KubectlAction::Restart { kind, name, namespace } => {
+            push(&["rollout", "restart", &resource_ref(kind, name), "-n", namespace]);
+        }
+        KubectlAction::Rollback { name, namespace, revision } => {
+            push(&["rollout", "undo", &resource_ref("deployment", name), "-n", namespace]);
+            if let Some(revision) = revision {
+                push(&[&format!("--to-revision={}", revision)]);
+            }
+        }
+        KubectlAction::Delete { kind, name, namespace, grace_period_seconds, force } => {
+            push(&["delete", &resource_ref(kind, name)]);
+            if let Some(namespace) = namespace {
+                push(&["-n", namespace]);
+            }
+            if *force {
+                push(&["--grace-period=0", "--force"]);
+            } else if let Some(seconds) = grace_period_seconds {
+                push(&[&format!("--grace-period={}", seconds)]);
+            }
+        }
+        KubectlAction::Cordon { node } => push(&["cordon", node]),
+        KubectlAction::Uncordon { node } => push(&["uncordon", node]),
+        KubectlAction::Drain { node, ignore_daemonsets, delete_emptydir_data } => {
+            push(&["drain", node]);
+            if *ignore_daemonsets {
+                push(&["--ignore-daemonsets"]);
+            }
+            if *delete_emptydir_data {
+                push(&["--delete-emptydir-data"]);
+            }
+        }
+        KubectlAction::SuspendCronJob { name, namespace, suspend } => {
+            push(&[
+                "patch", &resource_ref("cronjob", name), "-n", namespace,
+                "--type=merge", "-p", &format!(r#"{{"spec":{{"suspend":{}}}}}"#, suspend),
+            ]);
+        }
+        KubectlAction::TriggerCronJob { name, namespace, job_name } => {
+            push(&["create", "job", job_name, &format!("--from=cronjob/{}", name), "-n", namespace]);
+        }
+        KubectlAction::Logs { pod_name, namespace, container, tail_lines } => {
+            push(&["logs", pod_name, "-n", namespace]);
+            if let Some(container) = container {
+                push(&["-c", container]);
+            }
+            if let Some(lines) = tail_lines {
+                push(&[&format!("--tail={}", lines)]);
+            }
+        }
+        KubectlAction::Exec { pod_name, namespace, container, command } => {
+            push(&["exec", "-it", pod_name, "-n", namespace]);
+            if let Some(container) = container {
+                push(&["-c", container]);
+            }
+            push(&["--"]);
+            args.extend(command.iter().cloned());
+        }
+    }
+
+    args
+}
+
+// Renders the full command line, pinned to the context so it can't hit the wrong cluster when pasted
+pub fn kuboard_kubectl_command(action: &KubectlAction, context: Option<&str>) -> String {
+    let mut parts = vec!["kubectl".to_string()];
+    if let Some(context) = context {
+        parts.push(format!("--context={}", shell_quote(context)));
+    }
+    parts.extend(kuboard_kubectl_args(action).iter().map(|arg| shell_quote(arg)));
+    parts.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kubectl_command_for_scale_and_force_delete() {
+        let scale = KubectlAction::Scale {
+            kind: "Deployment".to_string(),
+            name: "web".to_string(),
+            namespace: "default".to_string(),
+            replicas: 3,
+        };
+        assert_eq!(
+            kuboard_kubectl_command(&scale, Some("kind-dev")),
+            "kubectl --context=kind-dev scale deployment/web -n default --replicas=3"
+        );
+
+        let delete = KubectlAction::Delete {
+            kind: "Pod".to_string(),
+            name: "web-abc".to_string(),
+            namespace: Some("default".to_string()),
+            grace_period_seconds: Some(30),
+            force: true,
+        };
+        assert_eq!(
+            kuboard_kubectl_command(&delete, None),
+            "kubectl delete pod/web-abc -n default --grace-period=0 --force"
+        );
+    }
+
+    #[test]
+    fn test_kubectl_command_quotes_shell_arguments() {
+        let suspend = KubectlAction::SuspendCronJob {
+            name: "nightly".to_string(),
+            namespace: "default".to_string(),
+            suspend: true,
+        };
+        assert_eq!(
+            kuboard_kubectl_command(&suspend, Some("arn:aws:eks:us-east-1:1234:cluster/prod ops")),
+            r#"kubectl --context='arn:aws:eks:us-east-1:1234:cluster/prod ops' patch cronjob/nightly -n default --type=merge -p '{"spec":{"suspend":true}}'"#
+        );
+
+        let exec = KubectlAction::Exec {
+            pod_name: "web-abc".to_string(),
+            namespace: "default".to_string(),
+            container: None,
+            command: vec!["sh".to_string(), "-c".to_string(), "echo it's up".to_string()],
+        };
+        assert_eq!(
+            kuboard_kubectl_command(&exec, None),
+            r#"kubectl exec -it web-abc -n default -- sh -c 'echo it'\''s up'"#
+        );
+    }
+}
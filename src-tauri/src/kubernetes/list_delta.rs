@@ -0,0 +1,181 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Incremental List Refreshes
+// Diffs a fresh list against the resourceVersions last sent to the frontend so a refresh only
+// carries changed and deleted objects; unknown revisions fall back to a full resync
+
+use anyhow::Result;
+use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, ReplicaSet, StatefulSet};
+use k8s_openapi::api::batch::v1::CronJob;
+use k8s_openapi::api::core::v1::{Pod, Service};
+use k8s_openapi::NamespaceResourceScope;
+use kube::api::ListParams;
+use kube::{Api, Client, Resource};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Debug;
+
+use crate::kubernetes::namespace_access::kuboard_list_accessible;
+use crate::types::{ListDelta, NamespaceFilter, WatchKind};
+
+// Several views can refresh the same kind, so a few revisions are kept per kind
+const SNAPSHOTS_PER_KIND: usize = 4;
+
+// resourceVersion of every object in a list as it was sent, keyed by namespace/name
+#[derive(Debug, Clone)]
+pub struct ListSnapshot {
+    pub revision: String,
+    versions: HashMap<String, String>,
+}
+
+pub struct ListDeltaStore {
+    snapshots: HashMap<(String, WatchKind), VecDeque<ListSnapshot>>,
+}
+
+impl ListDeltaStore {
+    pub fn new() -> Self {
+        Self { snapshots: HashMap::new() }
+    }
+
+    pub fn find(&self, context: &str, kind: WatchKind, revision: &str) -> Option<&ListSnapshot> {
+        self.snapshots.get(&(context.to_string(), kind))?.iter().find(|s| s.revision == revision)
+    }
+
+    pub fn record(&mut self, context: &str, kind: WatchKind, snapshot: ListSnapshot) {
+        let snapshots = self.snapshots.entry((context.to_string(), kind)).or_default();
+        snapshots.push_back(snapshot);
+        while snapshots.len() > SNAPSHOTS_PER_KIND {
+            snapshots.pop_front();
+        }
+    }
+}
+
+impl Default for ListDeltaStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn object_key(object: &Value) -> String {
+    let metadata = &object["metadata"];
+    format!("{}/{}", metadata["namespace"].as_str().unwrap_or_default(), metadata["name"].as_str().unwrap_or_default())
+}
+
+// Everything is sent when there is no previous snapshot to diff against
+pub fn kuboard_compute_list_delta(kind: WatchKind, previous: Option<&ListSnapshot>, objects: Vec<Value>) -> (ListDelta, ListSnapshot) {
+    let versions: HashMap<String, String> = objects.iter()
+        .map(|object| (object_key(object), object["metadata"]["resourceVersion"].as_str().unwrap_or_default().to_string()))
+        .collect();
+
+    let (upserted, mut deleted) = match previous {
+        Some(previous) => {
+            let upserted = objects.into_iter()
+                .filter(|object| {
+                    let version = &versions[&object_key(object)];
+                    // Objects without a resourceVersion can't be compared, so they are always resent
+                    version.is_empty() || previous.versions.get(&object_key(object)) != Some(version)
+                })
+                .collect();
+            let deleted = previous.versions.keys().filter(|key| !versions.contains_key(*key)).cloned().collect();
+            (upserted, deleted)
+        }
+        None => (objects, Vec::new()),
+    };
+    deleted.sort();
+
+    let snapshot = ListSnapshot { revision: uuid::Uuid::new_v4().to_string(), versions };
+    let delta = ListDelta {
+        kind,
+        revision: snapshot.revision.clone(),
+        full: previous.is_none(),
+        upserted,
+        deleted,
+    };
+    (delta, snapshot)
+}
+
+async fn list_values<K>(client: &Client, filter: &NamespaceFilter) -> Result<Vec<Value>>
+where
+    K: Resource<Scope = NamespaceResourceScope> + Clone + DeserializeOwned + Debug + Serialize,
+    K::DynamicType: Default,
+{
+    let items = kuboard_list_accessible::<K>(client, filter, &ListParams::default()).await?;
+    Ok(items.iter().map(serde_json::to_value).collect::<Result<_, _>>()?)
+}
+
+pub async fn kuboard_list_kind(client: &Client, filter: &NamespaceFilter, kind: WatchKind) -> Result<Vec<Value>> {
+    match kind {
+        WatchKind::Pod => list_values::<Pod>(client, filter).await,
+        WatchKind::Deployment => list_values::<Deployment>(client, filter).await,
+        WatchKind::StatefulSet => list_values::<StatefulSet>(client, filter).await,
+        WatchKind::DaemonSet => list_values::<DaemonSet>(client, filter).await,
+        WatchKind::ReplicaSet => list_values::<ReplicaSet>(client, filter).await,
+        WatchKind::Service => list_values::<Service>(client, filter).await,
+        WatchKind::CronJob => list_values::<CronJob>(client, filter).await,
+    }
+}
+
+async fn get_value<K>(client: &Client, namespace: &str, name: &str) -> Result<Option<Value>>
+where
+    K: Resource<Scope = NamespaceResourceScope> + Clone + DeserializeOwned + Debug + Serialize,
+    K::DynamicType: Default,
+{
+    let api: Api<K> = Api::namespaced(client.clone(), namespace);
+    Ok(api.get_opt(name).await?.map(|object| serde_json::to_value(object)).transpose()?)
+}
+
+// A single object of the kind, None when it doesn't exist
+pub async fn kuboard_get_kind(client: &Client, kind: WatchKind, namespace: &str, name: &str) -> Result<Option<Value>> {
+    match kind {
+        WatchKind::Pod => get_value::<Pod>(client, namespace, name).await,
+        WatchKind::Deployment => get_value::<Deployment>(client, namespace, name).await,
+        WatchKind::StatefulSet => get_value::<StatefulSet>(client, namespace, name).await,
+        WatchKind::DaemonSet => get_value::<DaemonSet>(client, namespace, name).await,
+        WatchKind::ReplicaSet => get_value::<ReplicaSet>(client, namespace, name).await,
+        WatchKind::Service => get_value::<Service>(client, namespace, name).await,
+        WatchKind::CronJob => get_value::<CronJob>(client, namespace, name).await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn pod(name: &str, version: &str) -> Value {
+        json!({ "metadata": { "name": name, "namespace": "default", "resourceVersion": version } })
+    }
+
+    #[test]
+    fn test_list_delta_sends_only_changes() {
+        let (full, snapshot) = kuboard_compute_list_delta(WatchKind::Pod, None, vec![pod("a", "1"), pod("b", "2"), pod("c", "3")]);
+        assert!(full.full);
+        assert_eq!(full.upserted.len(), 3);
+
+        let (delta, _) = kuboard_compute_list_delta(WatchKind::Pod, Some(&snapshot), vec![pod("a", "1"), pod("b", "5"), pod("d", "6")]);
+        assert!(!delta.full);
+        assert_ne!(delta.revision, snapshot.revision);
+        let names: Vec<&str> = delta.upserted.iter().map(|o| o["metadata"]["name"].as_str().unwrap()).collect();
+        assert_eq!(names, vec!["b", "d"]);
+        assert_eq!(delta.deleted, vec!["default/c"]);
+    }
+
+    #[test]
+    fn test_store_keeps_recent_revisions() {
+        let mut store = ListDeltaStore::new();
+        let mut revisions = Vec::new();
+        for _ in 0..SNAPSHOTS_PER_KIND + 1 {
+            let (_, snapshot) = kuboard_compute_list_delta(WatchKind::Service, None, Vec::new());
+            revisions.push(snapshot.revision.clone());
+            store.record("kind-dev", WatchKind::Service, snapshot);
+        }
+
+        assert!(store.find("kind-dev", WatchKind::Service, &revisions[0]).is_none());
+        assert!(store.find("kind-dev", WatchKind::Service, &revisions[SNAPSHOTS_PER_KIND]).is_some());
+        assert!(store.find("kind-dev", WatchKind::Pod, &revisions[SNAPSHOTS_PER_KIND]).is_none());
+        assert!(store.find("other", WatchKind::Service, &revisions[SNAPSHOTS_PER_KIND]).is_none());
+    }
+}
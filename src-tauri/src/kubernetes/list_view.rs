@@ -0,0 +1,166 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// List View
+// Server-side sorting and status filtering of the summary lists, so very large lists can be sliced
+// before they reach the frontend and pages stay consistent between requests
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use k8s_openapi::api::core::v1::Pod;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use kube::Resource;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::kubernetes::pod_status::kuboard_pod_summary;
+use crate::metrics::PodMetrics;
+use crate::quantity::{kuboard_parse_bytes, kuboard_parse_cpu};
+use crate::types::{PodSummary, WorkloadSummary};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortKey {
+    Name,
+    Age,
+    Restarts,
+    Cpu,
+    Memory,
+}
+
+impl SortKey {
+    // Metrics are only fetched for the keys that need them
+    pub fn needs_metrics(self) -> bool {
+        matches!(self, SortKey::Cpu | SortKey::Memory)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ListView {
+    pub sort_by: Option<SortKey>,
+    // Lowercased; an item is kept when its status matches any of them
+    pub statuses: Vec<String>,
+}
+
+// `sort_by` is one of name, age, restarts, cpu or memory; `status` is a comma-separated list
+pub fn kuboard_list_view(sort_by: Option<&str>, status: Option<&str>) -> Result<ListView> {
+    let sort_by = match sort_by.map(|s| s.trim().to_lowercase()).filter(|s| !s.is_empty()).as_deref() {
+        None => None,
+        Some("name") => Some(SortKey::Name),
+        Some("age") => Some(SortKey::Age),
+        Some("restarts") => Some(SortKey::Restarts),
+        Some("cpu") => Some(SortKey::Cpu),
+        Some("memory") => Some(SortKey::Memory),
+        Some(other) => return Err(anyhow!("Unknown sort key {}; expected name, age, restarts, cpu or memory", other)),
+    };
+    let statuses = status.into_iter()
+        .flat_map(|status| status.split(','))
+        .map(|status| status.trim().to_lowercase())
+        .filter(|status| !status.is_empty())
+        .collect();
+    Ok(ListView { sort_by, statuses })
+}
+
+fn status_matches(view: &ListView, values: &[&str]) -> bool {
+    view.statuses.is_empty() || values.iter().any(|value| view.statuses.contains(&value.to_lowercase()))
+}
+
+fn created(metadata: &ObjectMeta) -> Option<DateTime<Utc>> {
+    metadata.creation_timestamp.as_ref().map(|t| t.0)
+}
+
+// CPU in millicores and memory in bytes of each pod, keyed by namespace and name
+pub fn kuboard_pod_usage(metrics: &[PodMetrics]) -> HashMap<(String, String), (u64, u64)> {
+    metrics.iter()
+        .map(|pod| {
+            let cpu = pod.containers.iter()
+                .map(|c| kuboard_parse_cpu(&c.usage.cpu).map(|cores| (cores * 1000.0).round() as u64).unwrap_or(0))
+                .sum();
+            let memory = pod.containers.iter().map(|c| kuboard_parse_bytes(&c.usage.memory).unwrap_or(0)).sum();
+            ((pod.metadata.namespace.clone(), pod.metadata.name.clone()), (cpu, memory))
+        })
+        .collect()
+}
+
+// Name ascending, age newest first, restarts, CPU and memory highest first; pods without metrics
+// sort last and ties fall back to namespace and name
+pub fn kuboard_pod_list(pods: &[Pod], view: &ListView, usage: &HashMap<(String, String), (u64, u64)>) -> Vec<PodSummary> {
+    let mut rows: Vec<(PodSummary, Option<DateTime<Utc>>)> = pods.iter()
+        .map(|pod| (kuboard_pod_summary(pod), created(&pod.metadata)))
+        .filter(|(summary, _)| status_matches(view, &[&summary.status, &summary.phase]))
+        .collect();
+    let usage_of = |summary: &PodSummary| usage.get(&(summary.namespace.clone(), summary.name.clone())).copied();
+    rows.sort_by(|(a, a_created), (b, b_created)| {
+        let order = match view.sort_by {
+            None | Some(SortKey::Name) => Ordering::Equal,
+            Some(SortKey::Age) => b_created.cmp(a_created),
+            Some(SortKey::Restarts) => b.restarts.cmp(&a.restarts),
+            Some(SortKey::Cpu) => usage_of(b).map(|u| u.0).cmp(&usage_of(a).map(|u| u.0)),
+            Some(SortKey::Memory) => usage_of(b).map(|u| u.1).cmp(&usage_of(a).map(|u| u.1)),
+        };
+        order.then_with(|| a.namespace.cmp(&b.namespace)).then_with(|| a.name.cmp(&b.name))
+    });
+    rows.into_iter().map(|(summary, _)| summary).collect()
+}
+
+// Workloads filter on their health and sort by name or age only
+pub fn kuboard_workload_list<K: Resource>(items: &[K], summarize: fn(&K) -> WorkloadSummary, view: &ListView) -> Result<Vec<WorkloadSummary>> {
+    if let Some(key) = view.sort_by.filter(|key| !matches!(key, SortKey::Name | SortKey::Age)) {
+        return Err(anyhow!("Workloads can't be sorted by {:?}; use name or age", key));
+    }
+    let mut rows: Vec<(WorkloadSummary, Option<DateTime<Utc>>)> = items.iter()
+        .map(|item| (summarize(item), created(item.meta())))
+        .filter(|(summary, _)| status_matches(view, &[&format!("{:?}", summary.health)]))
+        .collect();
+    rows.sort_by(|(a, a_created), (b, b_created)| {
+        let order = match view.sort_by {
+            Some(SortKey::Age) => b_created.cmp(a_created),
+            _ => Ordering::Equal,
+        };
+        order.then_with(|| a.namespace.cmp(&b.namespace)).then_with(|| a.name.cmp(&b.name))
+    });
+    Ok(rows.into_iter().map(|(summary, _)| summary).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn pod(name: &str, created: &str, restarts: i32, waiting: Option<&str>) -> Pod {
+        let state = match waiting {
+            Some(reason) => json!({ "waiting": { "reason": reason } }),
+            None => json!({ "running": {} }),
+        };
+        serde_json::from_value(json!({
+            "metadata": { "name": name, "namespace": "shop", "creationTimestamp": created },
+            "spec": { "containers": [{ "name": "app" }] },
+            "status": { "phase": "Running", "containerStatuses": [{
+                "name": "app", "ready": waiting.is_none(), "restartCount": restarts, "image": "", "imageID": "", "state": state,
+            }] },
+        })).unwrap()
+    }
+
+    #[test]
+    fn test_pod_list() {
+        let pods = vec![
+            pod("api", "2025-01-01T00:00:00Z", 0, None),
+            pod("worker", "2025-03-01T00:00:00Z", 9, Some("CrashLoopBackOff")),
+            pod("cache", "2025-02-01T00:00:00Z", 2, None),
+        ];
+        let names = |view: ListView, usage: &HashMap<(String, String), (u64, u64)>| -> Vec<String> {
+            kuboard_pod_list(&pods, &view, usage).into_iter().map(|p| p.name).collect()
+        };
+        let none = HashMap::new();
+
+        assert_eq!(names(kuboard_list_view(None, None).unwrap(), &none), vec!["api", "cache", "worker"]);
+        assert_eq!(names(kuboard_list_view(Some("age"), None).unwrap(), &none), vec!["worker", "cache", "api"]);
+        assert_eq!(names(kuboard_list_view(Some("Restarts"), None).unwrap(), &none), vec!["worker", "cache", "api"]);
+        assert_eq!(names(kuboard_list_view(None, Some("crashloopbackoff, Error")).unwrap(), &none), vec!["worker"]);
+
+        let usage = HashMap::from([(("shop".to_string(), "cache".to_string()), (10, 1 << 30)), (("shop".to_string(), "api".to_string()), (250, 1 << 20))]);
+        assert_eq!(names(kuboard_list_view(Some("cpu"), None).unwrap(), &usage), vec!["api", "cache", "worker"]);
+        assert_eq!(names(kuboard_list_view(Some("memory"), None).unwrap(), &usage), vec!["cache", "api", "worker"]);
+
+        assert!(kuboard_list_view(Some("size"), None).is_err());
+    }
+}
@@ -0,0 +1,184 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Load Balancer Provisioning
+// Explains a LoadBalancer Service that stays Pending, from its cloud annotations and the events
+// the service controller or load balancer controller recorded on it
+
+use anyhow::Result;
+use k8s_openapi::api::core::v1::{Event, Service};
+use kube::api::ListParams;
+use kube::{Api, Client};
+use std::collections::BTreeMap;
+
+use crate::types::{LoadBalancerIssue, LoadBalancerStatus};
+
+// Annotation prefixes and load balancer classes by provider
+const PROVIDER_MARKERS: &[(&str, &str)] = &[
+    ("service.beta.kubernetes.io/aws-load-balancer-", "aws"),
+    ("service.k8s.aws/", "aws"),
+    ("service.beta.kubernetes.io/azure-", "azure"),
+    ("cloud.google.com/", "gcp"),
+    ("networking.gke.io/", "gcp"),
+    ("metallb.universe.tf/", "metallb"),
+    ("metallb.io/", "metallb"),
+    ("service.beta.kubernetes.io/oci-", "oci"),
+    ("oci.oraclecloud.com/", "oci"),
+    ("service.beta.kubernetes.io/do-loadbalancer-", "digitalocean"),
+];
+
+// Annotation values that make the load balancer internal-only
+const INTERNAL_MARKERS: &[(&str, &str)] = &[
+    ("service.beta.kubernetes.io/aws-load-balancer-internal", "true"),
+    ("service.beta.kubernetes.io/aws-load-balancer-scheme", "internal"),
+    ("service.beta.kubernetes.io/azure-load-balancer-internal", "true"),
+    ("networking.gke.io/load-balancer-type", "internal"),
+    ("cloud.google.com/load-balancer-type", "internal"),
+    ("service.beta.kubernetes.io/oci-load-balancer-internal", "true"),
+];
+
+// (category, message fragments, hint), checked in order against the lowercased event message
+const ISSUE_CATEGORIES: &[(&str, &[&str], &str)] = &[
+    ("quota", &["quota", "limitexceeded", "limit exceeded", "toomany"], "The cloud account has hit a load balancer or public IP quota; raise the quota or free unused load balancers"),
+    ("subnet", &["subnet"], "No suitable subnet was found; check the subnet annotations or the cluster-discovery tags on the VPC subnets"),
+    ("permission", &["unauthorized", "forbidden", "accessdenied", "access denied", "not authorized", "permission"], "The cloud controller's credentials lack permission to manage load balancers"),
+    ("address", &["no available ip", "ip address", "publicip", "already in use", "address is in use"], "The requested or pooled IP address isn't available; check loadBalancerIP and the address pool"),
+    ("certificate", &["certificate"], "The TLS certificate referenced by the annotations couldn't be used"),
+];
+
+pub fn kuboard_load_balancer_provider(service: &Service) -> Option<String> {
+    let class = service.spec.as_ref().and_then(|s| s.load_balancer_class.clone());
+    let annotations = service.metadata.annotations.clone().unwrap_or_default();
+    class.iter().chain(annotations.keys())
+        .find_map(|key| PROVIDER_MARKERS.iter().find(|(prefix, _)| key.starts_with(prefix)))
+        .map(|(_, provider)| provider.to_string())
+}
+
+pub fn kuboard_classify_load_balancer_issue(message: &str) -> (&'static str, Option<&'static str>) {
+    let message = message.to_lowercase();
+    ISSUE_CATEGORIES.iter()
+        .find(|(_, fragments, _)| fragments.iter().any(|f| message.contains(f)))
+        .map(|(category, _, hint)| (*category, Some(*hint)))
+        .unwrap_or(("unknown", None))
+}
+
+fn event_time(event: &Event) -> Option<String> {
+    event.last_timestamp.as_ref().map(|t| t.0.to_rfc3339())
+        .or_else(|| event.event_time.as_ref().map(|t| t.0.to_rfc3339()))
+}
+
+pub fn kuboard_load_balancer_status(service: &Service, events: &[Event]) -> LoadBalancerStatus {
+    let annotations = service.metadata.annotations.clone().unwrap_or_default();
+    let internal = INTERNAL_MARKERS.iter()
+        .any(|(key, value)| annotations.get(*key).is_some_and(|v| v.eq_ignore_ascii_case(value)));
+    let cloud_annotations: BTreeMap<String, String> = annotations.into_iter()
+        .filter(|(key, _)| PROVIDER_MARKERS.iter().any(|(prefix, _)| key.starts_with(prefix)))
+        .collect();
+    let addresses: Vec<String> = service.status.as_ref()
+        .and_then(|s| s.load_balancer.as_ref())
+        .and_then(|lb| lb.ingress.as_ref())
+        .map(|ingress| ingress.iter().filter_map(|i| i.hostname.clone().or_else(|| i.ip.clone())).collect())
+        .unwrap_or_default();
+
+    let mut events: Vec<&Event> = events.iter().collect();
+    events.sort_by_key(|e| event_time(e));
+    let issues: Vec<LoadBalancerIssue> = events.iter()
+        .filter(|e| e.type_.as_deref() == Some("Warning"))
+        .map(|e| {
+            let message = e.message.clone().unwrap_or_default();
+            let (category, hint) = kuboard_classify_load_balancer_issue(&message);
+            LoadBalancerIssue {
+                reason: e.reason.clone().unwrap_or_default(),
+                category: category.to_string(),
+                message,
+                hint: hint.map(str::to_string),
+                count: e.count.unwrap_or(1),
+                last_seen: event_time(e),
+            }
+        })
+        .collect();
+    let latest = events.last();
+
+    // The newest event decides between still working on it and stuck on an error
+    let state = if !addresses.is_empty() {
+        "provisioned"
+    } else if latest.is_some_and(|e| e.type_.as_deref() == Some("Warning")) {
+        "failed"
+    } else {
+        "provisioning"
+    };
+    let hint = (addresses.is_empty() && events.is_empty()).then(|| {
+        "Nothing has reported on this Service yet; the cluster may have no load balancer implementation (e.g. kind or bare metal without MetalLB)".to_string()
+    });
+
+    LoadBalancerStatus {
+        name: service.metadata.name.clone().unwrap_or_default(),
+        namespace: service.metadata.namespace.clone().unwrap_or_default(),
+        provider: kuboard_load_balancer_provider(service),
+        internal,
+        state: state.to_string(),
+        addresses,
+        annotations: cloud_annotations,
+        last_event: latest.map(|e| format!("{}: {}", e.reason.as_deref().unwrap_or_default(), e.message.as_deref().unwrap_or_default())),
+        issues,
+        hint,
+    }
+}
+
+pub async fn kuboard_inspect_load_balancer(client: &Client, name: &str, namespace: &str) -> Result<LoadBalancerStatus> {
+    let service = Api::<Service>::namespaced(client.clone(), namespace).get(name).await?;
+    let field_selector = format!("involvedObject.kind=Service,involvedObject.name={}", name);
+    let events = Api::<Event>::namespaced(client.clone(), namespace)
+        .list(&ListParams::default().fields(&field_selector))
+        .await?
+        .items;
+    Ok(kuboard_load_balancer_status(&service, &events))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn event(type_: &str, reason: &str, message: &str, at: &str) -> Event {
+        serde_json::from_value(json!({
+            "metadata": { "name": format!("shop.{}", reason) },
+            "involvedObject": { "kind": "Service", "name": "shop" },
+            "type": type_,
+            "reason": reason,
+            "message": message,
+            "lastTimestamp": at,
+            "count": 3,
+        })).unwrap()
+    }
+
+    #[test]
+    fn test_pending_load_balancer_status() {
+        let service: Service = serde_json::from_value(json!({
+            "metadata": { "name": "shop", "namespace": "web", "annotations": {
+                "service.beta.kubernetes.io/aws-load-balancer-scheme": "internal",
+                "service.beta.kubernetes.io/aws-load-balancer-subnets": "subnet-123",
+                "team": "payments",
+            } },
+            "spec": { "type": "LoadBalancer" },
+        })).unwrap();
+        let events = vec![
+            event("Warning", "SyncLoadBalancerFailed", "Error syncing load balancer: failed to ensure load balancer: could not find any suitable subnets", "2025-03-01T10:05:00Z"),
+            event("Normal", "EnsuringLoadBalancer", "Ensuring load balancer", "2025-03-01T10:00:00Z"),
+        ];
+
+        let status = kuboard_load_balancer_status(&service, &events);
+
+        assert_eq!(status.provider.as_deref(), Some("aws"));
+        assert!(status.internal);
+        assert_eq!(status.state, "failed");
+        assert_eq!(status.annotations.len(), 2);
+        assert_eq!(status.issues.len(), 1);
+        assert_eq!(status.issues[0].category, "subnet");
+        assert!(status.last_event.unwrap().starts_with("SyncLoadBalancerFailed"));
+
+        assert_eq!(kuboard_classify_load_balancer_issue("TooManyLoadBalancers: Exceeded quota of account").0, "quota");
+        assert_eq!(kuboard_load_balancer_status(&service, &[]).state, "provisioning");
+        assert!(kuboard_load_balancer_status(&service, &[]).hint.is_some());
+    }
+}
@@ -0,0 +1,184 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Manifest Search
+// Finds the fields of manifests that match a key path and/or a value, e.g. every workload setting
+// a given env var or running a given image, for config audits across a namespace
+
+use anyhow::{anyhow, Result};
+use k8s_openapi::NamespaceResourceScope;
+use kube::api::ListParams;
+use kube::{Client, Resource};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt::Debug;
+
+use crate::kubernetes::namespace_access::kuboard_list_scoped;
+use crate::types::{ManifestMatch, ManifestSearchHit, NamespaceFilter};
+
+// Searched when no kinds are given
+pub const DEFAULT_SEARCH_KINDS: [&str; 5] = ["Deployment", "StatefulSet", "DaemonSet", "CronJob", "Job"];
+
+// Every kind a search can cover
+pub const SEARCHABLE_KINDS: [&str; 8] = ["Deployment", "StatefulSet", "DaemonSet", "CronJob", "Job", "Pod", "Service", "ConfigMap"];
+
+const LAST_APPLIED_ANNOTATION: &str = "kubectl.kubernetes.io/last-applied-configuration";
+
+// A kind name, case-insensitive, into its canonical spelling
+pub fn kuboard_search_kind(value: &str) -> Result<&'static str> {
+    SEARCHABLE_KINDS.iter()
+        .find(|kind| kind.eq_ignore_ascii_case(value.trim()))
+        .copied()
+        .ok_or_else(|| anyhow!("Can't search {}; expected one of {}", value, SEARCHABLE_KINDS.join(", ")))
+}
+
+pub struct ManifestQuery {
+    // Dotted key path matched against the end of a field's path, list indices ignored
+    key: Vec<String>,
+    // Lowercased, matched as a substring of scalar values
+    value: Option<String>,
+}
+
+impl ManifestQuery {
+    fn key_matches(&self, segments: &[&str]) -> bool {
+        segments.len() >= self.key.len() && segments[segments.len() - self.key.len()..].iter().eq(self.key.iter())
+    }
+}
+
+// A field matches when its path ends with `key` (e.g. `image` or `env.name`) and its value
+// contains `value`, case-insensitively
+pub fn kuboard_manifest_query(key: Option<&str>, value: Option<&str>) -> Result<ManifestQuery> {
+    let key = key.map(str::trim).filter(|key| !key.is_empty());
+    let value = value.filter(|value| !value.is_empty());
+    if key.is_none() && value.is_none() {
+        return Err(anyhow!("A key or a value to search for is required"));
+    }
+    Ok(ManifestQuery {
+        key: key.map(|key| key.split('.').map(str::to_string).collect()).unwrap_or_default(),
+        value: value.map(str::to_lowercase),
+    })
+}
+
+// managedFields and the last-applied annotation are skipped, they repeat the manifest
+pub fn kuboard_search_manifest(manifest: &Value, query: &ManifestQuery) -> Vec<ManifestMatch> {
+    let mut matches = Vec::new();
+    walk(manifest, query, &mut Vec::new(), String::new(), &mut matches);
+    matches
+}
+
+// One hit per object with at least one matching field
+pub fn kuboard_search_objects<K>(objects: &[K], query: &ManifestQuery) -> Result<Vec<ManifestSearchHit>>
+where
+    K: Resource<DynamicType = ()> + Serialize,
+{
+    let mut hits = Vec::new();
+    for object in objects {
+        let matches = kuboard_search_manifest(&serde_json::to_value(object)?, query);
+        if !matches.is_empty() {
+            hits.push(ManifestSearchHit {
+                kind: K::kind(&()).to_string(),
+                name: object.meta().name.clone().unwrap_or_default(),
+                namespace: object.meta().namespace.clone(),
+                matches,
+            });
+        }
+    }
+    Ok(hits)
+}
+
+// Lists one kind in `namespace`, or every namespace the filter allows, and searches each object
+pub async fn kuboard_search_scoped<K>(
+    client: &Client,
+    filter: &NamespaceFilter,
+    namespace: Option<&str>,
+    params: &ListParams,
+    query: &ManifestQuery,
+) -> Result<Vec<ManifestSearchHit>>
+where
+    K: Resource<Scope = NamespaceResourceScope, DynamicType = ()> + Clone + DeserializeOwned + Debug + Serialize,
+{
+    let objects = kuboard_list_scoped::<K>(client, filter, namespace, params).await
+        .map_err(|e| anyhow!("Failed to list {}: {}", K::plural(&()), e))?;
+    kuboard_search_objects(&objects, query)
+}
+
+fn walk<'v>(node: &'v Value, query: &ManifestQuery, segments: &mut Vec<&'v str>, path: String, matches: &mut Vec<ManifestMatch>) {
+    let scalar = match node {
+        Value::Object(fields) => {
+            for (name, child) in fields {
+                if (path == "metadata" && name == "managedFields") || (path == "metadata.annotations" && name == LAST_APPLIED_ANNOTATION) {
+                    continue;
+                }
+                let child_path = match (path.is_empty(), name.contains(['.', '/'])) {
+                    (true, _) => name.clone(),
+                    (false, true) => format!("{}[\"{}\"]", path, name),
+                    (false, false) => format!("{}.{}", path, name),
+                };
+                segments.push(name);
+                walk(child, query, segments, child_path, matches);
+                segments.pop();
+            }
+            None
+        }
+        Value::Array(items) => {
+            for (index, child) in items.iter().enumerate() {
+                walk(child, query, segments, format!("{}[{}]", path, index), matches);
+            }
+            None
+        }
+        Value::String(text) => Some(text.clone()),
+        Value::Null => None,
+        other => Some(other.to_string()),
+    };
+
+    let key_matches = query.key_matches(segments);
+    let found = match (&query.value, scalar) {
+        (Some(wanted), Some(scalar)) => key_matches.then_some(scalar).filter(|scalar| scalar.to_lowercase().contains(wanted.as_str())),
+        (Some(_), None) => None,
+        // A key alone also matches whole objects and lists, e.g. `livenessProbe`
+        (None, scalar) if key_matches && !path.ends_with(']') => Some(scalar.unwrap_or_else(|| node.to_string())),
+        (None, _) => None,
+    };
+    if let Some(value) = found {
+        matches.push(ManifestMatch { path, value });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_search_manifest() {
+        let deployment = json!({
+            "metadata": {
+                "name": "api",
+                "labels": { "app.kubernetes.io/name": "api" },
+                "annotations": { "kubectl.kubernetes.io/last-applied-configuration": "{\"image\":\"nginx:1.25\"}" },
+                "managedFields": [{ "manager": "nginx" }],
+            },
+            "spec": { "template": { "spec": { "containers": [
+                { "name": "sidecar", "image": "envoy:1.30" },
+                { "name": "api", "image": "nginx:1.25", "env": [{ "name": "LOG_LEVEL", "value": "debug" }, { "name": "PORT", "value": "8080" }] },
+            ] } } },
+        });
+        let search = |key: Option<&str>, value: Option<&str>| -> Vec<(String, String)> {
+            let query = kuboard_manifest_query(key, value).unwrap();
+            kuboard_search_manifest(&deployment, &query).into_iter().map(|m| (m.path, m.value)).collect()
+        };
+
+        assert_eq!(search(Some("image"), Some("NGINX")), vec![("spec.template.spec.containers[1].image".to_string(), "nginx:1.25".to_string())]);
+        assert_eq!(search(Some("env.name"), Some("log_level")), vec![("spec.template.spec.containers[1].env[0].name".to_string(), "LOG_LEVEL".to_string())]);
+        assert_eq!(search(None, Some("8080")), vec![("spec.template.spec.containers[1].env[1].value".to_string(), "8080".to_string())]);
+        assert_eq!(search(Some("labels"), None)[0], ("metadata.labels".to_string(), "{\"app.kubernetes.io/name\":\"api\"}".to_string()));
+        assert!(search(None, Some("api")).iter().any(|(path, _)| path == "metadata.labels[\"app.kubernetes.io/name\"]"));
+        assert_eq!(search(Some("containers.env.name"), Some("PORT")).len(), 1);
+        assert!(kuboard_manifest_query(Some(" "), Some("")).is_err());
+
+        assert_eq!(kuboard_search_kind("configmap").unwrap(), "ConfigMap");
+        assert_eq!(kuboard_search_kind(" Deployment ").unwrap(), "Deployment");
+        assert!(kuboard_search_kind("Secret").is_err());
+    }
+}
@@ -0,0 +1,391 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Manifest Cleaning
+// Strips server-populated fields so a live object can be recreated elsewhere
+
+use anyhow::{anyhow, Result};
+use kube::api::{DynamicObject, GroupVersionKind};
+use kube::discovery::{pinned_kind, Scope};
+use kube::{Api, Client};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::types::ManifestFormat;
+
+// Metadata the API server assigns; none of it can be submitted on create
+const SERVER_METADATA_FIELDS: &[&str] = &[
+    "uid",
+    "resourceVersion",
+    "creationTimestamp",
+    "deletionTimestamp",
+    "deletionGracePeriodSeconds",
+    "generation",
+    "managedFields",
+    "selfLink",
+    "ownerReferences",
+];
+
+// Annotations written by controllers and kubectl rather than by the resource's author
+const SERVER_ANNOTATIONS: &[&str] = &[
+    "kubectl.kubernetes.io/last-applied-configuration",
+    "deployment.kubernetes.io/revision",
+];
+
+// Pod template labels the job controller adds to tie pods to their Job
+const JOB_CONTROLLER_LABELS: &[&str] = &[
+    "controller-uid",
+    "job-name",
+    "batch.kubernetes.io/controller-uid",
+    "batch.kubernetes.io/job-name",
+];
+
+fn remove_fields(object: Option<&mut Value>, fields: &[&str]) {
+    if let Some(Value::Object(map)) = object {
+        for field in fields {
+            map.remove(*field);
+        }
+    }
+}
+
+fn remove_if_empty(object: Option<&mut Value>, field: &str) {
+    if let Some(Value::Object(map)) = object {
+        let empty = match map.get(field) {
+            Some(Value::Object(inner)) => inner.is_empty(),
+            Some(Value::Array(inner)) => inner.is_empty(),
+            Some(Value::Null) => true,
+            _ => false,
+        };
+        if empty {
+            map.remove(field);
+        }
+    }
+}
+
+// Removes status, server metadata and cluster-allocated addresses from a manifest
+pub fn kuboard_clean_manifest(manifest: &mut Value) {
+    if let Value::Object(map) = manifest {
+        map.remove("status");
+    }
+
+    remove_fields(manifest.get_mut("metadata"), SERVER_METADATA_FIELDS);
+    remove_fields(manifest.pointer_mut("/metadata/annotations"), SERVER_ANNOTATIONS);
+    remove_if_empty(manifest.get_mut("metadata"), "annotations");
+
+    if manifest.get("kind").and_then(Value::as_str) == Some("Service") {
+        // Allocated per cluster; copying them would conflict with the source service. "None" is no
+        // allocation but what makes a service headless, so it stays
+        let headless = manifest.pointer("/spec/clusterIP").and_then(Value::as_str) == Some("None");
+        if !headless {
+            remove_fields(manifest.get_mut("spec"), &["clusterIP", "clusterIPs"]);
+        }
+        remove_fields(manifest.get_mut("spec"), &["healthCheckNodePort"]);
+        if let Some(Value::Array(ports)) = manifest.pointer_mut("/spec/ports") {
+            for port in ports {
+                remove_fields(Some(port), &["nodePort"]);
+            }
+        }
+    }
+}
+
+// Removes a field only when it still holds the value the API server would default it to
+fn remove_defaults(object: Option<&mut Value>, defaults: &[(&str, Value)]) {
+    if let Some(Value::Object(map)) = object {
+        for (field, default) in defaults {
+            if map.get(*field) == Some(default) {
+                map.remove(*field);
+            }
+        }
+    }
+}
+
+fn strip_pod_spec_defaults(pod_spec: Option<&mut Value>) {
+    let Some(pod_spec) = pod_spec else { return };
+    remove_defaults(Some(pod_spec), &[
+        ("dnsPolicy", json!("ClusterFirst")),
+        ("restartPolicy", json!("Always")),
+        ("schedulerName", json!("default-scheduler")),
+        ("securityContext", json!({})),
+        ("terminationGracePeriodSeconds", json!(30)),
+    ]);
+    for pointer in ["/containers", "/initContainers"] {
+        if let Some(Value::Array(containers)) = pod_spec.pointer_mut(pointer) {
+            for container in containers {
+                remove_defaults(Some(container), &[
+                    ("terminationMessagePath", json!("/dev/termination-log")),
+                    ("terminationMessagePolicy", json!("File")),
+                    ("resources", json!({})),
+                ]);
+                if let Some(Value::Array(ports)) = container.get_mut("ports") {
+                    for port in ports {
+                        remove_defaults(Some(port), &[("protocol", json!("TCP"))]);
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Removes fields the API server fills in with defaults, so exports only carry what the author set
+pub fn kuboard_strip_default_fields(manifest: &mut Value) {
+    let kind = manifest.get("kind").and_then(Value::as_str).unwrap_or_default().to_string();
+    match kind.as_str() {
+        "Deployment" | "StatefulSet" | "DaemonSet" | "ReplicaSet" | "Job" => {
+            remove_defaults(manifest.get_mut("spec"), &[
+                ("progressDeadlineSeconds", json!(600)),
+                ("revisionHistoryLimit", json!(10)),
+                ("podManagementPolicy", json!("OrderedReady")),
+                ("strategy", json!({ "type": "RollingUpdate", "rollingUpdate": { "maxSurge": "25%", "maxUnavailable": "25%" } })),
+                ("updateStrategy", json!({ "type": "RollingUpdate", "rollingUpdate": { "partition": 0 } })),
+                ("updateStrategy", json!({ "type": "RollingUpdate", "rollingUpdate": { "maxSurge": 0, "maxUnavailable": 1 } })),
+                ("backoffLimit", json!(6)),
+                ("completionMode", json!("NonIndexed")),
+                ("suspend", json!(false)),
+            ]);
+            if kind == "Job" {
+                // The job controller generates the selector and matching pod labels
+                remove_fields(manifest.get_mut("spec"), &["selector"]);
+                remove_fields(manifest.pointer_mut("/spec/template/metadata/labels"), JOB_CONTROLLER_LABELS);
+                remove_if_empty(manifest.pointer_mut("/spec/template/metadata"), "labels");
+            }
+            // creationTimestamp: null is serialised into every pod template
+            remove_if_empty(manifest.pointer_mut("/spec/template/metadata"), "creationTimestamp");
+            strip_pod_spec_defaults(manifest.pointer_mut("/spec/template/spec"));
+        }
+        "CronJob" => {
+            remove_defaults(manifest.get_mut("spec"), &[
+                ("concurrencyPolicy", json!("Allow")),
+                ("suspend", json!(false)),
+                ("successfulJobsHistoryLimit", json!(3)),
+                ("failedJobsHistoryLimit", json!(1)),
+            ]);
+            remove_if_empty(manifest.pointer_mut("/spec/jobTemplate/metadata"), "creationTimestamp");
+            remove_if_empty(manifest.pointer_mut("/spec/jobTemplate/spec/template/metadata"), "creationTimestamp");
+            strip_pod_spec_defaults(manifest.pointer_mut("/spec/jobTemplate/spec/template/spec"));
+        }
+        "Pod" => strip_pod_spec_defaults(manifest.get_mut("spec")),
+        "Service" => {
+            remove_defaults(manifest.get_mut("spec"), &[
+                ("type", json!("ClusterIP")),
+                ("sessionAffinity", json!("None")),
+                ("ipFamilies", json!(["IPv4"])),
+                ("ipFamilyPolicy", json!("SingleStack")),
+                ("internalTrafficPolicy", json!("Cluster")),
+            ]);
+            if let Some(Value::Array(ports)) = manifest.pointer_mut("/spec/ports") {
+                for port in ports {
+                    remove_defaults(Some(port), &[("protocol", json!("TCP"))]);
+                    // targetPort defaults to the port itself
+                    if port.get("targetPort").is_some() && port.get("targetPort") == port.get("port") {
+                        remove_fields(Some(port), &["targetPort"]);
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+    remove_if_empty(manifest.get_mut("metadata"), "creationTimestamp");
+}
+
+// API group/version for the kinds Kuboard can export
+fn api_version_for_kind(kind: &str) -> Option<&'static str> {
+    match kind {
+        "Pod" | "Service" | "ConfigMap" | "Secret" | "ServiceAccount" | "PersistentVolumeClaim" | "Namespace" => Some("v1"),
+        "Deployment" | "StatefulSet" | "DaemonSet" | "ReplicaSet" => Some("apps/v1"),
+        "Job" | "CronJob" => Some("batch/v1"),
+        "Ingress" | "NetworkPolicy" => Some("networking.k8s.io/v1"),
+        "HorizontalPodAutoscaler" => Some("autoscaling/v2"),
+        _ => None,
+    }
+}
+
+// Fetches a live object of one of the kinds Kuboard knows the API version of
+pub async fn kuboard_get_live_object(client: &Client, kind: &str, name: &str, namespace: &str) -> Result<DynamicObject> {
+    let api_version = api_version_for_kind(kind).ok_or_else(|| anyhow!("Exporting {} is not supported", kind))?;
+    let (group, version) = api_version.rsplit_once('/').unwrap_or(("", api_version));
+    let (resource, capabilities) = pinned_kind(client, &GroupVersionKind::gvk(group, version, kind)).await?;
+
+    let api: Api<DynamicObject> = if capabilities.scope == Scope::Namespaced {
+        Api::namespaced_with(client.clone(), namespace, &resource)
+    } else {
+        Api::all_with(client.clone(), &resource)
+    };
+    Ok(api.get(name).await?)
+}
+
+// Fetches a live object and returns a manifest suitable for committing to a GitOps repository
+pub async fn kuboard_export_manifest(client: &Client, kind: &str, name: &str, namespace: &str) -> Result<Value> {
+    let object = kuboard_get_live_object(client, kind, name, namespace).await?;
+
+    let mut manifest = serde_json::to_value(&object)?;
+    kuboard_clean_manifest(&mut manifest);
+    kuboard_strip_default_fields(&mut manifest);
+    Ok(manifest)
+}
+
+// The text users paste into manifests and kubectl: YAML by default, pretty JSON on request
+pub fn kuboard_render_manifest<T: Serialize>(object: &T, format: ManifestFormat) -> Result<String> {
+    Ok(match format {
+        ManifestFormat::Yaml => serde_yaml::to_string(object)?,
+        ManifestFormat::Json => serde_json::to_string_pretty(object)?,
+    })
+}
+
+// Reads a manifest in either format; JSON keeps serde_json's error messages
+pub fn kuboard_parse_manifest<T: DeserializeOwned>(content: &str) -> Result<T> {
+    if content.trim_start().starts_with('{') {
+        Ok(serde_json::from_str(content)?)
+    } else {
+        Ok(serde_yaml::from_str(content)?)
+    }
+}
+
+// Replaces the tag (and any digest) of an image reference, keeping a registry port intact
+pub fn kuboard_rewrite_image_tag(image: &str, tag: &str) -> String {
+    let without_digest = image.split('@').next().unwrap_or(image);
+    let name_start = without_digest.rfind('/').map(|i| i + 1).unwrap_or(0);
+    let repository = match without_digest[name_start..].rfind(':') {
+        Some(colon) => &without_digest[..name_start + colon],
+        None => without_digest,
+    };
+    format!("{}:{}", repository, tag)
+}
+
+// Rewrites the image of every container and init container in a pod template
+pub fn kuboard_rewrite_pod_template_images(manifest: &mut Value, tag: &str) {
+    for pointer in ["/spec/template/spec/containers", "/spec/template/spec/initContainers"] {
+        if let Some(Value::Array(containers)) = manifest.pointer_mut(pointer) {
+            for container in containers {
+                if let Some(image) = container.get("image").and_then(Value::as_str) {
+                    let rewritten = kuboard_rewrite_image_tag(image, tag);
+                    container["image"] = Value::String(rewritten);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::core::v1::ConfigMap;
+
+    #[test]
+    fn test_render_and_parse_manifest() {
+        let config_map: ConfigMap = serde_json::from_value(json!({
+            "metadata": { "name": "settings", "namespace": "default" },
+            "data": { "replicas": "3", "debug": "true" },
+        })).unwrap();
+
+        let yaml = kuboard_render_manifest(&config_map, ManifestFormat::Yaml).unwrap();
+        assert!(yaml.starts_with("apiVersion: v1\n"));
+        assert!(yaml.contains("kind: ConfigMap\n"));
+        // Strings that look like numbers or booleans stay quoted so they survive a round trip
+        assert!(yaml.contains("replicas: '3'"));
+        assert_eq!(kuboard_parse_manifest::<ConfigMap>(&yaml).unwrap(), config_map);
+
+        let json = kuboard_render_manifest(&config_map, ManifestFormat::Json).unwrap();
+        assert!(json.starts_with("{\n  \"apiVersion\": \"v1\""));
+        assert_eq!(kuboard_parse_manifest::<ConfigMap>(&json).unwrap(), config_map);
+        assert!(kuboard_parse_manifest::<Value>("kind: [").is_err());
+    }
+
+    #[test]
+    fn test_clean_manifest_strips_server_fields() {
+        let mut service = json!({
+            "apiVersion": "v1",
+            "kind": "Service",
+            "metadata": {
+                "name": "web",
+                "namespace": "default",
+                "uid": "1234",
+                "resourceVersion": "42",
+                "managedFields": [{}],
+                "annotations": { "kubectl.kubernetes.io/last-applied-configuration": "{}" },
+                "labels": { "app": "web" },
+            },
+            "spec": {
+                "clusterIP": "10.0.0.1",
+                "clusterIPs": ["10.0.0.1"],
+                "ports": [{ "port": 80, "nodePort": 30080 }],
+            },
+            "status": { "loadBalancer": {} },
+        });
+
+        kuboard_clean_manifest(&mut service);
+
+        assert_eq!(service, json!({
+            "apiVersion": "v1",
+            "kind": "Service",
+            "metadata": { "name": "web", "namespace": "default", "labels": { "app": "web" } },
+            "spec": { "ports": [{ "port": 80 }] },
+        }));
+    }
+
+    #[test]
+    fn test_clean_manifest_keeps_headless_services() {
+        let mut service = json!({
+            "apiVersion": "v1",
+            "kind": "Service",
+            "metadata": { "name": "db", "uid": "1234" },
+            "spec": { "clusterIP": "None", "clusterIPs": ["None"], "ports": [{ "port": 5432 }] },
+        });
+
+        kuboard_clean_manifest(&mut service);
+
+        assert_eq!(service["spec"]["clusterIP"], "None");
+        assert_eq!(service["spec"]["clusterIPs"], json!(["None"]));
+        assert!(service["metadata"].get("uid").is_none());
+    }
+
+    #[test]
+    fn test_strip_default_fields_keeps_authored_values() {
+        let mut deployment = json!({
+            "apiVersion": "apps/v1",
+            "kind": "Deployment",
+            "metadata": { "name": "web", "creationTimestamp": null },
+            "spec": {
+                "replicas": 2,
+                "revisionHistoryLimit": 10,
+                "progressDeadlineSeconds": 120,
+                "template": {
+                    "metadata": { "creationTimestamp": null, "labels": { "app": "web" } },
+                    "spec": {
+                        "dnsPolicy": "ClusterFirst",
+                        "restartPolicy": "Always",
+                        "containers": [{
+                            "name": "web",
+                            "image": "nginx",
+                            "terminationMessagePath": "/dev/termination-log",
+                            "ports": [{ "containerPort": 80, "protocol": "TCP" }, { "containerPort": 53, "protocol": "UDP" }],
+                        }],
+                    },
+                },
+            },
+        });
+
+        kuboard_strip_default_fields(&mut deployment);
+
+        assert_eq!(deployment["metadata"], json!({ "name": "web" }));
+        assert_eq!(deployment["spec"]["progressDeadlineSeconds"], 120);
+        assert!(deployment["spec"].get("revisionHistoryLimit").is_none());
+        assert_eq!(deployment["spec"]["template"]["metadata"], json!({ "labels": { "app": "web" } }));
+        assert_eq!(deployment["spec"]["template"]["spec"], json!({
+            "containers": [{
+                "name": "web",
+                "image": "nginx",
+                "ports": [{ "containerPort": 80 }, { "containerPort": 53, "protocol": "UDP" }],
+            }],
+        }));
+    }
+
+    #[test]
+    fn test_rewrite_image_tag() {
+        assert_eq!(kuboard_rewrite_image_tag("nginx", "1.27"), "nginx:1.27");
+        assert_eq!(kuboard_rewrite_image_tag("nginx:1.25", "1.27"), "nginx:1.27");
+        assert_eq!(kuboard_rewrite_image_tag("registry:5000/team/api:v1", "pr-12"), "registry:5000/team/api:pr-12");
+        assert_eq!(kuboard_rewrite_image_tag("registry:5000/team/api", "pr-12"), "registry:5000/team/api:pr-12");
+        assert_eq!(kuboard_rewrite_image_tag("ghcr.io/a/b:v1@sha256:abc", "v2"), "ghcr.io/a/b:v2");
+    }
+}
@@ -0,0 +1,228 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Metrics Server Installation
+// Applies the upstream metrics-server manifests, pinned to one release, for clusters that ship without it
+
+use anyhow::Result;
+use kube::Client;
+use serde_json::{json, Value};
+use std::time::Duration;
+
+use crate::kubernetes::apply::kuboard_apply_object;
+use crate::metrics::kuboard_check_metrics_server_availability;
+use crate::types::MetricsServerInstallResult;
+
+pub const METRICS_SERVER_VERSION: &str = "v0.7.2";
+pub const METRICS_SERVER_READY_TIMEOUT: Duration = Duration::from_secs(120);
+const METRICS_SERVER_POLL_INTERVAL: Duration = Duration::from_secs(5);
+const METRICS_SERVER_NAMESPACE: &str = "kube-system";
+
+// The objects from the release's components.yaml, in apply order. Dev clusters such as kind or
+// minikube serve kubelet certificates the metrics-server can't verify, hence the insecure toggle
+pub fn kuboard_metrics_server_manifests(insecure_kubelet_tls: bool) -> Vec<Value> {
+    let labels = json!({ "k8s-app": "metrics-server" });
+    let service_account = json!([{ "kind": "ServiceAccount", "name": "metrics-server", "namespace": METRICS_SERVER_NAMESPACE }]);
+
+    let mut args = vec![
+        "--cert-dir=/tmp".to_string(),
+        "--secure-port=10250".to_string(),
+        "--kubelet-preferred-address-types=InternalIP,ExternalIP,Hostname".to_string(),
+        "--kubelet-use-node-status-port".to_string(),
+        "--metric-resolution=15s".to_string(),
+    ];
+    if insecure_kubelet_tls {
+        args.push("--kubelet-insecure-tls".to_string());
+    }
+
+    vec![
+        json!({
+            "apiVersion": "v1",
+            "kind": "ServiceAccount",
+            "metadata": { "name": "metrics-server", "namespace": METRICS_SERVER_NAMESPACE, "labels": labels },
+        }),
+        json!({
+            "apiVersion": "rbac.authorization.k8s.io/v1",
+            "kind": "ClusterRole",
+            "metadata": {
+                "name": "system:aggregated-metrics-reader",
+                "labels": {
+                    "k8s-app": "metrics-server",
+                    "rbac.authorization.k8s.io/aggregate-to-admin": "true",
+                    "rbac.authorization.k8s.io/aggregate-to-edit": "true",
+                    "rbac.authorization.k8s.io/aggregate-to-view": "true",
+                },
+            },
+            "rules": [{ "apiGroups": ["metrics.k8s.io"], "resources": ["pods", "nodes"], "verbs": ["get", "list", "watch"] }],
+        }),
+        json!({
+            "apiVersion": "rbac.authorization.k8s.io/v1",
+            "kind": "ClusterRole",
+            "metadata": { "name": "system:metrics-server", "labels": labels },
+            "rules": [
+                { "apiGroups": [""], "resources": ["nodes/metrics"], "verbs": ["get"] },
+                { "apiGroups": [""], "resources": ["pods", "nodes"], "verbs": ["get", "list", "watch"] },
+            ],
+        }),
+        json!({
+            "apiVersion": "rbac.authorization.k8s.io/v1",
+            "kind": "RoleBinding",
+            "metadata": { "name": "metrics-server-auth-reader", "namespace": METRICS_SERVER_NAMESPACE, "labels": labels },
+            "roleRef": { "apiGroup": "rbac.authorization.k8s.io", "kind": "Role", "name": "extension-apiserver-authentication-reader" },
+            "subjects": service_account,
+        }),
+        json!({
+            "apiVersion": "rbac.authorization.k8s.io/v1",
+            "kind": "ClusterRoleBinding",
+            "metadata": { "name": "metrics-server:system:auth-delegator", "labels": labels },
+            "roleRef": { "apiGroup": "rbac.authorization.k8s.io", "kind": "ClusterRole", "name": "system:auth-delegator" },
+            "subjects": service_account,
+        }),
+        json!({
+            "apiVersion": "rbac.authorization.k8s.io/v1",
+            "kind": "ClusterRoleBinding",
+            "metadata": { "name": "system:metrics-server", "labels": labels },
+            "roleRef": { "apiGroup": "rbac.authorization.k8s.io", "kind": "ClusterRole", "name": "system:metrics-server" },
+            "subjects": service_account,
+        }),
+        json!({
+            "apiVersion": "v1",
+            "kind": "Service",
+            "metadata": { "name": "metrics-server", "namespace": METRICS_SERVER_NAMESPACE, "labels": labels },
+            "spec": {
+                "ports": [{ "name": "https", "port": 443, "protocol": "TCP", "targetPort": "https" }],
+                "selector": labels,
+            },
+        }),
+        json!({
+            "apiVersion": "apps/v1",
+            "kind": "Deployment",
+            "metadata": { "name": "metrics-server", "namespace": METRICS_SERVER_NAMESPACE, "labels": labels },
+            "spec": {
+                "selector": { "matchLabels": labels },
+                "strategy": { "rollingUpdate": { "maxUnavailable": 0 } },
+                "template": {
+                    "metadata": { "labels": labels },
+                    "spec": {
+                        "containers": [{
+                            "name": "metrics-server",
+                            "image": format!("registry.k8s.io/metrics-server/metrics-server:{}", METRICS_SERVER_VERSION),
+                            "imagePullPolicy": "IfNotPresent",
+                            "args": args,
+                            "ports": [{ "name": "https", "containerPort": 10250, "protocol": "TCP" }],
+                            "livenessProbe": {
+                                "httpGet": { "path": "/livez", "port": "https", "scheme": "HTTPS" },
+                                "periodSeconds": 10,
+                                "failureThreshold": 3,
+                            },
+                            "readinessProbe": {
+                                "httpGet": { "path": "/readyz", "port": "https", "scheme": "HTTPS" },
+                                "initialDelaySeconds": 20,
+                                "periodSeconds": 10,
+                                "failureThreshold": 3,
+                            },
+                            "resources": { "requests": { "cpu": "100m", "memory": "200Mi" } },
+                            "securityContext": {
+                                "allowPrivilegeEscalation": false,
+                                "capabilities": { "drop": ["ALL"] },
+                                "readOnlyRootFilesystem": true,
+                                "runAsNonRoot": true,
+                                "runAsUser": 1000,
+                                "seccompProfile": { "type": "RuntimeDefault" },
+                            },
+                            "volumeMounts": [{ "name": "tmp-dir", "mountPath": "/tmp" }],
+                        }],
+                        "nodeSelector": { "kubernetes.io/os": "linux" },
+                        "priorityClassName": "system-cluster-critical",
+                        "serviceAccountName": "metrics-server",
+                        "volumes": [{ "name": "tmp-dir", "emptyDir": {} }],
+                    },
+                },
+            },
+        }),
+        json!({
+            "apiVersion": "apiregistration.k8s.io/v1",
+            "kind": "APIService",
+            "metadata": { "name": "v1beta1.metrics.k8s.io", "labels": labels },
+            "spec": {
+                "group": "metrics.k8s.io",
+                "groupPriorityMinimum": 100,
+                "insecureSkipTLSVerify": true,
+                "service": { "name": "metrics-server", "namespace": METRICS_SERVER_NAMESPACE },
+                "version": "v1beta1",
+                "versionPriority": 100,
+            },
+        }),
+    ]
+}
+
+async fn wait_for_metrics_api(client: &Client) -> bool {
+    let poll = async {
+        while !kuboard_check_metrics_server_availability(client).await.unwrap_or(false) {
+            tokio::time::sleep(METRICS_SERVER_POLL_INTERVAL).await;
+        }
+    };
+    tokio::time::timeout(METRICS_SERVER_READY_TIMEOUT, poll).await.is_ok()
+}
+
+// Leaves a cluster that already serves the metrics API alone
+pub async fn kuboard_deploy_metrics_server(client: &Client, insecure_kubelet_tls: bool) -> Result<MetricsServerInstallResult> {
+    if kuboard_check_metrics_server_availability(client).await? {
+        return Ok(MetricsServerInstallResult {
+            version: METRICS_SERVER_VERSION.to_string(),
+            already_available: true,
+            applied: Vec::new(),
+            available: true,
+            message: Some("The metrics API is already available; nothing was installed".to_string()),
+        });
+    }
+
+    let mut applied = Vec::new();
+    for manifest in kuboard_metrics_server_manifests(insecure_kubelet_tls) {
+        applied.push(kuboard_apply_object(client, &manifest, METRICS_SERVER_NAMESPACE).await?);
+    }
+
+    let available = wait_for_metrics_api(client).await;
+    let message = (!available).then(|| {
+        let mut message = format!(
+            "metrics-server was installed but the metrics API didn't respond within {}s; check the metrics-server pod in {}",
+            METRICS_SERVER_READY_TIMEOUT.as_secs(),
+            METRICS_SERVER_NAMESPACE,
+        );
+        if !insecure_kubelet_tls {
+            message.push_str(" (on dev clusters with self-signed kubelet certificates, reinstall with insecure kubelet TLS)");
+        }
+        message
+    });
+
+    Ok(MetricsServerInstallResult {
+        version: METRICS_SERVER_VERSION.to_string(),
+        already_available: false,
+        applied,
+        available,
+        message,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn container_args(manifests: &[Value]) -> Vec<String> {
+        let deployment = manifests.iter().find(|m| m["kind"] == "Deployment").unwrap();
+        serde_json::from_value(deployment.pointer("/spec/template/spec/containers/0/args").cloned().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_metrics_server_manifests() {
+        let manifests = kuboard_metrics_server_manifests(false);
+        assert_eq!(manifests.len(), 9);
+        assert_eq!(manifests.last().unwrap()["kind"], "APIService");
+        assert!(!container_args(&manifests).contains(&"--kubelet-insecure-tls".to_string()));
+
+        let insecure = kuboard_metrics_server_manifests(true);
+        assert!(container_args(&insecure).contains(&"--kubelet-insecure-tls".to_string()));
+        let image = insecure[7].pointer("/spec/template/spec/containers/0/image").and_then(Value::as_str).unwrap();
+        assert!(image.ends_with(METRICS_SERVER_VERSION));
+    }
+}
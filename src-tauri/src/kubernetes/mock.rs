@@ -0,0 +1,1220 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Mock Cluster Backend
+// In-memory API server so the UI and command handlers can run without a real cluster
+
+use http::{Method, Request, Response, StatusCode};
+use kube::client::Body;
+use kube::Client;
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::convert::Infallible;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+use tokio::sync::Notify;
+use tracing::debug;
+
+use crate::quantity::kuboard_parse_bytes;
+
+// Context name that routes to the mock cluster instead of a kubeconfig entry
+pub const MOCK_CONTEXT_NAME: &str = "kuboard-mock";
+
+// Older watch events are dropped so the log stays bounded
+const MAX_WATCH_EVENTS: usize = 1000;
+const DEFAULT_WATCH_TIMEOUT_SECONDS: u64 = 290;
+
+// Pod annotation holding the ephemeral storage the mock kubelet reports the pod using, e.g. "900Mi"
+pub const MOCK_EPHEMERAL_STORAGE_ANNOTATION: &str = "mock.kuboard.io/ephemeral-storage-used";
+
+// (api prefix, plural, kind) for the resources the commands work with
+const KNOWN_RESOURCES: &[(&str, &str, &str)] = &[
+    ("api/v1", "namespaces", "Namespace"),
+    ("api/v1", "nodes", "Node"),
+    ("api/v1", "pods", "Pod"),
+    ("api/v1", "services", "Service"),
+    ("api/v1", "endpoints", "Endpoints"),
+    ("api/v1", "configmaps", "ConfigMap"),
+    ("api/v1", "secrets", "Secret"),
+    ("api/v1", "events", "Event"),
+    ("api/v1", "serviceaccounts", "ServiceAccount"),
+    ("api/v1", "persistentvolumeclaims", "PersistentVolumeClaim"),
+    ("api/v1", "persistentvolumes", "PersistentVolume"),
+    ("api/v1", "resourcequotas", "ResourceQuota"),
+    ("apis/apps/v1", "deployments", "Deployment"),
+    ("apis/apps/v1", "replicasets", "ReplicaSet"),
+    ("apis/apps/v1", "statefulsets", "StatefulSet"),
+    ("apis/apps/v1", "daemonsets", "DaemonSet"),
+    ("apis/apps/v1", "controllerrevisions", "ControllerRevision"),
+    ("apis/batch/v1", "jobs", "Job"),
+    ("apis/batch/v1", "cronjobs", "CronJob"),
+    ("apis/networking.k8s.io/v1", "ingresses", "Ingress"),
+    ("apis/networking.k8s.io/v1", "ingressclasses", "IngressClass"),
+    ("apis/policy/v1", "poddisruptionbudgets", "PodDisruptionBudget"),
+    ("apis/storage.k8s.io/v1", "storageclasses", "StorageClass"),
+    ("apis/storage.k8s.io/v1", "csidrivers", "CSIDriver"),
+    ("apis/storage.k8s.io/v1", "csinodes", "CSINode"),
+    ("apis/storage.k8s.io/v1", "volumeattachments", "VolumeAttachment"),
+    ("apis/rbac.authorization.k8s.io/v1", "roles", "Role"),
+    ("apis/rbac.authorization.k8s.io/v1", "rolebindings", "RoleBinding"),
+    ("apis/rbac.authorization.k8s.io/v1", "clusterroles", "ClusterRole"),
+    ("apis/rbac.authorization.k8s.io/v1", "clusterrolebindings", "ClusterRoleBinding"),
+    ("apis/apiextensions.k8s.io/v1", "customresourcedefinitions", "CustomResourceDefinition"),
+    // Served as if cert-manager's CRD were installed
+    ("apis/cert-manager.io/v1", "certificates", "Certificate"),
+    ("apis/metrics.k8s.io/v1beta1", "nodes", "NodeMetrics"),
+    ("apis/metrics.k8s.io/v1beta1", "pods", "PodMetrics"),
+];
+
+fn kind_for(api: &str, plural: &str) -> Option<&'static str> {
+    KNOWN_RESOURCES
+        .iter()
+        .find(|(a, p, _)| *a == api && *p == plural)
+        .map(|(_, _, kind)| *kind)
+}
+
+fn api_version_for(api: &str) -> String {
+    api.trim_start_matches("apis/").trim_start_matches("api/").to_string()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct ObjectKey {
+    api: String,
+    plural: String,
+    namespace: String,
+    name: String,
+}
+
+#[derive(Debug, Clone)]
+struct ResourcePath {
+    api: String,
+    namespace: Option<String>,
+    plural: Option<String>,
+    name: Option<String>,
+    subresource: Option<String>,
+}
+
+impl ResourcePath {
+    fn parse(path: &str) -> Option<Self> {
+        let segments: Vec<&str> = path.trim_matches('/').split('/').filter(|s| !s.is_empty()).collect();
+        let (api, rest) = match segments.first() {
+            Some(&"api") if segments.len() >= 2 => (format!("api/{}", segments[1]), &segments[2..]),
+            Some(&"apis") if segments.len() >= 3 => (format!("apis/{}/{}", segments[1], segments[2]), &segments[3..]),
+            _ => return None,
+        };
+
+        // "namespaces/{ns}/{plural}" scopes a namespaced resource, shorter paths address namespaces themselves
+        let (namespace, rest) = if rest.len() >= 3 && rest[0] == "namespaces" {
+            (Some(rest[1].to_string()), &rest[2..])
+        } else {
+            (None, rest)
+        };
+
+        Some(Self {
+            api,
+            namespace,
+            plural: rest.first().map(|s| s.to_string()),
+            name: rest.get(1).map(|s| s.to_string()),
+            subresource: rest.get(2).map(|s| s.to_string()),
+        })
+    }
+
+    fn key(&self, name: &str) -> ObjectKey {
+        ObjectKey {
+            api: self.api.clone(),
+            plural: self.plural.clone().unwrap_or_default(),
+            namespace: self.namespace.clone().unwrap_or_default(),
+            name: name.to_string(),
+        }
+    }
+
+    fn matches(&self, key: &ObjectKey) -> bool {
+        key.api == self.api
+            && Some(&key.plural) == self.plural.as_ref()
+            && self.namespace.as_ref().is_none_or(|ns| *ns == key.namespace)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct WatchEvent {
+    resource_version: u64,
+    key: ObjectKey,
+    event_type: &'static str,
+    object: Value,
+}
+
+#[derive(Default)]
+struct MockStore {
+    objects: BTreeMap<ObjectKey, Value>,
+    events: Vec<WatchEvent>,
+    resource_version: u64,
+    logs: HashMap<(String, String), String>,
+    // Set when simulating a user bound to a few namespaces only
+    allowed_namespaces: Option<Vec<String>>,
+}
+
+impl MockStore {
+    fn next_resource_version(&mut self) -> u64 {
+        self.resource_version += 1;
+        self.resource_version
+    }
+
+    // Stamps server-managed metadata, stores the object and records a watch event
+    fn write(&mut self, key: ObjectKey, mut object: Value, event_type: &'static str) -> Value {
+        let resource_version = self.next_resource_version();
+        let previous_generation = self.objects.get(&key)
+            .and_then(|o| o.pointer("/metadata/generation"))
+            .and_then(Value::as_i64);
+        let spec_changed = self.objects.get(&key).map(|o| o.get("spec") != object.get("spec")).unwrap_or(true);
+
+        if let Some(kind) = kind_for(&key.api, &key.plural) {
+            object["kind"] = json!(kind);
+            object["apiVersion"] = json!(api_version_for(&key.api));
+        }
+
+        let metadata = ensure_object(&mut object, "metadata");
+        metadata.insert("name".to_string(), json!(key.name));
+        if !key.namespace.is_empty() {
+            metadata.insert("namespace".to_string(), json!(key.namespace));
+        }
+        metadata.insert("resourceVersion".to_string(), json!(resource_version.to_string()));
+        metadata.entry("uid").or_insert_with(|| json!(uuid::Uuid::new_v4().to_string()));
+        metadata.entry("creationTimestamp").or_insert_with(|| json!(chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true)));
+        let generation = match previous_generation {
+            Some(generation) if spec_changed => generation + 1,
+            Some(generation) => generation,
+            None => 1,
+        };
+        metadata.insert("generation".to_string(), json!(generation));
+
+        self.objects.insert(key.clone(), object.clone());
+        self.record(key, event_type, object.clone(), resource_version);
+        object
+    }
+
+    fn remove(&mut self, key: &ObjectKey) -> Option<Value> {
+        let object = self.objects.remove(key)?;
+        let resource_version = self.next_resource_version();
+        self.record(key.clone(), "DELETED", object.clone(), resource_version);
+        Some(object)
+    }
+
+    fn record(&mut self, key: ObjectKey, event_type: &'static str, object: Value, resource_version: u64) {
+        self.events.push(WatchEvent { resource_version, key, event_type, object });
+        if self.events.len() > MAX_WATCH_EVENTS {
+            let excess = self.events.len() - MAX_WATCH_EVENTS;
+            self.events.drain(..excess);
+        }
+    }
+}
+
+fn ensure_object<'a>(value: &'a mut Value, field: &str) -> &'a mut Map<String, Value> {
+    if !value.get(field).map(Value::is_object).unwrap_or(false) {
+        value[field] = json!({});
+    }
+    value[field].as_object_mut().expect("field was just set to an object")
+}
+
+// Splits a selector on commas that are not inside an "in (a,b)" value set
+fn split_selector(selector: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0;
+    for ch in selector.chars() {
+        match ch {
+            '(' => { depth += 1; current.push(ch); }
+            ')' => { depth -= 1; current.push(ch); }
+            ',' if depth == 0 => parts.push(std::mem::take(&mut current)),
+            _ => current.push(ch),
+        }
+    }
+    parts.push(current);
+    parts.into_iter().map(|p| p.trim().to_string()).filter(|p| !p.is_empty()).collect()
+}
+
+// Evaluates an equality- or set-based label selector the way the API server does
+pub fn label_selector_matches(selector: &str, labels: &BTreeMap<String, String>) -> bool {
+    split_selector(selector).iter().all(|requirement| {
+        let set_values = |rest: &str| -> Vec<String> {
+            rest.trim().trim_start_matches('(').trim_end_matches(')')
+                .split(',')
+                .map(|v| v.trim().to_string())
+                .collect()
+        };
+
+        if let Some((key, rest)) = requirement.split_once(" notin ") {
+            return labels.get(key.trim()).is_none_or(|v| !set_values(rest).contains(v));
+        }
+        if let Some((key, rest)) = requirement.split_once(" in ") {
+            return labels.get(key.trim()).is_some_and(|v| set_values(rest).contains(v));
+        }
+        if let Some((key, value)) = requirement.split_once("!=") {
+            return labels.get(key.trim()).map(String::as_str) != Some(value.trim());
+        }
+        if let Some((key, value)) = requirement.split_once("==").or_else(|| requirement.split_once('=')) {
+            return labels.get(key.trim()).map(String::as_str) == Some(value.trim());
+        }
+        if let Some(key) = requirement.strip_prefix('!') {
+            return !labels.contains_key(key.trim());
+        }
+        labels.contains_key(requirement.as_str())
+    })
+}
+
+fn field_selector_matches(selector: &str, object: &Value) -> bool {
+    split_selector(selector).iter().all(|requirement| {
+        let (path, expected, negate) = if let Some((path, value)) = requirement.split_once("!=") {
+            (path, value, true)
+        } else if let Some((path, value)) = requirement.split_once("==").or_else(|| requirement.split_once('=')) {
+            (path, value, false)
+        } else {
+            return true;
+        };
+
+        let pointer = format!("/{}", path.trim().replace('.', "/"));
+        let actual = match object.pointer(&pointer) {
+            Some(Value::String(s)) => s.clone(),
+            Some(Value::Null) | None => String::new(),
+            Some(other) => other.to_string(),
+        };
+        (actual == expected.trim()) != negate
+    })
+}
+
+fn object_labels(object: &Value) -> BTreeMap<String, String> {
+    object.pointer("/metadata/labels")
+        .and_then(|labels| serde_json::from_value(labels.clone()).ok())
+        .unwrap_or_default()
+}
+
+// RFC 7386 JSON merge patch, also used for strategic merge and apply patches
+fn merge_patch(target: &mut Value, patch: &Value) {
+    match patch {
+        Value::Object(patch_map) => {
+            if !target.is_object() {
+                *target = json!({});
+            }
+            let target_map = target.as_object_mut().expect("target was just made an object");
+            for (key, value) in patch_map {
+                if value.is_null() {
+                    target_map.remove(key);
+                } else {
+                    merge_patch(target_map.entry(key.clone()).or_insert(Value::Null), value);
+                }
+            }
+        }
+        _ => *target = patch.clone(),
+    }
+}
+
+// Minimal RFC 6902 support covering the add/replace/remove operations the commands send
+fn json_patch(target: &mut Value, operations: &Value) -> Result<(), String> {
+    for operation in operations.as_array().ok_or("JSON patch must be an array")? {
+        let op = operation.get("op").and_then(Value::as_str).unwrap_or_default();
+        let path = operation.get("path").and_then(Value::as_str).ok_or("JSON patch operation is missing a path")?;
+        let (parent_path, field) = path.rsplit_once('/').ok_or("Invalid JSON patch path")?;
+        let field = field.replace("~1", "/").replace("~0", "~");
+        let parent = target.pointer_mut(parent_path).ok_or_else(|| format!("Path {} does not exist", parent_path))?;
+
+        match (op, parent) {
+            ("add" | "replace", Value::Object(map)) => {
+                map.insert(field, operation.get("value").cloned().unwrap_or(Value::Null));
+            }
+            ("add", Value::Array(items)) if field == "-" => {
+                items.push(operation.get("value").cloned().unwrap_or(Value::Null));
+            }
+            ("add" | "replace", Value::Array(items)) => {
+                let index: usize = field.parse().map_err(|_| format!("Invalid array index {}", field))?;
+                let value = operation.get("value").cloned().unwrap_or(Value::Null);
+                if op == "add" && index <= items.len() {
+                    items.insert(index, value);
+                } else if index < items.len() {
+                    items[index] = value;
+                } else {
+                    return Err(format!("Array index {} out of bounds", index));
+                }
+            }
+            ("remove", Value::Object(map)) => {
+                map.remove(&field);
+            }
+            ("remove", Value::Array(items)) => {
+                let index: usize = field.parse().map_err(|_| format!("Invalid array index {}", field))?;
+                if index < items.len() {
+                    items.remove(index);
+                }
+            }
+            (other, _) => return Err(format!("Unsupported JSON patch operation {}", other)),
+        }
+    }
+    Ok(())
+}
+
+fn json_response(status: StatusCode, value: &Value) -> Response<Body> {
+    Response::builder()
+        .status(status)
+        .header(http::header::CONTENT_TYPE, "application/json")
+        .body(Body::from(serde_json::to_vec(value).unwrap_or_default()))
+        .expect("static response parts are valid")
+}
+
+fn status_response(status: StatusCode, reason: &str, message: String) -> Response<Body> {
+    json_response(status, &json!({
+        "kind": "Status",
+        "apiVersion": "v1",
+        "metadata": {},
+        "status": "Failure",
+        "message": message,
+        "reason": reason,
+        "code": status.as_u16(),
+    }))
+}
+
+fn not_found(path: &ResourcePath, name: &str) -> Response<Body> {
+    status_response(
+        StatusCode::NOT_FOUND,
+        "NotFound",
+        format!("{} \"{}\" not found", path.plural.as_deref().unwrap_or("resource"), name),
+    )
+}
+
+#[derive(Clone)]
+pub struct MockCluster {
+    store: Arc<Mutex<MockStore>>,
+    changed: Arc<Notify>,
+}
+
+impl MockCluster {
+    // A cluster pre-populated with a small, realistic set of workloads
+    pub fn new() -> Self {
+        let cluster = Self::empty();
+        cluster.seed();
+        cluster
+    }
+
+    pub fn empty() -> Self {
+        Self {
+            store: Arc::new(Mutex::new(MockStore::default())),
+            changed: Arc::new(Notify::new()),
+        }
+    }
+
+    // Shared instance backing the mock context, so data survives context switches
+    pub fn shared() -> Self {
+        static SHARED: OnceLock<MockCluster> = OnceLock::new();
+        SHARED.get_or_init(MockCluster::new).clone()
+    }
+
+    pub fn client(&self) -> Client {
+        let cluster = self.clone();
+        let service = tower::service_fn(move |request: Request<Body>| {
+            let cluster = cluster.clone();
+            async move { Ok::<_, Infallible>(cluster.handle(request).await) }
+        });
+        Client::new(service, "default")
+    }
+
+    // Inserts or replaces an object, addressed by its apiVersion, kind, namespace and name
+    pub fn insert(&self, object: Value) {
+        let api_version = object.get("apiVersion").and_then(Value::as_str).unwrap_or("v1");
+        let kind = object.get("kind").and_then(Value::as_str).unwrap_or_default();
+        let api = if api_version.contains('/') { format!("apis/{}", api_version) } else { format!("api/{}", api_version) };
+        let plural = KNOWN_RESOURCES
+            .iter()
+            .find(|(a, _, k)| *a == api && *k == kind)
+            .map(|(_, plural, _)| plural.to_string())
+            .unwrap_or_else(|| format!("{}s", kind.to_lowercase()));
+        let key = ObjectKey {
+            api,
+            plural,
+            namespace: object.pointer("/metadata/namespace").and_then(Value::as_str).unwrap_or_default().to_string(),
+            name: object.pointer("/metadata/name").and_then(Value::as_str).unwrap_or_default().to_string(),
+        };
+
+        let mut store = self.store.lock().expect("mock store lock poisoned");
+        let event_type = if store.objects.contains_key(&key) { "MODIFIED" } else { "ADDED" };
+        store.write(key, object, event_type);
+        drop(store);
+        self.changed.notify_waiters();
+    }
+
+    pub fn set_pod_logs(&self, namespace: &str, pod_name: &str, logs: &str) {
+        self.store.lock().expect("mock store lock poisoned")
+            .logs
+            .insert((namespace.to_string(), pod_name.to_string()), logs.to_string());
+    }
+
+    // Simulates a user without cluster-wide access: cluster-scoped and other-namespace requests are forbidden
+    pub fn restrict_to_namespaces(&self, namespaces: &[&str]) {
+        self.store.lock().expect("mock store lock poisoned").allowed_namespaces =
+            Some(namespaces.iter().map(|ns| ns.to_string()).collect());
+    }
+
+    pub async fn handle(&self, request: Request<Body>) -> Response<Body> {
+        let (parts, body) = request.into_parts();
+        let body = match body.collect_bytes().await {
+            Ok(bytes) => bytes.to_vec(),
+            Err(e) => return status_response(StatusCode::BAD_REQUEST, "BadRequest", e.to_string()),
+        };
+        let query: HashMap<String, String> = parts.uri.query()
+            .map(|q| url::form_urlencoded::parse(q.as_bytes()).into_owned().collect())
+            .unwrap_or_default();
+        let content_type = parts.headers.get(http::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or("application/json")
+            .to_string();
+
+        debug!("Mock cluster request: {} {}", parts.method, parts.uri);
+
+        if parts.uri.path() == "/version" {
+            return json_response(StatusCode::OK, &json!({
+                "major": "1",
+                "minor": "31",
+                "gitVersion": "v1.31.0-kuboard-mock",
+                "gitCommit": "mock",
+                "gitTreeState": "clean",
+                "buildDate": "2025-01-01T00:00:00Z",
+                "goVersion": "go1.22.0",
+                "compiler": "gc",
+                "platform": "linux/amd64",
+            }));
+        }
+
+        let Some(path) = ResourcePath::parse(parts.uri.path()) else {
+            return status_response(StatusCode::NOT_FOUND, "NotFound", format!("the server could not find the requested resource: {}", parts.uri.path()));
+        };
+
+        let Some(plural) = path.plural.clone() else {
+            return self.discovery(&path);
+        };
+
+        if plural == "selfsubjectrulesreviews" && parts.method == Method::POST {
+            return self.rules_review(&body);
+        }
+        if let Some(response) = self.forbidden(&parts.method, &path, &plural) {
+            return response;
+        }
+
+        match (parts.method.clone(), path.name.clone()) {
+            (Method::GET, None) if query.get("watch").map(String::as_str) == Some("true") => {
+                self.watch(&path, &query).await
+            }
+            (Method::GET, None) => self.list(&path, &plural, &query),
+            (Method::GET, Some(name)) if path.subresource.as_deref() == Some("proxy") => self.proxy(&path, &name),
+            (Method::GET, Some(name)) => self.get(&path, &name),
+            (Method::POST, None) => self.create(&path, &body, &query),
+            (Method::POST, Some(name)) if path.subresource.as_deref() == Some("eviction") => self.delete(&path, &name, &body, &query),
+            (Method::PUT, Some(name)) => self.replace(&path, &name, &body, &query),
+            (Method::PATCH, Some(name)) => self.patch(&path, &name, &body, &content_type, &query),
+            (Method::DELETE, Some(name)) => self.delete(&path, &name, &body, &query),
+            (method, _) => status_response(
+                StatusCode::METHOD_NOT_ALLOWED,
+                "MethodNotAllowed",
+                format!("{} is not supported by the mock cluster for {}", method, parts.uri.path()),
+            ),
+        }
+    }
+
+    fn forbidden(&self, method: &Method, path: &ResourcePath, plural: &str) -> Option<Response<Body>> {
+        let store = self.store.lock().expect("mock store lock poisoned");
+        let allowed = store.allowed_namespaces.as_ref()?;
+        let permitted = match (&path.namespace, &path.name) {
+            (Some(namespace), _) => allowed.contains(namespace),
+            // Getting an allowed namespace object itself is still permitted
+            (None, Some(name)) => plural == "namespaces" && *method == Method::GET && allowed.contains(name),
+            (None, None) => false,
+        };
+        if permitted {
+            return None;
+        }
+        let scope = path.namespace.as_ref().map(|ns| format!("in the namespace \"{}\"", ns)).unwrap_or_else(|| "at the cluster scope".to_string());
+        Some(status_response(
+            StatusCode::FORBIDDEN,
+            "Forbidden",
+            format!("{} is forbidden: User \"mock-user\" cannot {} resource \"{}\" {}", plural, method.as_str().to_lowercase(), plural, scope),
+        ))
+    }
+
+    // Everything is allowed unless restricted; restricted users also see which namespaces they may get
+    fn rules_review(&self, body: &[u8]) -> Response<Body> {
+        let mut review: Value = serde_json::from_slice(body).unwrap_or_else(|_| json!({}));
+        let namespace = review.pointer("/spec/namespace").and_then(Value::as_str).unwrap_or_default().to_string();
+        let allowed = self.store.lock().expect("mock store lock poisoned").allowed_namespaces.clone();
+        let everything = json!({ "verbs": ["*"], "apiGroups": ["*"], "resources": ["*"] });
+        let rules = match allowed {
+            None => vec![everything],
+            Some(allowed) => {
+                let mut rules = vec![json!({ "verbs": ["get"], "apiGroups": [""], "resources": ["namespaces"], "resourceNames": allowed })];
+                if allowed.contains(&namespace) {
+                    rules.push(everything);
+                }
+                rules
+            }
+        };
+        review["status"] = json!({ "resourceRules": rules, "nonResourceRules": [], "incomplete": false });
+        json_response(StatusCode::CREATED, &review)
+    }
+
+    fn discovery(&self, path: &ResourcePath) -> Response<Body> {
+        let resources: Vec<Value> = KNOWN_RESOURCES
+            .iter()
+            .filter(|(api, _, _)| *api == path.api)
+            .map(|(_, plural, kind)| json!({
+                "name": plural,
+                "singularName": kind.to_lowercase(),
+                "namespaced": !matches!(*kind, "Namespace" | "Node" | "PersistentVolume" | "NodeMetrics" | "ClusterRole" | "ClusterRoleBinding" | "IngressClass" | "StorageClass" | "CSIDriver" | "CSINode" | "VolumeAttachment" | "CustomResourceDefinition"),
+                "kind": kind,
+                "verbs": ["get", "list", "watch", "create", "update", "patch", "delete"],
+            }))
+            .collect();
+
+        if resources.is_empty() {
+            return status_response(StatusCode::NOT_FOUND, "NotFound", format!("the server could not find the requested resource: /{}", path.api));
+        }
+
+        json_response(StatusCode::OK, &json!({
+            "kind": "APIResourceList",
+            "apiVersion": "v1",
+            "groupVersion": api_version_for(&path.api),
+            "resources": resources,
+        }))
+    }
+
+    fn matching_objects(&self, store: &MockStore, path: &ResourcePath, query: &HashMap<String, String>) -> Vec<Value> {
+        store.objects
+            .iter()
+            .filter(|(key, _)| path.matches(key))
+            .map(|(_, object)| object)
+            .filter(|object| query.get("labelSelector").is_none_or(|s| label_selector_matches(s, &object_labels(object))))
+            .filter(|object| query.get("fieldSelector").is_none_or(|s| field_selector_matches(s, object)))
+            .cloned()
+            .collect()
+    }
+
+    fn list(&self, path: &ResourcePath, plural: &str, query: &HashMap<String, String>) -> Response<Body> {
+        let store = self.store.lock().expect("mock store lock poisoned");
+        let mut items = self.matching_objects(&store, path, query);
+
+        // limit/continue pagination over the stable key ordering
+        let offset: usize = query.get("continue").and_then(|c| c.parse().ok()).unwrap_or(0);
+        let limit: Option<usize> = query.get("limit").and_then(|l| l.parse().ok()).filter(|l| *l > 0);
+        let total = items.len();
+        items = items.into_iter().skip(offset).collect();
+        let mut metadata = json!({ "resourceVersion": store.resource_version.to_string() });
+        if let Some(limit) = limit {
+            items.truncate(limit);
+            if offset + limit < total {
+                metadata["continue"] = json!((offset + limit).to_string());
+                metadata["remainingItemCount"] = json!(total - offset - limit);
+            }
+        }
+
+        let kind = kind_for(&path.api, plural).map(|k| format!("{}List", k)).unwrap_or_else(|| "List".to_string());
+        json_response(StatusCode::OK, &json!({
+            "kind": kind,
+            "apiVersion": api_version_for(&path.api),
+            "metadata": metadata,
+            "items": items,
+        }))
+    }
+
+    async fn watch(&self, path: &ResourcePath, query: &HashMap<String, String>) -> Response<Body> {
+        let since: u64 = query.get("resourceVersion").and_then(|rv| rv.parse().ok()).unwrap_or(0);
+        let timeout = query.get("timeoutSeconds")
+            .and_then(|t| t.parse().ok())
+            .unwrap_or(DEFAULT_WATCH_TIMEOUT_SECONDS);
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(timeout);
+
+        loop {
+            // Register interest before checking so a write in between is not missed
+            let notified = self.changed.notified();
+
+            let lines: Vec<String> = {
+                let store = self.store.lock().expect("mock store lock poisoned");
+                store.events
+                    .iter()
+                    .filter(|event| event.resource_version > since && path.matches(&event.key))
+                    .filter(|event| query.get("labelSelector").is_none_or(|s| label_selector_matches(s, &object_labels(&event.object))))
+                    .filter(|event| query.get("fieldSelector").is_none_or(|s| field_selector_matches(s, &event.object)))
+                    .map(|event| json!({ "type": event.event_type, "object": event.object }).to_string())
+                    .collect()
+            };
+
+            if !lines.is_empty() {
+                let body = lines.join("\n") + "\n";
+                return Response::builder()
+                    .status(StatusCode::OK)
+                    .header(http::header::CONTENT_TYPE, "application/json")
+                    .body(Body::from(body.into_bytes()))
+                    .expect("static response parts are valid");
+            }
+
+            if tokio::time::timeout_at(deadline, notified).await.is_err() {
+                return Response::builder()
+                    .status(StatusCode::OK)
+                    .body(Body::empty())
+                    .expect("static response parts are valid");
+            }
+        }
+    }
+
+    fn get(&self, path: &ResourcePath, name: &str) -> Response<Body> {
+        let store = self.store.lock().expect("mock store lock poisoned");
+        let Some(object) = store.objects.get(&path.key(name)) else {
+            return not_found(path, name);
+        };
+
+        match path.subresource.as_deref() {
+            Some("log") => {
+                let logs = store.logs
+                    .get(&(path.namespace.clone().unwrap_or_default(), name.to_string()))
+                    .cloned()
+                    .unwrap_or_else(|| format!("[kuboard-mock] {} started\n[kuboard-mock] {} is serving requests\n", name, name));
+                Response::builder()
+                    .status(StatusCode::OK)
+                    .header(http::header::CONTENT_TYPE, "text/plain")
+                    .body(Body::from(logs.into_bytes()))
+                    .expect("static response parts are valid")
+            }
+            Some("scale") => json_response(StatusCode::OK, &scale_for(object)),
+            _ => json_response(StatusCode::OK, object),
+        }
+    }
+
+    // Pod proxy answers for running pods, like a container serving on every port;
+    // anything else gets the API server's own 503
+    fn proxy(&self, path: &ResourcePath, name: &str) -> Response<Body> {
+        if path.plural.as_deref() == Some("nodes") {
+            return self.stats_summary(name);
+        }
+        let pod_name = name.split(':').next().unwrap_or(name);
+        let store = self.store.lock().expect("mock store lock poisoned");
+        let running = store.objects.get(&path.key(pod_name))
+            .is_some_and(|pod| pod.pointer("/status/phase").and_then(Value::as_str) == Some("Running"));
+        if !running {
+            return status_response(StatusCode::SERVICE_UNAVAILABLE, "ServiceUnavailable", format!("error trying to reach service: dial tcp: connection refused ({})", name));
+        }
+        Response::builder()
+            .status(StatusCode::OK)
+            .header(http::header::CONTENT_TYPE, "text/plain")
+            .body(Body::from(b"ok".to_vec()))
+            .expect("static response parts are valid")
+    }
+
+    // The kubelet's /stats/summary, reporting the ephemeral storage a pod's MOCK_EPHEMERAL_STORAGE_ANNOTATION claims
+    fn stats_summary(&self, node: &str) -> Response<Body> {
+        let store = self.store.lock().expect("mock store lock poisoned");
+        let pods: Vec<Value> = store.objects.iter()
+            .filter(|(key, pod)| key.plural == "pods" && pod.pointer("/spec/nodeName").and_then(Value::as_str) == Some(node))
+            .map(|(key, pod)| {
+                let used = pod.pointer("/metadata/annotations")
+                    .and_then(|a| a.get(MOCK_EPHEMERAL_STORAGE_ANNOTATION))
+                    .and_then(Value::as_str)
+                    .and_then(|q| kuboard_parse_bytes(q).ok())
+                    .unwrap_or(0);
+                json!({
+                    "podRef": { "name": key.name, "namespace": key.namespace, "uid": "" },
+                    "ephemeral-storage": { "usedBytes": used },
+                })
+            })
+            .collect();
+        json_response(StatusCode::OK, &json!({ "node": { "nodeName": node }, "pods": pods }))
+    }
+
+    fn create(&self, path: &ResourcePath, body: &[u8], query: &HashMap<String, String>) -> Response<Body> {
+        let mut object: Value = match serde_json::from_slice(body) {
+            Ok(object) => object,
+            Err(e) => return status_response(StatusCode::BAD_REQUEST, "BadRequest", format!("Invalid object: {}", e)),
+        };
+
+        let name = match object.pointer("/metadata/name").and_then(Value::as_str) {
+            Some(name) => name.to_string(),
+            None => match object.pointer("/metadata/generateName").and_then(Value::as_str) {
+                Some(prefix) => format!("{}{}", prefix, &uuid::Uuid::new_v4().simple().to_string()[..5]),
+                None => return status_response(StatusCode::UNPROCESSABLE_ENTITY, "Invalid", "metadata.name: Required value".to_string()),
+            },
+        };
+        ensure_object(&mut object, "metadata").insert("name".to_string(), json!(name));
+
+        let key = path.key(&name);
+        let mut store = self.store.lock().expect("mock store lock poisoned");
+        if store.objects.contains_key(&key) {
+            return status_response(StatusCode::CONFLICT, "AlreadyExists", format!("{} \"{}\" already exists", key.plural, name));
+        }
+
+        if is_dry_run(query) {
+            return json_response(StatusCode::CREATED, &object);
+        }
+
+        let created = store.write(key, object, "ADDED");
+        drop(store);
+        self.changed.notify_waiters();
+        json_response(StatusCode::CREATED, &created)
+    }
+
+    fn replace(&self, path: &ResourcePath, name: &str, body: &[u8], query: &HashMap<String, String>) -> Response<Body> {
+        let incoming: Value = match serde_json::from_slice(body) {
+            Ok(object) => object,
+            Err(e) => return status_response(StatusCode::BAD_REQUEST, "BadRequest", format!("Invalid object: {}", e)),
+        };
+
+        let key = path.key(name);
+        let mut store = self.store.lock().expect("mock store lock poisoned");
+        let Some(current) = store.objects.get(&key).cloned() else {
+            return not_found(path, name);
+        };
+
+        let object = match path.subresource.as_deref() {
+            Some("scale") => {
+                let mut object = current.clone();
+                if let Some(replicas) = incoming.pointer("/spec/replicas") {
+                    ensure_object(&mut object, "spec").insert("replicas".to_string(), replicas.clone());
+                }
+                object
+            }
+            _ => {
+                // Optimistic concurrency: a stale resourceVersion is rejected like the real API server
+                let expected = incoming.pointer("/metadata/resourceVersion").and_then(Value::as_str);
+                let actual = current.pointer("/metadata/resourceVersion").and_then(Value::as_str);
+                if expected.is_some() && expected != actual {
+                    return status_response(
+                        StatusCode::CONFLICT,
+                        "Conflict",
+                        format!("Operation cannot be fulfilled on {} \"{}\": the object has been modified; please apply your changes to the latest version and try again", key.plural, name),
+                    );
+                }
+                incoming
+            }
+        };
+
+        if is_dry_run(query) {
+            return json_response(StatusCode::OK, &object);
+        }
+
+        let updated = store.write(key, object, "MODIFIED");
+        drop(store);
+        self.changed.notify_waiters();
+
+        match path.subresource.as_deref() {
+            Some("scale") => json_response(StatusCode::OK, &scale_for(&updated)),
+            _ => json_response(StatusCode::OK, &updated),
+        }
+    }
+
+    fn patch(&self, path: &ResourcePath, name: &str, body: &[u8], content_type: &str, query: &HashMap<String, String>) -> Response<Body> {
+        let patch: Value = match serde_json::from_slice(body) {
+            Ok(patch) => patch,
+            Err(e) => return status_response(StatusCode::BAD_REQUEST, "BadRequest", format!("Invalid patch: {}", e)),
+        };
+
+        let key = path.key(name);
+        let mut store = self.store.lock().expect("mock store lock poisoned");
+        let is_apply = content_type.starts_with("application/apply-patch");
+        let (mut object, event_type) = match store.objects.get(&key) {
+            Some(current) => (current.clone(), "MODIFIED"),
+            // Server-side apply creates the object when it does not exist yet
+            None if is_apply => (json!({}), "ADDED"),
+            None => return not_found(path, name),
+        };
+
+        let scale_patch = path.subresource.as_deref() == Some("scale");
+        // A patch carrying a resourceVersion is checked against it like an update
+        let expected = patch.pointer("/metadata/resourceVersion").and_then(Value::as_str);
+        if !scale_patch && event_type == "MODIFIED" && expected.is_some() && expected != object.pointer("/metadata/resourceVersion").and_then(Value::as_str) {
+            return status_response(
+                StatusCode::CONFLICT,
+                "Conflict",
+                format!("Operation cannot be fulfilled on {} \"{}\": the object has been modified; please apply your changes to the latest version and try again", key.plural, name),
+            );
+        }
+        let target = if scale_patch { scale_for(&object) } else { object.clone() };
+        let mut patched = target;
+        if content_type.starts_with("application/json-patch") {
+            if let Err(e) = json_patch(&mut patched, &patch) {
+                return status_response(StatusCode::UNPROCESSABLE_ENTITY, "Invalid", e);
+            }
+        } else {
+            merge_patch(&mut patched, &patch);
+        }
+
+        if scale_patch {
+            if let Some(replicas) = patched.pointer("/spec/replicas") {
+                ensure_object(&mut object, "spec").insert("replicas".to_string(), replicas.clone());
+            }
+        } else {
+            object = patched;
+        }
+
+        if is_dry_run(query) {
+            return json_response(StatusCode::OK, &object);
+        }
+
+        let updated = store.write(key, object, event_type);
+        drop(store);
+        self.changed.notify_waiters();
+
+        if scale_patch {
+            json_response(StatusCode::OK, &scale_for(&updated))
+        } else {
+            json_response(StatusCode::OK, &updated)
+        }
+    }
+
+    fn delete(&self, path: &ResourcePath, name: &str, body: &[u8], query: &HashMap<String, String>) -> Response<Body> {
+        let key = path.key(name);
+        let mut store = self.store.lock().expect("mock store lock poisoned");
+
+        if is_dry_run(query) || delete_options_dry_run(body) {
+            return match store.objects.get(&key) {
+                Some(object) => json_response(StatusCode::OK, object),
+                None => not_found(path, name),
+            };
+        }
+
+        let Some(object) = store.remove(&key) else {
+            return not_found(path, name);
+        };
+        drop(store);
+        self.changed.notify_waiters();
+
+        if path.subresource.as_deref() == Some("eviction") {
+            return json_response(StatusCode::CREATED, &json!({
+                "kind": "Status",
+                "apiVersion": "v1",
+                "metadata": {},
+                "status": "Success",
+            }));
+        }
+        json_response(StatusCode::OK, &object)
+    }
+
+    fn seed(&self) {
+        for namespace in ["default", "kube-system"] {
+            self.insert(json!({
+                "apiVersion": "v1",
+                "kind": "Namespace",
+                "metadata": { "name": namespace, "labels": { "kubernetes.io/metadata.name": namespace } },
+                "status": { "phase": "Active" },
+            }));
+        }
+
+        for (index, node) in ["mock-node-1", "mock-node-2"].iter().enumerate() {
+            self.insert(json!({
+                "apiVersion": "v1",
+                "kind": "Node",
+                "metadata": {
+                    "name": node,
+                    "labels": {
+                        "kubernetes.io/hostname": node,
+                        "kubernetes.io/os": "linux",
+                        "topology.kubernetes.io/zone": format!("mock-zone-{}", index + 1),
+                        "topology.kubernetes.io/region": "mock-region",
+                        "node-role.kubernetes.io/worker": "",
+                    },
+                },
+                "spec": {},
+                "status": {
+                    "capacity": { "cpu": "4", "memory": "16Gi", "ephemeral-storage": "100Gi", "pods": "110" },
+                    "allocatable": { "cpu": "3800m", "memory": "15Gi", "ephemeral-storage": "95Gi", "pods": "110" },
+                    "conditions": [
+                        { "type": "Ready", "status": "True", "reason": "KubeletReady", "message": "kubelet is posting ready status" },
+                    ],
+                    "addresses": [
+                        { "type": "InternalIP", "address": format!("10.0.0.{}", index + 10) },
+                        { "type": "Hostname", "address": node },
+                    ],
+                    "nodeInfo": {
+                        "architecture": "amd64",
+                        "bootID": "",
+                        "containerRuntimeVersion": "containerd://1.7.0",
+                        "kernelVersion": "6.1.0",
+                        "kubeProxyVersion": "v1.31.0",
+                        "kubeletVersion": "v1.31.0",
+                        "machineID": "",
+                        "operatingSystem": "linux",
+                        "osImage": "Kuboard Mock Linux",
+                        "systemUUID": "",
+                    },
+                },
+            }));
+            self.insert(json!({
+                "apiVersion": "metrics.k8s.io/v1beta1",
+                "kind": "NodeMetrics",
+                "metadata": { "name": node },
+                "timestamp": "2025-01-01T00:00:00Z",
+                "window": "30s",
+                "usage": { "cpu": format!("{}m", 600 + index * 250), "memory": format!("{}Mi", 4096 + index * 1024) },
+            }));
+        }
+
+        let web_labels = json!({ "app": "web", "pod-template-hash": "7d9f8" });
+        self.insert(json!({
+            "apiVersion": "apps/v1",
+            "kind": "Deployment",
+            "metadata": { "name": "web", "namespace": "default", "labels": { "app": "web" } },
+            "spec": {
+                "replicas": 2,
+                "selector": { "matchLabels": { "app": "web" } },
+                "template": {
+                    "metadata": { "labels": { "app": "web" } },
+                    "spec": { "containers": [{ "name": "nginx", "image": "nginx:1.27", "ports": [{ "containerPort": 80 }] }] },
+                },
+            },
+            "status": { "replicas": 2, "readyReplicas": 2, "availableReplicas": 2, "updatedReplicas": 2, "observedGeneration": 1 },
+        }));
+        self.insert(json!({
+            "apiVersion": "apps/v1",
+            "kind": "ReplicaSet",
+            "metadata": {
+                "name": "web-7d9f8",
+                "namespace": "default",
+                "labels": web_labels,
+                "annotations": { "deployment.kubernetes.io/revision": "1" },
+                "ownerReferences": [{ "apiVersion": "apps/v1", "kind": "Deployment", "name": "web", "uid": "mock-web-uid", "controller": true }],
+            },
+            "spec": {
+                "replicas": 2,
+                "selector": { "matchLabels": web_labels },
+                "template": {
+                    "metadata": { "labels": web_labels },
+                    "spec": { "containers": [{ "name": "nginx", "image": "nginx:1.27" }] },
+                },
+            },
+            "status": { "replicas": 2, "readyReplicas": 2, "availableReplicas": 2 },
+        }));
+        for (index, suffix) in ["abc12", "def34"].iter().enumerate() {
+            let node = format!("mock-node-{}", index + 1);
+            self.insert(mock_pod(&format!("web-7d9f8-{}", suffix), "default", &web_labels, "nginx", "nginx:1.27", &node, "ReplicaSet", "web-7d9f8"));
+        }
+
+        let db_labels = json!({ "app": "db" });
+        self.insert(json!({
+            "apiVersion": "apps/v1",
+            "kind": "StatefulSet",
+            "metadata": { "name": "db", "namespace": "default", "labels": db_labels },
+            "spec": {
+                "replicas": 1,
+                "serviceName": "db",
+                "selector": { "matchLabels": db_labels },
+                "template": {
+                    "metadata": { "labels": db_labels },
+                    "spec": { "containers": [{ "name": "postgres", "image": "postgres:16" }] },
+                },
+            },
+            "status": { "replicas": 1, "readyReplicas": 1, "currentReplicas": 1, "updatedReplicas": 1, "availableReplicas": 1, "observedGeneration": 1 },
+        }));
+        self.insert(mock_pod("db-0", "default", &db_labels, "postgres", "postgres:16", "mock-node-1", "StatefulSet", "db"));
+
+        let agent_labels = json!({ "app": "node-agent" });
+        self.insert(json!({
+            "apiVersion": "apps/v1",
+            "kind": "DaemonSet",
+            "metadata": { "name": "node-agent", "namespace": "kube-system", "labels": agent_labels },
+            "spec": {
+                "selector": { "matchLabels": agent_labels },
+                "template": {
+                    "metadata": { "labels": agent_labels },
+                    "spec": { "containers": [{ "name": "agent", "image": "busybox:1.36" }] },
+                },
+            },
+            "status": {
+                "currentNumberScheduled": 2, "desiredNumberScheduled": 2, "numberReady": 2, "numberMisscheduled": 0,
+                "numberAvailable": 2, "updatedNumberScheduled": 2, "observedGeneration": 1,
+            },
+        }));
+        for node in ["mock-node-1", "mock-node-2"] {
+            self.insert(mock_pod(&format!("node-agent-{}", node), "kube-system", &agent_labels, "agent", "busybox:1.36", node, "DaemonSet", "node-agent"));
+        }
+
+        self.insert(json!({
+            "apiVersion": "v1",
+            "kind": "Service",
+            "metadata": { "name": "web", "namespace": "default", "labels": { "app": "web" } },
+            "spec": {
+                "type": "ClusterIP",
+                "clusterIP": "10.96.0.20",
+                "selector": { "app": "web" },
+                "ports": [{ "name": "http", "port": 80, "targetPort": 80, "protocol": "TCP" }],
+            },
+        }));
+        self.insert(json!({
+            "apiVersion": "v1",
+            "kind": "Endpoints",
+            "metadata": { "name": "web", "namespace": "default" },
+            "subsets": [{
+                "addresses": [
+                    { "ip": "10.244.0.10", "nodeName": "mock-node-1", "targetRef": { "kind": "Pod", "name": "web-7d9f8-abc12", "namespace": "default" } },
+                    { "ip": "10.244.0.11", "nodeName": "mock-node-2", "targetRef": { "kind": "Pod", "name": "web-7d9f8-def34", "namespace": "default" } },
+                ],
+                "ports": [{ "name": "http", "port": 80, "protocol": "TCP" }],
+            }],
+        }));
+        self.insert(json!({
+            "apiVersion": "v1",
+            "kind": "ConfigMap",
+            "metadata": { "name": "web-config", "namespace": "default" },
+            "data": { "LOG_LEVEL": "info" },
+        }));
+        self.insert(json!({
+            "apiVersion": "v1",
+            "kind": "Secret",
+            "metadata": { "name": "web-secret", "namespace": "default" },
+            "type": "Opaque",
+            "data": { "password": "bW9jay1wYXNzd29yZA==" },
+        }));
+        self.insert(json!({
+            "apiVersion": "v1",
+            "kind": "ServiceAccount",
+            "metadata": { "name": "default", "namespace": "default" },
+        }));
+        self.insert(json!({
+            "apiVersion": "rbac.authorization.k8s.io/v1",
+            "kind": "Role",
+            "metadata": { "name": "config-reader", "namespace": "default" },
+            "rules": [{ "apiGroups": [""], "resources": ["configmaps"], "verbs": ["get", "list", "watch"] }],
+        }));
+        self.insert(json!({
+            "apiVersion": "rbac.authorization.k8s.io/v1",
+            "kind": "RoleBinding",
+            "metadata": { "name": "default-config-reader", "namespace": "default" },
+            "roleRef": { "apiGroup": "rbac.authorization.k8s.io", "kind": "Role", "name": "config-reader" },
+            "subjects": [{ "kind": "ServiceAccount", "name": "default", "namespace": "default" }],
+        }));
+        self.insert(json!({
+            "apiVersion": "batch/v1",
+            "kind": "CronJob",
+            "metadata": { "name": "nightly-report", "namespace": "default" },
+            "spec": {
+                "schedule": "0 2 * * *",
+                "suspend": false,
+                "jobTemplate": {
+                    "spec": {
+                        "template": {
+                            "spec": {
+                                "restartPolicy": "OnFailure",
+                                "containers": [{ "name": "report", "image": "busybox:1.36", "command": ["sh", "-c", "echo report"] }],
+                            },
+                        },
+                    },
+                },
+            },
+            "status": {},
+        }));
+        self.insert(json!({
+            "apiVersion": "v1",
+            "kind": "Event",
+            "metadata": { "name": "web-7d9f8-abc12.pulled", "namespace": "default" },
+            "involvedObject": { "kind": "Pod", "name": "web-7d9f8-abc12", "namespace": "default" },
+            "type": "Normal",
+            "reason": "Pulled",
+            "message": "Container image \"nginx:1.27\" already present on machine",
+            "count": 1,
+            "firstTimestamp": "2025-01-01T00:00:00Z",
+            "lastTimestamp": "2025-01-01T00:00:00Z",
+        }));
+    }
+}
+
+impl Default for MockCluster {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn is_dry_run(query: &HashMap<String, String>) -> bool {
+    query.get("dryRun").map(String::as_str) == Some("All")
+}
+
+// Deletes can also carry dryRun in their DeleteOptions body, which is where kube puts it
+fn delete_options_dry_run(body: &[u8]) -> bool {
+    serde_json::from_slice::<Value>(body)
+        .is_ok_and(|options| options["dryRun"].as_array().is_some_and(|modes| modes.iter().any(|mode| mode == "All")))
+}
+
+fn scale_for(object: &Value) -> Value {
+    json!({
+        "apiVersion": "autoscaling/v1",
+        "kind": "Scale",
+        "metadata": {
+            "name": object.pointer("/metadata/name"),
+            "namespace": object.pointer("/metadata/namespace"),
+            "resourceVersion": object.pointer("/metadata/resourceVersion"),
+        },
+        "spec": { "replicas": object.pointer("/spec/replicas").cloned().unwrap_or(json!(0)) },
+        "status": { "replicas": object.pointer("/status/replicas").cloned().unwrap_or(json!(0)) },
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+fn mock_pod(name: &str, namespace: &str, labels: &Value, container: &str, image: &str, node: &str, owner_kind: &str, owner_name: &str) -> Value {
+    json!({
+        "apiVersion": "v1",
+        "kind": "Pod",
+        "metadata": {
+            "name": name,
+            "namespace": namespace,
+            "labels": labels,
+            "ownerReferences": [{ "apiVersion": "apps/v1", "kind": owner_kind, "name": owner_name, "uid": format!("mock-{}-uid", owner_name), "controller": true }],
+        },
+        "spec": {
+            "nodeName": node,
+            "containers": [{
+                "name": container,
+                "image": image,
+                "resources": { "requests": { "cpu": "100m", "memory": "128Mi" }, "limits": { "cpu": "500m", "memory": "256Mi" } },
+            }],
+        },
+        "status": {
+            "phase": "Running",
+            "podIP": "10.244.0.10",
+            "hostIP": "10.0.0.10",
+            "qosClass": "Burstable",
+            "startTime": "2025-01-01T00:00:00Z",
+            "conditions": [{ "type": "Ready", "status": "True" }],
+            "containerStatuses": [{
+                "name": container,
+                "image": image,
+                "imageID": "",
+                "ready": true,
+                "started": true,
+                "restartCount": 0,
+                "state": { "running": { "startedAt": "2025-01-01T00:00:00Z" } },
+            }],
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn test_label_selector_matches_equality_and_set_requirements() {
+        let pod_labels = labels(&[("app", "web"), ("tier", "frontend")]);
+
+        assert!(label_selector_matches("app=web", &pod_labels));
+        assert!(label_selector_matches("app==web,tier!=backend", &pod_labels));
+        assert!(label_selector_matches("tier in (frontend, backend),!canary", &pod_labels));
+        assert!(!label_selector_matches("tier notin (frontend)", &pod_labels));
+        assert!(!label_selector_matches("app=db", &pod_labels));
+        assert!(label_selector_matches("app", &pod_labels));
+    }
+
+    #[test]
+    fn test_resource_path_parsing() {
+        let path = ResourcePath::parse("/apis/apps/v1/namespaces/default/deployments/web/scale").unwrap();
+        assert_eq!(path.api, "apis/apps/v1");
+        assert_eq!(path.namespace.as_deref(), Some("default"));
+        assert_eq!(path.plural.as_deref(), Some("deployments"));
+        assert_eq!(path.name.as_deref(), Some("web"));
+        assert_eq!(path.subresource.as_deref(), Some("scale"));
+
+        let namespace = ResourcePath::parse("/api/v1/namespaces/default").unwrap();
+        assert_eq!(namespace.namespace, None);
+        assert_eq!(namespace.plural.as_deref(), Some("namespaces"));
+        assert_eq!(namespace.name.as_deref(), Some("default"));
+    }
+}
@@ -7,19 +7,108 @@
 pub mod watch;
 pub mod exec;
 pub mod port_forward;
+pub mod notifier;
+pub mod drift;
+pub mod selectors;
+pub mod cronjobs;
+pub mod kubectl;
+pub mod recording;
+pub mod saved_commands;
+pub mod files;
+pub mod node_shell;
+pub mod workloads;
+pub mod scaling;
+pub mod manifests;
+pub mod clone;
+pub mod templates;
+pub mod schema;
+pub mod pod_security;
+pub mod service_accounts;
+pub mod rbac;
+pub mod best_practices;
+pub mod deprecations;
+pub mod upgrade;
+pub mod resilience;
+pub mod placement;
+pub mod topology;
+pub mod endpoint_probe;
+pub mod http_probe;
+pub mod dns;
+pub mod ingress;
+pub mod tls;
+pub mod namespace_access;
+pub mod profiles;
+pub mod rate_limit;
+pub mod shared_watch;
+pub mod list_delta;
+pub mod compression;
+pub mod prefetch;
+pub mod keepalive;
+pub mod auth_monitor;
+pub mod progress;
+pub mod trash;
+pub mod batch;
+pub mod pvc;
+pub mod expose;
+pub mod service_urls;
+pub mod load_balancer;
+pub mod addons;
+pub mod metrics_server;
+pub mod context_metadata;
+pub mod guardrails;
+pub mod audit;
+pub mod pod_status;
+pub mod workload_status;
+pub mod list_view;
+pub mod manifest_search;
+pub mod pagination;
+pub mod change_cause;
+pub mod conflict;
+pub mod field_managers;
+pub mod apply;
+pub mod eviction;
+pub mod ephemeral_storage;
+pub mod csi;
+pub mod diff;
+pub mod statefulset_volumes;
+pub mod job_progress;
+pub mod edit;
+pub mod rollout;
+pub mod template_diff;
+pub mod event_trends;
+pub mod warning_feed;
+pub mod scale_guard;
+pub mod dynamic;
+pub mod persistent_volumes;
+pub mod printer_columns;
+pub mod namespace_overview;
+pub mod config_reload;
+#[cfg(feature = "mock-cluster")]
+pub mod mock;
 
 use anyhow::{anyhow, Result};
 use kube::{Client, Config, Api};
+use kube::client::ClientBuilder;
 use kube::api::{ListParams, LogParams};
 use kube::config::{KubeConfigOptions, Kubeconfig};
-use k8s_openapi::api::core::v1::Node;
+use k8s_openapi::api::core::v1::{Container, ContainerStatus, Endpoints, Node, Pod, Service};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::env;
 use std::path::PathBuf;
 use tracing::{debug, warn};
 
-use crate::types::{ClusterMetrics, NodeDetails};
-use crate::utils::{kuboard_parse_cpu_string, kuboard_parse_memory_string};
+use crate::types::{
+    ClusterMetrics, NodeDetails, PodDescribe, PodDescribeStatus, PodDescribeCondition,
+    PodDescribeContainer, PodDescribeResources, PodDescribePort, PodDescribeEnvVar,
+    PodDescribeEnvSource, PodDescribeFieldRef, PodDescribeContainerStatus,
+    PodDescribeContainerState, PodDescribeRunningState, PodDescribeWaitingState,
+    PodDescribeTerminatedState, PodDescribeVolume, PodDescribeToleration,
+    PodDescribeEvent, PodDescribeMetadata, RateLimitSettings, ServiceDetails, ServiceRouting,
+};
+use crate::quantity::{kuboard_parse_bytes, kuboard_parse_cpu};
+use crate::utils::kuboard_format_age;
 
 // Kubeconfig Management
 pub async fn kuboard_load_kubeconfig() -> Result<Kubeconfig> {
@@ -33,18 +122,49 @@ pub async fn kuboard_load_kubeconfig() -> Result<Kubeconfig> {
 
     debug!("Loading kubeconfig from: {:?}", kubeconfig_path);
     
-    if !kubeconfig_path.exists() {
-        return Err(anyhow!("Kubeconfig file not found at {:?}", kubeconfig_path));
+    #[cfg(feature = "mock-cluster")]
+    {
+        // The mock context is always offered, even on machines without a kubeconfig
+        let mut kubeconfig = if kubeconfig_path.exists() {
+            Kubeconfig::read_from(&kubeconfig_path)?
+        } else {
+            Kubeconfig::default()
+        };
+        kubeconfig.contexts.push(kube::config::NamedContext {
+            name: mock::MOCK_CONTEXT_NAME.to_string(),
+            context: Some(kube::config::Context {
+                cluster: mock::MOCK_CONTEXT_NAME.to_string(),
+                user: Some(mock::MOCK_CONTEXT_NAME.to_string()),
+                namespace: Some("default".to_string()),
+                ..Default::default()
+            }),
+        });
+        if kubeconfig.current_context.is_none() {
+            kubeconfig.current_context = Some(mock::MOCK_CONTEXT_NAME.to_string());
+        }
+        Ok(kubeconfig)
     }
 
-    let kubeconfig = Kubeconfig::read_from(&kubeconfig_path)?;
-    Ok(kubeconfig)
+    #[cfg(not(feature = "mock-cluster"))]
+    {
+        if !kubeconfig_path.exists() {
+            return Err(anyhow!("Kubeconfig file not found at {:?}", kubeconfig_path));
+        }
+
+        let kubeconfig = Kubeconfig::read_from(&kubeconfig_path)?;
+        Ok(kubeconfig)
+    }
 }
 
 pub async fn kuboard_create_client_from_context(
     kubeconfig: &Kubeconfig, 
     context_name: &str
 ) -> Result<Client> {
+    #[cfg(feature = "mock-cluster")]
+    if context_name == mock::MOCK_CONTEXT_NAME {
+        return Ok(mock::MockCluster::shared().client());
+    }
+
     let config_options = KubeConfigOptions {
         context: Some(context_name.to_string()),
         cluster: None,
@@ -52,8 +172,20 @@ pub async fn kuboard_create_client_from_context(
     };
     
     let config = Config::from_custom_kubeconfig(kubeconfig.clone(), &config_options).await?;
-    let client = Client::try_from(config)?;
-    
+    let builder = ClientBuilder::try_from(config)?.with_layer(&auth_monitor::AuthFailureLayer::new(context_name));
+
+    // Unreadable settings fall back to the default limit rather than failing the connection
+    let rate_limit = rate_limit::kuboard_rate_limits_path()
+        .and_then(|path| rate_limit::kuboard_load_rate_limit(&path, context_name))
+        .unwrap_or_else(|e| {
+            warn!("Failed to load rate limit for {}, using defaults: {}", context_name, e);
+            RateLimitSettings::default()
+        });
+    let client = match rate_limit::RateLimitLayer::new(&rate_limit) {
+        Some(layer) => builder.with_layer(&layer).build(),
+        None => builder.build(),
+    };
+
     Ok(client)
 }
 
@@ -123,17 +255,17 @@ pub async fn kuboard_calculate_cluster_metrics(client: &Client) -> Result<Cluste
             // Max capacity
             if let Some(capacity) = &node_status.capacity {
                 if let Some(cpu) = capacity.get("cpu") {
-                    if let Ok(cpu_cores) = kuboard_parse_cpu_string(&cpu.0) {
+                    if let Ok(cpu_cores) = kuboard_parse_cpu(&cpu.0) {
                         max_cpu_cores = cpu_cores;
                     }
                 }
                 if let Some(memory) = capacity.get("memory") {
-                    if let Ok(memory_bytes) = kuboard_parse_memory_string(&memory.0) {
+                    if let Ok(memory_bytes) = kuboard_parse_bytes(&memory.0) {
                         max_memory_bytes = memory_bytes;
                     }
                 }
                 if let Some(disk) = capacity.get("ephemeral-storage") {
-                    if let Ok(disk_bytes) = kuboard_parse_memory_string(&disk.0) {
+                    if let Ok(disk_bytes) = kuboard_parse_bytes(&disk.0) {
                         disk_capacity = Some(disk_bytes);
                     }
                 }
@@ -142,17 +274,17 @@ pub async fn kuboard_calculate_cluster_metrics(client: &Client) -> Result<Cluste
             // Allocatable resources
             if let Some(allocatable) = &node_status.allocatable {
                 if let Some(cpu) = allocatable.get("cpu") {
-                    if let Ok(cpu_cores) = kuboard_parse_cpu_string(&cpu.0) {
+                    if let Ok(cpu_cores) = kuboard_parse_cpu(&cpu.0) {
                         allocatable_cpu_cores = cpu_cores;
                     }
                 }
                 if let Some(memory) = allocatable.get("memory") {
-                    if let Ok(memory_bytes) = kuboard_parse_memory_string(&memory.0) {
+                    if let Ok(memory_bytes) = kuboard_parse_bytes(&memory.0) {
                         allocatable_memory_bytes = memory_bytes;
                     }
                 }
                 if let Some(disk) = allocatable.get("ephemeral-storage") {
-                    if let Ok(disk_bytes) = kuboard_parse_memory_string(&disk.0) {
+                    if let Ok(disk_bytes) = kuboard_parse_bytes(&disk.0) {
                         disk_allocatable = Some(disk_bytes);
                     }
                 }
@@ -210,6 +342,7 @@ pub async fn kuboard_calculate_cluster_metrics(client: &Client) -> Result<Cluste
         
         // Extract labels and annotations
         let labels = node.metadata.labels.clone().unwrap_or_default();
+        let (zone, region) = topology::kuboard_node_topology(&labels);
         let annotations = node.metadata.annotations.clone().unwrap_or_default();
         
         // Extract taints
@@ -248,6 +381,8 @@ pub async fn kuboard_calculate_cluster_metrics(client: &Client) -> Result<Cluste
             kernel_version,
             kubelet_version,
             container_runtime,
+            zone,
+            region,
             disk_capacity,
             disk_allocatable,
             disk_usage_percent,
@@ -256,6 +391,7 @@ pub async fn kuboard_calculate_cluster_metrics(client: &Client) -> Result<Cluste
             taints,
             metrics_available,
             metrics_error,
+            age: node.metadata.creation_timestamp.as_ref().map(|t| kuboard_format_age(t.0)),
         });
     }
     
@@ -344,4 +480,194 @@ pub async fn kuboard_fetch_pod_logs(
     // server compatibility but still return a snapshot per request.
     let logs = pods_api.logs(pod_name, &lp).await?;
     Ok(logs)
-}
\ No newline at end of file
+}
+
+// Pod Describe
+fn describe_time(time: Option<&Time>) -> String {
+    time.map(|t| t.0.to_rfc3339()).unwrap_or_else(|| "None".to_string())
+}
+
+fn describe_or(value: Option<&String>, fallback: &str) -> String {
+    value.cloned().unwrap_or_else(|| fallback.to_string())
+}
+
+fn describe_container_status(status: &ContainerStatus) -> PodDescribeContainerStatus {
+    let state = status.state.as_ref();
+    PodDescribeContainerStatus {
+        ready: status.ready,
+        restart_count: status.restart_count,
+        state: PodDescribeContainerState {
+            running: state.and_then(|st| st.running.as_ref()).map(|_| PodDescribeRunningState {
+                started_at: "Running".to_string(),
+            }),
+            waiting: state.and_then(|st| st.waiting.as_ref()).map(|w| PodDescribeWaitingState {
+                reason: describe_or(w.reason.as_ref(), "None"),
+                message: describe_or(w.message.as_ref(), "None"),
+            }),
+            terminated: state.and_then(|st| st.terminated.as_ref()).map(|t| PodDescribeTerminatedState {
+                reason: describe_or(t.reason.as_ref(), "None"),
+                exit_code: t.exit_code,
+                started_at: describe_time(t.started_at.as_ref()),
+                finished_at: describe_time(t.finished_at.as_ref()),
+            }),
+        },
+    }
+}
+
+fn describe_container(container: &Container, status: Option<&ContainerStatus>) -> PodDescribeContainer {
+    let quantities = |values: Option<&std::collections::BTreeMap<String, k8s_openapi::apimachinery::pkg::api::resource::Quantity>>| {
+        values
+            .map(|values| values.iter().map(|(k, v)| (k.clone(), v.0.clone())).collect())
+            .unwrap_or_default()
+    };
+
+    PodDescribeContainer {
+        name: container.name.clone(),
+        image: container.image.clone(),
+        image_pull_policy: describe_or(container.image_pull_policy.as_ref(), "IfNotPresent"),
+        resources: container.resources.as_ref().map(|r| PodDescribeResources {
+            requests: quantities(r.requests.as_ref()),
+            limits: quantities(r.limits.as_ref()),
+        }),
+        ports: container.ports.as_ref()
+            .map(|ports| ports.iter().map(|p| PodDescribePort {
+                name: describe_or(p.name.as_ref(), "None"),
+                container_port: p.container_port,
+                protocol: describe_or(p.protocol.as_ref(), "TCP"),
+            }).collect())
+            .unwrap_or_default(),
+        env: container.env.as_ref()
+            .map(|envs| envs.iter().map(|e| PodDescribeEnvVar {
+                name: e.name.clone(),
+                value: describe_or(e.value.as_ref(), "None"),
+                value_from: e.value_from.as_ref().map(|vf| PodDescribeEnvSource {
+                    field_ref: vf.field_ref.as_ref().map(|fr| PodDescribeFieldRef {
+                        field_path: fr.field_path.clone(),
+                    }),
+                }),
+            }).collect())
+            .unwrap_or_default(),
+        // The describe view reads status fields directly, so an absent status is an empty one
+        status: status.map(describe_container_status).unwrap_or_default(),
+    }
+}
+
+pub fn kuboard_build_pod_describe(pod: &Pod, events: &[PodEvent]) -> PodDescribe {
+    let status = pod.status.as_ref();
+    let spec = pod.spec.as_ref();
+
+    PodDescribe {
+        name: describe_or(pod.metadata.name.as_ref(), "Unknown"),
+        namespace: describe_or(pod.metadata.namespace.as_ref(), "default"),
+        labels: pod.metadata.labels.clone().unwrap_or_default(),
+        annotations: pod.metadata.annotations.clone().unwrap_or_default(),
+        status: PodDescribeStatus {
+            phase: describe_or(status.and_then(|s| s.phase.as_ref()), "Unknown"),
+            pod_ip: describe_or(status.and_then(|s| s.pod_ip.as_ref()), "None"),
+            host_ip: describe_or(status.and_then(|s| s.host_ip.as_ref()), "None"),
+            node_name: describe_or(spec.and_then(|s| s.node_name.as_ref()), "None"),
+            qos_class: describe_or(status.and_then(|s| s.qos_class.as_ref()), "Unknown"),
+            start_time: describe_time(status.and_then(|s| s.start_time.as_ref())),
+        },
+        conditions: status
+            .and_then(|s| s.conditions.as_ref())
+            .map(|conditions| conditions.iter().map(|c| PodDescribeCondition {
+                type_: c.type_.clone(),
+                status: c.status.clone(),
+                reason: describe_or(c.reason.as_ref(), "None"),
+                message: describe_or(c.message.as_ref(), "None"),
+                last_transition_time: describe_time(c.last_transition_time.as_ref()),
+            }).collect())
+            .unwrap_or_default(),
+        containers: spec
+            .map(|s| s.containers.iter().map(|c| {
+                let container_status = status
+                    .and_then(|s| s.container_statuses.as_ref())
+                    .and_then(|statuses| statuses.iter().find(|cs| cs.name == c.name));
+                describe_container(c, container_status)
+            }).collect())
+            .unwrap_or_default(),
+        volumes: spec
+            .and_then(|s| s.volumes.as_ref())
+            .map(|volumes| volumes.iter().map(|v| PodDescribeVolume {
+                name: v.name.clone(),
+                type_: if v.config_map.is_some() { "ConfigMap" }
+                       else if v.secret.is_some() { "Secret" }
+                       else if v.persistent_volume_claim.is_some() { "PVC" }
+                       else if v.empty_dir.is_some() { "EmptyDir" }
+                       else { "Other" }.to_string(),
+            }).collect())
+            .unwrap_or_default(),
+        tolerations: spec
+            .and_then(|s| s.tolerations.as_ref())
+            .map(|tolerations| tolerations.iter().map(|t| PodDescribeToleration {
+                key: describe_or(t.key.as_ref(), ""),
+                operator: describe_or(t.operator.as_ref(), "Equal"),
+                value: describe_or(t.value.as_ref(), "None"),
+                effect: describe_or(t.effect.as_ref(), "None"),
+                toleration_seconds: t.toleration_seconds,
+            }).collect())
+            .unwrap_or_default(),
+        events: events.iter().map(|e| PodDescribeEvent {
+            type_: e.type_.clone(),
+            reason: e.reason.clone(),
+            message: e.message.clone(),
+            count: e.count,
+            first_timestamp: e.first_timestamp.clone().unwrap_or_else(|| "None".to_string()),
+            last_timestamp: e.last_timestamp.clone().unwrap_or_else(|| "None".to_string()),
+        }).collect(),
+        metadata: PodDescribeMetadata {
+            uid: describe_or(pod.metadata.uid.as_ref(), "None"),
+            resource_version: describe_or(pod.metadata.resource_version.as_ref(), "None"),
+            creation_timestamp: describe_time(pod.metadata.creation_timestamp.as_ref()),
+            age: pod.metadata.creation_timestamp.as_ref().map(|t| kuboard_format_age(t.0)).unwrap_or_else(|| "None".to_string()),
+            generation: pod.metadata.generation.unwrap_or(0),
+        },
+    }
+}
+
+pub fn kuboard_service_is_external_name(service: &Service) -> bool {
+    service.spec.as_ref().and_then(|s| s.type_.as_deref()) == Some("ExternalName")
+}
+
+// ExternalName services ignore their selector, so only other types target pods
+pub fn kuboard_service_pod_selector(service: &Service) -> Option<&BTreeMap<String, String>> {
+    if kuboard_service_is_external_name(service) {
+        return None;
+    }
+    service.spec.as_ref()
+        .and_then(|s| s.selector.as_ref())
+        .filter(|selector| !selector.is_empty())
+}
+
+pub fn kuboard_build_service_details(service: Service, endpoints: Option<Endpoints>) -> ServiceDetails {
+    let spec = service.spec.as_ref();
+    let external_name = kuboard_service_is_external_name(&service);
+
+    let routing = if external_name {
+        ServiceRouting::ExternalName
+    } else if kuboard_service_pod_selector(&service).is_some() {
+        ServiceRouting::Selector
+    } else if endpoints.is_some() {
+        ServiceRouting::ManualEndpoints
+    } else {
+        ServiceRouting::Unrouted
+    };
+
+    ServiceDetails {
+        service_type: spec.and_then(|s| s.type_.clone()).unwrap_or_else(|| "ClusterIP".to_string()),
+        headless: spec.and_then(|s| s.cluster_ip.as_deref()) == Some("None"),
+        external_name: if external_name { spec.and_then(|s| s.external_name.clone()) } else { None },
+        routing,
+        // Stale Endpoints may linger after a service is switched to ExternalName
+        endpoints: if external_name { None } else { endpoints },
+        service,
+    }
+}
+
+// Workload Pod Ownership
+pub fn kuboard_pod_controlled_by(pod: &Pod, kind: &str, name: &str) -> bool {
+    pod.metadata.owner_references.as_ref().is_some_and(|owner_refs| {
+        owner_refs.iter().any(|owner| owner.kind == kind && owner.name == name && owner.controller == Some(true))
+    })
+}
@@ -0,0 +1,397 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Namespace Access
+// Per-context namespace filters, plus per-namespace listing for users without cluster-wide permissions
+
+use anyhow::{anyhow, Result};
+use k8s_openapi::api::authorization::v1::{ResourceRule, SelfSubjectRulesReview, SelfSubjectRulesReviewSpec};
+use k8s_openapi::api::core::v1::Namespace;
+use k8s_openapi::NamespaceResourceScope;
+use kube::api::{ApiResource, DynamicObject, ListParams, PostParams};
+use kube::{Api, Client, Resource, ResourceExt};
+use serde::de::DeserializeOwned;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Debug;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
+use crate::kubernetes::pagination::{kuboard_list_all_pages, kuboard_list_page};
+use crate::types::{ListPage, NamespaceAccess, NamespaceFilter};
+
+pub fn kuboard_namespace_filters_path() -> Result<PathBuf> {
+    dirs::data_local_dir()
+        .map(|dir| dir.join("kuboard").join("namespace_filters.json"))
+        .ok_or_else(|| anyhow!("Could not find local data directory"))
+}
+
+fn load_filters(path: &Path) -> Result<BTreeMap<String, NamespaceFilter>> {
+    if !path.exists() {
+        return Ok(BTreeMap::new());
+    }
+    Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+}
+
+pub fn kuboard_load_namespace_filter(path: &Path, context: &str) -> Result<NamespaceFilter> {
+    Ok(load_filters(path)?.remove(context).unwrap_or_default())
+}
+
+fn normalize(patterns: &[String]) -> Vec<String> {
+    patterns.iter()
+        .map(|p| p.trim().to_string())
+        .filter(|p| !p.is_empty())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+// Replaces the context's filter; an empty filter removes it
+pub fn kuboard_save_namespace_filter(path: &Path, context: &str, filter: &NamespaceFilter) -> Result<NamespaceFilter> {
+    let filter = NamespaceFilter { allow: normalize(&filter.allow), deny: normalize(&filter.deny) };
+
+    let mut filters = load_filters(path)?;
+    if filter.allow.is_empty() && filter.deny.is_empty() {
+        filters.remove(context);
+    } else {
+        filters.insert(context.to_string(), filter.clone());
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let tmp_path = path.with_extension("json.tmp");
+    fs::write(&tmp_path, serde_json::to_string_pretty(&filters)?)?;
+    fs::rename(&tmp_path, path)?;
+    Ok(filter)
+}
+
+// Exact names, or a prefix ending in "*" such as "team-*"
+fn pattern_matches(pattern: &str, namespace: &str) -> bool {
+    match pattern.strip_suffix('*') {
+        Some(prefix) => namespace.starts_with(prefix),
+        None => pattern == namespace,
+    }
+}
+
+// Deny wins over allow, an empty allow-list allows everything, and cluster-scoped objects always pass
+pub fn kuboard_namespace_allowed(filter: &NamespaceFilter, namespace: Option<&str>) -> bool {
+    let Some(namespace) = namespace else { return true };
+    if filter.deny.iter().any(|p| pattern_matches(p, namespace)) {
+        return false;
+    }
+    filter.allow.is_empty() || filter.allow.iter().any(|p| pattern_matches(p, namespace))
+}
+
+// An allow-list of plain names can be queried namespace by namespace instead of cluster-wide
+fn exact_allow_list(filter: &NamespaceFilter) -> Option<Vec<String>> {
+    if filter.allow.is_empty() || filter.allow.iter().any(|p| p.ends_with('*')) {
+        return None;
+    }
+    Some(filter.allow.iter().filter(|ns| kuboard_namespace_allowed(filter, Some(ns.as_str()))).cloned().collect())
+}
+
+pub fn kuboard_is_forbidden(error: &kube::Error) -> bool {
+    matches!(error, kube::Error::Api(api_error) if api_error.code == 403)
+}
+
+fn grants(values: Option<&Vec<String>>, value: &str) -> bool {
+    values.is_some_and(|values| values.iter().any(|v| v == "*" || v == value))
+}
+
+// Namespaces a rules review names explicitly, e.g. a ClusterRole granting get on specific namespaces
+pub fn kuboard_rules_namespace_names(rules: &[ResourceRule]) -> Vec<String> {
+    rules.iter()
+        .filter(|r| grants(r.api_groups.as_ref(), "") && grants(r.resources.as_ref(), "namespaces"))
+        .filter(|r| r.verbs.iter().any(|v| matches!(v.as_str(), "get" | "list" | "*")))
+        .flat_map(|r| r.resource_names.iter().flatten().cloned())
+        .collect()
+}
+
+// Whether the rules let the user list anything besides namespaces themselves
+pub fn kuboard_rules_allow_listing(rules: &[ResourceRule]) -> bool {
+    rules.iter().any(|r| {
+        r.verbs.iter().any(|v| v == "list" || v == "*")
+            && r.resources.iter().flatten().any(|resource| resource != "namespaces")
+    })
+}
+
+async fn rules_review(client: &Client, namespace: &str) -> Result<Vec<ResourceRule>> {
+    let review = SelfSubjectRulesReview {
+        spec: SelfSubjectRulesReviewSpec { namespace: Some(namespace.to_string()) },
+        ..Default::default()
+    };
+    let reviewed = Api::<SelfSubjectRulesReview>::all(client.clone()).create(&PostParams::default(), &review).await?;
+    Ok(reviewed.status.map(|s| s.resource_rules).unwrap_or_default())
+}
+
+// Cluster-wide namespace list when permitted, else the filter's allow-list, else a rules review
+// starting from the client's default namespace; the filter applies to all three
+pub async fn kuboard_discover_namespaces(client: &Client, filter: &NamespaceFilter) -> Result<NamespaceAccess> {
+    let mut access = match Api::<Namespace>::all(client.clone()).list(&ListParams::default()).await {
+        Ok(list) => NamespaceAccess {
+            source: "cluster".to_string(),
+            namespaces: list.items.into_iter().filter_map(|ns| ns.metadata.name).collect(),
+        },
+        Err(e) if !kuboard_is_forbidden(&e) => return Err(e.into()),
+        Err(_) => match exact_allow_list(filter) {
+            Some(namespaces) => NamespaceAccess { source: "allow-list".to_string(), namespaces },
+            None => {
+                let default_namespace = client.default_namespace().to_string();
+                let mut candidates = BTreeSet::from([default_namespace.clone()]);
+                candidates.extend(kuboard_rules_namespace_names(&rules_review(client, &default_namespace).await?));
+
+                let mut namespaces = Vec::new();
+                for namespace in candidates {
+                    match rules_review(client, &namespace).await {
+                        Ok(rules) if kuboard_rules_allow_listing(&rules) => namespaces.push(namespace),
+                        Ok(_) => {}
+                        Err(e) => warn!("Could not review permissions in namespace {}: {}", namespace, e),
+                    }
+                }
+                NamespaceAccess { source: "rules-review".to_string(), namespaces }
+            }
+        },
+    };
+    access.namespaces.retain(|ns| kuboard_namespace_allowed(filter, Some(ns.as_str())));
+    Ok(access)
+}
+
+// Namespace objects passing the filter; ones a restricted user may not get are returned with just a name
+pub async fn kuboard_list_accessible_namespaces(client: &Client, filter: &NamespaceFilter) -> Result<Vec<Namespace>> {
+    let api: Api<Namespace> = Api::all(client.clone());
+    match api.list(&ListParams::default()).await {
+        Ok(list) => {
+            return Ok(list.items.into_iter().filter(|ns| kuboard_namespace_allowed(filter, ns.metadata.name.as_deref())).collect());
+        }
+        Err(e) if kuboard_is_forbidden(&e) => {}
+        Err(e) => return Err(e.into()),
+    }
+
+    let mut namespaces = Vec::new();
+    for name in kuboard_discover_namespaces(client, filter).await?.namespaces {
+        match api.get(&name).await {
+            Ok(namespace) => namespaces.push(namespace),
+            Err(e) if kuboard_is_forbidden(&e) => {
+                let mut namespace = Namespace::default();
+                namespace.metadata.name = Some(name);
+                namespaces.push(namespace);
+            }
+            // A stale allow-list entry shouldn't hide the namespaces that do exist
+            Err(kube::Error::Api(api_error)) if api_error.code == 404 => warn!("Namespace {} no longer exists", name),
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok(namespaces)
+}
+
+async fn list_per_namespace<K>(namespaced: impl Fn(&str) -> Api<K>, namespaces: &[String], params: &ListParams) -> Result<Vec<K>>
+where
+    K: Clone + DeserializeOwned + Debug,
+{
+    let mut items = Vec::new();
+    for namespace in namespaces {
+        match kuboard_list_all_pages(&namespaced(namespace), params).await {
+            Ok(list) => items.extend(list),
+            Err(e) if kuboard_is_forbidden(&e) => warn!("Skipping namespace {}: {}", namespace, e),
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok(items)
+}
+
+async fn list_accessible_with<K>(
+    client: &Client,
+    filter: &NamespaceFilter,
+    all: Api<K>,
+    namespaced: impl Fn(&str) -> Api<K>,
+    params: &ListParams,
+) -> Result<Vec<K>>
+where
+    K: Resource + Clone + DeserializeOwned + Debug,
+{
+    let mut items = match exact_allow_list(filter) {
+        Some(namespaces) => list_per_namespace(namespaced, &namespaces, params).await?,
+        None => match kuboard_list_all_pages(&all, params).await {
+            Ok(list) => list,
+            Err(e) if kuboard_is_forbidden(&e) => {
+                let access = kuboard_discover_namespaces(client, filter).await?;
+                list_per_namespace(namespaced, &access.namespaces, params).await?
+            }
+            Err(e) => return Err(e.into()),
+        },
+    };
+    items.retain(|item| kuboard_namespace_allowed(filter, item.namespace().as_deref()));
+    Ok(items)
+}
+
+// Objects in the namespaces the filter allows. Plain allow-lists are queried namespace by namespace;
+// otherwise Api::all, falling back to per-namespace lists when the user can't list cluster-wide
+pub async fn kuboard_list_accessible<K>(client: &Client, filter: &NamespaceFilter, params: &ListParams) -> Result<Vec<K>>
+where
+    K: Resource<Scope = NamespaceResourceScope> + Clone + DeserializeOwned + Debug,
+    K::DynamicType: Default,
+{
+    list_accessible_with(client, filter, Api::all(client.clone()), |ns| Api::namespaced(client.clone(), ns), params).await
+}
+
+// Objects of one namespace, or of every accessible namespace when none (or "") is given. Asking
+// for a namespace the filter hides is an error rather than a quietly empty list
+pub async fn kuboard_list_scoped<K>(client: &Client, filter: &NamespaceFilter, namespace: Option<&str>, params: &ListParams) -> Result<Vec<K>>
+where
+    K: Resource<Scope = NamespaceResourceScope> + Clone + DeserializeOwned + Debug,
+    K::DynamicType: Default,
+{
+    match namespace.filter(|namespace| !namespace.is_empty()) {
+        Some(namespace) if !kuboard_namespace_allowed(filter, Some(namespace)) => {
+            Err(anyhow!("Namespace {} is excluded by the namespace filter", namespace))
+        }
+        Some(namespace) => Ok(kuboard_list_all_pages(&Api::<K>::namespaced(client.clone(), namespace), params).await?),
+        None => kuboard_list_accessible(client, filter, params).await,
+    }
+}
+
+// kuboard_list_scoped for a namespaced kind only known at runtime, such as a custom resource
+pub async fn kuboard_list_scoped_dynamic(
+    client: &Client,
+    filter: &NamespaceFilter,
+    resource: &ApiResource,
+    namespace: Option<&str>,
+    params: &ListParams,
+) -> Result<Vec<DynamicObject>> {
+    let namespaced = |namespace: &str| Api::<DynamicObject>::namespaced_with(client.clone(), namespace, resource);
+    match namespace.filter(|namespace| !namespace.is_empty()) {
+        Some(namespace) if !kuboard_namespace_allowed(filter, Some(namespace)) => {
+            Err(anyhow!("Namespace {} is excluded by the namespace filter", namespace))
+        }
+        Some(namespace) => Ok(kuboard_list_all_pages(&namespaced(namespace), params).await?),
+        None => list_accessible_with(client, filter, Api::all_with(client.clone(), resource), namespaced, params).await,
+    }
+}
+
+async fn list_page_with<K>(
+    filter: &NamespaceFilter,
+    all: Api<K>,
+    namespaced: impl Fn(&str) -> Api<K>,
+    namespace: Option<&str>,
+    params: &ListParams,
+    limit: Option<u32>,
+    continue_token: Option<&str>,
+) -> Result<ListPage<K>>
+where
+    K: Resource + Clone + DeserializeOwned + Debug,
+{
+    let allow_list = exact_allow_list(filter);
+    let namespace = namespace.filter(|namespace| !namespace.is_empty())
+        .or(allow_list.as_deref().filter(|namespaces| namespaces.len() == 1).map(|namespaces| namespaces[0].as_str()));
+    match namespace {
+        Some(namespace) if !kuboard_namespace_allowed(filter, Some(namespace)) => {
+            Err(anyhow!("Namespace {} is excluded by the namespace filter", namespace))
+        }
+        Some(namespace) => kuboard_list_page(&namespaced(namespace), params, limit, continue_token).await,
+        // A continue token belongs to one list request, so pages can't hop between namespaces
+        None if allow_list.is_some() => Err(anyhow!("Pick one of the filter's namespaces to list page by page")),
+        None => {
+            let mut page = kuboard_list_page(&all, params, limit, continue_token).await.map_err(|e| {
+                if e.downcast_ref::<kube::Error>().is_some_and(kuboard_is_forbidden) {
+                    anyhow!("Listing across all namespaces is forbidden; pick a namespace to list page by page")
+                } else {
+                    e
+                }
+            })?;
+            // Pages may come back short; the token stays valid
+            page.items.retain(|item| kuboard_namespace_allowed(filter, item.namespace().as_deref()));
+            Ok(page)
+        }
+    }
+}
+
+// One page of kuboard_list_scoped. Without a namespace the page spans the cluster, which needs
+// cluster-wide list permission unless the filter allows a single namespace
+pub async fn kuboard_list_scoped_page<K>(
+    client: &Client,
+    filter: &NamespaceFilter,
+    namespace: Option<&str>,
+    params: &ListParams,
+    limit: Option<u32>,
+    continue_token: Option<&str>,
+) -> Result<ListPage<K>>
+where
+    K: Resource<Scope = NamespaceResourceScope> + Clone + DeserializeOwned + Debug,
+    K::DynamicType: Default,
+{
+    let namespaced = |namespace: &str| Api::<K>::namespaced(client.clone(), namespace);
+    list_page_with(filter, Api::all(client.clone()), namespaced, namespace, params, limit, continue_token).await
+}
+
+pub async fn kuboard_list_scoped_dynamic_page(
+    client: &Client,
+    filter: &NamespaceFilter,
+    resource: &ApiResource,
+    namespace: Option<&str>,
+    params: &ListParams,
+    limit: Option<u32>,
+    continue_token: Option<&str>,
+) -> Result<ListPage<DynamicObject>> {
+    let namespaced = |namespace: &str| Api::<DynamicObject>::namespaced_with(client.clone(), namespace, resource);
+    list_page_with(filter, Api::all_with(client.clone(), resource), namespaced, namespace, params, limit, continue_token).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_rules_review_namespaces() {
+        let rules: Vec<ResourceRule> = serde_json::from_value(json!([
+            { "verbs": ["get"], "apiGroups": [""], "resources": ["namespaces"], "resourceNames": ["team-a", "team-b"] },
+            { "verbs": ["create"], "apiGroups": ["authorization.k8s.io"], "resources": ["selfsubjectrulesreviews"] },
+        ])).unwrap();
+
+        assert_eq!(kuboard_rules_namespace_names(&rules), vec!["team-a", "team-b"]);
+        assert!(!kuboard_rules_allow_listing(&rules));
+
+        let rules: Vec<ResourceRule> = serde_json::from_value(json!([
+            { "verbs": ["get", "list", "watch"], "apiGroups": ["", "apps"], "resources": ["pods", "deployments"] },
+        ])).unwrap();
+        assert!(kuboard_rules_allow_listing(&rules));
+    }
+
+    #[test]
+    fn test_namespace_filter_matching() {
+        let filter = NamespaceFilter {
+            allow: vec!["team-*".to_string(), "default".to_string()],
+            deny: vec!["team-legacy".to_string()],
+        };
+        assert!(kuboard_namespace_allowed(&filter, Some("team-a")));
+        assert!(kuboard_namespace_allowed(&filter, Some("default")));
+        assert!(!kuboard_namespace_allowed(&filter, Some("team-legacy")));
+        assert!(!kuboard_namespace_allowed(&filter, Some("kube-system")));
+        assert!(kuboard_namespace_allowed(&filter, None));
+        assert!(exact_allow_list(&filter).is_none());
+
+        let hide_system = NamespaceFilter { allow: Vec::new(), deny: vec!["kube-*".to_string()] };
+        assert!(kuboard_namespace_allowed(&hide_system, Some("default")));
+        assert!(!kuboard_namespace_allowed(&hide_system, Some("kube-public")));
+    }
+
+    #[test]
+    fn test_namespace_filter_is_scoped_per_context() {
+        let dir = std::env::temp_dir().join(format!("kuboard-namespace-filters-{}", uuid::Uuid::new_v4()));
+        let path = dir.join("namespace_filters.json");
+
+        let filter = NamespaceFilter {
+            allow: vec![" team-b ".to_string(), "team-a".to_string(), "team-a".to_string()],
+            deny: Vec::new(),
+        };
+        let saved = kuboard_save_namespace_filter(&path, "dev", &filter).unwrap();
+        assert_eq!(saved.allow, vec!["team-a", "team-b"]);
+        assert_eq!(kuboard_load_namespace_filter(&path, "dev").unwrap().allow, saved.allow);
+        assert!(kuboard_load_namespace_filter(&path, "prod").unwrap().allow.is_empty());
+
+        kuboard_save_namespace_filter(&path, "dev", &NamespaceFilter::default()).unwrap();
+        assert!(kuboard_load_namespace_filter(&path, "dev").unwrap().allow.is_empty());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,238 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Namespace Overview
+// Everything a namespace landing page shows in one payload: workload counts, pod health, quota
+// usage, the latest warnings and the pods using the most CPU and memory
+
+use anyhow::{anyhow, Result};
+use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, StatefulSet};
+use k8s_openapi::api::batch::v1::{CronJob, Job};
+use k8s_openapi::api::core::v1::{Event, Namespace, Pod, ResourceQuota, Service};
+use k8s_openapi::NamespaceResourceScope;
+use kube::api::ListParams;
+use kube::{Api, Client, Resource};
+use serde::de::DeserializeOwned;
+use std::fmt::Debug;
+use tracing::warn;
+
+use crate::kubernetes::pod_status::kuboard_pod_summary;
+use crate::kubernetes::warning_feed::kuboard_warning_toast;
+use crate::kubernetes::workload_status::{kuboard_daemonset_summary, kuboard_deployment_summary, kuboard_statefulset_summary};
+use crate::metrics::{get_namespace_pod_metrics, PodMetrics};
+use crate::quantity::{kuboard_parse_bytes, kuboard_parse_cpu, kuboard_parse_quantity, kuboard_quantity_percent};
+use crate::types::{
+    NamespaceDetail, NamespacePodHealth, NamespaceWorkloadCounts, QuotaUsage, ResourceConsumer, WarningToast, WorkloadHealth,
+};
+use crate::utils::kuboard_format_age;
+
+pub const NAMESPACE_TOP_CONSUMERS: usize = 5;
+pub const NAMESPACE_RECENT_WARNINGS: usize = 10;
+
+pub fn kuboard_namespace_pod_health(pods: &[Pod]) -> NamespacePodHealth {
+    let mut health = NamespacePodHealth { total: pods.len(), ..Default::default() };
+    for summary in pods.iter().map(kuboard_pod_summary) {
+        health.restarts += summary.restarts;
+        let ready = summary.ready_containers == summary.total_containers;
+        match summary.phase.as_str() {
+            "Running" if !ready => health.not_ready += 1,
+            "Running" => health.running += 1,
+            "Pending" => health.pending += 1,
+            "Succeeded" => health.succeeded += 1,
+            "Failed" => health.failed += 1,
+            _ => {}
+        }
+        if summary.phase != "Succeeded" && !(summary.phase == "Running" && ready) {
+            health.unhealthy.push(summary);
+        }
+    }
+    health.unhealthy.sort_by(|a, b| b.restarts.cmp(&a.restarts).then_with(|| a.name.cmp(&b.name)));
+    health
+}
+
+// One row per resource a quota limits, fullest first
+pub fn kuboard_quota_usage(quotas: &[ResourceQuota]) -> Vec<QuotaUsage> {
+    let mut usage: Vec<QuotaUsage> = quotas.iter()
+        .flat_map(|quota| {
+            let name = quota.metadata.name.clone().unwrap_or_default();
+            let status = quota.status.as_ref();
+            let used = status.and_then(|s| s.used.as_ref());
+            status.and_then(|s| s.hard.as_ref()).into_iter().flatten().map(move |(resource, hard)| {
+                let used = used.and_then(|used| used.get(resource)).map_or("0", |q| q.0.as_str());
+                let percent = match (kuboard_parse_quantity(used), kuboard_parse_quantity(&hard.0)) {
+                    (Ok(used), Ok(hard)) if hard > 0.0 => Some(kuboard_quantity_percent(used, hard)),
+                    _ => None,
+                };
+                QuotaUsage { quota: name.clone(), resource: resource.clone(), hard: hard.0.clone(), used: used.to_string(), percent }
+            })
+        })
+        .collect();
+    usage.sort_by(|a, b| b.percent.unwrap_or(-1.0).total_cmp(&a.percent.unwrap_or(-1.0)));
+    usage
+}
+
+// The heaviest pods by CPU and by memory
+pub fn kuboard_top_consumers(metrics: &[PodMetrics], limit: usize) -> (Vec<ResourceConsumer>, Vec<ResourceConsumer>) {
+    let consumers: Vec<ResourceConsumer> = metrics.iter()
+        .map(|pod| ResourceConsumer {
+            pod: pod.metadata.name.clone(),
+            cpu_millicores: pod.containers.iter()
+                .map(|c| kuboard_parse_cpu(&c.usage.cpu).map(|cores| (cores * 1000.0).round() as u64).unwrap_or(0))
+                .sum(),
+            memory_bytes: pod.containers.iter().map(|c| kuboard_parse_bytes(&c.usage.memory).unwrap_or(0)).sum(),
+        })
+        .collect();
+    let mut by_cpu = consumers.clone();
+    by_cpu.sort_by(|a, b| b.cpu_millicores.cmp(&a.cpu_millicores).then_with(|| a.pod.cmp(&b.pod)));
+    by_cpu.truncate(limit);
+    let mut by_memory = consumers;
+    by_memory.sort_by(|a, b| b.memory_bytes.cmp(&a.memory_bytes).then_with(|| a.pod.cmp(&b.pod)));
+    by_memory.truncate(limit);
+    (by_cpu, by_memory)
+}
+
+pub fn kuboard_recent_warnings(events: &[Event], limit: usize) -> Vec<WarningToast> {
+    let mut warnings: Vec<WarningToast> = events.iter()
+        .filter(|event| event.type_.as_deref() == Some("Warning"))
+        .map(kuboard_warning_toast)
+        .collect();
+    warnings.sort_by(|a, b| b.last_seen.cmp(&a.last_seen));
+    warnings.truncate(limit);
+    warnings
+}
+
+async fn list<K>(client: &Client, namespace: &str, params: &ListParams) -> Result<Vec<K>>
+where
+    K: Resource<Scope = NamespaceResourceScope> + Clone + DeserializeOwned + Debug,
+    K::DynamicType: Default,
+{
+    Ok(Api::<K>::namespaced(client.clone(), namespace).list(params).await?.items)
+}
+
+pub async fn kuboard_namespace_detail(client: &Client, name: &str) -> Result<NamespaceDetail> {
+    let namespace = match Api::<Namespace>::all(client.clone()).get_opt(name).await {
+        Ok(Some(namespace)) => namespace,
+        Ok(None) => return Err(anyhow!("Namespace {} not found", name)),
+        // Users limited to the namespace may not read the Namespace object itself
+        Err(kube::Error::Api(e)) if e.code == 403 => Namespace::default(),
+        Err(e) => return Err(e.into()),
+    };
+    let all = ListParams::default();
+
+    let deployments: Vec<Deployment> = list(client, name, &all).await?;
+    let statefulsets: Vec<StatefulSet> = list(client, name, &all).await?;
+    let daemonsets: Vec<DaemonSet> = list(client, name, &all).await?;
+    let workloads = NamespaceWorkloadCounts {
+        deployments: deployments.len(),
+        statefulsets: statefulsets.len(),
+        daemonsets: daemonsets.len(),
+        jobs: list::<Job>(client, name, &all).await?.len(),
+        cronjobs: list::<CronJob>(client, name, &all).await?.len(),
+        services: list::<Service>(client, name, &all).await?.len(),
+    };
+    let degraded_workloads = deployments.iter().map(kuboard_deployment_summary)
+        .chain(statefulsets.iter().map(kuboard_statefulset_summary))
+        .chain(daemonsets.iter().map(kuboard_daemonset_summary))
+        .filter(|summary| summary.health != WorkloadHealth::Healthy)
+        .collect();
+
+    let pods: Vec<Pod> = list(client, name, &all).await?;
+    let quotas: Vec<ResourceQuota> = list(client, name, &all).await?;
+    let events: Vec<Event> = list(client, name, &ListParams::default().fields("type=Warning")).await?;
+
+    let (top_cpu, top_memory, metrics_available) = match get_namespace_pod_metrics(client, name).await {
+        Ok(metrics) => {
+            let (cpu, memory) = kuboard_top_consumers(&metrics.items, NAMESPACE_TOP_CONSUMERS);
+            (cpu, memory, true)
+        }
+        Err(e) => {
+            warn!("Pod metrics unavailable for namespace {}: {}", name, e);
+            (Vec::new(), Vec::new(), false)
+        }
+    };
+
+    Ok(NamespaceDetail {
+        name: name.to_string(),
+        phase: namespace.status.as_ref().and_then(|s| s.phase.clone()),
+        labels: namespace.metadata.labels.clone().unwrap_or_default(),
+        age: namespace.metadata.creation_timestamp.as_ref().map(|t| kuboard_format_age(t.0)),
+        workloads,
+        degraded_workloads,
+        pods: kuboard_namespace_pod_health(&pods),
+        quotas: kuboard_quota_usage(&quotas),
+        recent_warnings: kuboard_recent_warnings(&events, NAMESPACE_RECENT_WARNINGS),
+        top_cpu,
+        top_memory,
+        metrics_available,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_namespace_pod_health() {
+        let pods: Vec<Pod> = serde_json::from_value(json!([
+            {
+                "metadata": { "name": "web-1" },
+                "spec": { "containers": [{ "name": "web" }] },
+                "status": { "phase": "Running", "containerStatuses": [{ "name": "web", "ready": true, "restartCount": 1, "image": "", "imageID": "" }] },
+            },
+            {
+                "metadata": { "name": "web-2" },
+                "spec": { "containers": [{ "name": "web" }] },
+                "status": { "phase": "Running", "containerStatuses": [{
+                    "name": "web", "ready": false, "restartCount": 7, "image": "", "imageID": "",
+                    "state": { "waiting": { "reason": "CrashLoopBackOff" } },
+                }] },
+            },
+            { "metadata": { "name": "migrate" }, "spec": { "containers": [{ "name": "job" }] }, "status": { "phase": "Succeeded" } },
+            { "metadata": { "name": "queued" }, "spec": { "containers": [{ "name": "job" }] }, "status": { "phase": "Pending" } },
+        ])).unwrap();
+
+        let health = kuboard_namespace_pod_health(&pods);
+        assert_eq!((health.total, health.running, health.not_ready, health.succeeded, health.pending), (4, 1, 1, 1, 1));
+        assert_eq!(health.restarts, 8);
+        let unhealthy: Vec<(&str, &str)> = health.unhealthy.iter().map(|p| (p.name.as_str(), p.status.as_str())).collect();
+        assert_eq!(unhealthy, vec![("web-2", "CrashLoopBackOff"), ("queued", "Pending")]);
+    }
+
+    #[test]
+    fn test_quota_usage() {
+        let quotas: Vec<ResourceQuota> = serde_json::from_value(json!([{
+            "metadata": { "name": "compute" },
+            "status": {
+                "hard": { "requests.cpu": "4", "requests.memory": "8Gi", "pods": "20", "services.loadbalancers": "0" },
+                "used": { "requests.cpu": "3500m", "requests.memory": "2Gi", "pods": "5" },
+            },
+        }])).unwrap();
+
+        let usage = kuboard_quota_usage(&quotas);
+        let rows: Vec<(&str, &str, Option<f64>)> = usage.iter().map(|u| (u.resource.as_str(), u.used.as_str(), u.percent)).collect();
+        assert_eq!(rows, vec![
+            ("requests.cpu", "3500m", Some(87.5)),
+            ("pods", "5", Some(25.0)),
+            ("requests.memory", "2Gi", Some(25.0)),
+            ("services.loadbalancers", "0", None),
+        ]);
+    }
+
+    #[test]
+    fn test_top_consumers() {
+        let metrics: Vec<PodMetrics> = serde_json::from_value(json!([
+            { "metadata": { "name": "api", "namespace": "shop" }, "timestamp": "", "window": "30s", "containers": [
+                { "name": "api", "usage": { "cpu": "250m", "memory": "300Mi" } },
+                { "name": "sidecar", "usage": { "cpu": "50m", "memory": "20Mi" } },
+            ] },
+            { "metadata": { "name": "cache", "namespace": "shop" }, "timestamp": "", "window": "30s", "containers": [
+                { "name": "redis", "usage": { "cpu": "10m", "memory": "1Gi" } },
+            ] },
+        ])).unwrap();
+
+        let (cpu, memory) = kuboard_top_consumers(&metrics, 1);
+        assert_eq!((cpu[0].pod.as_str(), cpu[0].cpu_millicores), ("api", 300));
+        assert_eq!((memory[0].pod.as_str(), memory[0].memory_bytes), ("cache", 1024 * 1024 * 1024));
+    }
+}
@@ -0,0 +1,144 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Node Shell
+// Opens a host shell on a node through a privileged debug pod (nsenter into PID 1)
+
+use anyhow::{anyhow, Result};
+use kube::api::{DeleteParams, PostParams};
+use kube::runtime::wait::{await_condition, conditions::is_pod_running};
+use kube::{Api, Client};
+use k8s_openapi::api::core::v1::{Node, Pod};
+use serde_json::json;
+use std::time::Duration;
+use tracing::{info, warn};
+
+use crate::kubernetes::exec::{start_exec_session, ExecSession};
+
+pub const NODE_SHELL_DEFAULT_IMAGE: &str = "busybox:1.36";
+pub const NODE_SHELL_LABEL: &str = "kuboard.io/node-shell";
+const NODE_SHELL_START_TIMEOUT: Duration = Duration::from_secs(60);
+// The debug pod removes itself after this long even if the app never cleans it up
+const NODE_SHELL_MAX_LIFETIME_SECONDS: i64 = 4 * 60 * 60;
+
+// The debug pod behind a node shell, with the client of the cluster it was created in
+// so it is deleted there even after the active context changes
+#[derive(Clone)]
+pub struct NodeShellPod {
+    pub name: String,
+    pub client: Client,
+}
+
+// Enters every host namespace of the node's init process
+pub fn kuboard_node_shell_command() -> Vec<String> {
+    ["nsenter", "--target", "1", "--mount", "--uts", "--ipc", "--net", "--pid", "--", "sh", "-l"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect()
+}
+
+pub fn kuboard_node_shell_pod(node_name: &str, image: &str) -> Result<Pod> {
+    Ok(serde_json::from_value(json!({
+        "apiVersion": "v1",
+        "kind": "Pod",
+        "metadata": {
+            "generateName": "kuboard-node-shell-",
+            "labels": {
+                "app.kubernetes.io/managed-by": "kuboard",
+                NODE_SHELL_LABEL: node_name,
+            },
+        },
+        "spec": {
+            "nodeName": node_name,
+            "hostPID": true,
+            "hostNetwork": true,
+            "hostIPC": true,
+            "restartPolicy": "Never",
+            "terminationGracePeriodSeconds": 0,
+            "activeDeadlineSeconds": NODE_SHELL_MAX_LIFETIME_SECONDS,
+            // Must land on the chosen node regardless of taints
+            "tolerations": [{ "operator": "Exists" }],
+            "containers": [{
+                "name": "shell",
+                "image": image,
+                "command": ["sleep", NODE_SHELL_MAX_LIFETIME_SECONDS.to_string()],
+                "stdin": true,
+                "tty": true,
+                "securityContext": { "privileged": true },
+            }],
+        },
+    }))?)
+}
+
+// Creates the debug pod and a session for it; the caller attaches kuboard_node_shell_command()
+pub async fn start_node_shell(
+    client: &Client,
+    node_name: &str,
+    namespace: &str,
+    image: Option<&str>,
+) -> Result<ExecSession> {
+    let nodes_api: Api<Node> = Api::all(client.clone());
+    nodes_api.get(node_name).await
+        .map_err(|e| anyhow!("Node {} not found: {}", node_name, e))?;
+
+    let pods_api: Api<Pod> = Api::namespaced(client.clone(), namespace);
+    let pod = kuboard_node_shell_pod(node_name, image.unwrap_or(NODE_SHELL_DEFAULT_IMAGE))?;
+    let created = pods_api.create(&PostParams::default(), &pod).await?;
+    let pod_name = created.metadata.name.ok_or_else(|| anyhow!("Created node shell pod has no name"))?;
+    info!("Created node shell pod {}/{} on node {}", namespace, pod_name, node_name);
+
+    let running = tokio::time::timeout(
+        NODE_SHELL_START_TIMEOUT,
+        await_condition(pods_api.clone(), &pod_name, is_pod_running()),
+    ).await;
+    let started = match running {
+        Ok(Ok(_)) => start_exec_session(client, &pod_name, namespace, Some("shell")).await,
+        Ok(Err(e)) => Err(anyhow!("Failed waiting for node shell pod: {}", e)),
+        Err(_) => Err(anyhow!("Node shell pod did not start within {}s", NODE_SHELL_START_TIMEOUT.as_secs())),
+    };
+
+    match started {
+        Ok(mut session) => {
+            session.node_shell = Some(NodeShellPod { name: pod_name, client: client.clone() });
+            Ok(session)
+        }
+        Err(e) => {
+            // Don't leave a privileged pod behind when the shell can't be used
+            delete_node_shell_pod(client, namespace, &pod_name).await;
+            Err(e)
+        }
+    }
+}
+
+async fn delete_node_shell_pod(client: &Client, namespace: &str, pod_name: &str) {
+    let pods_api: Api<Pod> = Api::namespaced(client.clone(), namespace);
+    match pods_api.delete(pod_name, &DeleteParams::default().grace_period(0)).await {
+        Ok(_) => info!("Deleted node shell pod {}/{}", namespace, pod_name),
+        Err(kube::Error::Api(e)) if e.code == 404 => {}
+        Err(e) => warn!("Failed to delete node shell pod {}/{}: {}", namespace, pod_name, e),
+    }
+}
+
+// Removes the debug pod backing a node shell session, if it has one
+pub async fn cleanup_node_shell(session: &ExecSession) {
+    if let Some(pod) = &session.node_shell {
+        delete_node_shell_pod(&pod.client, &session.namespace, &pod.name).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_node_shell_pod_is_pinned_and_privileged() {
+        let pod = kuboard_node_shell_pod("worker-1", NODE_SHELL_DEFAULT_IMAGE).unwrap();
+        let spec = pod.spec.unwrap();
+
+        assert_eq!(spec.node_name.as_deref(), Some("worker-1"));
+        assert_eq!(spec.host_pid, Some(true));
+        assert_eq!(spec.containers[0].security_context.as_ref().and_then(|s| s.privileged), Some(true));
+        assert_eq!(spec.tolerations.unwrap()[0].operator.as_deref(), Some("Exists"));
+        assert_eq!(pod.metadata.labels.unwrap()[NODE_SHELL_LABEL], "worker-1");
+    }
+}
@@ -0,0 +1,375 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Kubernetes Incident Notifier
+// Opt-in desktop notifications for watch-driven incidents
+
+use kube::{Api, Client};
+use kube::runtime::watcher;
+use k8s_openapi::api::core::v1::{Node, Pod};
+use k8s_openapi::api::apps::v1::Deployment;
+use serde::{Serialize, Deserialize};
+use std::collections::HashSet;
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_notification::NotificationExt;
+use tokio::sync::{mpsc, RwLock};
+use tokio::task::JoinHandle;
+use tokio_stream::StreamExt;
+use tracing::{error, info, warn};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IncidentRule {
+    PodCrashLoopBackOff,
+    NodeNotReady,
+    DeploymentRolloutFailed,
+}
+
+impl IncidentRule {
+    pub fn all() -> [IncidentRule; 3] {
+        [
+            IncidentRule::PodCrashLoopBackOff,
+            IncidentRule::NodeNotReady,
+            IncidentRule::DeploymentRolloutFailed,
+        ]
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            IncidentRule::PodCrashLoopBackOff => "Pod in CrashLoopBackOff",
+            IncidentRule::NodeNotReady => "Node NotReady",
+            IncidentRule::DeploymentRolloutFailed => "Deployment rollout failed",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Incident {
+    pub rule: IncidentRule,
+    pub kind: String,
+    pub name: String,
+    pub namespace: Option<String>,
+    pub message: String,
+    pub timestamp: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotifierRuleStatus {
+    pub rule: IncidentRule,
+    pub muted: bool,
+}
+
+// Returns the crashing container name if any container is in CrashLoopBackOff
+pub fn pod_crash_loop_container(pod: &Pod) -> Option<String> {
+    pod.status.as_ref()
+        .and_then(|s| s.container_statuses.as_ref())
+        .and_then(|statuses| statuses.iter().find(|cs| {
+            cs.state.as_ref()
+                .and_then(|st| st.waiting.as_ref())
+                .and_then(|w| w.reason.as_deref())
+                == Some("CrashLoopBackOff")
+        }))
+        .map(|cs| cs.name.clone())
+}
+
+pub fn node_is_not_ready(node: &Node) -> bool {
+    !node.status.as_ref()
+        .and_then(|s| s.conditions.as_ref())
+        .map(|conditions| conditions.iter().any(|c| c.type_ == "Ready" && c.status == "True"))
+        .unwrap_or(false)
+}
+
+// A rollout has failed once the Progressing condition reports ProgressDeadlineExceeded
+pub fn deployment_rollout_failure(deployment: &Deployment) -> Option<String> {
+    deployment.status.as_ref()
+        .and_then(|s| s.conditions.as_ref())
+        .and_then(|conditions| conditions.iter().find(|c| {
+            c.type_ == "Progressing"
+                && c.status == "False"
+                && c.reason.as_deref() == Some("ProgressDeadlineExceeded")
+        }))
+        .map(|c| c.message.clone().unwrap_or_else(|| "Progress deadline exceeded".to_string()))
+}
+
+// Tracks which incidents are currently firing so each one is only raised once
+// until the condition clears
+struct IncidentTracker {
+    firing: HashSet<String>,
+    muted: Arc<RwLock<HashSet<IncidentRule>>>,
+}
+
+impl IncidentTracker {
+    // Returns the incident to raise, if this update starts one
+    async fn update(&mut self, rule: IncidentRule, kind: &str, name: &str, namespace: Option<&str>, message: Option<String>) -> Option<Incident> {
+        let key = format!("{:?}/{}/{}", rule, namespace.unwrap_or(""), name);
+
+        let Some(message) = message else {
+            self.firing.remove(&key);
+            return None;
+        };
+
+        // A muted incident isn't recorded as firing, so it is raised if still firing after an unmute
+        if self.muted.read().await.contains(&rule) {
+            info!("Incident suppressed by muted rule {:?}: {}", rule, name);
+            return None;
+        }
+
+        if !self.firing.insert(key) {
+            return None;
+        }
+
+        Some(Incident {
+            rule,
+            kind: kind.to_string(),
+            name: name.to_string(),
+            namespace: namespace.map(|ns| ns.to_string()),
+            message,
+            timestamp: chrono::Utc::now().timestamp(),
+        })
+    }
+
+    fn clear(&mut self, rule: IncidentRule, name: &str, namespace: Option<&str>) {
+        let key = format!("{:?}/{}/{}", rule, namespace.unwrap_or(""), name);
+        self.firing.remove(&key);
+    }
+}
+
+fn raise_incident(app_handle: &AppHandle, incident: Incident) {
+    warn!("Incident raised: {} {} - {}", incident.rule.title(), incident.name, incident.message);
+
+    let subject = match incident.namespace.as_ref() {
+        Some(ns) => format!("{} {}/{}", incident.kind, ns, incident.name),
+        None => format!("{} {}", incident.kind, incident.name),
+    };
+
+    if let Err(e) = app_handle.notification()
+        .builder()
+        .title(incident.rule.title())
+        .body(format!("{}: {}", subject, incident.message))
+        .show()
+    {
+        error!("Failed to show desktop notification: {}", e);
+    }
+
+    if let Err(e) = app_handle.emit("incident-event", incident) {
+        error!("Failed to emit incident event: {}", e);
+    }
+}
+
+pub struct IncidentNotifier {
+    handle: Option<JoinHandle<()>>,
+    stop_tx: Option<mpsc::Sender<()>>,
+    muted: Arc<RwLock<HashSet<IncidentRule>>>,
+}
+
+impl IncidentNotifier {
+    pub fn new() -> Self {
+        Self {
+            handle: None,
+            stop_tx: None,
+            muted: Arc::new(RwLock::new(HashSet::new())),
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.handle.is_some()
+    }
+
+    pub async fn rules(&self) -> Vec<NotifierRuleStatus> {
+        let muted = self.muted.read().await;
+        IncidentRule::all()
+            .iter()
+            .map(|rule| NotifierRuleStatus {
+                rule: *rule,
+                muted: muted.contains(rule),
+            })
+            .collect()
+    }
+
+    pub async fn set_muted(&self, rule: IncidentRule, muted: bool) {
+        let mut muted_rules = self.muted.write().await;
+        if muted {
+            muted_rules.insert(rule);
+        } else {
+            muted_rules.remove(&rule);
+        }
+    }
+
+    pub fn stop(&mut self) {
+        if let Some(tx) = self.stop_tx.take() {
+            let _ = tx.try_send(());
+        }
+        if let Some(handle) = self.handle.take() {
+            handle.abort();
+        }
+    }
+
+    pub async fn start(
+        &mut self,
+        client: Client,
+        app_handle: AppHandle,
+    ) -> Result<(), String> {
+        self.stop();
+
+        info!("Starting incident notifier");
+
+        let pods_api: Api<Pod> = Api::all(client.clone());
+        let nodes_api: Api<Node> = Api::all(client.clone());
+        let deployments_api: Api<Deployment> = Api::all(client);
+        let (stop_tx, mut stop_rx) = mpsc::channel::<()>(1);
+        let mut tracker = IncidentTracker {
+            firing: HashSet::new(),
+            muted: self.muted.clone(),
+        };
+
+        let handle = tokio::spawn(async move {
+            let pod_stream = watcher(pods_api, Default::default());
+            let node_stream = watcher(nodes_api, Default::default());
+            let deployment_stream = watcher(deployments_api, Default::default());
+            tokio::pin!(pod_stream);
+            tokio::pin!(node_stream);
+            tokio::pin!(deployment_stream);
+
+            info!("Incident notifier started, listening for events");
+
+            loop {
+                tokio::select! {
+                    _ = stop_rx.recv() => {
+                        info!("Incident notifier stopped by user");
+                        break;
+                    }
+                    Some(result) = pod_stream.next() => {
+                        match result {
+                            Ok(watcher::Event::Apply(pod)) | Ok(watcher::Event::InitApply(pod)) => {
+                                let name = pod.metadata.name.clone().unwrap_or_default();
+                                let message = pod_crash_loop_container(&pod)
+                                    .map(|container| format!("Container {} is in CrashLoopBackOff", container));
+                                if let Some(incident) = tracker.update(IncidentRule::PodCrashLoopBackOff, "Pod", &name, pod.metadata.namespace.as_deref(), message).await {
+                                    raise_incident(&app_handle, incident);
+                                }
+                            }
+                            Ok(watcher::Event::Delete(pod)) => {
+                                let name = pod.metadata.name.clone().unwrap_or_default();
+                                tracker.clear(IncidentRule::PodCrashLoopBackOff, &name, pod.metadata.namespace.as_deref());
+                            }
+                            Ok(_) => {}
+                            Err(e) => warn!("Incident notifier pod watch error: {}", e),
+                        }
+                    }
+                    Some(result) = node_stream.next() => {
+                        match result {
+                            Ok(watcher::Event::Apply(node)) | Ok(watcher::Event::InitApply(node)) => {
+                                let name = node.metadata.name.clone().unwrap_or_default();
+                                let message = if node_is_not_ready(&node) {
+                                    Some("Node is not reporting Ready".to_string())
+                                } else {
+                                    None
+                                };
+                                if let Some(incident) = tracker.update(IncidentRule::NodeNotReady, "Node", &name, None, message).await {
+                                    raise_incident(&app_handle, incident);
+                                }
+                            }
+                            Ok(watcher::Event::Delete(node)) => {
+                                let name = node.metadata.name.clone().unwrap_or_default();
+                                tracker.clear(IncidentRule::NodeNotReady, &name, None);
+                            }
+                            Ok(_) => {}
+                            Err(e) => warn!("Incident notifier node watch error: {}", e),
+                        }
+                    }
+                    Some(result) = deployment_stream.next() => {
+                        match result {
+                            Ok(watcher::Event::Apply(deployment)) | Ok(watcher::Event::InitApply(deployment)) => {
+                                let name = deployment.metadata.name.clone().unwrap_or_default();
+                                let message = deployment_rollout_failure(&deployment);
+                                if let Some(incident) = tracker.update(IncidentRule::DeploymentRolloutFailed, "Deployment", &name, deployment.metadata.namespace.as_deref(), message).await {
+                                    raise_incident(&app_handle, incident);
+                                }
+                            }
+                            Ok(watcher::Event::Delete(deployment)) => {
+                                let name = deployment.metadata.name.clone().unwrap_or_default();
+                                tracker.clear(IncidentRule::DeploymentRolloutFailed, &name, deployment.metadata.namespace.as_deref());
+                            }
+                            Ok(_) => {}
+                            Err(e) => warn!("Incident notifier deployment watch error: {}", e),
+                        }
+                    }
+                    else => {
+                        warn!("Incident notifier streams ended");
+                        break;
+                    }
+                }
+            }
+
+            info!("Incident notifier task completed");
+        });
+
+        self.handle = Some(handle);
+        self.stop_tx = Some(stop_tx);
+
+        Ok(())
+    }
+}
+
+impl Drop for IncidentNotifier {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tracker() -> IncidentTracker {
+        IncidentTracker { firing: HashSet::new(), muted: Arc::new(RwLock::new(HashSet::new())) }
+    }
+
+    fn crash(message: &str) -> Option<String> {
+        Some(message.to_string())
+    }
+
+    #[tokio::test]
+    async fn test_incident_raised_once_while_firing() {
+        let mut tracker = tracker();
+        let rule = IncidentRule::PodCrashLoopBackOff;
+
+        let incident = tracker.update(rule, "Pod", "api-0", Some("shop"), crash("Container app is in CrashLoopBackOff")).await.unwrap();
+        assert_eq!((incident.kind.as_str(), incident.name.as_str()), ("Pod", "api-0"));
+        assert_eq!(incident.namespace.as_deref(), Some("shop"));
+        assert!(tracker.update(rule, "Pod", "api-0", Some("shop"), crash("still crashing")).await.is_none());
+
+        // The same pod name in another namespace is a separate incident
+        assert!(tracker.update(rule, "Pod", "api-0", Some("staging"), crash("crashing")).await.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_incident_raised_again_after_resolving() {
+        let mut tracker = tracker();
+        let rule = IncidentRule::NodeNotReady;
+
+        assert!(tracker.update(rule, "Node", "node-1", None, crash("Node is not reporting Ready")).await.is_some());
+        assert!(tracker.update(rule, "Node", "node-1", None, None).await.is_none());
+        assert!(tracker.update(rule, "Node", "node-1", None, crash("Node is not reporting Ready")).await.is_some());
+
+        tracker.clear(rule, "node-1", None);
+        assert!(tracker.update(rule, "Node", "node-1", None, crash("Node is not reporting Ready")).await.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_muted_incident_raised_after_unmute() {
+        let mut tracker = tracker();
+        let rule = IncidentRule::DeploymentRolloutFailed;
+        tracker.muted.write().await.insert(rule);
+
+        assert!(tracker.update(rule, "Deployment", "web", Some("shop"), crash("Progress deadline exceeded")).await.is_none());
+        // Other rules still fire while one is muted
+        assert!(tracker.update(IncidentRule::NodeNotReady, "Node", "node-1", None, crash("not ready")).await.is_some());
+
+        tracker.muted.write().await.remove(&rule);
+        let incident = tracker.update(rule, "Deployment", "web", Some("shop"), crash("Progress deadline exceeded")).await.unwrap();
+        assert_eq!(incident.rule, rule);
+        assert!(tracker.update(rule, "Deployment", "web", Some("shop"), crash("Progress deadline exceeded")).await.is_none());
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Pagination
+// limit/continue listing: one page at a time behind a cursor for the frontend, or every page
+// drained in bounded requests so large lists don't hit API server timeouts
+
+use anyhow::{anyhow, Result};
+use kube::api::{ListParams, ObjectList};
+use kube::Api;
+use serde::de::DeserializeOwned;
+use std::fmt::Debug;
+
+use crate::types::ListPage;
+
+pub const DEFAULT_PAGE_SIZE: u32 = 500;
+pub const MAX_PAGE_SIZE: u32 = 5000;
+
+// No limit means the default page size; zero or oversized limits are clamped
+pub fn kuboard_page_size(limit: Option<u32>) -> u32 {
+    limit.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE)
+}
+
+fn next_token<K>(list: &ObjectList<K>) -> Option<String>
+where
+    K: Clone,
+{
+    list.metadata.continue_.clone().filter(|token| !token.is_empty())
+}
+
+// One page; `continue_token` is the previous page's token, none for the first page
+pub async fn kuboard_list_page<K>(api: &Api<K>, params: &ListParams, limit: Option<u32>, continue_token: Option<&str>) -> Result<ListPage<K>>
+where
+    K: Clone + DeserializeOwned + Debug,
+{
+    let mut params = params.clone().limit(kuboard_page_size(limit));
+    if let Some(token) = continue_token.filter(|token| !token.is_empty()) {
+        params = params.continue_token(token);
+    }
+    let list = match api.list(&params).await {
+        Ok(list) => list,
+        // Tokens expire with the resource version they were issued at, after about five minutes
+        Err(kube::Error::Api(e)) if e.code == 410 => {
+            return Err(anyhow!("The continue token has expired; list again from the first page"));
+        }
+        Err(e) => return Err(e.into()),
+    };
+    Ok(ListPage {
+        continue_token: next_token(&list),
+        remaining_item_count: list.metadata.remaining_item_count,
+        items: list.items,
+    })
+}
+
+// Every page, fetched `DEFAULT_PAGE_SIZE` items at a time. Errors are kube's own so callers can
+// still tell a forbidden list apart
+pub async fn kuboard_list_all_pages<K>(api: &Api<K>, params: &ListParams) -> kube::Result<Vec<K>>
+where
+    K: Clone + DeserializeOwned + Debug,
+{
+    let mut params = params.clone().limit(DEFAULT_PAGE_SIZE);
+    let mut items = Vec::new();
+    loop {
+        let list = api.list(&params).await?;
+        let token = next_token(&list);
+        items.extend(list.items);
+        match token {
+            Some(token) => params.continue_token = Some(token),
+            None => return Ok(items),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_size() {
+        assert_eq!(kuboard_page_size(None), DEFAULT_PAGE_SIZE);
+        assert_eq!(kuboard_page_size(Some(0)), 1);
+        assert_eq!(kuboard_page_size(Some(50)), 50);
+        assert_eq!(kuboard_page_size(Some(100_000)), MAX_PAGE_SIZE);
+    }
+}
@@ -0,0 +1,169 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Persistent Volumes
+// Cluster-scoped volumes with their reclaim policy and the claim they are reserved for, and the
+// PVC ↔ PV ↔ Pod chain of one volume with the point where it breaks
+
+use k8s_openapi::api::core::v1::{PersistentVolume, PersistentVolumeClaim, PersistentVolumeSpec, Pod};
+
+use crate::kubernetes::pvc::kuboard_pvc_summary;
+use crate::types::{PvSummary, VolumeBinding};
+use crate::utils::kuboard_format_age;
+
+// The volume plugin backing the volume; CSI volumes are named by driver
+pub fn kuboard_pv_source(spec: &PersistentVolumeSpec) -> Option<String> {
+    let plugins = [
+        (spec.host_path.is_some(), "hostPath"),
+        (spec.local.is_some(), "local"),
+        (spec.nfs.is_some(), "nfs"),
+        (spec.iscsi.is_some(), "iscsi"),
+        (spec.fc.is_some(), "fc"),
+        (spec.rbd.is_some(), "rbd"),
+        (spec.cephfs.is_some(), "cephfs"),
+        (spec.aws_elastic_block_store.is_some(), "awsElasticBlockStore"),
+        (spec.gce_persistent_disk.is_some(), "gcePersistentDisk"),
+        (spec.azure_disk.is_some(), "azureDisk"),
+        (spec.azure_file.is_some(), "azureFile"),
+    ];
+    match &spec.csi {
+        Some(csi) => Some(format!("csi:{}", csi.driver)),
+        None => plugins.iter().find(|(set, _)| *set).map(|(_, name)| name.to_string()),
+    }
+}
+
+pub fn kuboard_pv_summary(volume: &PersistentVolume) -> PvSummary {
+    let spec = volume.spec.as_ref();
+    let status = volume.status.as_ref();
+    PvSummary {
+        name: volume.metadata.name.clone().unwrap_or_default(),
+        phase: status.and_then(|s| s.phase.clone()),
+        capacity: spec.and_then(|s| s.capacity.as_ref()).and_then(|c| c.get("storage")).map(|q| q.0.clone()),
+        access_modes: spec.and_then(|s| s.access_modes.clone()).unwrap_or_default(),
+        reclaim_policy: spec.and_then(|s| s.persistent_volume_reclaim_policy.clone()),
+        storage_class: spec.and_then(|s| s.storage_class_name.clone()),
+        volume_mode: spec.and_then(|s| s.volume_mode.clone()),
+        claim: spec.and_then(|s| s.claim_ref.as_ref()).map(|claim| {
+            format!("{}/{}", claim.namespace.as_deref().unwrap_or_default(), claim.name.as_deref().unwrap_or_default())
+        }),
+        source: spec.and_then(kuboard_pv_source),
+        reason: status.and_then(|s| s.reason.clone()),
+        message: status.and_then(|s| s.message.clone()),
+        age: volume.metadata.creation_timestamp.as_ref().map(|t| kuboard_format_age(t.0)),
+    }
+}
+
+// `claim` is the one the volume's claimRef names, if it still exists; `pods` are from its namespace
+pub fn kuboard_volume_binding(volume: &PersistentVolume, claim: Option<&PersistentVolumeClaim>, pods: &[Pod]) -> VolumeBinding {
+    let summary = kuboard_pv_summary(volume);
+    let claim_ref = volume.spec.as_ref().and_then(|s| s.claim_ref.as_ref());
+    let mut issues = Vec::new();
+
+    if summary.phase.as_deref() == Some("Failed") {
+        issues.push(format!("Volume failed: {}", summary.message.as_deref().unwrap_or("no message")));
+    }
+    match (&summary.claim, claim) {
+        (None, _) => {}
+        (Some(reserved), None) => {
+            let mut issue = format!("Claim {} no longer exists", reserved);
+            if summary.phase.as_deref() == Some("Released") && summary.reclaim_policy.as_deref() == Some("Retain") {
+                issue.push_str("; the data is retained, and the volume can't be bound again until its claimRef is removed");
+            }
+            issues.push(issue);
+        }
+        (Some(reserved), Some(claim)) => {
+            let recreated = claim_ref.and_then(|r| r.uid.as_deref())
+                .is_some_and(|uid| claim.metadata.uid.as_deref() != Some(uid));
+            let claim_volume = claim.spec.as_ref().and_then(|s| s.volume_name.as_deref());
+            if recreated {
+                issues.push(format!("Claim {} was recreated after the volume was bound to the previous one", reserved));
+            } else if claim_volume.is_none() {
+                issues.push(format!("Claim {} is not bound yet", reserved));
+            } else if claim_volume != Some(summary.name.as_str()) {
+                issues.push(format!("Claim {} is bound to volume {}", reserved, claim_volume.unwrap_or_default()));
+            }
+        }
+    }
+
+    let claim = claim.map(|claim| kuboard_pvc_summary(claim, std::slice::from_ref(volume), pods));
+    for mount in claim.iter().flat_map(|c| &c.mounted_by) {
+        if mount.phase.as_deref() != Some("Running") && mount.phase.as_deref() != Some("Succeeded") {
+            issues.push(format!("Pod {} is {}", mount.pod, mount.phase.as_deref().unwrap_or("Unknown")));
+        }
+    }
+
+    VolumeBinding {
+        bound: summary.phase.as_deref() == Some("Bound") && claim.is_some() && issues.is_empty(),
+        volume: summary,
+        claim,
+        issues,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn volume(claim_ref: serde_json::Value, phase: &str) -> PersistentVolume {
+        serde_json::from_value(json!({
+            "metadata": { "name": "pv-data" },
+            "spec": {
+                "capacity": { "storage": "10Gi" },
+                "persistentVolumeReclaimPolicy": "Retain",
+                "csi": { "driver": "ebs.csi.aws.com", "volumeHandle": "vol-1" },
+                "claimRef": claim_ref,
+            },
+            "status": { "phase": phase },
+        })).unwrap()
+    }
+
+    fn claim(uid: &str, volume_name: Option<&str>) -> PersistentVolumeClaim {
+        serde_json::from_value(json!({
+            "metadata": { "name": "data", "namespace": "prod", "uid": uid },
+            "spec": { "volumeName": volume_name },
+        })).unwrap()
+    }
+
+    #[test]
+    fn test_pv_summary() {
+        let summary = kuboard_pv_summary(&volume(json!({ "namespace": "prod", "name": "data" }), "Bound"));
+        assert_eq!(summary.source.as_deref(), Some("csi:ebs.csi.aws.com"));
+        assert_eq!(summary.claim.as_deref(), Some("prod/data"));
+        assert_eq!((summary.capacity.as_deref(), summary.reclaim_policy.as_deref()), (Some("10Gi"), Some("Retain")));
+
+        let spec: PersistentVolumeSpec = serde_json::from_value(json!({ "hostPath": { "path": "/data" } })).unwrap();
+        assert_eq!(kuboard_pv_source(&spec).as_deref(), Some("hostPath"));
+        assert_eq!(kuboard_pv_source(&PersistentVolumeSpec::default()), None);
+    }
+
+    #[test]
+    fn test_volume_binding() {
+        let claim_ref = json!({ "namespace": "prod", "name": "data", "uid": "uid-1" });
+        let pods: Vec<Pod> = serde_json::from_value(json!([{
+            "metadata": { "name": "db-0" },
+            "spec": { "containers": [], "volumes": [{ "name": "data", "persistentVolumeClaim": { "claimName": "data" } }] },
+            "status": { "phase": "Pending" },
+        }])).unwrap();
+
+        let bound = kuboard_volume_binding(&volume(claim_ref.clone(), "Bound"), Some(&claim("uid-1", Some("pv-data"))), &[]);
+        assert!(bound.bound && bound.issues.is_empty());
+        assert_eq!(bound.claim.unwrap().reclaim_policy.as_deref(), Some("Retain"));
+
+        let pending_pod = kuboard_volume_binding(&volume(claim_ref.clone(), "Bound"), Some(&claim("uid-1", Some("pv-data"))), &pods);
+        assert_eq!(pending_pod.issues, vec!["Pod db-0 is Pending"]);
+
+        let released = kuboard_volume_binding(&volume(claim_ref.clone(), "Released"), None, &[]);
+        assert!(!released.bound);
+        assert!(released.issues[0].starts_with("Claim prod/data no longer exists; the data is retained"));
+
+        let recreated = kuboard_volume_binding(&volume(claim_ref.clone(), "Released"), Some(&claim("uid-2", None)), &[]);
+        assert_eq!(recreated.issues, vec!["Claim prod/data was recreated after the volume was bound to the previous one"]);
+
+        let elsewhere = kuboard_volume_binding(&volume(claim_ref, "Bound"), Some(&claim("uid-1", Some("pv-other"))), &[]);
+        assert_eq!(elsewhere.issues, vec!["Claim prod/data is bound to volume pv-other"]);
+
+        let available = kuboard_volume_binding(&volume(json!(null), "Available"), None, &[]);
+        assert!(!available.bound && available.issues.is_empty());
+    }
+}
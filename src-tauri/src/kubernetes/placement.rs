@@ -0,0 +1,198 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Pod Placement Heatmap
+// Pod counts, requests and usage per namespace or workload on each node, for spotting scheduling skew
+
+use anyhow::Result;
+use kube::api::ListParams;
+use kube::{Api, Client};
+use k8s_openapi::api::core::v1::{Node, Pod};
+use std::collections::{BTreeMap, HashMap};
+use tracing::warn;
+
+use crate::kubernetes::topology::kuboard_node_topology;
+use crate::metrics::get_pod_metrics;
+use crate::quantity::{kuboard_parse_bytes, kuboard_parse_cpu};
+use crate::types::{PlacementCell, PlacementHeatmap, PlacementNode, PlacementRow};
+
+// (cpu millicores, memory bytes)
+pub type PodUsage = HashMap<(String, String), (u64, u64)>;
+
+// A row's namespace, plus the workload kind and name when grouping by workload
+type RowKey = (String, Option<(String, String)>);
+
+// The workload a pod belongs to; ReplicaSet pods are attributed to their Deployment
+pub fn kuboard_pod_workload(pod: &Pod) -> (String, String) {
+    let name = pod.metadata.name.clone().unwrap_or_default();
+    let Some(owner) = pod.metadata.owner_references.iter().flatten().find(|o| o.controller == Some(true)) else {
+        return ("Pod".to_string(), name);
+    };
+    let template_hash = pod.metadata.labels.as_ref().and_then(|l| l.get("pod-template-hash"));
+    match template_hash.and_then(|hash| owner.name.strip_suffix(&format!("-{}", hash))) {
+        Some(deployment) if owner.kind == "ReplicaSet" => ("Deployment".to_string(), deployment.to_string()),
+        _ => (owner.kind.clone(), owner.name.clone()),
+    }
+}
+
+fn pod_requests(pod: &Pod) -> (u64, u64) {
+    let containers = pod.spec.iter().flat_map(|s| s.containers.iter());
+    containers.fold((0, 0), |(cpu, memory), container| {
+        let requests = container.resources.as_ref().and_then(|r| r.requests.as_ref());
+        let request = |name: &str| requests.and_then(|r| r.get(name)).map(|q| q.0.clone());
+        (
+            cpu + request("cpu").and_then(|q| kuboard_parse_cpu(&q).ok()).map(|c| (c * 1000.0).round() as u64).unwrap_or(0),
+            memory + request("memory").and_then(|q| kuboard_parse_bytes(&q).ok()).unwrap_or(0),
+        )
+    })
+}
+
+// Scheduled, unfinished pods grouped into rows by namespace, or by workload when `by_workload` is set
+pub fn kuboard_build_placement_heatmap(nodes: &[Node], pods: &[Pod], usage: Option<&PodUsage>, by_workload: bool) -> PlacementHeatmap {
+    let placement_nodes: Vec<PlacementNode> = nodes.iter()
+        .map(|node| {
+            let (zone, region) = node.metadata.labels.as_ref().map(kuboard_node_topology).unwrap_or_default();
+            PlacementNode { name: node.metadata.name.clone().unwrap_or_default(), zone, region }
+        })
+        .collect();
+    let node_index: HashMap<&str, usize> = placement_nodes.iter().enumerate().map(|(i, n)| (n.name.as_str(), i)).collect();
+
+    let empty_cells = || -> Vec<PlacementCell> {
+        placement_nodes.iter().map(|n| PlacementCell {
+            node: n.name.clone(),
+            pod_count: 0,
+            cpu_requests_millicores: 0,
+            memory_requests_bytes: 0,
+            cpu_usage_millicores: usage.map(|_| 0),
+            memory_usage_bytes: usage.map(|_| 0),
+        }).collect()
+    };
+
+    let mut rows: BTreeMap<RowKey, Vec<PlacementCell>> = BTreeMap::new();
+    for pod in pods {
+        if matches!(pod.status.as_ref().and_then(|s| s.phase.as_deref()), Some("Succeeded" | "Failed")) {
+            continue;
+        }
+        let Some(&index) = pod.spec.as_ref().and_then(|s| s.node_name.as_deref()).and_then(|n| node_index.get(n)) else {
+            continue;
+        };
+        let namespace = pod.metadata.namespace.clone().unwrap_or_default();
+        let key = (namespace.clone(), by_workload.then(|| kuboard_pod_workload(pod)));
+        let cell = &mut rows.entry(key).or_insert_with(empty_cells)[index];
+
+        let (cpu, memory) = pod_requests(pod);
+        cell.pod_count += 1;
+        cell.cpu_requests_millicores += cpu;
+        cell.memory_requests_bytes += memory;
+        if let Some(usage) = usage {
+            let (cpu, memory) = usage.get(&(namespace, pod.metadata.name.clone().unwrap_or_default())).copied().unwrap_or_default();
+            cell.cpu_usage_millicores = cell.cpu_usage_millicores.map(|c| c + cpu);
+            cell.memory_usage_bytes = cell.memory_usage_bytes.map(|m| m + memory);
+        }
+    }
+
+    let rows = rows.into_iter()
+        .map(|((namespace, workload), cells)| {
+            let mut pods_by_zone: BTreeMap<String, u32> = BTreeMap::new();
+            for (cell, node) in cells.iter().zip(&placement_nodes) {
+                if let (Some(zone), true) = (&node.zone, cell.pod_count > 0) {
+                    *pods_by_zone.entry(zone.clone()).or_default() += cell.pod_count;
+                }
+            }
+            PlacementRow {
+                namespace,
+                workload_kind: workload.as_ref().map(|(kind, _)| kind.clone()),
+                workload_name: workload.map(|(_, name)| name),
+                total_pods: cells.iter().map(|c| c.pod_count).sum(),
+                pods_by_zone,
+                cells,
+            }
+        })
+        .collect();
+
+    PlacementHeatmap {
+        nodes: placement_nodes,
+        rows,
+        metrics_available: usage.is_some(),
+    }
+}
+
+async fn pod_usage(client: &Client) -> Option<PodUsage> {
+    match get_pod_metrics(client).await {
+        Ok(metrics) => Some(metrics.items.into_iter().map(|pod| {
+            let (cpu, memory) = pod.containers.iter().fold((0, 0), |(cpu, memory), c| (
+                cpu + kuboard_parse_cpu(&c.usage.cpu).map(|v| (v * 1000.0).round() as u64).unwrap_or(0),
+                memory + kuboard_parse_bytes(&c.usage.memory).unwrap_or(0),
+            ));
+            ((pod.metadata.namespace, pod.metadata.name), (cpu, memory))
+        }).collect()),
+        Err(e) => {
+            // The heatmap still works from requests without metrics-server
+            warn!("Pod metrics unavailable for placement heatmap: {}", e);
+            None
+        }
+    }
+}
+
+pub async fn kuboard_get_pod_placement(client: &Client, namespace: Option<&str>, by_workload: bool) -> Result<PlacementHeatmap> {
+    let nodes = Api::<Node>::all(client.clone()).list(&ListParams::default()).await?.items;
+    let pods_api: Api<Pod> = match namespace {
+        Some(namespace) => Api::namespaced(client.clone(), namespace),
+        None => Api::all(client.clone()),
+    };
+    let pods = pods_api.list(&ListParams::default()).await?.items;
+    let usage = pod_usage(client).await;
+    Ok(kuboard_build_placement_heatmap(&nodes, &pods, usage.as_ref(), by_workload))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn pod(name: &str, node: &str, owner: Option<(&str, &str)>, hash: Option<&str>) -> Pod {
+        let mut labels = json!({});
+        if let Some(hash) = hash {
+            labels["pod-template-hash"] = json!(hash);
+        }
+        let owners: Vec<_> = owner.into_iter()
+            .map(|(kind, name)| json!({ "apiVersion": "apps/v1", "kind": kind, "name": name, "uid": "u", "controller": true }))
+            .collect();
+        serde_json::from_value(json!({
+            "metadata": { "name": name, "namespace": "apps", "labels": labels, "ownerReferences": owners },
+            "spec": {
+                "nodeName": node,
+                "containers": [{ "name": "app", "resources": { "requests": { "cpu": "250m", "memory": "64Mi" } } }],
+            },
+            "status": { "phase": "Running" },
+        })).unwrap()
+    }
+
+    #[test]
+    fn test_placement_heatmap_groups_by_workload() {
+        let nodes: Vec<Node> = ["node-a", "node-b"].iter().enumerate().map(|(i, name)| serde_json::from_value(json!({
+            "metadata": { "name": name, "labels": { "topology.kubernetes.io/zone": format!("zone-{}", i) } },
+        })).unwrap()).collect();
+        let pods = vec![
+            pod("api-5f6c7-a", "node-a", Some(("ReplicaSet", "api-5f6c7")), Some("5f6c7")),
+            pod("api-5f6c7-b", "node-a", Some(("ReplicaSet", "api-5f6c7")), Some("5f6c7")),
+            pod("db-0", "node-b", Some(("StatefulSet", "db")), None),
+            pod("debug", "node-b", None, None),
+        ];
+
+        let heatmap = kuboard_build_placement_heatmap(&nodes, &pods, None, true);
+
+        let api = &heatmap.rows[0];
+        assert_eq!((api.workload_kind.as_deref(), api.workload_name.as_deref()), (Some("Deployment"), Some("api")));
+        assert_eq!(api.cells[0].pod_count, 2);
+        assert_eq!(api.cells[0].cpu_requests_millicores, 500);
+        assert_eq!(api.cells[1].pod_count, 0);
+        assert_eq!(api.pods_by_zone, BTreeMap::from([("zone-0".to_string(), 2)]));
+        assert_eq!(api.cells[0].cpu_usage_millicores, None);
+        assert_eq!(heatmap.rows.len(), 3);
+
+        let by_namespace = kuboard_build_placement_heatmap(&nodes, &pods, None, false);
+        assert_eq!(by_namespace.rows.len(), 1);
+        assert_eq!(by_namespace.rows[0].total_pods, 4);
+    }
+}
@@ -0,0 +1,276 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Pod Security Admission
+// Reads and writes pod-security.kubernetes.io namespace labels and evaluates pods against a level
+
+use anyhow::{anyhow, Result};
+use kube::api::{ListParams, Patch, PatchParams};
+use kube::{Api, Client};
+use k8s_openapi::api::core::v1::{Container, Namespace, Pod, PodSpec, SecurityContext};
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+
+use crate::types::{PodSecurityCheck, PodSecurityLabels, PodSecurityViolation};
+
+pub const POD_SECURITY_LEVELS: &[&str] = &["privileged", "baseline", "restricted"];
+const POD_SECURITY_LABEL_PREFIX: &str = "pod-security.kubernetes.io/";
+const POD_SECURITY_MODES: &[&str] = &["enforce", "audit", "warn"];
+
+// Capabilities the baseline level still allows containers to add
+const BASELINE_CAPABILITIES: &[&str] = &[
+    "AUDIT_WRITE", "CHOWN", "DAC_OVERRIDE", "FOWNER", "FSETID", "KILL", "MKNOD", "NET_BIND_SERVICE",
+    "SETFCAP", "SETGID", "SETPCAP", "SETUID", "SYS_CHROOT",
+];
+const SAFE_SYSCTLS: &[&str] = &[
+    "kernel.shm_rmid_forced", "net.ipv4.ip_local_port_range", "net.ipv4.ip_unprivileged_port_start",
+    "net.ipv4.tcp_syncookies", "net.ipv4.ping_group_range", "net.ipv4.ip_local_reserved_ports",
+    "net.ipv4.tcp_keepalive_time", "net.ipv4.tcp_fin_timeout", "net.ipv4.tcp_keepalive_intvl",
+    "net.ipv4.tcp_keepalive_probes",
+];
+
+fn label(labels: &BTreeMap<String, String>, key: &str) -> Option<String> {
+    labels.get(&format!("{}{}", POD_SECURITY_LABEL_PREFIX, key)).cloned()
+}
+
+pub fn kuboard_pod_security_labels(namespace: &Namespace) -> PodSecurityLabels {
+    let labels = namespace.metadata.labels.clone().unwrap_or_default();
+    PodSecurityLabels {
+        namespace: namespace.metadata.name.clone().unwrap_or_default(),
+        enforce: label(&labels, "enforce"),
+        enforce_version: label(&labels, "enforce-version"),
+        audit: label(&labels, "audit"),
+        audit_version: label(&labels, "audit-version"),
+        warn: label(&labels, "warn"),
+        warn_version: label(&labels, "warn-version"),
+    }
+}
+
+pub fn validate_level(level: &str) -> Result<()> {
+    if POD_SECURITY_LEVELS.contains(&level) {
+        Ok(())
+    } else {
+        Err(anyhow!("Unknown pod security level {}, expected one of {}", level, POD_SECURITY_LEVELS.join(", ")))
+    }
+}
+
+// Sets each given mode to a level; an empty level removes the label
+pub async fn kuboard_patch_pod_security_labels(
+    client: &Client,
+    namespace: &str,
+    levels: &[(&str, Option<String>)],
+) -> Result<PodSecurityLabels> {
+    let mut labels = Map::new();
+    for (mode, level) in levels {
+        if !POD_SECURITY_MODES.contains(mode) {
+            return Err(anyhow!("Unknown pod security mode {}", mode));
+        }
+        match level.as_deref() {
+            None => {}
+            Some("") => {
+                labels.insert(format!("{}{}", POD_SECURITY_LABEL_PREFIX, mode), Value::Null);
+                labels.insert(format!("{}{}-version", POD_SECURITY_LABEL_PREFIX, mode), Value::Null);
+            }
+            Some(level) => {
+                validate_level(level)?;
+                labels.insert(format!("{}{}", POD_SECURITY_LABEL_PREFIX, mode), json!(level));
+            }
+        }
+    }
+
+    let namespaces_api: Api<Namespace> = Api::all(client.clone());
+    let patch = json!({ "metadata": { "labels": labels } });
+    let updated = namespaces_api.patch(namespace, &PatchParams::default(), &Patch::Merge(&patch)).await?;
+    Ok(kuboard_pod_security_labels(&updated))
+}
+
+fn all_containers(spec: &PodSpec) -> impl Iterator<Item = &Container> {
+    spec.init_containers.iter().flatten().chain(spec.containers.iter())
+}
+
+fn container_context(container: &Container) -> Option<&SecurityContext> {
+    container.security_context.as_ref()
+}
+
+fn baseline_violations(spec: &PodSpec) -> Vec<String> {
+    let mut violations = Vec::new();
+    if spec.host_network == Some(true) {
+        violations.push("hostNetwork=true".to_string());
+    }
+    if spec.host_pid == Some(true) {
+        violations.push("hostPID=true".to_string());
+    }
+    if spec.host_ipc == Some(true) {
+        violations.push("hostIPC=true".to_string());
+    }
+    for volume in spec.volumes.iter().flatten().filter(|v| v.host_path.is_some()) {
+        violations.push(format!("hostPath volume {}", volume.name));
+    }
+    let pod_seccomp = spec.security_context.as_ref().and_then(|c| c.seccomp_profile.as_ref());
+    if pod_seccomp.is_some_and(|p| p.type_ == "Unconfined") {
+        violations.push("pod seccompProfile Unconfined".to_string());
+    }
+    for sysctl in spec.security_context.as_ref().and_then(|c| c.sysctls.as_ref()).into_iter().flatten() {
+        if !SAFE_SYSCTLS.contains(&sysctl.name.as_str()) {
+            violations.push(format!("unsafe sysctl {}", sysctl.name));
+        }
+    }
+
+    for container in all_containers(spec) {
+        let context = container_context(container);
+        if context.and_then(|c| c.privileged) == Some(true) {
+            violations.push(format!("container {} is privileged", container.name));
+        }
+        let added = context.and_then(|c| c.capabilities.as_ref()).and_then(|c| c.add.as_ref());
+        for capability in added.into_iter().flatten().filter(|c| !BASELINE_CAPABILITIES.contains(&c.as_str())) {
+            violations.push(format!("container {} adds capability {}", container.name, capability));
+        }
+        for port in container.ports.iter().flatten().filter(|p| p.host_port.is_some_and(|h| h != 0)) {
+            violations.push(format!("container {} uses hostPort {}", container.name, port.host_port.unwrap_or_default()));
+        }
+        if context.and_then(|c| c.proc_mount.as_deref()).is_some_and(|m| m != "Default") {
+            violations.push(format!("container {} uses a non-default procMount", container.name));
+        }
+        if context.and_then(|c| c.seccomp_profile.as_ref()).is_some_and(|p| p.type_ == "Unconfined") {
+            violations.push(format!("container {} seccompProfile Unconfined", container.name));
+        }
+    }
+    violations
+}
+
+fn restricted_violations(spec: &PodSpec) -> Vec<String> {
+    let mut violations = Vec::new();
+    let pod_context = spec.security_context.as_ref();
+
+    // Only these volume types are allowed by the restricted profile
+    for volume in spec.volumes.iter().flatten() {
+        let allowed = volume.config_map.is_some() || volume.csi.is_some() || volume.downward_api.is_some()
+            || volume.empty_dir.is_some() || volume.ephemeral.is_some() || volume.persistent_volume_claim.is_some()
+            || volume.projected.is_some() || volume.secret.is_some();
+        if !allowed && volume.host_path.is_none() {
+            violations.push(format!("volume {} uses a restricted volume type", volume.name));
+        }
+    }
+
+    let pod_non_root = pod_context.and_then(|c| c.run_as_non_root);
+    let pod_seccomp = pod_context.and_then(|c| c.seccomp_profile.as_ref()).map(|p| p.type_.as_str());
+    if pod_context.and_then(|c| c.run_as_user) == Some(0) {
+        violations.push("pod runAsUser=0".to_string());
+    }
+
+    for container in all_containers(spec) {
+        let context = container_context(container);
+        if context.and_then(|c| c.allow_privilege_escalation) != Some(false) {
+            violations.push(format!("container {} must set allowPrivilegeEscalation=false", container.name));
+        }
+        if context.and_then(|c| c.run_as_non_root).or(pod_non_root) != Some(true) {
+            violations.push(format!("container {} must set runAsNonRoot=true", container.name));
+        }
+        if context.and_then(|c| c.run_as_user) == Some(0) {
+            violations.push(format!("container {} runAsUser=0", container.name));
+        }
+        let seccomp = context.and_then(|c| c.seccomp_profile.as_ref()).map(|p| p.type_.as_str()).or(pod_seccomp);
+        if !matches!(seccomp, Some("RuntimeDefault" | "Localhost")) {
+            violations.push(format!("container {} must set seccompProfile RuntimeDefault or Localhost", container.name));
+        }
+        let capabilities = context.and_then(|c| c.capabilities.as_ref());
+        if !capabilities.and_then(|c| c.drop.as_ref()).is_some_and(|d| d.iter().any(|c| c == "ALL")) {
+            violations.push(format!("container {} must drop ALL capabilities", container.name));
+        }
+        let added = capabilities.and_then(|c| c.add.as_ref());
+        for capability in added.into_iter().flatten().filter(|c| *c != "NET_BIND_SERVICE") {
+            violations.push(format!("container {} adds capability {}", container.name, capability));
+        }
+    }
+    violations
+}
+
+// Checks a pod spec against a Pod Security Standards level, mirroring the admission plugin's checks
+pub fn kuboard_pod_security_violations(spec: &PodSpec, level: &str) -> Vec<String> {
+    match level {
+        "baseline" => baseline_violations(spec),
+        "restricted" => {
+            let mut violations = baseline_violations(spec);
+            violations.extend(restricted_violations(spec));
+            violations
+        }
+        _ => Vec::new(),
+    }
+}
+
+// Evaluates the namespace's current pods against a level without changing any labels
+pub async fn kuboard_check_pod_security_level(client: &Client, namespace: &str, level: &str) -> Result<PodSecurityCheck> {
+    validate_level(level)?;
+    let pods_api: Api<Pod> = Api::namespaced(client.clone(), namespace);
+    let pods = pods_api.list(&ListParams::default()).await?;
+
+    let violations: Vec<PodSecurityViolation> = pods.items
+        .iter()
+        .filter_map(|pod| {
+            let violations = kuboard_pod_security_violations(pod.spec.as_ref()?, level);
+            if violations.is_empty() {
+                return None;
+            }
+            let owner = pod.metadata.owner_references.as_ref()
+                .and_then(|refs| refs.iter().find(|r| r.controller == Some(true)));
+            Some(PodSecurityViolation {
+                pod_name: pod.metadata.name.clone().unwrap_or_default(),
+                owner_kind: owner.map(|o| o.kind.clone()),
+                owner_name: owner.map(|o| o.name.clone()),
+                violations,
+            })
+        })
+        .collect();
+
+    Ok(PodSecurityCheck {
+        namespace: namespace.to_string(),
+        level: level.to_string(),
+        pods_checked: pods.items.len(),
+        violations,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pod_spec(value: Value) -> PodSpec {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_baseline_flags_host_access() {
+        let spec = pod_spec(json!({
+            "hostNetwork": true,
+            "containers": [{
+                "name": "agent",
+                "securityContext": { "privileged": true, "capabilities": { "add": ["NET_ADMIN", "CHOWN"] } },
+            }],
+        }));
+
+        let violations = kuboard_pod_security_violations(&spec, "baseline");
+
+        assert_eq!(violations, vec![
+            "hostNetwork=true".to_string(),
+            "container agent is privileged".to_string(),
+            "container agent adds capability NET_ADMIN".to_string(),
+        ]);
+        assert!(kuboard_pod_security_violations(&spec, "privileged").is_empty());
+    }
+
+    #[test]
+    fn test_restricted_requires_hardened_context() {
+        let hardened = pod_spec(json!({
+            "securityContext": { "runAsNonRoot": true, "seccompProfile": { "type": "RuntimeDefault" } },
+            "containers": [{
+                "name": "web",
+                "securityContext": { "allowPrivilegeEscalation": false, "capabilities": { "drop": ["ALL"] } },
+            }],
+        }));
+        assert!(kuboard_pod_security_violations(&hardened, "restricted").is_empty());
+
+        let plain = pod_spec(json!({ "containers": [{ "name": "web" }] }));
+        assert!(kuboard_pod_security_violations(&plain, "baseline").is_empty());
+        assert_eq!(kuboard_pod_security_violations(&plain, "restricted").len(), 4);
+    }
+}
@@ -0,0 +1,207 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Pod Status
+// Derives the composite STATUS column kubectl prints (Init:1/2, CrashLoopBackOff, Terminating,
+// Completed) from container states, since the raw phase stays "Running" through most failures
+
+use k8s_openapi::api::core::v1::{ContainerState, Pod};
+
+use crate::types::PodSummary;
+use crate::utils::kuboard_format_age;
+
+// "ExitCode:1" or "Signal:9" for a container that stopped without a reason
+fn terminated_without_reason(state: &ContainerState) -> Option<String> {
+    let terminated = state.terminated.as_ref()?;
+    Some(match terminated.signal {
+        Some(signal) if signal != 0 => format!("Signal:{}", signal),
+        _ => format!("ExitCode:{}", terminated.exit_code),
+    })
+}
+
+fn condition_is_true(pod: &Pod, condition: &str) -> bool {
+    pod.status.as_ref()
+        .and_then(|s| s.conditions.as_ref())
+        .is_some_and(|conditions| conditions.iter().any(|c| c.type_ == condition && c.status == "True"))
+}
+
+// Follows kubectl's printPod: the first unfinished init container wins, then the last container
+// with a waiting or terminated reason, and deletion overrides everything
+pub fn kuboard_pod_status(pod: &Pod) -> String {
+    let status = pod.status.as_ref();
+    let spec = pod.spec.as_ref();
+    let mut reason = status
+        .and_then(|s| s.reason.clone().or_else(|| s.phase.clone()))
+        .unwrap_or_else(|| "Unknown".to_string());
+
+    let scheduling_gated = status
+        .and_then(|s| s.conditions.as_ref())
+        .is_some_and(|conditions| conditions.iter().any(|c| {
+            c.type_ == "PodScheduled" && c.status == "False" && c.reason.as_deref() == Some("SchedulingGated")
+        }));
+    if scheduling_gated {
+        reason = "SchedulingGated".to_string();
+    }
+
+    let init_containers = spec.and_then(|s| s.init_containers.as_ref());
+    let mut initializing = false;
+    for (index, container) in status.and_then(|s| s.init_container_statuses.as_ref()).into_iter().flatten().enumerate() {
+        let state = container.state.clone().unwrap_or_default();
+        // Sidecars are init containers with restartPolicy Always that keep running
+        let sidecar = init_containers
+            .and_then(|containers| containers.iter().find(|c| c.name == container.name))
+            .is_some_and(|c| c.restart_policy.as_deref() == Some("Always"));
+
+        if state.terminated.as_ref().is_some_and(|t| t.exit_code == 0) || (sidecar && container.started == Some(true)) {
+            continue;
+        }
+
+        let waiting_reason = state.waiting.as_ref()
+            .and_then(|w| w.reason.as_deref())
+            .filter(|r| !r.is_empty() && *r != "PodInitializing");
+        reason = match (state.terminated.as_ref(), waiting_reason) {
+            (Some(terminated), _) => match terminated.reason.as_deref().filter(|r| !r.is_empty()) {
+                Some(terminated_reason) => format!("Init:{}", terminated_reason),
+                None => format!("Init:{}", terminated_without_reason(&state).unwrap_or_default()),
+            },
+            (None, Some(waiting)) => format!("Init:{}", waiting),
+            (None, None) => format!("Init:{}/{}", index, init_containers.map_or(0, Vec::len)),
+        };
+        initializing = true;
+        break;
+    }
+
+    if !initializing || condition_is_true(pod, "Initialized") {
+        let mut has_running = false;
+        for container in status.and_then(|s| s.container_statuses.as_ref()).into_iter().flatten().rev() {
+            let state = container.state.clone().unwrap_or_default();
+            if let Some(waiting) = state.waiting.as_ref().and_then(|w| w.reason.clone()).filter(|r| !r.is_empty()) {
+                reason = waiting;
+            } else if let Some(terminated) = &state.terminated {
+                reason = terminated.reason.clone()
+                    .filter(|r| !r.is_empty())
+                    .or_else(|| terminated_without_reason(&state))
+                    .unwrap_or_default();
+            } else if container.ready && state.running.is_some() {
+                has_running = true;
+            }
+        }
+
+        // A container that exited cleanly next to one still running leaves the pod running
+        if reason == "Completed" && has_running {
+            reason = if condition_is_true(pod, "Ready") { "Running" } else { "NotReady" }.to_string();
+        }
+    }
+
+    if pod.metadata.deletion_timestamp.is_some() {
+        reason = if status.and_then(|s| s.reason.as_deref()) == Some("NodeLost") { "Unknown" } else { "Terminating" }.to_string();
+    }
+
+    reason
+}
+
+pub fn kuboard_pod_summary(pod: &Pod) -> PodSummary {
+    let status = pod.status.as_ref();
+    let container_statuses = status.and_then(|s| s.container_statuses.as_ref());
+    let init_statuses = status.and_then(|s| s.init_container_statuses.as_ref());
+
+    PodSummary {
+        name: pod.metadata.name.clone().unwrap_or_default(),
+        namespace: pod.metadata.namespace.clone().unwrap_or_default(),
+        status: kuboard_pod_status(pod),
+        phase: status.and_then(|s| s.phase.clone()).unwrap_or_else(|| "Unknown".to_string()),
+        ready_containers: container_statuses.map_or(0, |s| s.iter().filter(|c| c.ready).count()),
+        total_containers: pod.spec.as_ref().map_or(0, |s| s.containers.len()),
+        restarts: container_statuses.into_iter().chain(init_statuses).flatten().map(|c| c.restart_count).sum(),
+        node: pod.spec.as_ref().and_then(|s| s.node_name.clone()),
+        pod_ip: status.and_then(|s| s.pod_ip.clone()),
+        age: pod.metadata.creation_timestamp.as_ref().map(|t| kuboard_format_age(t.0)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    fn pod(spec: Value, status: Value) -> Pod {
+        serde_json::from_value(json!({ "metadata": { "name": "web", "namespace": "default" }, "spec": spec, "status": status })).unwrap()
+    }
+
+    fn containers(names: &[&str]) -> Value {
+        json!(names.iter().map(|name| json!({ "name": name, "image": "nginx" })).collect::<Vec<_>>())
+    }
+
+    fn container_status(name: &str, ready: bool, state: Value, restarts: i32) -> Value {
+        json!({ "name": name, "ready": ready, "restartCount": restarts, "image": "nginx", "imageID": "", "state": state })
+    }
+
+    #[test]
+    fn test_pod_status_follows_container_states() {
+        let running = pod(
+            json!({ "containers": containers(&["app"]) }),
+            json!({ "phase": "Running", "containerStatuses": [container_status("app", true, json!({ "running": {} }), 0)] }),
+        );
+        assert_eq!(kuboard_pod_status(&running), "Running");
+
+        let crashing = pod(
+            json!({ "containers": containers(&["app", "proxy"]) }),
+            json!({ "phase": "Running", "containerStatuses": [
+                container_status("app", false, json!({ "waiting": { "reason": "CrashLoopBackOff" } }), 7),
+                container_status("proxy", true, json!({ "running": {} }), 0),
+            ] }),
+        );
+        assert_eq!(kuboard_pod_status(&crashing), "CrashLoopBackOff");
+        let summary = kuboard_pod_summary(&crashing);
+        assert_eq!((summary.ready_containers, summary.total_containers, summary.restarts), (1, 2, 7));
+        assert_eq!(summary.phase, "Running");
+
+        let completed = pod(
+            json!({ "containers": containers(&["job"]) }),
+            json!({ "phase": "Succeeded", "containerStatuses": [
+                container_status("job", false, json!({ "terminated": { "exitCode": 0, "reason": "Completed" } }), 0),
+            ] }),
+        );
+        assert_eq!(kuboard_pod_status(&completed), "Completed");
+
+        let killed = pod(
+            json!({ "containers": containers(&["app"]) }),
+            json!({ "phase": "Running", "containerStatuses": [
+                container_status("app", false, json!({ "terminated": { "exitCode": 137, "signal": 9 } }), 1),
+            ] }),
+        );
+        assert_eq!(kuboard_pod_status(&killed), "Signal:9");
+
+        let mut terminating = running.clone();
+        terminating.metadata.deletion_timestamp = Some(k8s_openapi::apimachinery::pkg::apis::meta::v1::Time(chrono::Utc::now()));
+        assert_eq!(kuboard_pod_status(&terminating), "Terminating");
+    }
+
+    #[test]
+    fn test_pod_status_init_containers() {
+        let spec = json!({ "initContainers": containers(&["migrate", "seed"]), "containers": containers(&["app"]) });
+        let waiting = container_status("seed", false, json!({ "waiting": { "reason": "PodInitializing" } }), 0);
+        let done = container_status("migrate", false, json!({ "terminated": { "exitCode": 0, "reason": "Completed" } }), 0);
+
+        let second = pod(spec.clone(), json!({ "phase": "Pending", "initContainerStatuses": [done, waiting] }));
+        assert_eq!(kuboard_pod_status(&second), "Init:1/2");
+
+        let failing = pod(spec.clone(), json!({ "phase": "Pending", "initContainerStatuses": [
+            container_status("migrate", false, json!({ "waiting": { "reason": "CrashLoopBackOff" } }), 3),
+        ] }));
+        assert_eq!(kuboard_pod_status(&failing), "Init:CrashLoopBackOff");
+
+        let errored = pod(spec, json!({ "phase": "Pending", "initContainerStatuses": [
+            container_status("migrate", false, json!({ "terminated": { "exitCode": 2 } }), 0),
+        ] }));
+        assert_eq!(kuboard_pod_status(&errored), "Init:ExitCode:2");
+
+        let sidecar = pod(
+            json!({ "initContainers": [{ "name": "mesh", "image": "envoy", "restartPolicy": "Always" }], "containers": containers(&["app"]) }),
+            json!({ "phase": "Running", "initContainerStatuses": [
+                json!({ "name": "mesh", "ready": true, "started": true, "restartCount": 0, "image": "envoy", "imageID": "", "state": { "running": {} } }),
+            ], "containerStatuses": [container_status("app", true, json!({ "running": {} }), 0)] }),
+        );
+        assert_eq!(kuboard_pod_status(&sidecar), "Running");
+    }
+}
@@ -0,0 +1,179 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Context Prefetch
+// Loads the first screens' data in the background after a context switch; each prefetched
+// result is handed out once, so later refreshes still go to the API server
+
+use k8s_openapi::api::apps::v1::Deployment;
+use k8s_openapi::api::core::v1::{Namespace, Node, Pod};
+use kube::api::ListParams;
+use kube::{Api, Client};
+use serde::Serialize;
+use std::time::{Duration, Instant};
+use tracing::warn;
+
+use crate::kubernetes::kuboard_calculate_cluster_metrics;
+use crate::kubernetes::namespace_access::{kuboard_list_accessible, kuboard_list_accessible_namespaces};
+use crate::types::{ClusterInfo, ClusterOverview, NamespaceFilter};
+
+// Older prefetches are dropped rather than served as if they were current
+pub const PREFETCH_TTL: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, Default)]
+pub struct PrefetchedData {
+    pub overview: Option<ClusterOverview>,
+    pub namespaces: Option<Vec<Namespace>>,
+    pub pods: Option<Vec<Pod>>,
+    pub deployments: Option<Vec<Deployment>>,
+}
+
+// Payload of the "context-warm" event
+#[derive(Debug, Clone, Serialize)]
+pub struct ContextWarmEvent {
+    pub context: String,
+    pub overview: bool,
+    pub namespaces: bool,
+    pub pods: bool,
+    pub deployments: bool,
+}
+
+impl PrefetchedData {
+    pub fn warm_event(&self, context: &str) -> ContextWarmEvent {
+        ContextWarmEvent {
+            context: context.to_string(),
+            overview: self.overview.is_some(),
+            namespaces: self.namespaces.is_some(),
+            pods: self.pods.is_some(),
+            deployments: self.deployments.is_some(),
+        }
+    }
+}
+
+pub struct PrefetchCache {
+    entry: Option<(String, Instant, PrefetchedData)>,
+}
+
+impl PrefetchCache {
+    pub fn new() -> Self {
+        Self { entry: None }
+    }
+
+    pub fn store(&mut self, context: &str, data: PrefetchedData) {
+        self.entry = Some((context.to_string(), Instant::now(), data));
+    }
+
+    pub fn clear(&mut self) {
+        self.entry = None;
+    }
+
+    // Removes one prefetched result if it belongs to this context and is still fresh
+    pub fn take<T>(&mut self, context: &str, pick: impl FnOnce(&mut PrefetchedData) -> Option<T>) -> Option<T> {
+        let (entry_context, fetched_at, data) = self.entry.as_mut()?;
+        if entry_context != context || fetched_at.elapsed() > PREFETCH_TTL {
+            self.entry = None;
+            return None;
+        }
+        pick(data)
+    }
+}
+
+impl Default for PrefetchCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Counts are passed in so callers that already hold the lists don't fetch them twice
+pub async fn kuboard_build_cluster_overview(
+    client: &Client,
+    context_name: String,
+    namespace_count: usize,
+    pod_count: usize,
+    deployment_count: usize,
+) -> ClusterOverview {
+    // Get cluster info
+    let cluster_info = ClusterInfo {
+        name: context_name,
+        server: "unknown".to_string(), // Client doesn't expose apiserver_url in this version
+        version: None,
+    };
+
+    // Count nodes
+    let nodes_api: Api<Node> = Api::all(client.clone());
+    let node_count = match nodes_api.list(&Default::default()).await {
+        Ok(nodes) => nodes.items.len(),
+        Err(e) => {
+            warn!("Failed to get nodes: {}", e);
+            0
+        }
+    };
+
+    // Try to get Kubernetes version
+    let kubernetes_version = match client.apiserver_version().await {
+        Ok(version) => Some(format!("{}.{}", version.major, version.minor)),
+        Err(e) => {
+            warn!("Failed to get Kubernetes version: {}", e);
+            None
+        }
+    };
+
+    // Calculate cluster metrics
+    let cluster_metrics = match kuboard_calculate_cluster_metrics(client).await {
+        Ok(metrics) => Some(metrics),
+        Err(e) => {
+            warn!("Failed to calculate cluster metrics: {}", e);
+            None
+        }
+    };
+
+    ClusterOverview {
+        cluster_info,
+        node_count,
+        namespace_count,
+        pod_count,
+        deployment_count,
+        kubernetes_version,
+        cluster_metrics,
+    }
+}
+
+// Lists that fail are left empty so the screen falls back to its normal request
+pub async fn kuboard_prefetch_context(client: &Client, context_name: &str, filter: &NamespaceFilter) -> PrefetchedData {
+    let params = ListParams::default();
+    let (namespaces, pods, deployments) = tokio::join!(
+        kuboard_list_accessible_namespaces(client, filter),
+        kuboard_list_accessible::<Pod>(client, filter, &params),
+        kuboard_list_accessible::<Deployment>(client, filter, &params),
+    );
+    let namespaces = namespaces.inspect_err(|e| warn!("Failed to prefetch namespaces: {}", e)).ok();
+    let pods = pods.inspect_err(|e| warn!("Failed to prefetch pods: {}", e)).ok();
+    let deployments = deployments.inspect_err(|e| warn!("Failed to prefetch deployments: {}", e)).ok();
+
+    let overview = kuboard_build_cluster_overview(
+        client,
+        context_name.to_string(),
+        namespaces.as_ref().map_or(0, Vec::len),
+        pods.as_ref().map_or(0, Vec::len),
+        deployments.as_ref().map_or(0, Vec::len),
+    ).await;
+
+    PrefetchedData { overview: Some(overview), namespaces, pods, deployments }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prefetched_results_are_taken_once_per_context() {
+        let mut cache = PrefetchCache::new();
+        cache.store("kind-dev", PrefetchedData { pods: Some(Vec::new()), namespaces: Some(Vec::new()), ..Default::default() });
+
+        assert!(cache.take("kind-dev", |d| d.pods.take()).is_some());
+        assert!(cache.take("kind-dev", |d| d.pods.take()).is_none());
+        // Asking for another context discards the entry
+        assert!(cache.take("prod", |d| d.namespaces.take()).is_none());
+        assert!(cache.take("kind-dev", |d| d.namespaces.take()).is_none());
+    }
+}
@@ -0,0 +1,317 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Printer Columns
+// The columns `kubectl get` shows for custom resources, read from the CRD's
+// additionalPrinterColumns and filled in by evaluating their JSONPath against each object. Covers
+// the JSONPath subset CRDs use: fields, indexes, wildcards and ==/!= filters
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::{
+    CustomResourceColumnDefinition, CustomResourceDefinition,
+};
+use kube::api::{ApiResource, DynamicObject};
+use kube::{Api, Client};
+use serde_json::Value;
+use tracing::warn;
+
+use crate::types::{ResourceTable, ResourceTableRow};
+use crate::utils::kuboard_format_age;
+
+#[derive(Debug, Clone)]
+enum Step {
+    Field(String),
+    Index(i64),
+    Wildcard,
+    // [?(@.path)] keeps items where the path exists; with a literal it compares the first value found
+    Filter { path: Vec<Step>, negate: bool, literal: Option<Value> },
+}
+
+fn column(name: &str, type_: &str, json_path: &str, description: &str) -> CustomResourceColumnDefinition {
+    CustomResourceColumnDefinition {
+        name: name.to_string(),
+        type_: type_.to_string(),
+        json_path: json_path.to_string(),
+        description: Some(description.to_string()),
+        ..Default::default()
+    }
+}
+
+// Reads up to the `]` closing the bracket just opened, skipping brackets inside quotes and filters
+fn bracket_content(chars: &[char], start: usize) -> Result<(String, usize)> {
+    let mut depth = 0;
+    let mut quote = None;
+    for (offset, &c) in chars[start..].iter().enumerate() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"') => quote = Some(c),
+            (None, '[' | '(') => depth += 1,
+            (None, ')') => depth -= 1,
+            (None, ']') if depth == 0 => return Ok((chars[start..start + offset].iter().collect(), start + offset + 1)),
+            (None, ']') => depth -= 1,
+            _ => {}
+        }
+    }
+    Err(anyhow!("Unclosed ["))
+}
+
+fn parse_literal(literal: &str) -> Result<Value> {
+    let literal = literal.trim();
+    if let Some(quoted) = literal.strip_prefix('\'').and_then(|l| l.strip_suffix('\'')) {
+        return Ok(Value::String(quoted.to_string()));
+    }
+    serde_json::from_str(literal).map_err(|_| anyhow!("Unsupported filter value {}", literal))
+}
+
+fn parse_filter(filter: &str) -> Result<Step> {
+    let (path, negate, literal) = match filter.split_once("!=").map(|(p, l)| (p, true, l))
+        .or_else(|| filter.split_once("==").map(|(p, l)| (p, false, l)))
+    {
+        Some((path, negate, literal)) => (path.trim(), negate, Some(parse_literal(literal)?)),
+        None => (filter.trim(), false, None),
+    };
+    let path = path.strip_prefix('@').ok_or_else(|| anyhow!("Filter {} must start with @", filter))?;
+    Ok(Step::Filter { path: parse_steps(path)?, negate, literal })
+}
+
+fn parse_bracket(content: &str) -> Result<Step> {
+    let content = content.trim();
+    if content == "*" {
+        return Ok(Step::Wildcard);
+    }
+    if let Some(filter) = content.strip_prefix("?(").and_then(|f| f.strip_suffix(')')) {
+        return parse_filter(filter);
+    }
+    if let Some(field) = content.strip_prefix('\'').and_then(|f| f.strip_suffix('\''))
+        .or_else(|| content.strip_prefix('"').and_then(|f| f.strip_suffix('"')))
+    {
+        return Ok(Step::Field(field.to_string()));
+    }
+    content.parse().map(Step::Index).map_err(|_| anyhow!("Unsupported JSONPath selector [{}]", content))
+}
+
+fn parse_steps(path: &str) -> Result<Vec<Step>> {
+    let chars: Vec<char> = path.chars().collect();
+    let mut steps = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '.' if chars.get(i + 1) == Some(&'.') => return Err(anyhow!("Recursive descent (..) is not supported")),
+            '.' => {
+                let end = chars[i + 1..].iter().position(|c| matches!(c, '.' | '[')).map_or(chars.len(), |p| i + 1 + p);
+                let field: String = chars[i + 1..end].iter().collect();
+                match field.as_str() {
+                    "" => return Err(anyhow!("Empty field name in {}", path)),
+                    "*" => steps.push(Step::Wildcard),
+                    _ => steps.push(Step::Field(field)),
+                }
+                i = end;
+            }
+            '[' => {
+                let (content, next) = bracket_content(&chars, i + 1)?;
+                steps.push(parse_bracket(&content)?);
+                i = next;
+            }
+            c => return Err(anyhow!("Unexpected {:?} in {}", c, path)),
+        }
+    }
+    Ok(steps)
+}
+
+// CRDs write paths as ".status.phase"; "{...}" and "$" forms are accepted too
+fn parse_jsonpath(expression: &str) -> Result<Vec<Step>> {
+    let path = expression.trim();
+    let path = path.strip_prefix('{').and_then(|p| p.strip_suffix('}')).unwrap_or(path).trim();
+    let path = path.strip_prefix('$').unwrap_or(path);
+    parse_steps(path).map_err(|e| anyhow!("Invalid JSONPath {}: {}", expression, e))
+}
+
+fn evaluate<'a>(steps: &[Step], root: &'a Value) -> Vec<&'a Value> {
+    let mut current = vec![root];
+    for step in steps {
+        current = current.into_iter()
+            .flat_map(|value| -> Vec<&'a Value> {
+                match (step, value) {
+                    (Step::Field(field), Value::Object(fields)) => fields.get(field).into_iter().collect(),
+                    (Step::Index(index), Value::Array(items)) => {
+                        let index = if *index < 0 { items.len() as i64 + index } else { *index };
+                        usize::try_from(index).ok().and_then(|i| items.get(i)).into_iter().collect()
+                    }
+                    (Step::Wildcard, Value::Array(items)) => items.iter().collect(),
+                    (Step::Wildcard, Value::Object(fields)) => fields.values().collect(),
+                    (Step::Filter { path, negate, literal }, Value::Array(items)) => items.iter()
+                        .filter(|item| {
+                            let found = evaluate(path, item);
+                            match literal {
+                                Some(literal) => (found.first() == Some(&literal)) != *negate,
+                                None => !found.is_empty(),
+                            }
+                        })
+                        .collect(),
+                    _ => Vec::new(),
+                }
+            })
+            .collect();
+    }
+    current
+}
+
+pub fn kuboard_jsonpath<'a>(value: &'a Value, expression: &str) -> Result<Vec<&'a Value>> {
+    Ok(evaluate(&parse_jsonpath(expression)?, value))
+}
+
+// As kubectl prints a cell: strings bare, several results space-separated and dates as an age
+fn format_cell(values: &[&Value], type_: &str) -> Option<String> {
+    let text: Vec<String> = values.iter()
+        .filter(|value| !value.is_null())
+        .map(|value| match value {
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        })
+        .collect();
+    if text.is_empty() {
+        return None;
+    }
+    let text = text.join(" ");
+    if type_ == "date" {
+        if let Ok(date) = text.parse::<DateTime<Utc>>() {
+            return Some(kuboard_format_age(date));
+        }
+    }
+    Some(text)
+}
+
+// Name first, as the API server's table output has it; Age stands in when the CRD declares no columns
+pub fn kuboard_printer_columns(crd: Option<&CustomResourceDefinition>, version: &str) -> Vec<CustomResourceColumnDefinition> {
+    let declared = crd.and_then(|crd| crd.spec.versions.iter().find(|v| v.name == version))
+        .and_then(|v| v.additional_printer_columns.clone())
+        .unwrap_or_default();
+    let mut columns = vec![column("Name", "string", ".metadata.name", "Name of the object")];
+    if declared.is_empty() {
+        columns.push(column("Age", "date", ".metadata.creationTimestamp", "Time since the object was created"));
+    }
+    columns.extend(declared);
+    columns
+}
+
+pub fn kuboard_resource_table(kind: &str, columns: Vec<CustomResourceColumnDefinition>, objects: &[DynamicObject]) -> Result<ResourceTable> {
+    let mut warnings = Vec::new();
+    let paths: Vec<Option<Vec<Step>>> = columns.iter()
+        .map(|column| match parse_jsonpath(&column.json_path) {
+            Ok(steps) => Some(steps),
+            Err(e) => {
+                warnings.push(format!("Column {}: {}", column.name, e));
+                None
+            }
+        })
+        .collect();
+
+    let mut rows = Vec::new();
+    for object in objects {
+        let value = serde_json::to_value(object)?;
+        let cells = columns.iter().zip(&paths)
+            .map(|(column, steps)| steps.as_ref().and_then(|steps| format_cell(&evaluate(steps, &value), &column.type_)))
+            .collect();
+        rows.push(ResourceTableRow {
+            name: object.metadata.name.clone().unwrap_or_default(),
+            namespace: object.metadata.namespace.clone(),
+            cells,
+        });
+    }
+    Ok(ResourceTable { kind: kind.to_string(), columns, rows, warnings })
+}
+
+// The CRD behind a kind, named <plural>.<group>. Built-in and aggregated kinds have none, and
+// users who can't read CRDs get the default columns rather than an error
+pub async fn kuboard_fetch_crd(client: &Client, resource: &ApiResource) -> Option<CustomResourceDefinition> {
+    if resource.group.is_empty() {
+        return None;
+    }
+    let name = format!("{}.{}", resource.plural, resource.group);
+    match Api::<CustomResourceDefinition>::all(client.clone()).get_opt(&name).await {
+        Ok(crd) => crd,
+        Err(e) => {
+            warn!("Could not read CustomResourceDefinition {}: {}", name, e);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_jsonpath() {
+        let object = json!({
+            "metadata": { "name": "web-cert", "labels": { "app.kubernetes.io/name": "web" } },
+            "spec": { "dnsNames": ["a.example.com", "b.example.com"] },
+            "status": {
+                "conditions": [
+                    { "type": "Issuing", "status": "False" },
+                    { "type": "Ready", "status": "True", "message": "Certificate is up to date" },
+                ],
+            },
+        });
+        let eval = |path: &str| kuboard_jsonpath(&object, path).unwrap().into_iter().cloned().collect::<Vec<Value>>();
+
+        assert_eq!(eval(".metadata.name"), vec![json!("web-cert")]);
+        assert_eq!(eval("{.metadata.labels['app.kubernetes.io/name']}"), vec![json!("web")]);
+        assert_eq!(eval("$.spec.dnsNames[-1]"), vec![json!("b.example.com")]);
+        assert_eq!(eval(".spec.dnsNames[*]").len(), 2);
+        assert_eq!(eval(".status.conditions[?(@.type==\"Ready\")].status"), vec![json!("True")]);
+        assert_eq!(eval(".status.conditions[?(@.type != 'Ready')].type"), vec![json!("Issuing")]);
+        assert_eq!(eval(".status.conditions[?(@.message)].type"), vec![json!("Ready")]);
+        assert!(eval(".status.missing[0]").is_empty());
+
+        assert!(kuboard_jsonpath(&object, "..name").is_err());
+        assert!(kuboard_jsonpath(&object, ".spec.dnsNames[0:1]").is_err());
+        assert!(kuboard_jsonpath(&object, ".status.conditions[?(@.type==Ready)]").is_err());
+    }
+
+    #[test]
+    fn test_resource_table() {
+        let crd: CustomResourceDefinition = serde_json::from_value(json!({
+            "metadata": { "name": "certificates.cert-manager.io" },
+            "spec": {
+                "group": "cert-manager.io",
+                "names": { "kind": "Certificate", "plural": "certificates" },
+                "scope": "Namespaced",
+                "versions": [{
+                    "name": "v1", "served": true, "storage": true,
+                    "additionalPrinterColumns": [
+                        { "name": "Ready", "type": "string", "jsonPath": ".status.conditions[?(@.type==\"Ready\")].status" },
+                        { "name": "Secret", "type": "string", "jsonPath": ".spec.secretName" },
+                        { "name": "Renewals", "type": "integer", "jsonPath": ".status.renewals" },
+                        { "name": "Issued", "type": "date", "jsonPath": ".status.notBefore" },
+                        { "name": "Broken", "type": "string", "jsonPath": "..secretName" },
+                    ],
+                }],
+            },
+        })).unwrap();
+        let columns = kuboard_printer_columns(Some(&crd), "v1");
+        let names: Vec<&str> = columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["Name", "Ready", "Secret", "Renewals", "Issued", "Broken"]);
+
+        let objects: Vec<DynamicObject> = serde_json::from_value(json!([{
+            "apiVersion": "cert-manager.io/v1", "kind": "Certificate",
+            "metadata": { "name": "web-cert", "namespace": "prod" },
+            "spec": { "secretName": "web-tls" },
+            "status": { "conditions": [{ "type": "Ready", "status": "True" }], "renewals": 3, "notBefore": "2025-01-01T00:00:00Z" },
+        }])).unwrap();
+        let table = kuboard_resource_table("Certificate", columns, &objects).unwrap();
+        let issued = kuboard_format_age("2025-01-01T00:00:00Z".parse().unwrap());
+        assert_eq!(table.rows[0].cells, vec![
+            Some("web-cert".to_string()), Some("True".to_string()), Some("web-tls".to_string()),
+            Some("3".to_string()), Some(issued), None,
+        ]);
+        assert_eq!(table.rows[0].namespace.as_deref(), Some("prod"));
+        assert_eq!(table.warnings.len(), 1);
+
+        let defaults: Vec<String> = kuboard_printer_columns(None, "v1").into_iter().map(|c| c.name).collect();
+        assert_eq!(defaults, vec!["Name", "Age"]);
+    }
+}
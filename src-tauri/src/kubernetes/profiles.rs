@@ -0,0 +1,209 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Access Profiles
+// A developer profile narrows the command surface to a namespace set and hides node and cluster-scoped operations
+
+use anyhow::{anyhow, Result};
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::ipc::{Invoke, InvokeBody};
+use tauri::{Manager, Runtime};
+use tracing::warn;
+
+use crate::app_state::AppState;
+use crate::kubernetes::namespace_access::kuboard_namespace_allowed;
+use crate::types::{KuboardProfile, NamespaceFilter, ProfileKind};
+
+// Node, cluster-scoped and cluster-wide operations, plus switching the profile itself
+pub const DEVELOPER_BLOCKED_COMMANDS: &[&str] = &[
+    "kuboard_get_cluster_overview",
+    "kuboard_get_nodes",
+    "kuboard_get_node_metrics",
+    "kuboard_get_node_metrics_history",
+    "kuboard_get_node_eviction_order",
+    "kuboard_get_volume_attachments",
+    "kuboard_get_csi_drivers",
+    "kuboard_get_cluster_metrics",
+    "kuboard_start_node_shell",
+    "kuboard_clone_namespace",
+    "kuboard_set_pod_security_labels",
+    "kuboard_who_can",
+    "kuboard_scan_deprecated_apis",
+    "kuboard_get_upgrade_readiness",
+    "kuboard_get_placement_heatmap",
+    "kuboard_get_topology_zones",
+    "kuboard_get_zone_concentration",
+    "kuboard_get_cluster_dns",
+    "kuboard_get_ingress_routes",
+    "kuboard_lookup_ingress_route",
+    "kuboard_set_profile",
+];
+
+pub fn kuboard_profile_path() -> Result<PathBuf> {
+    dirs::data_local_dir()
+        .map(|dir| dir.join("kuboard").join("profile.json"))
+        .ok_or_else(|| anyhow!("Could not find local data directory"))
+}
+
+pub fn kuboard_load_profile(path: &Path) -> Result<KuboardProfile> {
+    if !path.exists() {
+        return Ok(KuboardProfile::default());
+    }
+    Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+}
+
+pub fn kuboard_save_profile(path: &Path, profile: &KuboardProfile) -> Result<KuboardProfile> {
+    let mut profile = profile.clone();
+    profile.namespaces = profile.namespaces.iter().map(|ns| ns.trim().to_string()).filter(|ns| !ns.is_empty()).collect();
+    profile.namespaces.sort();
+    profile.namespaces.dedup();
+    if profile.kind == ProfileKind::Developer && profile.namespaces.is_empty() {
+        return Err(anyhow!("A developer profile needs at least one namespace"));
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let tmp_path = path.with_extension("json.tmp");
+    fs::write(&tmp_path, serde_json::to_string_pretty(&profile)?)?;
+    fs::rename(&tmp_path, path)?;
+    Ok(profile)
+}
+
+pub fn kuboard_profile_allows_command(profile: &KuboardProfile, command: &str) -> bool {
+    profile.kind == ProfileKind::Admin || !DEVELOPER_BLOCKED_COMMANDS.contains(&command)
+}
+
+// A developer's namespaces replace the context's allow-list; its deny-list still applies
+pub fn kuboard_profile_namespace_filter(profile: &KuboardProfile, filter: NamespaceFilter) -> NamespaceFilter {
+    match profile.kind {
+        ProfileKind::Admin => filter,
+        ProfileKind::Developer => NamespaceFilter { allow: profile.namespaces.clone(), deny: filter.deny },
+    }
+}
+
+// Namespace arguments at the top level or one object down (request structs); null means "all namespaces"
+fn namespace_arguments(args: &Value) -> Vec<Option<&str>> {
+    let Some(args) = args.as_object() else { return Vec::new() };
+    let nested = args.values().filter_map(Value::as_object).filter_map(|object| object.get("namespace"));
+    args.get("namespace").into_iter().chain(nested).map(Value::as_str).collect()
+}
+
+// Why the profile refuses a command with these arguments, if it does
+pub fn kuboard_profile_rejection(profile: &KuboardProfile, command: &str, args: &Value) -> Option<String> {
+    if profile.kind == ProfileKind::Admin {
+        return None;
+    }
+    if !kuboard_profile_allows_command(profile, command) {
+        return Some(format!("{} is not available in the developer profile", command));
+    }
+    let filter = kuboard_profile_namespace_filter(profile, NamespaceFilter::default());
+    namespace_arguments(args).into_iter().find_map(|namespace| match namespace {
+        None => Some("The developer profile requires an explicit namespace".to_string()),
+        Some(ns) if !kuboard_namespace_allowed(&filter, Some(ns)) => {
+            Some(format!("Namespace {} is outside the developer profile", ns))
+        }
+        Some(_) => None,
+    })
+}
+
+// The same check for an object named inside a manifest rather than in the command arguments;
+// cluster-scoped objects (no namespace) are outside every developer profile
+pub fn kuboard_profile_object_rejection(profile: &KuboardProfile, kind: &str, name: &str, namespace: Option<&str>) -> Option<String> {
+    if profile.kind == ProfileKind::Admin {
+        return None;
+    }
+    let filter = kuboard_profile_namespace_filter(profile, NamespaceFilter::default());
+    match namespace {
+        None => Some(format!("{} {} is cluster-scoped, which the developer profile can't change", kind, name)),
+        Some(ns) if !kuboard_namespace_allowed(&filter, Some(ns)) => {
+            Some(format!("Namespace {} is outside the developer profile", ns))
+        }
+        Some(_) => None,
+    }
+}
+
+// Wraps the generated invoke handler so every command passes the active profile first
+pub fn kuboard_profile_gate<R: Runtime>(
+    handler: impl Fn(Invoke<R>) -> bool + Send + Sync + 'static,
+) -> impl Fn(Invoke<R>) -> bool + Send + Sync + 'static {
+    move |invoke: Invoke<R>| {
+        let rejection = {
+            let state = invoke.message.webview_ref().state::<AppState>();
+            // Profile writes are rare and short; refuse rather than block the IPC thread
+            let rejection = match state.profile.try_read() {
+                Ok(profile) => {
+                    let args = match invoke.message.payload() {
+                        InvokeBody::Json(args) => args.clone(),
+                        InvokeBody::Raw(_) => Value::Null,
+                    };
+                    kuboard_profile_rejection(&profile, invoke.message.command(), &args)
+                }
+                Err(_) => Some("The access profile is being changed, please retry".to_string()),
+            };
+            rejection
+        };
+        match rejection {
+            Some(message) => {
+                warn!("Rejected {}: {}", invoke.message.command(), message);
+                invoke.resolver.reject(message);
+                true
+            }
+            None => handler(invoke),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn developer() -> KuboardProfile {
+        KuboardProfile { kind: ProfileKind::Developer, namespaces: vec!["team-a".to_string()] }
+    }
+
+    #[test]
+    fn test_developer_profile_rejections() {
+        let profile = developer();
+        assert!(kuboard_profile_rejection(&profile, "kuboard_get_nodes", &json!({})).is_some());
+        assert!(kuboard_profile_rejection(&profile, "kuboard_set_profile", &json!({})).is_some());
+        assert!(kuboard_profile_rejection(&profile, "kuboard_get_pods", &json!({})).is_none());
+        assert!(kuboard_profile_rejection(&profile, "kuboard_get_service", &json!({ "name": "web", "namespace": "team-a" })).is_none());
+        assert!(kuboard_profile_rejection(&profile, "kuboard_get_service", &json!({ "name": "web", "namespace": "kube-system" })).is_some());
+        assert!(kuboard_profile_rejection(&profile, "kuboard_get_pod_logs", &json!({ "request": { "podName": "x", "namespace": "team-b" } })).is_some());
+        assert!(kuboard_profile_rejection(&profile, "kuboard_get_resilience_report", &json!({ "namespace": null })).is_some());
+
+        let admin = KuboardProfile::default();
+        assert!(kuboard_profile_rejection(&admin, "kuboard_get_nodes", &json!({ "namespace": null })).is_none());
+
+        assert!(kuboard_profile_object_rejection(&profile, "ConfigMap", "settings", Some("team-a")).is_none());
+        assert!(kuboard_profile_object_rejection(&profile, "ConfigMap", "settings", Some("team-b")).is_some());
+        assert!(kuboard_profile_object_rejection(&profile, "ClusterRole", "reader", None).is_some());
+        assert!(kuboard_profile_object_rejection(&admin, "ClusterRole", "reader", None).is_none());
+    }
+
+    #[test]
+    fn test_developer_namespaces_replace_allow_list() {
+        let filter = NamespaceFilter { allow: vec!["team-*".to_string()], deny: vec!["team-a-old".to_string()] };
+        let scoped = kuboard_profile_namespace_filter(&developer(), filter.clone());
+        assert_eq!(scoped.allow, vec!["team-a"]);
+        assert_eq!(scoped.deny, filter.deny);
+        assert_eq!(kuboard_profile_namespace_filter(&KuboardProfile::default(), filter.clone()).allow, filter.allow);
+    }
+
+    #[test]
+    fn test_save_profile_requires_namespaces_for_developers() {
+        let dir = std::env::temp_dir().join(format!("kuboard-profile-{}", uuid::Uuid::new_v4()));
+        let path = dir.join("profile.json");
+
+        let empty = KuboardProfile { kind: ProfileKind::Developer, namespaces: vec![" ".to_string()] };
+        assert!(kuboard_save_profile(&path, &empty).is_err());
+        kuboard_save_profile(&path, &developer()).unwrap();
+        assert_eq!(kuboard_load_profile(&path).unwrap().kind, ProfileKind::Developer);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,93 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Operation Progress
+// Long-running operations report stages through a ProgressReporter, which the commands
+// turn into "operation-progress" events keyed by an operation id the frontend can pick
+
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter, Runtime};
+use tracing::{debug, error};
+
+use crate::types::OperationProgress;
+
+pub const PROGRESS_EVENT: &str = "operation-progress";
+
+#[derive(Clone)]
+pub struct ProgressReporter {
+    operation: String,
+    operation_id: String,
+    sink: Arc<dyn Fn(OperationProgress) + Send + Sync>,
+}
+
+impl ProgressReporter {
+    // A missing operation id gets a generated one
+    pub fn new(
+        operation: &str,
+        operation_id: Option<String>,
+        sink: impl Fn(OperationProgress) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            operation: operation.to_string(),
+            operation_id: operation_id.unwrap_or_else(|| uuid::Uuid::new_v4().to_string()),
+            sink: Arc::new(sink),
+        }
+    }
+
+    pub fn for_app<R: Runtime>(app: AppHandle<R>, operation: &str, operation_id: Option<String>) -> Self {
+        Self::new(operation, operation_id, move |progress| {
+            if let Err(e) = app.emit(PROGRESS_EVENT, progress) {
+                error!("Failed to emit progress event: {}", e);
+            }
+        })
+    }
+
+    // For callers with nobody to report to
+    pub fn silent(operation: &str) -> Self {
+        Self::new(operation, None, |progress| debug!("{} {}: {}%", progress.operation, progress.stage, progress.percent))
+    }
+
+    pub fn operation_id(&self) -> &str {
+        &self.operation_id
+    }
+
+    // `done` of `total` steps complete when entering `stage`
+    pub fn report(&self, stage: &str, done: usize, total: usize, message: Option<String>) {
+        let percent = (done.min(total) * 100).checked_div(total).unwrap_or(100) as u8;
+        (self.sink)(OperationProgress {
+            operation_id: self.operation_id.clone(),
+            operation: self.operation.clone(),
+            stage: stage.to_string(),
+            percent,
+            message,
+        });
+    }
+
+    pub fn finish(&self, message: Option<String>) {
+        self.report("done", 1, 1, message);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_progress_percentages() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = events.clone();
+        let progress = ProgressReporter::new("clone-namespace", Some("op-1".to_string()), move |p| sink.lock().unwrap().push(p));
+
+        progress.report("ConfigMap", 1, 3, None);
+        progress.report("Service", 2, 3, Some("2 created".to_string()));
+        progress.finish(None);
+
+        let events = events.lock().unwrap();
+        let percents: Vec<u8> = events.iter().map(|e| e.percent).collect();
+        assert_eq!(percents, vec![33, 66, 100]);
+        assert!(events.iter().all(|e| e.operation_id == "op-1" && e.operation == "clone-namespace"));
+        assert_eq!(events[2].stage, "done");
+        assert!(!ProgressReporter::silent("drain").operation_id().is_empty());
+    }
+}
@@ -0,0 +1,324 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// PersistentVolumeClaims
+// Creates claims after checking the StorageClass exists and can provide the requested access
+// modes, then optionally waits for them to bind; and summarises existing claims with their bound
+// volume and the pods mounting them
+
+use anyhow::{anyhow, Result};
+use k8s_openapi::api::core::v1::{
+    PersistentVolume, PersistentVolumeClaim, PersistentVolumeClaimSpec, Pod, VolumeResourceRequirements,
+};
+use k8s_openapi::api::storage::v1::StorageClass;
+use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use kube::api::{ListParams, PostParams};
+use kube::runtime::wait::await_condition;
+use kube::{Api, Client};
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use crate::quantity::kuboard_parse_bytes;
+use crate::types::{PvcCreateResult, PvcMount, PvcSummary};
+use crate::utils::kuboard_format_age;
+
+pub const PVC_BIND_TIMEOUT: Duration = Duration::from_secs(60);
+
+pub const ACCESS_MODES: &[&str] = &["ReadWriteOnce", "ReadOnlyMany", "ReadWriteMany", "ReadWriteOncePod"];
+
+const DEFAULT_CLASS_ANNOTATIONS: &[&str] = &[
+    "storageclass.kubernetes.io/is-default-class",
+    "storageclass.beta.kubernetes.io/is-default-class",
+];
+
+const BLOCK_ACCESS_MODES: &[&str] = &["ReadWriteOnce", "ReadWriteOncePod"];
+const FILE_ACCESS_MODES: &[&str] = ACCESS_MODES;
+
+// StorageClasses don't declare access modes, so well-known provisioners are looked up here;
+// claims for any other provisioner are created unchecked
+const PROVISIONER_ACCESS_MODES: &[(&str, &[&str])] = &[
+    ("ebs.csi.aws.com", BLOCK_ACCESS_MODES),
+    ("kubernetes.io/aws-ebs", BLOCK_ACCESS_MODES),
+    ("pd.csi.storage.gke.io", &["ReadWriteOnce", "ReadOnlyMany", "ReadWriteOncePod"]),
+    ("kubernetes.io/gce-pd", &["ReadWriteOnce", "ReadOnlyMany", "ReadWriteOncePod"]),
+    ("disk.csi.azure.com", BLOCK_ACCESS_MODES),
+    ("kubernetes.io/azure-disk", BLOCK_ACCESS_MODES),
+    ("rancher.io/local-path", BLOCK_ACCESS_MODES),
+    ("kubernetes.io/no-provisioner", BLOCK_ACCESS_MODES),
+    ("efs.csi.aws.com", FILE_ACCESS_MODES),
+    ("file.csi.azure.com", FILE_ACCESS_MODES),
+    ("kubernetes.io/azure-file", FILE_ACCESS_MODES),
+    ("filestore.csi.storage.gke.io", FILE_ACCESS_MODES),
+    ("nfs.csi.k8s.io", FILE_ACCESS_MODES),
+    ("cephfs.csi.ceph.com", FILE_ACCESS_MODES),
+];
+
+pub fn kuboard_provisioner_access_modes(provisioner: &str) -> Option<&'static [&'static str]> {
+    PROVISIONER_ACCESS_MODES.iter()
+        .find(|(name, _)| *name == provisioner)
+        .map(|(_, modes)| *modes)
+}
+
+pub fn kuboard_default_storage_class(classes: &[StorageClass]) -> Option<&StorageClass> {
+    classes.iter().find(|class| {
+        class.metadata.annotations.as_ref().is_some_and(|annotations| {
+            DEFAULT_CLASS_ANNOTATIONS.iter().any(|key| annotations.get(*key).map(String::as_str) == Some("true"))
+        })
+    })
+}
+
+// Quantities like "10Gi", "500Mi" or "1.5G"
+fn validate_size(size: &str) -> Result<()> {
+    if !kuboard_parse_bytes(size).is_ok_and(|bytes| bytes > 0) {
+        return Err(anyhow!("Invalid size {}, expected a quantity such as 10Gi", size));
+    }
+    Ok(())
+}
+
+// Picks the class the claim will use and rejects access modes its provisioner can't provide
+pub fn kuboard_check_pvc_storage_class<'a>(
+    classes: &'a [StorageClass],
+    storage_class: Option<&str>,
+    access_modes: &[String],
+) -> Result<&'a StorageClass> {
+    if let Some(mode) = access_modes.iter().find(|mode| !ACCESS_MODES.contains(&mode.as_str())) {
+        return Err(anyhow!("Unknown access mode {}", mode));
+    }
+
+    let class = match storage_class {
+        Some(name) => classes.iter()
+            .find(|class| class.metadata.name.as_deref() == Some(name))
+            .ok_or_else(|| anyhow!("StorageClass {} does not exist", name))?,
+        None => kuboard_default_storage_class(classes)
+            .ok_or_else(|| anyhow!("No storage class given and the cluster has no default StorageClass"))?,
+    };
+
+    if let Some(supported) = kuboard_provisioner_access_modes(&class.provisioner) {
+        if let Some(mode) = access_modes.iter().find(|mode| !supported.contains(&mode.as_str())) {
+            return Err(anyhow!(
+                "StorageClass {} ({}) does not support {}; it supports {}",
+                class.metadata.name.as_deref().unwrap_or_default(),
+                class.provisioner,
+                mode,
+                supported.join(", "),
+            ));
+        }
+    }
+    Ok(class)
+}
+
+fn is_bound(pvc: Option<&PersistentVolumeClaim>) -> bool {
+    pvc.and_then(|pvc| pvc.status.as_ref())
+        .and_then(|status| status.phase.as_deref())
+        == Some("Bound")
+}
+
+pub async fn kuboard_create_pvc_checked(
+    client: &Client,
+    name: &str,
+    namespace: &str,
+    size: &str,
+    storage_class: Option<&str>,
+    access_modes: &[String],
+    wait_for_bound: bool,
+) -> Result<PvcCreateResult> {
+    validate_size(size)?;
+    let access_modes = if access_modes.is_empty() { vec!["ReadWriteOnce".to_string()] } else { access_modes.to_vec() };
+
+    let classes = Api::<StorageClass>::all(client.clone()).list(&ListParams::default()).await?.items;
+    let class = kuboard_check_pvc_storage_class(&classes, storage_class, &access_modes)?;
+    let class_name = class.metadata.name.clone().unwrap_or_default();
+    // These classes only provision once a pod using the claim is scheduled
+    let waits_for_consumer = class.volume_binding_mode.as_deref() == Some("WaitForFirstConsumer");
+
+    let claim = PersistentVolumeClaim {
+        metadata: ObjectMeta {
+            name: Some(name.to_string()),
+            namespace: Some(namespace.to_string()),
+            ..Default::default()
+        },
+        spec: Some(PersistentVolumeClaimSpec {
+            access_modes: Some(access_modes),
+            storage_class_name: Some(class_name.clone()),
+            resources: Some(VolumeResourceRequirements {
+                requests: Some(BTreeMap::from([("storage".to_string(), Quantity(size.to_string()))])),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let pvc_api: Api<PersistentVolumeClaim> = Api::namespaced(client.clone(), namespace);
+    let mut claim = pvc_api.create(&PostParams::default(), &claim).await?;
+
+    let mut message = None;
+    if waits_for_consumer {
+        message = Some(format!("StorageClass {} binds the claim once a pod using it is scheduled", class_name));
+    } else if wait_for_bound {
+        match tokio::time::timeout(PVC_BIND_TIMEOUT, await_condition(pvc_api, name, is_bound)).await {
+            Ok(Ok(Some(bound))) => claim = bound,
+            Ok(Ok(None)) => return Err(anyhow!("PersistentVolumeClaim {}/{} was deleted while waiting", namespace, name)),
+            Ok(Err(e)) => return Err(anyhow!("Failed waiting for {}/{} to bind: {}", namespace, name, e)),
+            Err(_) => message = Some(format!("Not bound after {}s; check the claim's events", PVC_BIND_TIMEOUT.as_secs())),
+        }
+    }
+
+    Ok(PvcCreateResult {
+        bound: is_bound(Some(&claim)),
+        claim,
+        storage_class: class_name,
+        waits_for_consumer,
+        message,
+    })
+}
+
+// Pods using the claim, either directly or through a generic ephemeral volume, whose claim is
+// named <pod>-<volume>
+pub fn kuboard_pvc_mounts(claim_name: &str, pods: &[Pod]) -> Vec<PvcMount> {
+    let mut mounts = Vec::new();
+    for pod in pods {
+        let pod_name = pod.metadata.name.as_deref().unwrap_or_default();
+        let Some(spec) = pod.spec.as_ref() else { continue };
+        for volume in spec.volumes.iter().flatten() {
+            let read_only = match (&volume.persistent_volume_claim, &volume.ephemeral) {
+                (Some(source), _) if source.claim_name == claim_name => source.read_only.unwrap_or(false),
+                (None, Some(_)) if format!("{}-{}", pod_name, volume.name) == claim_name => false,
+                _ => continue,
+            };
+            mounts.push(PvcMount {
+                pod: pod_name.to_string(),
+                node: spec.node_name.clone(),
+                phase: pod.status.as_ref().and_then(|status| status.phase.clone()),
+                volume: volume.name.clone(),
+                read_only,
+            });
+        }
+    }
+    mounts
+}
+
+pub fn kuboard_pvc_summary(claim: &PersistentVolumeClaim, volumes: &[PersistentVolume], pods: &[Pod]) -> PvcSummary {
+    let name = claim.metadata.name.clone().unwrap_or_default();
+    let spec = claim.spec.as_ref();
+    let status = claim.status.as_ref();
+    let volume_name = spec.and_then(|s| s.volume_name.clone());
+    let volume = volume_name.as_deref()
+        .and_then(|bound| volumes.iter().find(|pv| pv.metadata.name.as_deref() == Some(bound)));
+
+    PvcSummary {
+        mounted_by: kuboard_pvc_mounts(&name, pods),
+        name,
+        namespace: claim.metadata.namespace.clone().unwrap_or_default(),
+        phase: status.and_then(|s| s.phase.clone()),
+        volume_name,
+        storage_class: spec.and_then(|s| s.storage_class_name.clone()),
+        capacity: status.and_then(|s| s.capacity.as_ref()).and_then(|c| c.get("storage")).map(|q| q.0.clone()),
+        requested: spec.and_then(|s| s.resources.as_ref())
+            .and_then(|r| r.requests.as_ref())
+            .and_then(|r| r.get("storage"))
+            .map(|q| q.0.clone()),
+        // Once bound, the status holds the modes the volume actually provides
+        access_modes: status.and_then(|s| s.access_modes.clone())
+            .or_else(|| spec.and_then(|s| s.access_modes.clone()))
+            .unwrap_or_default(),
+        volume_mode: spec.and_then(|s| s.volume_mode.clone()),
+        reclaim_policy: volume.and_then(|pv| pv.spec.as_ref()).and_then(|s| s.persistent_volume_reclaim_policy.clone()),
+        age: claim.metadata.creation_timestamp.as_ref().map(|t| kuboard_format_age(t.0)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn class(name: &str, provisioner: &str, default: bool) -> StorageClass {
+        let annotations = default.then(|| {
+            BTreeMap::from([("storageclass.kubernetes.io/is-default-class".to_string(), "true".to_string())])
+        });
+        StorageClass {
+            metadata: ObjectMeta { name: Some(name.to_string()), annotations, ..Default::default() },
+            provisioner: provisioner.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_check_pvc_storage_class() {
+        let classes = vec![
+            class("gp3", "ebs.csi.aws.com", true),
+            class("efs", "efs.csi.aws.com", false),
+            class("custom", "example.com/custom", false),
+        ];
+        let rwx = vec!["ReadWriteMany".to_string()];
+
+        let picked = kuboard_check_pvc_storage_class(&classes, None, &["ReadWriteOnce".to_string()]).unwrap();
+        assert_eq!(picked.metadata.name.as_deref(), Some("gp3"));
+        assert!(kuboard_check_pvc_storage_class(&classes, None, &rwx).is_err());
+        assert!(kuboard_check_pvc_storage_class(&classes, Some("efs"), &rwx).is_ok());
+        // Unknown provisioners can't be checked
+        assert!(kuboard_check_pvc_storage_class(&classes, Some("custom"), &rwx).is_ok());
+        assert!(kuboard_check_pvc_storage_class(&classes, Some("missing"), &rwx).is_err());
+        assert!(kuboard_check_pvc_storage_class(&classes, Some("efs"), &["ReadWriteSome".to_string()]).is_err());
+        assert!(kuboard_check_pvc_storage_class(&classes[1..], None, &rwx).is_err());
+    }
+
+    #[test]
+    fn test_pvc_summary() {
+        let claim: PersistentVolumeClaim = serde_json::from_value(json!({
+            "metadata": { "name": "data-db-0", "namespace": "prod" },
+            "spec": {
+                "accessModes": ["ReadWriteOnce"],
+                "storageClassName": "gp3",
+                "volumeName": "pvc-1234",
+                "resources": { "requests": { "storage": "10Gi" } },
+            },
+            "status": { "phase": "Bound", "accessModes": ["ReadWriteOnce"], "capacity": { "storage": "16Gi" } },
+        })).unwrap();
+        let volume: PersistentVolume = serde_json::from_value(json!({
+            "metadata": { "name": "pvc-1234" },
+            "spec": { "persistentVolumeReclaimPolicy": "Retain" },
+        })).unwrap();
+        let pods: Vec<Pod> = serde_json::from_value(json!([
+            {
+                "metadata": { "name": "db-0" },
+                "spec": {
+                    "nodeName": "node-a",
+                    "containers": [],
+                    "volumes": [{ "name": "data", "persistentVolumeClaim": { "claimName": "data-db-0", "readOnly": true } }],
+                },
+                "status": { "phase": "Running" },
+            },
+            {
+                "metadata": { "name": "data-db" },
+                "spec": { "containers": [], "volumes": [{ "name": "0", "ephemeral": { "volumeClaimTemplate": { "spec": {} } } }] },
+            },
+            {
+                "metadata": { "name": "db-1" },
+                "spec": { "containers": [], "volumes": [{ "name": "data", "persistentVolumeClaim": { "claimName": "data-db-1" } }] },
+            },
+        ])).unwrap();
+
+        let summary = kuboard_pvc_summary(&claim, &[volume], &pods);
+        assert_eq!(summary.capacity.as_deref(), Some("16Gi"));
+        assert_eq!(summary.requested.as_deref(), Some("10Gi"));
+        assert_eq!(summary.reclaim_policy.as_deref(), Some("Retain"));
+        let mounts: Vec<(&str, bool)> = summary.mounted_by.iter().map(|m| (m.pod.as_str(), m.read_only)).collect();
+        assert_eq!(mounts, vec![("db-0", true), ("data-db", false)]);
+        assert_eq!(summary.mounted_by[0].node.as_deref(), Some("node-a"));
+
+        // Unbound claims have no volume to read the policy from
+        assert_eq!(kuboard_pvc_summary(&claim, &[], &[]).reclaim_policy, None);
+    }
+
+    #[test]
+    fn test_validate_size() {
+        for size in ["10Gi", "500Mi", "1.5G", "1024"] {
+            assert!(validate_size(size).is_ok(), "{}", size);
+        }
+        for size in ["", "Gi", "10GB", "-1Gi", "0Gi"] {
+            assert!(validate_size(size).is_err(), "{}", size);
+        }
+    }
+}
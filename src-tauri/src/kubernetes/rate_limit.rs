@@ -0,0 +1,206 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// API Rate Limiting
+// Token bucket layer under the kube client so many open views can't flood a small control plane
+
+use anyhow::{anyhow, Result};
+use std::collections::BTreeMap;
+use std::fs;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+use tokio::time::Sleep;
+use tower::{Layer, Service};
+
+use crate::types::RateLimitSettings;
+
+// Slower limits would park requests for minutes per token
+pub const MIN_RATE_LIMIT_QPS: f64 = 0.01;
+
+// Upper bound on a single wait, for settings files edited by hand
+const MAX_RATE_LIMIT_WAIT: Duration = Duration::from_secs(300);
+
+pub fn kuboard_rate_limits_path() -> Result<PathBuf> {
+    dirs::data_local_dir()
+        .map(|dir| dir.join("kuboard").join("rate_limits.json"))
+        .ok_or_else(|| anyhow!("Could not find local data directory"))
+}
+
+fn load_rate_limits(path: &Path) -> Result<BTreeMap<String, RateLimitSettings>> {
+    if !path.exists() {
+        return Ok(BTreeMap::new());
+    }
+    Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+}
+
+// Contexts without saved settings get the defaults
+pub fn kuboard_load_rate_limit(path: &Path, context: &str) -> Result<RateLimitSettings> {
+    Ok(load_rate_limits(path)?.remove(context).unwrap_or_default())
+}
+
+pub fn kuboard_save_rate_limit(path: &Path, context: &str, settings: &RateLimitSettings) -> Result<RateLimitSettings> {
+    if !settings.qps.is_finite() || settings.qps < 0.0 || (settings.qps > 0.0 && settings.qps < MIN_RATE_LIMIT_QPS) {
+        return Err(anyhow!("QPS must be at least {}, or 0 to disable rate limiting", MIN_RATE_LIMIT_QPS));
+    }
+    if settings.qps > 0.0 && settings.burst == 0 {
+        return Err(anyhow!("Burst must be at least 1"));
+    }
+
+    let mut limits = load_rate_limits(path)?;
+    limits.insert(context.to_string(), settings.clone());
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let tmp_path = path.with_extension("json.tmp");
+    fs::write(&tmp_path, serde_json::to_string_pretty(&limits)?)?;
+    fs::rename(&tmp_path, path)?;
+    Ok(settings.clone())
+}
+
+// Refills at `qps` tokens per second up to `burst`
+pub struct TokenBucket {
+    qps: f64,
+    burst: f64,
+    tokens: f64,
+    updated: Instant,
+}
+
+impl TokenBucket {
+    pub fn new(qps: f64, burst: u32, now: Instant) -> Self {
+        Self { qps, burst: f64::from(burst), tokens: f64::from(burst), updated: now }
+    }
+
+    // Takes a token, returning how long to wait for it; a zero wait means it was available
+    pub fn acquire(&mut self, now: Instant) -> Duration {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.qps).min(self.burst);
+        self.updated = now;
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Duration::ZERO
+        } else {
+            // The token is reserved now so callers queue in order instead of racing for the next refill
+            let wait = Duration::try_from_secs_f64((1.0 - self.tokens) / self.qps)
+                .unwrap_or(MAX_RATE_LIMIT_WAIT)
+                .min(MAX_RATE_LIMIT_WAIT);
+            self.tokens -= 1.0;
+            wait
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct RateLimitLayer {
+    bucket: Arc<Mutex<TokenBucket>>,
+}
+
+impl RateLimitLayer {
+    // None when rate limiting is disabled (qps of 0)
+    pub fn new(settings: &RateLimitSettings) -> Option<Self> {
+        if settings.qps <= 0.0 {
+            return None;
+        }
+        let bucket = TokenBucket::new(settings.qps, settings.burst.max(1), Instant::now());
+        Some(Self { bucket: Arc::new(Mutex::new(bucket)) })
+    }
+}
+
+impl<S> Layer<S> for RateLimitLayer {
+    type Service = RateLimit<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RateLimit { inner, bucket: self.bucket.clone(), delay: None, permitted: false }
+    }
+}
+
+// Waits for a token in poll_ready, which kube's request buffer drives before each call
+pub struct RateLimit<S> {
+    inner: S,
+    bucket: Arc<Mutex<TokenBucket>>,
+    delay: Option<Pin<Box<Sleep>>>,
+    permitted: bool,
+}
+
+impl<S, Request> Service<Request> for RateLimit<S>
+where
+    S: Service<Request>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        if !self.permitted {
+            if self.delay.is_none() {
+                let wait = self.bucket.lock().expect("rate limiter lock poisoned").acquire(Instant::now());
+                if !wait.is_zero() {
+                    self.delay = Some(Box::pin(tokio::time::sleep(wait)));
+                }
+            }
+            if let Some(delay) = self.delay.as_mut() {
+                if delay.as_mut().poll(cx).is_pending() {
+                    return Poll::Pending;
+                }
+                self.delay = None;
+            }
+            self.permitted = true;
+        }
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request) -> Self::Future {
+        self.permitted = false;
+        self.inner.call(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_bucket_burst_then_refill() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::new(2.0, 3, start);
+
+        for _ in 0..3 {
+            assert_eq!(bucket.acquire(start), Duration::ZERO);
+        }
+        // Queued callers wait one refill interval further each
+        assert_eq!(bucket.acquire(start), Duration::from_millis(500));
+        assert_eq!(bucket.acquire(start), Duration::from_millis(1000));
+
+        // After 2s four tokens have refilled, two of them owed to the queued callers
+        assert_eq!(bucket.acquire(start + Duration::from_secs(2)), Duration::ZERO);
+        assert_eq!(bucket.acquire(start + Duration::from_secs(2)), Duration::ZERO);
+        assert!(bucket.acquire(start + Duration::from_secs(2)) > Duration::ZERO);
+    }
+
+    #[test]
+    fn test_token_bucket_caps_wait_for_tiny_qps() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::new(1e-320, 1, start);
+        assert_eq!(bucket.acquire(start), Duration::ZERO);
+        assert_eq!(bucket.acquire(start), MAX_RATE_LIMIT_WAIT);
+    }
+
+    #[test]
+    fn test_rate_limit_settings_validation() {
+        let dir = std::env::temp_dir().join(format!("kuboard-rate-limits-{}", uuid::Uuid::new_v4()));
+        let path = dir.join("rate_limits.json");
+
+        assert_eq!(kuboard_load_rate_limit(&path, "edge").unwrap().qps, RateLimitSettings::default().qps);
+        assert!(kuboard_save_rate_limit(&path, "edge", &RateLimitSettings { qps: 5.0, burst: 0 }).is_err());
+        assert!(kuboard_save_rate_limit(&path, "edge", &RateLimitSettings { qps: 1e-320, burst: 1 }).is_err());
+        assert!(kuboard_save_rate_limit(&path, "edge", &RateLimitSettings { qps: MIN_RATE_LIMIT_QPS, burst: 1 }).is_ok());
+        kuboard_save_rate_limit(&path, "edge", &RateLimitSettings { qps: 2.0, burst: 4 }).unwrap();
+        assert_eq!(kuboard_load_rate_limit(&path, "edge").unwrap().burst, 4);
+        assert!(RateLimitLayer::new(&RateLimitSettings { qps: 0.0, burst: 1 }).is_none());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,173 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// RBAC Who-Can
+// Reverse lookup from an action to the subjects whose bindings allow it
+
+use k8s_openapi::api::rbac::v1::{PolicyRule, RoleRef, Subject};
+
+use crate::kubernetes::service_accounts::RbacSnapshot;
+use crate::types::WhoCanSubject;
+
+// The action being audited; `api_group` "" is the core group
+pub struct RbacAction<'a> {
+    pub verb: &'a str,
+    pub resource: &'a str,
+    pub subresource: Option<&'a str>,
+    pub api_group: &'a str,
+}
+
+impl<'a> RbacAction<'a> {
+    // Accepts kubectl-style resources: "pods", "pods/log", "deployments.apps";
+    // like kubectl, a bare resource is in the core group
+    pub fn parse(verb: &'a str, resource: &'a str) -> Self {
+        let (resource, subresource) = match resource.split_once('/') {
+            Some((resource, subresource)) => (resource, Some(subresource)),
+            None => (resource, None),
+        };
+        let (resource, api_group) = match resource.split_once('.') {
+            Some((resource, group)) => (resource, group),
+            None => (resource, ""),
+        };
+        Self { verb, resource, subresource, api_group }
+    }
+}
+
+fn contains_or_wildcard(values: Option<&Vec<String>>, value: &str) -> bool {
+    values.is_some_and(|values| values.iter().any(|v| v == "*" || v == value))
+}
+
+fn rule_allows(rule: &PolicyRule, action: &RbacAction) -> bool {
+    if !rule.verbs.iter().any(|v| v == "*" || v == action.verb) {
+        return false;
+    }
+    if !contains_or_wildcard(rule.api_groups.as_ref(), action.api_group) {
+        return false;
+    }
+    let full_resource = match action.subresource {
+        Some(subresource) => format!("{}/{}", action.resource, subresource),
+        None => action.resource.to_string(),
+    };
+    rule.resources.as_ref().is_some_and(|resources| resources.iter().any(|r| {
+        r == "*" || *r == full_resource || (action.subresource.is_some() && *r == format!("{}/*", action.resource))
+    }))
+}
+
+fn subjects_for(
+    snapshot: &RbacSnapshot,
+    action: &RbacAction,
+    subjects: Option<&Vec<Subject>>,
+    role_ref: &RoleRef,
+    binding: (&str, &str, Option<&str>),
+) -> Vec<WhoCanSubject> {
+    let (binding_kind, binding_name, binding_namespace) = binding;
+    let matching: Vec<&PolicyRule> = snapshot.role_rules(role_ref, binding_namespace)
+        .into_iter()
+        .filter(|rule| rule_allows(rule, action))
+        .collect();
+    if matching.is_empty() {
+        return Vec::new();
+    }
+
+    // A rule without resourceNames covers every object; otherwise only the listed ones
+    let resource_names: Vec<String> = if matching.iter().any(|r| r.resource_names.as_ref().is_none_or(|n| n.is_empty())) {
+        Vec::new()
+    } else {
+        matching.iter().flat_map(|r| r.resource_names.iter().flatten().cloned()).collect()
+    };
+
+    subjects.into_iter().flatten().map(|subject| WhoCanSubject {
+        subject_kind: subject.kind.clone(),
+        subject_name: subject.name.clone(),
+        subject_namespace: match subject.kind.as_str() {
+            "ServiceAccount" => subject.namespace.clone().or_else(|| binding_namespace.map(str::to_string)),
+            _ => None,
+        },
+        binding_kind: binding_kind.to_string(),
+        binding_name: binding_name.to_string(),
+        role_kind: role_ref.kind.clone(),
+        role_name: role_ref.name.clone(),
+        resource_names: resource_names.clone(),
+    }).collect()
+}
+
+// Subjects allowed to perform the action, one entry per granting binding
+pub fn kuboard_who_can_subjects(snapshot: &RbacSnapshot, action: &RbacAction) -> Vec<WhoCanSubject> {
+    let namespaced = snapshot.role_bindings.iter().flat_map(|binding| subjects_for(
+        snapshot,
+        action,
+        binding.subjects.as_ref(),
+        &binding.role_ref,
+        ("RoleBinding", binding.metadata.name.as_deref().unwrap_or_default(), binding.metadata.namespace.as_deref()),
+    ));
+    let cluster = snapshot.cluster_role_bindings.iter().flat_map(|binding| subjects_for(
+        snapshot,
+        action,
+        binding.subjects.as_ref(),
+        &binding.role_ref,
+        ("ClusterRoleBinding", binding.metadata.name.as_deref().unwrap_or_default(), None),
+    ));
+    namespaced.chain(cluster).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_who_can_matches_verbs_groups_and_subresources() {
+        let snapshot = RbacSnapshot {
+            role_bindings: vec![serde_json::from_value(json!({
+                "metadata": { "name": "log-readers", "namespace": "apps" },
+                "roleRef": { "apiGroup": "rbac.authorization.k8s.io", "kind": "Role", "name": "log-reader" },
+                "subjects": [
+                    { "kind": "User", "name": "alice" },
+                    { "kind": "ServiceAccount", "name": "collector" },
+                ],
+            })).unwrap()],
+            cluster_role_bindings: vec![serde_json::from_value(json!({
+                "metadata": { "name": "ops-admin" },
+                "roleRef": { "apiGroup": "rbac.authorization.k8s.io", "kind": "ClusterRole", "name": "admin" },
+                "subjects": [{ "kind": "Group", "name": "ops" }],
+            })).unwrap()],
+            roles: vec![serde_json::from_value(json!({
+                "metadata": { "name": "log-reader", "namespace": "apps" },
+                "rules": [{ "apiGroups": [""], "resources": ["pods", "pods/log"], "verbs": ["get"], "resourceNames": ["api-0"] }],
+            })).unwrap()],
+            cluster_roles: vec![serde_json::from_value(json!({
+                "metadata": { "name": "admin" },
+                "rules": [{ "apiGroups": ["*"], "resources": ["*"], "verbs": ["*"] }],
+            })).unwrap()],
+        };
+
+        let logs = kuboard_who_can_subjects(&snapshot, &RbacAction::parse("get", "pods/log"));
+        assert_eq!(logs.len(), 3);
+        assert_eq!(logs[1].subject_namespace.as_deref(), Some("apps"));
+        assert_eq!(logs[0].resource_names, vec!["api-0".to_string()]);
+        assert!(logs[2].resource_names.is_empty());
+
+        let delete = kuboard_who_can_subjects(&snapshot, &RbacAction::parse("delete", "deployments.apps"));
+        assert_eq!(delete.iter().map(|s| s.subject_name.as_str()).collect::<Vec<_>>(), vec!["ops"]);
+    }
+
+    #[test]
+    fn test_bare_resource_is_core_group() {
+        let action = RbacAction::parse("get", "deployments");
+        assert_eq!((action.resource, action.api_group), ("deployments", ""));
+        let action = RbacAction::parse("get", "deployments.apps/scale");
+        assert_eq!((action.resource, action.subresource, action.api_group), ("deployments", Some("scale"), "apps"));
+
+        let apps_rule: PolicyRule = serde_json::from_value(json!({
+            "apiGroups": ["apps"], "resources": ["deployments"], "verbs": ["get"],
+        })).unwrap();
+        assert!(!rule_allows(&apps_rule, &RbacAction::parse("get", "deployments")));
+        assert!(rule_allows(&apps_rule, &RbacAction::parse("get", "deployments.apps")));
+
+        let core_rule: PolicyRule = serde_json::from_value(json!({
+            "apiGroups": [""], "resources": ["pods"], "verbs": ["get"],
+        })).unwrap();
+        assert!(rule_allows(&core_rule, &RbacAction::parse("get", "pods")));
+        assert!(!rule_allows(&core_rule, &RbacAction::parse("get", "pods.metrics.k8s.io")));
+    }
+}
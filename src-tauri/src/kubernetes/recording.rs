@@ -0,0 +1,186 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Exec Session Recording
+// Writes exec terminal sessions as asciicast v2 files for later playback
+
+use anyhow::{anyhow, Result};
+use serde_json::json;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use tracing::{info, warn};
+
+use crate::types::ExecRecordingInfo;
+
+const RECORDING_EXTENSION: &str = "cast";
+
+// Recordings live next to other per-user app data, not in the kubeconfig directory
+pub fn kuboard_recordings_dir() -> Result<PathBuf> {
+    dirs::data_local_dir()
+        .map(|dir| dir.join("kuboard").join("recordings"))
+        .ok_or_else(|| anyhow!("Could not find local data directory"))
+}
+
+// Recording ids come from the frontend, so they must not be able to escape the directory
+fn recording_path(dir: &Path, recording_id: &str) -> Result<PathBuf> {
+    let valid = !recording_id.is_empty()
+        && recording_id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-');
+    if !valid {
+        return Err(anyhow!("Invalid recording id: {}", recording_id));
+    }
+    Ok(dir.join(format!("{}.{}", recording_id, RECORDING_EXTENSION)))
+}
+
+#[derive(Clone)]
+pub struct ExecRecorder {
+    pub recording_id: String,
+    started: Instant,
+    file: Arc<Mutex<File>>,
+}
+
+impl ExecRecorder {
+    pub fn create(
+        dir: &Path,
+        recording_id: &str,
+        pod_name: &str,
+        namespace: &str,
+        container_name: Option<&str>,
+        width: u16,
+        height: u16,
+    ) -> Result<Self> {
+        fs::create_dir_all(dir)?;
+        let path = recording_path(dir, recording_id)?;
+        let mut file = OpenOptions::new().write(true).create_new(true).open(&path)?;
+
+        let title = match container_name {
+            Some(container) => format!("{}/{} ({})", namespace, pod_name, container),
+            None => format!("{}/{}", namespace, pod_name),
+        };
+        // Players ignore unknown header keys, so the pod reference rides along for listing
+        let header = json!({
+            "version": 2,
+            "width": width,
+            "height": height,
+            "timestamp": chrono::Utc::now().timestamp(),
+            "title": title,
+            "kuboard": {
+                "podName": pod_name,
+                "namespace": namespace,
+                "containerName": container_name,
+            },
+        });
+        writeln!(file, "{}", header)?;
+
+        info!("Recording exec session to {:?}", path);
+        Ok(Self {
+            recording_id: recording_id.to_string(),
+            started: Instant::now(),
+            file: Arc::new(Mutex::new(file)),
+        })
+    }
+
+    fn record(&self, event_type: &str, data: &str) -> Result<()> {
+        let event = json!([self.started.elapsed().as_secs_f64(), event_type, data]);
+        let mut file = self.file.lock().map_err(|_| anyhow!("Recording file lock poisoned"))?;
+        writeln!(file, "{}", event)?;
+        Ok(())
+    }
+
+    pub fn record_output(&self, data: &str) -> Result<()> {
+        self.record("o", data)
+    }
+
+    pub fn record_input(&self, data: &str) -> Result<()> {
+        self.record("i", data)
+    }
+}
+
+fn read_recording_info(path: &Path) -> Result<ExecRecordingInfo> {
+    let reader = BufReader::new(File::open(path)?);
+    let mut lines = reader.lines();
+    let header: serde_json::Value = serde_json::from_str(&lines.next().ok_or_else(|| anyhow!("Empty recording"))??)?;
+
+    // The last event's offset is the session length
+    let duration_seconds = lines
+        .map_while(|line| line.ok())
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(&line).ok())
+        .last()
+        .and_then(|event| event.get(0).and_then(|t| t.as_f64()))
+        .unwrap_or(0.0);
+
+    let kuboard = &header["kuboard"];
+    Ok(ExecRecordingInfo {
+        id: path.file_stem().and_then(|s| s.to_str()).unwrap_or_default().to_string(),
+        pod_name: kuboard["podName"].as_str().unwrap_or_default().to_string(),
+        namespace: kuboard["namespace"].as_str().unwrap_or_default().to_string(),
+        container_name: kuboard["containerName"].as_str().map(|s| s.to_string()),
+        started_at: header["timestamp"].as_i64()
+            .and_then(|ts| chrono::DateTime::from_timestamp(ts, 0))
+            .map(|t| t.to_rfc3339())
+            .unwrap_or_default(),
+        duration_seconds,
+        size_bytes: fs::metadata(path)?.len(),
+    })
+}
+
+// Newest recordings first; unreadable files are skipped rather than failing the listing
+pub fn kuboard_list_recordings(dir: &Path) -> Result<Vec<ExecRecordingInfo>> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut recordings = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().and_then(|e| e.to_str()) != Some(RECORDING_EXTENSION) {
+            continue;
+        }
+        match read_recording_info(&path) {
+            Ok(info) => recordings.push(info),
+            Err(e) => warn!("Skipping unreadable recording {:?}: {}", path, e),
+        }
+    }
+    recordings.sort_by(|a, b| b.started_at.cmp(&a.started_at));
+    Ok(recordings)
+}
+
+pub fn kuboard_read_recording(dir: &Path, recording_id: &str) -> Result<String> {
+    Ok(fs::read_to_string(recording_path(dir, recording_id)?)?)
+}
+
+pub fn kuboard_delete_recording(dir: &Path, recording_id: &str) -> Result<()> {
+    fs::remove_file(recording_path(dir, recording_id)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recording_roundtrip_as_asciicast() {
+        let dir = std::env::temp_dir().join(format!("kuboard-recordings-{}", uuid::Uuid::new_v4()));
+        let recorder = ExecRecorder::create(&dir, "rec-1", "web-abc", "default", Some("nginx"), 80, 24).unwrap();
+        recorder.record_input("ls\r").unwrap();
+        recorder.record_output("index.html\r\n").unwrap();
+
+        let cast = kuboard_read_recording(&dir, "rec-1").unwrap();
+        let lines: Vec<serde_json::Value> = cast.lines().map(|l| serde_json::from_str(l).unwrap()).collect();
+        assert_eq!(lines[0]["version"], 2);
+        assert_eq!(lines[1][1], "i");
+        assert_eq!(lines[2][2], "index.html\r\n");
+
+        let recordings = kuboard_list_recordings(&dir).unwrap();
+        assert_eq!(recordings.len(), 1);
+        assert_eq!(recordings[0].pod_name, "web-abc");
+        assert_eq!(recordings[0].container_name.as_deref(), Some("nginx"));
+
+        assert!(kuboard_read_recording(&dir, "../rec-1").is_err());
+        kuboard_delete_recording(&dir, "rec-1").unwrap();
+        assert!(kuboard_list_recordings(&dir).unwrap().is_empty());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
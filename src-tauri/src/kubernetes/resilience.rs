@@ -0,0 +1,156 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Workload Resilience
+// Finds availability gaps: missing PodDisruptionBudgets, no spreading across nodes, single replicas
+
+use anyhow::Result;
+use kube::api::ListParams;
+use kube::{Api, Client};
+use k8s_openapi::api::apps::v1::{Deployment, StatefulSet};
+use k8s_openapi::api::core::v1::{Pod, PodTemplateSpec};
+use k8s_openapi::api::policy::v1::PodDisruptionBudget;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::{LabelSelector, ObjectMeta};
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::kubernetes::selectors::kuboard_label_selector_matches;
+use crate::types::WorkloadResilience;
+
+// PodDisruptionBudgets in the namespace whose selector matches the labels;
+// an empty PDB selector matches every pod in the namespace
+pub fn kuboard_covering_pdbs<'a>(pdbs: &'a [PodDisruptionBudget], namespace: &str, labels: &BTreeMap<String, String>) -> Vec<&'a PodDisruptionBudget> {
+    pdbs.iter()
+        .filter(|pdb| pdb.metadata.namespace.as_deref() == Some(namespace))
+        .filter(|pdb| {
+            let selector = pdb.spec.as_ref().and_then(|s| s.selector.clone()).unwrap_or_default();
+            kuboard_label_selector_matches(&selector, labels)
+        })
+        .collect()
+}
+
+fn has_topology_spread(template: &PodTemplateSpec) -> bool {
+    template.spec.as_ref()
+        .and_then(|s| s.topology_spread_constraints.as_ref())
+        .is_some_and(|c| !c.is_empty())
+}
+
+fn has_anti_affinity(template: &PodTemplateSpec) -> bool {
+    let anti_affinity = template.spec.as_ref()
+        .and_then(|s| s.affinity.as_ref())
+        .and_then(|a| a.pod_anti_affinity.as_ref());
+    anti_affinity.is_some_and(|a| {
+        a.required_during_scheduling_ignored_during_execution.as_ref().is_some_and(|t| !t.is_empty())
+            || a.preferred_during_scheduling_ignored_during_execution.as_ref().is_some_and(|t| !t.is_empty())
+    })
+}
+
+// Gaps for a workload given its replica count, protections and the nodes its pods run on
+pub fn kuboard_resilience_gaps(replicas: i32, has_pdb: bool, spreads: bool, nodes: &[String]) -> Vec<String> {
+    let mut gaps = Vec::new();
+    if replicas == 1 {
+        gaps.push(match nodes.first() {
+            Some(node) => format!("Single replica on node {}; any node disruption takes it down", node),
+            None => "Single replica; any node disruption takes it down".to_string(),
+        });
+        return gaps;
+    }
+    if !has_pdb {
+        gaps.push("No PodDisruptionBudget".to_string());
+    }
+    if !spreads {
+        gaps.push("No topology spread constraints or pod anti-affinity".to_string());
+    }
+    if nodes.len() == 1 {
+        gaps.push(format!("All {} replicas run on node {}", replicas, nodes[0]));
+    }
+    gaps
+}
+
+fn assess(
+    kind: &str,
+    metadata: &ObjectMeta,
+    replicas: Option<i32>,
+    selector: &LabelSelector,
+    template: &PodTemplateSpec,
+    pods: &[Pod],
+    pdbs: &[PodDisruptionBudget],
+) -> WorkloadResilience {
+    let namespace = metadata.namespace.clone().unwrap_or_default();
+    let replicas = replicas.unwrap_or(1);
+    let template_labels = template.metadata.as_ref().and_then(|m| m.labels.clone()).unwrap_or_default();
+    let has_pdb = !kuboard_covering_pdbs(pdbs, &namespace, &template_labels).is_empty();
+    let has_topology_spread = has_topology_spread(template);
+    let has_anti_affinity = has_anti_affinity(template);
+
+    let nodes: Vec<String> = pods.iter()
+        .filter(|pod| pod.metadata.namespace.as_deref() == Some(namespace.as_str()))
+        .filter(|pod| pod.metadata.labels.as_ref().is_some_and(|labels| kuboard_label_selector_matches(selector, labels)))
+        .filter_map(|pod| pod.spec.as_ref()?.node_name.clone())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+
+    WorkloadResilience {
+        kind: kind.to_string(),
+        name: metadata.name.clone().unwrap_or_default(),
+        gaps: kuboard_resilience_gaps(replicas, has_pdb, has_topology_spread || has_anti_affinity, &nodes),
+        namespace,
+        replicas,
+        has_pdb,
+        has_topology_spread,
+        has_anti_affinity,
+        nodes,
+    }
+}
+
+// Deployments and StatefulSets with at least one availability gap; scaled-to-zero workloads are skipped
+pub async fn kuboard_find_resilience_gaps(client: &Client, namespace: Option<&str>) -> Result<Vec<WorkloadResilience>> {
+    let params = ListParams::default();
+    let (deployments, stateful_sets, pods, pdbs) = match namespace {
+        Some(namespace) => (
+            Api::<Deployment>::namespaced(client.clone(), namespace).list(&params).await?.items,
+            Api::<StatefulSet>::namespaced(client.clone(), namespace).list(&params).await?.items,
+            Api::<Pod>::namespaced(client.clone(), namespace).list(&params).await?.items,
+            Api::<PodDisruptionBudget>::namespaced(client.clone(), namespace).list(&params).await?.items,
+        ),
+        None => (
+            Api::<Deployment>::all(client.clone()).list(&params).await?.items,
+            Api::<StatefulSet>::all(client.clone()).list(&params).await?.items,
+            Api::<Pod>::all(client.clone()).list(&params).await?.items,
+            Api::<PodDisruptionBudget>::all(client.clone()).list(&params).await?.items,
+        ),
+    };
+
+    let deployments = deployments.iter().filter_map(|d| {
+        let spec = d.spec.as_ref()?;
+        Some(assess("Deployment", &d.metadata, spec.replicas, &spec.selector, &spec.template, &pods, &pdbs))
+    });
+    let stateful_sets = stateful_sets.iter().filter_map(|s| {
+        let spec = s.spec.as_ref()?;
+        Some(assess("StatefulSet", &s.metadata, spec.replicas, &spec.selector, &spec.template, &pods, &pdbs))
+    });
+
+    Ok(deployments
+        .chain(stateful_sets)
+        .filter(|w| w.replicas > 0 && !w.gaps.is_empty())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resilience_gaps() {
+        let one_node = vec!["node-a".to_string()];
+        let two_nodes = vec!["node-a".to_string(), "node-b".to_string()];
+
+        assert!(kuboard_resilience_gaps(3, true, true, &two_nodes).is_empty());
+        assert_eq!(kuboard_resilience_gaps(3, false, false, &one_node), vec![
+            "No PodDisruptionBudget".to_string(),
+            "No topology spread constraints or pod anti-affinity".to_string(),
+            "All 3 replicas run on node node-a".to_string(),
+        ]);
+        assert_eq!(kuboard_resilience_gaps(1, true, true, &one_node).len(), 1);
+    }
+}
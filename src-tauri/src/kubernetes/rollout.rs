@@ -0,0 +1,272 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Deployment Rollouts
+// A Deployment's revisions are the ReplicaSets it owns, numbered by their revision annotation;
+// rolling back copies an older ReplicaSet's pod template into the Deployment, like kubectl rollout undo
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Duration, Utc};
+use k8s_openapi::api::apps::v1::{Deployment, ReplicaSet};
+use k8s_openapi::api::core::v1::PodTemplateSpec;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector;
+use kube::api::ListParams;
+use kube::{Api, Client};
+
+use crate::kubernetes::change_cause::CHANGE_CAUSE_ANNOTATION;
+use crate::kubernetes::selectors::kuboard_label_selector_string;
+use crate::types::{DeploymentRevision, DeploymentRolloutStatus, RolloutState};
+use crate::utils::kuboard_format_age;
+
+pub const REVISION_ANNOTATION: &str = "deployment.kubernetes.io/revision";
+
+// Added by the deployment controller to tell its ReplicaSets apart; never part of the Deployment's template
+const POD_TEMPLATE_HASH_LABEL: &str = "pod-template-hash";
+
+// The API server's default for spec.progressDeadlineSeconds
+const DEFAULT_PROGRESS_DEADLINE_SECONDS: i32 = 600;
+
+// ReplicaSets owned by the named Deployment, narrowed server-side with its selector
+pub async fn kuboard_list_deployment_replicasets(
+    client: &Client,
+    namespace: &str,
+    name: &str,
+    selector: &LabelSelector,
+) -> Result<Vec<ReplicaSet>> {
+    let label_selector = kuboard_label_selector_string(selector)?;
+    if label_selector.is_empty() {
+        return Ok(Vec::new());
+    }
+    let replicasets_api: Api<ReplicaSet> = Api::namespaced(client.clone(), namespace);
+    let replicasets = replicasets_api.list(&ListParams::default().labels(&label_selector)).await?;
+    Ok(replicasets.items.into_iter()
+        .filter(|rs| {
+            rs.metadata.owner_references.as_ref()
+                .is_some_and(|owners| owners.iter().any(|owner| owner.kind == "Deployment" && owner.name == name))
+        })
+        .collect())
+}
+
+pub fn kuboard_replicaset_revision(replicaset: &ReplicaSet) -> Option<i64> {
+    replicaset.metadata.annotations.as_ref()?.get(REVISION_ANNOTATION)?.parse().ok()
+}
+
+// The ReplicaSet to roll back to: the requested revision, or without one (or 0, as kubectl takes
+// it) the revision before the latest
+pub fn kuboard_rollback_target(replicasets: &[ReplicaSet], revision: Option<i64>) -> Result<&ReplicaSet> {
+    let mut revisions: Vec<(i64, &ReplicaSet)> = replicasets.iter()
+        .filter_map(|rs| kuboard_replicaset_revision(rs).map(|revision| (revision, rs)))
+        .collect();
+    revisions.sort_by_key(|(revision, _)| std::cmp::Reverse(*revision));
+
+    match revision.filter(|revision| *revision != 0) {
+        Some(wanted) => revisions.iter()
+            .find(|(revision, _)| *revision == wanted)
+            .map(|(_, rs)| *rs)
+            .ok_or_else(|| anyhow!("Revision {} not found", wanted)),
+        None => revisions.get(1)
+            .map(|(_, rs)| *rs)
+            .ok_or_else(|| anyhow!("No previous revision to roll back to")),
+    }
+}
+
+// Every revision the ReplicaSets still record, newest first; ReplicaSets without a revision
+// annotation weren't created by the deployment controller and are left out
+pub fn kuboard_rollout_history(replicasets: &[ReplicaSet]) -> Vec<DeploymentRevision> {
+    let mut history: Vec<DeploymentRevision> = replicasets.iter()
+        .filter_map(|rs| {
+            let revision = kuboard_replicaset_revision(rs)?;
+            let spec = rs.spec.as_ref();
+            let pod_spec = spec.and_then(|s| s.template.as_ref()).and_then(|t| t.spec.as_ref());
+            let created = rs.metadata.creation_timestamp.as_ref().map(|t| t.0);
+            Some(DeploymentRevision {
+                revision,
+                replicaset: rs.metadata.name.clone().unwrap_or_default(),
+                change_cause: rs.metadata.annotations.as_ref().and_then(|a| a.get(CHANGE_CAUSE_ANNOTATION)).cloned(),
+                images: pod_spec
+                    .map(|s| s.init_containers.iter().flatten().chain(&s.containers).filter_map(|c| c.image.clone()).collect())
+                    .unwrap_or_default(),
+                replicas: spec.and_then(|s| s.replicas).unwrap_or(0),
+                created: created.map(|t| t.to_rfc3339()),
+                age: created.map(kuboard_format_age),
+                current: false,
+            })
+        })
+        .collect();
+    history.sort_by_key(|revision| std::cmp::Reverse(revision.revision));
+    if let Some(latest) = history.first_mut() {
+        latest.current = true;
+    }
+    history
+}
+
+// Follows kubectl rollout status: done once the controller has observed the latest spec, every pod
+// runs the new template and the new pods are available. A rollout whose last progress is older
+// than its deadline counts as stuck even before the controller reports ProgressDeadlineExceeded
+pub fn kuboard_rollout_status(deployment: &Deployment, now: DateTime<Utc>) -> DeploymentRolloutStatus {
+    let name = deployment.metadata.name.clone().unwrap_or_default();
+    let spec = deployment.spec.as_ref();
+    let status = deployment.status.clone().unwrap_or_default();
+    let desired = spec.and_then(|s| s.replicas).unwrap_or(1);
+    let paused = spec.and_then(|s| s.paused).unwrap_or(false);
+    let progress_deadline_seconds = spec.and_then(|s| s.progress_deadline_seconds).unwrap_or(DEFAULT_PROGRESS_DEADLINE_SECONDS);
+    let replicas = status.replicas.unwrap_or(0);
+    let updated = status.updated_replicas.unwrap_or(0);
+    let available = status.available_replicas.unwrap_or(0);
+    let observed = status.observed_generation.unwrap_or(0) >= deployment.metadata.generation.unwrap_or(0);
+
+    let progressing = status.conditions.iter().flatten().find(|c| c.type_ == "Progressing");
+    let last_progress = progressing
+        .and_then(|c| c.last_update_time.as_ref().or(c.last_transition_time.as_ref()))
+        .map(|t| t.0);
+
+    let waiting = |detail: String| format!("Waiting for deployment \"{}\" rollout to finish: {}", name, detail);
+    let pending = if !observed {
+        Some("Waiting for deployment spec update to be observed...".to_string())
+    } else if updated < desired {
+        Some(waiting(format!("{} out of {} new replicas have been updated...", updated, desired)))
+    } else if replicas > updated {
+        Some(waiting(format!("{} old replicas are pending termination...", replicas - updated)))
+    } else if available < updated {
+        Some(waiting(format!("{} of {} updated replicas are available...", available, updated)))
+    } else {
+        None
+    };
+    let deadline_exceeded = progressing.is_some_and(|c| c.reason.as_deref() == Some("ProgressDeadlineExceeded"))
+        || last_progress.is_some_and(|at| now - at > Duration::seconds(progress_deadline_seconds as i64));
+
+    // The controller doesn't track progress while paused
+    let (state, message) = match pending {
+        _ if paused => (RolloutState::Paused, format!("deployment \"{}\" is paused", name)),
+        Some(_) if deadline_exceeded => (RolloutState::Stuck, format!("deployment \"{}\" exceeded its progress deadline", name)),
+        Some(pending) => (RolloutState::Progressing, pending),
+        None => (RolloutState::Complete, format!("deployment \"{}\" successfully rolled out", name)),
+    };
+
+    DeploymentRolloutStatus {
+        namespace: deployment.metadata.namespace.clone().unwrap_or_default(),
+        state,
+        message,
+        revision: deployment.metadata.annotations.as_ref()
+            .and_then(|a| a.get(REVISION_ANNOTATION))
+            .and_then(|revision| revision.parse().ok()),
+        paused,
+        desired,
+        updated,
+        ready: status.ready_replicas.unwrap_or(0),
+        available,
+        unavailable: status.unavailable_replicas.unwrap_or(0),
+        old_replicas: (replicas - updated).max(0),
+        progress_deadline_seconds,
+        progressing_reason: progressing.and_then(|c| c.reason.clone()),
+        progressing_message: progressing.and_then(|c| c.message.clone()),
+        last_progress: last_progress.map(|t| t.to_rfc3339()),
+        name,
+    }
+}
+
+// The ReplicaSet's pod template as the Deployment would declare it
+pub fn kuboard_rollback_template(replicaset: &ReplicaSet) -> Result<PodTemplateSpec> {
+    let mut template = replicaset.spec.as_ref()
+        .and_then(|spec| spec.template.clone())
+        .ok_or_else(|| anyhow!("ReplicaSet {} has no pod template", replicaset.metadata.name.as_deref().unwrap_or_default()))?;
+    if let Some(labels) = template.metadata.as_mut().and_then(|metadata| metadata.labels.as_mut()) {
+        labels.remove(POD_TEMPLATE_HASH_LABEL);
+    }
+    Ok(template)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn replicaset(name: &str, revision: &str, image: &str) -> ReplicaSet {
+        serde_json::from_value(json!({
+            "metadata": { "name": name, "annotations": { REVISION_ANNOTATION: revision } },
+            "spec": {
+                "selector": { "matchLabels": { "app": "web" } },
+                "template": {
+                    "metadata": { "labels": { "app": "web", "pod-template-hash": name } },
+                    "spec": { "containers": [{ "name": "web", "image": image }] },
+                },
+            },
+        })).unwrap()
+    }
+
+    #[test]
+    fn test_rollback_target() {
+        let replicasets = vec![
+            replicaset("web-a", "1", "web:1"),
+            replicaset("web-c", "3", "web:3"),
+            replicaset("web-b", "2", "web:2"),
+        ];
+        let name = |rs: Result<&ReplicaSet>| rs.map(|rs| rs.metadata.name.clone().unwrap()).map_err(|e| e.to_string());
+
+        assert_eq!(name(kuboard_rollback_target(&replicasets, None)), Ok("web-b".to_string()));
+        assert_eq!(name(kuboard_rollback_target(&replicasets, Some(0))), Ok("web-b".to_string()));
+        assert_eq!(name(kuboard_rollback_target(&replicasets, Some(1))), Ok("web-a".to_string()));
+        assert_eq!(name(kuboard_rollback_target(&replicasets, Some(7))), Err("Revision 7 not found".to_string()));
+        assert!(kuboard_rollback_target(&replicasets[..1], None).is_err());
+
+        let template = kuboard_rollback_template(&replicasets[0]).unwrap();
+        let labels = template.metadata.unwrap().labels.unwrap();
+        assert_eq!(labels.keys().collect::<Vec<_>>(), vec!["app"]);
+        assert_eq!(template.spec.unwrap().containers[0].image.as_deref(), Some("web:1"));
+    }
+
+    #[test]
+    fn test_rollout_history() {
+        let mut annotated = replicaset("web-b", "2", "web:2");
+        annotated.metadata.annotations.as_mut().unwrap()
+            .insert(CHANGE_CAUSE_ANNOTATION.to_string(), "set image deployment/web web=web:2".to_string());
+        let mut unrevisioned = replicaset("web-x", "1", "web:x");
+        unrevisioned.metadata.annotations = None;
+
+        let history = kuboard_rollout_history(&[replicaset("web-a", "1", "web:1"), unrevisioned, annotated]);
+        let summary: Vec<(i64, bool, &str)> = history.iter().map(|r| (r.revision, r.current, r.images[0].as_str())).collect();
+        assert_eq!(summary, vec![(2, true, "web:2"), (1, false, "web:1")]);
+        assert_eq!(history[0].change_cause.as_deref(), Some("set image deployment/web web=web:2"));
+        assert_eq!(history[1].change_cause, None);
+    }
+
+    #[test]
+    fn test_rollout_status() {
+        let now: DateTime<Utc> = "2025-01-01T01:00:00Z".parse().unwrap();
+        let deployment = |paused: bool, status: serde_json::Value| -> Deployment {
+            serde_json::from_value(json!({
+                "metadata": { "name": "web", "namespace": "shop", "generation": 4, "annotations": { REVISION_ANNOTATION: "3" } },
+                "spec": { "replicas": 3, "paused": paused, "selector": {}, "template": {}, "progressDeadlineSeconds": 600 },
+                "status": status,
+            })).unwrap()
+        };
+        let progressing = |reason: &str, at: &str| json!([{
+            "type": "Progressing", "status": "True", "reason": reason, "lastUpdateTime": at, "lastTransitionTime": at,
+        }]);
+
+        let done = kuboard_rollout_status(&deployment(false, json!({
+            "observedGeneration": 4, "replicas": 3, "updatedReplicas": 3, "readyReplicas": 3, "availableReplicas": 3,
+            "conditions": progressing("NewReplicaSetAvailable", "2025-01-01T00:00:00Z"),
+        })), now);
+        assert_eq!((done.state, done.message.as_str()), (RolloutState::Complete, "deployment \"web\" successfully rolled out"));
+        assert_eq!(done.revision, Some(3));
+
+        let rolling = json!({
+            "observedGeneration": 4, "replicas": 4, "updatedReplicas": 2, "readyReplicas": 3, "availableReplicas": 3, "unavailableReplicas": 1,
+            "conditions": progressing("ReplicaSetUpdated", "2025-01-01T00:58:00Z"),
+        });
+        let status = kuboard_rollout_status(&deployment(false, rolling.clone()), now);
+        assert_eq!(status.state, RolloutState::Progressing);
+        assert_eq!(status.message, "Waiting for deployment \"web\" rollout to finish: 2 out of 3 new replicas have been updated...");
+        assert_eq!((status.old_replicas, status.unavailable), (2, 1));
+        assert_eq!(kuboard_rollout_status(&deployment(true, rolling.clone()), now).state, RolloutState::Paused);
+
+        // No progress for 15 minutes, not yet flagged by the controller
+        let mut quiet = rolling;
+        quiet["conditions"] = progressing("ReplicaSetUpdated", "2025-01-01T00:45:00Z");
+        assert_eq!(kuboard_rollout_status(&deployment(false, quiet), now).state, RolloutState::Stuck);
+
+        let unobserved = kuboard_rollout_status(&deployment(false, json!({ "observedGeneration": 3 })), now);
+        assert_eq!(unobserved.message, "Waiting for deployment spec update to be observed...");
+    }
+}
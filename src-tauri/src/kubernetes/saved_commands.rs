@@ -0,0 +1,173 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Saved Commands Library
+// Persists frequently used exec commands and log queries per kubeconfig context
+
+use anyhow::{anyhow, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::types::{SavedCommand, SavedCommandKind, SavedCommandRequest};
+
+// Exec output kept for a run; longer output is truncated
+pub const MAX_SAVED_COMMAND_OUTPUT_BYTES: u64 = 1024 * 1024;
+// Saved exec commands that haven't exited by then (e.g. `tail -f`, `top`) are aborted
+pub const SAVED_COMMAND_TIMEOUT: Duration = Duration::from_secs(30);
+
+pub fn kuboard_saved_commands_path() -> Result<PathBuf> {
+    dirs::data_local_dir()
+        .map(|dir| dir.join("kuboard").join("saved_commands.json"))
+        .ok_or_else(|| anyhow!("Could not find local data directory"))
+}
+
+pub fn kuboard_load_saved_commands(path: &Path) -> Result<Vec<SavedCommand>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+}
+
+fn store_saved_commands(path: &Path, commands: &[SavedCommand]) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    // Write then rename so a crash mid-write can't truncate the library
+    let tmp_path = path.with_extension("json.tmp");
+    fs::write(&tmp_path, serde_json::to_string_pretty(commands)?)?;
+    fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+pub fn kuboard_add_saved_command(path: &Path, context: &str, request: SavedCommandRequest) -> Result<SavedCommand> {
+    let name = request.name.trim().to_string();
+    if name.is_empty() {
+        return Err(anyhow!("Saved command name must not be empty"));
+    }
+    let command = request.command.unwrap_or_default();
+    if request.kind == SavedCommandKind::Exec && command.is_empty() {
+        return Err(anyhow!("Exec commands need at least one argument"));
+    }
+
+    let mut commands = kuboard_load_saved_commands(path)?;
+    if commands.iter().any(|c| c.context == context && c.name == name) {
+        return Err(anyhow!("A saved command named {} already exists for {}", name, context));
+    }
+
+    let saved = SavedCommand {
+        id: uuid::Uuid::new_v4().to_string(),
+        context: context.to_string(),
+        name,
+        kind: request.kind,
+        command,
+        log_filter: request.log_filter,
+        tail_lines: request.tail_lines,
+        created_at: chrono::Utc::now().to_rfc3339(),
+        last_run_at: None,
+        run_count: 0,
+    };
+    commands.push(saved.clone());
+    store_saved_commands(path, &commands)?;
+    Ok(saved)
+}
+
+// Most used first, so the library doubles as a history
+pub fn kuboard_list_saved_commands_for_context(path: &Path, context: &str) -> Result<Vec<SavedCommand>> {
+    let mut commands: Vec<SavedCommand> = kuboard_load_saved_commands(path)?
+        .into_iter()
+        .filter(|c| c.context == context)
+        .collect();
+    commands.sort_by(|a, b| b.run_count.cmp(&a.run_count).then_with(|| a.name.cmp(&b.name)));
+    Ok(commands)
+}
+
+pub fn kuboard_remove_saved_command(path: &Path, context: &str, id: &str) -> Result<()> {
+    let mut commands = kuboard_load_saved_commands(path)?;
+    let before = commands.len();
+    commands.retain(|c| !(c.context == context && c.id == id));
+    if commands.len() == before {
+        return Err(anyhow!("Saved command {} not found", id));
+    }
+    store_saved_commands(path, &commands)
+}
+
+pub fn kuboard_find_saved_command(path: &Path, context: &str, id: &str) -> Result<SavedCommand> {
+    kuboard_load_saved_commands(path)?
+        .into_iter()
+        .find(|c| c.context == context && c.id == id)
+        .ok_or_else(|| anyhow!("Saved command {} not found", id))
+}
+
+// Looks up a command in the context and records that it was run
+pub fn kuboard_mark_saved_command_run(path: &Path, context: &str, id: &str) -> Result<SavedCommand> {
+    let mut commands = kuboard_load_saved_commands(path)?;
+    let saved = commands
+        .iter_mut()
+        .find(|c| c.context == context && c.id == id)
+        .ok_or_else(|| anyhow!("Saved command {} not found", id))?;
+    saved.run_count += 1;
+    saved.last_run_at = Some(chrono::Utc::now().to_rfc3339());
+    let saved = saved.clone();
+    store_saved_commands(path, &commands)?;
+    Ok(saved)
+}
+
+pub fn kuboard_filter_log_lines(logs: &str, filter: Option<&str>) -> String {
+    match filter.filter(|f| !f.is_empty()) {
+        Some(filter) => logs
+            .lines()
+            .filter(|line| line.contains(filter))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        None => logs.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(name: &str, kind: SavedCommandKind, command: &[&str]) -> SavedCommandRequest {
+        SavedCommandRequest {
+            name: name.to_string(),
+            kind,
+            command: Some(command.iter().map(|c| c.to_string()).collect()),
+            log_filter: None,
+            tail_lines: None,
+        }
+    }
+
+    #[test]
+    fn test_saved_commands_are_scoped_per_context() {
+        let dir = std::env::temp_dir().join(format!("kuboard-saved-{}", uuid::Uuid::new_v4()));
+        let path = dir.join("saved_commands.json");
+
+        let env = kuboard_add_saved_command(&path, "prod", request("env", SavedCommandKind::Exec, &["env"])).unwrap();
+        kuboard_add_saved_command(&path, "prod", request("disk", SavedCommandKind::Exec, &["df", "-h"])).unwrap();
+        kuboard_add_saved_command(&path, "dev", request("env", SavedCommandKind::Exec, &["env"])).unwrap();
+        assert!(kuboard_add_saved_command(&path, "prod", request("env", SavedCommandKind::Exec, &["env"])).is_err());
+        assert!(kuboard_add_saved_command(&path, "prod", request("empty", SavedCommandKind::Exec, &[])).is_err());
+
+        kuboard_mark_saved_command_run(&path, "prod", &env.id).unwrap();
+        let prod = kuboard_list_saved_commands_for_context(&path, "prod").unwrap();
+        assert_eq!(prod.iter().map(|c| c.name.as_str()).collect::<Vec<_>>(), vec!["env", "disk"]);
+        assert_eq!(prod[0].run_count, 1);
+
+        // Ids from another context can't be used to run or delete
+        assert!(kuboard_find_saved_command(&path, "dev", &env.id).is_err());
+        assert!(kuboard_mark_saved_command_run(&path, "dev", &env.id).is_err());
+        assert_eq!(kuboard_find_saved_command(&path, "prod", &env.id).unwrap().run_count, 1);
+        kuboard_remove_saved_command(&path, "prod", &env.id).unwrap();
+        assert_eq!(kuboard_list_saved_commands_for_context(&path, "prod").unwrap().len(), 1);
+        assert_eq!(kuboard_list_saved_commands_for_context(&path, "dev").unwrap().len(), 1);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_filter_log_lines() {
+        let logs = "GET /health 200\nPOST /api 500\nGET /api 200";
+        assert_eq!(kuboard_filter_log_lines(logs, Some("/api")), "POST /api 500\nGET /api 200");
+        assert_eq!(kuboard_filter_log_lines(logs, Some("")), logs);
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Scale Guard
+// Scaling an object that a controller manages only lasts until the controller reconciles it, e.g.
+// a Deployment resets its ReplicaSets' replicas right away, so such scales are redirected to the controller
+
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+use crate::types::ManagedScaleWarning;
+
+// Controllers Kuboard can scale directly
+const SCALE_COMMANDS: &[(&str, &str)] = &[
+    ("Deployment", "kuboard_scale_deployment"),
+    ("StatefulSet", "kuboard_scale_statefulset"),
+];
+
+// The warning for scaling `kind` when its ownerReferences name a controller, None when nothing manages it
+pub fn kuboard_managed_scale_warning(kind: &str, metadata: &ObjectMeta) -> Option<ManagedScaleWarning> {
+    let owner = metadata.owner_references.as_ref()?.iter().find(|owner| owner.controller == Some(true))?;
+    let name = metadata.name.clone().unwrap_or_default();
+    let namespace = metadata.namespace.clone().unwrap_or_default();
+    let suggested_command = SCALE_COMMANDS.iter()
+        .find(|(controller_kind, _)| *controller_kind == owner.kind)
+        .map(|(_, command)| command.to_string());
+    Some(ManagedScaleWarning {
+        reason: "ManagedByController".to_string(),
+        message: format!(
+            "{} {}/{} is managed by {} {}, which will revert its replica count; scale the {} instead",
+            kind, namespace, name, owner.kind, owner.name, owner.kind,
+        ),
+        kind: kind.to_string(),
+        name,
+        namespace,
+        controller_kind: owner.kind.clone(),
+        controller_name: owner.name.clone(),
+        suggested_command,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn metadata(owners: serde_json::Value) -> ObjectMeta {
+        serde_json::from_value(json!({ "name": "web-7d9f8", "namespace": "shop", "ownerReferences": owners })).unwrap()
+    }
+
+    #[test]
+    fn test_managed_scale_warning() {
+        let owned = metadata(json!([{ "apiVersion": "apps/v1", "kind": "Deployment", "name": "web", "uid": "1", "controller": true }]));
+        let warning = kuboard_managed_scale_warning("ReplicaSet", &owned).unwrap();
+        assert_eq!((warning.controller_kind.as_str(), warning.controller_name.as_str()), ("Deployment", "web"));
+        assert_eq!(warning.suggested_command.as_deref(), Some("kuboard_scale_deployment"));
+        assert!(warning.message.starts_with("ReplicaSet shop/web-7d9f8 is managed by Deployment web"));
+
+        let rollout = metadata(json!([{ "apiVersion": "argoproj.io/v1alpha1", "kind": "Rollout", "name": "web", "uid": "1", "controller": true }]));
+        assert_eq!(kuboard_managed_scale_warning("ReplicaSet", &rollout).unwrap().suggested_command, None);
+
+        // An owner that isn't the controller doesn't reconcile replicas
+        let adopted = metadata(json!([{ "apiVersion": "v1", "kind": "ConfigMap", "name": "web", "uid": "1" }]));
+        assert!(kuboard_managed_scale_warning("ReplicaSet", &adopted).is_none());
+        assert!(kuboard_managed_scale_warning("ReplicaSet", &metadata(json!(null))).is_none());
+    }
+}
@@ -0,0 +1,575 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Scheduled Scaling Rules
+// Applies time-of-day scale-down/restore rules while the app runs, with an audit trail
+
+use chrono::{DateTime, Datelike, Local, NaiveDate, NaiveTime, TimeZone};
+use kube::api::ListParams;
+use kube::{Api, Client};
+use k8s_openapi::api::apps::v1::{Deployment, StatefulSet};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter};
+use tokio::sync::{mpsc, RwLock};
+use tokio::task::JoinHandle;
+use tracing::{error, info, warn};
+
+use crate::kubernetes::audit::kuboard_append_audit_entry;
+use crate::kubernetes::context_metadata::kuboard_load_context_metadata;
+use crate::kubernetes::guardrails::GuardrailPaths;
+use crate::kubernetes::{kuboard_create_client_from_context, kuboard_load_kubeconfig};
+use crate::kubernetes::workloads::{
+    kuboard_resume_workload_replicas, kuboard_suspend_workload_replicas, SUSPENDED_REPLICAS_ANNOTATION,
+};
+use crate::types::{AuditEntry, ContextMetadata};
+
+// Keep the audit trail bounded like drift alerts
+const MAX_SCALING_EXECUTIONS: usize = 200;
+const SCALING_CHECK_INTERVAL_SECONDS: u64 = 30;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScalingAction {
+    // Scale to zero, remembering replicas like kuboard_suspend_workload
+    ScaleDown,
+    // Restore replicas remembered by a previous scale-down
+    Restore,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScalingRuleRequest {
+    pub name: String,
+    // Defaults to the active context when the rule is created, and to the rule's own on update
+    #[serde(default)]
+    pub context: Option<String>,
+    pub namespace: String,
+    // "Deployment" and/or "StatefulSet"; empty means both
+    #[serde(default)]
+    pub kinds: Vec<String>,
+    pub label_selector: Option<String>,
+    pub action: ScalingAction,
+    // Local wall-clock time, "HH:MM"
+    pub time: String,
+    // 0 = Monday .. 6 = Sunday; empty means every day
+    #[serde(default)]
+    pub weekdays: Vec<u32>,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScalingRule {
+    pub id: String,
+    pub name: String,
+    pub context: String,
+    pub namespace: String,
+    pub kinds: Vec<String>,
+    pub label_selector: Option<String>,
+    pub action: ScalingAction,
+    pub time: String,
+    pub weekdays: Vec<u32>,
+    pub enabled: bool,
+    // The production confirmation used when the rule was created or last updated; without one a
+    // rule on a production context never fires
+    #[serde(default)]
+    pub confirmation_token: Option<String>,
+    // The context and targets the token was given for; see confirmation_scope
+    #[serde(default)]
+    pub confirmed_scope: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScalingExecution {
+    pub rule_id: String,
+    pub rule_name: String,
+    pub context: String,
+    pub executed_at: i64,
+    pub action: ScalingAction,
+    pub kind: String,
+    pub name: String,
+    pub namespace: String,
+    pub success: bool,
+    pub message: String,
+}
+
+fn validate_rule_request(request: &ScalingRuleRequest) -> Result<(), String> {
+    if request.name.trim().is_empty() {
+        return Err("Scaling rule name must not be empty".to_string());
+    }
+    if request.context.as_deref().is_some_and(|c| c.trim().is_empty()) {
+        return Err("Scaling rule context must not be empty".to_string());
+    }
+    if request.namespace.trim().is_empty() {
+        return Err("Scaling rules must target a namespace".to_string());
+    }
+    NaiveTime::parse_from_str(&request.time, "%H:%M")
+        .map_err(|_| format!("Invalid time {}, expected HH:MM", request.time))?;
+    if let Some(day) = request.weekdays.iter().find(|d| **d > 6) {
+        return Err(format!("Invalid weekday {}, expected 0 (Monday) to 6 (Sunday)", day));
+    }
+    if let Some(kind) = request.kinds.iter().find(|k| *k != "Deployment" && *k != "StatefulSet") {
+        return Err(format!("Scaling rules don't support {}", kind));
+    }
+    Ok(())
+}
+
+fn rule_from_request(id: String, context: String, request: ScalingRuleRequest, confirmation_token: Option<String>) -> ScalingRule {
+    let mut rule = ScalingRule {
+        id,
+        name: request.name.trim().to_string(),
+        context,
+        namespace: request.namespace,
+        kinds: request.kinds,
+        label_selector: request.label_selector.filter(|s| !s.trim().is_empty()),
+        action: request.action,
+        time: request.time,
+        weekdays: request.weekdays,
+        enabled: request.enabled,
+        confirmation_token,
+        confirmed_scope: None,
+    };
+    if rule.confirmation_token.is_some() {
+        rule.confirmed_scope = Some(confirmation_scope(&rule));
+    }
+    rule
+}
+
+// Everything that decides which cluster and workloads a rule scales; a confirmation only
+// covers the scope it was given for
+fn confirmation_scope(rule: &ScalingRule) -> String {
+    format!(
+        "{}/{}/{}/{}/{:?}",
+        rule.context,
+        rule.namespace,
+        rule.kinds.join(","),
+        rule.label_selector.as_deref().unwrap_or_default(),
+        rule.action,
+    )
+}
+
+// A rule fires once on each matching day, at the first check on or after its time
+pub fn kuboard_scaling_rule_due<Tz: TimeZone>(rule: &ScalingRule, now: &DateTime<Tz>, last_fired: Option<NaiveDate>) -> bool {
+    if !rule.enabled {
+        return false;
+    }
+    let Ok(time) = NaiveTime::parse_from_str(&rule.time, "%H:%M") else {
+        return false;
+    };
+    let today = now.date_naive();
+    let weekday_matches = rule.weekdays.is_empty() || rule.weekdays.contains(&now.weekday().num_days_from_monday());
+
+    weekday_matches && now.time() >= time && last_fired != Some(today)
+}
+
+fn rule_applies_to(rule: &ScalingRule, kind: &str) -> bool {
+    rule.kinds.is_empty() || rule.kinds.iter().any(|k| k == kind)
+}
+
+async fn rule_targets(client: &Client, rule: &ScalingRule) -> kube::Result<Vec<(String, String, bool)>> {
+    let params = match &rule.label_selector {
+        Some(selector) => ListParams::default().labels(selector),
+        None => ListParams::default(),
+    };
+    let suspended = |annotations: Option<&std::collections::BTreeMap<String, String>>| {
+        annotations.is_some_and(|a| a.contains_key(SUSPENDED_REPLICAS_ANNOTATION))
+    };
+
+    let mut targets = Vec::new();
+    if rule_applies_to(rule, "Deployment") {
+        let api: Api<Deployment> = Api::namespaced(client.clone(), &rule.namespace);
+        for deployment in api.list(&params).await?.items {
+            let is_suspended = suspended(deployment.metadata.annotations.as_ref());
+            targets.push(("Deployment".to_string(), deployment.metadata.name.unwrap_or_default(), is_suspended));
+        }
+    }
+    if rule_applies_to(rule, "StatefulSet") {
+        let api: Api<StatefulSet> = Api::namespaced(client.clone(), &rule.namespace);
+        for statefulset in api.list(&params).await?.items {
+            let is_suspended = suspended(statefulset.metadata.annotations.as_ref());
+            targets.push(("StatefulSet".to_string(), statefulset.metadata.name.unwrap_or_default(), is_suspended));
+        }
+    }
+    Ok(targets)
+}
+
+// Nobody is around to confirm a scheduled run, so a production rule has to have been confirmed
+// when it was created or updated
+fn scaling_guard(rule: &ScalingRule, metadata: &ContextMetadata) -> Result<(), String> {
+    if metadata.read_only {
+        return Err(format!("Context {} is read-only; scaling rule {} was skipped", rule.context, rule.name));
+    }
+    if metadata.is_protected() && rule.confirmation_token.is_none() {
+        return Err(format!(
+            "Context {} is a production context and scaling rule {} was not confirmed; update the rule to confirm it",
+            rule.context, rule.name
+        ));
+    }
+    if metadata.is_protected() && rule.confirmed_scope.as_deref() != Some(confirmation_scope(rule).as_str()) {
+        return Err(format!(
+            "Scaling rule {} was confirmed for a different context or target than {}/{}; update the rule to confirm it again",
+            rule.name, rule.context, rule.namespace
+        ));
+    }
+    Ok(())
+}
+
+fn scaling_audit_entry(rule: &ScalingRule, target: &str, outcome: &str) -> AuditEntry {
+    let action = match rule.action {
+        ScalingAction::ScaleDown => "scaling_rule_scale_down",
+        ScalingAction::Restore => "scaling_rule_restore",
+    };
+    AuditEntry {
+        timestamp: chrono::Utc::now().to_rfc3339(),
+        context: rule.context.clone(),
+        action: action.to_string(),
+        target: target.to_string(),
+        outcome: outcome.to_string(),
+        confirmation_token: None,
+    }
+}
+
+async fn rule_client(context: &str) -> anyhow::Result<Client> {
+    let kubeconfig = kuboard_load_kubeconfig().await?;
+    kuboard_create_client_from_context(&kubeconfig, context).await
+}
+
+// Applies a rule to every matching workload; workloads already in the target state are skipped.
+// The client is built for the rule's context on each run, so switching the active context never
+// points a rule at another cluster
+pub async fn execute_scaling_rule(rule: &ScalingRule, paths: &GuardrailPaths) -> Vec<ScalingExecution> {
+    let executed_at = chrono::Utc::now().timestamp();
+    let record = |kind: &str, name: &str, success: bool, message: String| ScalingExecution {
+        rule_id: rule.id.clone(),
+        rule_name: rule.name.clone(),
+        context: rule.context.clone(),
+        executed_at,
+        action: rule.action,
+        kind: kind.to_string(),
+        name: name.to_string(),
+        namespace: rule.namespace.clone(),
+        success,
+        message,
+    };
+
+    // Fails closed like production_guard: unreadable settings skip the run
+    let metadata = match kuboard_load_context_metadata(&paths.context_metadata) {
+        Ok(mut metadata) => metadata.remove(&rule.context).unwrap_or_default(),
+        Err(e) => return vec![record("", "", false, format!("Failed to read context settings: {}", e))],
+    };
+    if let Err(message) = scaling_guard(rule, &metadata) {
+        warn!("{}", message);
+        let outcome = if metadata.read_only { "blocked_read_only" } else { "confirmation_required" };
+        let entry = scaling_audit_entry(rule, &rule.namespace, outcome);
+        if let Err(e) = kuboard_append_audit_entry(&paths.audit_log, &entry) {
+            error!("Failed to write audit log entry for scaling rule {}: {}", rule.name, e);
+        }
+        return vec![record("", "", false, message)];
+    }
+
+    let client = match rule_client(&rule.context).await {
+        Ok(client) => client,
+        Err(e) => return vec![record("", "", false, format!("Failed to connect to context {}: {}", rule.context, e))],
+    };
+
+    let targets = match rule_targets(&client, rule).await {
+        Ok(targets) => targets,
+        Err(e) => return vec![record("", "", false, format!("Failed to list workloads: {}", e))],
+    };
+
+    let mut executions = Vec::new();
+    for (kind, name, is_suspended) in targets {
+        let already_applied = match rule.action {
+            ScalingAction::ScaleDown => is_suspended,
+            ScalingAction::Restore => !is_suspended,
+        };
+        if already_applied {
+            continue;
+        }
+        // Every scale is audited before it's made; without the entry it isn't made at all
+        let target = format!("{}/{}/{}", kind, rule.namespace, name);
+        if let Err(e) = kuboard_append_audit_entry(&paths.audit_log, &scaling_audit_entry(rule, &target, "scheduled")) {
+            error!("Failed to write audit log entry for {}: {}", target, e);
+            executions.push(record(&kind, &name, false, format!("Failed to write the audit log, so {} was not scaled: {}", target, e)));
+            continue;
+        }
+        let result = match rule.action {
+            ScalingAction::ScaleDown => kuboard_suspend_workload_replicas(&client, &kind, &rule.namespace, &name).await
+                .map(|s| format!("Scaled to 0 (was {})", s.suspended_replicas.unwrap_or(0))),
+            ScalingAction::Restore => kuboard_resume_workload_replicas(&client, &kind, &rule.namespace, &name).await
+                .map(|s| format!("Restored to {} replicas", s.replicas)),
+        };
+        match result {
+            Ok(message) => executions.push(record(&kind, &name, true, message)),
+            Err(e) => executions.push(record(&kind, &name, false, e.to_string())),
+        }
+    }
+    executions
+}
+
+pub struct ScalingScheduler {
+    handle: Option<JoinHandle<()>>,
+    stop_tx: Option<mpsc::Sender<()>>,
+    rules: Arc<RwLock<Vec<ScalingRule>>>,
+    executions: Arc<RwLock<Vec<ScalingExecution>>>,
+}
+
+impl ScalingScheduler {
+    pub fn new() -> Self {
+        Self {
+            handle: None,
+            stop_tx: None,
+            rules: Arc::new(RwLock::new(Vec::new())),
+            executions: Arc::new(RwLock::new(Vec::new())),
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.handle.is_some()
+    }
+
+    pub async fn rules(&self) -> Vec<ScalingRule> {
+        self.rules.read().await.clone()
+    }
+
+    pub async fn rule(&self, id: &str) -> Option<ScalingRule> {
+        self.rules.read().await.iter().find(|r| r.id == id).cloned()
+    }
+
+    pub async fn executions(&self) -> Vec<ScalingExecution> {
+        self.executions.read().await.clone()
+    }
+
+    pub async fn add_rule(&self, request: ScalingRuleRequest, confirmation_token: Option<String>) -> Result<ScalingRule, String> {
+        validate_rule_request(&request)?;
+        let context = request.context.clone().ok_or_else(|| "Scaling rules must target a context".to_string())?;
+        let rule = rule_from_request(uuid::Uuid::new_v4().to_string(), context, request, confirmation_token);
+        self.rules.write().await.push(rule.clone());
+        Ok(rule)
+    }
+
+    pub async fn update_rule(&self, id: &str, request: ScalingRuleRequest, confirmation_token: Option<String>) -> Result<ScalingRule, String> {
+        validate_rule_request(&request)?;
+        let mut rules = self.rules.write().await;
+        let existing = rules.iter_mut().find(|r| r.id == id)
+            .ok_or_else(|| format!("Scaling rule {} not found", id))?;
+        let context = request.context.clone().unwrap_or_else(|| existing.context.clone());
+        *existing = rule_from_request(id.to_string(), context, request, confirmation_token);
+        Ok(existing.clone())
+    }
+
+    pub async fn delete_rule(&self, id: &str) -> Result<(), String> {
+        let mut rules = self.rules.write().await;
+        let before = rules.len();
+        rules.retain(|r| r.id != id);
+        if rules.len() == before {
+            return Err(format!("Scaling rule {} not found", id));
+        }
+        Ok(())
+    }
+
+    pub async fn record(&self, executions: Vec<ScalingExecution>) {
+        record_executions(&self.executions, executions).await;
+    }
+
+    pub fn stop(&mut self) {
+        if let Some(tx) = self.stop_tx.take() {
+            let _ = tx.try_send(());
+        }
+        if let Some(handle) = self.handle.take() {
+            handle.abort();
+        }
+    }
+
+    pub async fn start(&mut self, app_handle: AppHandle, paths: GuardrailPaths) -> Result<(), String> {
+        self.stop();
+
+        info!("Starting scaling scheduler");
+
+        let rules = self.rules.clone();
+        let executions = self.executions.clone();
+        let (stop_tx, mut stop_rx) = mpsc::channel::<()>(1);
+
+        let handle = tokio::spawn(async move {
+            // Rules whose time already passed today shouldn't fire just because the app started
+            let started = Local::now();
+            let mut last_fired: HashMap<String, NaiveDate> = rules.read().await.iter()
+                .filter(|rule| kuboard_scaling_rule_due(rule, &started, None))
+                .map(|rule| (rule.id.clone(), started.date_naive()))
+                .collect();
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(SCALING_CHECK_INTERVAL_SECONDS));
+
+            loop {
+                tokio::select! {
+                    _ = stop_rx.recv() => {
+                        info!("Scaling scheduler stopped by user");
+                        break;
+                    }
+                    _ = interval.tick() => {
+                        let now = Local::now();
+                        let due: Vec<ScalingRule> = rules.read().await.iter()
+                            .filter(|rule| kuboard_scaling_rule_due(rule, &now, last_fired.get(&rule.id).copied()))
+                            .cloned()
+                            .collect();
+
+                        for rule in due {
+                            last_fired.insert(rule.id.clone(), now.date_naive());
+                            info!("Running scaling rule {} for {}/{}", rule.name, rule.context, rule.namespace);
+
+                            let results = execute_scaling_rule(&rule, &paths).await;
+                            for failure in results.iter().filter(|r| !r.success) {
+                                warn!("Scaling rule {} failed for {}/{}: {}", rule.name, failure.kind, failure.name, failure.message);
+                            }
+                            if let Err(e) = app_handle.emit("scaling-rule-executed", &results) {
+                                error!("Failed to emit scaling rule execution: {}", e);
+                            }
+                            record_executions(&executions, results).await;
+                        }
+                    }
+                }
+            }
+
+            info!("Scaling scheduler task completed");
+        });
+
+        self.handle = Some(handle);
+        self.stop_tx = Some(stop_tx);
+
+        Ok(())
+    }
+}
+
+async fn record_executions(executions: &RwLock<Vec<ScalingExecution>>, results: Vec<ScalingExecution>) {
+    let mut executions_guard = executions.write().await;
+    executions_guard.extend(results);
+    if executions_guard.len() > MAX_SCALING_EXECUTIONS {
+        let excess = executions_guard.len() - MAX_SCALING_EXECUTIONS;
+        executions_guard.drain(..excess);
+    }
+}
+
+impl Drop for ScalingScheduler {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ContextEnvironment;
+    use chrono::Utc;
+
+    fn rule(time: &str, weekdays: Vec<u32>) -> ScalingRule {
+        rule_from_request("rule-1".to_string(), "dev-cluster".to_string(), ScalingRuleRequest {
+            name: "dev nightly".to_string(),
+            context: None,
+            namespace: "dev".to_string(),
+            kinds: vec![],
+            label_selector: None,
+            action: ScalingAction::ScaleDown,
+            time: time.to_string(),
+            weekdays,
+            enabled: true,
+        }, None)
+    }
+
+    #[test]
+    fn test_scaling_rule_due_once_per_matching_day() {
+        // 2025-01-06 is a Monday
+        let evening = Utc.with_ymd_and_hms(2025, 1, 6, 20, 0, 30).unwrap();
+        let morning = Utc.with_ymd_and_hms(2025, 1, 6, 8, 0, 0).unwrap();
+        let nightly = rule("20:00", vec![]);
+
+        assert!(kuboard_scaling_rule_due(&nightly, &evening, None));
+        assert!(!kuboard_scaling_rule_due(&nightly, &morning, None));
+        assert!(!kuboard_scaling_rule_due(&nightly, &evening, Some(evening.date_naive())));
+
+        let weekends = rule("20:00", vec![5, 6]);
+        assert!(!kuboard_scaling_rule_due(&weekends, &evening, None));
+    }
+
+    #[test]
+    fn test_scaling_rule_request_validation() {
+        let mut request = ScalingRuleRequest {
+            name: "dev".to_string(),
+            context: Some("dev-cluster".to_string()),
+            namespace: "dev".to_string(),
+            kinds: vec!["Deployment".to_string()],
+            label_selector: None,
+            action: ScalingAction::Restore,
+            time: "08:00".to_string(),
+            weekdays: vec![0, 1, 2, 3, 4],
+            enabled: true,
+        };
+        assert!(validate_rule_request(&request).is_ok());
+
+        request.time = "8am".to_string();
+        assert!(validate_rule_request(&request).is_err());
+        request.time = "08:00".to_string();
+        request.kinds = vec!["DaemonSet".to_string()];
+        assert!(validate_rule_request(&request).is_err());
+        request.kinds = vec![];
+        request.context = Some(" ".to_string());
+        assert!(validate_rule_request(&request).is_err());
+    }
+
+    #[test]
+    fn test_scaling_guard_and_audit_entry() {
+        let nightly = rule("20:00", vec![]);
+        assert!(scaling_guard(&nightly, &ContextMetadata::default()).is_ok());
+        let read_only = ContextMetadata { read_only: true, ..Default::default() };
+        assert!(scaling_guard(&nightly, &read_only).unwrap_err().contains("dev-cluster is read-only"));
+        let prod = ContextMetadata { environment: Some(ContextEnvironment::Prod), ..Default::default() };
+        assert!(scaling_guard(&nightly, &prod).unwrap_err().contains("was not confirmed"));
+        let confirmed = rule_from_request("rule-1".to_string(), "dev-cluster".to_string(), ScalingRuleRequest {
+            name: nightly.name.clone(),
+            context: None,
+            namespace: "dev".to_string(),
+            kinds: vec![],
+            label_selector: None,
+            action: ScalingAction::ScaleDown,
+            time: "20:00".to_string(),
+            weekdays: vec![],
+            enabled: true,
+        }, Some("token".to_string()));
+        assert!(scaling_guard(&confirmed, &prod).is_ok());
+
+        let entry = scaling_audit_entry(&nightly, "Deployment/dev/api", "scheduled");
+        assert_eq!(entry.context, "dev-cluster");
+        assert_eq!(entry.action, "scaling_rule_scale_down");
+        assert_eq!(entry.target, "Deployment/dev/api");
+    }
+
+    #[test]
+    fn test_scaling_guard_rejects_stale_confirmation() {
+        let prod = ContextMetadata { environment: Some(ContextEnvironment::Prod), ..Default::default() };
+        let confirmed = rule_from_request("rule-1".to_string(), "prod-eu".to_string(), ScalingRuleRequest {
+            name: "shop nightly".to_string(),
+            context: None,
+            namespace: "shop".to_string(),
+            kinds: vec!["Deployment".to_string()],
+            label_selector: None,
+            action: ScalingAction::ScaleDown,
+            time: "20:00".to_string(),
+            weekdays: vec![],
+            enabled: true,
+        }, Some("token".to_string()));
+        assert!(scaling_guard(&confirmed, &prod).is_ok());
+
+        let moved = ScalingRule { context: "prod-us".to_string(), ..confirmed.clone() };
+        assert!(scaling_guard(&moved, &prod).unwrap_err().contains("confirmed for a different context or target"));
+        let widened = ScalingRule { namespace: "payments".to_string(), ..confirmed.clone() };
+        assert!(scaling_guard(&widened, &prod).is_err());
+        let reselected = ScalingRule { label_selector: Some("tier=db".to_string()), ..confirmed.clone() };
+        assert!(scaling_guard(&reselected, &prod).is_err());
+
+        // Outside production the scope doesn't matter
+        assert!(scaling_guard(&moved, &ContextMetadata::default()).is_ok());
+    }
+}
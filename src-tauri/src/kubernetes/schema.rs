@@ -0,0 +1,295 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Manifest Schema Validation
+// Checks manifests against the cluster's OpenAPI v3 schema before they are applied
+
+use anyhow::{anyhow, Result};
+use kube::Client;
+use serde_json::{Map, Value};
+
+use crate::kubernetes::manifests::kuboard_parse_manifest;
+use crate::types::{ManifestDiagnostic, ManifestValidation};
+
+// Nesting limit so recursive schemas (e.g. JSONSchemaProps) can't loop forever
+const MAX_SCHEMA_DEPTH: usize = 64;
+
+// Fetches the OpenAPI v3 document for one group/version, e.g. "apps/v1" or "v1"
+pub async fn fetch_openapi_document(client: &Client, api_version: &str) -> Result<Value> {
+    let path = if api_version.contains('/') {
+        format!("/openapi/v3/apis/{}", api_version)
+    } else {
+        format!("/openapi/v3/api/{}", api_version)
+    };
+    let req = http::Request::get(&path).body(vec![])?;
+    let text = client.request_text(req).await
+        .map_err(|e| anyhow!("Cluster does not serve an OpenAPI v3 schema for {}: {}", api_version, e))?;
+    Ok(serde_json::from_str(&text)?)
+}
+
+// Finds the component schema tagged with the manifest's group/version/kind
+fn schema_for_kind<'a>(document: &'a Value, api_version: &str, kind: &str) -> Option<&'a Value> {
+    let (group, version) = api_version.rsplit_once('/').unwrap_or(("", api_version));
+    document.pointer("/components/schemas")?.as_object()?.values().find(|schema| {
+        schema.get("x-kubernetes-group-version-kind")
+            .and_then(Value::as_array)
+            .is_some_and(|gvks| gvks.iter().any(|gvk| {
+                gvk.get("group").and_then(Value::as_str) == Some(group)
+                    && gvk.get("version").and_then(Value::as_str) == Some(version)
+                    && gvk.get("kind").and_then(Value::as_str) == Some(kind)
+            }))
+    })
+}
+
+// Follows a $ref, including the single-element allOf wrapper the API server emits
+fn resolve<'a>(document: &'a Value, schema: &'a Value) -> &'a Value {
+    let reference = schema.get("$ref").or_else(|| {
+        schema.get("allOf")
+            .and_then(Value::as_array)
+            .filter(|all| all.len() == 1)
+            .and_then(|all| all[0].get("$ref"))
+    });
+    reference
+        .and_then(Value::as_str)
+        .and_then(|r| r.strip_prefix('#'))
+        .and_then(|pointer| document.pointer(pointer))
+        .unwrap_or(schema)
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_i64() || n.is_u64() => "integer",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn type_matches(expected: &str, value: &Value) -> bool {
+    match (expected, type_name(value)) {
+        ("number", "integer") => true,
+        (expected, actual) => expected == actual,
+    }
+}
+
+fn diagnostic(path: &str, message: String) -> ManifestDiagnostic {
+    ManifestDiagnostic {
+        path: if path.is_empty() { "$".to_string() } else { path.to_string() },
+        message,
+    }
+}
+
+fn validate_object(
+    document: &Value,
+    schema: &Value,
+    object: &Map<String, Value>,
+    path: &str,
+    depth: usize,
+    diagnostics: &mut Vec<ManifestDiagnostic>,
+) {
+    let properties = schema.get("properties").and_then(Value::as_object);
+    let additional = schema.get("additionalProperties");
+
+    for required in schema.get("required").and_then(Value::as_array).into_iter().flatten().filter_map(Value::as_str) {
+        if !object.contains_key(required) {
+            diagnostics.push(diagnostic(&format!("{}.{}", path, required), "Missing required field".to_string()));
+        }
+    }
+
+    for (field, value) in object {
+        let field_path = format!("{}.{}", path, field);
+        match (properties.and_then(|p| p.get(field)), additional) {
+            (Some(field_schema), _) => validate_value(document, field_schema, value, &field_path, depth + 1, diagnostics),
+            (None, Some(additional_schema @ Value::Object(_))) => {
+                validate_value(document, additional_schema, value, &field_path, depth + 1, diagnostics)
+            }
+            (None, Some(Value::Bool(true))) => {}
+            // A schema without declared properties (e.g. RawExtension) accepts anything
+            (None, _) if properties.is_none() => {}
+            (None, _) => diagnostics.push(diagnostic(&field_path, "Unknown field".to_string())),
+        }
+    }
+}
+
+fn validate_value(
+    document: &Value,
+    schema: &Value,
+    value: &Value,
+    path: &str,
+    depth: usize,
+    diagnostics: &mut Vec<ManifestDiagnostic>,
+) {
+    let schema = resolve(document, schema);
+    // null clears a field, which the API server accepts anywhere
+    if depth > MAX_SCHEMA_DEPTH || value.is_null() {
+        return;
+    }
+    if schema.get("x-kubernetes-preserve-unknown-fields").and_then(Value::as_bool) == Some(true) {
+        return;
+    }
+    if schema.get("x-kubernetes-int-or-string").and_then(Value::as_bool) == Some(true) {
+        if !matches!(type_name(value), "integer" | "string") {
+            diagnostics.push(diagnostic(path, format!("Expected integer or string, got {}", type_name(value))));
+        }
+        return;
+    }
+
+    if let Some(expected) = schema.get("type").and_then(Value::as_str) {
+        if !type_matches(expected, value) {
+            diagnostics.push(diagnostic(path, format!("Expected {}, got {}", expected, type_name(value))));
+            return;
+        }
+    }
+
+    match value {
+        Value::Object(object) => validate_object(document, schema, object, path, depth, diagnostics),
+        Value::Array(items) => {
+            if let Some(item_schema) = schema.get("items") {
+                for (index, item) in items.iter().enumerate() {
+                    validate_value(document, item_schema, item, &format!("{}[{}]", path, index), depth + 1, diagnostics);
+                }
+            }
+        }
+        Value::String(s) => {
+            if let Some(allowed) = schema.get("enum").and_then(Value::as_array) {
+                if !allowed.iter().any(|a| a.as_str() == Some(s)) {
+                    diagnostics.push(diagnostic(path, format!("Unsupported value {:?}", s)));
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+// Validates a manifest against an OpenAPI v3 document for its group/version
+pub fn kuboard_validate_against_schema(document: &Value, manifest: &Value) -> ManifestValidation {
+    let api_version = manifest.get("apiVersion").and_then(Value::as_str).unwrap_or_default().to_string();
+    let kind = manifest.get("kind").and_then(Value::as_str).unwrap_or_default().to_string();
+
+    let diagnostics = match schema_for_kind(document, &api_version, &kind) {
+        Some(schema) => {
+            let mut diagnostics = Vec::new();
+            validate_value(document, schema, manifest, "", 0, &mut diagnostics);
+            diagnostics
+        }
+        None => vec![diagnostic("", format!("The cluster has no schema for {} {}", api_version, kind))],
+    };
+
+    ManifestValidation {
+        valid: diagnostics.is_empty(),
+        api_version,
+        kind,
+        diagnostics,
+    }
+}
+
+pub async fn kuboard_validate_manifest(client: &Client, content: &str) -> Result<ManifestValidation> {
+    let manifest: Value = match kuboard_parse_manifest(content) {
+        Ok(manifest) => manifest,
+        Err(e) => {
+            return Ok(ManifestValidation {
+                valid: false,
+                api_version: String::new(),
+                kind: String::new(),
+                diagnostics: vec![diagnostic("", format!("Invalid YAML/JSON format: {}", e))],
+            })
+        }
+    };
+
+    let missing: Vec<ManifestDiagnostic> = ["apiVersion", "kind"]
+        .iter()
+        .filter(|field| manifest.get(**field).and_then(Value::as_str).is_none())
+        .map(|field| diagnostic(&format!(".{}", field), "Missing required field".to_string()))
+        .collect();
+    if !missing.is_empty() {
+        return Ok(ManifestValidation {
+            valid: false,
+            api_version: String::new(),
+            kind: String::new(),
+            diagnostics: missing,
+        });
+    }
+
+    let api_version = manifest["apiVersion"].as_str().unwrap_or_default();
+    let document = fetch_openapi_document(client, api_version).await?;
+    Ok(kuboard_validate_against_schema(&document, &manifest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn document() -> Value {
+        json!({
+            "components": { "schemas": {
+                "io.k8s.api.apps.v1.Deployment": {
+                    "type": "object",
+                    "x-kubernetes-group-version-kind": [{ "group": "apps", "version": "v1", "kind": "Deployment" }],
+                    "properties": {
+                        "apiVersion": { "type": "string" },
+                        "kind": { "type": "string" },
+                        "metadata": { "allOf": [{ "$ref": "#/components/schemas/io.k8s.apimachinery.pkg.apis.meta.v1.ObjectMeta" }] },
+                        "spec": { "allOf": [{ "$ref": "#/components/schemas/io.k8s.api.apps.v1.DeploymentSpec" }] },
+                    },
+                },
+                "io.k8s.api.apps.v1.DeploymentSpec": {
+                    "type": "object",
+                    "required": ["selector", "template"],
+                    "properties": {
+                        "replicas": { "type": "integer" },
+                        "selector": { "type": "object", "properties": { "matchLabels": { "type": "object", "additionalProperties": { "type": "string" } } } },
+                        "template": { "type": "object" },
+                        "strategy": { "type": "object", "properties": {
+                            "type": { "type": "string", "enum": ["Recreate", "RollingUpdate"] },
+                            "rollingUpdate": { "type": "object", "properties": { "maxSurge": { "x-kubernetes-int-or-string": true } } },
+                        } },
+                    },
+                },
+                "io.k8s.apimachinery.pkg.apis.meta.v1.ObjectMeta": {
+                    "type": "object",
+                    "properties": {
+                        "name": { "type": "string" },
+                        "labels": { "type": "object", "additionalProperties": { "type": "string" } },
+                    },
+                },
+            } },
+        })
+    }
+
+    #[test]
+    fn test_validate_reports_per_path_diagnostics() {
+        let manifest = json!({
+            "apiVersion": "apps/v1",
+            "kind": "Deployment",
+            "metadata": { "name": "web", "labels": { "tier": 1 }, "lables": {} },
+            "spec": {
+                "replicas": "3",
+                "selector": { "matchLabels": { "app": "web" } },
+                "strategy": { "type": "Rolling", "rollingUpdate": { "maxSurge": "25%" } },
+            },
+        });
+
+        let validation = kuboard_validate_against_schema(&document(), &manifest);
+        let paths: Vec<(&str, &str)> = validation.diagnostics.iter().map(|d| (d.path.as_str(), d.message.as_str())).collect();
+
+        assert!(!validation.valid);
+        assert!(paths.contains(&(".metadata.labels.tier", "Expected string, got integer")));
+        assert!(paths.contains(&(".metadata.lables", "Unknown field")));
+        assert!(paths.contains(&(".spec.template", "Missing required field")));
+        assert!(paths.contains(&(".spec.replicas", "Expected integer, got string")));
+        assert!(paths.contains(&(".spec.strategy.type", "Unsupported value \"Rolling\"")));
+        assert_eq!(paths.len(), 5);
+    }
+
+    #[test]
+    fn test_validate_unknown_kind() {
+        let manifest = json!({ "apiVersion": "apps/v1", "kind": "Widget" });
+        let validation = kuboard_validate_against_schema(&document(), &manifest);
+        assert!(!validation.valid);
+        assert_eq!(validation.diagnostics[0].path, "$");
+    }
+}
@@ -0,0 +1,131 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Label Selector Helpers
+// Converts workload selectors into API server label selector queries
+
+use anyhow::{anyhow, Result};
+use kube::api::ListParams;
+use kube::{Api, Client};
+use k8s_openapi::api::core::v1::Pod;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector;
+use std::collections::BTreeMap;
+
+// Renders a LabelSelector in the API server's query syntax,
+// e.g. "app=web,tier in (frontend,edge),!canary". An operator the API server doesn't know is an
+// error rather than a requirement that silently changes the selection
+pub fn kuboard_label_selector_string(selector: &LabelSelector) -> Result<String> {
+    let mut requirements: Vec<String> = selector.match_labels.as_ref()
+        .map(|labels| labels.iter().map(|(key, value)| format!("{}={}", key, value)).collect())
+        .unwrap_or_default();
+
+    for expression in selector.match_expressions.iter().flatten() {
+        let values = expression.values.clone().unwrap_or_default().join(",");
+        requirements.push(match expression.operator.as_str() {
+            "In" => format!("{} in ({})", expression.key, values),
+            "NotIn" => format!("{} notin ({})", expression.key, values),
+            "Exists" => expression.key.clone(),
+            "DoesNotExist" => format!("!{}", expression.key),
+            other => return Err(anyhow!("Unsupported label selector operator {} for key {}", other, expression.key)),
+        });
+    }
+
+    Ok(requirements.join(","))
+}
+
+// Evaluates a LabelSelector against a label set with the same semantics as the API server.
+// Callers decide what an empty selector means; here it matches everything, as in Kubernetes.
+pub fn kuboard_label_selector_matches(selector: &LabelSelector, labels: &BTreeMap<String, String>) -> bool {
+    let labels_match = selector.match_labels.as_ref()
+        .map(|match_labels| match_labels.iter().all(|(key, value)| labels.get(key) == Some(value)))
+        .unwrap_or(true);
+
+    labels_match && selector.match_expressions.iter().flatten().all(|expression| {
+        let value = labels.get(&expression.key);
+        let values = expression.values.as_deref().unwrap_or_default();
+        match expression.operator.as_str() {
+            "In" => value.is_some_and(|v| values.contains(v)),
+            "NotIn" => value.is_none_or(|v| !values.contains(v)),
+            "Exists" => value.is_some(),
+            "DoesNotExist" => value.is_none(),
+            _ => false,
+        }
+    })
+}
+
+// Services select pods with a plain label map
+pub fn kuboard_service_label_selector(selector: &BTreeMap<String, String>) -> LabelSelector {
+    LabelSelector {
+        match_labels: Some(selector.clone()),
+        match_expressions: None,
+    }
+}
+
+// Lists the pods a workload selector targets, letting the API server do the filtering.
+// An empty selector selects nothing rather than every pod in the namespace.
+pub async fn kuboard_list_pods_by_selector(client: &Client, namespace: &str, selector: &LabelSelector) -> Result<Vec<Pod>> {
+    let label_selector = kuboard_label_selector_string(selector)?;
+    if label_selector.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let pods_api: Api<Pod> = Api::namespaced(client.clone(), namespace);
+    let pods = pods_api.list(&ListParams::default().labels(&label_selector)).await?;
+    Ok(pods.items)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_label_selector_string_includes_match_expressions() {
+        let selector: LabelSelector = serde_json::from_value(serde_json::json!({
+            "matchLabels": { "app": "web" },
+            "matchExpressions": [
+                { "key": "tier", "operator": "In", "values": ["frontend", "edge"] },
+                { "key": "track", "operator": "NotIn", "values": ["canary"] },
+                { "key": "team", "operator": "Exists" },
+                { "key": "legacy", "operator": "DoesNotExist" }
+            ]
+        })).unwrap();
+
+        assert_eq!(
+            kuboard_label_selector_string(&selector).unwrap(),
+            "app=web,tier in (frontend,edge),track notin (canary),team,!legacy"
+        );
+        assert_eq!(kuboard_label_selector_string(&LabelSelector::default()).unwrap(), "");
+    }
+
+    #[test]
+    fn test_label_selector_string_rejects_unknown_operators() {
+        let selector: LabelSelector = serde_json::from_value(serde_json::json!({
+            "matchLabels": { "app": "web" },
+            "matchExpressions": [{ "key": "tier", "operator": "Gt", "values": ["1"] }]
+        })).unwrap();
+
+        let error = kuboard_label_selector_string(&selector).unwrap_err();
+        assert_eq!(error.to_string(), "Unsupported label selector operator Gt for key tier");
+    }
+
+    #[test]
+    fn test_label_selector_matches_all_operators() {
+        let selector: LabelSelector = serde_json::from_value(serde_json::json!({
+            "matchLabels": { "app": "web" },
+            "matchExpressions": [
+                { "key": "tier", "operator": "In", "values": ["frontend"] },
+                { "key": "track", "operator": "NotIn", "values": ["canary"] },
+                { "key": "team", "operator": "Exists" },
+                { "key": "legacy", "operator": "DoesNotExist" }
+            ]
+        })).unwrap();
+        let labels = |pairs: &[(&str, &str)]| -> BTreeMap<String, String> {
+            pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+        };
+
+        assert!(kuboard_label_selector_matches(&selector, &labels(&[("app", "web"), ("tier", "frontend"), ("team", "a")])));
+        assert!(!kuboard_label_selector_matches(&selector, &labels(&[("app", "web"), ("tier", "frontend"), ("team", "a"), ("track", "canary")])));
+        assert!(!kuboard_label_selector_matches(&selector, &labels(&[("app", "web"), ("tier", "frontend")])));
+        assert!(!kuboard_label_selector_matches(&selector, &labels(&[("app", "web"), ("tier", "frontend"), ("team", "a"), ("legacy", "1")])));
+    }
+}
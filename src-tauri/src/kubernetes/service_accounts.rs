@@ -0,0 +1,201 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// ServiceAccount Usage
+// Maps each ServiceAccount to the workloads running as it and the RBAC rules bound to it
+
+use anyhow::Result;
+use kube::api::ListParams;
+use kube::{Api, Client};
+use k8s_openapi::api::core::v1::{PodSpec, ServiceAccount};
+use k8s_openapi::api::rbac::v1::{ClusterRole, ClusterRoleBinding, PolicyRule, Role, RoleBinding, RoleRef, Subject};
+use std::collections::BTreeMap;
+
+use crate::kubernetes::workloads::kuboard_list_workload_pod_specs;
+use crate::types::{ServiceAccountGrant, ServiceAccountRule, ServiceAccountUsage, ServiceAccountWorkload};
+
+// Everything needed to resolve bindings, fetched once per namespace
+pub struct RbacSnapshot {
+    pub role_bindings: Vec<RoleBinding>,
+    pub cluster_role_bindings: Vec<ClusterRoleBinding>,
+    pub roles: Vec<Role>,
+    pub cluster_roles: Vec<ClusterRole>,
+}
+
+impl RbacSnapshot {
+    // Namespaced roles and bindings come from one namespace; None fetches only cluster-wide RBAC
+    pub async fn fetch(client: &Client, namespace: Option<&str>) -> Result<Self> {
+        let params = ListParams::default();
+        let (role_bindings, roles) = match namespace {
+            Some(namespace) => (
+                Api::<RoleBinding>::namespaced(client.clone(), namespace).list(&params).await?.items,
+                Api::<Role>::namespaced(client.clone(), namespace).list(&params).await?.items,
+            ),
+            None => (Vec::new(), Vec::new()),
+        };
+        Ok(Self {
+            role_bindings,
+            cluster_role_bindings: Api::<ClusterRoleBinding>::all(client.clone()).list(&params).await?.items,
+            roles,
+            cluster_roles: Api::<ClusterRole>::all(client.clone()).list(&params).await?.items,
+        })
+    }
+
+    // Rules granted by a binding's roleRef; unknown roles grant nothing
+    pub fn role_rules(&self, role_ref: &RoleRef, binding_namespace: Option<&str>) -> Vec<&PolicyRule> {
+        let rules = match role_ref.kind.as_str() {
+            "Role" => self.roles.iter()
+                .find(|r| r.metadata.name.as_deref() == Some(&role_ref.name) && r.metadata.namespace.as_deref() == binding_namespace)
+                .and_then(|r| r.rules.as_ref()),
+            _ => self.cluster_roles.iter()
+                .find(|r| r.metadata.name.as_deref() == Some(&role_ref.name))
+                .and_then(|r| r.rules.as_ref()),
+        };
+        rules.into_iter().flatten().collect()
+    }
+}
+
+// The account a pod runs as; unset means "default"
+pub fn kuboard_pod_service_account_name(spec: Option<&PodSpec>) -> String {
+    spec.and_then(|s| s.service_account_name.clone().or_else(|| s.service_account.clone()))
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "default".to_string())
+}
+
+// A subject matches the account directly or through the service account groups
+fn subject_matches(subject: &Subject, binding_namespace: Option<&str>, namespace: &str, name: &str) -> bool {
+    match subject.kind.as_str() {
+        "ServiceAccount" => {
+            subject.name == name && subject.namespace.as_deref().or(binding_namespace) == Some(namespace)
+        }
+        "Group" => subject.name == "system:serviceaccounts" || subject.name == format!("system:serviceaccounts:{}", namespace),
+        _ => false,
+    }
+}
+
+fn rule_summary(rule: &PolicyRule) -> ServiceAccountRule {
+    ServiceAccountRule {
+        verbs: rule.verbs.clone(),
+        api_groups: rule.api_groups.clone().unwrap_or_default(),
+        resources: rule.resources.clone().unwrap_or_default(),
+        resource_names: rule.resource_names.clone().unwrap_or_default(),
+        non_resource_urls: rule.non_resource_urls.clone().unwrap_or_default(),
+    }
+}
+
+fn resolve_rules(snapshot: &RbacSnapshot, role_ref: &RoleRef, binding_namespace: Option<&str>) -> Vec<ServiceAccountRule> {
+    snapshot.role_rules(role_ref, binding_namespace).into_iter().map(rule_summary).collect()
+}
+
+// Every RoleBinding and ClusterRoleBinding that grants permissions to the account
+pub fn kuboard_service_account_grants(snapshot: &RbacSnapshot, namespace: &str, name: &str) -> Vec<ServiceAccountGrant> {
+    let namespaced = snapshot.role_bindings.iter()
+        .filter(|binding| {
+            let binding_namespace = binding.metadata.namespace.as_deref();
+            binding.subjects.iter().flatten().any(|s| subject_matches(s, binding_namespace, namespace, name))
+        })
+        .map(|binding| ServiceAccountGrant {
+            binding_kind: "RoleBinding".to_string(),
+            binding_name: binding.metadata.name.clone().unwrap_or_default(),
+            role_kind: binding.role_ref.kind.clone(),
+            role_name: binding.role_ref.name.clone(),
+            cluster_wide: false,
+            rules: resolve_rules(snapshot, &binding.role_ref, binding.metadata.namespace.as_deref()),
+        });
+    let cluster = snapshot.cluster_role_bindings.iter()
+        .filter(|binding| binding.subjects.iter().flatten().any(|s| subject_matches(s, None, namespace, name)))
+        .map(|binding| ServiceAccountGrant {
+            binding_kind: "ClusterRoleBinding".to_string(),
+            binding_name: binding.metadata.name.clone().unwrap_or_default(),
+            role_kind: binding.role_ref.kind.clone(),
+            role_name: binding.role_ref.name.clone(),
+            cluster_wide: true,
+            rules: resolve_rules(snapshot, &binding.role_ref, None),
+        });
+    namespaced.chain(cluster).collect()
+}
+
+pub async fn kuboard_map_service_accounts(client: &Client, namespace: &str) -> Result<Vec<ServiceAccountUsage>> {
+    let params = ListParams::default();
+    let service_accounts = Api::<ServiceAccount>::namespaced(client.clone(), namespace).list(&params).await?;
+    let snapshot = RbacSnapshot::fetch(client, Some(namespace)).await?;
+
+    let mut usage: BTreeMap<String, ServiceAccountUsage> = service_accounts.items
+        .into_iter()
+        .map(|sa| {
+            let name = sa.metadata.name.clone().unwrap_or_default();
+            (name.clone(), ServiceAccountUsage {
+                name,
+                namespace: namespace.to_string(),
+                exists: true,
+                automount_token: sa.automount_service_account_token,
+                workloads: Vec::new(),
+                grants: Vec::new(),
+            })
+        })
+        .collect();
+
+    for workload in kuboard_list_workload_pod_specs(client, Some(namespace)).await? {
+        let account = kuboard_pod_service_account_name(Some(&workload.spec));
+        // Workloads can reference an account that hasn't been created; their pods won't start
+        usage.entry(account.clone())
+            .or_insert_with(|| ServiceAccountUsage {
+                name: account,
+                namespace: namespace.to_string(),
+                exists: false,
+                automount_token: None,
+                workloads: Vec::new(),
+                grants: Vec::new(),
+            })
+            .workloads
+            .push(ServiceAccountWorkload { kind: workload.kind, name: workload.name });
+    }
+
+    for account in usage.values_mut() {
+        account.grants = kuboard_service_account_grants(&snapshot, namespace, &account.name);
+    }
+    Ok(usage.into_values().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_service_account_grants_resolve_roles() {
+        let snapshot = RbacSnapshot {
+            role_bindings: vec![serde_json::from_value(json!({
+                "metadata": { "name": "reader", "namespace": "apps" },
+                "roleRef": { "apiGroup": "rbac.authorization.k8s.io", "kind": "Role", "name": "config-reader" },
+                "subjects": [{ "kind": "ServiceAccount", "name": "api" }],
+            })).unwrap()],
+            cluster_role_bindings: vec![
+                serde_json::from_value(json!({
+                    "metadata": { "name": "all-sa-view" },
+                    "roleRef": { "apiGroup": "rbac.authorization.k8s.io", "kind": "ClusterRole", "name": "view" },
+                    "subjects": [{ "kind": "Group", "name": "system:serviceaccounts:apps" }],
+                })).unwrap(),
+                serde_json::from_value(json!({
+                    "metadata": { "name": "other-admin" },
+                    "roleRef": { "apiGroup": "rbac.authorization.k8s.io", "kind": "ClusterRole", "name": "admin" },
+                    "subjects": [{ "kind": "ServiceAccount", "name": "api", "namespace": "other" }],
+                })).unwrap(),
+            ],
+            roles: vec![serde_json::from_value(json!({
+                "metadata": { "name": "config-reader", "namespace": "apps" },
+                "rules": [{ "apiGroups": [""], "resources": ["configmaps"], "verbs": ["get"] }],
+            })).unwrap()],
+            cluster_roles: vec![],
+        };
+
+        let grants = kuboard_service_account_grants(&snapshot, "apps", "api");
+
+        assert_eq!(grants.len(), 2);
+        assert_eq!(grants[0].binding_name, "reader");
+        assert_eq!(grants[0].rules[0].resources, vec!["configmaps".to_string()]);
+        assert!(grants[1].cluster_wide);
+        // The ClusterRole isn't in the snapshot, so its rules are unknown
+        assert!(grants[1].rules.is_empty());
+    }
+}
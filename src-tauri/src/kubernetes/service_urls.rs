@@ -0,0 +1,140 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Service External URLs
+// Works out where a Service can be reached from outside the cluster: load balancer addresses,
+// node ports on every node, and external IPs
+
+use k8s_openapi::api::core::v1::{Node, Service, ServicePort};
+
+use crate::types::{ServiceExternalAccess, ServiceUrl};
+
+// The address a browser outside the cluster is most likely to reach
+fn node_address(node: &Node) -> Option<String> {
+    let addresses = node.status.as_ref()?.addresses.as_ref()?;
+    ["ExternalIP", "InternalIP"].iter().find_map(|kind| {
+        addresses.iter().find(|a| a.type_ == *kind).map(|a| a.address.clone())
+    })
+}
+
+fn is_https(port: &ServicePort) -> bool {
+    let named_https = |name: &str| name.to_lowercase().contains("https");
+    port.port == 443
+        || port.port == 8443
+        || port.name.as_deref().is_some_and(named_https)
+        || port.app_protocol.as_deref().is_some_and(named_https)
+}
+
+fn format_url(host: &str, port: i32, https: bool) -> String {
+    let scheme = if https { "https" } else { "http" };
+    // IPv6 literals need brackets in a URL
+    let host = if host.contains(':') { format!("[{}]", host) } else { host.to_string() };
+    if (https && port == 443) || (!https && port == 80) {
+        format!("{}://{}", scheme, host)
+    } else {
+        format!("{}://{}:{}", scheme, host, port)
+    }
+}
+
+fn url(source: &str, host: &str, port: &ServicePort, port_number: i32, node: Option<&Node>) -> ServiceUrl {
+    ServiceUrl {
+        url: format_url(host, port_number, is_https(port)),
+        source: source.to_string(),
+        port_name: port.name.clone(),
+        node: node.and_then(|n| n.metadata.name.clone()),
+    }
+}
+
+// UDP and SCTP ports aren't something a browser can open
+pub fn kuboard_service_external_access(service: &Service, nodes: &[Node]) -> ServiceExternalAccess {
+    let spec = service.spec.clone().unwrap_or_default();
+    let service_type = spec.type_.clone().unwrap_or_else(|| "ClusterIP".to_string());
+    let ports: Vec<&ServicePort> = spec.ports.iter().flatten()
+        .filter(|p| p.protocol.as_deref().unwrap_or("TCP") == "TCP")
+        .collect();
+
+    let mut urls = Vec::new();
+    let mut pending = false;
+    if service_type == "LoadBalancer" {
+        let ingress = service.status.as_ref()
+            .and_then(|s| s.load_balancer.as_ref())
+            .and_then(|lb| lb.ingress.clone())
+            .unwrap_or_default();
+        pending = ingress.is_empty();
+        for entry in &ingress {
+            let Some(host) = entry.hostname.as_deref().or(entry.ip.as_deref()) else { continue };
+            for port in &ports {
+                urls.push(url("load_balancer", host, port, port.port, None));
+            }
+        }
+    }
+
+    if service_type == "NodePort" || service_type == "LoadBalancer" {
+        for port in &ports {
+            let Some(node_port) = port.node_port else { continue };
+            for node in nodes {
+                if let Some(address) = node_address(node) {
+                    urls.push(url("node_port", &address, port, node_port, Some(node)));
+                }
+            }
+        }
+    }
+
+    for external_ip in spec.external_ips.iter().flatten() {
+        for port in &ports {
+            urls.push(url("external_ip", external_ip, port, port.port, None));
+        }
+    }
+
+    ServiceExternalAccess {
+        name: service.metadata.name.clone().unwrap_or_default(),
+        namespace: service.metadata.namespace.clone().unwrap_or_default(),
+        service_type,
+        pending,
+        urls,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_service_external_access() {
+        let node: Node = serde_json::from_value(json!({
+            "metadata": { "name": "node-1" },
+            "status": { "addresses": [
+                { "type": "InternalIP", "address": "10.0.0.5" },
+                { "type": "ExternalIP", "address": "203.0.113.5" },
+            ] },
+        })).unwrap();
+        let service: Service = serde_json::from_value(json!({
+            "metadata": { "name": "shop", "namespace": "web" },
+            "spec": {
+                "type": "LoadBalancer",
+                "ports": [
+                    { "name": "http", "port": 80, "nodePort": 30080 },
+                    { "name": "https", "port": 443, "nodePort": 30443 },
+                    { "name": "dns", "port": 53, "nodePort": 30053, "protocol": "UDP" },
+                ],
+            },
+            "status": { "loadBalancer": { "ingress": [{ "hostname": "shop.elb.example.com" }] } },
+        })).unwrap();
+
+        let access = kuboard_service_external_access(&service, std::slice::from_ref(&node));
+        let urls: Vec<&str> = access.urls.iter().map(|u| u.url.as_str()).collect();
+        assert!(!access.pending);
+        assert_eq!(urls, vec![
+            "http://shop.elb.example.com",
+            "https://shop.elb.example.com",
+            "http://203.0.113.5:30080",
+            "https://203.0.113.5:30443",
+        ]);
+        assert_eq!(access.urls[2].node.as_deref(), Some("node-1"));
+
+        let mut pending = service.clone();
+        pending.status = None;
+        assert!(kuboard_service_external_access(&pending, &[]).pending);
+    }
+}
@@ -0,0 +1,293 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Shared Watch Stream
+// Watches several resource kinds from one task using metadata-only streams, fetching full objects
+// only for changes that pass the namespace filter, and emits the same events as the per-kind watchers
+
+use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, ReplicaSet, StatefulSet};
+use k8s_openapi::api::batch::v1::CronJob;
+use k8s_openapi::api::core::v1::{Pod, Service};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use k8s_openapi::NamespaceResourceScope;
+use kube::runtime::{metadata_watcher, watcher};
+use kube::core::PartialObjectMeta;
+use kube::{Api, Client, Resource};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::pin::Pin;
+use tauri::{AppHandle, Emitter, Runtime};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio_stream::{Stream, StreamExt, StreamMap};
+use tracing::{error, info, warn};
+
+use crate::kubernetes::namespace_access::kuboard_namespace_allowed;
+use crate::kubernetes::watch::{
+    CronJobWatchEvent, DaemonSetWatchEvent, DeploymentWatchEvent, PodWatchEvent, ReplicaSetWatchEvent,
+    ServiceWatchEvent, StatefulSetWatchEvent, WatchEventType,
+};
+use crate::types::{NamespaceFilter, WatchKind};
+
+pub type SharedWatchEvent = Result<watcher::Event<ObjectMeta>, watcher::Error>;
+pub type SharedWatchStream = StreamMap<WatchKind, Pin<Box<dyn Stream<Item = SharedWatchEvent> + Send>>>;
+
+pub const ALL_WATCH_KINDS: &[WatchKind] = &[
+    WatchKind::Pod,
+    WatchKind::Deployment,
+    WatchKind::StatefulSet,
+    WatchKind::DaemonSet,
+    WatchKind::ReplicaSet,
+    WatchKind::Service,
+    WatchKind::CronJob,
+];
+
+// An empty selection means every kind; duplicates are dropped
+pub fn kuboard_shared_watch_kinds(kinds: &[WatchKind]) -> Vec<WatchKind> {
+    let mut selected = if kinds.is_empty() { ALL_WATCH_KINDS.to_vec() } else { kinds.to_vec() };
+    selected.sort();
+    selected.dedup();
+    selected
+}
+
+fn metadata_only<K>(event: watcher::Event<PartialObjectMeta<K>>) -> watcher::Event<ObjectMeta> {
+    match event {
+        watcher::Event::Apply(object) => watcher::Event::Apply(object.metadata),
+        watcher::Event::Delete(object) => watcher::Event::Delete(object.metadata),
+        watcher::Event::Init => watcher::Event::Init,
+        watcher::Event::InitApply(object) => watcher::Event::InitApply(object.metadata),
+        watcher::Event::InitDone => watcher::Event::InitDone,
+    }
+}
+
+fn metadata_stream<K>(client: &Client) -> Pin<Box<dyn Stream<Item = SharedWatchEvent> + Send>>
+where
+    K: Resource<DynamicType = ()> + Clone + DeserializeOwned + Debug + Send + 'static,
+{
+    let api: Api<K> = Api::all(client.clone());
+    Box::pin(metadata_watcher(api, watcher::Config::default()).map(|event| event.map(metadata_only)))
+}
+
+// One metadata-only watch per kind, merged into a single stream keyed by kind
+pub fn kuboard_shared_watch_stream(client: &Client, kinds: &[WatchKind]) -> SharedWatchStream {
+    let mut streams = StreamMap::new();
+    for kind in kuboard_shared_watch_kinds(kinds) {
+        let stream = match kind {
+            WatchKind::Pod => metadata_stream::<Pod>(client),
+            WatchKind::Deployment => metadata_stream::<Deployment>(client),
+            WatchKind::StatefulSet => metadata_stream::<StatefulSet>(client),
+            WatchKind::DaemonSet => metadata_stream::<DaemonSet>(client),
+            WatchKind::ReplicaSet => metadata_stream::<ReplicaSet>(client),
+            WatchKind::Service => metadata_stream::<Service>(client),
+            WatchKind::CronJob => metadata_stream::<CronJob>(client),
+        };
+        streams.insert(kind, stream);
+    }
+    streams
+}
+
+// Deleted objects are rebuilt from their last metadata; anything else is fetched in full.
+// None when the object vanished before it could be fetched, in which case a delete follows
+pub async fn kuboard_resolve_watched_object<K>(client: &Client, meta: ObjectMeta, event_type: &WatchEventType) -> Option<K>
+where
+    K: Resource<Scope = NamespaceResourceScope, DynamicType = ()> + Clone + DeserializeOwned + Debug + Default,
+{
+    if matches!(event_type, WatchEventType::Deleted) {
+        let mut object = K::default();
+        *object.meta_mut() = meta;
+        return Some(object);
+    }
+    let api: Api<K> = Api::namespaced(client.clone(), meta.namespace.as_deref()?);
+    match api.get_opt(meta.name.as_deref()?).await {
+        Ok(object) => object,
+        Err(e) => {
+            warn!("Failed to fetch {} {:?}: {}", K::kind(&()), meta.name, e);
+            None
+        }
+    }
+}
+
+async fn emit_resolved<K, E, R>(
+    client: &Client,
+    app_handle: &AppHandle<R>,
+    meta: ObjectMeta,
+    event_type: WatchEventType,
+    event_name: &str,
+    wrap: fn(WatchEventType, K) -> E,
+) where
+    K: Resource<Scope = NamespaceResourceScope, DynamicType = ()> + Clone + DeserializeOwned + Debug + Default,
+    E: Serialize + Clone,
+    R: Runtime,
+{
+    if let Some(object) = kuboard_resolve_watched_object::<K>(client, meta, &event_type).await {
+        if let Err(e) = app_handle.emit(event_name, wrap(event_type, object)) {
+            error!("Failed to emit {}: {}", event_name, e);
+        }
+    }
+}
+
+async fn emit_kind_event<R: Runtime>(
+    client: &Client,
+    app_handle: &AppHandle<R>,
+    kind: WatchKind,
+    meta: ObjectMeta,
+    event_type: WatchEventType,
+) {
+    match kind {
+        WatchKind::Pod => emit_resolved(client, app_handle, meta, event_type, "pod-watch-event",
+            |event_type, pod| PodWatchEvent { event_type, pod }).await,
+        WatchKind::Deployment => emit_resolved(client, app_handle, meta, event_type, "deployment-watch-event",
+            |event_type, deployment| DeploymentWatchEvent { event_type, deployment }).await,
+        WatchKind::StatefulSet => emit_resolved(client, app_handle, meta, event_type, "statefulset-watch-event",
+            |event_type, statefulset| StatefulSetWatchEvent { event_type, statefulset }).await,
+        WatchKind::DaemonSet => emit_resolved(client, app_handle, meta, event_type, "daemonset-watch-event",
+            |event_type, daemonset| DaemonSetWatchEvent { event_type, daemonset }).await,
+        WatchKind::ReplicaSet => emit_resolved(client, app_handle, meta, event_type, "replicaset-watch-event",
+            |event_type, replicaset| ReplicaSetWatchEvent { event_type, replicaset }).await,
+        WatchKind::Service => emit_resolved(client, app_handle, meta, event_type, "service-watch-event",
+            |event_type, service| ServiceWatchEvent { event_type, service }).await,
+        WatchKind::CronJob => emit_resolved(client, app_handle, meta, event_type, "cronjob-watch-event",
+            |event_type, cronjob| CronJobWatchEvent { event_type, cronjob }).await,
+    }
+}
+
+pub struct SharedWatcher {
+    handle: Option<JoinHandle<()>>,
+    stop_tx: Option<mpsc::Sender<()>>,
+    kinds: Vec<WatchKind>,
+}
+
+impl SharedWatcher {
+    pub fn new() -> Self {
+        Self {
+            handle: None,
+            stop_tx: None,
+            kinds: Vec::new(),
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.handle.is_some()
+    }
+
+    pub fn kinds(&self) -> &[WatchKind] {
+        &self.kinds
+    }
+
+    pub fn stop(&mut self) {
+        if let Some(tx) = self.stop_tx.take() {
+            let _ = tx.try_send(());
+        }
+        if let Some(handle) = self.handle.take() {
+            handle.abort();
+        }
+        self.kinds.clear();
+    }
+
+    pub async fn start<R: Runtime>(
+        &mut self,
+        client: Client,
+        app_handle: AppHandle<R>,
+        filter: NamespaceFilter,
+        kinds: &[WatchKind],
+    ) -> Result<(), String> {
+        // Stop existing watcher if any
+        self.stop();
+
+        let kinds = kuboard_shared_watch_kinds(kinds);
+        info!("Starting shared watcher for {:?}", kinds);
+
+        let mut stream = kuboard_shared_watch_stream(&client, &kinds);
+        let (stop_tx, mut stop_rx) = mpsc::channel::<()>(1);
+
+        let handle = tokio::spawn(async move {
+            // Track seen objects per kind to distinguish Added vs Modified on Applied events
+            let mut seen = HashSet::new();
+
+            loop {
+                tokio::select! {
+                    _ = stop_rx.recv() => {
+                        info!("Shared watcher stopped by user");
+                        break;
+                    }
+                    result = stream.next() => {
+                        match result {
+                            Some((kind, Ok(event))) => {
+                                let (meta, deleted) = match event {
+                                    watcher::Event::Apply(meta) => (meta, false),
+                                    watcher::Event::Delete(meta) => (meta, true),
+                                    watcher::Event::Init | watcher::Event::InitApply(_) | watcher::Event::InitDone => continue,
+                                };
+                                // Filtered before fetching so hidden namespaces cost no extra requests
+                                if !kuboard_namespace_allowed(&filter, meta.namespace.as_deref()) {
+                                    continue;
+                                }
+                                let key = (kind, meta.namespace.clone().unwrap_or_default(), meta.name.clone().unwrap_or_default());
+                                let event_type = if deleted {
+                                    seen.remove(&key);
+                                    WatchEventType::Deleted
+                                } else if seen.insert(key) {
+                                    WatchEventType::Added
+                                } else {
+                                    WatchEventType::Modified
+                                };
+                                emit_kind_event(&client, &app_handle, kind, meta, event_type).await;
+                            }
+                            Some((kind, Err(e))) => {
+                                error!("Shared watcher error for {:?}: {}", kind, e);
+                                let _ = app_handle.emit("shared-watch-error", serde_json::json!({
+                                    "kind": kind,
+                                    "error": format!("Watch error: {}", e)
+                                }));
+                                // Try to continue, but log the error
+                            }
+                            None => {
+                                warn!("Shared watcher streams ended");
+                                let _ = app_handle.emit("shared-watch-error", serde_json::json!({
+                                    "error": "Watch stream ended"
+                                }));
+                                break;
+                            }
+                        }
+                    }
+                }
+            }
+
+            info!("Shared watcher task completed");
+        });
+
+        self.handle = Some(handle);
+        self.stop_tx = Some(stop_tx);
+        self.kinds = kinds;
+
+        Ok(())
+    }
+}
+
+impl Default for SharedWatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for SharedWatcher {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shared_watch_kinds() {
+        assert_eq!(kuboard_shared_watch_kinds(&[]), ALL_WATCH_KINDS.to_vec());
+        assert_eq!(
+            kuboard_shared_watch_kinds(&[WatchKind::Service, WatchKind::Pod, WatchKind::Service]),
+            vec![WatchKind::Pod, WatchKind::Service]
+        );
+    }
+}
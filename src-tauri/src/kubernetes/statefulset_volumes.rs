@@ -0,0 +1,207 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// StatefulSet Volumes
+// Maps each StatefulSet ordinal to the claims its volumeClaimTemplates stamp out, and finds the
+// claims a scale-down leaves behind, which the controller keeps unless its retention policy says otherwise
+
+use anyhow::{anyhow, Result};
+use k8s_openapi::api::apps::v1::StatefulSet;
+use k8s_openapi::api::core::v1::{PersistentVolume, PersistentVolumeClaim, Pod};
+use std::collections::BTreeSet;
+
+use crate::types::{StatefulSetClaim, StatefulSetOrdinal, StatefulSetVolumes};
+
+// The controller names claims <template>-<statefulset>-<ordinal>
+pub fn kuboard_statefulset_claim_name(template: &str, statefulset: &str, ordinal: i32) -> String {
+    format!("{}-{}-{}", template, statefulset, ordinal)
+}
+
+fn claim_ordinal(claim: &str, template: &str, statefulset: &str) -> Option<i32> {
+    let ordinal = claim.strip_prefix(template)?.strip_prefix('-')?.strip_prefix(statefulset)?.strip_prefix('-')?;
+    // Rules out e.g. "0-1", which belongs to a statefulset named "<statefulset>-0"
+    ordinal.bytes().all(|b| b.is_ascii_digit()).then(|| ordinal.parse().ok()).flatten()
+}
+
+fn claim_info(
+    template: &str,
+    name: String,
+    claim: Option<&PersistentVolumeClaim>,
+    volumes: &[PersistentVolume],
+    orphaned: bool,
+) -> StatefulSetClaim {
+    let spec = claim.and_then(|c| c.spec.as_ref());
+    let status = claim.and_then(|c| c.status.as_ref());
+    let volume_name = spec.and_then(|s| s.volume_name.clone());
+    let capacity = status.and_then(|s| s.capacity.as_ref()).and_then(|c| c.get("storage"));
+    let requested = spec.and_then(|s| s.resources.as_ref()).and_then(|r| r.requests.as_ref()).and_then(|r| r.get("storage"));
+    StatefulSetClaim {
+        template: template.to_string(),
+        name,
+        exists: claim.is_some(),
+        phase: status.and_then(|s| s.phase.clone()),
+        size: capacity.or(requested).map(|q| q.0.clone()),
+        storage_class: spec.and_then(|s| s.storage_class_name.clone()),
+        reclaim_policy: volume_name.as_deref()
+            .and_then(|volume| volumes.iter().find(|v| v.metadata.name.as_deref() == Some(volume)))
+            .and_then(|v| v.spec.as_ref())
+            .and_then(|s| s.persistent_volume_reclaim_policy.clone()),
+        volume_name,
+        orphaned,
+    }
+}
+
+// `claims` and `pods` are from the statefulset's namespace; `volumes` may be empty when
+// PersistentVolumes can't be read, leaving reclaim policies unknown
+pub fn kuboard_statefulset_volumes(
+    statefulset: &StatefulSet,
+    claims: &[PersistentVolumeClaim],
+    pods: &[Pod],
+    volumes: &[PersistentVolume],
+) -> StatefulSetVolumes {
+    let name = statefulset.metadata.name.clone().unwrap_or_default();
+    let spec = statefulset.spec.as_ref();
+    let replicas = spec.and_then(|s| s.replicas).unwrap_or(1);
+    let start = spec.and_then(|s| s.ordinals.as_ref()).and_then(|o| o.start).unwrap_or(0);
+    let retention = spec.and_then(|s| s.persistent_volume_claim_retention_policy.as_ref());
+    let templates: Vec<String> = spec.and_then(|s| s.volume_claim_templates.as_ref())
+        .map(|templates| templates.iter().filter_map(|t| t.metadata.name.clone()).collect())
+        .unwrap_or_default();
+
+    let find_claim = |claim_name: &str| claims.iter().find(|c| c.metadata.name.as_deref() == Some(claim_name));
+    let pod_exists = |pod_name: &str| pods.iter().any(|p| p.metadata.name.as_deref() == Some(pod_name));
+
+    // Every ordinal that should run plus every ordinal a claim is still left for
+    let mut ordinals: BTreeSet<i32> = (start..start + replicas).collect();
+    for claim in claims.iter().filter_map(|c| c.metadata.name.as_deref()) {
+        ordinals.extend(templates.iter().filter_map(|template| claim_ordinal(claim, template, &name)));
+    }
+
+    let ordinals: Vec<StatefulSetOrdinal> = ordinals.into_iter().map(|ordinal| {
+        let pod_name = format!("{}-{}", name, ordinal);
+        let pod_exists = pod_exists(&pod_name);
+        let desired = ordinal >= start && ordinal < start + replicas;
+        let claims = templates.iter()
+            .map(|template| {
+                let claim_name = kuboard_statefulset_claim_name(template, &name, ordinal);
+                let claim = find_claim(&claim_name);
+                claim_info(template, claim_name, claim, volumes, claim.is_some() && !desired && !pod_exists)
+            })
+            // A missing claim is only worth showing for an ordinal that should have one
+            .filter(|claim| claim.exists || desired)
+            .collect();
+        StatefulSetOrdinal { ordinal, pod_name, pod_exists, desired, claims }
+    }).collect();
+
+    let orphaned: Vec<&StatefulSetClaim> = ordinals.iter().flat_map(|o| &o.claims).filter(|c| c.orphaned).collect();
+    let mut warnings = Vec::new();
+    if !orphaned.is_empty() {
+        warnings.push(format!(
+            "{} claim(s) from ordinals beyond the {} replica(s) are kept after scale-down; scaling back up reuses them with their old data",
+            orphaned.len(),
+            replicas,
+        ));
+    }
+    for claim in &orphaned {
+        match (claim.reclaim_policy.as_deref(), claim.volume_name.as_deref()) {
+            (Some("Delete"), Some(volume)) => warnings.push(format!(
+                "Deleting {} also deletes volume {} and its data (reclaim policy Delete)", claim.name, volume,
+            )),
+            (None, Some(volume)) => warnings.push(format!(
+                "The reclaim policy of volume {} couldn't be read; deleting {} may delete its data", volume, claim.name,
+            )),
+            _ => {}
+        }
+    }
+
+    StatefulSetVolumes {
+        namespace: statefulset.metadata.namespace.clone().unwrap_or_default(),
+        replicas,
+        when_scaled: retention.and_then(|r| r.when_scaled.clone()).unwrap_or_else(|| "Retain".to_string()),
+        when_deleted: retention.and_then(|r| r.when_deleted.clone()).unwrap_or_else(|| "Retain".to_string()),
+        orphaned_claims: orphaned.iter().map(|c| c.name.clone()).collect(),
+        ordinals,
+        warnings,
+        name,
+    }
+}
+
+// The claims to delete: the requested ones, all of which must be orphaned, or every orphaned claim
+pub fn kuboard_select_orphaned_claims(volumes: &StatefulSetVolumes, requested: Option<&[String]>) -> Result<Vec<String>> {
+    let Some(requested) = requested else {
+        return Ok(volumes.orphaned_claims.clone());
+    };
+    for claim_name in requested {
+        if volumes.orphaned_claims.contains(claim_name) {
+            continue;
+        }
+        let ordinal = volumes.ordinals.iter().find(|o| o.claims.iter().any(|c| &c.name == claim_name && c.exists));
+        return Err(match ordinal {
+            Some(o) if o.desired => anyhow!(
+                "{} is not orphaned: ordinal {} is within the {} replica(s) of {}", claim_name, o.ordinal, volumes.replicas, volumes.name,
+            ),
+            Some(o) => anyhow!("{} is not orphaned: pod {} still exists", claim_name, o.pod_name),
+            None => anyhow!("{} is not a claim of StatefulSet {}/{}", claim_name, volumes.namespace, volumes.name),
+        });
+    }
+    Ok(requested.to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn claim(name: &str, volume: Option<&str>) -> PersistentVolumeClaim {
+        serde_json::from_value(json!({
+            "metadata": { "name": name, "namespace": "db" },
+            "spec": { "storageClassName": "gp3", "volumeName": volume, "resources": { "requests": { "storage": "10Gi" } } },
+            "status": { "phase": if volume.is_some() { "Bound" } else { "Pending" } },
+        })).unwrap()
+    }
+
+    fn pod(name: &str) -> Pod {
+        serde_json::from_value(json!({ "metadata": { "name": name, "namespace": "db" } })).unwrap()
+    }
+
+    #[test]
+    fn test_statefulset_volumes() {
+        let statefulset: StatefulSet = serde_json::from_value(json!({
+            "metadata": { "name": "pg", "namespace": "db" },
+            "spec": {
+                "replicas": 2,
+                "serviceName": "pg",
+                "selector": { "matchLabels": { "app": "pg" } },
+                "template": {},
+                "volumeClaimTemplates": [{ "metadata": { "name": "data" } }],
+            },
+        })).unwrap();
+        let claims = vec![
+            claim("data-pg-0", Some("pv-0")),
+            claim("data-pg-2", Some("pv-2")),
+            claim("data-pg-3", Some("pv-3")),
+            // Another statefulset's claim sharing the prefix
+            claim("data-pg-0-0", None),
+        ];
+        let pods = vec![pod("pg-0"), pod("pg-1"), pod("pg-3")];
+        let volumes: Vec<PersistentVolume> = vec![serde_json::from_value(json!({
+            "metadata": { "name": "pv-2" },
+            "spec": { "persistentVolumeReclaimPolicy": "Delete" },
+        })).unwrap()];
+
+        let view = kuboard_statefulset_volumes(&statefulset, &claims, &pods, &volumes);
+        let ordinals: Vec<(i32, bool, usize)> = view.ordinals.iter().map(|o| (o.ordinal, o.desired, o.claims.len())).collect();
+        assert_eq!(ordinals, vec![(0, true, 1), (1, true, 1), (2, false, 1), (3, false, 1)]);
+        assert!(!view.ordinals[1].claims[0].exists);
+        assert_eq!(view.ordinals[0].claims[0].size.as_deref(), Some("10Gi"));
+        // pg-3 is still terminating, so its claim is in use
+        assert_eq!(view.orphaned_claims, vec!["data-pg-2"]);
+        assert_eq!(view.when_scaled, "Retain");
+        assert!(view.warnings.iter().any(|w| w.contains("deletes volume pv-2")));
+
+        assert_eq!(kuboard_select_orphaned_claims(&view, None).unwrap(), vec!["data-pg-2"]);
+        for claim_name in ["data-pg-0", "data-pg-3", "data-pg-0-0"] {
+            assert!(kuboard_select_orphaned_claims(&view, Some(&[claim_name.to_string()])).is_err(), "{}", claim_name);
+        }
+    }
+}
@@ -0,0 +1,147 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Pod Template Diff
+// What changed in a rollout: the pod templates of two workload revisions compared with containers,
+// env vars and volumes matched by name, and each change sorted into image, env, resources or other
+
+use anyhow::{anyhow, Result};
+use k8s_openapi::api::apps::v1::ControllerRevision;
+use k8s_openapi::api::core::v1::PodTemplateSpec;
+use serde_json::{Map, Value};
+
+use crate::kubernetes::diff::kuboard_diff_values;
+use crate::types::{TemplateChange, TemplateChangeCategory};
+
+const CONTAINER_LISTS: &[&str] = &["containers", "initContainers", "ephemeralContainers"];
+
+// Turns a list of named objects into an object keyed by name, so a reorder or an insert at the
+// front doesn't show up as every later item changing
+fn key_by_name(list: Option<&mut Value>) {
+    let Some(value) = list else { return };
+    let Value::Array(items) = value else { return };
+    if !items.iter().all(|item| item.get("name").is_some_and(Value::is_string)) {
+        return;
+    }
+    let keyed: Map<String, Value> = items.drain(..)
+        .map(|mut item| {
+            let name = item.as_object_mut().and_then(|o| o.remove("name")).and_then(|n| n.as_str().map(str::to_string));
+            (name.unwrap_or_default(), item)
+        })
+        .collect();
+    *value = Value::Object(keyed);
+}
+
+// The template as compared: the controller's pod-template-hash label dropped and named lists keyed
+pub fn kuboard_normalize_pod_template(template: &PodTemplateSpec) -> Result<Value> {
+    let mut template = serde_json::to_value(template)?;
+    if let Some(Value::Object(labels)) = template.pointer_mut("/metadata/labels") {
+        labels.remove("pod-template-hash");
+    }
+    for list in CONTAINER_LISTS {
+        if let Some(Value::Array(containers)) = template.pointer_mut(&format!("/spec/{}", list)) {
+            for container in containers.iter_mut() {
+                key_by_name(container.get_mut("env"));
+            }
+        }
+        key_by_name(template.pointer_mut(&format!("/spec/{}", list)));
+    }
+    key_by_name(template.pointer_mut("/spec/volumes"));
+    Ok(template)
+}
+
+fn unescape_pointer(segment: &str) -> String {
+    segment.replace("~1", "/").replace("~0", "~")
+}
+
+fn classify(path: &str) -> (TemplateChangeCategory, Option<String>) {
+    let segments: Vec<&str> = path.split('/').skip(1).collect();
+    match segments.as_slice() {
+        ["spec", list, container, rest @ ..] if CONTAINER_LISTS.contains(list) => {
+            let category = match rest.first() {
+                Some(&"image") => TemplateChangeCategory::Image,
+                Some(&"env") | Some(&"envFrom") => TemplateChangeCategory::Env,
+                Some(&"resources") => TemplateChangeCategory::Resources,
+                // A container added or removed as a whole
+                None => TemplateChangeCategory::Image,
+                _ => TemplateChangeCategory::Other,
+            };
+            (category, Some(unescape_pointer(container)))
+        }
+        _ => (TemplateChangeCategory::Other, None),
+    }
+}
+
+pub fn kuboard_template_changes(from: &PodTemplateSpec, to: &PodTemplateSpec) -> Result<Vec<TemplateChange>> {
+    let changes = kuboard_diff_values(&kuboard_normalize_pod_template(from)?, &kuboard_normalize_pod_template(to)?);
+    Ok(changes.into_iter()
+        .map(|diff| {
+            let (category, container) = classify(&diff.path);
+            TemplateChange { category, container, diff }
+        })
+        .collect())
+}
+
+// StatefulSets and DaemonSets record each revision's template as a patch in a ControllerRevision
+pub fn kuboard_controller_revision_template(revision: &ControllerRevision) -> Result<PodTemplateSpec> {
+    let name = revision.metadata.name.as_deref().unwrap_or_default();
+    let mut template = revision.data.as_ref()
+        .and_then(|data| data.0.pointer("/spec/template"))
+        .cloned()
+        .ok_or_else(|| anyhow!("ControllerRevision {} has no pod template", name))?;
+    if let Value::Object(fields) = &mut template {
+        fields.remove("$patch");
+    }
+    Ok(serde_json::from_value(template)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn template(containers: Value) -> PodTemplateSpec {
+        serde_json::from_value(json!({
+            "metadata": { "labels": { "app": "web", "pod-template-hash": "abc" } },
+            "spec": { "containers": containers },
+        })).unwrap()
+    }
+
+    #[test]
+    fn test_template_changes() {
+        let from = template(json!([
+            { "name": "web", "image": "web:1", "env": [{ "name": "MODE", "value": "a" }, { "name": "LOG", "value": "info" }] },
+        ]));
+        let to = template(json!([
+            { "name": "proxy", "image": "envoy:1" },
+            {
+                "name": "web", "image": "web:2",
+                "env": [{ "name": "LOG", "value": "debug" }, { "name": "MODE", "value": "a" }],
+                "resources": { "limits": { "memory": "512Mi" } },
+            },
+        ]));
+
+        let changes = kuboard_template_changes(&from, &to).unwrap();
+        let summary: Vec<(&str, TemplateChangeCategory, Option<&str>)> = changes.iter()
+            .map(|c| (c.diff.path.as_str(), c.category, c.container.as_deref()))
+            .collect();
+        assert_eq!(summary, vec![
+            ("/spec/containers/proxy", TemplateChangeCategory::Image, Some("proxy")),
+            ("/spec/containers/web/env/LOG/value", TemplateChangeCategory::Env, Some("web")),
+            ("/spec/containers/web/image", TemplateChangeCategory::Image, Some("web")),
+            ("/spec/containers/web/resources", TemplateChangeCategory::Resources, Some("web")),
+        ]);
+        assert!(kuboard_template_changes(&from, &from).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_controller_revision_template() {
+        let revision: ControllerRevision = serde_json::from_value(json!({
+            "metadata": { "name": "db-5d8f" },
+            "revision": 2,
+            "data": { "spec": { "template": { "$patch": "replace", "spec": { "containers": [{ "name": "db", "image": "postgres:16" }] } } } },
+        })).unwrap();
+        let template = kuboard_controller_revision_template(&revision).unwrap();
+        assert_eq!(template.spec.unwrap().containers[0].image.as_deref(), Some("postgres:16"));
+    }
+}
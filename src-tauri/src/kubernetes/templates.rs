@@ -0,0 +1,279 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Manifest Template Library
+// Built-in and user-saved manifest templates with {{variable}} substitution
+
+use anyhow::{anyhow, Result};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::types::{ManifestTemplate, ManifestTemplateRequest, TemplateVariable};
+
+const BUILTIN_PREFIX: &str = "builtin-";
+
+fn variable(name: &str, description: &str, default: Option<&str>) -> TemplateVariable {
+    TemplateVariable {
+        name: name.to_string(),
+        description: description.to_string(),
+        default: default.map(str::to_string),
+    }
+}
+
+fn builtin(kind: &str, description: &str, variables: Vec<TemplateVariable>, body: &str) -> ManifestTemplate {
+    ManifestTemplate {
+        id: format!("{}{}", BUILTIN_PREFIX, kind.to_lowercase()),
+        name: kind.to_string(),
+        kind: kind.to_string(),
+        description: description.to_string(),
+        variables,
+        body: body.to_string(),
+        builtin: true,
+    }
+}
+
+pub fn kuboard_builtin_templates() -> Vec<ManifestTemplate> {
+    vec![
+        builtin("Deployment", "Single-container deployment", vec![
+            variable("name", "Deployment name, also used as the app label", None),
+            variable("image", "Container image", None),
+            variable("replicas", "Replica count", Some("1")),
+            variable("port", "Container port", Some("8080")),
+        ], r#"{
+  "apiVersion": "apps/v1",
+  "kind": "Deployment",
+  "metadata": { "name": "{{name}}", "labels": { "app": "{{name}}" } },
+  "spec": {
+    "replicas": {{replicas}},
+    "selector": { "matchLabels": { "app": "{{name}}" } },
+    "template": {
+      "metadata": { "labels": { "app": "{{name}}" } },
+      "spec": {
+        "containers": [{
+          "name": "{{name}}",
+          "image": "{{image}}",
+          "ports": [{ "containerPort": {{port}} }]
+        }]
+      }
+    }
+  }
+}"#),
+        builtin("Service", "ClusterIP service selecting pods by app label", vec![
+            variable("name", "Service name", None),
+            variable("app", "Value of the app label to select", None),
+            variable("port", "Service port", Some("80")),
+            variable("target_port", "Container port", Some("8080")),
+        ], r#"{
+  "apiVersion": "v1",
+  "kind": "Service",
+  "metadata": { "name": "{{name}}" },
+  "spec": {
+    "selector": { "app": "{{app}}" },
+    "ports": [{ "port": {{port}}, "targetPort": {{target_port}} }]
+  }
+}"#),
+        builtin("Ingress", "Ingress routing a host to a service", vec![
+            variable("name", "Ingress name", None),
+            variable("host", "Host name", None),
+            variable("service", "Backend service name", None),
+            variable("port", "Backend service port", Some("80")),
+            variable("path", "URL path prefix", Some("/")),
+        ], r#"{
+  "apiVersion": "networking.k8s.io/v1",
+  "kind": "Ingress",
+  "metadata": { "name": "{{name}}" },
+  "spec": {
+    "rules": [{
+      "host": "{{host}}",
+      "http": {
+        "paths": [{
+          "path": "{{path}}",
+          "pathType": "Prefix",
+          "backend": { "service": { "name": "{{service}}", "port": { "number": {{port}} } } }
+        }]
+      }
+    }]
+  }
+}"#),
+        builtin("CronJob", "CronJob running a single command", vec![
+            variable("name", "CronJob name", None),
+            variable("schedule", "Cron schedule", Some("0 * * * *")),
+            variable("image", "Container image", Some("busybox:1.36")),
+            variable("command", "Shell command to run", None),
+        ], r#"{
+  "apiVersion": "batch/v1",
+  "kind": "CronJob",
+  "metadata": { "name": "{{name}}" },
+  "spec": {
+    "schedule": "{{schedule}}",
+    "jobTemplate": {
+      "spec": {
+        "template": {
+          "spec": {
+            "restartPolicy": "OnFailure",
+            "containers": [{
+              "name": "{{name}}",
+              "image": "{{image}}",
+              "command": ["sh", "-c", "{{command}}"]
+            }]
+          }
+        }
+      }
+    }
+  }
+}"#),
+    ]
+}
+
+pub fn kuboard_templates_path() -> Result<PathBuf> {
+    dirs::data_local_dir()
+        .map(|dir| dir.join("kuboard").join("templates.json"))
+        .ok_or_else(|| anyhow!("Could not find local data directory"))
+}
+
+fn load_user_templates(path: &Path) -> Result<Vec<ManifestTemplate>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+}
+
+fn store_user_templates(path: &Path, templates: &[ManifestTemplate]) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let tmp_path = path.with_extension("json.tmp");
+    fs::write(&tmp_path, serde_json::to_string_pretty(templates)?)?;
+    fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+// Built-in templates first, then the user's own
+pub fn kuboard_load_templates(path: &Path) -> Result<Vec<ManifestTemplate>> {
+    let mut templates = kuboard_builtin_templates();
+    templates.extend(load_user_templates(path)?);
+    Ok(templates)
+}
+
+pub fn kuboard_find_template(path: &Path, id: &str) -> Result<ManifestTemplate> {
+    kuboard_load_templates(path)?
+        .into_iter()
+        .find(|t| t.id == id)
+        .ok_or_else(|| anyhow!("Template {} not found", id))
+}
+
+pub fn kuboard_add_template(path: &Path, request: ManifestTemplateRequest) -> Result<ManifestTemplate> {
+    let name = request.name.trim().to_string();
+    if name.is_empty() {
+        return Err(anyhow!("Template name must not be empty"));
+    }
+
+    let template = ManifestTemplate {
+        id: uuid::Uuid::new_v4().to_string(),
+        name,
+        kind: String::new(),
+        description: request.description.unwrap_or_default(),
+        variables: request.variables,
+        body: request.body,
+        builtin: false,
+    };
+    // Rendering with every placeholder filled proves the body is a usable manifest
+    let sample: HashMap<String, String> = kuboard_template_placeholders(&template.body)
+        .into_iter()
+        .map(|name| (name, "1".to_string()))
+        .collect();
+    let kind = kuboard_render_template(&template, &sample)?
+        .get("kind")
+        .and_then(Value::as_str)
+        .ok_or_else(|| anyhow!("Template must render to a manifest with a kind"))?
+        .to_string();
+    let template = ManifestTemplate { kind, ..template };
+
+    let mut templates = load_user_templates(path)?;
+    templates.push(template.clone());
+    store_user_templates(path, &templates)?;
+    Ok(template)
+}
+
+pub fn kuboard_remove_template(path: &Path, id: &str) -> Result<()> {
+    if id.starts_with(BUILTIN_PREFIX) {
+        return Err(anyhow!("Built-in templates can't be deleted"));
+    }
+    let mut templates = load_user_templates(path)?;
+    let before = templates.len();
+    templates.retain(|t| t.id != id);
+    if templates.len() == before {
+        return Err(anyhow!("Template {} not found", id));
+    }
+    store_user_templates(path, &templates)
+}
+
+// Names of every {{placeholder}} in a template body, in order of first use
+pub fn kuboard_template_placeholders(body: &str) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    let mut rest = body;
+    while let Some(start) = rest.find("{{") {
+        let Some(end) = rest[start + 2..].find("}}") else { break };
+        let name = rest[start + 2..start + 2 + end].trim().to_string();
+        if !name.is_empty() && !names.contains(&name) {
+            names.push(name);
+        }
+        rest = &rest[start + 2 + end + 2..];
+    }
+    names
+}
+
+// Substitutes parameters (falling back to defaults) and parses the result as a manifest
+pub fn kuboard_render_template(template: &ManifestTemplate, parameters: &HashMap<String, String>) -> Result<Value> {
+    let mut rendered = template.body.clone();
+    for name in kuboard_template_placeholders(&template.body) {
+        let value = parameters.get(&name).cloned()
+            .or_else(|| template.variables.iter().find(|v| v.name == name).and_then(|v| v.default.clone()))
+            .ok_or_else(|| anyhow!("Missing value for template variable {}", name))?;
+        // Escape for a JSON string so quotes in a value can't break the manifest
+        let escaped = serde_json::to_string(&value)?;
+        let escaped = &escaped[1..escaped.len() - 1];
+        rendered = rendered
+            .replace(&format!("{{{{{}}}}}", name), escaped)
+            .replace(&format!("{{{{ {} }}}}", name), escaped);
+    }
+    serde_json::from_str(&rendered).map_err(|e| anyhow!("Rendered template is not a valid manifest: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_builtin_deployment() {
+        let deployment = kuboard_builtin_templates().into_iter().find(|t| t.kind == "Deployment").unwrap();
+        let parameters = HashMap::from([
+            ("name".to_string(), "api".to_string()),
+            ("image".to_string(), "ghcr.io/acme/api:1.2".to_string()),
+            ("replicas".to_string(), "3".to_string()),
+        ]);
+
+        let manifest = kuboard_render_template(&deployment, &parameters).unwrap();
+
+        assert_eq!(manifest["metadata"]["name"], "api");
+        assert_eq!(manifest["spec"]["replicas"], 3);
+        // Falls back to the variable default
+        assert_eq!(manifest["spec"]["template"]["spec"]["containers"][0]["ports"][0]["containerPort"], 8080);
+        assert!(kuboard_render_template(&deployment, &HashMap::new()).is_err());
+    }
+
+    #[test]
+    fn test_render_escapes_string_values() {
+        let cronjob = kuboard_builtin_templates().into_iter().find(|t| t.kind == "CronJob").unwrap();
+        let parameters = HashMap::from([
+            ("name".to_string(), "report".to_string()),
+            ("command".to_string(), r#"echo "done""#.to_string()),
+        ]);
+
+        let manifest = kuboard_render_template(&cronjob, &parameters).unwrap();
+
+        assert_eq!(manifest.pointer("/spec/jobTemplate/spec/template/spec/containers/0/command/2").unwrap(), r#"echo "done""#);
+    }
+}
@@ -0,0 +1,183 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// TLS Secret Inspector
+// Parses kubernetes.io/tls secrets and flags certificates that are expired or close to expiry
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use kube::api::ListParams;
+use kube::{Api, Client};
+use k8s_openapi::api::core::v1::Secret;
+use x509_parser::extensions::GeneralName;
+use x509_parser::pem::Pem;
+
+use crate::types::{TlsCertificateInfo, TlsInspectionReport, TlsSecretInfo};
+
+pub const DEFAULT_EXPIRY_WINDOW_DAYS: i64 = 30;
+const TLS_SECRET_TYPE: &str = "kubernetes.io/tls";
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+fn rfc3339(timestamp: i64) -> String {
+    DateTime::<Utc>::from_timestamp(timestamp, 0).map(|t| t.to_rfc3339()).unwrap_or_default()
+}
+
+fn subject_alt_names(certificate: &x509_parser::certificate::X509Certificate) -> Vec<String> {
+    let Ok(Some(extension)) = certificate.subject_alternative_name() else { return Vec::new() };
+    extension.value.general_names.iter().filter_map(|name| match name {
+        GeneralName::DNSName(dns) => Some(dns.to_string()),
+        GeneralName::RFC822Name(email) => Some(email.to_string()),
+        GeneralName::URI(uri) => Some(uri.to_string()),
+        GeneralName::IPAddress(bytes) => match bytes.len() {
+            4 => Some(std::net::Ipv4Addr::from(<[u8; 4]>::try_from(*bytes).ok()?).to_string()),
+            16 => Some(std::net::Ipv6Addr::from(<[u8; 16]>::try_from(*bytes).ok()?).to_string()),
+            _ => None,
+        },
+        _ => None,
+    }).collect()
+}
+
+// Every certificate in a PEM bundle, leaf first as stored in tls.crt
+pub fn kuboard_parse_certificate_chain(pem: &[u8], now: i64, window_days: i64) -> Result<Vec<TlsCertificateInfo>> {
+    let mut certificates = Vec::new();
+    for (position, block) in Pem::iter_from_buffer(pem).enumerate() {
+        let block = block.map_err(|e| anyhow!("Invalid PEM data: {}", e))?;
+        if block.label != "CERTIFICATE" {
+            continue;
+        }
+        let certificate = block.parse_x509().map_err(|e| anyhow!("Invalid certificate at position {}: {}", position, e))?;
+        let validity = certificate.validity();
+        let not_after = validity.not_after.timestamp();
+        // Floor division so a certificate that expired an hour ago reports -1, not 0
+        let days_remaining = (not_after - now).div_euclid(SECONDS_PER_DAY);
+
+        certificates.push(TlsCertificateInfo {
+            position,
+            subject: certificate.subject().to_string(),
+            issuer: certificate.issuer().to_string(),
+            subject_alt_names: subject_alt_names(&certificate),
+            serial_number: certificate.raw_serial_as_string(),
+            not_before: rfc3339(validity.not_before.timestamp()),
+            not_after: rfc3339(not_after),
+            days_remaining,
+            is_ca: certificate.is_ca(),
+            expired: not_after <= now,
+            expiring: not_after > now && days_remaining < window_days,
+        });
+    }
+    if certificates.is_empty() {
+        return Err(anyhow!("No certificates found in PEM data"));
+    }
+    Ok(certificates)
+}
+
+pub fn kuboard_inspect_tls_secret(secret: &Secret, now: i64, window_days: i64) -> TlsSecretInfo {
+    let mut info = TlsSecretInfo {
+        name: secret.metadata.name.clone().unwrap_or_default(),
+        namespace: secret.metadata.namespace.clone().unwrap_or_default(),
+        certificates: Vec::new(),
+        expired: false,
+        expiring: false,
+        error: None,
+    };
+    let Some(data) = secret.data.as_ref().and_then(|d| d.get("tls.crt")) else {
+        info.error = Some("Secret has no tls.crt".to_string());
+        return info;
+    };
+    match kuboard_parse_certificate_chain(&data.0, now, window_days) {
+        Ok(certificates) => info.certificates = certificates,
+        Err(e) => info.error = Some(e.to_string()),
+    }
+    info.expired = info.certificates.iter().any(|c| c.expired);
+    info.expiring = !info.expired && info.certificates.iter().any(|c| c.expiring);
+    info
+}
+
+pub async fn kuboard_inspect_tls_certificates(
+    client: &Client,
+    namespace: Option<&str>,
+    window_days: i64,
+) -> Result<TlsInspectionReport> {
+    let api: Api<Secret> = match namespace {
+        Some(ns) => Api::namespaced(client.clone(), ns),
+        None => Api::all(client.clone()),
+    };
+    let params = ListParams::default().fields(&format!("type={}", TLS_SECRET_TYPE));
+    let now = Utc::now().timestamp();
+
+    let mut secrets: Vec<TlsSecretInfo> = api.list(&params).await?.items.iter()
+        // Field selectors are advisory for some API servers and proxies, so check the type again
+        .filter(|s| s.type_.as_deref() == Some(TLS_SECRET_TYPE))
+        .map(|s| kuboard_inspect_tls_secret(s, now, window_days))
+        .collect();
+    // Soonest expiry first; secrets that couldn't be parsed go last
+    secrets.sort_by_key(|s| s.certificates.iter().map(|c| c.days_remaining).min().unwrap_or(i64::MAX));
+
+    Ok(TlsInspectionReport {
+        expiry_window_days: window_days,
+        expired: secrets.iter().filter(|s| s.expired).count(),
+        expiring: secrets.iter().filter(|s| s.expiring).count(),
+        secrets,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::ByteString;
+    use serde_json::json;
+
+    // Self-signed, valid 2026-10-16T12:41:49Z to 2036-10-13T12:41:49Z
+    const CERTIFICATE: &str = "-----BEGIN CERTIFICATE-----
+MIIB8jCCAZigAwIBAgIUJBbIiGsnnxOLM8bpCWy8dgyMw7swCgYIKoZIzj0EAwIw
+MjEZMBcGA1UEAwwQc2hvcC5leGFtcGxlLmNvbTEVMBMGA1UECgwMS3Vib2FyZCBU
+ZXN0MB4XDTI2MTAxNjEyNDE0OVoXDTM2MTAxMzEyNDE0OVowMjEZMBcGA1UEAwwQ
+c2hvcC5leGFtcGxlLmNvbTEVMBMGA1UECgwMS3Vib2FyZCBUZXN0MFkwEwYHKoZI
+zj0CAQYIKoZIzj0DAQcDQgAEGuu3YJaHKXT6S4Cj2bF0tYv4jfSXziDaDmfJtI/5
+aXRdQqN6F/cMztJuXj8HXK2lgYIE4pXtFq90UaYhZqvEDKOBizCBiDAdBgNVHQ4E
+FgQUJ0FcG8lBfZMuPC1SbZ53rNOj3nUwHwYDVR0jBBgwFoAUJ0FcG8lBfZMuPC1S
+bZ53rNOj3nUwDwYDVR0TAQH/BAUwAwEB/zA1BgNVHREELjAsghBzaG9wLmV4YW1w
+bGUuY29tghIqLnNob3AuZXhhbXBsZS5jb22HBAoAAAEwCgYIKoZIzj0EAwIDSAAw
+RQIhAKanguH9rumuCurFyTsy+K1T7jtmPpLnXNXDER3P8QRAAiAXTdBm5deKngsM
+A4BhJVGeqCGlEsvwP8PhCUAXsXRoKQ==
+-----END CERTIFICATE-----
+";
+    const NOT_AFTER: i64 = 2107514509;
+
+    #[test]
+    fn test_parse_certificate_chain() {
+        let certificates = kuboard_parse_certificate_chain(CERTIFICATE.as_bytes(), NOT_AFTER - 100 * SECONDS_PER_DAY, 30).unwrap();
+
+        assert_eq!(certificates.len(), 1);
+        let certificate = &certificates[0];
+        assert_eq!(certificate.subject, "CN=shop.example.com, O=Kuboard Test");
+        assert_eq!(certificate.subject, certificate.issuer);
+        assert_eq!(certificate.subject_alt_names, vec!["shop.example.com", "*.shop.example.com", "10.0.0.1"]);
+        assert_eq!(certificate.not_after, "2036-10-13T12:41:49+00:00");
+        assert_eq!(certificate.days_remaining, 100);
+        assert!(!certificate.expiring && !certificate.expired);
+
+        assert!(kuboard_parse_certificate_chain(b"not a certificate", 0, 30).is_err());
+    }
+
+    #[test]
+    fn test_expiry_window() {
+        let secret: Secret = serde_json::from_value(json!({
+            "metadata": { "name": "shop-tls", "namespace": "web" },
+            "type": TLS_SECRET_TYPE,
+        })).unwrap();
+        assert!(kuboard_inspect_tls_secret(&secret, 0, 30).error.is_some());
+
+        let secret = Secret {
+            data: Some([("tls.crt".to_string(), ByteString(CERTIFICATE.as_bytes().to_vec()))].into()),
+            ..secret
+        };
+        let expiring = kuboard_inspect_tls_secret(&secret, NOT_AFTER - 10 * SECONDS_PER_DAY, 30);
+        assert!(expiring.expiring && !expiring.expired);
+        assert!(!kuboard_inspect_tls_secret(&secret, NOT_AFTER - 10 * SECONDS_PER_DAY, 7).expiring);
+
+        let expired = kuboard_inspect_tls_secret(&secret, NOT_AFTER + 3600, 30);
+        assert!(expired.expired && !expired.expiring);
+        assert_eq!(expired.certificates[0].days_remaining, -1);
+    }
+}
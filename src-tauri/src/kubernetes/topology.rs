@@ -0,0 +1,155 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Topology Awareness
+// Zone and region from well-known node labels, and workloads concentrated in one zone
+
+use anyhow::Result;
+use kube::api::ListParams;
+use kube::{Api, Client};
+use k8s_openapi::api::core::v1::{Node, Pod};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::kubernetes::placement::kuboard_pod_workload;
+use crate::types::{TopologyZone, ZoneConcentration};
+
+// Current labels first, then the deprecated failure-domain ones older clusters still set
+const ZONE_LABELS: &[&str] = &["topology.kubernetes.io/zone", "failure-domain.beta.kubernetes.io/zone"];
+const REGION_LABELS: &[&str] = &["topology.kubernetes.io/region", "failure-domain.beta.kubernetes.io/region"];
+
+fn first_label(labels: &BTreeMap<String, String>, keys: &[&str]) -> Option<String> {
+    keys.iter().find_map(|key| labels.get(*key)).filter(|v| !v.is_empty()).cloned()
+}
+
+// (zone, region) of a node
+pub type NodeTopology = (Option<String>, Option<String>);
+
+pub fn kuboard_node_topology(labels: &BTreeMap<String, String>) -> NodeTopology {
+    (first_label(labels, ZONE_LABELS), first_label(labels, REGION_LABELS))
+}
+
+fn node_topology(node: &Node) -> NodeTopology {
+    node.metadata.labels.as_ref().map(kuboard_node_topology).unwrap_or_default()
+}
+
+// Nodes grouped by region and zone; nodes without topology labels share a None/None group
+pub fn kuboard_group_nodes_by_zone(nodes: &[Node]) -> Vec<TopologyZone> {
+    let mut zones: BTreeMap<(Option<String>, Option<String>), Vec<String>> = BTreeMap::new();
+    for node in nodes {
+        let (zone, region) = node_topology(node);
+        zones.entry((region, zone)).or_default().push(node.metadata.name.clone().unwrap_or_default());
+    }
+    zones.into_iter().map(|((region, zone), nodes)| TopologyZone { region, zone, nodes }).collect()
+}
+
+// Workloads whose running pods (two or more) all sit in one zone of a multi-zone cluster
+pub fn kuboard_zone_concentrations(nodes: &[Node], pods: &[Pod]) -> Vec<ZoneConcentration> {
+    let node_topology: HashMap<String, NodeTopology> = nodes.iter()
+        .map(|node| (node.metadata.name.clone().unwrap_or_default(), node_topology(node)))
+        .collect();
+    let cluster_zones: BTreeSet<&String> = node_topology.values().filter_map(|(zone, _)| zone.as_ref()).collect();
+    if cluster_zones.len() < 2 {
+        return Vec::new();
+    }
+
+    // Keyed by namespace, workload kind and name
+    let mut workloads: BTreeMap<(String, String, String), Vec<&NodeTopology>> = BTreeMap::new();
+    for pod in pods {
+        if pod.status.as_ref().and_then(|s| s.phase.as_deref()) != Some("Running") {
+            continue;
+        }
+        let Some(topology) = pod.spec.as_ref().and_then(|s| s.node_name.as_ref()).and_then(|n| node_topology.get(n)) else {
+            continue;
+        };
+        let (kind, name) = kuboard_pod_workload(pod);
+        if kind == "Pod" || kind == "DaemonSet" {
+            continue;
+        }
+        workloads.entry((pod.metadata.namespace.clone().unwrap_or_default(), kind, name)).or_default().push(topology);
+    }
+
+    workloads.into_iter()
+        .filter_map(|((namespace, workload_kind, workload_name), placements)| {
+            let (zone, region) = placements.first()?;
+            let zone = zone.clone()?;
+            let concentrated = placements.len() >= 2 && placements.iter().all(|(z, _)| z.as_ref() == Some(&zone));
+            concentrated.then(|| ZoneConcentration {
+                namespace,
+                workload_kind,
+                workload_name,
+                pods: placements.len(),
+                zone,
+                region: region.clone(),
+            })
+        })
+        .collect()
+}
+
+pub async fn kuboard_list_topology_zones(client: &Client) -> Result<Vec<TopologyZone>> {
+    let nodes = Api::<Node>::all(client.clone()).list(&ListParams::default()).await?.items;
+    Ok(kuboard_group_nodes_by_zone(&nodes))
+}
+
+pub async fn kuboard_find_zone_concentrations(client: &Client, namespace: Option<&str>) -> Result<Vec<ZoneConcentration>> {
+    let nodes = Api::<Node>::all(client.clone()).list(&ListParams::default()).await?.items;
+    let pods_api: Api<Pod> = match namespace {
+        Some(namespace) => Api::namespaced(client.clone(), namespace),
+        None => Api::all(client.clone()),
+    };
+    let pods = pods_api.list(&ListParams::default()).await?.items;
+    Ok(kuboard_zone_concentrations(&nodes, &pods))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn node(name: &str, labels: serde_json::Value) -> Node {
+        serde_json::from_value(json!({ "metadata": { "name": name, "labels": labels } })).unwrap()
+    }
+
+    fn pod(name: &str, node: &str, statefulset: &str) -> Pod {
+        serde_json::from_value(json!({
+            "metadata": {
+                "name": name,
+                "namespace": "apps",
+                "ownerReferences": [{ "apiVersion": "apps/v1", "kind": "StatefulSet", "name": statefulset, "uid": "u", "controller": true }],
+            },
+            "spec": { "nodeName": node, "containers": [] },
+            "status": { "phase": "Running" },
+        })).unwrap()
+    }
+
+    #[test]
+    fn test_node_topology_prefers_current_labels() {
+        let labels = BTreeMap::from([
+            ("topology.kubernetes.io/zone".to_string(), "eu-west-1a".to_string()),
+            ("failure-domain.beta.kubernetes.io/zone".to_string(), "legacy".to_string()),
+            ("failure-domain.beta.kubernetes.io/region".to_string(), "eu-west-1".to_string()),
+        ]);
+        assert_eq!(kuboard_node_topology(&labels), (Some("eu-west-1a".to_string()), Some("eu-west-1".to_string())));
+    }
+
+    #[test]
+    fn test_zone_concentrations() {
+        let nodes = vec![
+            node("a1", json!({ "topology.kubernetes.io/zone": "a" })),
+            node("a2", json!({ "topology.kubernetes.io/zone": "a" })),
+            node("b1", json!({ "topology.kubernetes.io/zone": "b" })),
+        ];
+        let pods = vec![
+            pod("db-0", "a1", "db"),
+            pod("db-1", "a2", "db"),
+            pod("cache-0", "a1", "cache"),
+            pod("cache-1", "b1", "cache"),
+        ];
+
+        let concentrated = kuboard_zone_concentrations(&nodes, &pods);
+
+        assert_eq!(concentrated.len(), 1);
+        assert_eq!((concentrated[0].workload_name.as_str(), concentrated[0].zone.as_str(), concentrated[0].pods), ("db", "a", 2));
+        // A single-zone cluster has nothing to spread across
+        assert!(kuboard_zone_concentrations(&nodes[..2], &pods).is_empty());
+    }
+}
@@ -0,0 +1,176 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Undo for Deletes
+// Keeps the cleaned manifest of each deleted object for a while, so an accidental delete can be
+// undone by creating the object again
+
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use kube::api::{DynamicObject, GroupVersionKind, PostParams};
+use kube::discovery::{pinned_kind, Scope};
+use kube::{Api, Client};
+use std::time::{Duration, Instant};
+
+use crate::kubernetes::manifests::{kuboard_clean_manifest, kuboard_get_live_object};
+use crate::types::TrashEntry;
+use crate::utils::kuboard_format_duration;
+
+pub const TRASH_TTL: Duration = Duration::from_secs(30 * 60);
+
+// Oldest entries are dropped first once the trash is full
+pub const TRASH_CAPACITY: usize = 50;
+
+pub struct TrashStore {
+    entries: Vec<(Instant, TrashEntry)>,
+}
+
+impl TrashStore {
+    pub fn new() -> Self {
+        Self { entries: Vec::new() }
+    }
+
+    fn prune(&mut self) {
+        self.entries.retain(|(deleted_at, _)| deleted_at.elapsed() < TRASH_TTL);
+    }
+
+    pub fn push(&mut self, entry: TrashEntry) {
+        self.prune();
+        self.entries.retain(|(_, e)| e.operation_id != entry.operation_id);
+        self.entries.push((Instant::now(), entry));
+        if self.entries.len() > TRASH_CAPACITY {
+            let excess = self.entries.len() - TRASH_CAPACITY;
+            self.entries.drain(..excess);
+        }
+    }
+
+    // Newest first
+    pub fn list(&mut self, context: &str) -> Vec<TrashEntry> {
+        self.prune();
+        self.entries.iter().rev()
+            .filter(|(_, e)| e.context == context)
+            .map(aged)
+            .collect()
+    }
+
+    pub fn get(&mut self, operation_id: &str) -> Option<TrashEntry> {
+        self.prune();
+        self.entries.iter().find(|(_, e)| e.operation_id == operation_id).map(aged)
+    }
+
+    pub fn remove(&mut self, operation_id: &str) {
+        self.entries.retain(|(_, e)| e.operation_id != operation_id);
+    }
+}
+
+// The entry with its age refreshed from when it was pushed
+fn aged((deleted_at, entry): &(Instant, TrashEntry)) -> TrashEntry {
+    TrashEntry { age: kuboard_format_duration(deleted_at.elapsed().as_secs() as i64), ..entry.clone() }
+}
+
+impl Default for TrashStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Reads the object about to be deleted; None when its controller would recreate it anyway
+pub async fn kuboard_capture_deleted_object(
+    client: &Client,
+    context: &str,
+    kind: &str,
+    name: &str,
+    namespace: &str,
+    operation_id: Option<String>,
+) -> Result<Option<TrashEntry>> {
+    let object = kuboard_get_live_object(client, kind, name, namespace).await?;
+    kuboard_trash_entry(context, &object, operation_id)
+}
+
+// The trash entry for an object already read, of any kind; None when its controller would recreate it
+pub fn kuboard_trash_entry(context: &str, object: &DynamicObject, operation_id: Option<String>) -> Result<Option<TrashEntry>> {
+    let kind = object.types.as_ref().map(|t| t.kind.as_str()).ok_or_else(|| anyhow!("Object has no apiVersion/kind"))?;
+    let name = object.metadata.name.as_deref().unwrap_or_default();
+    let namespace = object.metadata.namespace.as_deref().unwrap_or_default();
+    let controlled = object.metadata.owner_references.as_ref()
+        .is_some_and(|owners| owners.iter().any(|owner| owner.controller == Some(true)));
+    if controlled {
+        return Ok(None);
+    }
+
+    let mut manifest = serde_json::to_value(object)?;
+    kuboard_clean_manifest(&mut manifest);
+
+    let deleted_at = Utc::now();
+    Ok(Some(TrashEntry {
+        operation_id: operation_id.unwrap_or_else(|| uuid::Uuid::new_v4().to_string()),
+        context: context.to_string(),
+        kind: kind.to_string(),
+        name: name.to_string(),
+        namespace: namespace.to_string(),
+        deleted_at: deleted_at.to_rfc3339(),
+        expires_at: (deleted_at + TRASH_TTL).to_rfc3339(),
+        age: kuboard_format_duration(0),
+        manifest,
+    }))
+}
+
+// Creates the object again; fails rather than overwriting if something took its name since
+pub async fn kuboard_restore_trash_entry(client: &Client, entry: &TrashEntry) -> Result<()> {
+    let object: DynamicObject = serde_json::from_value(entry.manifest.clone())?;
+    let types = object.types.clone().ok_or_else(|| anyhow!("Manifest has no apiVersion/kind"))?;
+    let (resource, capabilities) = pinned_kind(client, &GroupVersionKind::try_from(&types)?).await?;
+
+    let api: Api<DynamicObject> = if capabilities.scope == Scope::Namespaced {
+        Api::namespaced_with(client.clone(), &entry.namespace, &resource)
+    } else {
+        Api::all_with(client.clone(), &resource)
+    };
+    match api.create(&PostParams::default(), &object).await {
+        Ok(_) => Ok(()),
+        Err(kube::Error::Api(e)) if e.code == 409 => {
+            Err(anyhow!("{} {}/{} already exists again", entry.kind, entry.namespace, entry.name))
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn entry(operation_id: &str, context: &str) -> TrashEntry {
+        TrashEntry {
+            operation_id: operation_id.to_string(),
+            context: context.to_string(),
+            kind: "ConfigMap".to_string(),
+            name: operation_id.to_string(),
+            namespace: "default".to_string(),
+            deleted_at: String::new(),
+            expires_at: String::new(),
+            age: String::new(),
+            manifest: json!({}),
+        }
+    }
+
+    #[test]
+    fn test_trash_is_per_context_and_bounded() {
+        let mut trash = TrashStore::new();
+        trash.push(entry("a", "kind-dev"));
+        trash.push(entry("b", "prod"));
+        trash.push(entry("c", "kind-dev"));
+
+        let ids: Vec<String> = trash.list("kind-dev").into_iter().map(|e| e.operation_id).collect();
+        assert_eq!(ids, vec!["c", "a"]);
+        assert!(trash.get("b").is_some());
+        trash.remove("b");
+        assert!(trash.get("b").is_none());
+
+        for i in 0..TRASH_CAPACITY {
+            trash.push(entry(&format!("op-{}", i), "kind-dev"));
+        }
+        assert_eq!(trash.list("kind-dev").len(), TRASH_CAPACITY);
+        assert!(trash.get("a").is_none());
+    }
+}
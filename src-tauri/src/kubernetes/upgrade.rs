@@ -0,0 +1,196 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Upgrade Readiness
+// Flags what will break or cause downtime when the cluster is upgraded to a target version
+
+use anyhow::{anyhow, Result};
+use kube::api::ListParams;
+use kube::{Api, Client};
+use k8s_openapi::api::apps::v1::{Deployment, StatefulSet};
+use k8s_openapi::api::core::v1::Node;
+use k8s_openapi::api::policy::v1::PodDisruptionBudget;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::{LabelSelector, ObjectMeta};
+use std::collections::BTreeMap;
+
+use crate::kubernetes::deprecations::{kuboard_find_deprecated_api_usage, parse_minor_version};
+use crate::kubernetes::resilience::kuboard_covering_pdbs;
+use crate::types::{FindingSeverity, UpgradeIssue, UpgradeReadinessReport};
+
+// Kubelets may lag the API server by up to three minor versions
+const MAX_KUBELET_SKEW: u32 = 3;
+
+fn issue(category: &str, severity: FindingSeverity, kind: &str, name: &str, namespace: Option<&str>, message: String) -> UpgradeIssue {
+    UpgradeIssue {
+        category: category.to_string(),
+        severity,
+        kind: kind.to_string(),
+        name: name.to_string(),
+        namespace: namespace.map(str::to_string),
+        message,
+    }
+}
+
+pub fn kuboard_version_skew_issues(current_minor: u32, target_minor: u32, kubelets: &[(String, String)]) -> Vec<UpgradeIssue> {
+    let mut issues = Vec::new();
+    if target_minor > current_minor + 1 {
+        issues.push(issue("version-skew", FindingSeverity::Critical, "Cluster", "control-plane", None,
+            format!("The control plane can only move one minor version at a time; upgrade 1.{} to 1.{} first", current_minor, current_minor + 1)));
+    }
+    for (node, version) in kubelets {
+        let Some(kubelet_minor) = parse_minor_version(version) else { continue };
+        if kubelet_minor + MAX_KUBELET_SKEW < target_minor {
+            issues.push(issue("version-skew", FindingSeverity::Critical, "Node", node, None,
+                format!("Kubelet {} is more than {} minor versions behind 1.{}; upgrade the node first", version, MAX_KUBELET_SKEW, target_minor)));
+        }
+    }
+    issues
+}
+
+// A workload that upgrade drains will evict, with the labels its PDBs select on
+pub struct DrainedWorkload {
+    pub kind: &'static str,
+    pub name: String,
+    pub namespace: String,
+    pub replicas: i32,
+    pub pod_labels: BTreeMap<String, String>,
+}
+
+pub fn kuboard_disruption_issues(workloads: &[DrainedWorkload], pdbs: &[PodDisruptionBudget]) -> Vec<UpgradeIssue> {
+    let mut issues = Vec::new();
+    for workload in workloads.iter().filter(|w| w.replicas > 0) {
+        let namespace = Some(workload.namespace.as_str());
+        let covering = kuboard_covering_pdbs(pdbs, &workload.namespace, &workload.pod_labels);
+
+        if workload.replicas == 1 {
+            issues.push(issue("single-replica", FindingSeverity::Warning, workload.kind, &workload.name, namespace,
+                "Single replica; the workload is down while its node is drained".to_string()));
+        } else if covering.is_empty() {
+            issues.push(issue("pdb-coverage", FindingSeverity::Info, workload.kind, &workload.name, namespace,
+                format!("No PodDisruptionBudget; drains may evict all {} replicas at once", workload.replicas)));
+        }
+
+        for pdb in covering {
+            let allowed = pdb.status.as_ref().map(|s| s.disruptions_allowed).unwrap_or_default();
+            if allowed == 0 {
+                issues.push(issue("pdb-coverage", FindingSeverity::Critical, workload.kind, &workload.name, namespace,
+                    format!("PodDisruptionBudget {} allows no disruptions; node drains will hang", pdb.metadata.name.as_deref().unwrap_or_default())));
+            }
+        }
+    }
+    issues
+}
+
+fn drained_workload(
+    kind: &'static str,
+    metadata: &ObjectMeta,
+    replicas: Option<i32>,
+    selector: &LabelSelector,
+    template_labels: Option<&BTreeMap<String, String>>,
+) -> DrainedWorkload {
+    DrainedWorkload {
+        kind,
+        name: metadata.name.clone().unwrap_or_default(),
+        namespace: metadata.namespace.clone().unwrap_or_default(),
+        replicas: replicas.unwrap_or(1),
+        pod_labels: template_labels.cloned().or_else(|| selector.match_labels.clone()).unwrap_or_default(),
+    }
+}
+
+// target_version defaults to the next minor version
+pub async fn kuboard_check_upgrade_readiness(client: &Client, target_version: Option<&str>) -> Result<UpgradeReadinessReport> {
+    let server_version = client.apiserver_version().await?;
+    let current_minor = parse_minor_version(&server_version.minor)
+        .ok_or_else(|| anyhow!("Could not parse server version {}", server_version.git_version))?;
+    let target_minor = match target_version {
+        Some(target) => parse_minor_version(target).ok_or_else(|| anyhow!("Invalid target version {}", target))?,
+        None => current_minor + 1,
+    };
+    if target_minor <= current_minor {
+        return Err(anyhow!("Target version 1.{} is not newer than the cluster (1.{})", target_minor, current_minor));
+    }
+
+    let params = ListParams::default();
+    let kubelets: Vec<(String, String)> = Api::<Node>::all(client.clone()).list(&params).await?.items
+        .into_iter()
+        .filter_map(|node| {
+            let version = node.status?.node_info?.kubelet_version;
+            Some((node.metadata.name.unwrap_or_default(), version))
+        })
+        .collect();
+    let mut issues = kuboard_version_skew_issues(current_minor, target_minor, &kubelets);
+
+    let deprecated = kuboard_find_deprecated_api_usage(client, Some(&format!("1.{}", target_minor))).await?;
+    for finding in deprecated.findings {
+        issues.push(issue("deprecated-api", FindingSeverity::Critical, &finding.kind, &finding.name, finding.namespace.as_deref(),
+            format!("Written as {} by {}, removed in {}; migrate to {}",
+                finding.api_version, finding.writers.join(", "), finding.removed_in, finding.replacement_api_version)));
+    }
+
+    let mut workloads: Vec<DrainedWorkload> = Api::<Deployment>::all(client.clone()).list(&params).await?.items
+        .iter()
+        .filter_map(|d| {
+            let spec = d.spec.as_ref()?;
+            Some(drained_workload("Deployment", &d.metadata, spec.replicas, &spec.selector, spec.template.metadata.as_ref().and_then(|m| m.labels.as_ref())))
+        })
+        .collect();
+    workloads.extend(Api::<StatefulSet>::all(client.clone()).list(&params).await?.items
+        .iter()
+        .filter_map(|s| {
+            let spec = s.spec.as_ref()?;
+            Some(drained_workload("StatefulSet", &s.metadata, spec.replicas, &spec.selector, spec.template.metadata.as_ref().and_then(|m| m.labels.as_ref())))
+        }));
+    let pdbs = Api::<PodDisruptionBudget>::all(client.clone()).list(&params).await?.items;
+    issues.extend(kuboard_disruption_issues(&workloads, &pdbs));
+
+    Ok(UpgradeReadinessReport {
+        server_version: format!("1.{}", current_minor),
+        target_version: format!("1.{}", target_minor),
+        ready: !issues.iter().any(|i| i.severity == FindingSeverity::Critical),
+        issues,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn workload(name: &str, replicas: i32) -> DrainedWorkload {
+        DrainedWorkload {
+            kind: "Deployment",
+            name: name.to_string(),
+            namespace: "apps".to_string(),
+            replicas,
+            pod_labels: BTreeMap::from([("app".to_string(), name.to_string())]),
+        }
+    }
+
+    #[test]
+    fn test_version_skew_issues() {
+        let kubelets = vec![("old".to_string(), "v1.27.3".to_string()), ("new".to_string(), "v1.30.1".to_string())];
+
+        let issues = kuboard_version_skew_issues(30, 31, &kubelets);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].name, "old");
+
+        assert_eq!(kuboard_version_skew_issues(30, 32, &kubelets)[0].name, "control-plane");
+    }
+
+    #[test]
+    fn test_disruption_issues() {
+        let pdb: PodDisruptionBudget = serde_json::from_value(json!({
+            "metadata": { "name": "api", "namespace": "apps" },
+            "spec": { "minAvailable": 3, "selector": { "matchLabels": { "app": "api" } } },
+            "status": { "disruptionsAllowed": 0, "currentHealthy": 3, "desiredHealthy": 3, "expectedPods": 3 },
+        })).unwrap();
+        let workloads = vec![workload("api", 3), workload("worker", 2), workload("cache", 1), workload("idle", 0)];
+
+        let issues = kuboard_disruption_issues(&workloads, &[pdb]);
+        let summary: Vec<(&str, &str)> = issues.iter().map(|i| (i.name.as_str(), i.category.as_str())).collect();
+
+        assert_eq!(summary, vec![("api", "pdb-coverage"), ("worker", "pdb-coverage"), ("cache", "single-replica")]);
+        assert_eq!(issues[0].severity, FindingSeverity::Critical);
+        assert_eq!(issues[1].severity, FindingSeverity::Info);
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Warning Feed
+// Picks the Warning events worth a toast out of an event watch: only the configured reasons and
+// namespaces, and each event once per new occurrence rather than on every update to it
+
+use k8s_openapi::api::core::v1::Event;
+use std::collections::HashMap;
+
+use crate::kubernetes::namespace_access::kuboard_namespace_allowed;
+use crate::types::{NamespaceFilter, WarningFeedFilter, WarningToast};
+
+// Reasons compare case-insensitively, so "backoff" matches BackOff
+pub fn kuboard_warning_feed_matches(filter: &WarningFeedFilter, event: &Event) -> bool {
+    if event.type_.as_deref() != Some("Warning") {
+        return false;
+    }
+    let reason = event.reason.as_deref().unwrap_or_default();
+    let namespaces = NamespaceFilter { allow: filter.namespaces.clone(), deny: Vec::new() };
+    (filter.reasons.is_empty() || filter.reasons.iter().any(|r| r.eq_ignore_ascii_case(reason)))
+        && kuboard_namespace_allowed(&namespaces, event.metadata.namespace.as_deref())
+}
+
+fn occurrences(event: &Event) -> i32 {
+    event.series.as_ref().and_then(|s| s.count).or(event.count).unwrap_or(1)
+}
+
+fn event_key(event: &Event) -> String {
+    event.metadata.uid.clone().unwrap_or_else(|| {
+        format!("{}/{}", event.metadata.namespace.as_deref().unwrap_or_default(), event.metadata.name.as_deref().unwrap_or_default())
+    })
+}
+
+// Occurrence counts of the events already seen. The kubelet and controllers fold repeats into one
+// Event by bumping its count, so a toast is due when the count grows, not on every update
+#[derive(Default)]
+pub struct WarningOccurrences {
+    counts: HashMap<String, i32>,
+}
+
+impl WarningOccurrences {
+    // Records the event and tells whether it has occurred again since it was last recorded
+    pub fn record(&mut self, event: &Event) -> bool {
+        let count = occurrences(event);
+        match self.counts.insert(event_key(event), count) {
+            Some(previous) => count > previous,
+            None => true,
+        }
+    }
+
+    pub fn forget(&mut self, event: &Event) {
+        self.counts.remove(&event_key(event));
+    }
+}
+
+pub fn kuboard_warning_toast(event: &Event) -> WarningToast {
+    let involved = &event.involved_object;
+    WarningToast {
+        namespace: event.metadata.namespace.clone().unwrap_or_default(),
+        reason: event.reason.clone().unwrap_or_default(),
+        message: event.message.clone().unwrap_or_default(),
+        involved_kind: involved.kind.clone().unwrap_or_default(),
+        involved_name: involved.name.clone().unwrap_or_default(),
+        count: occurrences(event),
+        first_seen: event.first_timestamp.as_ref().map(|t| t.0.to_rfc3339())
+            .or_else(|| event.event_time.as_ref().map(|t| t.0.to_rfc3339())),
+        last_seen: event.series.as_ref().and_then(|s| s.last_observed_time.as_ref()).map(|t| t.0.to_rfc3339())
+            .or_else(|| event.last_timestamp.as_ref().map(|t| t.0.to_rfc3339()))
+            .or_else(|| event.event_time.as_ref().map(|t| t.0.to_rfc3339())),
+        source: event.reporting_component.clone().filter(|c| !c.is_empty())
+            .or_else(|| event.source.as_ref().and_then(|s| s.component.clone())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn event(namespace: &str, type_: &str, reason: &str, count: i32) -> Event {
+        serde_json::from_value(json!({
+            "metadata": { "name": format!("web-0.{}", reason), "namespace": namespace, "uid": format!("{}-{}", namespace, reason) },
+            "involvedObject": { "kind": "Pod", "name": "web-0", "namespace": namespace },
+            "type": type_,
+            "reason": reason,
+            "message": "Back-off restarting failed container",
+            "count": count,
+            "source": { "component": "kubelet" },
+        })).unwrap()
+    }
+
+    #[test]
+    fn test_warning_feed_matches() {
+        let everything = WarningFeedFilter::default();
+        assert!(kuboard_warning_feed_matches(&everything, &event("shop", "Warning", "BackOff", 1)));
+        assert!(!kuboard_warning_feed_matches(&everything, &event("shop", "Normal", "Pulled", 1)));
+
+        let filter = WarningFeedFilter {
+            reasons: vec!["backoff".to_string(), "FailedScheduling".to_string()],
+            namespaces: vec!["team-*".to_string(), "shop".to_string()],
+        };
+        assert!(kuboard_warning_feed_matches(&filter, &event("team-a", "Warning", "BackOff", 1)));
+        assert!(kuboard_warning_feed_matches(&filter, &event("shop", "Warning", "FailedScheduling", 1)));
+        assert!(!kuboard_warning_feed_matches(&filter, &event("shop", "Warning", "Unhealthy", 1)));
+        assert!(!kuboard_warning_feed_matches(&filter, &event("kube-system", "Warning", "BackOff", 1)));
+    }
+
+    #[test]
+    fn test_warning_occurrences() {
+        let mut seen = WarningOccurrences::default();
+        assert!(seen.record(&event("shop", "Warning", "BackOff", 1)));
+        // Updated without occurring again
+        assert!(!seen.record(&event("shop", "Warning", "BackOff", 1)));
+        assert!(seen.record(&event("shop", "Warning", "BackOff", 3)));
+        seen.forget(&event("shop", "Warning", "BackOff", 3));
+        assert!(seen.record(&event("shop", "Warning", "BackOff", 3)));
+
+        let toast = kuboard_warning_toast(&event("shop", "Warning", "BackOff", 3));
+        assert_eq!((toast.involved_name.as_str(), toast.count, toast.source.as_deref()), ("web-0", 3, Some("kubelet")));
+    }
+}
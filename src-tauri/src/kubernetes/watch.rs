@@ -6,16 +6,21 @@
 
 use kube::{Api, Client};
 use kube::runtime::watcher;
-use k8s_openapi::api::core::v1::{Pod, Service};
+use k8s_openapi::api::core::v1::{Event, Pod, Service};
 use k8s_openapi::api::apps::v1::{Deployment, StatefulSet, DaemonSet, ReplicaSet};
-use k8s_openapi::api::batch::v1::CronJob;
+use k8s_openapi::api::batch::v1::{CronJob, Job};
 use serde::{Serialize, Deserialize};
-use tauri::{AppHandle, Emitter};
+use tauri::{AppHandle, Emitter, Runtime};
 use tracing::{error, info, warn};
 use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
 use tokio_stream::StreamExt;
 
+use crate::kubernetes::job_progress::kuboard_job_progress;
+use crate::kubernetes::namespace_access::kuboard_namespace_allowed;
+use crate::kubernetes::warning_feed::{kuboard_warning_feed_matches, kuboard_warning_toast, WarningOccurrences};
+use crate::types::{JobProgress, NamespaceFilter, WarningFeedFilter};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum WatchEventType {
     Added,
@@ -55,10 +60,11 @@ impl PodWatcher {
         }
     }
 
-    pub async fn start(
+    pub async fn start<R: Runtime>(
         &mut self,
         client: Client,
-        app_handle: AppHandle,
+        app_handle: AppHandle<R>,
+        filter: NamespaceFilter,
     ) -> Result<(), String> {
         // Stop existing watcher if any
         self.stop();
@@ -88,7 +94,7 @@ impl PodWatcher {
                         match result {
                             Some(Ok(event)) => {
                                 match event {
-                                    watcher::Event::Apply(pod) => {
+                                    watcher::Event::Apply(pod) if kuboard_namespace_allowed(&filter, pod.metadata.namespace.as_deref()) => {
                                         if let Some(name) = pod.metadata.name.as_ref() {
                                             let key = format!("{}/{}", 
                                                 pod.metadata.namespace.as_ref().unwrap_or(&"default".to_string()),
@@ -113,7 +119,7 @@ impl PodWatcher {
                                             }
                                         }
                                     }
-                                    watcher::Event::Delete(pod) => {
+                                    watcher::Event::Delete(pod) if kuboard_namespace_allowed(&filter, pod.metadata.namespace.as_deref()) => {
                                         if let Some(name) = pod.metadata.name.as_ref() {
                                             let key = format!("{}/{}", 
                                                 pod.metadata.namespace.as_ref().unwrap_or(&"default".to_string()),
@@ -129,6 +135,8 @@ impl PodWatcher {
                                             }
                                         }
                                     }
+                                    // Objects outside the context's namespace filter
+                                    watcher::Event::Apply(_) | watcher::Event::Delete(_) => {}
                                     watcher::Event::Init | watcher::Event::InitApply(_) | watcher::Event::InitDone => {
                                         // These events are part of the initial sync and don't need special handling
                                         // The Apply events during initialization will be handled above
@@ -204,10 +212,11 @@ impl DeploymentWatcher {
         }
     }
 
-    pub async fn start(
+    pub async fn start<R: Runtime>(
         &mut self,
         client: Client,
-        app_handle: AppHandle,
+        app_handle: AppHandle<R>,
+        filter: NamespaceFilter,
     ) -> Result<(), String> {
         self.stop();
 
@@ -234,7 +243,7 @@ impl DeploymentWatcher {
                         match result {
                             Some(Ok(event)) => {
                                 match event {
-                                    watcher::Event::Apply(deployment) => {
+                                    watcher::Event::Apply(deployment) if kuboard_namespace_allowed(&filter, deployment.metadata.namespace.as_deref()) => {
                                         if let Some(name) = deployment.metadata.name.as_ref() {
                                             let key = format!("{}/{}", 
                                                 deployment.metadata.namespace.as_ref().unwrap_or(&"default".to_string()),
@@ -259,7 +268,7 @@ impl DeploymentWatcher {
                                             }
                                         }
                                     }
-                                    watcher::Event::Delete(deployment) => {
+                                    watcher::Event::Delete(deployment) if kuboard_namespace_allowed(&filter, deployment.metadata.namespace.as_deref()) => {
                                         if let Some(name) = deployment.metadata.name.as_ref() {
                                             let key = format!("{}/{}", 
                                                 deployment.metadata.namespace.as_ref().unwrap_or(&"default".to_string()),
@@ -275,6 +284,8 @@ impl DeploymentWatcher {
                                             }
                                         }
                                     }
+                                    // Objects outside the context's namespace filter
+                                    watcher::Event::Apply(_) | watcher::Event::Delete(_) => {}
                                     watcher::Event::Init | watcher::Event::InitApply(_) | watcher::Event::InitDone => {
                                         info!("Deployment watcher initialization event");
                                     }
@@ -347,10 +358,11 @@ impl StatefulSetWatcher {
         }
     }
 
-    pub async fn start(
+    pub async fn start<R: Runtime>(
         &mut self,
         client: Client,
-        app_handle: AppHandle,
+        app_handle: AppHandle<R>,
+        filter: NamespaceFilter,
     ) -> Result<(), String> {
         self.stop();
 
@@ -377,7 +389,7 @@ impl StatefulSetWatcher {
                         match result {
                             Some(Ok(event)) => {
                                 match event {
-                                    watcher::Event::Apply(statefulset) => {
+                                    watcher::Event::Apply(statefulset) if kuboard_namespace_allowed(&filter, statefulset.metadata.namespace.as_deref()) => {
                                         if let Some(name) = statefulset.metadata.name.as_ref() {
                                             let key = format!("{}/{}", 
                                                 statefulset.metadata.namespace.as_ref().unwrap_or(&"default".to_string()),
@@ -402,7 +414,7 @@ impl StatefulSetWatcher {
                                             }
                                         }
                                     }
-                                    watcher::Event::Delete(statefulset) => {
+                                    watcher::Event::Delete(statefulset) if kuboard_namespace_allowed(&filter, statefulset.metadata.namespace.as_deref()) => {
                                         if let Some(name) = statefulset.metadata.name.as_ref() {
                                             let key = format!("{}/{}", 
                                                 statefulset.metadata.namespace.as_ref().unwrap_or(&"default".to_string()),
@@ -418,6 +430,8 @@ impl StatefulSetWatcher {
                                             }
                                         }
                                     }
+                                    // Objects outside the context's namespace filter
+                                    watcher::Event::Apply(_) | watcher::Event::Delete(_) => {}
                                     watcher::Event::Init | watcher::Event::InitApply(_) | watcher::Event::InitDone => {
                                         info!("StatefulSet watcher initialization event");
                                     }
@@ -490,10 +504,11 @@ impl DaemonSetWatcher {
         }
     }
 
-    pub async fn start(
+    pub async fn start<R: Runtime>(
         &mut self,
         client: Client,
-        app_handle: AppHandle,
+        app_handle: AppHandle<R>,
+        filter: NamespaceFilter,
     ) -> Result<(), String> {
         self.stop();
 
@@ -520,7 +535,7 @@ impl DaemonSetWatcher {
                         match result {
                             Some(Ok(event)) => {
                                 match event {
-                                    watcher::Event::Apply(daemonset) => {
+                                    watcher::Event::Apply(daemonset) if kuboard_namespace_allowed(&filter, daemonset.metadata.namespace.as_deref()) => {
                                         if let Some(name) = daemonset.metadata.name.as_ref() {
                                             let key = format!("{}/{}", 
                                                 daemonset.metadata.namespace.as_ref().unwrap_or(&"default".to_string()),
@@ -545,7 +560,7 @@ impl DaemonSetWatcher {
                                             }
                                         }
                                     }
-                                    watcher::Event::Delete(daemonset) => {
+                                    watcher::Event::Delete(daemonset) if kuboard_namespace_allowed(&filter, daemonset.metadata.namespace.as_deref()) => {
                                         if let Some(name) = daemonset.metadata.name.as_ref() {
                                             let key = format!("{}/{}", 
                                                 daemonset.metadata.namespace.as_ref().unwrap_or(&"default".to_string()),
@@ -561,6 +576,8 @@ impl DaemonSetWatcher {
                                             }
                                         }
                                     }
+                                    // Objects outside the context's namespace filter
+                                    watcher::Event::Apply(_) | watcher::Event::Delete(_) => {}
                                     watcher::Event::Init | watcher::Event::InitApply(_) | watcher::Event::InitDone => {
                                         info!("DaemonSet watcher initialization event");
                                     }
@@ -633,10 +650,11 @@ impl ReplicaSetWatcher {
         }
     }
 
-    pub async fn start(
+    pub async fn start<R: Runtime>(
         &mut self,
         client: Client,
-        app_handle: AppHandle,
+        app_handle: AppHandle<R>,
+        filter: NamespaceFilter,
     ) -> Result<(), String> {
         self.stop();
 
@@ -663,7 +681,7 @@ impl ReplicaSetWatcher {
                         match result {
                             Some(Ok(event)) => {
                                 match event {
-                                    watcher::Event::Apply(replicaset) => {
+                                    watcher::Event::Apply(replicaset) if kuboard_namespace_allowed(&filter, replicaset.metadata.namespace.as_deref()) => {
                                         if let Some(name) = replicaset.metadata.name.as_ref() {
                                             let key = format!("{}/{}", 
                                                 replicaset.metadata.namespace.as_ref().unwrap_or(&"default".to_string()),
@@ -688,7 +706,7 @@ impl ReplicaSetWatcher {
                                             }
                                         }
                                     }
-                                    watcher::Event::Delete(replicaset) => {
+                                    watcher::Event::Delete(replicaset) if kuboard_namespace_allowed(&filter, replicaset.metadata.namespace.as_deref()) => {
                                         if let Some(name) = replicaset.metadata.name.as_ref() {
                                             let key = format!("{}/{}", 
                                                 replicaset.metadata.namespace.as_ref().unwrap_or(&"default".to_string()),
@@ -704,6 +722,8 @@ impl ReplicaSetWatcher {
                                             }
                                         }
                                     }
+                                    // Objects outside the context's namespace filter
+                                    watcher::Event::Apply(_) | watcher::Event::Delete(_) => {}
                                     watcher::Event::Init | watcher::Event::InitApply(_) | watcher::Event::InitDone => {
                                         info!("ReplicaSet watcher initialization event");
                                     }
@@ -776,10 +796,11 @@ impl ServiceWatcher {
         }
     }
 
-    pub async fn start(
+    pub async fn start<R: Runtime>(
         &mut self,
         client: Client,
-        app_handle: AppHandle,
+        app_handle: AppHandle<R>,
+        filter: NamespaceFilter,
     ) -> Result<(), String> {
         self.stop();
 
@@ -806,7 +827,7 @@ impl ServiceWatcher {
                         match result {
                             Some(Ok(event)) => {
                                 match event {
-                                    watcher::Event::Apply(service) => {
+                                    watcher::Event::Apply(service) if kuboard_namespace_allowed(&filter, service.metadata.namespace.as_deref()) => {
                                         if let Some(name) = service.metadata.name.as_ref() {
                                             let key = format!("{}/{}", 
                                                 service.metadata.namespace.as_ref().unwrap_or(&"default".to_string()),
@@ -831,7 +852,7 @@ impl ServiceWatcher {
                                             }
                                         }
                                     }
-                                    watcher::Event::Delete(service) => {
+                                    watcher::Event::Delete(service) if kuboard_namespace_allowed(&filter, service.metadata.namespace.as_deref()) => {
                                         if let Some(name) = service.metadata.name.as_ref() {
                                             let key = format!("{}/{}", 
                                                 service.metadata.namespace.as_ref().unwrap_or(&"default".to_string()),
@@ -847,6 +868,8 @@ impl ServiceWatcher {
                                             }
                                         }
                                     }
+                                    // Objects outside the context's namespace filter
+                                    watcher::Event::Apply(_) | watcher::Event::Delete(_) => {}
                                     watcher::Event::Init | watcher::Event::InitApply(_) | watcher::Event::InitDone => {
                                         info!("Service watcher initialization event");
                                     }
@@ -919,10 +942,11 @@ impl CronJobWatcher {
         }
     }
 
-    pub async fn start(
+    pub async fn start<R: Runtime>(
         &mut self,
         client: Client,
-        app_handle: AppHandle,
+        app_handle: AppHandle<R>,
+        filter: NamespaceFilter,
     ) -> Result<(), String> {
         self.stop();
 
@@ -949,7 +973,7 @@ impl CronJobWatcher {
                         match result {
                             Some(Ok(event)) => {
                                 match event {
-                                    watcher::Event::Apply(cronjob) => {
+                                    watcher::Event::Apply(cronjob) if kuboard_namespace_allowed(&filter, cronjob.metadata.namespace.as_deref()) => {
                                         if let Some(name) = cronjob.metadata.name.as_ref() {
                                             let key = format!("{}/{}", 
                                                 cronjob.metadata.namespace.as_ref().unwrap_or(&"default".to_string()),
@@ -974,7 +998,7 @@ impl CronJobWatcher {
                                             }
                                         }
                                     }
-                                    watcher::Event::Delete(cronjob) => {
+                                    watcher::Event::Delete(cronjob) if kuboard_namespace_allowed(&filter, cronjob.metadata.namespace.as_deref()) => {
                                         if let Some(name) = cronjob.metadata.name.as_ref() {
                                             let key = format!("{}/{}", 
                                                 cronjob.metadata.namespace.as_ref().unwrap_or(&"default".to_string()),
@@ -990,6 +1014,8 @@ impl CronJobWatcher {
                                             }
                                         }
                                     }
+                                    // Objects outside the context's namespace filter
+                                    watcher::Event::Apply(_) | watcher::Event::Delete(_) => {}
                                     watcher::Event::Init | watcher::Event::InitApply(_) | watcher::Event::InitDone => {
                                         info!("CronJob watcher initialization event");
                                     }
@@ -1029,3 +1055,255 @@ impl Drop for CronJobWatcher {
     }
 }
 
+
+// Job Progress Watch Event
+// Carries progress rather than the Job so long batch runs can update counts and estimates live
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobProgressWatchEvent {
+    pub event_type: WatchEventType,
+    pub progress: JobProgress,
+}
+
+pub struct JobProgressWatcher {
+    handle: Option<JoinHandle<()>>,
+    stop_tx: Option<mpsc::Sender<()>>,
+}
+
+impl JobProgressWatcher {
+    pub fn new() -> Self {
+        Self {
+            handle: None,
+            stop_tx: None,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.handle.is_some()
+    }
+
+    pub fn stop(&mut self) {
+        if let Some(tx) = self.stop_tx.take() {
+            let _ = tx.try_send(());
+        }
+        if let Some(handle) = self.handle.take() {
+            handle.abort();
+        }
+    }
+
+    pub async fn start<R: Runtime>(
+        &mut self,
+        client: Client,
+        app_handle: AppHandle<R>,
+        filter: NamespaceFilter,
+    ) -> Result<(), String> {
+        self.stop();
+
+        info!("Starting job progress watcher");
+
+        let jobs_api: Api<Job> = Api::all(client);
+        let app_handle_clone = app_handle.clone();
+        let (stop_tx, mut stop_rx) = mpsc::channel::<()>(1);
+
+        let handle = tokio::spawn(async move {
+            let stream = watcher(jobs_api, Default::default());
+            tokio::pin!(stream);
+
+            info!("Job progress watcher started, listening for events");
+            let mut seen_jobs = std::collections::HashSet::new();
+
+            loop {
+                tokio::select! {
+                    _ = stop_rx.recv() => {
+                        info!("Job progress watcher stopped by user");
+                        break;
+                    }
+                    result = stream.next() => {
+                        match result {
+                            Some(Ok(event)) => {
+                                match event {
+                                    watcher::Event::Apply(job) if kuboard_namespace_allowed(&filter, job.metadata.namespace.as_deref()) => {
+                                        let progress = kuboard_job_progress(&job, chrono::Utc::now());
+                                        let key = format!("{}/{}", progress.namespace, progress.name);
+                                        let event_type = if seen_jobs.insert(key) {
+                                            WatchEventType::Added
+                                        } else {
+                                            WatchEventType::Modified
+                                        };
+                                        info!("Job progress watch event: {:?} {}", event_type, progress.name);
+
+                                        if let Err(e) = app_handle_clone.emit("job-progress-event", JobProgressWatchEvent { event_type, progress }) {
+                                            error!("Failed to emit job progress event: {}", e);
+                                        }
+                                    }
+                                    watcher::Event::Delete(job) if kuboard_namespace_allowed(&filter, job.metadata.namespace.as_deref()) => {
+                                        let progress = kuboard_job_progress(&job, chrono::Utc::now());
+                                        seen_jobs.remove(&format!("{}/{}", progress.namespace, progress.name));
+                                        info!("Job progress watch event: Deleted {}", progress.name);
+                                        if let Err(e) = app_handle_clone.emit("job-progress-event", JobProgressWatchEvent {
+                                            event_type: WatchEventType::Deleted,
+                                            progress,
+                                        }) {
+                                            error!("Failed to emit job progress event: {}", e);
+                                        }
+                                    }
+                                    // Objects outside the context's namespace filter
+                                    watcher::Event::Apply(_) | watcher::Event::Delete(_) => {}
+                                    watcher::Event::Init | watcher::Event::InitApply(_) | watcher::Event::InitDone => {
+                                        info!("Job progress watcher initialization event");
+                                    }
+                                }
+                            }
+                            Some(Err(e)) => {
+                                error!("Job progress watcher error: {}", e);
+                                let _ = app_handle_clone.emit("job-progress-watch-error", serde_json::json!({
+                                    "error": format!("Watch error: {}", e)
+                                }));
+                            }
+                            None => {
+                                warn!("Job progress watcher stream ended");
+                                let _ = app_handle_clone.emit("job-progress-watch-error", serde_json::json!({
+                                    "error": "Watch stream ended"
+                                }));
+                                break;
+                            }
+                        }
+                    }
+                }
+            }
+
+            info!("Job progress watcher task completed");
+        });
+
+        self.handle = Some(handle);
+        self.stop_tx = Some(stop_tx);
+
+        Ok(())
+    }
+}
+
+impl Drop for JobProgressWatcher {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+// Warning Feed
+// Warning events matching a WarningFeedFilter, one toast per new occurrence; events that exist when
+// the watch starts are taken as already seen
+pub struct WarningFeedWatcher {
+    handle: Option<JoinHandle<()>>,
+    stop_tx: Option<mpsc::Sender<()>>,
+}
+
+impl WarningFeedWatcher {
+    pub fn new() -> Self {
+        Self {
+            handle: None,
+            stop_tx: None,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.handle.is_some()
+    }
+
+    pub fn stop(&mut self) {
+        if let Some(tx) = self.stop_tx.take() {
+            let _ = tx.try_send(());
+        }
+        if let Some(handle) = self.handle.take() {
+            handle.abort();
+        }
+    }
+
+    pub async fn start<R: Runtime>(
+        &mut self,
+        client: Client,
+        app_handle: AppHandle<R>,
+        filter: NamespaceFilter,
+        feed_filter: WarningFeedFilter,
+    ) -> Result<(), String> {
+        self.stop();
+
+        info!("Starting warning feed watcher (reasons: {:?}, namespaces: {:?})", feed_filter.reasons, feed_filter.namespaces);
+
+        let events_api: Api<Event> = Api::all(client);
+        let app_handle_clone = app_handle.clone();
+        let (stop_tx, mut stop_rx) = mpsc::channel::<()>(1);
+
+        let handle = tokio::spawn(async move {
+            let stream = watcher(events_api, watcher::Config::default().fields("type=Warning"));
+            tokio::pin!(stream);
+
+            info!("Warning feed watcher started, listening for events");
+            let mut seen = WarningOccurrences::default();
+
+            loop {
+                tokio::select! {
+                    _ = stop_rx.recv() => {
+                        info!("Warning feed watcher stopped by user");
+                        break;
+                    }
+                    result = stream.next() => {
+                        match result {
+                            Some(Ok(event)) => {
+                                match event {
+                                    watcher::Event::Apply(event)
+                                        if kuboard_namespace_allowed(&filter, event.metadata.namespace.as_deref())
+                                            && kuboard_warning_feed_matches(&feed_filter, &event) =>
+                                    {
+                                        if !seen.record(&event) {
+                                            continue;
+                                        }
+                                        let toast = kuboard_warning_toast(&event);
+                                        info!("Warning feed event: {} {}/{}", toast.reason, toast.namespace, toast.involved_name);
+                                        if let Err(e) = app_handle_clone.emit("warning-feed-event", toast) {
+                                            error!("Failed to emit warning feed event: {}", e);
+                                        }
+                                    }
+                                    watcher::Event::InitApply(event) => {
+                                        seen.record(&event);
+                                    }
+                                    watcher::Event::Delete(event) => {
+                                        seen.forget(&event);
+                                    }
+                                    // Events outside the namespace filter or the feed's reasons
+                                    watcher::Event::Apply(_) => {}
+                                    watcher::Event::Init | watcher::Event::InitDone => {
+                                        info!("Warning feed watcher initialization event");
+                                    }
+                                }
+                            }
+                            Some(Err(e)) => {
+                                error!("Warning feed watcher error: {}", e);
+                                let _ = app_handle_clone.emit("warning-feed-watch-error", serde_json::json!({
+                                    "error": format!("Watch error: {}", e)
+                                }));
+                            }
+                            None => {
+                                warn!("Warning feed watcher stream ended");
+                                let _ = app_handle_clone.emit("warning-feed-watch-error", serde_json::json!({
+                                    "error": "Watch stream ended"
+                                }));
+                                break;
+                            }
+                        }
+                    }
+                }
+            }
+
+            info!("Warning feed watcher task completed");
+        });
+
+        self.handle = Some(handle);
+        self.stop_tx = Some(stop_tx);
+
+        Ok(())
+    }
+}
+
+impl Drop for WarningFeedWatcher {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
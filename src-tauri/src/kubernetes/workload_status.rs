@@ -0,0 +1,197 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Workload Status
+// Rolls Deployment, StatefulSet and DaemonSet status up into the READY/UP-TO-DATE/AVAILABLE
+// columns of kubectl plus one health verdict for list views
+
+use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, StatefulSet};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+use crate::types::{WorkloadHealth, WorkloadSummary};
+use crate::utils::kuboard_format_age;
+
+struct Rollup {
+    desired: i32,
+    ready: i32,
+    up_to_date: i32,
+    available: i32,
+    // The controller hasn't finished moving pods to the current template
+    rolling: bool,
+    // The controller gave up, e.g. the progress deadline passed
+    failure: Option<String>,
+}
+
+// A failed rollout is degraded, an unfinished one is progressing, and a finished one missing
+// ready or available pods is degraded
+fn health(rollup: &Rollup) -> (WorkloadHealth, Option<String>) {
+    if let Some(failure) = &rollup.failure {
+        return (WorkloadHealth::Degraded, Some(failure.clone()));
+    }
+    if rollup.rolling {
+        let message = format!("{} of {} pods updated", rollup.up_to_date, rollup.desired);
+        return (WorkloadHealth::Progressing, Some(message));
+    }
+    if rollup.ready < rollup.desired || rollup.available < rollup.desired {
+        let message = format!("{} of {} pods ready, {} available", rollup.ready, rollup.desired, rollup.available);
+        return (WorkloadHealth::Degraded, Some(message));
+    }
+    (WorkloadHealth::Healthy, None)
+}
+
+fn summary(kind: &str, metadata: &ObjectMeta, rollup: Rollup) -> WorkloadSummary {
+    let (health, message) = health(&rollup);
+    WorkloadSummary {
+        kind: kind.to_string(),
+        name: metadata.name.clone().unwrap_or_default(),
+        namespace: metadata.namespace.clone().unwrap_or_default(),
+        desired: rollup.desired,
+        ready: rollup.ready,
+        up_to_date: rollup.up_to_date,
+        available: rollup.available,
+        health,
+        message,
+        age: metadata.creation_timestamp.as_ref().map(|t| kuboard_format_age(t.0)),
+    }
+}
+
+// The controller hasn't seen the latest spec yet
+fn stale(metadata: &ObjectMeta, observed_generation: Option<i64>) -> bool {
+    metadata.generation.is_some_and(|generation| observed_generation.unwrap_or(0) < generation)
+}
+
+pub fn kuboard_deployment_summary(deployment: &Deployment) -> WorkloadSummary {
+    let status = deployment.status.clone().unwrap_or_default();
+    let desired = deployment.spec.as_ref().and_then(|s| s.replicas).unwrap_or(1);
+    let up_to_date = status.updated_replicas.unwrap_or(0);
+    let conditions = status.conditions.unwrap_or_default();
+    let progressing = conditions.iter().find(|c| c.type_ == "Progressing");
+
+    let failure = progressing
+        .filter(|c| c.reason.as_deref() == Some("ProgressDeadlineExceeded"))
+        .map(|c| c.message.clone().unwrap_or_else(|| "Progress deadline exceeded".to_string()));
+    // Until the new ReplicaSet is available the controller reports the rollout as in flight
+    let rollout_reported = progressing.is_some_and(|c| c.status == "True" && c.reason.as_deref() != Some("NewReplicaSetAvailable"));
+    let paused = deployment.spec.as_ref().and_then(|s| s.paused).unwrap_or(false);
+    let rolling = !paused
+        && (stale(&deployment.metadata, status.observed_generation)
+            || up_to_date < desired
+            || status.replicas.unwrap_or(0) > up_to_date
+            || rollout_reported);
+
+    summary("Deployment", &deployment.metadata, Rollup {
+        desired,
+        ready: status.ready_replicas.unwrap_or(0),
+        up_to_date,
+        available: status.available_replicas.unwrap_or(0),
+        rolling,
+        failure,
+    })
+}
+
+pub fn kuboard_statefulset_summary(statefulset: &StatefulSet) -> WorkloadSummary {
+    let status = statefulset.status.clone().unwrap_or_default();
+    let spec = statefulset.spec.as_ref();
+    let desired = spec.and_then(|s| s.replicas).unwrap_or(1);
+    let up_to_date = status.updated_replicas.unwrap_or(0);
+    // OnDelete sets only update when their pods are deleted, so old revisions aren't a rollout
+    let on_delete = spec
+        .and_then(|s| s.update_strategy.as_ref())
+        .and_then(|s| s.type_.as_deref()) == Some("OnDelete");
+    let revision_pending = status.update_revision.is_some() && status.current_revision != status.update_revision;
+    let rolling = stale(&statefulset.metadata, status.observed_generation)
+        || (!on_delete && (up_to_date < desired || revision_pending));
+
+    summary("StatefulSet", &statefulset.metadata, Rollup {
+        desired,
+        ready: status.ready_replicas.unwrap_or(0),
+        up_to_date,
+        available: status.available_replicas.unwrap_or(0),
+        rolling,
+        failure: None,
+    })
+}
+
+pub fn kuboard_daemonset_summary(daemonset: &DaemonSet) -> WorkloadSummary {
+    let status = daemonset.status.clone().unwrap_or_default();
+    let desired = status.desired_number_scheduled;
+    let up_to_date = status.updated_number_scheduled.unwrap_or(0);
+    let on_delete = daemonset.spec.as_ref()
+        .and_then(|s| s.update_strategy.as_ref())
+        .and_then(|s| s.type_.as_deref()) == Some("OnDelete");
+    let rolling = stale(&daemonset.metadata, status.observed_generation) || (!on_delete && up_to_date < desired);
+
+    summary("DaemonSet", &daemonset.metadata, Rollup {
+        desired,
+        ready: status.number_ready,
+        up_to_date,
+        available: status.number_available.unwrap_or(0),
+        rolling,
+        failure: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    fn deployment(generation: i64, status: Value) -> Deployment {
+        serde_json::from_value(json!({
+            "metadata": { "name": "web", "namespace": "default", "generation": generation },
+            "spec": { "replicas": 3, "selector": {}, "template": {} },
+            "status": status,
+        })).unwrap()
+    }
+
+    #[test]
+    fn test_deployment_health() {
+        let healthy = kuboard_deployment_summary(&deployment(2, json!({
+            "observedGeneration": 2, "replicas": 3, "updatedReplicas": 3, "readyReplicas": 3, "availableReplicas": 3,
+            "conditions": [{ "type": "Progressing", "status": "True", "reason": "NewReplicaSetAvailable" }],
+        })));
+        assert_eq!(healthy.health, WorkloadHealth::Healthy);
+        assert_eq!((healthy.desired, healthy.ready, healthy.up_to_date, healthy.available), (3, 3, 3, 3));
+
+        let rolling = kuboard_deployment_summary(&deployment(3, json!({
+            "observedGeneration": 3, "replicas": 4, "updatedReplicas": 1, "readyReplicas": 3, "availableReplicas": 3,
+            "conditions": [{ "type": "Progressing", "status": "True", "reason": "ReplicaSetUpdated" }],
+        })));
+        assert_eq!(rolling.health, WorkloadHealth::Progressing);
+        assert_eq!(rolling.message.as_deref(), Some("1 of 3 pods updated"));
+
+        let stuck = kuboard_deployment_summary(&deployment(3, json!({
+            "observedGeneration": 3, "replicas": 4, "updatedReplicas": 1, "readyReplicas": 3, "availableReplicas": 3,
+            "conditions": [{ "type": "Progressing", "status": "False", "reason": "ProgressDeadlineExceeded", "message": "ReplicaSet \"web-2\" has timed out progressing." }],
+        })));
+        assert_eq!(stuck.health, WorkloadHealth::Degraded);
+        assert!(stuck.message.unwrap().contains("timed out"));
+
+        let crashing = kuboard_deployment_summary(&deployment(2, json!({
+            "observedGeneration": 2, "replicas": 3, "updatedReplicas": 3, "readyReplicas": 1, "availableReplicas": 1,
+            "conditions": [{ "type": "Progressing", "status": "True", "reason": "NewReplicaSetAvailable" }],
+        })));
+        assert_eq!(crashing.health, WorkloadHealth::Degraded);
+    }
+
+    #[test]
+    fn test_statefulset_and_daemonset_health() {
+        let statefulset: StatefulSet = serde_json::from_value(json!({
+            "metadata": { "name": "db", "namespace": "default", "generation": 1 },
+            "spec": { "replicas": 2, "selector": {}, "serviceName": "db", "template": {} },
+            "status": { "observedGeneration": 1, "replicas": 2, "readyReplicas": 2, "availableReplicas": 2, "updatedReplicas": 1,
+                        "currentRevision": "db-1", "updateRevision": "db-2" },
+        })).unwrap();
+        assert_eq!(kuboard_statefulset_summary(&statefulset).health, WorkloadHealth::Progressing);
+
+        let daemonset: DaemonSet = serde_json::from_value(json!({
+            "metadata": { "name": "agent", "namespace": "kube-system", "generation": 1 },
+            "spec": { "selector": {}, "template": {} },
+            "status": { "observedGeneration": 1, "desiredNumberScheduled": 3, "currentNumberScheduled": 3, "numberMisscheduled": 0,
+                        "numberReady": 2, "numberAvailable": 2, "updatedNumberScheduled": 3 },
+        })).unwrap();
+        let summary = kuboard_daemonset_summary(&daemonset);
+        assert_eq!(summary.health, WorkloadHealth::Degraded);
+        assert_eq!((summary.desired, summary.ready), (3, 2));
+    }
+}
@@ -0,0 +1,310 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Workload Suspend/Resume
+// Scales Deployments and StatefulSets to zero and back, remembering the replica count
+
+use anyhow::{anyhow, Result};
+use kube::api::{ListParams, Patch, PatchParams};
+use kube::{Api, Client, Resource};
+use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, StatefulSet};
+use k8s_openapi::api::batch::v1::{CronJob, Job};
+use k8s_openapi::api::core::v1::{Pod, PodSpec};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use k8s_openapi::NamespaceResourceScope;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::fmt::Debug;
+
+use crate::kubernetes::conflict::{kuboard_changed_fields, ConflictError};
+use crate::types::{ConflictReport, WorkloadSuspendState};
+
+// Replica count recorded on the workload while it is suspended
+pub const SUSPENDED_REPLICAS_ANNOTATION: &str = "kuboard.io/suspended-replicas";
+
+fn workload_replicas<K: Serialize>(workload: &K) -> Result<i32> {
+    let value = serde_json::to_value(workload)?;
+    // The API server defaults spec.replicas to 1
+    Ok(value.pointer("/spec/replicas").and_then(|r| r.as_i64()).unwrap_or(1) as i32)
+}
+
+fn suspended_replicas<K: Resource>(workload: &K) -> Option<i32> {
+    workload.meta().annotations.as_ref()
+        .and_then(|a| a.get(SUSPENDED_REPLICAS_ANNOTATION))
+        .and_then(|r| r.parse().ok())
+}
+
+// Merge-patches the workload as it was read, so a scale change that lands in between is
+// reported as a ConflictError instead of being overwritten
+async fn patch_unchanged<K>(api: &Api<K>, workload: &K, kind: &str, action: &str, mut patch: Value) -> Result<()>
+where
+    K: Resource + Clone + Debug + Serialize + DeserializeOwned,
+{
+    let name = workload.meta().name.clone().unwrap_or_default();
+    let namespace = workload.meta().namespace.clone();
+    patch["metadata"]["resourceVersion"] = json!(workload.meta().resource_version);
+    match api.patch(&name, &PatchParams::default(), &Patch::Merge(&patch)).await {
+        Ok(_) => Ok(()),
+        Err(kube::Error::Api(e)) if e.code == 409 => {
+            let latest = api.get(&name).await?;
+            Err(ConflictError(ConflictReport {
+                reason: "Conflict".to_string(),
+                kind: kind.to_string(),
+                name: name.clone(),
+                namespace: namespace.clone(),
+                attempts: 1,
+                changed_fields: kuboard_changed_fields(&serde_json::to_value(workload)?, &serde_json::to_value(&latest)?),
+                latest_resource_version: latest.meta().resource_version.clone(),
+                message: format!(
+                    "{} {}/{} changed while it was being {}; check its replicas and try again",
+                    kind, namespace.unwrap_or_default(), name, action
+                ),
+            }).into())
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+async fn suspend<K>(api: Api<K>, kind: &str, namespace: &str, name: &str) -> Result<WorkloadSuspendState>
+where
+    K: Resource + Clone + Debug + Serialize + DeserializeOwned,
+{
+    let workload = api.get(name).await?;
+    if suspended_replicas(&workload).is_some() {
+        return Err(anyhow!("{} {}/{} is already suspended", kind, namespace, name));
+    }
+    let replicas = workload_replicas(&workload)?;
+
+    // One merge patch keeps the annotation and the scale-down atomic
+    let patch = json!({
+        "metadata": { "annotations": { SUSPENDED_REPLICAS_ANNOTATION: replicas.to_string() } },
+        "spec": { "replicas": 0 },
+    });
+    patch_unchanged(&api, &workload, kind, "suspended", patch).await?;
+
+    Ok(WorkloadSuspendState {
+        kind: kind.to_string(),
+        name: name.to_string(),
+        namespace: namespace.to_string(),
+        replicas: 0,
+        suspended_replicas: Some(replicas),
+    })
+}
+
+async fn resume<K>(api: Api<K>, kind: &str, namespace: &str, name: &str) -> Result<WorkloadSuspendState>
+where
+    K: Resource + Clone + Debug + Serialize + DeserializeOwned,
+{
+    let workload = api.get(name).await?;
+    let replicas = suspended_replicas(&workload)
+        .ok_or_else(|| anyhow!("{} {}/{} was not suspended by Kuboard", kind, namespace, name))?;
+
+    let patch = json!({
+        "metadata": { "annotations": { SUSPENDED_REPLICAS_ANNOTATION: null } },
+        "spec": { "replicas": replicas },
+    });
+    patch_unchanged(&api, &workload, kind, "resumed", patch).await?;
+
+    Ok(WorkloadSuspendState {
+        kind: kind.to_string(),
+        name: name.to_string(),
+        namespace: namespace.to_string(),
+        replicas,
+        suspended_replicas: None,
+    })
+}
+
+pub async fn kuboard_suspend_workload_replicas(client: &Client, kind: &str, namespace: &str, name: &str) -> Result<WorkloadSuspendState> {
+    match kind {
+        "Deployment" => suspend(Api::<Deployment>::namespaced(client.clone(), namespace), kind, namespace, name).await,
+        "StatefulSet" => suspend(Api::<StatefulSet>::namespaced(client.clone(), namespace), kind, namespace, name).await,
+        other => Err(anyhow!("Suspend is not supported for {}", other)),
+    }
+}
+
+pub async fn kuboard_resume_workload_replicas(client: &Client, kind: &str, namespace: &str, name: &str) -> Result<WorkloadSuspendState> {
+    match kind {
+        "Deployment" => resume(Api::<Deployment>::namespaced(client.clone(), namespace), kind, namespace, name).await,
+        "StatefulSet" => resume(Api::<StatefulSet>::namespaced(client.clone(), namespace), kind, namespace, name).await,
+        other => Err(anyhow!("Resume is not supported for {}", other)),
+    }
+}
+
+// A top-level workload and the pod spec its pods are created from
+pub struct WorkloadPodSpec {
+    pub kind: String,
+    pub name: String,
+    pub namespace: String,
+    pub spec: PodSpec,
+}
+
+fn is_controlled(metadata: &ObjectMeta) -> bool {
+    metadata.owner_references.as_ref().is_some_and(|refs| refs.iter().any(|r| r.controller == Some(true)))
+}
+
+fn workload_pod_spec(kind: &str, metadata: ObjectMeta, spec: Option<PodSpec>) -> Option<WorkloadPodSpec> {
+    Some(WorkloadPodSpec {
+        kind: kind.to_string(),
+        name: metadata.name?,
+        namespace: metadata.namespace.unwrap_or_default(),
+        spec: spec?,
+    })
+}
+
+fn list_api<K>(client: &Client, namespace: Option<&str>) -> Api<K>
+where
+    K: Resource<DynamicType = (), Scope = NamespaceResourceScope>,
+{
+    match namespace {
+        Some(namespace) => Api::namespaced(client.clone(), namespace),
+        None => Api::all(client.clone()),
+    }
+}
+
+// Pod specs of top-level workloads; Jobs and Pods owned by a controller are reported through their owner
+pub async fn kuboard_list_workload_pod_specs(client: &Client, namespace: Option<&str>) -> Result<Vec<WorkloadPodSpec>> {
+    let params = ListParams::default();
+    let mut workloads = Vec::new();
+
+    for d in list_api::<Deployment>(client, namespace).list(&params).await? {
+        workloads.extend(workload_pod_spec("Deployment", d.metadata, d.spec.and_then(|s| s.template.spec)));
+    }
+    for s in list_api::<StatefulSet>(client, namespace).list(&params).await? {
+        workloads.extend(workload_pod_spec("StatefulSet", s.metadata, s.spec.and_then(|s| s.template.spec)));
+    }
+    for d in list_api::<DaemonSet>(client, namespace).list(&params).await? {
+        workloads.extend(workload_pod_spec("DaemonSet", d.metadata, d.spec.and_then(|s| s.template.spec)));
+    }
+    for c in list_api::<CronJob>(client, namespace).list(&params).await? {
+        let spec = c.spec.and_then(|s| s.job_template.spec).and_then(|s| s.template.spec);
+        workloads.extend(workload_pod_spec("CronJob", c.metadata, spec));
+    }
+    for j in list_api::<Job>(client, namespace).list(&params).await? {
+        if !is_controlled(&j.metadata) {
+            workloads.extend(workload_pod_spec("Job", j.metadata, j.spec.and_then(|s| s.template.spec)));
+        }
+    }
+    for p in list_api::<Pod>(client, namespace).list(&params).await? {
+        if !is_controlled(&p.metadata) {
+            workloads.extend(workload_pod_spec("Pod", p.metadata, p.spec));
+        }
+    }
+    Ok(workloads)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deployment(replicas: Option<i32>, annotation: Option<&str>) -> Deployment {
+        let mut value = json!({
+            "apiVersion": "apps/v1",
+            "kind": "Deployment",
+            "metadata": { "name": "web", "namespace": "default" },
+            "spec": { "selector": { "matchLabels": { "app": "web" } }, "template": {} },
+        });
+        if let Some(replicas) = replicas {
+            value["spec"]["replicas"] = json!(replicas);
+        }
+        if let Some(annotation) = annotation {
+            value["metadata"]["annotations"] = json!({ SUSPENDED_REPLICAS_ANNOTATION: annotation });
+        }
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_saved_replicas_annotation() {
+        assert_eq!(workload_replicas(&deployment(Some(3), None)).unwrap(), 3);
+        // Unset replicas are saved as the API server's default of one
+        assert_eq!(workload_replicas(&deployment(None, None)).unwrap(), 1);
+
+        assert_eq!(suspended_replicas(&deployment(Some(0), Some("3"))), Some(3));
+        assert_eq!(suspended_replicas(&deployment(Some(0), None)), None);
+        assert_eq!(suspended_replicas(&deployment(Some(0), Some("three"))), None);
+    }
+
+    #[cfg(feature = "mock-cluster")]
+    mod mock_cluster {
+        use super::*;
+        use crate::kubernetes::mock::MockCluster;
+
+        fn client_with(workload: Deployment) -> Client {
+            let cluster = MockCluster::empty();
+            cluster.insert(serde_json::to_value(workload).unwrap());
+            cluster.client()
+        }
+
+        async fn get(client: &Client) -> Deployment {
+            Api::<Deployment>::namespaced(client.clone(), "default").get("web").await.unwrap()
+        }
+
+        #[tokio::test]
+        async fn test_suspend_resume_round_trip() {
+            let client = client_with(deployment(Some(3), None));
+
+            let suspended = kuboard_suspend_workload_replicas(&client, "Deployment", "default", "web").await.unwrap();
+            assert_eq!((suspended.replicas, suspended.suspended_replicas), (0, Some(3)));
+            let stored = get(&client).await;
+            assert_eq!(stored.spec.as_ref().and_then(|s| s.replicas), Some(0));
+            assert_eq!(suspended_replicas(&stored), Some(3));
+
+            let resumed = kuboard_resume_workload_replicas(&client, "Deployment", "default", "web").await.unwrap();
+            assert_eq!((resumed.replicas, resumed.suspended_replicas), (3, None));
+            let stored = get(&client).await;
+            assert_eq!(stored.spec.as_ref().and_then(|s| s.replicas), Some(3));
+            assert!(!stored.metadata.annotations.unwrap_or_default().contains_key(SUSPENDED_REPLICAS_ANNOTATION));
+        }
+
+        #[tokio::test]
+        async fn test_suspend_rejects_already_suspended() {
+            let client = client_with(deployment(Some(0), Some("4")));
+
+            let err = kuboard_suspend_workload_replicas(&client, "Deployment", "default", "web").await.unwrap_err();
+            assert!(err.to_string().contains("already suspended"));
+            // The first suspend's saved count is kept
+            assert_eq!(suspended_replicas(&get(&client).await), Some(4));
+        }
+
+        #[tokio::test]
+        async fn test_resume_rejects_missing_annotation() {
+            let client = client_with(deployment(Some(0), None));
+
+            let err = kuboard_resume_workload_replicas(&client, "Deployment", "default", "web").await.unwrap_err();
+            assert!(err.to_string().contains("was not suspended by Kuboard"));
+            assert_eq!(get(&client).await.spec.and_then(|s| s.replicas), Some(0));
+        }
+
+        #[tokio::test]
+        async fn test_patch_reports_concurrent_scale_as_conflict() {
+            let cluster = MockCluster::empty();
+            cluster.insert(serde_json::to_value(deployment(Some(0), Some("3"))).unwrap());
+            let client = cluster.client();
+            let api = Api::<Deployment>::namespaced(client.clone(), "default");
+            let stale = api.get("web").await.unwrap();
+
+            // Someone scales the workload between the read and the patch
+            let mut scaled = serde_json::to_value(&stale).unwrap();
+            scaled["spec"]["replicas"] = json!(5);
+            cluster.insert(scaled);
+
+            let patch = json!({ "metadata": { "annotations": { SUSPENDED_REPLICAS_ANNOTATION: null } }, "spec": { "replicas": 3 } });
+            let err = patch_unchanged(&api, &stale, "Deployment", "resumed", patch).await.unwrap_err();
+            let ConflictError(report) = err.downcast_ref::<ConflictError>().unwrap();
+            assert_eq!(report.changed_fields, vec!["/spec/replicas".to_string()]);
+            assert_eq!(get(&client).await.spec.and_then(|s| s.replicas), Some(5));
+        }
+
+        #[tokio::test]
+        async fn test_resume_restores_default_replicas() {
+            // A workload suspended without spec.replicas comes back at the default of one
+            let client = client_with(deployment(None, None));
+
+            let suspended = kuboard_suspend_workload_replicas(&client, "Deployment", "default", "web").await.unwrap();
+            assert_eq!(suspended.suspended_replicas, Some(1));
+            let resumed = kuboard_resume_workload_replicas(&client, "Deployment", "default", "web").await.unwrap();
+            assert_eq!(resumed.replicas, 1);
+            assert_eq!(get(&client).await.spec.and_then(|s| s.replicas), Some(1));
+        }
+    }
+}
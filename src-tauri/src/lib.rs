@@ -14,6 +14,7 @@ pub mod types;
 pub mod app_state;
 pub mod utils;
 pub mod metrics;
+pub mod quantity;
 
 // Re-exports for convenience
 pub use app_state::AppState;
@@ -21,6 +22,355 @@ pub use types::*;
 
 use tracing::info;
 
+// Command API version reported to the frontend; bump on breaking command changes
+pub const KUBOARD_API_VERSION: u32 = 2;
+
+// Every command registered with the invoke handler, reported by kuboard_get_capabilities
+pub const KUBOARD_COMMANDS: &[&str] = &[
+    // Context Management
+    "kuboard_list_contexts",
+    "kuboard_set_context_metadata",
+    "kuboard_request_confirmation",
+    "kuboard_confirm_action",
+    "kuboard_get_audit_log",
+    "kuboard_set_context",
+    "kuboard_get_current_context",
+
+    // Cluster Overview
+    "kuboard_get_cluster_overview",
+    "kuboard_get_cluster_addons",
+
+    // Resource Management
+    "kuboard_get_nodes",
+    "kuboard_get_namespaces",
+    "kuboard_get_namespace_detail",
+    "kuboard_get_pods",
+    "kuboard_get_pods_page",
+    "kuboard_get_pod_summaries",
+    "kuboard_get_deployments",
+    "kuboard_get_deployment_summaries",
+    "kuboard_get_replicasets",
+    "kuboard_get_replicaset",
+    "kuboard_scale_replicaset",
+    "kuboard_get_replicaset_pods",
+    "kuboard_get_deployment",
+    "kuboard_scale_deployment",
+    "kuboard_rollback_deployment",
+    "kuboard_pause_deployment",
+    "kuboard_resume_deployment",
+    "kuboard_get_deployment_rollout_status",
+    "kuboard_restart_deployment",
+    "kuboard_get_deployment_replicasets",
+    "kuboard_get_deployment_rollout_history",
+    "kuboard_diff_workload_revisions",
+    "kuboard_get_deployment_pods",
+    "kuboard_get_statefulsets",
+    "kuboard_get_statefulset_summaries",
+    "kuboard_get_statefulset",
+    "kuboard_scale_statefulset",
+    "kuboard_restart_statefulset",
+    "kuboard_get_statefulset_pods",
+    "kuboard_get_statefulset_volumes",
+    "kuboard_delete_statefulset_orphaned_pvcs",
+    "kuboard_get_daemonsets",
+    "kuboard_get_daemonset_summaries",
+    "kuboard_get_daemonset",
+    "kuboard_restart_daemonset",
+    "kuboard_get_daemonset_pods",
+    "kuboard_get_cronjobs",
+    "kuboard_get_cronjob",
+    "kuboard_trigger_cronjob",
+    "kuboard_suspend_cronjob",
+    "kuboard_resume_cronjob",
+    "kuboard_get_cronjob_jobs",
+    "kuboard_get_cronjob_run_summary",
+    "kuboard_set_cronjob_history_limits",
+    "kuboard_get_services",
+    "kuboard_get_service",
+    "kuboard_get_service_endpoints",
+    "kuboard_get_service_urls",
+    "kuboard_get_load_balancer_status",
+    "kuboard_get_service_details",
+    "kuboard_get_service_pods",
+    "kuboard_get_pod_services",
+    "kuboard_get_configmaps",
+    "kuboard_get_secrets",
+    "kuboard_search_manifests",
+    "kuboard_update_configmap_key",
+
+    // Metrics (Real Implementation)
+    "kuboard_get_node_metrics",
+    "kuboard_get_node_metrics_history",
+    "kuboard_get_node_eviction_order",
+    "kuboard_get_pod_ephemeral_storage",
+    "kuboard_get_ephemeral_storage_report",
+    "kuboard_get_pod_metrics",
+    "kuboard_get_pod_metrics_history",
+    "kuboard_get_pod_events",
+    "kuboard_get_event_trends",
+    "kuboard_get_pod_logs",
+    "kuboard_check_metrics_availability",
+    "kuboard_install_metrics_server",
+    "kuboard_get_cluster_metrics",
+
+    // Pod Actions
+    "kuboard_delete_pod",
+    "kuboard_get_pod_delete_confirmation",
+    "kuboard_restart_pod",
+    "kuboard_restart_statefulset_pod",
+    "kuboard_restart_daemonset_pod",
+    "kuboard_suspend_workload",
+    "kuboard_resume_workload",
+    "kuboard_get_pod_yaml",
+    "kuboard_update_pod_from_yaml",
+    "kuboard_update_resource_from_yaml",
+
+    // Dynamic Resource Commands
+    "kuboard_get_resource",
+    "kuboard_list_resources",
+    "kuboard_list_resources_page",
+    "kuboard_list_resource_table",
+
+    // Resource Delete Commands
+    "kuboard_delete_resource",
+    "kuboard_delete_deployment",
+    "kuboard_delete_statefulset",
+    "kuboard_delete_daemonset",
+    "kuboard_delete_replicaset",
+    "kuboard_delete_service",
+    "kuboard_delete_cronjob",
+
+    // Resource YAML Commands
+    "kuboard_get_deployment_yaml",
+    "kuboard_get_statefulset_yaml",
+    "kuboard_get_daemonset_yaml",
+    "kuboard_get_replicaset_yaml",
+    "kuboard_get_service_yaml",
+    "kuboard_get_cronjob_yaml",
+
+    // Pod Watch
+    "kuboard_start_pod_watch",
+    "kuboard_stop_pod_watch",
+
+    // Deployment Watch
+    "kuboard_start_deployment_watch",
+    "kuboard_stop_deployment_watch",
+
+    // StatefulSet Watch
+    "kuboard_start_statefulset_watch",
+    "kuboard_stop_statefulset_watch",
+
+    // DaemonSet Watch
+    "kuboard_start_daemonset_watch",
+    "kuboard_stop_daemonset_watch",
+
+    // ReplicaSet Watch
+    "kuboard_start_replicaset_watch",
+    "kuboard_stop_replicaset_watch",
+
+    // Service Watch
+    "kuboard_start_service_watch",
+    "kuboard_stop_service_watch",
+
+    // CronJob Watch
+    "kuboard_start_cronjob_watch",
+    "kuboard_stop_cronjob_watch",
+    "kuboard_start_job_progress_watch",
+    "kuboard_stop_job_progress_watch",
+    "kuboard_start_warning_feed",
+    "kuboard_stop_warning_feed",
+
+    // Incident Notifier
+    "kuboard_start_incident_notifier",
+    "kuboard_stop_incident_notifier",
+    "kuboard_get_notifier_rules",
+    "kuboard_set_notifier_rule_muted",
+
+    // Resource Describe
+    "kuboard_describe_pod",
+
+    // Pod Exec Commands
+    "kuboard_exec_into_pod",
+    "kuboard_send_exec_input",
+    "kuboard_start_node_shell",
+    "kuboard_close_exec_session",
+    "kuboard_list_exec_recordings",
+    "kuboard_get_exec_recording",
+    "kuboard_delete_exec_recording",
+
+    // Pod File Browser
+    "kuboard_list_pod_files",
+    "kuboard_stat_pod_path",
+    "kuboard_get_pod_file",
+
+    // Saved Commands
+    "kuboard_save_command",
+    "kuboard_list_saved_commands",
+    "kuboard_delete_saved_command",
+    "kuboard_run_saved_command",
+
+    // Port Forwarding Commands
+    "kuboard_port_forward",
+    "kuboard_list_port_forwards",
+    "kuboard_stop_port_forward",
+
+    // Drift Snapshots
+    "kuboard_start_drift_scheduler",
+    "kuboard_stop_drift_scheduler",
+    "kuboard_get_drift_alerts",
+
+    // Capability Discovery
+    "kuboard_get_capabilities",
+
+    // kubectl Command Generation
+    "kuboard_get_kubectl_command",
+
+    // Scheduled Scaling
+    "kuboard_create_scaling_rule",
+    "kuboard_update_scaling_rule",
+    "kuboard_delete_scaling_rule",
+    "kuboard_list_scaling_rules",
+    "kuboard_run_scaling_rule",
+    "kuboard_start_scaling_scheduler",
+    "kuboard_stop_scaling_scheduler",
+    "kuboard_get_scaling_audit",
+
+    // Namespace Clone
+    "kuboard_clone_namespace",
+
+    // Manifest Templates
+    "kuboard_list_manifest_templates",
+    "kuboard_save_manifest_template",
+    "kuboard_delete_manifest_template",
+    "kuboard_render_manifest_template",
+    "kuboard_apply_manifest_template",
+
+    // GitOps Export
+    "kuboard_apply_manifest",
+    "kuboard_diff_manifest",
+    "kuboard_export_clean_yaml",
+    "kuboard_get_field_managers",
+
+    // Manifest Validation
+    "kuboard_validate_yaml",
+
+    // Pod Security Admission
+    "kuboard_get_pod_security_labels",
+    "kuboard_set_pod_security_labels",
+    "kuboard_dry_run_pod_security_level",
+
+    // ServiceAccount Usage
+    "kuboard_get_service_account_usage",
+
+    // RBAC Who-Can
+    "kuboard_who_can",
+
+    // Best-Practices Scanner
+    "kuboard_scan_best_practices",
+
+    // Deprecated API Scanner
+    "kuboard_scan_deprecated_apis",
+
+    // Upgrade Readiness
+    "kuboard_get_upgrade_readiness",
+
+    // Workload Resilience
+    "kuboard_get_resilience_report",
+
+    // Pod Placement Heatmap
+    "kuboard_get_placement_heatmap",
+
+    // Topology
+    "kuboard_get_topology_zones",
+    "kuboard_get_zone_concentration",
+
+    // Service Endpoint Probes
+    "kuboard_probe_service_endpoints",
+
+    // HTTP Request Tester
+    "kuboard_http_probe",
+
+    // Cluster DNS
+    "kuboard_get_cluster_dns",
+
+    // Ingress Routes
+    "kuboard_get_ingress_routes",
+    "kuboard_lookup_ingress_route",
+    "kuboard_create_ingress",
+
+    // TLS secret inspector
+    "kuboard_inspect_tls_secrets",
+
+    // Namespace access
+    "kuboard_get_namespace_access",
+    "kuboard_get_namespace_filter",
+    "kuboard_set_namespace_filter",
+
+    // Access profiles
+    "kuboard_get_profile",
+    "kuboard_set_profile",
+
+    // API rate limiting
+    "kuboard_get_rate_limit",
+    "kuboard_set_rate_limit",
+
+    // Formatting
+    "kuboard_get_format_settings",
+    "kuboard_set_format_settings",
+
+    // Shared watch
+    "kuboard_start_shared_watch",
+    "kuboard_stop_shared_watch",
+
+    // Incremental lists
+    "kuboard_get_list_delta",
+
+    // Compressed payloads
+    "kuboard_get_list_compressed",
+    "kuboard_get_yaml_compressed",
+
+    // Connection keepalive
+    "kuboard_get_connection_status",
+
+    // Credential refresh
+    "kuboard_reauthenticate",
+
+    // Undo Commands
+    "kuboard_list_trash",
+    "kuboard_undo_delete",
+
+    // Batch Job Wizard Commands
+    "kuboard_create_job",
+    "kuboard_create_cronjob",
+    "kuboard_check_cron_schedule",
+
+    // Job Progress Commands
+    "kuboard_get_job_progress",
+
+    // Job Suspend Commands
+    "kuboard_suspend_job",
+    "kuboard_resume_job",
+
+    // PVC Commands
+    "kuboard_create_pvc",
+    "kuboard_get_pvcs",
+    "kuboard_get_pvc",
+    "kuboard_delete_pvc",
+
+    // Persistent Volume Commands
+    "kuboard_get_persistent_volumes",
+    "kuboard_get_persistent_volume",
+    "kuboard_get_volume_binding",
+
+    // CSI Health Commands
+    "kuboard_get_volume_attachments",
+    "kuboard_get_csi_drivers",
+    "kuboard_get_volume_attach_issues",
+
+    // Workload Exposure Commands
+    "kuboard_expose_workload",
+];
+
 // Main application entry point
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
@@ -30,24 +380,43 @@ pub fn run() {
     info!("Starting Kuboard application");
 
     let app_state = AppState::new();
+    // A saved developer profile is in force from the first command on
+    match kubernetes::profiles::kuboard_profile_path().and_then(|path| kubernetes::profiles::kuboard_load_profile(&path)) {
+        Ok(profile) => *app_state.profile.blocking_write() = profile,
+        Err(e) => tracing::warn!("Failed to load access profile, using admin: {}", e),
+    }
 
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
+        .plugin(tauri_plugin_notification::init())
         .manage(app_state)
-        .invoke_handler(tauri::generate_handler![
+        .setup(|app| {
+            kubernetes::auth_monitor::kuboard_spawn_auth_monitor(app.handle().clone());
+            Ok(())
+        })
+        .invoke_handler(kubernetes::profiles::kuboard_profile_gate(tauri::generate_handler![
             // Context Management
             commands::kuboard_list_contexts,
+            commands::kuboard_set_context_metadata,
+            commands::kuboard_request_confirmation,
+            commands::kuboard_confirm_action,
+            commands::kuboard_get_audit_log,
             commands::kuboard_set_context,
             commands::kuboard_get_current_context,
             
             // Cluster Overview
             commands::kuboard_get_cluster_overview,
+            commands::kuboard_get_cluster_addons,
             
             // Resource Management
             commands::kuboard_get_nodes,
             commands::kuboard_get_namespaces,
+            commands::kuboard_get_namespace_detail,
             commands::kuboard_get_pods,
+            commands::kuboard_get_pods_page,
+            commands::kuboard_get_pod_summaries,
             commands::kuboard_get_deployments,
+            commands::kuboard_get_deployment_summaries,
             commands::kuboard_get_replicasets,
             commands::kuboard_get_replicaset,
             commands::kuboard_scale_replicaset,
@@ -55,15 +424,24 @@ pub fn run() {
             commands::kuboard_get_deployment,
             commands::kuboard_scale_deployment,
             commands::kuboard_rollback_deployment,
+            commands::kuboard_pause_deployment,
+            commands::kuboard_resume_deployment,
+            commands::kuboard_get_deployment_rollout_status,
             commands::kuboard_restart_deployment,
             commands::kuboard_get_deployment_replicasets,
+            commands::kuboard_get_deployment_rollout_history,
+            commands::kuboard_diff_workload_revisions,
             commands::kuboard_get_deployment_pods,
             commands::kuboard_get_statefulsets,
+            commands::kuboard_get_statefulset_summaries,
             commands::kuboard_get_statefulset,
             commands::kuboard_scale_statefulset,
             commands::kuboard_restart_statefulset,
             commands::kuboard_get_statefulset_pods,
+            commands::kuboard_get_statefulset_volumes,
+            commands::kuboard_delete_statefulset_orphaned_pvcs,
             commands::kuboard_get_daemonsets,
+            commands::kuboard_get_daemonset_summaries,
             commands::kuboard_get_daemonset,
             commands::kuboard_restart_daemonset,
             commands::kuboard_get_daemonset_pods,
@@ -73,29 +451,56 @@ pub fn run() {
             commands::kuboard_suspend_cronjob,
             commands::kuboard_resume_cronjob,
             commands::kuboard_get_cronjob_jobs,
+            commands::kuboard_get_cronjob_run_summary,
+            commands::kuboard_set_cronjob_history_limits,
             commands::kuboard_get_services,
             commands::kuboard_get_service,
             commands::kuboard_get_service_endpoints,
+            commands::kuboard_get_service_urls,
+            commands::kuboard_get_load_balancer_status,
+            commands::kuboard_get_service_details,
+            commands::kuboard_get_service_pods,
+            commands::kuboard_get_pod_services,
             commands::kuboard_get_configmaps,
             commands::kuboard_get_secrets,
+            commands::kuboard_search_manifests,
+            commands::kuboard_update_configmap_key,
             
             // Metrics (Real Implementation)
             commands::kuboard_get_node_metrics,
             commands::kuboard_get_node_metrics_history,
+        commands::kuboard_get_node_eviction_order,
+        commands::kuboard_get_pod_ephemeral_storage,
+        commands::kuboard_get_ephemeral_storage_report,
         commands::kuboard_get_pod_metrics,
         commands::kuboard_get_pod_metrics_history,
         commands::kuboard_get_pod_events,
+        commands::kuboard_get_event_trends,
         commands::kuboard_get_pod_logs,
         commands::kuboard_check_metrics_availability,
+        commands::kuboard_install_metrics_server,
         commands::kuboard_get_cluster_metrics,
         
         // Pod Actions
         commands::kuboard_delete_pod,
+        commands::kuboard_get_pod_delete_confirmation,
         commands::kuboard_restart_pod,
+        commands::kuboard_restart_statefulset_pod,
+        commands::kuboard_restart_daemonset_pod,
+        commands::kuboard_suspend_workload,
+        commands::kuboard_resume_workload,
         commands::kuboard_get_pod_yaml,
         commands::kuboard_update_pod_from_yaml,
+        commands::kuboard_update_resource_from_yaml,
+        
+        // Dynamic Resource Commands
+        commands::kuboard_get_resource,
+        commands::kuboard_list_resources,
+        commands::kuboard_list_resources_page,
+        commands::kuboard_list_resource_table,
         
         // Resource Delete Commands
+        commands::kuboard_delete_resource,
         commands::kuboard_delete_deployment,
         commands::kuboard_delete_statefulset,
         commands::kuboard_delete_daemonset,
@@ -138,18 +543,203 @@ pub fn run() {
         // CronJob Watch
         commands::kuboard_start_cronjob_watch,
         commands::kuboard_stop_cronjob_watch,
+        commands::kuboard_start_job_progress_watch,
+        commands::kuboard_stop_job_progress_watch,
+        commands::kuboard_start_warning_feed,
+        commands::kuboard_stop_warning_feed,
+        
+        // Incident Notifier
+        commands::kuboard_start_incident_notifier,
+        commands::kuboard_stop_incident_notifier,
+        commands::kuboard_get_notifier_rules,
+        commands::kuboard_set_notifier_rule_muted,
         
         // Resource Describe
         commands::kuboard_describe_pod,
         
         // Pod Exec Commands
         commands::kuboard_exec_into_pod,
+        commands::kuboard_send_exec_input,
+        commands::kuboard_start_node_shell,
+        commands::kuboard_close_exec_session,
+        commands::kuboard_list_exec_recordings,
+        commands::kuboard_get_exec_recording,
+        commands::kuboard_delete_exec_recording,
+        
+        // Pod File Browser
+        commands::kuboard_list_pod_files,
+        commands::kuboard_stat_pod_path,
+        commands::kuboard_get_pod_file,
+        
+        // Saved Commands
+        commands::kuboard_save_command,
+        commands::kuboard_list_saved_commands,
+        commands::kuboard_delete_saved_command,
+        commands::kuboard_run_saved_command,
         
         // Port Forwarding Commands
         commands::kuboard_port_forward,
         commands::kuboard_list_port_forwards,
         commands::kuboard_stop_port_forward,
-        ])
+        
+        // Drift Snapshots
+        commands::kuboard_start_drift_scheduler,
+        commands::kuboard_stop_drift_scheduler,
+        commands::kuboard_get_drift_alerts,
+        
+        // Capability Discovery
+        commands::kuboard_get_capabilities,
+        
+        // kubectl Command Generation
+        commands::kuboard_get_kubectl_command,
+        
+        // Scheduled Scaling
+        commands::kuboard_create_scaling_rule,
+        commands::kuboard_update_scaling_rule,
+        commands::kuboard_delete_scaling_rule,
+        commands::kuboard_list_scaling_rules,
+        commands::kuboard_run_scaling_rule,
+        commands::kuboard_start_scaling_scheduler,
+        commands::kuboard_stop_scaling_scheduler,
+        commands::kuboard_get_scaling_audit,
+        
+        // Namespace Clone
+        commands::kuboard_clone_namespace,
+        
+        // Manifest Templates
+        commands::kuboard_list_manifest_templates,
+        commands::kuboard_save_manifest_template,
+        commands::kuboard_delete_manifest_template,
+        commands::kuboard_render_manifest_template,
+        commands::kuboard_apply_manifest_template,
+        
+        // Generic Apply
+        commands::kuboard_apply_manifest,
+        commands::kuboard_diff_manifest,
+        
+        // GitOps Export
+        commands::kuboard_export_clean_yaml,
+        commands::kuboard_get_field_managers,
+        
+        // Manifest Validation
+        commands::kuboard_validate_yaml,
+        
+        // Pod Security Admission
+        commands::kuboard_get_pod_security_labels,
+        commands::kuboard_set_pod_security_labels,
+        commands::kuboard_dry_run_pod_security_level,
+        
+        // ServiceAccount Usage
+        commands::kuboard_get_service_account_usage,
+        
+        // RBAC Who-Can
+        commands::kuboard_who_can,
+        
+        // Best-Practices Scanner
+        commands::kuboard_scan_best_practices,
+        
+        // Deprecated API Scanner
+        commands::kuboard_scan_deprecated_apis,
+        
+        // Upgrade Readiness
+        commands::kuboard_get_upgrade_readiness,
+        
+        // Workload Resilience
+        commands::kuboard_get_resilience_report,
+        
+        // Pod Placement Heatmap
+        commands::kuboard_get_placement_heatmap,
+        
+        // Topology
+        commands::kuboard_get_topology_zones,
+        commands::kuboard_get_zone_concentration,
+        
+        // Service Endpoint Probes
+        commands::kuboard_probe_service_endpoints,
+        
+        // HTTP Request Tester
+        commands::kuboard_http_probe,
+        
+        // Cluster DNS
+        commands::kuboard_get_cluster_dns,
+        
+        // Ingress Routes
+        commands::kuboard_get_ingress_routes,
+        commands::kuboard_lookup_ingress_route,
+        commands::kuboard_create_ingress,
+        
+        // TLS secret inspector
+        commands::kuboard_inspect_tls_secrets,
+        
+        // Namespace access
+        commands::kuboard_get_namespace_access,
+        commands::kuboard_get_namespace_filter,
+        commands::kuboard_set_namespace_filter,
+        
+        // Access profiles
+        commands::kuboard_get_profile,
+        commands::kuboard_set_profile,
+        
+        // API rate limiting
+        commands::kuboard_get_rate_limit,
+        commands::kuboard_set_rate_limit,
+        
+        // Formatting
+        commands::kuboard_get_format_settings,
+        commands::kuboard_set_format_settings,
+        
+        // Shared watch
+        commands::kuboard_start_shared_watch,
+        commands::kuboard_stop_shared_watch,
+        
+        // Incremental lists
+        commands::kuboard_get_list_delta,
+        
+        // Compressed payloads
+        commands::kuboard_get_list_compressed,
+        commands::kuboard_get_yaml_compressed,
+        
+        // Connection keepalive
+        commands::kuboard_get_connection_status,
+        
+        // Credential refresh
+        commands::kuboard_reauthenticate,
+        
+        // Undo Commands
+        commands::kuboard_list_trash,
+        commands::kuboard_undo_delete,
+        
+        // Batch Job Wizard Commands
+        commands::kuboard_create_job,
+        commands::kuboard_create_cronjob,
+        commands::kuboard_check_cron_schedule,
+        
+        // Job Progress Commands
+        commands::kuboard_get_job_progress,
+        
+        // Job Suspend Commands
+        commands::kuboard_suspend_job,
+        commands::kuboard_resume_job,
+        
+        // PVC Commands
+        commands::kuboard_create_pvc,
+        commands::kuboard_get_pvcs,
+        commands::kuboard_get_pvc,
+        commands::kuboard_delete_pvc,
+        
+        // Persistent Volume Commands
+        commands::kuboard_get_persistent_volumes,
+        commands::kuboard_get_persistent_volume,
+        commands::kuboard_get_volume_binding,
+        
+        // CSI Health Commands
+        commands::kuboard_get_volume_attachments,
+        commands::kuboard_get_csi_drivers,
+        commands::kuboard_get_volume_attach_issues,
+        
+        // Workload Exposure Commands
+        commands::kuboard_expose_workload,
+        ]))
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
@@ -168,6 +758,8 @@ mod tests {
                 user: "test-user".to_string(),
                 namespace: Some("default".to_string()),
                 is_current: true,
+                metadata: ContextMetadata::default(),
+                protected: false,
             }
         ];
 
@@ -204,4 +796,69 @@ mod tests {
         assert_eq!(overview.deployment_count, 8);
         assert_eq!(overview.kubernetes_version, Some("v1.28.0".to_string()));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_kuboard_request_accepts_snake_and_camel_case() {
+        let snake: PodLogsRequest = serde_json::from_value(serde_json::json!({
+            "pod_name": "web-0",
+            "namespace": "default",
+            "container_name": "app",
+            "tail_lines": 100
+        })).unwrap();
+        let camel: PodLogsRequest = serde_json::from_value(serde_json::json!({
+            "podName": "web-0",
+            "namespace": "default",
+            "containerName": "app",
+            "tailLines": 100
+        })).unwrap();
+
+        assert_eq!(snake.pod_name, camel.pod_name);
+        assert_eq!(snake.container_name, camel.container_name);
+        assert_eq!(snake.tail_lines, Some(100));
+        assert_eq!(camel.tail_lines, Some(100));
+        assert_eq!(camel.follow, None);
+    }
+
+    #[test]
+    fn test_kuboard_commands_match_invoke_handler() {
+        let source = include_str!("lib.rs");
+        let handler = source
+            .split("generate_handler![")
+            .nth(1)
+            .and_then(|rest| rest.split("])").next())
+            .unwrap();
+        let registered: std::collections::BTreeSet<&str> = handler
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix("commands::"))
+            .map(|name| name.trim_end_matches(','))
+            .collect();
+        let reported: std::collections::BTreeSet<&str> = KUBOARD_COMMANDS.iter().copied().collect();
+
+        assert_eq!(registered, reported);
+        assert_eq!(reported.len(), KUBOARD_COMMANDS.len());
+    }
+
+    #[test]
+    fn test_kuboard_pod_describe_keeps_json_shape() {
+        let pod: k8s_openapi::api::core::v1::Pod = serde_json::from_value(serde_json::json!({
+            "metadata": { "name": "web-0", "namespace": "default" },
+            "spec": {
+                "containers": [{
+                    "name": "app",
+                    "image": "nginx",
+                    "env": [{ "name": "NODE", "valueFrom": { "fieldRef": { "fieldPath": "spec.nodeName" } } }]
+                }]
+            },
+            "status": { "phase": "Pending", "podIP": "10.0.0.5" }
+        })).unwrap();
+
+        let describe = serde_json::to_value(kubernetes::kuboard_build_pod_describe(&pod, &[])).unwrap();
+
+        assert_eq!(describe["status"]["podIP"], "10.0.0.5");
+        assert_eq!(describe["status"]["hostIP"], "None");
+        assert_eq!(describe["containers"][0]["imagePullPolicy"], "IfNotPresent");
+        assert_eq!(describe["containers"][0]["env"][0]["valueFrom"]["fieldRef"]["fieldPath"], "spec.nodeName");
+        assert_eq!(describe["containers"][0]["status"]["restartCount"], 0);
+        assert_eq!(describe["metadata"]["resourceVersion"], "None");
+    }
+}
@@ -10,6 +10,8 @@ use serde::{Deserialize, Serialize};
 use tracing::{debug, warn, info};
 use chrono::{DateTime, Utc};
 
+use crate::quantity::{kuboard_parse_bytes, kuboard_parse_cpu};
+
 // Real Kubernetes Metrics API types
 #[derive(Debug, Deserialize, Clone)]
 pub struct NodeMetricsList {
@@ -149,6 +151,17 @@ pub async fn get_pod_metrics(client: &Client) -> Result<PodMetricsList> {
     Ok(parsed)
 }
 
+/// Get metrics for the pods of one namespace
+pub async fn get_namespace_pod_metrics(client: &Client, namespace: &str) -> Result<PodMetricsList> {
+    let req = http::Request::get(format!("/apis/metrics.k8s.io/v1beta1/namespaces/{}/pods", namespace))
+        .body(vec![])
+        .unwrap();
+
+    let text = client.request_text(req).await?;
+    let parsed: PodMetricsList = serde_json::from_str(&text)?;
+    Ok(parsed)
+}
+
 /// Get metrics for a specific pod
 pub async fn get_pod_metrics_by_name(client: &Client, pod_name: &str, namespace: &str) -> Result<PodMetrics> {
     let req = http::Request::get(&format!("/apis/metrics.k8s.io/v1beta1/namespaces/{}/pods/{}", namespace, pod_name))
@@ -181,11 +194,11 @@ pub async fn kuboard_fetch_node_metrics_real(
             debug!("Raw memory usage: '{}'", node_metrics.usage.memory);
             
             // Parse CPU usage (e.g., "150m" -> 0.15 cores)
-            let cpu_cores = parse_cpu_quantity(&node_metrics.usage.cpu)
+            let cpu_cores = kuboard_parse_cpu(&node_metrics.usage.cpu)
                 .map_err(|e| anyhow::anyhow!("Failed to parse CPU usage '{}': {}", node_metrics.usage.cpu, e))?;
             
             // Parse memory usage (e.g., "123Mi" -> bytes)
-            let memory_bytes = parse_memory_quantity(&node_metrics.usage.memory)
+            let memory_bytes = kuboard_parse_bytes(&node_metrics.usage.memory)
                 .map_err(|e| anyhow::anyhow!("Failed to parse memory usage '{}': {}", node_metrics.usage.memory, e))?;
             
             // For disk usage, we'll use a default since it's not in node metrics
@@ -235,8 +248,8 @@ pub async fn kuboard_fetch_node_metrics_history(
             info!("✅ Successfully fetched current metrics for history generation");
             
             // Parse current metrics
-            let cpu_cores = parse_cpu_quantity(&current_metrics.usage.cpu)?;
-            let memory_bytes = parse_memory_quantity(&current_metrics.usage.memory)?;
+            let cpu_cores = kuboard_parse_cpu(&current_metrics.usage.cpu)?;
+            let memory_bytes = kuboard_parse_bytes(&current_metrics.usage.memory)?;
             
             // Generate a simple history with slight variations around current values
             let mut history = Vec::new();
@@ -316,12 +329,12 @@ pub async fn kuboard_fetch_pod_metrics_real(
                 debug!("Container {} CPU: '{}', Memory: '{}'", container.name, container.usage.cpu, container.usage.memory);
                 
                 // Parse CPU usage (e.g., "150m" -> 0.15 cores)
-                let cpu_cores = parse_cpu_quantity(&container.usage.cpu)
+                let cpu_cores = kuboard_parse_cpu(&container.usage.cpu)
                     .map_err(|e| anyhow::anyhow!("Failed to parse CPU usage '{}': {}", container.usage.cpu, e))?;
                 total_cpu_cores += cpu_cores;
                 
                 // Parse memory usage (e.g., "123Mi" -> bytes)
-                let memory_bytes = parse_memory_quantity(&container.usage.memory)
+                let memory_bytes = kuboard_parse_bytes(&container.usage.memory)
                     .map_err(|e| anyhow::anyhow!("Failed to parse memory usage '{}': {}", container.usage.memory, e))?;
                 total_memory_bytes += memory_bytes;
             }
@@ -378,10 +391,10 @@ pub async fn kuboard_fetch_pod_metrics_history(
             let mut total_memory_bytes = 0u64;
             
             for container in &current_metrics.containers {
-                let cpu_cores = parse_cpu_quantity(&container.usage.cpu)?;
+                let cpu_cores = kuboard_parse_cpu(&container.usage.cpu)?;
                 total_cpu_cores += cpu_cores;
                 
-                let memory_bytes = parse_memory_quantity(&container.usage.memory)?;
+                let memory_bytes = kuboard_parse_bytes(&container.usage.memory)?;
                 total_memory_bytes += memory_bytes;
             }
             
@@ -429,78 +442,3 @@ pub async fn kuboard_fetch_pod_metrics_history(
         }
     }
 }
-
-// Parse CPU quantity (e.g., "150m", "1.5", "1", "0.5")
-fn parse_cpu_quantity(cpu_str: &str) -> Result<f64> {
-    let cpu_str = cpu_str.trim();
-    
-    if cpu_str.ends_with('m') {
-        // Millicores (e.g., "150m" = 0.15 cores)
-        let millicores_str = cpu_str.trim_end_matches('m');
-        let millicores = millicores_str.parse::<f64>()
-            .map_err(|e| anyhow::anyhow!("Invalid CPU millicores '{}': {}", cpu_str, e))?;
-        Ok(millicores / 1000.0)
-    } else if cpu_str.ends_with('n') {
-        // Nanocores (e.g., "500000000n" = 0.5 cores)
-        let nanocores_str = cpu_str.trim_end_matches('n');
-        let nanocores = nanocores_str.parse::<f64>()
-            .map_err(|e| anyhow::anyhow!("Invalid CPU nanocores '{}': {}", cpu_str, e))?;
-        Ok(nanocores / 1_000_000_000.0)
-    } else if cpu_str.ends_with('u') {
-        // Microcores (e.g., "500000u" = 0.5 cores)
-        let microcores_str = cpu_str.trim_end_matches('u');
-        let microcores = microcores_str.parse::<f64>()
-            .map_err(|e| anyhow::anyhow!("Invalid CPU microcores '{}': {}", cpu_str, e))?;
-        Ok(microcores / 1_000_000.0)
-    } else {
-        // Cores (e.g., "1.5", "1", "0.5")
-        cpu_str.parse::<f64>()
-            .map_err(|e| anyhow::anyhow!("Invalid CPU cores '{}': {}", cpu_str, e))
-    }
-}
-
-// Parse memory quantity (e.g., "123Mi", "1Gi", "1024Ki", "1.5Gi")
-fn parse_memory_quantity(memory_str: &str) -> Result<u64> {
-    let memory_str = memory_str.trim();
-    
-    if memory_str.ends_with("Ki") {
-        let kibibytes_str = memory_str.trim_end_matches("Ki");
-        let kibibytes = kibibytes_str.parse::<f64>()
-            .map_err(|e| anyhow::anyhow!("Invalid memory KiB '{}': {}", memory_str, e))?;
-        Ok((kibibytes * 1024.0) as u64)
-    } else if memory_str.ends_with("Mi") {
-        let mebibytes_str = memory_str.trim_end_matches("Mi");
-        let mebibytes = mebibytes_str.parse::<f64>()
-            .map_err(|e| anyhow::anyhow!("Invalid memory MiB '{}': {}", memory_str, e))?;
-        Ok((mebibytes * 1024.0 * 1024.0) as u64)
-    } else if memory_str.ends_with("Gi") {
-        let gibibytes_str = memory_str.trim_end_matches("Gi");
-        let gibibytes = gibibytes_str.parse::<f64>()
-            .map_err(|e| anyhow::anyhow!("Invalid memory GiB '{}': {}", memory_str, e))?;
-        Ok((gibibytes * 1024.0 * 1024.0 * 1024.0) as u64)
-    } else if memory_str.ends_with("Ti") {
-        let tebibytes_str = memory_str.trim_end_matches("Ti");
-        let tebibytes = tebibytes_str.parse::<f64>()
-            .map_err(|e| anyhow::anyhow!("Invalid memory TiB '{}': {}", memory_str, e))?;
-        Ok((tebibytes * 1024.0 * 1024.0 * 1024.0 * 1024.0) as u64)
-    } else if memory_str.ends_with("K") {
-        let kilobytes_str = memory_str.trim_end_matches("K");
-        let kilobytes = kilobytes_str.parse::<f64>()
-            .map_err(|e| anyhow::anyhow!("Invalid memory K '{}': {}", memory_str, e))?;
-        Ok((kilobytes * 1000.0) as u64)
-    } else if memory_str.ends_with("M") {
-        let megabytes_str = memory_str.trim_end_matches("M");
-        let megabytes = megabytes_str.parse::<f64>()
-            .map_err(|e| anyhow::anyhow!("Invalid memory M '{}': {}", memory_str, e))?;
-        Ok((megabytes * 1000.0 * 1000.0) as u64)
-    } else if memory_str.ends_with("G") {
-        let gigabytes_str = memory_str.trim_end_matches("G");
-        let gigabytes = gigabytes_str.parse::<f64>()
-            .map_err(|e| anyhow::anyhow!("Invalid memory G '{}': {}", memory_str, e))?;
-        Ok((gigabytes * 1000.0 * 1000.0 * 1000.0) as u64)
-    } else {
-        // Assume bytes
-        memory_str.parse::<u64>()
-            .map_err(|e| anyhow::anyhow!("Invalid memory bytes '{}': {}", memory_str, e))
-    }
-}
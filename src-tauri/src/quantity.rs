@@ -0,0 +1,134 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Kubernetes Resource Quantities
+// One parser for every suffix the API server accepts, plus the arithmetic and formatting the
+// metrics, capacity and placement views need
+
+use anyhow::{anyhow, Result};
+
+// Two-letter suffixes first so "Mi" isn't read as "M" followed by junk
+const SUFFIXES: &[(&str, f64)] = &[
+    ("Ki", 1024.0),
+    ("Mi", 1_048_576.0),
+    ("Gi", 1_073_741_824.0),
+    ("Ti", 1_099_511_627_776.0),
+    ("Pi", 1_125_899_906_842_624.0),
+    ("Ei", 1_152_921_504_606_846_976.0),
+    ("k", 1e3),
+    ("M", 1e6),
+    ("G", 1e9),
+    ("T", 1e12),
+    ("P", 1e15),
+    ("E", 1e18),
+];
+
+// Divisors rather than multipliers, so "250m" comes out as exactly 0.25
+const FRACTIONAL_SUFFIXES: &[(&str, f64)] = &[("n", 1e9), ("u", 1e6), ("m", 1e3)];
+
+const BINARY_SUFFIXES: &[(&str, f64)] = &[("Ei", 1_152_921_504_606_846_976.0), ("Pi", 1_125_899_906_842_624.0), ("Ti", 1_099_511_627_776.0), ("Gi", 1_073_741_824.0), ("Mi", 1_048_576.0), ("Ki", 1024.0)];
+const DECIMAL_SUFFIXES: &[(&str, f64)] = &[("E", 1e18), ("P", 1e15), ("T", 1e12), ("G", 1e9), ("M", 1e6), ("k", 1e3)];
+
+/// Parses any quantity ("250m", "1.5Gi", "100M", "2e3", "500000n") into base units
+pub fn kuboard_parse_quantity(quantity: &str) -> Result<f64> {
+    let quantity = quantity.trim();
+    let (number, multiplier, divisor) = SUFFIXES.iter()
+        .find_map(|(suffix, multiplier)| quantity.strip_suffix(suffix).map(|n| (n, *multiplier, 1.0)))
+        .or_else(|| FRACTIONAL_SUFFIXES.iter().find_map(|(suffix, divisor)| quantity.strip_suffix(suffix).map(|n| (n, 1.0, *divisor))))
+        .unwrap_or((quantity, 1.0, 1.0));
+    // A decimal exponent is only valid without a suffix, and "1E" is the exa suffix rather than an exponent
+    let value = number.parse::<f64>()
+        .ok()
+        .filter(|v| v.is_finite() && !number.is_empty() && !number.ends_with(['e', 'E']))
+        .filter(|_| (multiplier == 1.0 && divisor == 1.0) || !number.contains(['e', 'E']))
+        .ok_or_else(|| anyhow!("Invalid quantity '{}'", quantity))?;
+    Ok(value * multiplier / divisor)
+}
+
+/// Parses a CPU quantity into cores
+pub fn kuboard_parse_cpu(quantity: &str) -> Result<f64> {
+    kuboard_parse_quantity(quantity)
+}
+
+/// Parses a memory or storage quantity into bytes, rounding fractional bytes up as the API server does
+pub fn kuboard_parse_bytes(quantity: &str) -> Result<u64> {
+    let value = kuboard_parse_quantity(quantity)?;
+    if value < 0.0 {
+        return Err(anyhow!("Negative quantity '{}' is not a size", quantity));
+    }
+    Ok(value.ceil() as u64)
+}
+
+/// Sums quantities, e.g. the requests of every container in a pod
+pub fn kuboard_sum_quantities<'a>(quantities: impl IntoIterator<Item = &'a str>) -> Result<f64> {
+    quantities.into_iter().map(kuboard_parse_quantity).sum()
+}
+
+/// `used` as a percentage of `total`, 0 when there is no total
+pub fn kuboard_quantity_percent(used: f64, total: f64) -> f64 {
+    if total > 0.0 {
+        used / total * 100.0
+    } else {
+        0.0
+    }
+}
+
+/// Formats cores the way kubectl writes them: whole cores, otherwise millicores
+pub fn kuboard_format_cpu_quantity(cores: f64) -> String {
+    let millicores = (cores * 1000.0).round() as i64;
+    if millicores % 1000 == 0 {
+        (millicores / 1000).to_string()
+    } else {
+        format!("{}m", millicores)
+    }
+}
+
+/// Formats bytes with the largest binary (or decimal) suffix that keeps the number whole
+pub fn kuboard_format_bytes_quantity(bytes: u64, binary: bool) -> String {
+    let suffixes = if binary { BINARY_SUFFIXES } else { DECIMAL_SUFFIXES };
+    suffixes.iter()
+        .find(|(_, multiplier)| bytes > 0 && (bytes as f64) % multiplier == 0.0)
+        .map(|(suffix, multiplier)| format!("{}{}", (bytes as f64 / multiplier) as u64, suffix))
+        .unwrap_or_else(|| bytes.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_quantity_suffixes() {
+        assert_eq!(kuboard_parse_cpu("250m").unwrap(), 0.25);
+        assert_eq!(kuboard_parse_cpu("2").unwrap(), 2.0);
+        assert_eq!(kuboard_parse_cpu("500000000n").unwrap(), 0.5);
+        assert_eq!(kuboard_parse_cpu("1500u").unwrap(), 0.0015);
+        assert_eq!(kuboard_parse_bytes("1.5Gi").unwrap(), 1_610_612_736);
+        assert_eq!(kuboard_parse_bytes("128974848").unwrap(), 128_974_848);
+        assert_eq!(kuboard_parse_bytes("129e6").unwrap(), 129_000_000);
+        assert_eq!(kuboard_parse_bytes("123Mi").unwrap(), 128_974_848);
+        assert_eq!(kuboard_parse_bytes("100M").unwrap(), 100_000_000);
+        assert_eq!(kuboard_parse_bytes("1k").unwrap(), 1000);
+        assert_eq!(kuboard_parse_bytes("2Ei").unwrap(), 2_305_843_009_213_693_952);
+        assert_eq!(kuboard_parse_bytes("1E").unwrap(), 1_000_000_000_000_000_000);
+        assert_eq!(kuboard_parse_bytes("0.5").unwrap(), 1);
+
+        for invalid in ["", "Gi", "10Gb", "1.5K", "1e3Mi", "abc", "-1Gi"] {
+            assert!(kuboard_parse_bytes(invalid).is_err(), "{} should not parse", invalid);
+        }
+    }
+
+    #[test]
+    fn test_quantity_arithmetic_and_formatting() {
+        assert_eq!(kuboard_sum_quantities(["250m", "1", "500m"]).unwrap(), 1.75);
+        assert!(kuboard_sum_quantities(["250m", "bogus"]).is_err());
+        assert_eq!(kuboard_quantity_percent(2.0, 8.0), 25.0);
+        assert_eq!(kuboard_quantity_percent(2.0, 0.0), 0.0);
+
+        assert_eq!(kuboard_format_cpu_quantity(0.25), "250m");
+        assert_eq!(kuboard_format_cpu_quantity(3.0), "3");
+        assert_eq!(kuboard_format_bytes_quantity(1_610_612_736, true), "1536Mi");
+        assert_eq!(kuboard_format_bytes_quantity(16 * 1024 * 1024 * 1024, true), "16Gi");
+        assert_eq!(kuboard_format_bytes_quantity(100_000_000, false), "100M");
+        assert_eq!(kuboard_format_bytes_quantity(1000, true), "1000");
+    }
+}
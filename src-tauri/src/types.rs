@@ -7,6 +7,11 @@
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
+use k8s_openapi::api::core::v1::{Endpoints, Service};
+
+use crate::metrics::MetricsDataPoint;
+use crate::utils::{kuboard_format_cpu_with, kuboard_format_memory_with};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KubeContext {
     pub name: String,
@@ -14,6 +19,8 @@ pub struct KubeContext {
     pub user: String,
     pub namespace: Option<String>,
     pub is_current: bool,
+    pub metadata: ContextMetadata,
+    pub protected: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,6 +29,34 @@ pub struct ContextListResponse {
     pub current_context: Option<String>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ContextEnvironment {
+    Prod,
+    Staging,
+    Dev,
+}
+
+// User-assigned presentation for a context, stored alongside the other per-context settings
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContextMetadata {
+    pub display_name: Option<String>,
+    // Hex colour, e.g. "#d03050"
+    pub color: Option<String>,
+    pub environment: Option<ContextEnvironment>,
+    pub group: Option<String>,
+    // Refuses every mutation against the context
+    #[serde(default)]
+    pub read_only: bool,
+}
+
+impl ContextMetadata {
+    // Mutations against production contexts need a confirmation token and are audited
+    pub fn is_protected(&self) -> bool {
+        self.environment == Some(ContextEnvironment::Prod)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClusterInfo {
     pub name: String,
@@ -62,6 +97,8 @@ pub struct NodeDetails {
     pub kernel_version: Option<String>,
     pub kubelet_version: Option<String>,
     pub container_runtime: Option<String>,
+    pub zone: Option<String>,
+    pub region: Option<String>,
     pub disk_capacity: Option<u64>,
     pub disk_allocatable: Option<u64>,
     pub disk_usage_percent: f64,
@@ -70,4 +107,1977 @@ pub struct NodeDetails {
     pub taints: Vec<String>,
     pub metrics_available: bool,
     pub metrics_error: Option<String>,
+    pub age: Option<String>,
+}
+
+// Command Request Types
+// Command arguments are snake_case; the camelCase aliases are accepted inside the request
+// during the transition period, and the commands still take the older flat arguments
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct PodMetricsRequest {
+    #[serde(alias = "podName")]
+    pub pod_name: String,
+    pub namespace: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct PodMetricsHistoryRequest {
+    #[serde(alias = "podName")]
+    pub pod_name: String,
+    pub namespace: String,
+    #[serde(alias = "durationMinutes")]
+    pub duration_minutes: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct PodEventsRequest {
+    #[serde(alias = "podName")]
+    pub pod_name: String,
+    pub namespace: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct PodLogsRequest {
+    #[serde(alias = "podName")]
+    pub pod_name: String,
+    pub namespace: String,
+    #[serde(alias = "containerName")]
+    pub container_name: Option<String>,
+    #[serde(alias = "tailLines")]
+    pub tail_lines: Option<u32>,
+    pub follow: Option<bool>,
+}
+
+// Metrics Response Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResourceUsage {
+    pub usage: String,
+    pub usage_percent: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeMetricsResponse {
+    pub cpu: ResourceUsage,
+    pub memory: ResourceUsage,
+    pub disk: ResourceUsage,
+    pub timestamp: i64,
+    pub is_mock_data: bool,
+}
+
+impl NodeMetricsResponse {
+    pub fn formatted(data_point: MetricsDataPoint, settings: &FormatSettings) -> Self {
+        Self {
+            cpu: ResourceUsage {
+                usage: kuboard_format_cpu_with(data_point.cpu_usage_cores, settings),
+                usage_percent: data_point.cpu_usage_percent,
+            },
+            memory: ResourceUsage {
+                usage: kuboard_format_memory_with(data_point.memory_usage_bytes, settings),
+                usage_percent: data_point.memory_usage_percent,
+            },
+            disk: ResourceUsage {
+                usage: kuboard_format_memory_with(data_point.disk_usage_bytes, settings),
+                usage_percent: data_point.disk_usage_percent,
+            },
+            timestamp: data_point.timestamp,
+            is_mock_data: data_point.is_mock_data,
+        }
+    }
+}
+
+impl From<MetricsDataPoint> for NodeMetricsResponse {
+    fn from(data_point: MetricsDataPoint) -> Self {
+        Self::formatted(data_point, &FormatSettings::default())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricsAvailability {
+    pub available: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CpuCapacitySummary {
+    pub total_cores: f64,
+    pub used_cores: f64,
+    pub usage_percent: f64,
+    // total_cores and used_cores in the session's formatting
+    pub total_formatted: String,
+    pub used_formatted: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ByteCapacitySummary {
+    pub total_bytes: u64,
+    pub used_bytes: u64,
+    pub usage_percent: f64,
+    pub total_formatted: String,
+    pub used_formatted: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClusterMetricsSummary {
+    pub cpu: CpuCapacitySummary,
+    pub memory: ByteCapacitySummary,
+    pub disk: ByteCapacitySummary,
+    pub nodes_count: usize,
+    pub metrics_available: bool,
+}
+
+// Pod Summary Types
+// `status` is the kubectl STATUS column; `phase` is the raw pod phase
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PodSummary {
+    pub name: String,
+    pub namespace: String,
+    pub status: String,
+    pub phase: String,
+    pub ready_containers: usize,
+    pub total_containers: usize,
+    pub restarts: i32,
+    pub node: Option<String>,
+    pub pod_ip: Option<String>,
+    pub age: Option<String>,
+}
+
+// Workload Summary Types
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WorkloadHealth {
+    Healthy,
+    Progressing,
+    Degraded,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkloadSummary {
+    // Deployment, StatefulSet or DaemonSet
+    pub kind: String,
+    pub name: String,
+    pub namespace: String,
+    pub desired: i32,
+    pub ready: i32,
+    pub up_to_date: i32,
+    pub available: i32,
+    pub health: WorkloadHealth,
+    // Why the workload isn't healthy
+    pub message: Option<String>,
+    pub age: Option<String>,
+}
+
+// Conflict Report Types
+// Returned, serialized, as the error of a replace that kept hitting 409 conflicts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConflictReport {
+    // Always "Conflict", so the frontend can tell a report from a plain error message
+    pub reason: String,
+    pub kind: String,
+    pub name: String,
+    pub namespace: Option<String>,
+    pub attempts: u32,
+    // JSON pointers of the fields someone else changed since the object was first read; for a stale
+    // YAML edit, every field the edit and the latest version disagree on
+    pub changed_fields: Vec<String>,
+    pub latest_resource_version: Option<String>,
+    pub message: String,
+}
+
+// Managed Scale Types
+// Returned, serialized, as the error of scaling an object whose controller would scale it straight back
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManagedScaleWarning {
+    // Always "ManagedByController", so the frontend can tell a warning from a plain error message
+    pub reason: String,
+    pub kind: String,
+    pub name: String,
+    pub namespace: String,
+    pub controller_kind: String,
+    pub controller_name: String,
+    // The command that scales the controller instead, when there is one
+    pub suggested_command: Option<String>,
+    pub message: String,
+}
+
+// Field Manager Types
+// Who owns which fields of an object according to its managedFields
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldManagerEntry {
+    pub manager: String,
+    // "Apply" for server-side apply, "Update" for everything else
+    pub operation: String,
+    pub api_version: Option<String>,
+    pub subresource: Option<String>,
+    pub time: Option<String>,
+    pub fields: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SharedField {
+    pub path: String,
+    pub managers: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldOwnership {
+    pub kind: String,
+    pub name: String,
+    pub namespace: Option<String>,
+    pub managers: Vec<FieldManagerEntry>,
+    // Fields claimed by more than one manager, the usual reason an edit gets reverted
+    pub shared_fields: Vec<SharedField>,
+}
+
+// Eviction Order Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvictionCandidate {
+    // 1 is evicted first
+    pub rank: usize,
+    pub name: String,
+    pub namespace: String,
+    pub qos_class: String,
+    pub priority: i32,
+    pub priority_class: Option<String>,
+    pub memory_request_bytes: u64,
+    // None when the metrics API has no sample for the pod
+    pub memory_usage_bytes: Option<u64>,
+    pub exceeds_requests: bool,
+    // False for static, mirror and system-critical pods, which the kubelet doesn't evict
+    pub evictable: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeEvictionReport {
+    pub node: String,
+    // Without metrics, pods are ranked by priority and memory request only
+    pub metrics_available: bool,
+    pub pods: Vec<EvictionCandidate>,
+}
+
+// Ephemeral Storage Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EphemeralStorageUsage {
+    pub name: String,
+    pub namespace: String,
+    pub node: Option<String>,
+    pub request_bytes: u64,
+    // Sum of the container limits; None when no container sets one
+    pub limit_bytes: Option<u64>,
+    // None when the kubelet stats couldn't be read
+    pub used_bytes: Option<u64>,
+    pub percent_of_limit: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EphemeralStorageReport {
+    pub threshold_percent: f64,
+    pub pods: Vec<EphemeralStorageUsage>,
+    pub unreachable_nodes: Vec<String>,
+}
+
+// Pod Describe Types
+// Field names follow the camelCase keys the describe view has always received
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PodDescribe {
+    pub name: String,
+    pub namespace: String,
+    pub labels: BTreeMap<String, String>,
+    pub annotations: BTreeMap<String, String>,
+    pub status: PodDescribeStatus,
+    pub conditions: Vec<PodDescribeCondition>,
+    pub containers: Vec<PodDescribeContainer>,
+    pub volumes: Vec<PodDescribeVolume>,
+    pub tolerations: Vec<PodDescribeToleration>,
+    pub events: Vec<PodDescribeEvent>,
+    pub metadata: PodDescribeMetadata,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PodDescribeStatus {
+    pub phase: String,
+    #[serde(rename = "podIP")]
+    pub pod_ip: String,
+    #[serde(rename = "hostIP")]
+    pub host_ip: String,
+    pub node_name: String,
+    pub qos_class: String,
+    pub start_time: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PodDescribeCondition {
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub status: String,
+    pub reason: String,
+    pub message: String,
+    pub last_transition_time: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PodDescribeContainer {
+    pub name: String,
+    pub image: Option<String>,
+    pub image_pull_policy: String,
+    pub resources: Option<PodDescribeResources>,
+    pub ports: Vec<PodDescribePort>,
+    pub env: Vec<PodDescribeEnvVar>,
+    pub status: PodDescribeContainerStatus,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PodDescribeResources {
+    pub requests: BTreeMap<String, String>,
+    pub limits: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PodDescribePort {
+    pub name: String,
+    pub container_port: i32,
+    pub protocol: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PodDescribeEnvVar {
+    pub name: String,
+    pub value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value_from: Option<PodDescribeEnvSource>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PodDescribeEnvSource {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field_ref: Option<PodDescribeFieldRef>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PodDescribeFieldRef {
+    pub field_path: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PodDescribeContainerStatus {
+    pub ready: bool,
+    pub restart_count: i32,
+    pub state: PodDescribeContainerState,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PodDescribeContainerState {
+    pub running: Option<PodDescribeRunningState>,
+    pub waiting: Option<PodDescribeWaitingState>,
+    pub terminated: Option<PodDescribeTerminatedState>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PodDescribeRunningState {
+    pub started_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PodDescribeWaitingState {
+    pub reason: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PodDescribeTerminatedState {
+    pub reason: String,
+    pub exit_code: i32,
+    pub started_at: String,
+    pub finished_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PodDescribeVolume {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PodDescribeToleration {
+    pub key: String,
+    pub operator: String,
+    pub value: String,
+    pub effect: String,
+    pub toleration_seconds: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PodDescribeEvent {
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub reason: String,
+    pub message: String,
+    pub count: Option<i32>,
+    pub first_timestamp: String,
+    pub last_timestamp: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PodDescribeMetadata {
+    pub uid: String,
+    pub resource_version: String,
+    pub creation_timestamp: String,
+    pub age: String,
+    pub generation: i64,
+}
+
+// Capability Discovery Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackendFeatures {
+    pub metrics_available: bool,
+    // Helm release management is not implemented yet, so this is always false
+    pub helm_support: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackendCapabilities {
+    pub version: String,
+    pub api_version: u32,
+    pub commands: Vec<String>,
+    pub features: BackendFeatures,
+    pub profile: KuboardProfile,
+}
+
+// Service Detail Types
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ServiceRouting {
+    // Endpoints are maintained by the control plane from the pod selector
+    Selector,
+    // Selector-less service backed by hand-managed Endpoints
+    ManualEndpoints,
+    // DNS alias only; the cluster never allocates endpoints
+    ExternalName,
+    // Selector-less service with no Endpoints object yet
+    Unrouted,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceDetails {
+    pub service: Service,
+    pub service_type: String,
+    pub headless: bool,
+    pub external_name: Option<String>,
+    pub routing: ServiceRouting,
+    pub endpoints: Option<Endpoints>,
+}
+
+// CronJob History Types
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CronJobRunState {
+    Active,
+    Succeeded,
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CronJobRun {
+    pub name: String,
+    pub state: CronJobRunState,
+    pub start_time: Option<String>,
+    pub completion_time: Option<String>,
+    pub duration_seconds: Option<i64>,
+    pub duration: Option<String>,
+    pub age: Option<String>,
+    pub failure_reason: Option<String>,
+    pub failure_message: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CronJobRunSummary {
+    pub schedule: String,
+    pub suspended: bool,
+    pub last_schedule_time: Option<String>,
+    pub last_successful_time: Option<String>,
+    pub successful_jobs_history_limit: i32,
+    pub failed_jobs_history_limit: i32,
+    pub active: Vec<CronJobRun>,
+    pub last_successful: Option<CronJobRun>,
+    pub last_failed: Option<CronJobRun>,
+    pub runs: Vec<CronJobRun>,
+}
+
+// Job Progress Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobProgress {
+    pub name: String,
+    pub namespace: String,
+    pub state: CronJobRunState,
+    pub suspended: bool,
+    // None for work-queue jobs, which finish once any pod succeeds
+    pub completions: Option<i32>,
+    pub parallelism: i32,
+    pub succeeded: i32,
+    pub failed: i32,
+    pub active: i32,
+    pub ready: i32,
+    pub backoff_limit: i32,
+    pub percent_complete: Option<f64>,
+    pub start_time: Option<String>,
+    pub elapsed_seconds: Option<i64>,
+    pub elapsed: Option<String>,
+    // Extrapolated from the completion rate so far; None until a pod has succeeded
+    pub estimated_remaining_seconds: Option<i64>,
+    pub estimated_remaining: Option<String>,
+    pub estimated_completion: Option<String>,
+}
+
+// Rollout History Types
+// One Deployment revision, reconstructed from the ReplicaSet that carries it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeploymentRevision {
+    pub revision: i64,
+    pub replicaset: String,
+    pub change_cause: Option<String>,
+    pub images: Vec<String>,
+    pub replicas: i32,
+    pub created: Option<String>,
+    pub age: Option<String>,
+    // The revision the deployment runs now, the highest one
+    pub current: bool,
+}
+
+// Rollout Status Types
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RolloutState {
+    Complete,
+    Progressing,
+    Paused,
+    // Past its progress deadline without finishing
+    Stuck,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeploymentRolloutStatus {
+    pub name: String,
+    pub namespace: String,
+    pub state: RolloutState,
+    // As kubectl rollout status words it
+    pub message: String,
+    pub revision: Option<i64>,
+    pub paused: bool,
+    pub desired: i32,
+    pub updated: i32,
+    pub ready: i32,
+    pub available: i32,
+    pub unavailable: i32,
+    // Pods of older revisions still running
+    pub old_replicas: i32,
+    pub progress_deadline_seconds: i32,
+    pub progressing_reason: Option<String>,
+    pub progressing_message: Option<String>,
+    pub last_progress: Option<String>,
+}
+
+// Pod Template Diff Types
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TemplateChangeCategory {
+    Image,
+    Env,
+    Resources,
+    Other,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemplateChange {
+    pub category: TemplateChangeCategory,
+    // The container the change is in, for container fields
+    pub container: Option<String>,
+    // Containers, env vars and volumes are keyed by name in the path, e.g. /spec/containers/web/env/LOG_LEVEL/value
+    #[serde(flatten)]
+    pub diff: FieldDiff,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemplateDiff {
+    pub kind: String,
+    pub name: String,
+    pub namespace: String,
+    pub from_revision: i64,
+    pub to_revision: i64,
+    pub changes: Vec<TemplateChange>,
+}
+
+// Event Trend Types
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EventTrendDirection {
+    Rising,
+    Falling,
+    Steady,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventTrendBucket {
+    pub start: String,
+    pub total: i64,
+    pub warnings: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventReasonCount {
+    pub reason: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub count: i64,
+    pub namespaces: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NamespaceEventTrend {
+    pub namespace: String,
+    pub total: i64,
+    pub warnings: i64,
+    pub per_hour: f64,
+    // Warnings in the later half of the window against the earlier half
+    pub trend: EventTrendDirection,
+    // Warning occurrences per bucket, aligned with EventTrends.buckets
+    pub warning_buckets: Vec<i64>,
+    pub top_reasons: Vec<EventReasonCount>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventTrends {
+    pub since: String,
+    pub window_minutes: i64,
+    pub bucket_minutes: i64,
+    pub total: i64,
+    pub warnings: i64,
+    pub trend: EventTrendDirection,
+    pub buckets: Vec<EventTrendBucket>,
+    // Noisiest first: most warnings, then most events
+    pub namespaces: Vec<NamespaceEventTrend>,
+    pub reasons: Vec<EventReasonCount>,
+}
+
+// Warning Feed Types
+// Which Warning events become toasts; an empty list lets everything through. Namespaces may be
+// prefixes ending in "*" as in NamespaceFilter
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WarningFeedFilter {
+    #[serde(default)]
+    pub reasons: Vec<String>,
+    #[serde(default)]
+    pub namespaces: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WarningToast {
+    pub namespace: String,
+    pub reason: String,
+    pub message: String,
+    pub involved_kind: String,
+    pub involved_name: String,
+    // Occurrences so far, counting the one that raised this toast
+    pub count: i32,
+    pub first_seen: Option<String>,
+    pub last_seen: Option<String>,
+    pub source: Option<String>,
+}
+
+// Namespace Overview Types
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NamespaceWorkloadCounts {
+    pub deployments: usize,
+    pub statefulsets: usize,
+    pub daemonsets: usize,
+    pub jobs: usize,
+    pub cronjobs: usize,
+    pub services: usize,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NamespacePodHealth {
+    pub total: usize,
+    pub running: usize,
+    pub pending: usize,
+    pub succeeded: usize,
+    pub failed: usize,
+    // Running with at least one container not ready
+    pub not_ready: usize,
+    pub restarts: i32,
+    // Pods that are neither ready nor completed, most restarts first
+    pub unhealthy: Vec<PodSummary>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuotaUsage {
+    pub quota: String,
+    pub resource: String,
+    pub hard: String,
+    pub used: String,
+    // None when either quantity can't be parsed or the hard limit is zero
+    pub percent: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResourceConsumer {
+    pub pod: String,
+    pub cpu_millicores: u64,
+    pub memory_bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NamespaceDetail {
+    pub name: String,
+    pub phase: Option<String>,
+    pub labels: std::collections::BTreeMap<String, String>,
+    pub age: Option<String>,
+    pub workloads: NamespaceWorkloadCounts,
+    // Deployments, StatefulSets and DaemonSets that aren't Healthy
+    pub degraded_workloads: Vec<WorkloadSummary>,
+    pub pods: NamespacePodHealth,
+    pub quotas: Vec<QuotaUsage>,
+    // Newest first
+    pub recent_warnings: Vec<WarningToast>,
+    pub top_cpu: Vec<ResourceConsumer>,
+    pub top_memory: Vec<ResourceConsumer>,
+    // False without metrics-server, leaving the top consumers empty
+    pub metrics_available: bool,
+}
+
+// ConfigMap Reload Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigMapConsumer {
+    // Deployment, StatefulSet or DaemonSet
+    pub kind: String,
+    pub name: String,
+    // How the pod template reads the ConfigMap: "env", "envFrom" and/or "volume"
+    pub uses: Vec<String>,
+    pub restarted: bool,
+    // Why the restart failed; the ConfigMap update itself still stands
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigMapKeyUpdate {
+    pub name: String,
+    pub namespace: String,
+    pub key: String,
+    pub previous_value: Option<String>,
+    // False when the key already held the value; nothing is restarted then
+    pub changed: bool,
+    pub dry_run: bool,
+    pub consumers: Vec<ConfigMapConsumer>,
+}
+
+// Pod Delete Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PodDeleteConfirmation {
+    pub name: String,
+    pub namespace: String,
+    pub termination_grace_period_seconds: i64,
+    pub terminating: bool,
+    pub deletion_timestamp: Option<String>,
+    pub finalizers: Vec<String>,
+}
+
+// kubectl Command Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum KubectlAction {
+    Scale { kind: String, name: String, namespace: String, replicas: i32 },
+    Restart { kind: String, name: String, namespace: String },
+    Rollback { name: String, namespace: String, revision: Option<i64> },
+    Delete {
+        kind: String,
+        name: String,
+        namespace: Option<String>,
+        grace_period_seconds: Option<u32>,
+        #[serde(default)]
+        force: bool,
+    },
+    Cordon { node: String },
+    Uncordon { node: String },
+    Drain {
+        node: String,
+        #[serde(default)]
+        ignore_daemonsets: bool,
+        #[serde(default)]
+        delete_emptydir_data: bool,
+    },
+    SuspendCronJob { name: String, namespace: String, suspend: bool },
+    TriggerCronJob { name: String, namespace: String, job_name: String },
+    Logs { pod_name: String, namespace: String, container: Option<String>, tail_lines: Option<i64> },
+    Exec { pod_name: String, namespace: String, container: Option<String>, command: Vec<String> },
+}
+
+// Node Shell Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeShellSession {
+    pub session_id: String,
+    pub node_name: String,
+    // The privileged debug pod the shell runs in, deleted when the session closes
+    pub pod_name: String,
+    pub namespace: String,
+    pub container_name: Option<String>,
+}
+
+// Exec Recording Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecRecordingInfo {
+    pub id: String,
+    pub pod_name: String,
+    pub namespace: String,
+    pub container_name: Option<String>,
+    pub started_at: String,
+    pub duration_seconds: f64,
+    pub size_bytes: u64,
+}
+
+// Saved Command Types
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SavedCommandKind {
+    Exec,
+    LogQuery,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedCommand {
+    pub id: String,
+    pub context: String,
+    pub name: String,
+    pub kind: SavedCommandKind,
+    // Exec: the argv run in the container
+    #[serde(default)]
+    pub command: Vec<String>,
+    // LogQuery: only lines containing this text are returned
+    pub log_filter: Option<String>,
+    pub tail_lines: Option<u32>,
+    pub created_at: String,
+    pub last_run_at: Option<String>,
+    pub run_count: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct SavedCommandRequest {
+    pub name: String,
+    pub kind: SavedCommandKind,
+    pub command: Option<Vec<String>>,
+    #[serde(alias = "logFilter")]
+    pub log_filter: Option<String>,
+    #[serde(alias = "tailLines")]
+    pub tail_lines: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedCommandRun {
+    pub command: SavedCommand,
+    // Exec stdout or the filtered log lines
+    pub output: String,
+}
+
+// Pod File Browser Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PodFileEntry {
+    pub name: String,
+    pub file_type: String,
+    pub size: u64,
+    pub permissions: String,
+    pub modified: String,
+    pub link_target: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PodFileStat {
+    pub path: String,
+    pub file_type: String,
+    pub size: u64,
+    pub permissions: String,
+    pub modified_unix: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PodFileContent {
+    pub path: String,
+    pub content: String,
+    pub size_bytes: u64,
+    pub truncated: bool,
+    pub binary: bool,
+}
+
+// Workload Suspend Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkloadSuspendState {
+    pub kind: String,
+    pub name: String,
+    pub namespace: String,
+    pub replicas: i32,
+    // Replica count to restore on resume; None once the workload is running again
+    pub suspended_replicas: Option<i32>,
+}
+
+// Namespace Clone Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClonedResource {
+    pub kind: String,
+    pub name: String,
+    pub success: bool,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NamespaceCloneResult {
+    pub source: String,
+    pub target: String,
+    pub resources: Vec<ClonedResource>,
+}
+
+// Manifest Template Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemplateVariable {
+    pub name: String,
+    pub description: String,
+    pub default: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestTemplate {
+    pub id: String,
+    pub name: String,
+    pub kind: String,
+    pub description: String,
+    pub variables: Vec<TemplateVariable>,
+    // JSON manifest with {{variable}} placeholders
+    pub body: String,
+    pub builtin: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestTemplateRequest {
+    pub name: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub variables: Vec<TemplateVariable>,
+    pub body: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppliedManifest {
+    pub kind: String,
+    pub name: String,
+    pub namespace: Option<String>,
+    pub outcome: ApplyOutcome,
+}
+
+// What kubectl apply prints after each object
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ApplyOutcome {
+    Created,
+    Configured,
+    Unchanged,
+}
+
+// Manifest Format Types
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ManifestFormat {
+    #[default]
+    Yaml,
+    Json,
+}
+
+// Manifest Validation Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestDiagnostic {
+    // JSON path into the manifest, e.g. ".spec.template.spec.containers[0].image"
+    pub path: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestValidation {
+    pub valid: bool,
+    pub api_version: String,
+    pub kind: String,
+    pub diagnostics: Vec<ManifestDiagnostic>,
+}
+
+// Pod Security Admission Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PodSecurityLabels {
+    pub namespace: String,
+    pub enforce: Option<String>,
+    pub enforce_version: Option<String>,
+    pub audit: Option<String>,
+    pub audit_version: Option<String>,
+    pub warn: Option<String>,
+    pub warn_version: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PodSecurityViolation {
+    pub pod_name: String,
+    pub owner_kind: Option<String>,
+    pub owner_name: Option<String>,
+    pub violations: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PodSecurityCheck {
+    pub namespace: String,
+    pub level: String,
+    pub pods_checked: usize,
+    pub violations: Vec<PodSecurityViolation>,
+}
+
+// ServiceAccount Usage Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceAccountWorkload {
+    pub kind: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceAccountRule {
+    pub verbs: Vec<String>,
+    pub api_groups: Vec<String>,
+    pub resources: Vec<String>,
+    pub resource_names: Vec<String>,
+    pub non_resource_urls: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceAccountGrant {
+    pub binding_kind: String,
+    pub binding_name: String,
+    pub role_kind: String,
+    pub role_name: String,
+    pub cluster_wide: bool,
+    pub rules: Vec<ServiceAccountRule>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceAccountUsage {
+    pub name: String,
+    pub namespace: String,
+    // False when workloads reference an account that doesn't exist
+    pub exists: bool,
+    pub automount_token: Option<bool>,
+    pub workloads: Vec<ServiceAccountWorkload>,
+    pub grants: Vec<ServiceAccountGrant>,
+}
+
+// RBAC Who-Can Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WhoCanSubject {
+    pub subject_kind: String,
+    pub subject_name: String,
+    pub subject_namespace: Option<String>,
+    pub binding_kind: String,
+    pub binding_name: String,
+    pub role_kind: String,
+    pub role_name: String,
+    // Empty when the grant covers every object of the resource
+    pub resource_names: Vec<String>,
+}
+
+// Best-Practices Scanner Types
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FindingSeverity {
+    Critical,
+    Warning,
+    Info,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BestPracticeFinding {
+    pub check: String,
+    pub severity: FindingSeverity,
+    pub kind: String,
+    pub name: String,
+    pub container: Option<String>,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BestPracticeReport {
+    pub namespace: String,
+    // 0-100, weighted by severity
+    pub score: u32,
+    pub workloads_scanned: usize,
+    pub checks_run: usize,
+    pub findings: Vec<BestPracticeFinding>,
+}
+
+// Deprecated API Scanner Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeprecatedApiFinding {
+    pub kind: String,
+    pub name: String,
+    pub namespace: Option<String>,
+    pub api_version: String,
+    pub replacement_api_version: String,
+    pub deprecated_in: String,
+    pub removed_in: String,
+    // Removed in the cluster's current version; the writers will fail on their next update
+    pub already_removed: bool,
+    // Field managers still writing through the deprecated version
+    pub writers: Vec<String>,
+    pub helm_release: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeprecatedApiReport {
+    pub server_version: String,
+    pub target_version: String,
+    pub findings: Vec<DeprecatedApiFinding>,
+}
+
+// Upgrade Readiness Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpgradeIssue {
+    // version-skew, deprecated-api, pdb-coverage or single-replica
+    pub category: String,
+    pub severity: FindingSeverity,
+    pub kind: String,
+    pub name: String,
+    pub namespace: Option<String>,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpgradeReadinessReport {
+    pub server_version: String,
+    pub target_version: String,
+    // No critical issues
+    pub ready: bool,
+    pub issues: Vec<UpgradeIssue>,
+}
+
+// Workload Resilience Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkloadResilience {
+    pub kind: String,
+    pub name: String,
+    pub namespace: String,
+    pub replicas: i32,
+    pub has_pdb: bool,
+    pub has_topology_spread: bool,
+    pub has_anti_affinity: bool,
+    // Nodes the workload's pods are currently scheduled on
+    pub nodes: Vec<String>,
+    pub gaps: Vec<String>,
+}
+
+// Pod Placement Heatmap Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlacementNode {
+    pub name: String,
+    pub zone: Option<String>,
+    pub region: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlacementCell {
+    pub node: String,
+    pub pod_count: u32,
+    pub cpu_requests_millicores: u64,
+    pub memory_requests_bytes: u64,
+    // None when metrics-server is unavailable
+    pub cpu_usage_millicores: Option<u64>,
+    pub memory_usage_bytes: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlacementRow {
+    pub namespace: String,
+    // Set when grouping by workload
+    pub workload_kind: Option<String>,
+    pub workload_name: Option<String>,
+    pub total_pods: u32,
+    pub pods_by_zone: BTreeMap<String, u32>,
+    // One cell per node, in the order of PlacementHeatmap.nodes
+    pub cells: Vec<PlacementCell>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlacementHeatmap {
+    pub nodes: Vec<PlacementNode>,
+    pub rows: Vec<PlacementRow>,
+    pub metrics_available: bool,
+}
+
+// Topology Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopologyZone {
+    pub region: Option<String>,
+    pub zone: Option<String>,
+    pub nodes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ZoneConcentration {
+    pub namespace: String,
+    pub workload_kind: String,
+    pub workload_name: String,
+    pub pods: usize,
+    pub zone: String,
+    pub region: Option<String>,
+}
+
+// Service Endpoint Probe Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EndpointProbeResult {
+    pub ip: String,
+    pub port: u16,
+    pub port_name: Option<String>,
+    pub pod_name: Option<String>,
+    pub node_name: Option<String>,
+    // Listed under the Endpoints' ready addresses
+    pub ready: bool,
+    pub reachable: bool,
+    // HTTP probes only
+    pub status_code: Option<u16>,
+    pub latency_ms: Option<u64>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceProbeReport {
+    pub service: String,
+    pub namespace: String,
+    pub mode: String,
+    pub reachable: usize,
+    pub endpoints: Vec<EndpointProbeResult>,
+}
+
+// HTTP Request Tester Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HttpProbeResponse {
+    // Pod and container port the request was forwarded to
+    pub pod: String,
+    pub port: u16,
+    pub status: u16,
+    pub reason: String,
+    pub headers: BTreeMap<String, String>,
+    pub latency_ms: u64,
+    pub body_snippet: String,
+    pub body_truncated: bool,
+}
+
+// Cluster DNS Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DnsServerBlock {
+    pub zones: Vec<String>,
+    pub plugins: Vec<String>,
+    pub forwarders: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DnsPodStatus {
+    pub name: String,
+    pub phase: String,
+    pub ready: bool,
+    pub restarts: i32,
+    pub node: Option<String>,
+    pub age: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DnsLogLine {
+    pub pod: String,
+    pub line: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClusterDnsSummary {
+    // coredns, kube-dns or unknown
+    pub provider: String,
+    pub corefile: Option<String>,
+    pub server_blocks: Vec<DnsServerBlock>,
+    pub upstream_forwarders: Vec<String>,
+    pub stub_domains: BTreeMap<String, Vec<String>>,
+    pub service_ip: Option<String>,
+    pub ready_endpoints: usize,
+    pub not_ready_endpoints: usize,
+    pub pods: Vec<DnsPodStatus>,
+    pub recent_errors: Vec<DnsLogLine>,
+}
+
+// Ingress Route Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IngressControllerInfo {
+    // nginx, traefik, haproxy or other
+    pub kind: String,
+    pub controller: Option<String>,
+    pub ingress_classes: Vec<String>,
+    pub default_class: bool,
+    pub namespace: Option<String>,
+    pub pods: usize,
+    pub ready_pods: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IngressRoute {
+    pub host: String,
+    pub path: String,
+    pub path_type: String,
+    pub service: String,
+    pub service_port: Option<String>,
+    pub ingress: String,
+    pub namespace: String,
+    pub ingress_class: Option<String>,
+    pub tls: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IngressRouteTable {
+    pub controllers: Vec<IngressControllerInfo>,
+    pub routes: Vec<IngressRoute>,
+}
+
+// TLS Secret Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TlsCertificateInfo {
+    // Index in the tls.crt bundle; 0 is the leaf
+    pub position: usize,
+    pub subject: String,
+    pub issuer: String,
+    pub subject_alt_names: Vec<String>,
+    pub serial_number: String,
+    pub not_before: String,
+    pub not_after: String,
+    pub days_remaining: i64,
+    pub is_ca: bool,
+    pub expired: bool,
+    pub expiring: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TlsSecretInfo {
+    pub name: String,
+    pub namespace: String,
+    pub certificates: Vec<TlsCertificateInfo>,
+    pub expired: bool,
+    pub expiring: bool,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TlsInspectionReport {
+    pub expiry_window_days: i64,
+    pub secrets: Vec<TlsSecretInfo>,
+    pub expired: usize,
+    pub expiring: usize,
+}
+
+// Namespace Access Types
+// Namespace patterns are exact names or prefixes ending in "*"
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NamespaceFilter {
+    #[serde(default)]
+    pub allow: Vec<String>,
+    #[serde(default)]
+    pub deny: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NamespaceAccess {
+    // cluster, allow-list or rules-review
+    pub source: String,
+    pub namespaces: Vec<String>,
+}
+
+// Access Profile Types
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProfileKind {
+    #[default]
+    Admin,
+    Developer,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct KuboardProfile {
+    pub kind: ProfileKind,
+    // Namespaces a developer profile is limited to; ignored for admins
+    #[serde(default)]
+    pub namespaces: Vec<String>,
+}
+
+// API Rate Limit Types
+// qps of 0 disables rate limiting for the context
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateLimitSettings {
+    pub qps: f64,
+    pub burst: u32,
+}
+
+impl Default for RateLimitSettings {
+    fn default() -> Self {
+        Self { qps: 20.0, burst: 40 }
+    }
+}
+
+// Shared Watch Types
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WatchKind {
+    Pod,
+    Deployment,
+    StatefulSet,
+    DaemonSet,
+    ReplicaSet,
+    Service,
+    CronJob,
+}
+
+// Incremental List Types
+// Objects are namespace/name keyed; `full` means `upserted` replaces the caller's list
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListDelta {
+    pub kind: WatchKind,
+    pub revision: String,
+    pub full: bool,
+    pub upserted: Vec<serde_json::Value>,
+    pub deleted: Vec<String>,
+}
+
+// Compressed Payload Types
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PayloadEncoding {
+    Identity,
+    Gzip,
+    Zstd,
+}
+
+// `data` is the JSON text for identity, otherwise the base64 of the compressed JSON
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompressedPayload {
+    pub encoding: PayloadEncoding,
+    pub original_size: usize,
+    pub data: String,
+}
+
+// Connection Keepalive Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectionStatus {
+    pub checked_at: String,
+    pub latency_ms: u64,
+    pub server_version: Option<String>,
+    pub error: Option<String>,
+}
+
+// Operation Progress Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OperationProgress {
+    pub operation_id: String,
+    pub operation: String,
+    pub stage: String,
+    pub percent: u8,
+    pub message: Option<String>,
+}
+
+// Resource Table Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResourceTableRow {
+    pub name: String,
+    pub namespace: Option<String>,
+    // One per column, formatted as kubectl prints them; None where kubectl shows <none>
+    pub cells: Vec<Option<String>>,
+}
+
+// The columns `kubectl get` shows for a kind: Name followed by the CRD's additionalPrinterColumns,
+// or Name and Age when it has none
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResourceTable {
+    pub kind: String,
+    pub columns: Vec<k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceColumnDefinition>,
+    pub rows: Vec<ResourceTableRow>,
+    // Columns left empty because their JSONPath isn't supported
+    pub warnings: Vec<String>,
+}
+
+// Undo Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrashEntry {
+    pub operation_id: String,
+    pub context: String,
+    pub kind: String,
+    pub name: String,
+    pub namespace: String,
+    pub deleted_at: String,
+    pub expires_at: String,
+    pub age: String,
+    pub manifest: serde_json::Value,
+}
+
+// Batch Job Wizard Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobCreateRequest {
+    pub name: String,
+    pub namespace: String,
+    pub image: String,
+    // Overrides the image entrypoint when not empty
+    #[serde(default)]
+    pub command: Vec<String>,
+    #[serde(default)]
+    pub env: BTreeMap<String, String>,
+    // "OnFailure" (default) or "Never"
+    pub restart_policy: Option<String>,
+    pub backoff_limit: Option<i32>,
+    pub active_deadline_seconds: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CronJobCreateRequest {
+    #[serde(flatten)]
+    pub job: JobCreateRequest,
+    pub schedule: String,
+    // IANA name such as "Europe/Berlin"; the controller's local time when unset
+    pub time_zone: Option<String>,
+    // "Allow" (default), "Forbid" or "Replace"
+    pub concurrency_policy: Option<String>,
+    pub suspend: Option<bool>,
+}
+
+// PVC Creation Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PvcCreateResult {
+    pub claim: k8s_openapi::api::core::v1::PersistentVolumeClaim,
+    pub storage_class: String,
+    pub bound: bool,
+    // WaitForFirstConsumer classes stay Pending until a pod using the claim is scheduled
+    pub waits_for_consumer: bool,
+    pub message: Option<String>,
+}
+
+// PVC Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PvcMount {
+    pub pod: String,
+    pub node: Option<String>,
+    pub phase: Option<String>,
+    pub volume: String,
+    pub read_only: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PvcSummary {
+    pub name: String,
+    pub namespace: String,
+    pub phase: Option<String>,
+    pub volume_name: Option<String>,
+    pub storage_class: Option<String>,
+    // What the bound volume provides, which can exceed the request
+    pub capacity: Option<String>,
+    pub requested: Option<String>,
+    pub access_modes: Vec<String>,
+    pub volume_mode: Option<String>,
+    // Taken from the bound PersistentVolume
+    pub reclaim_policy: Option<String>,
+    pub mounted_by: Vec<PvcMount>,
+    pub age: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PvcDetail {
+    pub summary: PvcSummary,
+    pub claim: k8s_openapi::api::core::v1::PersistentVolumeClaim,
+    pub volume: Option<k8s_openapi::api::core::v1::PersistentVolume>,
+}
+
+// Persistent Volume Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PvSummary {
+    pub name: String,
+    // Available, Bound, Released or Failed
+    pub phase: Option<String>,
+    pub capacity: Option<String>,
+    pub access_modes: Vec<String>,
+    pub reclaim_policy: Option<String>,
+    pub storage_class: Option<String>,
+    pub volume_mode: Option<String>,
+    // The claim the volume is reserved for, as namespace/name; kept after a Released volume's claim is gone
+    pub claim: Option<String>,
+    // The volume plugin, e.g. "csi:ebs.csi.aws.com", "hostPath" or "nfs"
+    pub source: Option<String>,
+    pub reason: Option<String>,
+    pub message: Option<String>,
+    pub age: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PvDetail {
+    pub summary: PvSummary,
+    pub volume: k8s_openapi::api::core::v1::PersistentVolume,
+}
+
+// A volume's PVC ↔ PV ↔ Pod chain; `issues` says where it breaks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VolumeBinding {
+    pub volume: PvSummary,
+    // With the pods mounting it
+    pub claim: Option<PvcSummary>,
+    pub bound: bool,
+    pub issues: Vec<String>,
+}
+
+// CSI Health Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VolumeAttachmentInfo {
+    pub name: String,
+    pub driver: String,
+    pub node: String,
+    // None for inline volumes migrated from in-tree plugins
+    pub persistent_volume: Option<String>,
+    pub attached: bool,
+    pub attach_error: Option<String>,
+    pub detach_error: Option<String>,
+    // Set while the volume is being detached
+    pub deleting: bool,
+    pub created: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CsiDriverNode {
+    pub node: String,
+    // The driver's own ID for the node, e.g. the cloud instance ID
+    pub node_id: String,
+    // How many of the driver's volumes the node can take, when the driver reports a limit
+    pub allocatable_count: Option<i32>,
+    pub attached_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CsiDriverHealth {
+    pub name: String,
+    // False when nodes or attachments reference a driver without a CSIDriver object
+    pub registered: bool,
+    pub attach_required: bool,
+    // "Persistent" and/or "Ephemeral"
+    pub volume_lifecycle_modes: Vec<String>,
+    // Nodes where the driver's node plugin has registered
+    pub nodes: Vec<CsiDriverNode>,
+    pub attachments: usize,
+    // Attachments carrying an attach or detach error
+    pub failing_attachments: usize,
+}
+
+// A pod volume stuck attaching or mounting, traced to its driver, node and attachment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VolumeAttachIssue {
+    pub pod: String,
+    pub namespace: String,
+    pub node: Option<String>,
+    // FailedAttachVolume, FailedMount or FailedMapVolume
+    pub reason: String,
+    pub message: String,
+    pub count: i32,
+    pub last_seen: Option<String>,
+    pub volume: String,
+    pub claim: Option<String>,
+    pub persistent_volume: Option<String>,
+    pub driver: Option<String>,
+    // The attachment of this volume to the pod's node, if one exists
+    pub attachment: Option<VolumeAttachmentInfo>,
+    pub diagnosis: String,
+}
+
+// Manifest Diff Types
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DiffOperation {
+    Added,
+    Removed,
+    Changed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldDiff {
+    // JSON pointer such as /spec/template/spec/containers/0/image
+    pub path: String,
+    pub operation: DiffOperation,
+    // The live value, None when added
+    pub before: Option<serde_json::Value>,
+    // The manifest's value, None when removed
+    pub after: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestDiff {
+    pub kind: String,
+    pub name: String,
+    pub namespace: Option<String>,
+    // False when applying the manifest would create the object
+    pub exists: bool,
+    pub changes: Vec<FieldDiff>,
+}
+
+// StatefulSet Volume Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatefulSetClaim {
+    // The volumeClaimTemplate the claim is stamped from
+    pub template: String,
+    pub name: String,
+    // False for ordinals whose pod hasn't created its claim yet
+    pub exists: bool,
+    pub phase: Option<String>,
+    // Provisioned capacity once bound, the requested size before
+    pub size: Option<String>,
+    pub storage_class: Option<String>,
+    pub volume_name: Option<String>,
+    // The bound volume's reclaim policy: "Delete" means deleting the claim deletes the data
+    pub reclaim_policy: Option<String>,
+    // Left behind by a scale-down: beyond the replica count with no pod using it
+    pub orphaned: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatefulSetOrdinal {
+    pub ordinal: i32,
+    pub pod_name: String,
+    pub pod_exists: bool,
+    // Within the current replica count
+    pub desired: bool,
+    pub claims: Vec<StatefulSetClaim>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatefulSetVolumes {
+    pub name: String,
+    pub namespace: String,
+    pub replicas: i32,
+    // persistentVolumeClaimRetentionPolicy, "Retain" or "Delete"
+    pub when_scaled: String,
+    pub when_deleted: String,
+    pub ordinals: Vec<StatefulSetOrdinal>,
+    pub orphaned_claims: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrphanedClaimCleanup {
+    pub deleted: Vec<String>,
+    pub warnings: Vec<String>,
+    pub dry_run: bool,
+}
+
+// Ingress Creation Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IngressPathRequest {
+    pub path: String,
+    // "Prefix" (default), "Exact" or "ImplementationSpecific"
+    pub path_type: Option<String>,
+    pub service: String,
+    // Port number or port name on the service
+    pub service_port: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IngressCreateRequest {
+    pub name: String,
+    pub namespace: String,
+    // Without a host the rule answers for any host
+    pub host: Option<String>,
+    pub paths: Vec<IngressPathRequest>,
+    pub tls_secret: Option<String>,
+    pub ingress_class: Option<String>,
+}
+
+// Service External URL Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceUrl {
+    pub url: String,
+    // "load_balancer", "node_port" or "external_ip"
+    pub source: String,
+    pub port_name: Option<String>,
+    // Node the URL goes through, for node ports
+    pub node: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceExternalAccess {
+    pub name: String,
+    pub namespace: String,
+    pub service_type: String,
+    // A LoadBalancer service still waiting for its address
+    pub pending: bool,
+    pub urls: Vec<ServiceUrl>,
+}
+
+// Load Balancer Provisioning Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoadBalancerIssue {
+    pub reason: String,
+    // "quota", "subnet", "permission", "address", "certificate" or "unknown"
+    pub category: String,
+    pub message: String,
+    pub hint: Option<String>,
+    pub count: i32,
+    pub last_seen: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoadBalancerStatus {
+    pub name: String,
+    pub namespace: String,
+    // "aws", "azure", "gcp", "metallb", "oci" or "digitalocean", from annotations or loadBalancerClass
+    pub provider: Option<String>,
+    pub internal: bool,
+    // "provisioned", "provisioning" or "failed"
+    pub state: String,
+    pub addresses: Vec<String>,
+    // The provider-specific annotations on the Service
+    pub annotations: BTreeMap<String, String>,
+    pub last_event: Option<String>,
+    pub issues: Vec<LoadBalancerIssue>,
+    pub hint: Option<String>,
+}
+
+// Cluster Addon Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClusterAddon {
+    pub name: String,
+    // "dns", "metrics", "proxy", "ingress", "certificates", "network" or "storage"
+    pub category: String,
+    pub namespace: Option<String>,
+    // Deployment, DaemonSet, or CSIDriver for a driver with no recognised workload
+    pub workload_kind: Option<String>,
+    pub workload_name: Option<String>,
+    pub image: Option<String>,
+    // The image tag
+    pub version: Option<String>,
+    pub ready: i32,
+    pub desired: i32,
+    pub healthy: bool,
+}
+
+// Metrics Server Installation Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricsServerInstallResult {
+    // The pinned metrics-server release
+    pub version: String,
+    // The metrics API was already served, so nothing was applied
+    pub already_available: bool,
+    pub applied: Vec<AppliedManifest>,
+    // Whether the metrics API responded after installing
+    pub available: bool,
+    pub message: Option<String>,
+}
+
+// Production Guardrail Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProductionConfirmation {
+    pub token: String,
+    pub context: String,
+    // The command without its kuboard_ prefix, e.g. "delete_pod"
+    pub action: String,
+    // Usually namespace/name
+    pub target: String,
+    pub expires_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub timestamp: String,
+    pub context: String,
+    pub action: String,
+    pub target: String,
+    // "confirmed", "blocked_read_only" or "confirmation_required"; "scheduled" for scales made by a scaling rule
+    pub outcome: String,
+    pub confirmation_token: Option<String>,
+}
+
+// Formatting Types
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MemoryUnits {
+    // KiB, MiB, GiB, as Kubernetes quantities use
+    #[default]
+    Binary,
+    // kB, MB, GB
+    Decimal,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CpuUnits {
+    // Millicores below one core, cores above
+    #[default]
+    Auto,
+    Millicores,
+    Cores,
+}
+
+// Applies to the formatted strings in summary responses for the rest of the session
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FormatSettings {
+    #[serde(default)]
+    pub memory_units: MemoryUnits,
+    #[serde(default)]
+    pub cpu_units: CpuUnits,
+    // "." or ","
+    #[serde(default = "default_decimal_separator")]
+    pub decimal_separator: String,
+}
+
+fn default_decimal_separator() -> String {
+    ".".to_string()
+}
+
+impl Default for FormatSettings {
+    fn default() -> Self {
+        Self {
+            memory_units: MemoryUnits::default(),
+            cpu_units: CpuUnits::default(),
+            decimal_separator: default_decimal_separator(),
+        }
+    }
+}
+
+// Pagination Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListPage<T> {
+    pub items: Vec<T>,
+    // Passed back to fetch the next page; None on the last page
+    pub continue_token: Option<String>,
+    // The API server's estimate, not always provided
+    pub remaining_item_count: Option<i64>,
+}
+
+// Manifest Search Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestMatch {
+    // Field path such as spec.template.spec.containers[0].image
+    pub path: String,
+    // Scalars as text, objects and lists as compact JSON
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestSearchHit {
+    pub kind: String,
+    pub name: String,
+    pub namespace: Option<String>,
+    pub matches: Vec<ManifestMatch>,
 }
@@ -4,61 +4,133 @@
 // Kuboard Utility Functions
 // This module contains helper functions and utilities
 
-use anyhow::Result;
-
-/// Parses CPU string (e.g., "1000m", "1") into CPU cores as f64
-pub fn kuboard_parse_cpu_string(cpu_str: &str) -> Result<f64> {
-    if cpu_str.ends_with('m') {
-        // Millicores (e.g., "1000m" = 1 core)
-        let millicores = cpu_str.trim_end_matches('m').parse::<f64>()?;
-        Ok(millicores / 1000.0)
-    } else {
-        // Cores (e.g., "2")
-        Ok(cpu_str.parse::<f64>()?)
-    }
-}
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
 
-/// Parses memory string (e.g., "8Gi", "8192Mi") into bytes as u64
-pub fn kuboard_parse_memory_string(memory_str: &str) -> Result<u64> {
-    let memory_str = memory_str.trim();
-    if memory_str.ends_with("Gi") {
-        let gib = memory_str.trim_end_matches("Gi").parse::<f64>()?;
-        Ok((gib * 1024.0 * 1024.0 * 1024.0) as u64)
-    } else if memory_str.ends_with("Mi") {
-        let mib = memory_str.trim_end_matches("Mi").parse::<f64>()?;
-        Ok((mib * 1024.0 * 1024.0) as u64)
-    } else if memory_str.ends_with("Ki") {
-        let kib = memory_str.trim_end_matches("Ki").parse::<f64>()?;
-        Ok((kib * 1024.0) as u64)
-    } else {
-        // Assume bytes
-        Ok(memory_str.parse::<u64>()?)
-    }
-}
+use crate::types::{CpuUnits, FormatSettings, MemoryUnits};
 
 /// Formats bytes into human-readable memory string
 pub fn kuboard_format_memory(bytes: u64) -> String {
-    const UNITS: &[&str] = &["B", "KB", "MB", "GB", "TB"];
+    kuboard_format_memory_with(bytes, &FormatSettings::default())
+}
+
+/// Formats CPU cores into human-readable string
+pub fn kuboard_format_cpu(cores: f64) -> String {
+    kuboard_format_cpu_with(cores, &FormatSettings::default())
+}
+
+/// Formats bytes in binary (KiB, 1024) or decimal (kB, 1000) units
+pub fn kuboard_format_memory_with(bytes: u64, settings: &FormatSettings) -> String {
+    let (base, units): (f64, &[&str]) = match settings.memory_units {
+        MemoryUnits::Binary => (1024.0, &["B", "KiB", "MiB", "GiB", "TiB"]),
+        MemoryUnits::Decimal => (1000.0, &["B", "kB", "MB", "GB", "TB"]),
+    };
     let mut size = bytes as f64;
     let mut unit_index = 0;
-    
-    while size >= 1024.0 && unit_index < UNITS.len() - 1 {
-        size /= 1024.0;
+
+    while size >= base && unit_index < units.len() - 1 {
+        size /= base;
         unit_index += 1;
     }
-    
+
     if unit_index == 0 {
-        format!("{} {}", bytes, UNITS[unit_index])
+        format!("{} {}", bytes, units[unit_index])
     } else {
-        format!("{:.1} {}", size, UNITS[unit_index])
+        format!("{} {}", localize(format!("{:.1}", size), settings), units[unit_index])
     }
 }
 
-/// Formats CPU cores into human-readable string
-pub fn kuboard_format_cpu(cores: f64) -> String {
-    if cores < 1.0 {
+/// Formats CPU cores as millicores, cores, or millicores below one core
+pub fn kuboard_format_cpu_with(cores: f64, settings: &FormatSettings) -> String {
+    let millicores = matches!(settings.cpu_units, CpuUnits::Millicores)
+        || (matches!(settings.cpu_units, CpuUnits::Auto) && cores < 1.0);
+    if millicores {
         format!("{:.0}m", cores * 1000.0)
+    } else if matches!(settings.cpu_units, CpuUnits::Cores) {
+        localize(format!("{:.2}", cores), settings)
+    } else {
+        localize(format!("{:.1}", cores), settings)
+    }
+}
+
+fn localize(number: String, settings: &FormatSettings) -> String {
+    if settings.decimal_separator == "." {
+        number
     } else {
-        format!("{:.1}", cores)
+        number.replace('.', &settings.decimal_separator)
+    }
+}
+
+// Largest unit first; an age shows at most two of them
+const DURATION_UNITS: &[(&str, i64)] = &[("y", 31_536_000), ("d", 86_400), ("h", 3_600), ("m", 60), ("s", 1)];
+
+/// Formats seconds as a compact duration ("5d3h", "12m", "45s"), the way kubectl prints ages
+pub fn kuboard_format_duration(seconds: i64) -> String {
+    let seconds = seconds.max(0);
+    let Some(largest) = DURATION_UNITS.iter().position(|(_, size)| seconds >= *size) else {
+        return "0s".to_string();
+    };
+    let (unit, size) = DURATION_UNITS[largest];
+    let mut formatted = format!("{}{}", seconds / size, unit);
+    if let Some((next_unit, next_size)) = DURATION_UNITS.get(largest + 1) {
+        let rest = seconds % size / next_size;
+        if rest > 0 {
+            formatted.push_str(&format!("{}{}", rest, next_unit));
+        }
+    }
+    formatted
+}
+
+/// Formats the time elapsed since `since`, e.g. a creation timestamp, as an age
+pub fn kuboard_format_age(since: DateTime<Utc>) -> String {
+    kuboard_format_duration((Utc::now() - since).num_seconds())
+}
+
+/// Checks the decimal separator is one the formatters can use
+pub fn kuboard_validate_format_settings(settings: &FormatSettings) -> Result<()> {
+    if !matches!(settings.decimal_separator.as_str(), "." | ",") {
+        return Err(anyhow!("Decimal separator must be \".\" or \",\", not {:?}", settings.decimal_separator));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_with_settings() {
+        let defaults = FormatSettings::default();
+        assert_eq!(kuboard_format_memory(1536 * 1024 * 1024), "1.5 GiB");
+        assert_eq!(kuboard_format_cpu(0.25), "250m");
+        assert_eq!(kuboard_format_cpu(2.0), "2.0");
+
+        let european = FormatSettings {
+            memory_units: MemoryUnits::Decimal,
+            cpu_units: CpuUnits::Cores,
+            decimal_separator: ",".to_string(),
+        };
+        assert_eq!(kuboard_format_memory_with(1_500_000_000, &european), "1,5 GB");
+        assert_eq!(kuboard_format_cpu_with(0.25, &european), "0,25");
+        assert_eq!(kuboard_format_memory_with(512, &european), "512 B");
+
+        let millicores = FormatSettings { cpu_units: CpuUnits::Millicores, ..defaults.clone() };
+        assert_eq!(kuboard_format_cpu_with(2.5, &millicores), "2500m");
+
+        assert!(kuboard_validate_format_settings(&defaults).is_ok());
+        assert!(kuboard_validate_format_settings(&FormatSettings { decimal_separator: "'".to_string(), ..defaults }).is_err());
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(kuboard_format_duration(0), "0s");
+        assert_eq!(kuboard_format_duration(-30), "0s");
+        assert_eq!(kuboard_format_duration(45), "45s");
+        assert_eq!(kuboard_format_duration(12 * 60), "12m");
+        assert_eq!(kuboard_format_duration(12 * 60 + 5), "12m5s");
+        assert_eq!(kuboard_format_duration(3 * 3600 + 12 * 60 + 40), "3h12m");
+        assert_eq!(kuboard_format_duration(5 * 86_400 + 3 * 3600 + 59), "5d3h");
+        assert_eq!(kuboard_format_duration(400 * 86_400), "1y35d");
+        assert_eq!(kuboard_format_age(Utc::now() - chrono::Duration::hours(2)), "2h");
     }
 }
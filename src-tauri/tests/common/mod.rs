@@ -0,0 +1,124 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Local Cluster Test Fixture
+// Connects to a kind/k3d cluster and isolates each test in its own namespace
+
+use k8s_openapi::api::apps::v1::Deployment;
+use k8s_openapi::api::core::v1::Namespace;
+use kube::api::{DeleteParams, PostParams};
+use kube::{Api, Client};
+use kuboard_lib::kubernetes::{kuboard_create_client_from_context, kuboard_load_kubeconfig};
+use kuboard_lib::AppState;
+use std::future::Future;
+use std::process::Command;
+use std::time::Duration;
+use tauri::Manager;
+
+// Context used when KUBOARD_TEST_CONTEXT is not set; k3d users can point it at "k3d-<name>"
+pub const DEFAULT_TEST_CONTEXT: &str = "kind-kuboard-test";
+const KIND_CLUSTER_NAME: &str = "kuboard-test";
+
+// Label on every namespace the fixture creates, so leftovers can be swept with kubectl
+pub const TEST_NAMESPACE_LABEL: &str = "kuboard.dev/integration-test";
+
+pub struct LocalCluster {
+    pub client: Client,
+    pub context: String,
+    pub namespace: String,
+}
+
+impl LocalCluster {
+    pub async fn connect() -> Self {
+        let context = std::env::var("KUBOARD_TEST_CONTEXT").unwrap_or_else(|_| DEFAULT_TEST_CONTEXT.to_string());
+
+        if std::env::var("KUBOARD_TEST_CREATE_CLUSTER").as_deref() == Ok("1") {
+            ensure_kind_cluster();
+        }
+
+        let kubeconfig = kuboard_load_kubeconfig().await.expect("kubeconfig should be loadable");
+        let client = kuboard_create_client_from_context(&kubeconfig, &context)
+            .await
+            .unwrap_or_else(|e| panic!("Failed to connect to test context {}: {}", context, e));
+
+        let namespace = format!("kuboard-it-{}", &uuid::Uuid::new_v4().simple().to_string()[..8]);
+        let namespaces_api: Api<Namespace> = Api::all(client.clone());
+        let ns: Namespace = serde_json::from_value(serde_json::json!({
+            "metadata": { "name": namespace, "labels": { TEST_NAMESPACE_LABEL: "true" } }
+        })).unwrap();
+        namespaces_api.create(&PostParams::default(), &ns).await.expect("test namespace should be created");
+
+        Self { client, context, namespace }
+    }
+
+    // A mock Tauri app whose AppState points at this cluster, for calling commands directly
+    pub fn app(&self) -> tauri::App<tauri::test::MockRuntime> {
+        let app = tauri::test::mock_app();
+        let state = AppState::new();
+        *state.current_client.try_write().unwrap() = Some(self.client.clone());
+        *state.current_context.try_write().unwrap() = Some(self.context.clone());
+        app.manage(state);
+        app
+    }
+
+    pub async fn create_deployment(&self, name: &str, replicas: i32) -> Deployment {
+        let deployment: Deployment = serde_json::from_value(serde_json::json!({
+            "metadata": { "name": name, "labels": { "app": name } },
+            "spec": {
+                "replicas": replicas,
+                "selector": { "matchLabels": { "app": name } },
+                "template": {
+                    "metadata": { "labels": { "app": name } },
+                    "spec": {
+                        "terminationGracePeriodSeconds": 0,
+                        "containers": [{ "name": "pause", "image": "registry.k8s.io/pause:3.9" }]
+                    }
+                }
+            }
+        })).unwrap();
+
+        Api::<Deployment>::namespaced(self.client.clone(), &self.namespace)
+            .create(&PostParams::default(), &deployment)
+            .await
+            .expect("test deployment should be created")
+    }
+
+    pub async fn cleanup(self) {
+        let namespaces_api: Api<Namespace> = Api::all(self.client);
+        if let Err(e) = namespaces_api.delete(&self.namespace, &DeleteParams::background()).await {
+            eprintln!("Failed to delete test namespace {}: {}", self.namespace, e);
+        }
+    }
+}
+
+// Polls until the check passes, so tests tolerate controller reconciliation delays
+pub async fn wait_for<F, Fut>(description: &str, timeout: Duration, mut check: F)
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = bool>,
+{
+    let deadline = tokio::time::Instant::now() + timeout;
+    while tokio::time::Instant::now() < deadline {
+        if check().await {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(500)).await;
+    }
+    panic!("Timed out after {:?} waiting for {}", timeout, description);
+}
+
+fn ensure_kind_cluster() {
+    let existing = Command::new("kind")
+        .args(["get", "clusters"])
+        .output()
+        .expect("kind should be installed when KUBOARD_TEST_CREATE_CLUSTER=1");
+    if String::from_utf8_lossy(&existing.stdout).lines().any(|c| c == KIND_CLUSTER_NAME) {
+        return;
+    }
+
+    let status = Command::new("kind")
+        .args(["create", "cluster", "--name", KIND_CLUSTER_NAME, "--wait", "120s"])
+        .status()
+        .expect("kind create cluster should run");
+    assert!(status.success(), "kind create cluster failed");
+}
@@ -0,0 +1,199 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Command tests against a local kind/k3d cluster
+// These are ignored by default. Run them with:
+//   kind create cluster --name kuboard-test
+//   cargo test --test kind_cluster -- --ignored --test-threads=1
+// Set KUBOARD_TEST_CONTEXT to use another context (e.g. k3d-kuboard-test), or
+// KUBOARD_TEST_CREATE_CLUSTER=1 to have the fixture create the kind cluster.
+
+mod common;
+
+use common::{wait_for, LocalCluster};
+use k8s_openapi::api::apps::v1::{Deployment, ReplicaSet};
+use k8s_openapi::api::core::v1::{Node, Pod};
+use kube::Api;
+use kuboard_lib::commands;
+use kuboard_lib::kubernetes::exec::EXEC_OUTPUT_EVENT;
+use kuboard_lib::kubernetes::watch::PodWatcher;
+use kuboard_lib::types::NamespaceFilter;
+use kuboard_lib::AppState;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::{Listener, Manager};
+
+const ROLLOUT_TIMEOUT: Duration = Duration::from_secs(120);
+
+#[tokio::test]
+#[ignore = "requires a kind/k3d cluster"]
+async fn test_scale_deployment_against_local_cluster() {
+    let cluster = LocalCluster::connect().await;
+    let app = cluster.app();
+    cluster.create_deployment("scale-target", 1).await;
+
+    let scaled = commands::kuboard_scale_deployment("scale-target".to_string(), cluster.namespace.clone(), 3, None, app.state())
+        .await
+        .unwrap();
+    assert_eq!(scaled.spec.unwrap().replicas, Some(3));
+
+    let deployments: Api<Deployment> = Api::namespaced(cluster.client.clone(), &cluster.namespace);
+    wait_for("three ready replicas", ROLLOUT_TIMEOUT, || async {
+        deployments.get("scale-target").await.ok()
+            .and_then(|d| d.status)
+            .and_then(|s| s.ready_replicas)
+            == Some(3)
+    }).await;
+
+    cluster.cleanup().await;
+}
+
+#[tokio::test]
+#[ignore = "requires a kind/k3d cluster"]
+async fn test_restart_deployment_rolls_out_new_replicaset() {
+    let cluster = LocalCluster::connect().await;
+    let app = cluster.app();
+    cluster.create_deployment("restart-target", 1).await;
+
+    let replicasets: Api<ReplicaSet> = Api::namespaced(cluster.client.clone(), &cluster.namespace);
+    wait_for("initial replicaset", ROLLOUT_TIMEOUT, || async {
+        replicasets.list(&Default::default()).await.map(|l| l.items.len() == 1).unwrap_or(false)
+    }).await;
+
+    commands::kuboard_restart_deployment("restart-target".to_string(), cluster.namespace.clone(), None, app.state())
+        .await
+        .unwrap();
+
+    wait_for("a second replicaset after restart", ROLLOUT_TIMEOUT, || async {
+        replicasets.list(&Default::default()).await.map(|l| l.items.len() == 2).unwrap_or(false)
+    }).await;
+
+    cluster.cleanup().await;
+}
+
+#[tokio::test]
+#[ignore = "requires a kind/k3d cluster"]
+async fn test_pod_watch_emits_events_for_new_pods() {
+    let cluster = LocalCluster::connect().await;
+    let app = cluster.app();
+
+    let seen = Arc::new(Mutex::new(Vec::<String>::new()));
+    let seen_clone = seen.clone();
+    app.listen_any("pod-watch-event", move |event| {
+        seen_clone.lock().unwrap().push(event.payload().to_string());
+    });
+
+    let mut watcher = PodWatcher::new();
+    watcher.start(cluster.client.clone(), app.handle().clone(), NamespaceFilter::default()).await.unwrap();
+    cluster.create_deployment("watch-target", 1).await;
+
+    let namespace = cluster.namespace.clone();
+    wait_for("a pod watch event from the test namespace", ROLLOUT_TIMEOUT, || {
+        let found = seen.lock().unwrap().iter().any(|payload| payload.contains(&namespace));
+        async move { found }
+    }).await;
+
+    watcher.stop();
+    cluster.cleanup().await;
+}
+
+#[tokio::test]
+#[ignore = "requires a kind/k3d cluster"]
+async fn test_update_pod_from_yaml_applies_label_change() {
+    let cluster = LocalCluster::connect().await;
+    let app = cluster.app();
+    cluster.create_deployment("apply-target", 1).await;
+
+    let pods: Api<Pod> = Api::namespaced(cluster.client.clone(), &cluster.namespace);
+    wait_for("a pod for the deployment", ROLLOUT_TIMEOUT, || async {
+        pods.list(&Default::default()).await.map(|l| !l.items.is_empty()).unwrap_or(false)
+    }).await;
+
+    let mut pod = pods.list(&Default::default()).await.unwrap().items.remove(0);
+    let pod_name = pod.metadata.name.clone().unwrap();
+    pod.metadata.labels.get_or_insert_with(Default::default).insert("kuboard-applied".to_string(), "yes".to_string());
+
+    commands::kuboard_update_pod_from_yaml(
+        pod_name.clone(),
+        cluster.namespace.clone(),
+        serde_yaml::to_string(&pod).unwrap(),
+        None,
+        None,
+        app.state(),
+    ).await.unwrap();
+
+    let updated = pods.get(&pod_name).await.unwrap();
+    assert_eq!(updated.metadata.labels.unwrap().get("kuboard-applied").map(String::as_str), Some("yes"));
+
+    cluster.cleanup().await;
+}
+
+#[tokio::test]
+#[ignore = "requires a kind/k3d cluster"]
+async fn test_node_shell_pod_is_removed_when_session_closes() {
+    let cluster = LocalCluster::connect().await;
+    let app = cluster.app();
+
+    let nodes: Api<Node> = Api::all(cluster.client.clone());
+    let node_name = nodes.list(&Default::default()).await.unwrap().items[0].metadata.name.clone().unwrap();
+
+    let shell = commands::kuboard_start_node_shell(node_name.clone(), Some(cluster.namespace.clone()), None, app.handle().clone(), app.state())
+        .await
+        .unwrap();
+    let pod_name = shell.pod_name.clone();
+
+    let pods: Api<Pod> = Api::namespaced(cluster.client.clone(), &cluster.namespace);
+    let pod = pods.get(&pod_name).await.unwrap();
+    assert_eq!(pod.spec.unwrap().node_name.as_deref(), Some(node_name.as_str()));
+
+    // The pod is deleted in the cluster it was created in, not through the active context
+    *app.state::<AppState>().current_client.write().await = None;
+    commands::kuboard_close_exec_session(shell.session_id, app.state())
+        .await
+        .unwrap();
+    wait_for("the node shell pod to be deleted", ROLLOUT_TIMEOUT, || async {
+        pods.get_opt(&pod_name).await.map(|p| p.is_none()).unwrap_or(false)
+    }).await;
+
+    cluster.cleanup().await;
+}
+
+#[tokio::test]
+#[ignore = "requires a kind/k3d cluster"]
+async fn test_node_shell_runs_input_and_is_removed_on_exit() {
+    let cluster = LocalCluster::connect().await;
+    let app = cluster.app();
+
+    let output = Arc::new(Mutex::new(String::new()));
+    let output_clone = output.clone();
+    app.listen_any(EXEC_OUTPUT_EVENT, move |event| {
+        let event: serde_json::Value = serde_json::from_str(event.payload()).unwrap();
+        output_clone.lock().unwrap().push_str(event["data"].as_str().unwrap_or_default());
+    });
+
+    let nodes: Api<Node> = Api::all(cluster.client.clone());
+    let node_name = nodes.list(&Default::default()).await.unwrap().items[0].metadata.name.clone().unwrap();
+    let shell = commands::kuboard_start_node_shell(node_name, Some(cluster.namespace.clone()), None, app.handle().clone(), app.state())
+        .await
+        .unwrap();
+
+    // The arithmetic only appears expanded in the command's output, not in the echoed input
+    commands::kuboard_send_exec_input(shell.session_id.clone(), "echo kuboard-$((40 + 2))\n".to_string(), app.state())
+        .await
+        .unwrap();
+    wait_for("node shell output", ROLLOUT_TIMEOUT, || {
+        let found = output.lock().unwrap().contains("kuboard-42");
+        async move { found }
+    }).await;
+
+    // Exiting the shell ends the stream, which removes the pod without an explicit close
+    commands::kuboard_send_exec_input(shell.session_id, "exit\n".to_string(), app.state())
+        .await
+        .unwrap();
+    let pods: Api<Pod> = Api::namespaced(cluster.client.clone(), &cluster.namespace);
+    wait_for("the node shell pod to be deleted", ROLLOUT_TIMEOUT, || async {
+        pods.get_opt(&shell.pod_name).await.map(|p| p.is_none()).unwrap_or(false)
+    }).await;
+
+    cluster.cleanup().await;
+}
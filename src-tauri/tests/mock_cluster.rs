@@ -0,0 +1,2574 @@
+// Copyright 2025 Kuboard Contributors
+// Licensed under the MIT License - see LICENSE file for details
+
+// Command handler tests against the in-memory mock cluster
+// Run with: cargo test --features mock-cluster
+
+#![cfg(feature = "mock-cluster")]
+
+use kuboard_lib::commands;
+use kuboard_lib::kubernetes::clone::kuboard_clone_namespace_resources;
+use kuboard_lib::kubernetes::guardrails::GuardrailPaths;
+use kuboard_lib::kubernetes::mock::{MockCluster, MOCK_CONTEXT_NAME};
+use kuboard_lib::kubernetes::progress::ProgressReporter;
+use kuboard_lib::kubernetes::namespace_access::{kuboard_list_accessible, kuboard_list_accessible_namespaces};
+use kuboard_lib::kubernetes::scaling::{ScalingAction, ScalingRuleRequest};
+use kuboard_lib::types::{
+    ApplyOutcome, CpuUnits, CronJobCreateRequest, DiffOperation, FormatSettings, IngressCreateRequest, IngressPathRequest, JobCreateRequest, KuboardProfile, ManifestFormat, MemoryUnits, NamespaceFilter, PayloadEncoding, PodSummary, ProfileKind, RolloutState, ServiceRouting, TemplateChangeCategory, WatchKind, WorkloadHealth,
+};
+use kuboard_lib::AppState;
+use std::collections::HashMap;
+use tauri::Manager;
+
+fn mock_app(cluster: &MockCluster) -> tauri::App<tauri::test::MockRuntime> {
+    let app = tauri::test::mock_app();
+    let state = AppState::new();
+    *state.current_client.try_write().unwrap() = Some(cluster.client());
+    app.manage(state);
+    app
+}
+
+#[tokio::test]
+async fn test_mock_cluster_lists_seeded_resources() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    let pods = commands::kuboard_get_pods(None, None, None, app.state()).await.unwrap();
+    let nodes = commands::kuboard_get_nodes(app.state()).await.unwrap();
+    let deployments = commands::kuboard_get_deployments(None, None, None, app.state()).await.unwrap();
+
+    assert_eq!(pods.len(), 5);
+    assert_eq!(nodes.len(), 2);
+    assert_eq!(deployments[0].metadata.name.as_deref(), Some("web"));
+}
+
+#[tokio::test]
+async fn test_mock_cluster_scale_and_restart_deployment() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    let scaled = commands::kuboard_scale_deployment("web".to_string(), "default".to_string(), 5, None, app.state())
+        .await
+        .unwrap();
+    assert_eq!(scaled.spec.as_ref().and_then(|s| s.replicas), Some(5));
+
+    let restarted = commands::kuboard_restart_deployment("web".to_string(), "default".to_string(), None, app.state())
+        .await
+        .unwrap();
+    let annotations = restarted.spec.unwrap().template.metadata.unwrap().annotations.unwrap();
+    assert!(annotations.contains_key("kubectl.kubernetes.io/restartedAt"));
+
+    let missing = commands::kuboard_scale_deployment("missing".to_string(), "default".to_string(), 1, None, app.state()).await;
+    assert_eq!(missing.unwrap_err(), "Deployment default/missing not found");
+}
+
+#[tokio::test]
+async fn test_mock_cluster_rollback_deployment() {
+    use k8s_openapi::api::apps::v1::ReplicaSet;
+    use kube::Api;
+    use serde_json::json;
+
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+    let api: Api<ReplicaSet> = Api::namespaced(cluster.client(), "default");
+    let current = serde_json::to_value(api.get("web-7d9f8").await.unwrap()).unwrap();
+    let mut previous = current.clone();
+    previous["metadata"] = json!({
+        "name": "web-6c8b5",
+        "namespace": "default",
+        "labels": { "app": "web", "pod-template-hash": "6c8b5" },
+        "annotations": { "deployment.kubernetes.io/revision": "1" },
+        "ownerReferences": current["metadata"]["ownerReferences"],
+    });
+    previous["spec"]["replicas"] = json!(0);
+    previous["spec"]["template"]["metadata"]["labels"]["pod-template-hash"] = json!("6c8b5");
+    previous["spec"]["template"]["spec"]["containers"][0]["image"] = json!("nginx:1.26");
+    cluster.insert(previous);
+    let mut current = current;
+    current["metadata"]["annotations"]["deployment.kubernetes.io/revision"] = json!("2");
+    cluster.insert(current);
+
+    let history = commands::kuboard_get_deployment_rollout_history("web".to_string(), "default".to_string(), app.state()).await.unwrap();
+    let revisions: Vec<(i64, bool, &str)> = history.iter().map(|r| (r.revision, r.current, r.images[0].as_str())).collect();
+    assert_eq!(revisions, vec![(2, true, "nginx:1.27"), (1, false, "nginx:1.26")]);
+
+    let image = |deployment: k8s_openapi::api::apps::v1::Deployment| deployment.spec.unwrap().template.spec.unwrap().containers[0].image.clone().unwrap();
+    let preview = commands::kuboard_rollback_deployment("web".to_string(), "default".to_string(), None, Some(true), app.state()).await.unwrap();
+    assert_eq!(image(preview), "nginx:1.26");
+    assert_eq!(image(commands::kuboard_get_deployment("web".to_string(), "default".to_string(), app.state()).await.unwrap()), "nginx:1.27");
+
+    let rolled_back = commands::kuboard_rollback_deployment("web".to_string(), "default".to_string(), None, None, app.state()).await.unwrap();
+    let labels = rolled_back.spec.as_ref().unwrap().template.metadata.as_ref().unwrap().labels.clone().unwrap();
+    assert!(!labels.contains_key("pod-template-hash"));
+    assert_eq!(image(rolled_back), "nginx:1.26");
+
+    let missing = commands::kuboard_rollback_deployment("web".to_string(), "default".to_string(), Some(5), None, app.state()).await;
+    assert_eq!(missing.unwrap_err(), "Cannot roll back deployment default/web: Revision 5 not found");
+}
+
+#[tokio::test]
+async fn test_mock_cluster_diff_workload_revisions() {
+    use k8s_openapi::api::apps::v1::{ReplicaSet, StatefulSet};
+    use kube::Api;
+    use serde_json::json;
+
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+    let api: Api<ReplicaSet> = Api::namespaced(cluster.client(), "default");
+    let current = serde_json::to_value(api.get("web-7d9f8").await.unwrap()).unwrap();
+    let mut previous = current.clone();
+    previous["metadata"] = json!({
+        "name": "web-6c8b5",
+        "namespace": "default",
+        "labels": { "app": "web", "pod-template-hash": "6c8b5" },
+        "annotations": { "deployment.kubernetes.io/revision": "1" },
+        "ownerReferences": current["metadata"]["ownerReferences"],
+    });
+    previous["spec"]["template"]["metadata"]["labels"]["pod-template-hash"] = json!("6c8b5");
+    previous["spec"]["template"]["spec"]["containers"][0]["image"] = json!("nginx:1.26");
+    previous["spec"]["template"]["spec"]["containers"][0]["env"] = json!([{ "name": "LOG_LEVEL", "value": "info" }]);
+    cluster.insert(previous);
+    let mut current = current;
+    current["metadata"]["annotations"]["deployment.kubernetes.io/revision"] = json!("2");
+    cluster.insert(current);
+
+    let diff = commands::kuboard_diff_workload_revisions(
+        "Deployment".to_string(), "web".to_string(), "default".to_string(), None, None, app.state(),
+    ).await.unwrap();
+    assert_eq!((diff.from_revision, diff.to_revision), (1, 2));
+    let changes: Vec<(&str, TemplateChangeCategory)> = diff.changes.iter().map(|c| (c.diff.path.as_str(), c.category)).collect();
+    assert_eq!(changes, vec![
+        ("/spec/containers/nginx/env", TemplateChangeCategory::Env),
+        ("/spec/containers/nginx/image", TemplateChangeCategory::Image),
+    ]);
+
+    // StatefulSet revisions live in ControllerRevisions named by status
+    let statefulsets: Api<StatefulSet> = Api::namespaced(cluster.client(), "default");
+    let mut statefulset = serde_json::to_value(statefulsets.get("db").await.unwrap()).unwrap();
+    let owner = json!([{ "apiVersion": "apps/v1", "kind": "StatefulSet", "name": "db", "uid": statefulset["metadata"]["uid"] }]);
+    for (name, revision, memory) in [("db-5d8f", 1, "1Gi"), ("db-7c9a", 2, "2Gi")] {
+        cluster.insert(json!({
+            "apiVersion": "apps/v1",
+            "kind": "ControllerRevision",
+            "metadata": { "name": name, "namespace": "default", "labels": { "app": "db" }, "ownerReferences": owner },
+            "revision": revision,
+            "data": { "spec": { "template": {
+                "$patch": "replace",
+                "metadata": { "labels": { "app": "db" } },
+                "spec": { "containers": [{ "name": "postgres", "image": "postgres:16", "resources": { "limits": { "memory": memory } } }] },
+            } } },
+        }));
+    }
+    statefulset["status"]["currentRevision"] = json!("db-5d8f");
+    statefulset["status"]["updateRevision"] = json!("db-7c9a");
+    cluster.insert(statefulset);
+
+    let diff = commands::kuboard_diff_workload_revisions(
+        "StatefulSet".to_string(), "db".to_string(), "default".to_string(), None, None, app.state(),
+    ).await.unwrap();
+    assert_eq!((diff.from_revision, diff.to_revision), (1, 2));
+    assert_eq!(diff.changes.len(), 1);
+    assert_eq!(diff.changes[0].diff.path, "/spec/containers/postgres/resources/limits/memory");
+    assert_eq!(diff.changes[0].category, TemplateChangeCategory::Resources);
+
+    let unsupported = commands::kuboard_diff_workload_revisions(
+        "DaemonSet".to_string(), "node-agent".to_string(), "default".to_string(), None, None, app.state(),
+    ).await;
+    assert!(unsupported.is_err());
+}
+
+#[tokio::test]
+async fn test_mock_cluster_pause_and_resume_deployment() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    let status = commands::kuboard_get_deployment_rollout_status("web".to_string(), "default".to_string(), app.state()).await.unwrap();
+    assert_eq!((status.state, status.updated, status.available), (RolloutState::Complete, 2, 2));
+
+    let paused = commands::kuboard_pause_deployment("web".to_string(), "default".to_string(), app.state()).await.unwrap();
+    assert_eq!(paused.spec.unwrap().paused, Some(true));
+    let status = commands::kuboard_get_deployment_rollout_status("web".to_string(), "default".to_string(), app.state()).await.unwrap();
+    assert_eq!(status.state, RolloutState::Paused);
+    let again = commands::kuboard_pause_deployment("web".to_string(), "default".to_string(), app.state()).await;
+    assert_eq!(again.unwrap_err(), "Deployment default/web is already paused");
+
+    let resumed = commands::kuboard_resume_deployment("web".to_string(), "default".to_string(), app.state()).await.unwrap();
+    assert_eq!(resumed.spec.unwrap().paused, Some(false));
+    assert!(commands::kuboard_get_deployment_rollout_status("missing".to_string(), "default".to_string(), app.state()).await.is_err());
+}
+
+#[tokio::test]
+async fn test_mock_cluster_scale_replicaset_guard() {
+    use kuboard_lib::types::ManagedScaleWarning;
+
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    let refused = commands::kuboard_scale_replicaset("web-7d9f8".to_string(), "default".to_string(), 5, None, None, app.state()).await;
+    let warning: ManagedScaleWarning = serde_json::from_str(&refused.unwrap_err()).unwrap();
+    assert_eq!(warning.reason, "ManagedByController");
+    assert_eq!((warning.controller_kind.as_str(), warning.controller_name.as_str()), ("Deployment", "web"));
+    assert_eq!(warning.suggested_command.as_deref(), Some("kuboard_scale_deployment"));
+
+    let forced = commands::kuboard_scale_replicaset("web-7d9f8".to_string(), "default".to_string(), 5, None, Some(true), app.state()).await.unwrap();
+    assert_eq!(forced.spec.unwrap().replicas, Some(5));
+}
+
+#[tokio::test]
+async fn test_mock_cluster_deployment_pods_and_describe() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    let pods = commands::kuboard_get_deployment_pods("web".to_string(), "default".to_string(), app.state())
+        .await
+        .unwrap();
+    assert_eq!(pods.len(), 2);
+
+    let describe = commands::kuboard_describe_pod("db-0".to_string(), "default".to_string(), app.state())
+        .await
+        .unwrap();
+    assert_eq!(describe.status.phase, "Running");
+    assert_eq!(describe.containers[0].image.as_deref(), Some("postgres:16"));
+}
+
+#[tokio::test]
+async fn test_mock_cluster_trigger_cronjob_and_delete_pod() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    let job = commands::kuboard_trigger_cronjob("nightly-report".to_string(), "default".to_string(), app.state())
+        .await
+        .unwrap();
+    assert!(job.metadata.name.unwrap().starts_with("nightly-report-manual-"));
+
+    commands::kuboard_delete_pod("db-0".to_string(), "default".to_string(), None, None, None, None, app.state())
+        .await
+        .unwrap();
+    let pods = commands::kuboard_get_pods(None, None, None, app.state()).await.unwrap();
+    assert_eq!(pods.len(), 4);
+}
+
+// Goes through the IPC layer so the argument names are mapped exactly as for a frontend call;
+// the client is built on Tauri's runtime, which runs the command while this thread waits
+#[test]
+fn test_mock_cluster_pod_logs_accept_flat_and_wrapped_arguments() {
+    use serde_json::json;
+    use tauri::test::{get_ipc_response, mock_builder, mock_context, noop_assets, INVOKE_KEY};
+
+    let cluster = MockCluster::new();
+    cluster.set_pod_logs("default", "web-7d9f8-abc12", "line one\nline two\n");
+    let app = mock_builder()
+        .invoke_handler(tauri::generate_handler![commands::kuboard_get_pod_logs])
+        .build(mock_context(noop_assets()))
+        .unwrap();
+    let state = AppState::new();
+    *state.current_client.try_write().unwrap() = Some(tauri::async_runtime::block_on(async { cluster.client() }));
+    app.manage(state);
+    let webview = tauri::WebviewWindowBuilder::new(&app, "main", Default::default()).build().unwrap();
+
+    let invoke = |body: serde_json::Value| {
+        get_ipc_response(
+            &webview,
+            tauri::webview::InvokeRequest {
+                cmd: "kuboard_get_pod_logs".into(),
+                callback: tauri::ipc::CallbackFn(0),
+                error: tauri::ipc::CallbackFn(1),
+                url: "http://tauri.localhost".parse().unwrap(),
+                body: tauri::ipc::InvokeBody::Json(body),
+                headers: Default::default(),
+                invoke_key: INVOKE_KEY.to_string(),
+            },
+        )
+        .map(|body| body.deserialize::<String>().unwrap())
+    };
+
+    let flat = invoke(json!({ "podName": "web-7d9f8-abc12", "namespace": "default", "tailLines": 10 }));
+    assert_eq!(flat.unwrap(), "line one\nline two\n");
+
+    let wrapped = invoke(json!({ "request": { "pod_name": "web-7d9f8-abc12", "namespace": "default", "tail_lines": 10 } }));
+    assert_eq!(wrapped.unwrap(), "line one\nline two\n");
+
+    let camel_in_request = invoke(json!({ "request": { "podName": "web-7d9f8-abc12", "namespace": "default" } }));
+    assert_eq!(camel_in_request.unwrap(), "line one\nline two\n");
+
+    let missing = invoke(json!({ "namespace": "default" }));
+    assert!(missing.is_err());
+}
+
+#[tokio::test]
+async fn test_mock_context_is_offered_and_selectable() {
+    let app = tauri::test::mock_app();
+    app.manage(AppState::new());
+
+    let contexts = commands::kuboard_list_contexts(app.state()).await.unwrap();
+    assert!(contexts.contexts.iter().any(|c| c.name == MOCK_CONTEXT_NAME));
+
+    commands::kuboard_set_context(MOCK_CONTEXT_NAME.to_string(), app.handle().clone(), app.state()).await.unwrap();
+    let namespaces = commands::kuboard_get_namespaces(app.state()).await.unwrap();
+    assert!(namespaces.iter().any(|ns| ns.metadata.name.as_deref() == Some("kube-system")));
+}
+
+#[tokio::test]
+async fn test_mock_cluster_watch_delivers_changes() {
+    use k8s_openapi::api::core::v1::ConfigMap;
+    use kube::runtime::watcher;
+    use tokio_stream::StreamExt;
+
+    let cluster = MockCluster::new();
+    let api: kube::Api<ConfigMap> = kube::Api::namespaced(cluster.client(), "default");
+    let stream = watcher(api, Default::default());
+    tokio::pin!(stream);
+
+    // Drain the initial listing before making a change
+    while let Some(event) = stream.next().await {
+        if matches!(event.unwrap(), watcher::Event::InitDone) {
+            break;
+        }
+    }
+
+    cluster.insert(serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "ConfigMap",
+        "metadata": { "name": "feature-flags", "namespace": "default" },
+        "data": { "beta": "true" },
+    }));
+
+    let event = tokio::time::timeout(std::time::Duration::from_secs(5), stream.next())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+    match event {
+        watcher::Event::Apply(cm) => assert_eq!(cm.metadata.name.as_deref(), Some("feature-flags")),
+        other => panic!("unexpected watch event: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_mock_cluster_workload_pods_honour_match_expressions() {
+    let cluster = MockCluster::new();
+    cluster.insert(serde_json::json!({
+        "apiVersion": "apps/v1",
+        "kind": "Deployment",
+        "metadata": { "name": "api", "namespace": "default" },
+        "spec": {
+            "selector": {
+                "matchLabels": { "app": "api" },
+                "matchExpressions": [{ "key": "track", "operator": "In", "values": ["stable"] }]
+            },
+            "template": { "metadata": { "labels": { "app": "api", "track": "stable" } }, "spec": { "containers": [] } }
+        }
+    }));
+    for (name, track) in [("api-stable", "stable"), ("api-canary", "canary")] {
+        cluster.insert(serde_json::json!({
+            "apiVersion": "v1",
+            "kind": "Pod",
+            "metadata": { "name": name, "namespace": "default", "labels": { "app": "api", "track": track } },
+            "spec": { "containers": [] }
+        }));
+    }
+    let app = mock_app(&cluster);
+
+    let pods = commands::kuboard_get_deployment_pods("api".to_string(), "default".to_string(), app.state())
+        .await
+        .unwrap();
+
+    assert_eq!(pods.len(), 1);
+    assert_eq!(pods[0].metadata.name.as_deref(), Some("api-stable"));
+}
+
+#[tokio::test]
+async fn test_mock_cluster_service_pod_lookups() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    let pods = commands::kuboard_get_service_pods("web".to_string(), "default".to_string(), app.state())
+        .await
+        .unwrap();
+    assert_eq!(pods.len(), 2);
+
+    let services = commands::kuboard_get_pod_services("web-7d9f8-abc12".to_string(), "default".to_string(), app.state())
+        .await
+        .unwrap();
+    assert_eq!(services.len(), 1);
+
+    let none = commands::kuboard_get_pod_services("db-0".to_string(), "default".to_string(), app.state())
+        .await
+        .unwrap();
+    assert!(none.is_empty());
+}
+
+#[tokio::test]
+async fn test_mock_cluster_service_details_without_selector() {
+    let cluster = MockCluster::new();
+    cluster.insert(serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "Service",
+        "metadata": { "name": "upstream", "namespace": "default" },
+        "spec": { "type": "ExternalName", "externalName": "db.example.com", "selector": { "app": "web" } }
+    }));
+    cluster.insert(serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "Service",
+        "metadata": { "name": "legacy", "namespace": "default" },
+        "spec": { "clusterIP": "None", "ports": [{ "port": 5432 }] }
+    }));
+    cluster.insert(serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "Endpoints",
+        "metadata": { "name": "legacy", "namespace": "default" },
+        "subsets": [{ "addresses": [{ "ip": "10.20.0.5" }], "ports": [{ "port": 5432 }] }]
+    }));
+    let app = mock_app(&cluster);
+
+    let web = commands::kuboard_get_service_details("web".to_string(), "default".to_string(), app.state())
+        .await
+        .unwrap();
+    assert_eq!(web.routing, ServiceRouting::Selector);
+    assert!(web.endpoints.is_some());
+
+    let upstream = commands::kuboard_get_service_details("upstream".to_string(), "default".to_string(), app.state())
+        .await
+        .unwrap();
+    assert_eq!(upstream.routing, ServiceRouting::ExternalName);
+    assert_eq!(upstream.external_name.as_deref(), Some("db.example.com"));
+    assert!(upstream.endpoints.is_none());
+    let upstream_pods = commands::kuboard_get_service_pods("upstream".to_string(), "default".to_string(), app.state())
+        .await
+        .unwrap();
+    assert!(upstream_pods.is_empty());
+
+    let legacy = commands::kuboard_get_service_details("legacy".to_string(), "default".to_string(), app.state())
+        .await
+        .unwrap();
+    assert_eq!(legacy.routing, ServiceRouting::ManualEndpoints);
+    assert_eq!(serde_json::to_value(legacy.routing).unwrap(), serde_json::json!("manual_endpoints"));
+    assert!(legacy.headless);
+    assert!(legacy.endpoints.is_some());
+}
+
+#[tokio::test]
+async fn test_mock_cluster_cronjob_history_limits_and_summary() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    let updated = commands::kuboard_set_cronjob_history_limits(
+        "nightly-report".to_string(),
+        "default".to_string(),
+        Some(10),
+        None,
+        app.state(),
+    )
+    .await
+    .unwrap();
+    let spec = updated.spec.unwrap();
+    assert_eq!(spec.successful_jobs_history_limit, Some(10));
+    assert_eq!(spec.schedule, "0 2 * * *");
+
+    let summary = commands::kuboard_get_cronjob_run_summary("nightly-report".to_string(), "default".to_string(), app.state())
+        .await
+        .unwrap();
+    assert_eq!(summary.successful_jobs_history_limit, 10);
+    assert_eq!(summary.failed_jobs_history_limit, 1);
+    assert!(summary.runs.is_empty());
+}
+
+#[tokio::test]
+async fn test_mock_cluster_targeted_workload_pod_restart() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    commands::kuboard_restart_statefulset_pod("db".to_string(), "default".to_string(), 0, None, app.state())
+        .await
+        .unwrap();
+    let out_of_range = commands::kuboard_restart_statefulset_pod("db".to_string(), "default".to_string(), 5, None, app.state()).await;
+    assert!(out_of_range.is_err());
+
+    // With spec.ordinals.start the valid ordinals are shifted
+    cluster.insert(serde_json::json!({
+        "apiVersion": "apps/v1",
+        "kind": "StatefulSet",
+        "metadata": { "name": "ledger", "namespace": "default" },
+        "spec": {
+            "replicas": 2,
+            "ordinals": { "start": 3 },
+            "serviceName": "ledger",
+            "selector": { "matchLabels": { "app": "ledger" } },
+            "template": { "metadata": { "labels": { "app": "ledger" } }, "spec": { "containers": [{ "name": "ledger", "image": "ledger:1" }] } },
+        },
+    }));
+    cluster.insert(serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "Pod",
+        "metadata": {
+            "name": "ledger-4",
+            "namespace": "default",
+            "labels": { "app": "ledger" },
+            "ownerReferences": [{ "apiVersion": "apps/v1", "kind": "StatefulSet", "name": "ledger", "uid": "mock-ledger-uid", "controller": true }],
+        },
+        "spec": { "containers": [{ "name": "ledger", "image": "ledger:1" }] },
+    }));
+    commands::kuboard_restart_statefulset_pod("ledger".to_string(), "default".to_string(), 4, None, app.state())
+        .await
+        .unwrap();
+    for ordinal in [0, 2, 5] {
+        let err = commands::kuboard_restart_statefulset_pod("ledger".to_string(), "default".to_string(), ordinal, None, app.state())
+            .await
+            .unwrap_err();
+        assert!(err.contains("has no ordinal"), "{}", err);
+    }
+
+    commands::kuboard_restart_daemonset_pod("node-agent".to_string(), "kube-system".to_string(), "mock-node-2".to_string(), None, app.state())
+        .await
+        .unwrap();
+    let agents = commands::kuboard_get_daemonset_pods("node-agent".to_string(), "kube-system".to_string(), app.state())
+        .await
+        .unwrap();
+    let names: Vec<_> = agents.iter().filter_map(|p| p.metadata.name.as_deref()).collect();
+    assert_eq!(names, vec!["node-agent-mock-node-1"]);
+}
+
+#[tokio::test]
+async fn test_mock_cluster_force_delete_pod() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    let confirmation = commands::kuboard_get_pod_delete_confirmation("web-7d9f8-abc12".to_string(), "default".to_string(), app.state())
+        .await
+        .unwrap();
+    assert_eq!(confirmation.termination_grace_period_seconds, 30);
+    assert!(!confirmation.terminating);
+
+    let rejected = commands::kuboard_delete_pod("web-7d9f8-abc12".to_string(), "default".to_string(), Some(10), Some(true), None, None, app.state()).await;
+    assert!(rejected.is_err());
+
+    commands::kuboard_delete_pod("web-7d9f8-abc12".to_string(), "default".to_string(), None, Some(true), None, None, app.state())
+        .await
+        .unwrap();
+    let gone = commands::kuboard_get_pod_delete_confirmation("web-7d9f8-abc12".to_string(), "default".to_string(), app.state()).await;
+    assert!(gone.is_err());
+}
+
+#[tokio::test]
+async fn test_mock_cluster_suspend_and_resume_workload() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    let suspended = commands::kuboard_suspend_workload("Deployment".to_string(), "web".to_string(), "default".to_string(), app.state())
+        .await
+        .unwrap();
+    assert_eq!(suspended.suspended_replicas, Some(2));
+    let again = commands::kuboard_suspend_workload("Deployment".to_string(), "web".to_string(), "default".to_string(), app.state()).await;
+    assert!(again.is_err());
+
+    let resumed = commands::kuboard_resume_workload("Deployment".to_string(), "web".to_string(), "default".to_string(), app.state())
+        .await
+        .unwrap();
+    assert_eq!(resumed.replicas, 2);
+
+    let deployment = commands::kuboard_get_deployment("web".to_string(), "default".to_string(), app.state())
+        .await
+        .unwrap();
+    assert_eq!(deployment.spec.unwrap().replicas, Some(2));
+    let annotations = deployment.metadata.annotations.unwrap_or_default();
+    assert!(!annotations.contains_key("kuboard.io/suspended-replicas"));
+}
+
+#[tokio::test]
+async fn test_mock_cluster_scaling_rule_run_now() {
+    // Rules connect through their context, which for the mock context is the shared cluster
+    let cluster = MockCluster::shared();
+    let app = tauri::test::mock_app();
+    let dir = std::env::temp_dir().join(format!("kuboard-scaling-{}", uuid::Uuid::new_v4()));
+    let mut state = AppState::new();
+    state.guardrail_paths = Some(GuardrailPaths::in_dir(&dir));
+    *state.current_client.try_write().unwrap() = Some(cluster.client());
+    app.manage(state);
+    *app.state::<AppState>().current_context.write().await = Some(MOCK_CONTEXT_NAME.to_string());
+
+    let rule = commands::kuboard_create_scaling_rule(ScalingRuleRequest {
+        name: "default nightly".to_string(),
+        context: None,
+        namespace: "default".to_string(),
+        kinds: vec!["Deployment".to_string()],
+        label_selector: None,
+        action: ScalingAction::ScaleDown,
+        time: "20:00".to_string(),
+        weekdays: vec![],
+        enabled: true,
+    }, app.state())
+        .await
+        .unwrap();
+
+    assert_eq!(rule.context, MOCK_CONTEXT_NAME);
+
+    // Switching away doesn't change which cluster the rule scales
+    *app.state::<AppState>().current_context.write().await = Some("staging".to_string());
+    let executions = commands::kuboard_run_scaling_rule(rule.id.clone(), app.state()).await.unwrap();
+    let web = executions.iter().find(|e| e.name == "web").unwrap();
+    assert!(web.success);
+    assert_eq!(web.context, MOCK_CONTEXT_NAME);
+
+    let deployment = commands::kuboard_get_deployment("web".to_string(), "default".to_string(), app.state())
+        .await
+        .unwrap();
+    assert_eq!(deployment.spec.unwrap().replicas, Some(0));
+
+    // Already scaled down, so a second run has nothing left to do
+    let rerun = commands::kuboard_run_scaling_rule(rule.id.clone(), app.state()).await.unwrap();
+    assert!(rerun.is_empty());
+
+    let audit = commands::kuboard_get_scaling_audit(app.state()).await.unwrap();
+    assert_eq!(audit.len(), executions.len());
+    assert_eq!(audit[0].rule_id, rule.id);
+
+    // Each scale was written to the test's own audit log
+    let entries = commands::kuboard_get_audit_log(Some(MOCK_CONTEXT_NAME.to_string()), None, app.state()).await.unwrap();
+    assert_eq!(entries.len(), executions.len());
+    assert!(entries.iter().any(|e| e.target == "Deployment/default/web" && e.outcome == "scheduled"));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_mock_cluster_clone_namespace() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    let result = commands::kuboard_clone_namespace(
+        "default".to_string(),
+        "review-42".to_string(),
+        Some(vec!["Deployment".to_string(), "Service".to_string()]),
+        Some("pr-42".to_string()),
+        None,
+        app.handle().clone(),
+        app.state(),
+    )
+        .await
+        .unwrap();
+    assert!(result.resources.iter().all(|r| r.success));
+    assert!(result.resources.iter().all(|r| r.kind != "ConfigMap"));
+
+    let web = commands::kuboard_get_deployment("web".to_string(), "review-42".to_string(), app.state())
+        .await
+        .unwrap();
+    assert_eq!(web.metadata.namespace.as_deref(), Some("review-42"));
+    let image = web.spec.unwrap().template.spec.unwrap().containers[0].image.clone().unwrap();
+    assert!(image.ends_with(":pr-42"));
+
+    // The target must be a new namespace
+    let again = commands::kuboard_clone_namespace("default".to_string(), "review-42".to_string(), None, None, None, app.handle().clone(), app.state()).await;
+    assert!(again.is_err());
+
+    // Each kind is a progress stage after the namespace itself
+    let stages = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let sink = stages.clone();
+    let progress = ProgressReporter::new("clone-namespace", None, move |p| sink.lock().unwrap().push((p.stage, p.percent)));
+    kuboard_clone_namespace_resources(&cluster.client(), "default", "review-43", &[], None, &progress).await.unwrap();
+    let stages = stages.lock().unwrap();
+    assert_eq!(stages.first(), Some(&("namespace".to_string(), 0)));
+    assert_eq!(stages.iter().map(|(stage, _)| stage.as_str()).collect::<Vec<_>>(), vec!["namespace", "ConfigMap", "Service", "Deployment", "done"]);
+    assert_eq!(stages.last(), Some(&("done".to_string(), 100)));
+}
+
+#[tokio::test]
+async fn test_mock_cluster_apply_manifest_template() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    let parameters = HashMap::from([
+        ("name".to_string(), "api".to_string()),
+        ("image".to_string(), "ghcr.io/acme/api:1.2".to_string()),
+        ("replicas".to_string(), "2".to_string()),
+    ]);
+    let applied = commands::kuboard_apply_manifest_template("builtin-deployment".to_string(), parameters, "default".to_string(), app.state())
+        .await
+        .unwrap();
+    assert_eq!(applied.kind, "Deployment");
+    assert_eq!(applied.namespace.as_deref(), Some("default"));
+
+    let deployment = commands::kuboard_get_deployment("api".to_string(), "default".to_string(), app.state())
+        .await
+        .unwrap();
+    assert_eq!(deployment.spec.unwrap().replicas, Some(2));
+}
+
+#[tokio::test]
+async fn test_mock_cluster_apply_manifest() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    let manifest = r#"
+apiVersion: v1
+kind: ConfigMap
+metadata:
+  name: settings
+data:
+  mode: fast
+---
+apiVersion: apps/v1
+kind: Deployment
+metadata:
+  name: web
+  namespace: default
+spec:
+  replicas: 3
+"#;
+    let applied = commands::kuboard_apply_manifest(manifest.to_string(), Some("default".to_string()), None, None, app.state()).await.unwrap();
+    assert_eq!(applied.len(), 2);
+    assert_eq!((applied[0].kind.as_str(), applied[0].outcome), ("ConfigMap", ApplyOutcome::Created));
+    assert_eq!(applied[0].namespace.as_deref(), Some("default"));
+    assert_eq!(applied[1].outcome, ApplyOutcome::Configured);
+    let deployment = commands::kuboard_get_deployment("web".to_string(), "default".to_string(), app.state()).await.unwrap();
+    assert_eq!(deployment.spec.unwrap().replicas, Some(3));
+    assert!(deployment.metadata.annotations.unwrap()["kubernetes.io/change-cause"].starts_with("kuboard apply deployment/web"));
+
+    // Nothing is applied when one object can't be resolved
+    let unknown = "apiVersion: v1\nkind: ConfigMap\nmetadata:\n  name: other\n---\napiVersion: example.com/v1\nkind: Widget\nmetadata:\n  name: w\n";
+    assert!(commands::kuboard_apply_manifest(unknown.to_string(), None, None, None, app.state()).await.is_err());
+    assert!(commands::kuboard_get_configmaps(Some("default".to_string()), None, None, app.state()).await.unwrap().iter().all(|c| c.metadata.name.as_deref() != Some("other")));
+
+    *app.state::<AppState>().profile.write().await = KuboardProfile {
+        kind: ProfileKind::Developer,
+        namespaces: vec!["default".to_string()],
+    };
+    let cluster_role = "apiVersion: rbac.authorization.k8s.io/v1\nkind: ClusterRole\nmetadata:\n  name: reader\n";
+    let refused = commands::kuboard_apply_manifest(cluster_role.to_string(), Some("default".to_string()), None, None, app.state()).await;
+    assert!(refused.unwrap_err().contains("cluster-scoped"));
+}
+
+#[tokio::test]
+async fn test_mock_cluster_diff_manifest() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    // What the editor sends back: the live YAML with one change, still carrying status and server metadata
+    let yaml = commands::kuboard_get_deployment_yaml("web".to_string(), "default".to_string(), None, app.state()).await.unwrap();
+    let mut edited: serde_json::Value = serde_yaml::from_str(&yaml).unwrap();
+    edited["spec"]["replicas"] = serde_json::json!(4);
+    edited["metadata"]["resourceVersion"] = serde_json::json!("1");
+    edited["status"] = serde_json::json!({ "readyReplicas": 0 });
+    let manifest = format!("{}---\napiVersion: v1\nkind: ConfigMap\nmetadata:\n  name: new-settings\ndata:\n  mode: fast\n", serde_yaml::to_string(&edited).unwrap());
+
+    let diffs = commands::kuboard_diff_manifest(manifest, Some("default".to_string()), app.state()).await.unwrap();
+    assert_eq!(diffs.len(), 2);
+    assert!(diffs[0].exists);
+    let changes: Vec<(&str, DiffOperation)> = diffs[0].changes.iter().map(|c| (c.path.as_str(), c.operation)).collect();
+    assert_eq!(changes, vec![("/spec/replicas", DiffOperation::Changed)]);
+    assert_eq!(diffs[0].changes[0].before, Some(serde_json::json!(2)));
+
+    assert!(!diffs[1].exists);
+    assert!(diffs[1].changes.iter().all(|c| c.operation == DiffOperation::Added));
+    assert!(diffs[1].changes.iter().any(|c| c.path == "/data"));
+}
+
+#[tokio::test]
+async fn test_mock_cluster_node_eviction_order() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+    cluster.insert(serde_json::json!({
+        "apiVersion": "metrics.k8s.io/v1beta1",
+        "kind": "PodMetrics",
+        "metadata": { "name": "db-0", "namespace": "default" },
+        "timestamp": "2025-01-01T00:00:00Z",
+        "window": "30s",
+        "containers": [{ "name": "postgres", "usage": { "cpu": "50m", "memory": "300Mi" } }],
+    }));
+
+    let report = commands::kuboard_get_node_eviction_order("mock-node-1".to_string(), app.state()).await.unwrap();
+    assert!(report.metrics_available);
+    assert_eq!(report.pods.len(), 3);
+    // db-0 is the only pod using more memory than it requested
+    assert_eq!((report.pods[0].name.as_str(), report.pods[0].rank), ("db-0", 1));
+    assert!(report.pods[0].exceeds_requests);
+    assert_eq!(report.pods[0].memory_request_bytes, 128 * 1024 * 1024);
+    assert!(report.pods[1..].iter().all(|pod| !pod.exceeds_requests && pod.qos_class == "Burstable"));
+}
+
+#[tokio::test]
+async fn test_mock_cluster_ephemeral_storage() {
+    use k8s_openapi::api::core::v1::Pod;
+    use kube::Api;
+    use kuboard_lib::kubernetes::mock::MOCK_EPHEMERAL_STORAGE_ANNOTATION;
+
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+    let api: Api<Pod> = Api::namespaced(cluster.client(), "default");
+    let mut db = serde_json::to_value(api.get("db-0").await.unwrap()).unwrap();
+    db["spec"]["containers"][0]["resources"]["limits"]["ephemeral-storage"] = serde_json::json!("1Gi");
+    db["metadata"]["annotations"] = serde_json::json!({ MOCK_EPHEMERAL_STORAGE_ANNOTATION: "900Mi" });
+    cluster.insert(db);
+
+    let usage = commands::kuboard_get_pod_ephemeral_storage("db-0".to_string(), "default".to_string(), app.state()).await.unwrap();
+    assert_eq!(usage.limit_bytes, Some(1024 * 1024 * 1024));
+    assert_eq!(usage.used_bytes, Some(900 * 1024 * 1024));
+
+    let report = commands::kuboard_get_ephemeral_storage_report(None, None, app.state()).await.unwrap();
+    assert!(report.unreachable_nodes.is_empty());
+    assert_eq!(report.pods.len(), 1);
+    assert_eq!(report.pods[0].name, "db-0");
+    assert!(commands::kuboard_get_ephemeral_storage_report(None, Some(95.0), app.state()).await.unwrap().pods.is_empty());
+}
+
+#[tokio::test]
+async fn test_mock_cluster_volume_attach_issues() {
+    use k8s_openapi::api::core::v1::Pod;
+    use kube::Api;
+    use serde_json::json;
+
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+    let api: Api<Pod> = Api::namespaced(cluster.client(), "default");
+    let mut db = serde_json::to_value(api.get("db-0").await.unwrap()).unwrap();
+    db["spec"]["volumes"] = json!([{ "name": "data", "persistentVolumeClaim": { "claimName": "data-db-0" } }]);
+    cluster.insert(db);
+    cluster.insert(json!({
+        "apiVersion": "v1", "kind": "PersistentVolumeClaim",
+        "metadata": { "name": "data-db-0", "namespace": "default" },
+        "spec": { "volumeName": "pvc-db" },
+    }));
+    cluster.insert(json!({
+        "apiVersion": "v1", "kind": "PersistentVolume",
+        "metadata": { "name": "pvc-db" },
+        "spec": { "csi": { "driver": "ebs.csi.aws.com", "volumeHandle": "vol-db" } },
+    }));
+    cluster.insert(json!({
+        "apiVersion": "storage.k8s.io/v1", "kind": "CSIDriver",
+        "metadata": { "name": "ebs.csi.aws.com" },
+        "spec": { "attachRequired": true },
+    }));
+    cluster.insert(json!({
+        "apiVersion": "storage.k8s.io/v1", "kind": "CSINode",
+        "metadata": { "name": "mock-node-1" },
+        "spec": { "drivers": [{ "name": "ebs.csi.aws.com", "nodeID": "i-mock-1" }] },
+    }));
+    cluster.insert(json!({
+        "apiVersion": "storage.k8s.io/v1", "kind": "VolumeAttachment",
+        "metadata": { "name": "csi-db" },
+        "spec": { "attacher": "ebs.csi.aws.com", "nodeName": "mock-node-1", "source": { "persistentVolumeName": "pvc-db" } },
+        "status": { "attached": false, "attachError": { "message": "rpc error: code = DeadlineExceeded" } },
+    }));
+    cluster.insert(json!({
+        "apiVersion": "v1", "kind": "Event",
+        "metadata": { "name": "db-0.attach", "namespace": "default" },
+        "involvedObject": { "kind": "Pod", "name": "db-0", "namespace": "default" },
+        "type": "Warning",
+        "reason": "FailedAttachVolume",
+        "message": "AttachVolume.Attach failed for volume \"pvc-db\" : rpc error: code = DeadlineExceeded",
+        "count": 3,
+        "lastTimestamp": "2025-01-01T00:05:00Z",
+    }));
+
+    let attachments = commands::kuboard_get_volume_attachments(Some("mock-node-1".to_string()), app.state()).await.unwrap();
+    assert_eq!(attachments.len(), 1);
+    assert!(commands::kuboard_get_volume_attachments(Some("mock-node-2".to_string()), app.state()).await.unwrap().is_empty());
+
+    let drivers = commands::kuboard_get_csi_drivers(app.state()).await.unwrap();
+    assert_eq!((drivers[0].name.as_str(), drivers[0].failing_attachments), ("ebs.csi.aws.com", 1));
+
+    let issues = commands::kuboard_get_volume_attach_issues(Some("default".to_string()), app.state()).await.unwrap();
+    assert_eq!(issues.len(), 1);
+    assert_eq!((issues[0].pod.as_str(), issues[0].node.as_deref()), ("db-0", Some("mock-node-1")));
+    assert_eq!(issues[0].attachment.as_ref().map(|a| a.name.as_str()), Some("csi-db"));
+    assert!(issues[0].diagnosis.contains("DeadlineExceeded"));
+}
+
+#[tokio::test]
+async fn test_mock_cluster_statefulset_orphaned_pvcs() {
+    use k8s_openapi::api::apps::v1::StatefulSet;
+    use kube::Api;
+    use serde_json::json;
+
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+    let api: Api<StatefulSet> = Api::namespaced(cluster.client(), "default");
+    let mut db = serde_json::to_value(api.get("db").await.unwrap()).unwrap();
+    db["spec"]["volumeClaimTemplates"] = json!([{ "metadata": { "name": "data" }, "spec": { "resources": { "requests": { "storage": "5Gi" } } } }]);
+    cluster.insert(db);
+    for (ordinal, volume) in [(0, "pv-db-0"), (1, "pv-db-1")] {
+        cluster.insert(json!({
+            "apiVersion": "v1", "kind": "PersistentVolumeClaim",
+            "metadata": { "name": format!("data-db-{}", ordinal), "namespace": "default" },
+            "spec": { "volumeName": volume, "storageClassName": "gp3", "resources": { "requests": { "storage": "5Gi" } } },
+            "status": { "phase": "Bound", "capacity": { "storage": "5Gi" } },
+        }));
+        cluster.insert(json!({
+            "apiVersion": "v1", "kind": "PersistentVolume",
+            "metadata": { "name": volume },
+            "spec": { "persistentVolumeReclaimPolicy": "Delete" },
+        }));
+    }
+
+    let volumes = commands::kuboard_get_statefulset_volumes("db".to_string(), "default".to_string(), app.state()).await.unwrap();
+    assert_eq!(volumes.ordinals.len(), 2);
+    assert!(volumes.ordinals[0].pod_exists && volumes.ordinals[0].desired);
+    assert_eq!(volumes.ordinals[1].claims[0].reclaim_policy.as_deref(), Some("Delete"));
+    assert_eq!(volumes.orphaned_claims, vec!["data-db-1"]);
+
+    let in_use = commands::kuboard_delete_statefulset_orphaned_pvcs(
+        "db".to_string(), "default".to_string(), Some(vec!["data-db-0".to_string()]), None, app.state(),
+    ).await;
+    assert!(in_use.unwrap_err().contains("ordinal 0 is within the 1 replica(s)"));
+
+    let preview = commands::kuboard_delete_statefulset_orphaned_pvcs("db".to_string(), "default".to_string(), None, Some(true), app.state())
+        .await
+        .unwrap();
+    assert_eq!(preview.deleted, vec!["data-db-1"]);
+    assert!(preview.warnings.iter().any(|w| w.contains("deletes volume pv-db-1")));
+    assert_eq!(commands::kuboard_get_statefulset_volumes("db".to_string(), "default".to_string(), app.state()).await.unwrap().orphaned_claims.len(), 1);
+
+    let cleanup = commands::kuboard_delete_statefulset_orphaned_pvcs("db".to_string(), "default".to_string(), None, None, app.state())
+        .await
+        .unwrap();
+    assert_eq!((cleanup.deleted.len(), cleanup.dry_run), (1, false));
+    let volumes = commands::kuboard_get_statefulset_volumes("db".to_string(), "default".to_string(), app.state()).await.unwrap();
+    assert_eq!(volumes.ordinals.len(), 1);
+    assert!(volumes.orphaned_claims.is_empty());
+}
+
+#[tokio::test]
+async fn test_mock_cluster_namespace_detail() {
+    use serde_json::json;
+
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+    let detail = commands::kuboard_get_namespace_detail("default".to_string(), app.state()).await.unwrap();
+    assert_eq!((detail.workloads.deployments, detail.workloads.statefulsets, detail.workloads.services), (1, 1, 1));
+    assert!(detail.degraded_workloads.is_empty());
+    assert_eq!((detail.pods.total, detail.pods.running), (3, 3));
+    assert!(detail.pods.unhealthy.is_empty());
+    assert!(detail.quotas.is_empty() && detail.recent_warnings.is_empty() && detail.top_cpu.is_empty());
+
+    cluster.insert(json!({
+        "apiVersion": "v1", "kind": "ResourceQuota",
+        "metadata": { "name": "compute", "namespace": "default" },
+        "status": { "hard": { "pods": "10", "requests.cpu": "1" }, "used": { "pods": "3", "requests.cpu": "500m" } },
+    }));
+    cluster.insert(json!({
+        "apiVersion": "v1", "kind": "Event",
+        "metadata": { "name": "db-0.backoff", "namespace": "default" },
+        "involvedObject": { "kind": "Pod", "name": "db-0", "namespace": "default" },
+        "type": "Warning", "reason": "BackOff", "message": "Back-off restarting failed container",
+        "lastTimestamp": "2025-01-01T00:10:00Z", "count": 4,
+    }));
+    for (pod, cpu, memory) in [("web-7d9f8-abc12", "20m", "64Mi"), ("db-0", "400m", "32Mi")] {
+        cluster.insert(json!({
+            "apiVersion": "metrics.k8s.io/v1beta1", "kind": "PodMetrics",
+            "metadata": { "name": pod, "namespace": "default" },
+            "timestamp": "2025-01-01T00:00:00Z", "window": "30s",
+            "containers": [{ "name": "main", "usage": { "cpu": cpu, "memory": memory } }],
+        }));
+    }
+
+    let detail = commands::kuboard_get_namespace_detail("default".to_string(), app.state()).await.unwrap();
+    assert_eq!(detail.quotas.iter().map(|q| (q.resource.as_str(), q.percent)).collect::<Vec<_>>(), vec![
+        ("requests.cpu", Some(50.0)),
+        ("pods", Some(30.0)),
+    ]);
+    assert_eq!(detail.recent_warnings.len(), 1);
+    assert_eq!((detail.recent_warnings[0].reason.as_str(), detail.recent_warnings[0].count), ("BackOff", 4));
+    assert!(detail.metrics_available);
+    assert_eq!((detail.top_cpu[0].pod.as_str(), detail.top_memory[0].pod.as_str()), ("db-0", "web-7d9f8-abc12"));
+
+    let missing = commands::kuboard_get_namespace_detail("nope".to_string(), app.state()).await;
+    assert!(missing.unwrap_err().contains("Namespace nope not found"));
+}
+
+#[tokio::test]
+async fn test_mock_cluster_pvcs() {
+    use serde_json::json;
+
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+    cluster.insert(json!({
+        "apiVersion": "v1", "kind": "PersistentVolumeClaim",
+        "metadata": { "name": "uploads", "namespace": "default" },
+        "spec": {
+            "accessModes": ["ReadWriteOnce"], "volumeName": "pv-uploads", "storageClassName": "gp3",
+            "resources": { "requests": { "storage": "5Gi" } },
+        },
+        "status": { "phase": "Bound", "capacity": { "storage": "8Gi" } },
+    }));
+    cluster.insert(json!({
+        "apiVersion": "v1", "kind": "PersistentVolume",
+        "metadata": { "name": "pv-uploads" },
+        "spec": { "persistentVolumeReclaimPolicy": "Retain", "capacity": { "storage": "8Gi" } },
+    }));
+    cluster.insert(json!({
+        "apiVersion": "v1", "kind": "Pod",
+        "metadata": { "name": "uploader", "namespace": "default" },
+        "spec": {
+            "nodeName": "mock-node-1",
+            "containers": [{ "name": "app", "image": "busybox" }],
+            "volumes": [{ "name": "files", "persistentVolumeClaim": { "claimName": "uploads" } }],
+        },
+        "status": { "phase": "Running" },
+    }));
+
+    let claims = commands::kuboard_get_pvcs(Some("default".to_string()), app.state()).await.unwrap();
+    assert_eq!(claims.len(), 1);
+    assert_eq!((claims[0].capacity.as_deref(), claims[0].requested.as_deref()), (Some("8Gi"), Some("5Gi")));
+    assert_eq!(claims[0].access_modes, vec!["ReadWriteOnce"]);
+    assert_eq!(claims[0].mounted_by[0].pod, "uploader");
+
+    let detail = commands::kuboard_get_pvc("uploads".to_string(), "default".to_string(), app.state()).await.unwrap();
+    assert_eq!(detail.summary.reclaim_policy.as_deref(), Some("Retain"));
+    assert_eq!(detail.volume.and_then(|pv| pv.metadata.name).as_deref(), Some("pv-uploads"));
+    let missing = commands::kuboard_get_pvc("nope".to_string(), "default".to_string(), app.state()).await;
+    assert_eq!(missing.unwrap_err(), "PersistentVolumeClaim default/nope not found");
+
+    let preview = commands::kuboard_delete_pvc("uploads".to_string(), "default".to_string(), Some(true), app.state()).await.unwrap();
+    assert!(preview.contains("would be deleted (dry run); it stays Terminating until uploader stop using it"));
+    let deleted = commands::kuboard_delete_pvc("uploads".to_string(), "default".to_string(), None, app.state()).await.unwrap();
+    assert!(deleted.starts_with("PersistentVolumeClaim default/uploads deleted successfully"));
+    assert!(commands::kuboard_get_pvcs(Some("default".to_string()), app.state()).await.unwrap().is_empty());
+    let again = commands::kuboard_delete_pvc("uploads".to_string(), "default".to_string(), None, app.state()).await.unwrap();
+    assert!(again.contains("not found (already deleted)"));
+}
+
+#[tokio::test]
+async fn test_mock_cluster_volume_binding() {
+    use k8s_openapi::api::core::v1::Pod;
+    use kube::Api;
+    use serde_json::json;
+
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+    cluster.insert(json!({
+        "apiVersion": "v1", "kind": "PersistentVolumeClaim",
+        "metadata": { "name": "data-db-0", "namespace": "default" },
+        "spec": { "volumeName": "pv-db-0", "resources": { "requests": { "storage": "5Gi" } } },
+        "status": { "phase": "Bound" },
+    }));
+    let pods: Api<Pod> = Api::namespaced(cluster.client(), "default");
+    let mut db = serde_json::to_value(pods.get("db-0").await.unwrap()).unwrap();
+    db["spec"]["volumes"] = json!([{ "name": "data", "persistentVolumeClaim": { "claimName": "data-db-0" } }]);
+    cluster.insert(db);
+    for (volume, claim, phase) in [("pv-db-0", "data-db-0", "Bound"), ("pv-old", "data-db-9", "Released")] {
+        cluster.insert(json!({
+            "apiVersion": "v1", "kind": "PersistentVolume",
+            "metadata": { "name": volume },
+            "spec": {
+                "capacity": { "storage": "5Gi" }, "persistentVolumeReclaimPolicy": "Retain",
+                "csi": { "driver": "ebs.csi.aws.com", "volumeHandle": volume },
+                "claimRef": { "namespace": "default", "name": claim },
+            },
+            "status": { "phase": phase },
+        }));
+    }
+
+    let volumes = commands::kuboard_get_persistent_volumes(app.state()).await.unwrap();
+    assert_eq!(volumes.len(), 2);
+    let detail = commands::kuboard_get_persistent_volume("pv-db-0".to_string(), app.state()).await.unwrap();
+    assert_eq!(detail.summary.claim.as_deref(), Some("default/data-db-0"));
+    assert_eq!(detail.summary.source.as_deref(), Some("csi:ebs.csi.aws.com"));
+    let missing = commands::kuboard_get_persistent_volume("nope".to_string(), app.state()).await;
+    assert_eq!(missing.unwrap_err(), "PersistentVolume nope not found");
+
+    let binding = commands::kuboard_get_volume_binding("pv-db-0".to_string(), app.state()).await.unwrap();
+    assert!(binding.bound, "{:?}", binding.issues);
+    let claim = binding.claim.unwrap();
+    assert_eq!((claim.name.as_str(), claim.mounted_by[0].pod.as_str()), ("data-db-0", "db-0"));
+
+    let released = commands::kuboard_get_volume_binding("pv-old".to_string(), app.state()).await.unwrap();
+    assert!(!released.bound && released.claim.is_none());
+    assert!(released.issues[0].starts_with("Claim default/data-db-9 no longer exists"));
+}
+
+#[tokio::test]
+async fn test_mock_cluster_create_cronjob_wizard() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    let request = CronJobCreateRequest {
+        job: JobCreateRequest {
+            name: "cleanup".to_string(),
+            namespace: "default".to_string(),
+            image: "busybox:1.36".to_string(),
+            command: vec!["sh".to_string(), "-c".to_string(), "echo cleaning".to_string()],
+            env: Default::default(),
+            restart_policy: None,
+            backoff_limit: None,
+            active_deadline_seconds: None,
+        },
+        schedule: "*/15 * * * *".to_string(),
+        time_zone: None,
+        concurrency_policy: Some("Forbid".to_string()),
+        suspend: None,
+    };
+
+    // A dry run creates nothing
+    commands::kuboard_create_cronjob(request.clone(), Some(true), app.state()).await.unwrap();
+    assert!(commands::kuboard_get_cronjob("cleanup".to_string(), "default".to_string(), app.state()).await.is_err());
+
+    let bad_schedule = CronJobCreateRequest { schedule: "every 15 minutes".to_string(), ..request.clone() };
+    assert!(commands::kuboard_create_cronjob(bad_schedule, None, app.state()).await.is_err());
+
+    commands::kuboard_create_cronjob(request.clone(), None, app.state()).await.unwrap();
+    let cronjob = commands::kuboard_get_cronjob("cleanup".to_string(), "default".to_string(), app.state())
+        .await
+        .unwrap();
+    assert_eq!(cronjob.spec.unwrap().schedule, "*/15 * * * *");
+
+    let job = commands::kuboard_create_job(JobCreateRequest { name: "cleanup-once".to_string(), ..request.job }, None, app.state())
+        .await
+        .unwrap();
+    assert_eq!(job.metadata.name.as_deref(), Some("cleanup-once"));
+}
+
+#[tokio::test]
+async fn test_mock_cluster_job_progress() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+    cluster.insert(serde_json::json!({
+        "apiVersion": "batch/v1", "kind": "Job",
+        "metadata": { "name": "render", "namespace": "default" },
+        "spec": { "completions": 8, "parallelism": 2, "template": { "spec": { "containers": [{ "name": "render", "image": "render:1" }] } } },
+        "status": { "startTime": "2025-01-01T00:00:00Z", "succeeded": 2, "failed": 1, "active": 2 },
+    }));
+
+    let progress = commands::kuboard_get_job_progress("render".to_string(), "default".to_string(), app.state()).await.unwrap();
+    assert_eq!((progress.succeeded, progress.failed, progress.active), (2, 1, 2));
+    assert_eq!(progress.percent_complete, Some(25.0));
+    assert!(progress.estimated_completion.is_some());
+
+    let missing = commands::kuboard_get_job_progress("nope".to_string(), "default".to_string(), app.state()).await;
+    assert_eq!(missing.unwrap_err(), "Job default/nope not found");
+
+    let suspended = commands::kuboard_suspend_job("render".to_string(), "default".to_string(), app.state()).await.unwrap();
+    assert_eq!(suspended.spec.unwrap().suspend, Some(true));
+    assert!(commands::kuboard_get_job_progress("render".to_string(), "default".to_string(), app.state()).await.unwrap().suspended);
+    let resumed = commands::kuboard_resume_job("render".to_string(), "default".to_string(), app.state()).await.unwrap();
+    assert_eq!(resumed.spec.unwrap().suspend, Some(false));
+
+    cluster.insert(serde_json::json!({
+        "apiVersion": "batch/v1", "kind": "Job",
+        "metadata": { "name": "done", "namespace": "default" },
+        "spec": { "template": { "spec": { "containers": [{ "name": "done", "image": "done:1" }] } } },
+        "status": { "succeeded": 1, "conditions": [{ "type": "Complete", "status": "True" }] },
+    }));
+    let finished = commands::kuboard_suspend_job("done".to_string(), "default".to_string(), app.state()).await;
+    assert_eq!(finished.unwrap_err(), "Job default/done has already finished");
+}
+
+#[tokio::test]
+async fn test_mock_cluster_create_pvc() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+    cluster.insert(serde_json::json!({
+        "apiVersion": "storage.k8s.io/v1",
+        "kind": "StorageClass",
+        "metadata": { "name": "standard", "annotations": { "storageclass.kubernetes.io/is-default-class": "true" } },
+        "provisioner": "rancher.io/local-path",
+        "volumeBindingMode": "WaitForFirstConsumer",
+    }));
+
+    let rwx = commands::kuboard_create_pvc(
+        "shared".to_string(), "default".to_string(), "1Gi".to_string(), None, Some(vec!["ReadWriteMany".to_string()]), None, app.state(),
+    ).await;
+    assert!(rwx.unwrap_err().contains("does not support ReadWriteMany"));
+    let missing = commands::kuboard_create_pvc(
+        "data".to_string(), "default".to_string(), "1Gi".to_string(), Some("fast".to_string()), None, None, app.state(),
+    ).await;
+    assert!(missing.is_err());
+
+    // Nothing to wait for until a pod uses the claim
+    let created = commands::kuboard_create_pvc(
+        "data".to_string(), "default".to_string(), "5Gi".to_string(), None, None, Some(true), app.state(),
+    ).await.unwrap();
+    assert_eq!(created.storage_class, "standard");
+    assert!(created.waits_for_consumer && !created.bound);
+    let spec = created.claim.spec.unwrap();
+    assert_eq!(spec.access_modes.unwrap(), vec!["ReadWriteOnce"]);
+    assert_eq!(spec.storage_class_name.as_deref(), Some("standard"));
+}
+
+#[tokio::test]
+async fn test_mock_cluster_create_ingress() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    let request = IngressCreateRequest {
+        name: "web".to_string(),
+        namespace: "default".to_string(),
+        host: Some("web.example.com".to_string()),
+        paths: vec![IngressPathRequest { path: "/".to_string(), path_type: None, service: "web".to_string(), service_port: "8080".to_string() }],
+        tls_secret: None,
+        ingress_class: None,
+    };
+    let wrong_port = commands::kuboard_create_ingress(request.clone(), app.state()).await;
+    assert!(wrong_port.unwrap_err().contains("has no port 8080"));
+
+    let mut request = request;
+    request.paths[0].service_port = "http".to_string();
+    let opaque_secret = IngressCreateRequest { tls_secret: Some("web-secret".to_string()), ..request.clone() };
+    assert!(commands::kuboard_create_ingress(opaque_secret, app.state()).await.is_err());
+
+    commands::kuboard_create_ingress(request, app.state()).await.unwrap();
+    let table = commands::kuboard_get_ingress_routes(app.state()).await.unwrap();
+    assert_eq!(table.routes.len(), 1);
+    assert_eq!((table.routes[0].host.as_str(), table.routes[0].service.as_str()), ("web.example.com", "web"));
+}
+
+#[tokio::test]
+async fn test_mock_cluster_expose_workload() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    // The seeded web service already has the deployment's name
+    let taken = commands::kuboard_expose_workload(
+        "Deployment".to_string(), "web".to_string(), "default".to_string(), 80, None, None, app.state(),
+    ).await;
+    assert!(taken.unwrap_err().contains("already exists"));
+
+    let service = commands::kuboard_expose_workload(
+        "StatefulSet".to_string(), "db".to_string(), "default".to_string(), 5432, None, Some("NodePort".to_string()), app.state(),
+    ).await.unwrap();
+    let spec = service.spec.unwrap();
+    assert_eq!(spec.type_.as_deref(), Some("NodePort"));
+    assert_eq!(spec.selector.unwrap()["app"], "db");
+    assert_eq!(spec.ports.unwrap()[0].port, 5432);
+}
+
+#[tokio::test]
+async fn test_mock_cluster_service_urls() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+    cluster.insert(serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "Service",
+        "metadata": { "name": "web-public", "namespace": "default" },
+        "spec": {
+            "type": "NodePort",
+            "selector": { "app": "web" },
+            "ports": [{ "name": "http", "port": 80, "targetPort": 80, "nodePort": 30080, "protocol": "TCP" }],
+        },
+    }));
+
+    // The seeded ClusterIP service isn't reachable from outside
+    let access = commands::kuboard_get_service_urls(None, app.state()).await.unwrap();
+    assert_eq!(access.len(), 1);
+    assert_eq!(access[0].name, "web-public");
+    let urls: Vec<&str> = access[0].urls.iter().map(|u| u.url.as_str()).collect();
+    assert_eq!(urls, vec!["http://10.0.0.10:30080", "http://10.0.0.11:30080"]);
+}
+
+#[tokio::test]
+async fn test_mock_cluster_load_balancer_status() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+    cluster.insert(serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "Service",
+        "metadata": { "name": "web-lb", "namespace": "default", "annotations": { "metallb.universe.tf/address-pool": "public" } },
+        "spec": { "type": "LoadBalancer", "selector": { "app": "web" }, "ports": [{ "port": 80, "protocol": "TCP" }] },
+    }));
+    cluster.insert(serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "Event",
+        "metadata": { "name": "web-lb.1", "namespace": "default" },
+        "involvedObject": { "kind": "Service", "name": "web-lb", "namespace": "default" },
+        "type": "Warning",
+        "reason": "AllocationFailed",
+        "message": "Failed to allocate IP for \"default/web-lb\": no available IPs",
+        "lastTimestamp": "2025-03-01T10:00:00Z",
+    }));
+
+    let status = commands::kuboard_get_load_balancer_status("web-lb".to_string(), "default".to_string(), app.state())
+        .await
+        .unwrap();
+    assert_eq!(status.provider.as_deref(), Some("metallb"));
+    assert_eq!(status.state, "failed");
+    assert_eq!(status.issues[0].category, "address");
+}
+
+#[tokio::test]
+async fn test_mock_cluster_cluster_addons() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+    cluster.insert(serde_json::json!({
+        "apiVersion": "apps/v1",
+        "kind": "Deployment",
+        "metadata": { "name": "coredns", "namespace": "kube-system" },
+        "spec": {
+            "replicas": 2,
+            "selector": { "matchLabels": { "k8s-app": "kube-dns" } },
+            "template": {
+                "metadata": { "labels": { "k8s-app": "kube-dns" } },
+                "spec": { "containers": [{ "name": "coredns", "image": "registry.k8s.io/coredns/coredns:v1.11.1" }] },
+            },
+        },
+        "status": { "replicas": 2, "readyReplicas": 2 },
+    }));
+    cluster.insert(serde_json::json!({
+        "apiVersion": "storage.k8s.io/v1",
+        "kind": "CSIDriver",
+        "metadata": { "name": "ebs.csi.aws.com" },
+        "spec": {},
+    }));
+
+    let addons = commands::kuboard_get_cluster_addons(app.state()).await.unwrap();
+    let names: Vec<&str> = addons.iter().map(|a| a.name.as_str()).collect();
+    assert_eq!(names, vec!["CoreDNS", "AWS EBS CSI driver"]);
+    assert_eq!(addons[0].version.as_deref(), Some("v1.11.1"));
+    assert!(addons[0].healthy);
+    assert_eq!(addons[1].workload_kind.as_deref(), Some("CSIDriver"));
+}
+
+#[tokio::test]
+async fn test_mock_cluster_install_metrics_server_when_available() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    // The mock serves the metrics API, so the install leaves the cluster alone
+    let result = commands::kuboard_install_metrics_server(true, app.state()).await.unwrap();
+    assert!(result.already_available);
+    assert!(result.available);
+    assert!(result.applied.is_empty());
+}
+
+#[tokio::test]
+async fn test_mock_cluster_metrics_formatting() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    let summary = commands::kuboard_get_cluster_metrics(app.state()).await.unwrap();
+    assert_eq!(summary.memory.total_formatted, "32.0 GiB");
+    assert_eq!(summary.cpu.total_formatted, "8.0");
+
+    commands::kuboard_set_format_settings(FormatSettings {
+        memory_units: MemoryUnits::Decimal,
+        cpu_units: CpuUnits::Millicores,
+        decimal_separator: ",".to_string(),
+    }, app.state()).await.unwrap();
+    let summary = commands::kuboard_get_cluster_metrics(app.state()).await.unwrap();
+    assert_eq!(summary.memory.total_formatted, "34,4 GB");
+    assert_eq!(summary.cpu.total_formatted, "8000m");
+
+    let invalid = FormatSettings { decimal_separator: ";".to_string(), ..Default::default() };
+    assert!(commands::kuboard_set_format_settings(invalid, app.state()).await.is_err());
+}
+
+#[tokio::test]
+async fn test_mock_cluster_pod_summaries() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    let summaries = commands::kuboard_get_pod_summaries(None, None, None, None, None, app.state()).await.unwrap();
+    assert_eq!(summaries.len(), 5);
+    assert!(summaries.iter().all(|pod| pod.status == "Running" && pod.ready_containers == pod.total_containers));
+    assert!(summaries.iter().all(|pod| pod.age.is_some()));
+
+    let by_name = |s: &[PodSummary]| s.iter().map(|p| (p.namespace.clone(), p.name.clone())).collect::<Vec<_>>();
+    let sorted = commands::kuboard_get_pod_summaries(None, None, None, Some("name".to_string()), None, app.state()).await.unwrap();
+    let mut expected = by_name(&summaries);
+    expected.sort();
+    assert_eq!(by_name(&sorted), expected);
+    let pending = commands::kuboard_get_pod_summaries(None, None, None, None, Some("Pending,CrashLoopBackOff".to_string()), app.state()).await.unwrap();
+    assert!(pending.is_empty());
+    let unknown = commands::kuboard_get_pod_summaries(None, None, None, Some("size".to_string()), None, app.state()).await;
+    assert!(unknown.unwrap_err().starts_with("Unknown sort key size"));
+}
+
+#[tokio::test]
+async fn test_mock_cluster_workload_summaries() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    let deployments = commands::kuboard_get_deployment_summaries(None, None, None, None, None, app.state()).await.unwrap();
+    assert_eq!((deployments[0].ready, deployments[0].desired), (2, 2));
+    let statefulsets = commands::kuboard_get_statefulset_summaries(None, None, None, None, None, app.state()).await.unwrap();
+    let daemonsets = commands::kuboard_get_daemonset_summaries(None, None, None, None, None, app.state()).await.unwrap();
+    assert!(deployments.iter().chain(&statefulsets).chain(&daemonsets).all(|w| w.health == WorkloadHealth::Healthy));
+
+    commands::kuboard_scale_deployment("web".to_string(), "default".to_string(), 4, None, app.state()).await.unwrap();
+    let scaled = commands::kuboard_get_deployment_summaries(None, None, None, None, None, app.state()).await.unwrap();
+    assert_ne!(scaled[0].health, WorkloadHealth::Healthy);
+    let unhealthy = commands::kuboard_get_deployment_summaries(None, None, None, Some("age".to_string()), Some("progressing,degraded".to_string()), app.state())
+        .await
+        .unwrap();
+    assert_eq!(unhealthy.iter().map(|w| w.name.as_str()).collect::<Vec<_>>(), vec!["web"]);
+    let healthy = commands::kuboard_get_deployment_summaries(None, None, None, None, Some("Healthy".to_string()), app.state()).await.unwrap();
+    assert!(healthy.is_empty());
+    assert!(commands::kuboard_get_statefulset_summaries(None, None, None, Some("cpu".to_string()), None, app.state()).await.is_err());
+}
+
+#[tokio::test]
+async fn test_mock_cluster_lists_by_namespace() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    let pods = commands::kuboard_get_pods(Some("kube-system".to_string()), None, None, app.state()).await.unwrap();
+    assert_eq!(pods.len(), 2);
+    assert!(pods.iter().all(|pod| pod.metadata.namespace.as_deref() == Some("kube-system")));
+    assert_eq!(commands::kuboard_get_pods(Some(String::new()), None, None, app.state()).await.unwrap().len(), 5);
+    assert!(commands::kuboard_get_deployments(Some("kube-system".to_string()), None, None, app.state()).await.unwrap().is_empty());
+    assert_eq!(commands::kuboard_get_statefulset_summaries(Some("default".to_string()), None, None, None, None, app.state()).await.unwrap().len(), 1);
+
+    // A developer profile limited to kube-system hides default
+    *app.state::<AppState>().profile.write().await = KuboardProfile {
+        kind: ProfileKind::Developer,
+        namespaces: vec!["kube-system".to_string()],
+    };
+    assert_eq!(commands::kuboard_get_daemonsets(Some("kube-system".to_string()), None, None, app.state()).await.unwrap().len(), 1);
+    assert!(commands::kuboard_get_pods(Some("default".to_string()), None, None, app.state()).await.is_err());
+}
+
+#[tokio::test]
+async fn test_mock_cluster_lists_by_label_selector() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    let web = commands::kuboard_get_pods(None, Some("app=web".to_string()), None, app.state()).await.unwrap();
+    assert_eq!(web.len(), 2);
+    let not_web = commands::kuboard_get_pods(Some("default".to_string()), Some("app!=web".to_string()), None, app.state()).await.unwrap();
+    assert_eq!(not_web.len(), 1);
+    assert_eq!(commands::kuboard_get_pods(None, Some(" ".to_string()), None, app.state()).await.unwrap().len(), 5);
+    let agents = commands::kuboard_get_daemonset_summaries(None, Some("app in (node-agent)".to_string()), None, None, None, app.state()).await.unwrap();
+    assert_eq!(agents.len(), 1);
+}
+
+#[tokio::test]
+async fn test_mock_cluster_lists_by_field_selector() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+    let default = || Some("default".to_string());
+
+    let on_node = commands::kuboard_get_pods(default(), None, Some("spec.nodeName=mock-node-1".to_string()), app.state()).await.unwrap();
+    let mut names: Vec<&str> = on_node.iter().filter_map(|pod| pod.metadata.name.as_deref()).collect();
+    names.sort();
+    assert_eq!(names, vec!["db-0", "web-7d9f8-abc12"]);
+    let web_on_node = commands::kuboard_get_pods(default(), Some("app=web".to_string()), Some("spec.nodeName=mock-node-1".to_string()), app.state())
+        .await
+        .unwrap();
+    assert_eq!(web_on_node.len(), 1);
+    let failing = commands::kuboard_get_pod_summaries(None, None, Some("status.phase!=Running".to_string()), None, None, app.state()).await.unwrap();
+    assert!(failing.is_empty());
+    assert_eq!(commands::kuboard_get_pods(None, None, Some(" ".to_string()), app.state()).await.unwrap().len(), 5);
+}
+
+#[tokio::test]
+async fn test_mock_cluster_records_change_cause() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+    let change_cause = |annotations: Option<std::collections::BTreeMap<String, String>>| {
+        annotations.unwrap_or_default().get("kubernetes.io/change-cause").cloned().unwrap_or_default()
+    };
+
+    let scaled = commands::kuboard_scale_deployment("web".to_string(), "default".to_string(), 3, None, app.state()).await.unwrap();
+    assert!(change_cause(scaled.metadata.annotations).starts_with("kuboard scale deployment/web --replicas=3 ("));
+
+    let restarted = commands::kuboard_restart_statefulset("db".to_string(), "default".to_string(), None, app.state()).await.unwrap();
+    assert!(change_cause(restarted.metadata.annotations).starts_with("kuboard rollout restart statefulset/db ("));
+}
+
+#[tokio::test]
+async fn test_mock_cluster_conflict_retry() {
+    use k8s_openapi::api::apps::v1::Deployment;
+    use kube::Api;
+    use kuboard_lib::kubernetes::conflict::{kuboard_replace_with_retry, ConflictError, CONFLICT_RETRY_ATTEMPTS};
+
+    let cluster = MockCluster::new();
+    let api: Api<Deployment> = Api::namespaced(cluster.client(), "default");
+    let web = api.get("web").await.unwrap();
+    // Someone else relabels the deployment between our read and our write
+    let concurrent_edit = |owner: &str| {
+        let mut object = serde_json::to_value(&web).unwrap();
+        object["metadata"]["labels"]["owner"] = serde_json::json!(owner);
+        cluster.insert(object);
+    };
+
+    let mut attempts = 0;
+    let updated = kuboard_replace_with_retry(&api, "web", web.clone(), false, |deployment| {
+        attempts += 1;
+        if attempts == 1 {
+            concurrent_edit("ops");
+        }
+        deployment.spec.as_mut().unwrap().replicas = Some(4);
+        Ok(())
+    }).await.unwrap();
+    assert_eq!(attempts, 2);
+    assert_eq!(updated.spec.unwrap().replicas, Some(4));
+    assert_eq!(updated.metadata.labels.unwrap()["owner"], "ops");
+
+    let web = api.get("web").await.unwrap();
+    let error = kuboard_replace_with_retry(&api, "web", web.clone(), false, |deployment| {
+        concurrent_edit("platform");
+        deployment.spec.as_mut().unwrap().replicas = Some(1);
+        Ok(())
+    }).await.unwrap_err();
+    let ConflictError(report) = error.downcast_ref::<ConflictError>().unwrap();
+    assert_eq!(report.attempts, CONFLICT_RETRY_ATTEMPTS);
+    // The concurrent writer's stale copy also put replicas back to 2
+    assert_eq!(report.changed_fields, vec!["/metadata/labels/owner", "/spec/replicas"]);
+    assert_eq!(report.namespace.as_deref(), Some("default"));
+}
+
+#[tokio::test]
+async fn test_mock_cluster_undo_delete() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+    *app.state::<AppState>().current_context.write().await = Some(MOCK_CONTEXT_NAME.to_string());
+
+    commands::kuboard_delete_service("web".to_string(), "default".to_string(), Some("undo-web".to_string()), None, app.state())
+        .await
+        .unwrap();
+    // Controller-owned pods come back on their own, so they aren't kept
+    commands::kuboard_delete_pod("web-7d9f8-abc12".to_string(), "default".to_string(), None, None, None, None, app.state())
+        .await
+        .unwrap();
+
+    let trash = commands::kuboard_list_trash(app.state()).await.unwrap();
+    assert_eq!(trash.len(), 1);
+    assert_eq!(trash[0].operation_id, "undo-web");
+    assert!(trash[0].manifest["spec"].get("clusterIP").is_none());
+    assert!(commands::kuboard_get_service("web".to_string(), "default".to_string(), app.state()).await.is_err());
+
+    commands::kuboard_undo_delete("undo-web".to_string(), app.state()).await.unwrap();
+    let service = commands::kuboard_get_service("web".to_string(), "default".to_string(), app.state())
+        .await
+        .unwrap();
+    assert_eq!(service.spec.unwrap().selector.unwrap()["app"], "web");
+    assert!(commands::kuboard_list_trash(app.state()).await.unwrap().is_empty());
+    assert!(commands::kuboard_undo_delete("undo-web".to_string(), app.state()).await.is_err());
+}
+
+#[tokio::test]
+async fn test_mock_cluster_delete_resource() {
+    use serde_json::json;
+
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+    *app.state::<AppState>().current_context.write().await = Some(MOCK_CONTEXT_NAME.to_string());
+    let delete = |api_version: &str, kind: &str, name: &str, namespace: Option<&str>, propagation: Option<&str>, dry_run: Option<bool>| {
+        commands::kuboard_delete_resource(
+            api_version.to_string(), kind.to_string(), name.to_string(), namespace.map(str::to_string),
+            propagation.map(str::to_string), Some(format!("undo-{}", name)), dry_run, app.state(),
+        )
+    };
+
+    let preview = delete("v1", "ConfigMap", "web-config", Some("default"), None, Some(true)).await.unwrap();
+    assert_eq!(preview, "ConfigMap default/web-config would be deleted (dry run)");
+    assert!(commands::kuboard_list_trash(app.state()).await.unwrap().is_empty());
+
+    let deleted = delete("v1", "ConfigMap", "web-config", Some("default"), Some("Foreground"), None).await.unwrap();
+    assert_eq!(deleted, "ConfigMap default/web-config deleted successfully");
+    let again = delete("v1", "ConfigMap", "web-config", Some("default"), None, None).await.unwrap();
+    assert_eq!(again, "ConfigMap default/web-config not found (already deleted)");
+    commands::kuboard_undo_delete("undo-web-config".to_string(), app.state()).await.unwrap();
+
+    cluster.insert(json!({
+        "apiVersion": "rbac.authorization.k8s.io/v1", "kind": "ClusterRole",
+        "metadata": { "name": "viewer" },
+        "rules": [],
+    }));
+    let cluster_scoped = delete("rbac.authorization.k8s.io/v1", "ClusterRole", "viewer", Some("default"), Some("orphan"), None).await.unwrap();
+    assert_eq!(cluster_scoped, "ClusterRole viewer deleted successfully");
+
+    assert!(delete("v1", "ConfigMap", "web-config", None, None, None).await.unwrap_err().contains("namespace is required"));
+    assert!(delete("v1", "ConfigMap", "web-config", Some("default"), Some("cascade"), None).await.is_err());
+    assert!(delete("example.com/v1", "Widget", "w", Some("default"), None, None).await.unwrap_err().starts_with("Unknown kind Widget"));
+}
+
+#[tokio::test]
+async fn test_mock_cluster_dynamic_get_and_list() {
+    use serde_json::json;
+
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+    let get = |group: &str, version: &str, kind: &str, name: &str, namespace: Option<&str>| {
+        commands::kuboard_get_resource(
+            group.to_string(), version.to_string(), kind.to_string(), name.to_string(), namespace.map(str::to_string), app.state(),
+        )
+    };
+    let list = |group: &str, version: &str, kind: &str, namespace: Option<&str>, selector: Option<&str>| {
+        commands::kuboard_list_resources(
+            group.to_string(), version.to_string(), kind.to_string(), namespace.map(str::to_string), selector.map(str::to_string), None, app.state(),
+        )
+    };
+
+    let config = get("", "v1", "ConfigMap", "web-config", Some("default")).await.unwrap();
+    assert_eq!(config.metadata.name.as_deref(), Some("web-config"));
+    assert_eq!(config.types.map(|t| t.kind).as_deref(), Some("ConfigMap"));
+    assert!(get("core", "v1", "ConfigMap", "web-config", Some("default")).await.is_ok());
+    assert_eq!(get("", "v1", "ConfigMap", "nope", Some("default")).await.unwrap_err(), "ConfigMap default/nope not found");
+    assert!(get("", "v1", "ConfigMap", "web-config", None).await.unwrap_err().contains("namespace is required"));
+
+    let replica_sets = list("apps", "v1", "ReplicaSet", None, Some("app=web")).await.unwrap();
+    assert_eq!(replica_sets.len(), 1);
+    assert_eq!(replica_sets[0].data["spec"]["replicas"], 2);
+    assert!(list("apps", "v1", "Deployment", Some("kube-system"), None).await.unwrap().is_empty());
+
+    cluster.insert(json!({
+        "apiVersion": "rbac.authorization.k8s.io/v1", "kind": "ClusterRole",
+        "metadata": { "name": "viewer" },
+        "rules": [],
+    }));
+    let roles = list("rbac.authorization.k8s.io", "v1", "ClusterRole", Some("default"), None).await.unwrap();
+    assert!(roles.iter().any(|role| role.metadata.name.as_deref() == Some("viewer")));
+    assert_eq!(get("rbac.authorization.k8s.io", "v1", "ClusterRole", "missing", None).await.unwrap_err(), "ClusterRole missing not found");
+    assert!(list("example.com", "v1", "Widget", None, None).await.unwrap_err().starts_with("Unknown kind Widget in example.com/v1"));
+}
+
+#[tokio::test]
+async fn test_mock_cluster_resource_table_printer_columns() {
+    use serde_json::json;
+
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+    let table = |group: &str, kind: &str, namespace: Option<&str>| {
+        commands::kuboard_list_resource_table(
+            group.to_string(), "v1".to_string(), kind.to_string(), namespace.map(str::to_string), None, None, app.state(),
+        )
+    };
+    cluster.insert(json!({
+        "apiVersion": "cert-manager.io/v1", "kind": "Certificate",
+        "metadata": { "name": "web-cert", "namespace": "default" },
+        "spec": { "secretName": "web-tls" },
+        "status": { "conditions": [{ "type": "Ready", "status": "True" }] },
+    }));
+
+    // Without the CRD readable the API server's defaults are used
+    let defaults = table("cert-manager.io", "Certificate", Some("default")).await.unwrap();
+    assert_eq!(defaults.columns.iter().map(|c| c.name.as_str()).collect::<Vec<_>>(), vec!["Name", "Age"]);
+
+    cluster.insert(json!({
+        "apiVersion": "apiextensions.k8s.io/v1", "kind": "CustomResourceDefinition",
+        "metadata": { "name": "certificates.cert-manager.io" },
+        "spec": {
+            "group": "cert-manager.io",
+            "names": { "kind": "Certificate", "plural": "certificates" },
+            "scope": "Namespaced",
+            "versions": [{
+                "name": "v1", "served": true, "storage": true,
+                "additionalPrinterColumns": [
+                    { "name": "Ready", "type": "string", "jsonPath": ".status.conditions[?(@.type==\"Ready\")].status" },
+                    { "name": "Secret", "type": "string", "jsonPath": ".spec.secretName" },
+                    { "name": "Issuer", "type": "string", "jsonPath": ".spec.issuerRef.name", "priority": 1 },
+                ],
+            }],
+        },
+    }));
+    let certificates = table("cert-manager.io", "Certificate", None).await.unwrap();
+    assert_eq!(certificates.columns.iter().map(|c| c.name.as_str()).collect::<Vec<_>>(), vec!["Name", "Ready", "Secret", "Issuer"]);
+    assert_eq!(certificates.rows.len(), 1);
+    assert_eq!(certificates.rows[0].cells, vec![Some("web-cert".to_string()), Some("True".to_string()), Some("web-tls".to_string()), None]);
+
+    let config_maps = table("", "ConfigMap", Some("default")).await.unwrap();
+    assert!(config_maps.rows.iter().any(|row| row.name == "web-config"));
+    assert_eq!(config_maps.columns.len(), 2);
+}
+
+#[tokio::test]
+async fn test_mock_cluster_dry_run_persists_nothing() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+    *app.state::<AppState>().current_context.write().await = Some(MOCK_CONTEXT_NAME.to_string());
+
+    let scaled = commands::kuboard_scale_deployment("web".to_string(), "default".to_string(), 7, Some(true), app.state()).await.unwrap();
+    assert_eq!(scaled.spec.unwrap().replicas, Some(7));
+    let restarted = commands::kuboard_restart_statefulset("db".to_string(), "default".to_string(), Some(true), app.state()).await.unwrap();
+    assert!(restarted.spec.unwrap().template.metadata.unwrap().annotations.unwrap().contains_key("kubectl.kubernetes.io/restartedAt"));
+    let deployment = commands::kuboard_get_deployment("web".to_string(), "default".to_string(), app.state()).await.unwrap();
+    assert_eq!(deployment.spec.unwrap().replicas, Some(2));
+
+    let deleted = commands::kuboard_delete_service("web".to_string(), "default".to_string(), Some("dry-web".to_string()), Some(true), app.state())
+        .await
+        .unwrap();
+    assert_eq!(deleted, "Service default/web would be deleted (dry run)");
+    commands::kuboard_restart_pod("db-0".to_string(), "default".to_string(), Some(true), app.state()).await.unwrap();
+    assert!(commands::kuboard_get_service("web".to_string(), "default".to_string(), app.state()).await.is_ok());
+    assert_eq!(commands::kuboard_get_pods(None, None, None, app.state()).await.unwrap().len(), 5);
+    assert!(commands::kuboard_list_trash(app.state()).await.unwrap().is_empty());
+
+    let manifest = "apiVersion: v1\nkind: ConfigMap\nmetadata:\n  name: preview\ndata:\n  mode: fast\n";
+    let applied = commands::kuboard_apply_manifest(manifest.to_string(), Some("default".to_string()), None, Some(true), app.state()).await.unwrap();
+    assert_eq!(applied[0].outcome, ApplyOutcome::Created);
+    assert!(commands::kuboard_get_configmaps(Some("default".to_string()), None, None, app.state()).await.unwrap().iter().all(|c| c.metadata.name.as_deref() != Some("preview")));
+}
+
+#[tokio::test]
+async fn test_mock_cluster_export_clean_yaml() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    let exported = commands::kuboard_export_clean_yaml("Deployment".to_string(), "web".to_string(), "default".to_string(), None, app.state())
+        .await
+        .unwrap();
+    assert!(exported.starts_with("apiVersion: apps/v1\n"));
+    let manifest: serde_json::Value = serde_yaml::from_str(&exported).unwrap();
+    assert_eq!(manifest["kind"], "Deployment");
+    assert_eq!(manifest["metadata"]["name"], "web");
+    assert!(manifest.get("status").is_none());
+    assert!(manifest["metadata"].get("resourceVersion").is_none());
+    assert!(manifest["metadata"].get("uid").is_none());
+
+    let json = commands::kuboard_export_clean_yaml("Deployment".to_string(), "web".to_string(), "default".to_string(), Some(ManifestFormat::Json), app.state())
+        .await
+        .unwrap();
+    assert_eq!(serde_json::from_str::<serde_json::Value>(&json).unwrap(), manifest);
+
+    let missing = commands::kuboard_export_clean_yaml("Deployment".to_string(), "nope".to_string(), "default".to_string(), None, app.state()).await;
+    assert_eq!(missing.unwrap_err(), "Deployment default/nope not found");
+}
+
+#[tokio::test]
+async fn test_mock_cluster_pod_yaml_round_trip() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+    let pods = commands::kuboard_get_pods(Some("default".to_string()), None, None, app.state()).await.unwrap();
+    let name = pods[0].metadata.name.clone().unwrap();
+
+    let yaml = commands::kuboard_get_pod_yaml(name.clone(), "default".to_string(), None, app.state()).await.unwrap();
+    assert!(yaml.starts_with("apiVersion: v1\nkind: Pod\n"));
+
+    // What the user edits and sends back is the YAML they were given
+    let mut pod: serde_json::Value = serde_yaml::from_str(&yaml).unwrap();
+    pod["metadata"]["labels"]["edited"] = serde_json::json!("yes");
+
+    // A controller writes while the editor is open; the stale resourceVersion no longer fails the save
+    let mut concurrent = pod.clone();
+    concurrent["metadata"]["labels"] = serde_json::json!({ "controller": "touched" });
+    cluster.insert(concurrent);
+
+    commands::kuboard_update_pod_from_yaml(name.clone(), "default".to_string(), serde_yaml::to_string(&pod).unwrap(), None, None, app.state())
+        .await
+        .unwrap();
+
+    let json = commands::kuboard_get_pod_yaml(name.clone(), "default".to_string(), Some(ManifestFormat::Json), app.state()).await.unwrap();
+    let updated: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(updated["metadata"]["labels"]["edited"], "yes");
+    assert_eq!(updated["metadata"]["labels"]["controller"], "touched");
+
+    // Apply would create a pod that isn't there; an edit must not
+    pod["metadata"]["name"] = serde_json::json!("nope");
+    let missing = commands::kuboard_update_pod_from_yaml("nope".to_string(), "default".to_string(), serde_yaml::to_string(&pod).unwrap(), None, None, app.state()).await;
+    assert_eq!(missing.unwrap_err(), "Pod default/nope not found");
+}
+
+#[tokio::test]
+async fn test_mock_cluster_resource_yaml_edit() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+    let yaml = commands::kuboard_get_deployment_yaml("web".to_string(), "default".to_string(), None, app.state()).await.unwrap();
+    let mut edited: serde_json::Value = serde_yaml::from_str(&yaml).unwrap();
+    edited["spec"]["replicas"] = serde_json::json!(4);
+
+    // Someone scales the deployment while the editor is open
+    let mut concurrent = edited.clone();
+    concurrent["spec"]["replicas"] = serde_json::json!(6);
+    cluster.insert(concurrent);
+
+    let update = |yaml: String, force: Option<bool>| {
+        commands::kuboard_update_resource_from_yaml("Deployment".to_string(), "web".to_string(), "default".to_string(), yaml, force, None, app.state())
+    };
+    let stale = update(serde_yaml::to_string(&edited).unwrap(), None).await.unwrap_err();
+    let report: serde_json::Value = serde_json::from_str(&stale).unwrap();
+    assert_eq!(report["reason"], "Conflict");
+    assert_eq!(report["changed_fields"], serde_json::json!(["/spec/replicas"]));
+
+    update(serde_yaml::to_string(&edited).unwrap(), Some(true)).await.unwrap();
+    let deployment = commands::kuboard_get_deployment("web".to_string(), "default".to_string(), app.state()).await.unwrap();
+    assert_eq!(deployment.spec.unwrap().replicas, Some(4));
+
+    // A clean export has no resourceVersion to check, so it replaces the latest version
+    let export = commands::kuboard_export_clean_yaml("Service".to_string(), "web".to_string(), "default".to_string(), None, app.state()).await.unwrap();
+    let mut service: serde_json::Value = serde_yaml::from_str(&export).unwrap();
+    service["metadata"]["labels"] = serde_json::json!({ "edited": "yes" });
+    let saved = commands::kuboard_update_resource_from_yaml(
+        "Service".to_string(), "web".to_string(), "default".to_string(), serde_json::to_string(&service).unwrap(), None, None, app.state(),
+    ).await.unwrap();
+    assert_eq!(saved, "Service default/web updated successfully");
+
+    let wrong_kind = update(export.clone(), None).await.unwrap_err();
+    assert!(wrong_kind.contains("Kind mismatch: expected Deployment, got Service"));
+    service["metadata"]["name"] = serde_json::json!("nope");
+    let missing = commands::kuboard_update_resource_from_yaml(
+        "Service".to_string(), "nope".to_string(), "default".to_string(), serde_json::to_string(&service).unwrap(), None, None, app.state(),
+    ).await;
+    assert!(missing.unwrap_err().ends_with("Service default/nope not found"));
+}
+
+#[tokio::test]
+async fn test_mock_cluster_field_managers() {
+    use k8s_openapi::api::apps::v1::Deployment;
+    use kube::Api;
+
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+    let api: Api<Deployment> = Api::namespaced(cluster.client(), "default");
+    let mut web = serde_json::to_value(api.get("web").await.unwrap()).unwrap();
+    web["metadata"]["managedFields"] = serde_json::json!([
+        { "manager": "argocd-controller", "operation": "Apply", "apiVersion": "apps/v1", "fieldsType": "FieldsV1",
+          "fieldsV1": { "f:spec": { "f:replicas": {} } } },
+        { "manager": "kuboard", "operation": "Update", "apiVersion": "apps/v1", "fieldsType": "FieldsV1",
+          "fieldsV1": { "f:spec": { "f:replicas": {} } } },
+    ]);
+    cluster.insert(web);
+
+    let ownership = commands::kuboard_get_field_managers("Deployment".to_string(), "web".to_string(), "default".to_string(), app.state())
+        .await
+        .unwrap();
+    assert_eq!(ownership.managers.len(), 2);
+    assert_eq!(ownership.shared_fields.len(), 1);
+    assert_eq!(ownership.shared_fields[0].path, ".spec.replicas");
+    assert_eq!(ownership.shared_fields[0].managers, vec!["argocd-controller", "kuboard"]);
+}
+
+#[tokio::test]
+async fn test_mock_cluster_pod_security_labels() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    let labels = commands::kuboard_set_pod_security_labels("default".to_string(), Some("baseline".to_string()), None, Some("restricted".to_string()), app.state())
+        .await
+        .unwrap();
+    assert_eq!(labels.enforce.as_deref(), Some("baseline"));
+    assert_eq!(labels.warn.as_deref(), Some("restricted"));
+
+    let invalid = commands::kuboard_set_pod_security_labels("default".to_string(), Some("strict".to_string()), None, None, app.state()).await;
+    assert!(invalid.is_err());
+
+    let cleared = commands::kuboard_set_pod_security_labels("default".to_string(), None, None, Some(String::new()), app.state())
+        .await
+        .unwrap();
+    assert_eq!(cleared.enforce.as_deref(), Some("baseline"));
+    assert!(cleared.warn.is_none());
+
+    let check = commands::kuboard_dry_run_pod_security_level("default".to_string(), "restricted".to_string(), app.state())
+        .await
+        .unwrap();
+    assert!(check.pods_checked > 0);
+    assert_eq!(check.violations.len(), check.pods_checked);
+}
+
+#[tokio::test]
+async fn test_mock_cluster_service_account_usage() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    let usage = commands::kuboard_get_service_account_usage("default".to_string(), app.state())
+        .await
+        .unwrap();
+    let default = usage.iter().find(|sa| sa.name == "default").unwrap();
+
+    assert!(default.exists);
+    let workloads: Vec<(&str, &str)> = default.workloads.iter().map(|w| (w.kind.as_str(), w.name.as_str())).collect();
+    assert!(workloads.contains(&("Deployment", "web")));
+    assert!(workloads.contains(&("StatefulSet", "db")));
+    assert!(workloads.contains(&("CronJob", "nightly-report")));
+    // Controller-owned pods are reported through their workload
+    assert!(!workloads.iter().any(|(kind, _)| *kind == "Pod"));
+
+    assert_eq!(default.grants.len(), 1);
+    assert_eq!(default.grants[0].role_name, "config-reader");
+    assert_eq!(default.grants[0].rules[0].verbs, vec!["get", "list", "watch"]);
+}
+
+#[tokio::test]
+async fn test_mock_cluster_who_can() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    let readers = commands::kuboard_who_can("list".to_string(), "configmaps".to_string(), Some("default".to_string()), app.state())
+        .await
+        .unwrap();
+    assert_eq!(readers.len(), 1);
+    assert_eq!(readers[0].subject_kind, "ServiceAccount");
+    assert_eq!(readers[0].binding_name, "default-config-reader");
+
+    let deleters = commands::kuboard_who_can("delete".to_string(), "configmaps".to_string(), Some("default".to_string()), app.state())
+        .await
+        .unwrap();
+    assert!(deleters.is_empty());
+}
+
+#[tokio::test]
+async fn test_mock_cluster_scan_best_practices() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    let reports = commands::kuboard_scan_best_practices(None, app.state()).await.unwrap();
+    let namespaces: Vec<&str> = reports.iter().map(|r| r.namespace.as_str()).collect();
+    assert_eq!(namespaces, vec!["default", "kube-system"]);
+
+    let default = &reports[0];
+    assert!(default.score < 100);
+    // The seeded workloads have no limits and run as the default service account
+    assert!(default.findings.iter().any(|f| f.check == "resource-limits" && f.name == "web"));
+    assert!(default.findings.iter().any(|f| f.check == "default-service-account"));
+
+    let scoped = commands::kuboard_scan_best_practices(Some("kube-system".to_string()), app.state()).await.unwrap();
+    assert_eq!(scoped.len(), 1);
+    assert_eq!(scoped[0].workloads_scanned, 1);
+}
+
+#[tokio::test]
+async fn test_mock_cluster_scan_deprecated_apis() {
+    let cluster = MockCluster::new();
+    cluster.insert(serde_json::json!({
+        "apiVersion": "networking.k8s.io/v1",
+        "kind": "Ingress",
+        "metadata": {
+            "name": "legacy",
+            "namespace": "default",
+            "annotations": { "meta.helm.sh/release-name": "legacy-web" },
+            "managedFields": [{ "manager": "helm", "operation": "Update", "apiVersion": "networking.k8s.io/v1beta1" }],
+        },
+        "spec": {},
+    }));
+    let app = mock_app(&cluster);
+
+    let report = commands::kuboard_scan_deprecated_apis(None, app.state()).await.unwrap();
+    assert_eq!(report.server_version, "1.31");
+    assert_eq!(report.target_version, "1.33");
+    assert_eq!(report.findings.len(), 1);
+    let legacy = &report.findings[0];
+    assert_eq!(legacy.name, "legacy");
+    assert!(legacy.already_removed);
+    assert_eq!(legacy.writers, vec!["helm".to_string()]);
+    assert_eq!(legacy.helm_release.as_deref(), Some("legacy-web"));
+}
+
+#[tokio::test]
+async fn test_mock_cluster_upgrade_readiness() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    let report = commands::kuboard_get_upgrade_readiness(None, app.state()).await.unwrap();
+    assert_eq!(report.target_version, "1.32");
+    assert!(report.ready);
+    let flagged: Vec<(&str, &str)> = report.issues.iter().map(|i| (i.name.as_str(), i.category.as_str())).collect();
+    assert_eq!(flagged, vec![("web", "pdb-coverage"), ("db", "single-replica")]);
+
+    cluster.insert(serde_json::json!({
+        "apiVersion": "policy/v1",
+        "kind": "PodDisruptionBudget",
+        "metadata": { "name": "web", "namespace": "default" },
+        "spec": { "maxUnavailable": 0, "selector": { "matchLabels": { "app": "web" } } },
+        "status": { "disruptionsAllowed": 0, "currentHealthy": 2, "desiredHealthy": 2, "expectedPods": 2 },
+    }));
+    let report = commands::kuboard_get_upgrade_readiness(None, app.state()).await.unwrap();
+    assert!(!report.ready);
+    assert!(report.issues.iter().any(|i| i.name == "web" && i.message.contains("node drains will hang")));
+
+    let err = commands::kuboard_get_upgrade_readiness(Some("1.30".to_string()), app.state()).await.unwrap_err();
+    assert!(err.contains("not newer"));
+}
+
+#[tokio::test]
+async fn test_mock_cluster_resilience_report() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    let report = commands::kuboard_get_resilience_report(Some("default".to_string()), app.state()).await.unwrap();
+    let web = report.iter().find(|w| w.name == "web").unwrap();
+    assert!(!web.has_pdb);
+    assert_eq!(web.nodes, vec!["mock-node-1".to_string(), "mock-node-2".to_string()]);
+    assert_eq!(web.gaps, vec![
+        "No PodDisruptionBudget".to_string(),
+        "No topology spread constraints or pod anti-affinity".to_string(),
+    ]);
+    let db = report.iter().find(|w| w.name == "db").unwrap();
+    assert_eq!(db.replicas, 1);
+    assert_eq!(db.gaps, vec!["Single replica on node mock-node-1; any node disruption takes it down".to_string()]);
+}
+
+#[tokio::test]
+async fn test_mock_cluster_placement_heatmap() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    let heatmap = commands::kuboard_get_placement_heatmap(Some("workload".to_string()), Some("default".to_string()), app.state()).await.unwrap();
+    let web = heatmap.rows.iter().find(|r| r.workload_name.as_deref() == Some("web")).unwrap();
+    assert_eq!(web.workload_kind.as_deref(), Some("Deployment"));
+    assert_eq!(web.total_pods, 2);
+    assert_eq!(web.cells.len(), heatmap.nodes.len());
+    assert_eq!(web.pods_by_zone.len(), 2);
+    assert_eq!(web.cells.iter().map(|c| c.cpu_requests_millicores).sum::<u64>(), 200);
+
+    let err = commands::kuboard_get_placement_heatmap(Some("zone".to_string()), None, app.state()).await.unwrap_err();
+    assert!(err.contains("Unknown grouping"));
+}
+
+#[tokio::test]
+async fn test_mock_cluster_topology_zones() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    let zones = commands::kuboard_get_topology_zones(app.state()).await.unwrap();
+    assert!(zones.len() >= 2);
+    assert!(zones.iter().all(|z| z.region.as_deref() == Some("mock-region") && z.nodes.len() == 1));
+
+    // web's replicas are spread over two zones; nothing else runs more than one pod
+    let concentrated = commands::kuboard_get_zone_concentration(None, app.state()).await.unwrap();
+    assert!(concentrated.is_empty());
+
+    for index in 0..2 {
+        cluster.insert(serde_json::json!({
+            "apiVersion": "v1",
+            "kind": "Pod",
+            "metadata": {
+                "name": format!("cache-{}", index),
+                "namespace": "default",
+                "ownerReferences": [{ "apiVersion": "apps/v1", "kind": "StatefulSet", "name": "cache", "uid": "mock-cache-uid", "controller": true }],
+            },
+            "spec": { "nodeName": "mock-node-1", "containers": [{ "name": "redis", "image": "redis:7" }] },
+            "status": { "phase": "Running" },
+        }));
+    }
+    let concentrated = commands::kuboard_get_zone_concentration(Some("default".to_string()), app.state()).await.unwrap();
+    assert_eq!(concentrated.len(), 1);
+    assert_eq!((concentrated[0].workload_name.as_str(), concentrated[0].zone.as_str()), ("cache", "mock-zone-1"));
+}
+
+#[tokio::test]
+async fn test_mock_cluster_probe_service_endpoints() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    let report = commands::kuboard_probe_service_endpoints(
+        "web".to_string(), "default".to_string(), Some("http".to_string()), None, Some("/healthz".to_string()), app.state(),
+    ).await.unwrap();
+    assert_eq!(report.endpoints.len(), 2);
+    assert_eq!(report.reachable, 2);
+    assert!(report.endpoints.iter().all(|e| e.status_code == Some(200) && e.latency_ms.is_some()));
+
+    let mut pod = serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "Pod",
+        "metadata": { "name": "web-7d9f8-def34", "namespace": "default", "labels": { "app": "web" } },
+        "spec": { "nodeName": "mock-node-2", "containers": [{ "name": "nginx", "image": "nginx:1.27" }] },
+    });
+    pod["status"] = serde_json::json!({ "phase": "Pending" });
+    cluster.insert(pod);
+    let report = commands::kuboard_probe_service_endpoints(
+        "web".to_string(), "default".to_string(), Some("http".to_string()), Some(80), None, app.state(),
+    ).await.unwrap();
+    assert_eq!(report.reachable, 1);
+    let down = report.endpoints.iter().find(|e| e.pod_name.as_deref() == Some("web-7d9f8-def34")).unwrap();
+    assert_eq!(down.status_code, Some(503));
+
+    let err = commands::kuboard_probe_service_endpoints(
+        "missing".to_string(), "default".to_string(), None, None, None, app.state(),
+    ).await.unwrap_err();
+    assert_eq!(err, "Endpoints default/missing not found");
+}
+
+#[tokio::test]
+async fn test_mock_cluster_http_probe_resolves_service() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    let err = commands::kuboard_http_probe("svc/web".to_string(), "default".to_string(), 8080, None, None, None, app.state())
+        .await.unwrap_err();
+    assert_eq!(err, "HTTP probe failed: Service default/web has no port 8080");
+
+    let err = commands::kuboard_http_probe("svc/missing".to_string(), "default".to_string(), 80, None, None, None, app.state())
+        .await.unwrap_err();
+    assert_eq!(err, "svc/missing in namespace default not found");
+}
+
+#[tokio::test]
+async fn test_mock_cluster_cluster_dns() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    let summary = commands::kuboard_get_cluster_dns(app.state()).await.unwrap();
+    assert_eq!(summary.provider, "unknown");
+    assert!(summary.pods.is_empty());
+
+    cluster.insert(serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "ConfigMap",
+        "metadata": { "name": "coredns", "namespace": "kube-system" },
+        "data": { "Corefile": ".:53 {\n    errors\n    forward . 8.8.8.8\n}\ncorp.example:53 {\n    forward . 10.1.0.53\n}\n" },
+    }));
+    cluster.insert(serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "Service",
+        "metadata": { "name": "kube-dns", "namespace": "kube-system" },
+        "spec": { "clusterIP": "10.96.0.10", "ports": [{ "name": "dns", "port": 53, "protocol": "UDP" }] },
+    }));
+    cluster.insert(serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "Endpoints",
+        "metadata": { "name": "kube-dns", "namespace": "kube-system" },
+        "subsets": [{ "addresses": [{ "ip": "10.244.0.2" }], "notReadyAddresses": [{ "ip": "10.244.0.3" }] }],
+    }));
+    cluster.insert(serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "Pod",
+        "metadata": { "name": "coredns-5d78c", "namespace": "kube-system", "labels": { "k8s-app": "kube-dns" } },
+        "spec": { "nodeName": "mock-node-1", "containers": [{ "name": "coredns", "image": "coredns/coredns:1.11.1" }] },
+        "status": { "phase": "Running" },
+    }));
+    cluster.set_pod_logs("kube-system", "coredns-5d78c", "[INFO] Reloading\n[ERROR] plugin/errors: 2 api.corp.example. A: i/o timeout\n");
+
+    let summary = commands::kuboard_get_cluster_dns(app.state()).await.unwrap();
+    assert_eq!(summary.provider, "coredns");
+    assert_eq!(summary.upstream_forwarders, vec!["8.8.8.8".to_string()]);
+    assert_eq!(summary.stub_domains["corp.example:53"], vec!["10.1.0.53".to_string()]);
+    assert_eq!(summary.service_ip.as_deref(), Some("10.96.0.10"));
+    assert_eq!((summary.ready_endpoints, summary.not_ready_endpoints), (1, 1));
+    assert_eq!(summary.pods.len(), 1);
+    assert_eq!(summary.recent_errors.len(), 1);
+    assert_eq!(summary.recent_errors[0].pod, "coredns-5d78c");
+}
+
+#[tokio::test]
+async fn test_mock_cluster_ingress_routes() {
+    let cluster = MockCluster::new();
+    cluster.insert(serde_json::json!({
+        "apiVersion": "networking.k8s.io/v1",
+        "kind": "IngressClass",
+        "metadata": { "name": "nginx" },
+        "spec": { "controller": "k8s.io/ingress-nginx" },
+    }));
+    cluster.insert(serde_json::json!({
+        "apiVersion": "networking.k8s.io/v1",
+        "kind": "Ingress",
+        "metadata": { "name": "web", "namespace": "default" },
+        "spec": {
+            "ingressClassName": "nginx",
+            "rules": [{
+                "host": "web.example.com",
+                "http": { "paths": [{ "path": "/", "pathType": "Prefix", "backend": { "service": { "name": "web", "port": { "number": 80 } } } }] },
+            }],
+        },
+    }));
+    let app = mock_app(&cluster);
+
+    let table = commands::kuboard_get_ingress_routes(app.state()).await.unwrap();
+    assert_eq!(table.controllers.len(), 1);
+    assert_eq!(table.controllers[0].ingress_classes, vec!["nginx".to_string()]);
+    assert_eq!(table.routes.len(), 1);
+
+    let route = commands::kuboard_lookup_ingress_route("https://web.example.com/index.html".to_string(), app.state()).await.unwrap();
+    assert_eq!(route.map(|r| r.service), Some("web".to_string()));
+    let route = commands::kuboard_lookup_ingress_route("https://other.example.com/".to_string(), app.state()).await.unwrap();
+    assert!(route.is_none());
+}
+
+#[tokio::test]
+async fn test_mock_cluster_tls_secrets() {
+    let cluster = MockCluster::new();
+    cluster.insert(serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "Secret",
+        "metadata": { "name": "shop-tls", "namespace": "default" },
+        "type": "kubernetes.io/tls",
+        "data": { "tls.crt": "LS0tLS1CRUdJTiBDRVJUSUZJQ0FURS0tLS0tCk1JSUI4akNDQVppZ0F3SUJBZ0lVSkJiSWlHc25ueE9MTThicENXeThkZ3lNdzdzd0NnWUlLb1pJemowRUF3SXcKTWpFWk1CY0dBMVVFQXd3UWMyaHZjQzVsZUdGdGNHeGxMbU52YlRFVk1CTUdBMVVFQ2d3TVMzVmliMkZ5WkNCVQpaWE4wTUI0WERUSTJNVEF4TmpFeU5ERTBPVm9YRFRNMk1UQXhNekV5TkRFME9Wb3dNakVaTUJjR0ExVUVBd3dRCmMyaHZjQzVsZUdGdGNHeGxMbU52YlRFVk1CTUdBMVVFQ2d3TVMzVmliMkZ5WkNCVVpYTjBNRmt3RXdZSEtvWkkKemowQ0FRWUlLb1pJemowREFRY0RRZ0FFR3V1M1lKYUhLWFQ2UzRDajJiRjB0WXY0amZTWHppRGFEbWZKdEkvNQphWFJkUXFONkYvY016dEp1WGo4SFhLMmxnWUlFNHBYdEZxOTBVYVloWnF2RURLT0JpekNCaURBZEJnTlZIUTRFCkZnUVVKMEZjRzhsQmZaTXVQQzFTYlo1M3JOT2ozblV3SHdZRFZSMGpCQmd3Rm9BVUowRmNHOGxCZlpNdVBDMVMKYlo1M3JOT2ozblV3RHdZRFZSMFRBUUgvQkFVd0F3RUIvekExQmdOVkhSRUVMakFzZ2hCemFHOXdMbVY0WVcxdwpiR1V1WTI5dGdoSXFMbk5vYjNBdVpYaGhiWEJzWlM1amIyMkhCQW9BQUFFd0NnWUlLb1pJemowRUF3SURTQUF3ClJRSWhBS2FuZ3VIOXJ1bXVDdXJGeVRzeStLMVQ3anRtUHBMblhOWERFUjNQOFFSQUFpQVhUZEJtNWRlS25nc00KQTRCaEpWR2VxQ0dsRXN2d1A4UGhDVUFYc1hSb0tRPT0KLS0tLS1FTkQgQ0VSVElGSUNBVEUtLS0tLQo=" },
+    }));
+    cluster.insert(serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "Secret",
+        "metadata": { "name": "broken-tls", "namespace": "default" },
+        "type": "kubernetes.io/tls",
+        "data": { "tls.crt": "bm90IGEgY2VydA==" },
+    }));
+    let app = mock_app(&cluster);
+
+    let report = commands::kuboard_inspect_tls_secrets(None, None, app.state()).await.unwrap();
+    assert_eq!(report.expiry_window_days, 30);
+    // The Opaque web-secret is left out
+    assert_eq!(report.secrets.len(), 2);
+    assert_eq!(report.secrets[0].name, "shop-tls");
+    assert_eq!(report.secrets[0].certificates[0].subject_alt_names[0], "shop.example.com");
+    assert!(report.secrets[1].error.is_some());
+
+    // A window wider than the certificate's ten-year validity flags it
+    let report = commands::kuboard_inspect_tls_secrets(Some("default".to_string()), Some(10_000), app.state()).await.unwrap();
+    assert_eq!(report.expiring, 1);
+}
+
+#[tokio::test]
+async fn test_mock_cluster_restricted_namespace_discovery() {
+    let cluster = MockCluster::new();
+    cluster.insert(serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "Namespace",
+        "metadata": { "name": "team-b" },
+    }));
+    cluster.insert(serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "Pod",
+        "metadata": { "name": "worker-0", "namespace": "team-b" },
+        "spec": { "containers": [{ "name": "worker", "image": "busybox" }] },
+    }));
+    let app = mock_app(&cluster);
+    let all_pods = commands::kuboard_get_pods(None, None, None, app.state()).await.unwrap().len();
+
+    cluster.restrict_to_namespaces(&["default", "team-b"]);
+
+    let access = commands::kuboard_get_namespace_access(app.state()).await.unwrap();
+    assert_eq!(access.source, "rules-review");
+    assert_eq!(access.namespaces, vec!["default".to_string(), "team-b".to_string()]);
+
+    let namespaces = commands::kuboard_get_namespaces(app.state()).await.unwrap();
+    assert_eq!(namespaces.len(), 2);
+    assert_eq!(namespaces[0].status.as_ref().and_then(|s| s.phase.as_deref()), Some("Active"));
+
+    let pods = commands::kuboard_get_pods(None, None, None, app.state()).await.unwrap();
+    assert!(pods.len() < all_pods);
+    assert!(pods.iter().any(|p| p.metadata.name.as_deref() == Some("worker-0")));
+    assert!(pods.iter().all(|p| p.metadata.namespace.as_deref() != Some("kube-system")));
+
+    // Nodes are cluster-scoped and stay forbidden
+    assert!(commands::kuboard_get_nodes(app.state()).await.is_err());
+}
+
+#[tokio::test]
+async fn test_mock_cluster_namespace_filter() {
+    use k8s_openapi::api::apps::v1::DaemonSet;
+    use k8s_openapi::api::core::v1::Pod;
+    use kube::api::ListParams;
+
+    let cluster = MockCluster::new();
+    let client = cluster.client();
+
+    let hide_system = NamespaceFilter { allow: Vec::new(), deny: vec!["kube-*".to_string()] };
+    let namespaces = kuboard_list_accessible_namespaces(&client, &hide_system).await.unwrap();
+    assert_eq!(namespaces.iter().filter_map(|ns| ns.metadata.name.as_deref()).collect::<Vec<_>>(), vec!["default"]);
+    let pods: Vec<Pod> = kuboard_list_accessible(&client, &hide_system, &ListParams::default()).await.unwrap();
+    assert!(!pods.is_empty());
+    assert!(pods.iter().all(|p| p.metadata.namespace.as_deref() == Some("default")));
+
+    // A plain allow-list is queried namespace by namespace, so it works without cluster-wide access
+    cluster.restrict_to_namespaces(&["kube-system"]);
+    let only_system = NamespaceFilter { allow: vec!["kube-system".to_string()], deny: Vec::new() };
+    let daemonsets: Vec<DaemonSet> = kuboard_list_accessible(&client, &only_system, &ListParams::default()).await.unwrap();
+    assert_eq!(daemonsets.len(), 1);
+    let namespaces = kuboard_list_accessible_namespaces(&client, &only_system).await.unwrap();
+    assert_eq!(namespaces.len(), 1);
+}
+
+#[tokio::test]
+async fn test_mock_cluster_developer_profile() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+    *app.state::<AppState>().profile.write().await = KuboardProfile {
+        kind: ProfileKind::Developer,
+        namespaces: vec!["kube-system".to_string()],
+    };
+
+    let daemonsets = commands::kuboard_get_daemonsets(None, None, None, app.state()).await.unwrap();
+    assert_eq!(daemonsets.len(), 1);
+    assert!(commands::kuboard_get_deployments(None, None, None, app.state()).await.unwrap().is_empty());
+
+    let capabilities = commands::kuboard_get_capabilities(app.state()).await.unwrap();
+    assert_eq!(capabilities.profile.kind, ProfileKind::Developer);
+    assert!(!capabilities.commands.iter().any(|c| c == "kuboard_get_nodes"));
+    assert!(capabilities.commands.iter().any(|c| c == "kuboard_get_pods"));
+    assert!(!capabilities.features.helm_support);
+}
+
+#[tokio::test]
+async fn test_mock_cluster_shared_watch_stream() {
+    use k8s_openapi::api::core::v1::Service;
+    use kube::runtime::watcher;
+    use kuboard_lib::kubernetes::shared_watch::{kuboard_resolve_watched_object, kuboard_shared_watch_stream};
+    use kuboard_lib::kubernetes::watch::WatchEventType;
+    use tokio_stream::StreamExt;
+
+    let cluster = MockCluster::new();
+    let client = cluster.client();
+    let mut stream = kuboard_shared_watch_stream(&client, &[WatchKind::Pod, WatchKind::Service]);
+
+    // Both kinds finish their initial listing over the one stream
+    let mut synced = std::collections::HashSet::new();
+    let mut seeded_pods = 0;
+    while synced.len() < 2 {
+        let (kind, event) = stream.next().await.unwrap();
+        match event.unwrap() {
+            watcher::Event::InitApply(_) if kind == WatchKind::Pod => seeded_pods += 1,
+            watcher::Event::InitDone => { synced.insert(kind); }
+            _ => {}
+        }
+    }
+    assert!(seeded_pods > 0);
+
+    cluster.insert(serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "Service",
+        "metadata": { "name": "cache", "namespace": "default" },
+        "spec": { "ports": [{ "port": 6379 }] },
+    }));
+
+    let (kind, event) = tokio::time::timeout(std::time::Duration::from_secs(5), stream.next())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(kind, WatchKind::Service);
+    let meta = match event.unwrap() {
+        watcher::Event::Apply(meta) => meta,
+        other => panic!("unexpected watch event: {:?}", other),
+    };
+
+    // Changes are fetched in full; deletions are rebuilt from metadata alone
+    let service: Service = kuboard_resolve_watched_object(&client, meta.clone(), &WatchEventType::Added).await.unwrap();
+    assert_eq!(service.spec.unwrap().ports.unwrap()[0].port, 6379);
+    let deleted: Service = kuboard_resolve_watched_object(&client, meta, &WatchEventType::Deleted).await.unwrap();
+    assert_eq!(deleted.metadata.name.as_deref(), Some("cache"));
+    assert!(deleted.spec.is_none());
+}
+
+#[tokio::test]
+async fn test_mock_cluster_list_delta() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    let full = commands::kuboard_get_list_delta(WatchKind::Service, None, app.state()).await.unwrap();
+    assert!(full.full);
+    assert!(!full.upserted.is_empty());
+
+    cluster.insert(serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "Service",
+        "metadata": { "name": "cache", "namespace": "default" },
+        "spec": { "ports": [{ "port": 6379 }] },
+    }));
+    let delta = commands::kuboard_get_list_delta(WatchKind::Service, Some(full.revision.clone()), app.state()).await.unwrap();
+    assert!(!delta.full);
+    assert_eq!(delta.upserted.len(), 1);
+    assert_eq!(delta.upserted[0]["metadata"]["name"], "cache");
+    assert!(delta.deleted.is_empty());
+
+    // A revision the backend never issued falls back to a full resync
+    let resync = commands::kuboard_get_list_delta(WatchKind::Service, Some("stale".to_string()), app.state()).await.unwrap();
+    assert!(resync.full);
+    assert_eq!(resync.upserted.len(), full.upserted.len() + 1);
+}
+
+#[tokio::test]
+async fn test_mock_cluster_compressed_payloads() {
+    use kuboard_lib::kubernetes::compression::kuboard_decode_payload;
+
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+
+    let pods = commands::kuboard_get_pods(None, None, None, app.state()).await.unwrap();
+    let payload = commands::kuboard_get_list_compressed(WatchKind::Pod, vec![PayloadEncoding::Gzip], Some(0), app.state()).await.unwrap();
+    assert_eq!(payload.encoding, PayloadEncoding::Gzip);
+    let decoded: Vec<serde_json::Value> = serde_json::from_slice(&kuboard_decode_payload(&payload).unwrap()).unwrap();
+    assert_eq!(decoded.len(), pods.len());
+
+    // The default threshold leaves a single object's YAML uncompressed
+    let name = pods[0].metadata.name.clone().unwrap();
+    let namespace = pods[0].metadata.namespace.clone().unwrap();
+    let yaml = commands::kuboard_get_yaml_compressed(WatchKind::Pod, name.clone(), namespace.clone(), vec![PayloadEncoding::Zstd], None, None, app.state()).await.unwrap();
+    assert_eq!(yaml.encoding, PayloadEncoding::Identity);
+    let expected = commands::kuboard_get_pod_yaml(name, namespace.clone(), None, app.state()).await.unwrap();
+    assert_eq!(serde_json::from_str::<String>(&yaml.data).unwrap(), expected);
+
+    let missing = commands::kuboard_get_yaml_compressed(WatchKind::Pod, "missing".to_string(), namespace, vec![], None, None, app.state()).await;
+    assert!(missing.unwrap_err().contains("not found"));
+}
+
+#[tokio::test]
+async fn test_mock_cluster_context_switch_prefetch() {
+    let app = tauri::test::mock_app();
+    app.manage(AppState::new());
+    commands::kuboard_list_contexts(app.state()).await.unwrap();
+    commands::kuboard_set_context(MOCK_CONTEXT_NAME.to_string(), app.handle().clone(), app.state()).await.unwrap();
+
+    let state = app.state::<AppState>();
+    let warmed = async {
+        while state.prefetch.write().await.take(MOCK_CONTEXT_NAME, |data| data.pods.as_ref().map(|_| ())).is_none() {
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+    };
+    tokio::time::timeout(std::time::Duration::from_secs(5), warmed).await.unwrap();
+
+    // The keepalive's first ping warms the new client
+    let pinged = async {
+        loop {
+            if let Some(status) = commands::kuboard_get_connection_status(app.state()).await.unwrap() {
+                return status;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+    };
+    let status = tokio::time::timeout(std::time::Duration::from_secs(5), pinged).await.unwrap();
+    assert_eq!(status.server_version.as_deref(), Some("v1.31.0-kuboard-mock"));
+    assert!(status.error.is_none());
+
+    // The first request is served from the prefetch and consumes it
+    let pods = commands::kuboard_get_pods(None, None, None, app.state()).await.unwrap();
+    assert!(!pods.is_empty());
+    assert!(state.prefetch.write().await.take(MOCK_CONTEXT_NAME, |data| data.pods.take()).is_none());
+
+    let overview = commands::kuboard_get_cluster_overview(app.state()).await.unwrap();
+    assert_eq!(overview.pod_count, pods.len());
+    assert!(state.prefetch.write().await.take(MOCK_CONTEXT_NAME, |data| data.overview.take()).is_none());
+}
+
+#[tokio::test]
+async fn test_mock_cluster_search_manifests() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+    let search = |key: Option<&str>, value: Option<&str>, kinds: Option<Vec<String>>, namespace: Option<&str>| {
+        commands::kuboard_search_manifests(
+            key.map(str::to_string), value.map(str::to_string), kinds, namespace.map(str::to_string), None, None, app.state(),
+        )
+    };
+
+    let busybox = search(Some("image"), Some("busybox"), None, None).await.unwrap();
+    let found: Vec<(&str, &str)> = busybox.iter().map(|hit| (hit.kind.as_str(), hit.name.as_str())).collect();
+    assert_eq!(found, vec![("DaemonSet", "node-agent"), ("CronJob", "nightly-report")]);
+    let in_default = search(Some("image"), Some("busybox"), None, Some("default")).await.unwrap();
+    assert_eq!(in_default.len(), 1);
+    assert_eq!(in_default[0].matches[0].path, "spec.jobTemplate.spec.template.spec.containers[0].image");
+
+    let log_level = search(Some("data.LOG_LEVEL"), None, Some(vec!["configmap".to_string()]), Some("default")).await.unwrap();
+    assert_eq!(log_level[0].name, "web-config");
+    assert_eq!(log_level[0].matches[0].value, "info");
+
+    assert!(search(None, None, None, None).await.is_err());
+    assert!(search(Some("image"), None, Some(vec!["Secret".to_string()]), None).await.is_err());
+}
+
+#[tokio::test]
+async fn test_mock_cluster_list_pages() {
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+    let page = |namespace: Option<&str>, token: Option<String>| {
+        commands::kuboard_get_pods_page(namespace.map(str::to_string), None, None, Some(2), token, app.state())
+    };
+
+    let mut names = Vec::new();
+    let mut token = None;
+    let mut pages = 0;
+    loop {
+        let next = page(None, token).await.unwrap();
+        assert!(next.items.len() <= 2);
+        names.extend(next.items.into_iter().filter_map(|pod| pod.metadata.name));
+        pages += 1;
+        match next.continue_token {
+            Some(next_token) => token = Some(next_token),
+            None => break,
+        }
+    }
+    assert_eq!((pages, names.len()), (3, 5));
+    let all = commands::kuboard_get_pods(None, None, None, app.state()).await.unwrap();
+    assert_eq!(all.len(), 5);
+
+    let first = page(None, None).await.unwrap();
+    assert_eq!(first.remaining_item_count, Some(3));
+    let kube_system = page(Some("kube-system"), None).await.unwrap();
+    assert_eq!((kube_system.items.len(), kube_system.continue_token), (2, None));
+    let web_pods = commands::kuboard_get_pods_page(Some("default".to_string()), Some("app=web".to_string()), None, Some(1), None, app.state())
+        .await
+        .unwrap();
+    assert_eq!((web_pods.items.len(), web_pods.remaining_item_count), (1, Some(1)));
+    assert!(web_pods.continue_token.is_some());
+    let web_objects = commands::kuboard_list_resources_page(
+        String::new(), "v1".to_string(), "Pod".to_string(), Some("default".to_string()), Some("app=web".to_string()), None, Some(1), None, app.state(),
+    ).await.unwrap();
+    assert_eq!((web_objects.items.len(), web_objects.remaining_item_count), (1, Some(1)));
+
+    // A profile limited to one namespace pages through it without naming it
+    *app.state::<AppState>().profile.write().await = KuboardProfile {
+        kind: ProfileKind::Developer,
+        namespaces: vec!["kube-system".to_string()],
+    };
+    let limited = commands::kuboard_get_pods_page(None, None, None, None, None, app.state()).await.unwrap();
+    assert!(limited.items.iter().all(|pod| pod.metadata.namespace.as_deref() == Some("kube-system")));
+    assert_eq!(limited.items.len(), 2);
+    assert!(page(Some("default"), None).await.is_err());
+}
+
+#[tokio::test]
+async fn test_mock_cluster_event_trends() {
+    use serde_json::json;
+
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+    let now = chrono::Utc::now();
+    let minutes_ago = |minutes: i64| (now - chrono::Duration::minutes(minutes)).to_rfc3339();
+    cluster.insert(json!({
+        "apiVersion": "v1", "kind": "Event",
+        "metadata": { "name": "web-7d9f8-abc12.backoff", "namespace": "default" },
+        "involvedObject": { "kind": "Pod", "name": "web-7d9f8-abc12", "namespace": "default" },
+        "type": "Warning",
+        "reason": "BackOff",
+        "message": "Back-off restarting failed container nginx",
+        "count": 5,
+        "firstTimestamp": minutes_ago(20),
+        "lastTimestamp": minutes_ago(1),
+    }));
+    cluster.insert(json!({
+        "apiVersion": "v1", "kind": "Event",
+        "metadata": { "name": "db-0.scheduling", "namespace": "default" },
+        "involvedObject": { "kind": "Pod", "name": "db-0", "namespace": "default" },
+        "type": "Warning",
+        "reason": "FailedScheduling",
+        "message": "0/2 nodes are available: 2 Insufficient memory.",
+        "count": 1,
+        "firstTimestamp": minutes_ago(3),
+        "lastTimestamp": minutes_ago(3),
+    }));
+
+    let trends = commands::kuboard_get_event_trends(None, Some(30), Some(10), app.state()).await.unwrap();
+    // The fixture's Pulled event is long outside the window
+    assert_eq!((trends.total, trends.warnings, trends.buckets.len()), (6, 6, 3));
+    assert_eq!(trends.namespaces[0].namespace, "default");
+    let reasons: Vec<(&str, i64)> = trends.reasons.iter().map(|r| (r.reason.as_str(), r.count)).collect();
+    assert_eq!(reasons, vec![("BackOff", 5), ("FailedScheduling", 1)]);
+
+    let elsewhere = commands::kuboard_get_event_trends(Some("kube-system".to_string()), None, None, app.state()).await.unwrap();
+    assert_eq!((elsewhere.total, elsewhere.window_minutes), (0, 60));
+}
+
+#[tokio::test]
+async fn test_mock_cluster_update_configmap_key() {
+    use serde_json::json;
+
+    let cluster = MockCluster::new();
+    let app = mock_app(&cluster);
+    cluster.insert(json!({
+        "apiVersion": "apps/v1", "kind": "Deployment",
+        "metadata": { "name": "api", "namespace": "default" },
+        "spec": {
+            "replicas": 1,
+            "selector": { "matchLabels": { "app": "api" } },
+            "template": {
+                "metadata": { "labels": { "app": "api" } },
+                "spec": { "containers": [{ "name": "api", "image": "api:1", "envFrom": [{ "configMapRef": { "name": "web-config" } }] }] },
+            },
+        },
+    }));
+    let update = |value: &str, restart: Option<bool>, dry_run: Option<bool>| {
+        commands::kuboard_update_configmap_key(
+            "web-config".to_string(), "default".to_string(), "LOG_LEVEL".to_string(), value.to_string(), restart, dry_run, app.state(),
+        )
+    };
+    let restarted_at = |deployment: k8s_openapi::api::apps::v1::Deployment| {
+        deployment.spec.unwrap().template.metadata.unwrap().annotations.unwrap_or_default().remove("kubectl.kubernetes.io/restartedAt")
+    };
+
+    let preview = update("debug", Some(true), Some(true)).await.unwrap();
+    assert!(preview.changed && preview.dry_run && preview.consumers[0].restarted);
+    let configs = commands::kuboard_get_configmaps(Some("default".to_string()), None, None, app.state()).await.unwrap();
+    let config = configs.iter().find(|c| c.metadata.name.as_deref() == Some("web-config")).unwrap();
+    assert_eq!(config.data.as_ref().unwrap()["LOG_LEVEL"], "info");
+    let api = commands::kuboard_get_deployment("api".to_string(), "default".to_string(), app.state()).await.unwrap();
+    assert_eq!(restarted_at(api), None);
+
+    let updated = update("debug", Some(true), None).await.unwrap();
+    assert_eq!((updated.previous_value.as_deref(), updated.changed), (Some("info"), true));
+    let consumers: Vec<(&str, &str, bool)> = updated.consumers.iter().map(|c| (c.kind.as_str(), c.name.as_str(), c.restarted)).collect();
+    assert_eq!(consumers, vec![("Deployment", "api", true)]);
+    assert_eq!(updated.consumers[0].uses, vec!["envFrom"]);
+    let api = commands::kuboard_get_deployment("api".to_string(), "default".to_string(), app.state()).await.unwrap();
+    assert!(restarted_at(api).is_some());
+    let web = commands::kuboard_get_deployment("web".to_string(), "default".to_string(), app.state()).await.unwrap();
+    assert_eq!(restarted_at(web), None);
+
+    let unchanged = update("debug", Some(true), None).await.unwrap();
+    assert!(!unchanged.changed && !unchanged.consumers[0].restarted);
+
+    let missing = commands::kuboard_update_configmap_key(
+        "missing".to_string(), "default".to_string(), "LOG_LEVEL".to_string(), "debug".to_string(), None, None, app.state(),
+    ).await;
+    assert_eq!(missing.unwrap_err(), "ConfigMap default/missing not found");
+}